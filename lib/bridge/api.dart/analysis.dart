@@ -3,21 +3,27 @@
 
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
-import 'analysis/classifier.dart';
 import 'analysis/quantizer.dart';
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'types.dart';
 
 /// Classification result combining sound type and timing feedback
 ///
 /// This struct is sent to the Dart UI via flutter_rust_bridge Stream
 /// for real-time display of detected sounds and timing accuracy.
+///
+/// Serialization follows `schema_version`: fields newer than that version
+/// are omitted so older clients keep seeing the shape they were built for.
 class ClassificationResult {
   /// Detected beatbox sound type
   final BeatboxHit sound;
 
   /// Timing accuracy relative to metronome grid
-  final TimingFeedback timing;
+  ///
+  /// `None` in classification-only mode (BPM 0), where no metronome grid
+  /// exists and the quantizer is never run.
+  final TimingFeedback? timing;
 
   /// Timestamp in milliseconds since engine start
   final BigInt timestampMs;
@@ -26,11 +32,59 @@ class ClassificationResult {
   /// Calculated as max_score / sum_of_all_scores
   final double confidence;
 
+  /// Display name for `sound` (see [`labels::display_name`])
+  final String label;
+
+  /// Hit fell outside every active listening window (see
+  /// [`listening::ListeningWindows`]); such hits should not be scored
+  final bool extra;
+
+  /// Serialization shape of this result (see [`schema`])
+  final int schemaVersion;
+
+  /// Exponential moving average of recent confidences for this sound;
+  /// `None` unless `confidence_smoothing` is enabled
+  final double? smoothedConfidence;
+
+  /// Beat within the bar the hit landed on (0-based); only set while a
+  /// metronome is running
+  final int? beatIndex;
+
+  /// Subdivision within that beat (0-based); only set while a metronome
+  /// is running
+  final int? subdivisionIndex;
+
+  /// Wall-clock time (Unix milliseconds) when the result was produced,
+  /// for syncing with external systems; `timestamp_ms` stays
+  /// engine-relative
+  final BigInt wallClockMs;
+
+  /// Detector that triggered this result (debug information)
+  final HitTrigger trigger;
+
+  /// A second onset followed within `flam_window_ms`; the pair is reported
+  /// as this one result
+  final bool flam;
+
+  /// Enough of the hit's window sat at the input rails that its features
+  /// are unreliable (see `clip_fraction_threshold`); confidence is reduced
+  final bool clipped;
+
   const ClassificationResult({
     required this.sound,
-    required this.timing,
+    this.timing,
     required this.timestampMs,
     required this.confidence,
+    required this.label,
+    required this.extra,
+    required this.schemaVersion,
+    this.smoothedConfidence,
+    this.beatIndex,
+    this.subdivisionIndex,
+    required this.wallClockMs,
+    required this.trigger,
+    required this.flam,
+    required this.clipped,
   });
 
   @override
@@ -38,7 +92,17 @@ class ClassificationResult {
       sound.hashCode ^
       timing.hashCode ^
       timestampMs.hashCode ^
-      confidence.hashCode;
+      confidence.hashCode ^
+      label.hashCode ^
+      extra.hashCode ^
+      schemaVersion.hashCode ^
+      smoothedConfidence.hashCode ^
+      beatIndex.hashCode ^
+      subdivisionIndex.hashCode ^
+      wallClockMs.hashCode ^
+      trigger.hashCode ^
+      flam.hashCode ^
+      clipped.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -48,5 +112,24 @@ class ClassificationResult {
           sound == other.sound &&
           timing == other.timing &&
           timestampMs == other.timestampMs &&
-          confidence == other.confidence;
+          confidence == other.confidence &&
+          label == other.label &&
+          extra == other.extra &&
+          schemaVersion == other.schemaVersion &&
+          smoothedConfidence == other.smoothedConfidence &&
+          beatIndex == other.beatIndex &&
+          subdivisionIndex == other.subdivisionIndex &&
+          wallClockMs == other.wallClockMs &&
+          trigger == other.trigger &&
+          flam == other.flam &&
+          clipped == other.clipped;
+}
+
+/// Detector behind a classification result
+enum HitTrigger {
+  /// Spectral-flux onset detector
+  onset,
+
+  /// Gate-level crossing detector
+  levelCrossing,
 }
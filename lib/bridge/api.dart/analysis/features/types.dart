@@ -0,0 +1,71 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// Features extracted from an audio window
+///
+/// These features are used for beatbox sound classification (kick, snare, hi-hat).
+/// Each feature captures different acoustic properties of the audio signal.
+class Features {
+  /// Spectral centroid in Hz (weighted mean frequency)
+  ///
+  /// Measures the "brightness" of the sound. Higher values indicate
+  /// more high-frequency content.
+  final double centroid;
+
+  /// Zero-crossing rate (0.0 to 1.0, normalized)
+  ///
+  /// Measures how often the signal crosses zero. Higher values indicate
+  /// more noise-like or high-frequency content.
+  final double zcr;
+
+  /// Spectral flatness (0.0 to 1.0, geometric/arithmetic mean ratio)
+  ///
+  /// Measures how tonal vs. noise-like the signal is.
+  /// 0.0 = pure tone (e.g., sine wave)
+  /// 1.0 = white noise
+  final double flatness;
+
+  /// Spectral rolloff in Hz (85% energy threshold)
+  ///
+  /// The frequency below which 85% of the spectral energy is contained.
+  /// Indicates the frequency range of the signal.
+  final double rolloff;
+
+  /// Decay time in milliseconds (temporal envelope)
+  ///
+  /// Measures how quickly the signal amplitude decays from its peak.
+  /// Useful for distinguishing percussive sounds with different attack/decay.
+  final double decayTimeMs;
+
+  const Features({
+    required this.centroid,
+    required this.zcr,
+    required this.flatness,
+    required this.rolloff,
+    required this.decayTimeMs,
+  });
+
+  @override
+  int get hashCode =>
+      centroid.hashCode ^
+      zcr.hashCode ^
+      flatness.hashCode ^
+      rolloff.hashCode ^
+      decayTimeMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Features &&
+          runtimeType == other.runtimeType &&
+          centroid == other.centroid &&
+          zcr == other.zcr &&
+          flatness == other.flatness &&
+          rolloff == other.rolloff &&
+          decayTimeMs == other.decayTimeMs;
+}
@@ -0,0 +1,44 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import '../types.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// A classifiable sound as presented to the UI
+class SoundDescriptor {
+  /// Canonical sound value
+  final BeatboxHit sound;
+
+  /// Display name (see [`display_name`])
+  final String label;
+
+  /// Lowest classifier level that produces this sound
+  final int minLevel;
+
+  /// Whether the classifier can produce this sound at the queried level
+  final bool active;
+
+  const SoundDescriptor({
+    required this.sound,
+    required this.label,
+    required this.minLevel,
+    required this.active,
+  });
+
+  @override
+  int get hashCode =>
+      sound.hashCode ^ label.hashCode ^ minLevel.hashCode ^ active.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SoundDescriptor &&
+          runtimeType == other.runtimeType &&
+          sound == other.sound &&
+          label == other.label &&
+          minLevel == other.minLevel &&
+          active == other.active;
+}
@@ -4,7 +4,8 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import 'analysis.dart';
-import 'analysis/classifier.dart';
+import 'analysis/features/types.dart';
+import 'analysis/labels.dart';
 import 'analysis/quantizer.dart';
 import 'calibration/progress.dart';
 import 'engine/core.dart';
@@ -12,6 +13,7 @@ import 'error/audio.dart';
 import 'error/calibration.dart';
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'types.dart';
 
 /// Initialize and greet from Rust
 ///
@@ -33,6 +35,60 @@ String greet({required String name}) =>
 /// * `Result<String>` - Version string
 String getVersion() => RustLib.instance.api.crateApiGetVersion();
 
+/// Extract DSP features from an arbitrary audio clip
+///
+/// Runs the same `FeatureExtractor` the analysis thread uses, without
+/// starting the audio engine, so Dart tooling can analyze a recorded clip.
+///
+/// # Arguments
+/// * `samples` - Mono audio samples
+/// * `sample_rate` - Sample rate of `samples` in Hz
+///
+/// # Returns
+/// * `Features` - Centroid, ZCR, flatness, rolloff and decay time
+///
+/// # Errors
+/// - The feature extractor rejects its FFT size
+Features extractFeatures({
+  required List<double> samples,
+  required int sampleRate,
+}) => RustLib.instance.api.crateApiExtractFeatures(
+  samples: samples,
+  sampleRate: sampleRate,
+);
+
+/// Compute the magnitude spectrum of an audio buffer
+///
+/// Powers the spectrum analyzer widget; independent of the classification
+/// pipeline. The first `fft_size` samples are Hann-windowed (shorter
+/// buffers are zero-padded) and transformed with the same `FftProcessor`
+/// the feature extractor uses.
+///
+/// # Arguments
+/// * `samples` - Mono audio samples
+/// * `sample_rate` - Sample rate of `samples` in Hz
+/// * `fft_size` - Transform size; bin `k` is centred on `k * sample_rate / fft_size` Hz
+/// * `in_db` - Return magnitudes in decibels (floored at -120 dB)
+///
+/// # Returns
+/// * `Vec<f32>` - `fft_size / 2 + 1` magnitudes from DC to Nyquist
+///
+/// # Errors
+/// - `samples` is empty or contains NaN/Inf
+/// - `sample_rate` is 0
+/// - `fft_size` is outside the supported FFT range
+Float32List computeSpectrum({
+  required List<double> samples,
+  required int sampleRate,
+  required int fftSize,
+  required bool inDb,
+}) => RustLib.instance.api.crateApiComputeSpectrum(
+  samples: samples,
+  sampleRate: sampleRate,
+  fftSize: fftSize,
+  inDb: inDb,
+);
+
 /// Start the audio engine with specified BPM
 ///
 /// Initializes the audio engine, starts full-duplex audio streams with Oboe,
@@ -53,6 +109,26 @@ String getVersion() => RustLib.instance.api.crateApiGetVersion();
 Future<void> startAudio({required int bpm}) =>
     RustLib.instance.api.crateApiStartAudio(bpm: bpm);
 
+/// Start the metronome without microphone input
+///
+/// Opens only the output stream and plays clicks at the given tempo. No input
+/// stream or analysis thread is created, so no microphone permission is needed
+/// and no classification results are emitted. Use `stop_audio` to stop.
+///
+/// # Arguments
+/// * `bpm` - Beats per minute (typically 40-240)
+///
+/// # Returns
+/// * `Ok(())` - Metronome started successfully
+/// * `Err(AudioError)` - Error if initialization fails
+///
+/// # Errors
+/// - Output stream cannot be opened (device busy)
+/// - Audio engine already running (call stop_audio first)
+/// - Invalid BPM value (must be > 0)
+Future<void> startMetronomeOnly({required int bpm}) =>
+    RustLib.instance.api.crateApiStartMetronomeOnly(bpm: bpm);
+
 /// Stop the audio engine
 ///
 /// Stops audio streams, shuts down the analysis thread, and releases resources.
@@ -82,6 +158,41 @@ Future<void> stopAudio() => RustLib.instance.api.crateApiStopAudio();
 Future<void> setBpm({required int bpm}) =>
     RustLib.instance.api.crateApiSetBpm(bpm: bpm);
 
+/// Set the metronome click volume
+///
+/// `volume` runs from 0.0 (silent) to 1.0 (full level) and is clamped to
+/// that range. Takes effect on the next output buffer, whether or not the
+/// engine is running, and persists across restarts.
+void setMetronomeVolume({required double volume}) =>
+    RustLib.instance.api.crateApiSetMetronomeVolume(volume: volume);
+
+/// Mute or unmute the metronome click
+///
+/// Muting silences the click only: the beat grid keeps running, so timing
+/// feedback is still scored against it.
+void setMetronomeMuted({required bool muted}) =>
+    RustLib.instance.api.crateApiSetMetronomeMuted(muted: muted);
+
+/// Live adaptive onset threshold of the running analysis thread
+///
+/// The level the onset detection function must currently exceed for a hit
+/// to register, in the units of `threshold_offset`. Updated once per
+/// analyzed buffer; 0.0 before the engine has processed any audio.
+double getCurrentOnsetThreshold() =>
+    RustLib.instance.api.crateApiGetCurrentOnsetThreshold();
+
+/// Push a scripted classification result to `classification_stream` subscribers
+///
+/// Intended for UI tests: the result is delivered exactly as if the audio
+/// engine had detected it, so animations can be driven without a microphone.
+/// Release builds reject the call.
+///
+/// # Errors
+/// - `Unsupported` when called from a release build
+/// - Lock poisoning on the classification channel
+Future<void> injectClassification({required ClassificationResult result}) =>
+    RustLib.instance.api.crateApiInjectClassification(result: result);
+
 /// Apply parameter patch to running engine (BPM/threshold updates)
 Future<void> applyParams({required ParamPatch patch}) =>
     RustLib.instance.api.crateApiApplyParams(patch: patch);
@@ -152,6 +263,27 @@ Future<void> resetCalibrationSession() =>
 Future<void> finishCalibration() =>
     RustLib.instance.api.crateApiFinishCalibration();
 
+/// Calibrate from pre-recorded WAV clips instead of a live session
+///
+/// Each list holds one clip per hit for that sound, and all lists must be
+/// the same length. The resulting state is loaded into the engine exactly
+/// like `finish_calibration`, and returned as JSON for persistence (same
+/// format as `get_calibration_state`).
+///
+/// # Errors
+/// - A clip cannot be decoded
+/// - Mismatched or empty clip lists
+/// - Sample validation failed (out of range features)
+Future<String> calibrateFromWavs({
+  required List<String> kick,
+  required List<String> snare,
+  required List<String> hihat,
+}) => RustLib.instance.api.crateApiCalibrateFromWavs(
+  kick: kick,
+  snare: snare,
+  hihat: hihat,
+);
+
 /// User confirms current calibration step is OK and wants to advance
 ///
 /// Called when user clicks "OK" after reviewing the collected samples for current sound.
@@ -217,10 +349,11 @@ Stream<CalibrationProgress> calibrationStream() =>
 ///
 /// # Returns
 /// * `Ok(())` - Calibration state loaded successfully
-/// * `Err(CalibrationError)` - Error if deserialization fails or lock poisoning
+/// * `Err(CalibrationError)` - Error if deserialization or validation fails, or lock poisoning
 ///
 /// # Errors
 /// - JSON deserialization error (invalid format)
+/// - Implausible thresholds, see `CalibrationState::validate`
 /// - Lock poisoning on calibration state
 ///
 /// # Usage
@@ -235,6 +368,28 @@ Stream<CalibrationProgress> calibrationStream() =>
 Future<void> loadCalibrationState({required String json}) =>
     RustLib.instance.api.crateApiLoadCalibrationState(json: json);
 
+/// Load a built-in starter calibration by name
+///
+/// Gives new users reasonable thresholds before they calibrate. Presets are
+/// embedded in the binary: `phone_builtin`, `headset` and `usb_condenser`.
+///
+/// # Errors
+/// - `UnknownPreset` for an unknown name (the message lists the presets)
+/// - Lock poisoning on calibration state
+Future<void> loadPresetCalibration({required String name}) =>
+    RustLib.instance.api.crateApiLoadPresetCalibration(name: name);
+
+/// Load the built-in starter calibration suited to a device model
+///
+/// `model` is the phone or microphone model name; recognized headsets and
+/// USB microphones get their own preset, anything else `phone_builtin`.
+/// Returns the name of the preset that was loaded.
+///
+/// # Errors
+/// - Lock poisoning on calibration state
+Future<String> loadPresetCalibrationForDevice({required String model}) =>
+    RustLib.instance.api.crateApiLoadPresetCalibrationForDevice(model: model);
+
 /// Get current calibration state as JSON
 ///
 /// Retrieves the current calibration state serialized to JSON string.
@@ -305,6 +460,65 @@ Future<void> updateCalibrationThreshold({
 Future<(double, double, double)> getCurrentAudioLevel() =>
     RustLib.instance.api.crateApiGetCurrentAudioLevel();
 
+/// Override the display names attached to classification results
+///
+/// Keys are canonical sound names as serialized in `ClassificationResult`
+/// (`"Kick"`, `"Snare"`, `"HiHat"`, ...); values are the labels to show.
+/// Each call replaces all previous overrides, and an empty list restores
+/// the defaults. Classification logic is unaffected.
+///
+/// # Returns
+/// Number of labels applied (unknown sound names are skipped)
+int setSoundLabels({required List<(String, String)> labels}) =>
+    RustLib.instance.api.crateApiSetSoundLabels(labels: labels);
+
+/// Reset all telemetry counters and history
+///
+/// Clears the event history, event and suppression counters, the latency
+/// window and streaks so a new measurement starts from zero.
+void resetTelemetry() => RustLib.instance.api.crateApiResetTelemetry();
+
+/// List every supported sound with its label and whether it is active
+///
+/// "Active" follows the level the classifier will actually run at: a
+/// Level 2 setting without Level 2 thresholds classifies with Level 1 rules,
+/// so only the basic sounds are active. Lets the UI build the calibration
+/// sequence and legend without hardcoding sounds.
+List<SoundDescriptor> supportedSounds() =>
+    RustLib.instance.api.crateApiSupportedSounds();
+
+/// Only score hits inside the given windows (call-and-response exercises)
+///
+/// Each window is `(start_ms, end_ms)` on the same clock as
+/// `ClassificationResult::timestamp_ms`. Hits outside every window are
+/// flagged with `extra` or dropped, per `outside_window_policy` in the
+/// onset config. Each call replaces the previous windows; an empty list
+/// scores every hit again.
+///
+/// # Returns
+/// Number of windows applied (windows ending before they start are skipped)
+int setListeningWindows({required List<(BigInt, BigInt)> windows}) =>
+    RustLib.instance.api.crateApiSetListeningWindows(windows: windows);
+
+/// Choose the JSON shape of `ClassificationResult`
+///
+/// Version 1 is the original shape (`sound`, `timing`, `timestamp_ms`,
+/// `confidence`); version 2 adds `label`, `extra` and `schema_version`;
+/// version 3 adds `smoothed_confidence`, `beat_index`, `subdivision_index`,
+/// `wall_clock_ms`, `trigger`, `flam` and `clipped`.
+/// JSON consumers built against an older shape (e.g. tools reading the
+/// debug server's classification stream) request their version once at
+/// startup; results classified afterwards are serialized in that shape.
+/// Out-of-range versions are clamped to the supported range.
+///
+/// Debug/JSON only: `classification_stream` always carries every field,
+/// since its Dart class is generated from the same struct.
+///
+/// # Returns
+/// The version applied
+int setJsonResultSchemaVersion({required int version}) =>
+    RustLib.instance.api.crateApiSetJsonResultSchemaVersion(version: version);
+
 /// Enable or disable pipeline tracing at runtime
 ///
 /// When enabled, detailed trace logs are emitted for each pipeline stage:
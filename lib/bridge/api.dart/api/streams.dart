@@ -4,11 +4,13 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../analysis.dart';
-import '../analysis/classifier.dart';
+import '../analysis/features/types.dart';
 import '../analysis/quantizer.dart';
+import '../calibration/progress.dart';
 import '../engine/core.dart';
 import '../frb_generated.dart';
 import '../telemetry/events.dart';
+import '../types.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'types.dart';
 
@@ -29,8 +31,34 @@ Stream<TelemetryEvent> telemetryStream() =>
 Stream<MetricEvent> diagnosticMetricsStream() =>
     RustLib.instance.api.crateApiStreamsDiagnosticMetricsStream();
 
+/// Stream of diagnostic metrics restricted to the listed event kinds.
+///
+/// Same events as [`diagnostic_metrics_stream`], filtered before they cross
+/// the FFI boundary, for widgets that only show one kind of metric.
+Stream<MetricEvent> telemetryStreamFiltered({
+  required List<MetricEventKind> kinds,
+}) => RustLib.instance.api.crateApiStreamsTelemetryStreamFiltered(kinds: kinds);
+
 /// Stream of onset events for debug visualization
 ///
 /// Emits OnsetEvent whenever an onset is detected.
 Stream<OnsetEvent> onsetEventsStream() =>
     RustLib.instance.api.crateApiStreamsOnsetEventsStream();
+
+/// High-rate stream of calibration feature frames for tuning UIs
+///
+/// Emits a CalibrationDebugFrame (centroid, ZCR, RMS, max amplitude) for
+/// every analysed buffer while calibration runs, whether or not the sound
+/// is accepted. Only active when `calibration.debug_stream` is enabled in
+/// the app config.
+Stream<CalibrationDebugFrame> calibrationDebugStream() =>
+    RustLib.instance.api.crateApiStreamsCalibrationDebugStream();
+
+/// Provisional classifications of calibration hits
+///
+/// Each hit collected during calibration is also classified against the
+/// calibration currently loaded, so the UI can show how it would have been
+/// heard. The hits still become calibration samples. Only active when `calibration.preview_classification`
+/// is enabled in the app config.
+Stream<ClassificationResult> calibrationPreviewStream() =>
+    RustLib.instance.api.crateApiStreamsCalibrationPreviewStream();
@@ -4,16 +4,21 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../analysis.dart';
-import '../analysis/classifier.dart';
+import '../analysis/features/types.dart';
 import '../analysis/quantizer.dart';
 import '../frb_generated.dart';
+import '../types.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
 // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
 
 /// Audio metrics for debug visualization
 class AudioMetrics {
+  /// Raw RMS of the current analysis chunk
   final double rms;
+
+  /// RMS with VU-meter ballistics (see `vu_time_constant_ms`), for display
+  final double smoothedRms;
   final double spectralCentroid;
   final double spectralFlux;
   final BigInt frameNumber;
@@ -21,6 +26,7 @@ class AudioMetrics {
 
   const AudioMetrics({
     required this.rms,
+    required this.smoothedRms,
     required this.spectralCentroid,
     required this.spectralFlux,
     required this.frameNumber,
@@ -30,6 +36,7 @@ class AudioMetrics {
   @override
   int get hashCode =>
       rms.hashCode ^
+      smoothedRms.hashCode ^
       spectralCentroid.hashCode ^
       spectralFlux.hashCode ^
       frameNumber.hashCode ^
@@ -41,6 +48,7 @@ class AudioMetrics {
       other is AudioMetrics &&
           runtimeType == other.runtimeType &&
           rms == other.rms &&
+          smoothedRms == other.smoothedRms &&
           spectralCentroid == other.spectralCentroid &&
           spectralFlux == other.spectralFlux &&
           frameNumber == other.frameNumber &&
@@ -50,6 +58,8 @@ class AudioMetrics {
 /// Onset event with classification details
 class OnsetEvent {
   final BigInt timestamp;
+
+  /// RMS level of the onset window
   final double energy;
   final double centroid;
   final double zcr;
@@ -58,6 +68,14 @@ class OnsetEvent {
   final double decayTimeMs;
   final ClassificationResult? classification;
 
+  /// Onset strength: prominence of the spectral flux peak above the
+  /// adaptive threshold
+  final double strength;
+
+  /// Full feature set extracted from the onset window (`None` in monitor
+  /// mode, where onsets are not analysed)
+  final Features? features;
+
   const OnsetEvent({
     required this.timestamp,
     required this.energy,
@@ -67,6 +85,8 @@ class OnsetEvent {
     required this.rolloff,
     required this.decayTimeMs,
     this.classification,
+    required this.strength,
+    this.features,
   });
 
   @override
@@ -78,7 +98,9 @@ class OnsetEvent {
       flatness.hashCode ^
       rolloff.hashCode ^
       decayTimeMs.hashCode ^
-      classification.hashCode;
+      classification.hashCode ^
+      strength.hashCode ^
+      features.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -92,5 +114,7 @@ class OnsetEvent {
           flatness == other.flatness &&
           rolloff == other.rolloff &&
           decayTimeMs == other.decayTimeMs &&
-          classification == other.classification;
+          classification == other.classification &&
+          strength == other.strength &&
+          features == other.features;
 }
@@ -6,6 +6,61 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
+/// Live feature snapshot emitted on every analysis buffer during calibration
+///
+/// Unlike the `debug` payload on `CalibrationProgress`, frames are sent
+/// regardless of whether a sample was accepted, so tuning UIs can plot the
+/// full feature distribution in real time.
+class CalibrationDebugFrame {
+  /// Incrementing sequence to detect dropped frames
+  final BigInt seq;
+
+  /// Calibration phase the frame was captured in
+  final CalibrationSound sound;
+
+  /// Spectral centroid of the latest window (Hz)
+  final double centroid;
+
+  /// Zero-crossing rate of the latest window
+  final double zcr;
+
+  /// RMS of the latest window
+  final double rms;
+
+  /// Peak absolute amplitude of the latest window
+  final double maxAmp;
+
+  const CalibrationDebugFrame({
+    required this.seq,
+    required this.sound,
+    required this.centroid,
+    required this.zcr,
+    required this.rms,
+    required this.maxAmp,
+  });
+
+  @override
+  int get hashCode =>
+      seq.hashCode ^
+      sound.hashCode ^
+      centroid.hashCode ^
+      zcr.hashCode ^
+      rms.hashCode ^
+      maxAmp.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CalibrationDebugFrame &&
+          runtimeType == other.runtimeType &&
+          seq == other.seq &&
+          sound == other.sound &&
+          centroid == other.centroid &&
+          zcr == other.zcr &&
+          rms == other.rms &&
+          maxAmp == other.maxAmp;
+}
+
 /// Guidance payload accompanying calibration progress updates
 class CalibrationGuidance {
   /// Sound currently being calibrated
@@ -52,6 +107,13 @@ enum CalibrationGuidanceReason {
 
   /// Audio appears clipped or overly loud
   clipped,
+
+  /// Measured noise floor is too high for reliable detection
+  tooNoisy,
+
+  /// Accepted sample's features fall outside the typical range for the
+  /// sound being calibrated (e.g. a kick with a snare-like centroid)
+  unexpectedTimbre,
 }
 
 /// Progress information for the current calibration step
@@ -77,6 +139,21 @@ class CalibrationProgress {
   /// Whether a manual accept candidate is available for promotion
   final bool manualAcceptAvailable;
 
+  /// Whether `current_sound` was reached by auto-advance rather than
+  /// confirmation; stays set until that sound collects its first sample
+  final bool autoAdvanced;
+
+  /// Set on the first update after `current_sound` changed, so the UI can
+  /// react to phase transitions without diffing updates
+  final bool phaseChanged;
+
+  /// Estimated time left in the current phase (noise floor only), so the
+  /// UI can say "stay quiet for 2 more seconds"
+  final int? estimatedRemainingMs;
+
+  /// Set only on the final update, sent once calibration is finished
+  final CalibrationSummary? completed;
+
   /// Debug info (feature gates and levels) for instrumentation builds
   final CalibrationProgressDebug? debug;
 
@@ -87,6 +164,10 @@ class CalibrationProgress {
     required this.waitingForConfirmation,
     this.guidance,
     required this.manualAcceptAvailable,
+    required this.autoAdvanced,
+    required this.phaseChanged,
+    this.estimatedRemainingMs,
+    this.completed,
     this.debug,
   });
 
@@ -98,6 +179,10 @@ class CalibrationProgress {
       waitingForConfirmation.hashCode ^
       guidance.hashCode ^
       manualAcceptAvailable.hashCode ^
+      autoAdvanced.hashCode ^
+      phaseChanged.hashCode ^
+      estimatedRemainingMs.hashCode ^
+      completed.hashCode ^
       debug.hashCode;
 
   @override
@@ -111,6 +196,10 @@ class CalibrationProgress {
           waitingForConfirmation == other.waitingForConfirmation &&
           guidance == other.guidance &&
           manualAcceptAvailable == other.manualAcceptAvailable &&
+          autoAdvanced == other.autoAdvanced &&
+          phaseChanged == other.phaseChanged &&
+          estimatedRemainingMs == other.estimatedRemainingMs &&
+          completed == other.completed &&
           debug == other.debug;
 }
 
@@ -204,4 +293,54 @@ enum CalibrationSound {
 
   /// Step 4: Collecting hi-hat samples
   hiHat,
+
+  /// Optional step before the noise floor: measuring a few of the user's
+  /// loudest hits to derive the input gain
+  inputLevel,
+}
+
+/// Thresholds computed by a finished calibration
+///
+/// Sent on the progress stream so the UI can show a summary without a
+/// separate `get_calibration_state` call.
+class CalibrationSummary {
+  final double tKickCentroid;
+  final double tKickZcr;
+  final double tSnareCentroid;
+  final double tHihatZcr;
+  final double noiseFloorRms;
+
+  /// How distinct the calibrated sounds are (0-1), see
+  /// `CalibrationFeatureStats::quality_score`. `None` without feature stats.
+  final double? qualityScore;
+
+  const CalibrationSummary({
+    required this.tKickCentroid,
+    required this.tKickZcr,
+    required this.tSnareCentroid,
+    required this.tHihatZcr,
+    required this.noiseFloorRms,
+    this.qualityScore,
+  });
+
+  @override
+  int get hashCode =>
+      tKickCentroid.hashCode ^
+      tKickZcr.hashCode ^
+      tSnareCentroid.hashCode ^
+      tHihatZcr.hashCode ^
+      noiseFloorRms.hashCode ^
+      qualityScore.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CalibrationSummary &&
+          runtimeType == other.runtimeType &&
+          tKickCentroid == other.tKickCentroid &&
+          tKickZcr == other.tKickZcr &&
+          tSnareCentroid == other.tSnareCentroid &&
+          tHihatZcr == other.tHihatZcr &&
+          noiseFloorRms == other.noiseFloorRms &&
+          qualityScore == other.qualityScore;
 }
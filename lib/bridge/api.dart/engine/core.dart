@@ -14,11 +14,37 @@ class ParamPatch {
   final double? centroidThreshold;
   final double? zcrThreshold;
 
-  const ParamPatch({this.bpm, this.centroidThreshold, this.zcrThreshold});
+  /// Kick centroid threshold (Hz), `CalibrationState::t_kick_centroid`
+  final double? kickCentroidThreshold;
+
+  /// Kick ZCR threshold, `CalibrationState::t_kick_zcr`
+  final double? kickZcrThreshold;
+
+  /// Snare centroid threshold (Hz), `CalibrationState::t_snare_centroid`
+  final double? snareCentroidThreshold;
+
+  /// Hi-hat ZCR threshold, `CalibrationState::t_hihat_zcr`
+  final double? hihatZcrThreshold;
+
+  const ParamPatch({
+    this.bpm,
+    this.centroidThreshold,
+    this.zcrThreshold,
+    this.kickCentroidThreshold,
+    this.kickZcrThreshold,
+    this.snareCentroidThreshold,
+    this.hihatZcrThreshold,
+  });
 
   @override
   int get hashCode =>
-      bpm.hashCode ^ centroidThreshold.hashCode ^ zcrThreshold.hashCode;
+      bpm.hashCode ^
+      centroidThreshold.hashCode ^
+      zcrThreshold.hashCode ^
+      kickCentroidThreshold.hashCode ^
+      kickZcrThreshold.hashCode ^
+      snareCentroidThreshold.hashCode ^
+      hihatZcrThreshold.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -27,7 +53,11 @@ class ParamPatch {
           runtimeType == other.runtimeType &&
           bpm == other.bpm &&
           centroidThreshold == other.centroidThreshold &&
-          zcrThreshold == other.zcrThreshold;
+          zcrThreshold == other.zcrThreshold &&
+          kickCentroidThreshold == other.kickCentroidThreshold &&
+          kickZcrThreshold == other.kickZcrThreshold &&
+          snareCentroidThreshold == other.snareCentroidThreshold &&
+          hihatZcrThreshold == other.hihatZcrThreshold;
 }
 
 /// Telemetry event emitted by the engine core.
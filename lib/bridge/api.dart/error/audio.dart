@@ -48,6 +48,10 @@ sealed class AudioError with _$AudioError implements FrbException {
   /// Stream channel disconnected unexpectedly
   const factory AudioError.streamFailure({required String reason}) =
       AudioError_StreamFailure;
+
+  /// Operation is not available in this build (e.g. debug-only hooks)
+  const factory AudioError.unsupported({required String operation}) =
+      AudioError_Unsupported;
 }
 
 /// Audio error code constants exposed to Dart via FFI
@@ -56,7 +60,7 @@ sealed class AudioError with _$AudioError implements FrbException {
 /// shared between Rust and Dart. The flutter_rust_bridge will automatically
 /// generate corresponding Dart constants.
 ///
-/// Error code range: 1001-1010
+/// Error code range: 1001-1011
 class AudioErrorCodes {
   const AudioErrorCodes();
 
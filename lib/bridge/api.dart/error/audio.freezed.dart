@@ -55,7 +55,7 @@ extension AudioErrorPatterns on AudioError {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( AudioError_BpmInvalid value)?  bpmInvalid,TResult Function( AudioError_AlreadyRunning value)?  alreadyRunning,TResult Function( AudioError_NotRunning value)?  notRunning,TResult Function( AudioError_HardwareError value)?  hardwareError,TResult Function( AudioError_PermissionDenied value)?  permissionDenied,TResult Function( AudioError_StreamOpenFailed value)?  streamOpenFailed,TResult Function( AudioError_LockPoisoned value)?  lockPoisoned,TResult Function( AudioError_JniInitFailed value)?  jniInitFailed,TResult Function( AudioError_ContextNotInitialized value)?  contextNotInitialized,TResult Function( AudioError_StreamFailure value)?  streamFailure,required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( AudioError_BpmInvalid value)?  bpmInvalid,TResult Function( AudioError_AlreadyRunning value)?  alreadyRunning,TResult Function( AudioError_NotRunning value)?  notRunning,TResult Function( AudioError_HardwareError value)?  hardwareError,TResult Function( AudioError_PermissionDenied value)?  permissionDenied,TResult Function( AudioError_StreamOpenFailed value)?  streamOpenFailed,TResult Function( AudioError_LockPoisoned value)?  lockPoisoned,TResult Function( AudioError_JniInitFailed value)?  jniInitFailed,TResult Function( AudioError_ContextNotInitialized value)?  contextNotInitialized,TResult Function( AudioError_StreamFailure value)?  streamFailure,TResult Function( AudioError_Unsupported value)?  unsupported,required TResult orElse(),}){
 final _that = this;
 switch (_that) {
 case AudioError_BpmInvalid() when bpmInvalid != null:
@@ -68,7 +68,8 @@ return streamOpenFailed(_that);case AudioError_LockPoisoned() when lockPoisoned
 return lockPoisoned(_that);case AudioError_JniInitFailed() when jniInitFailed != null:
 return jniInitFailed(_that);case AudioError_ContextNotInitialized() when contextNotInitialized != null:
 return contextNotInitialized(_that);case AudioError_StreamFailure() when streamFailure != null:
-return streamFailure(_that);case _:
+return streamFailure(_that);case AudioError_Unsupported() when unsupported != null:
+return unsupported(_that);case _:
   return orElse();
 
 }
@@ -86,7 +87,7 @@ return streamFailure(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( AudioError_BpmInvalid value)  bpmInvalid,required TResult Function( AudioError_AlreadyRunning value)  alreadyRunning,required TResult Function( AudioError_NotRunning value)  notRunning,required TResult Function( AudioError_HardwareError value)  hardwareError,required TResult Function( AudioError_PermissionDenied value)  permissionDenied,required TResult Function( AudioError_StreamOpenFailed value)  streamOpenFailed,required TResult Function( AudioError_LockPoisoned value)  lockPoisoned,required TResult Function( AudioError_JniInitFailed value)  jniInitFailed,required TResult Function( AudioError_ContextNotInitialized value)  contextNotInitialized,required TResult Function( AudioError_StreamFailure value)  streamFailure,}){
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( AudioError_BpmInvalid value)  bpmInvalid,required TResult Function( AudioError_AlreadyRunning value)  alreadyRunning,required TResult Function( AudioError_NotRunning value)  notRunning,required TResult Function( AudioError_HardwareError value)  hardwareError,required TResult Function( AudioError_PermissionDenied value)  permissionDenied,required TResult Function( AudioError_StreamOpenFailed value)  streamOpenFailed,required TResult Function( AudioError_LockPoisoned value)  lockPoisoned,required TResult Function( AudioError_JniInitFailed value)  jniInitFailed,required TResult Function( AudioError_ContextNotInitialized value)  contextNotInitialized,required TResult Function( AudioError_StreamFailure value)  streamFailure,required TResult Function( AudioError_Unsupported value)  unsupported,}){
 final _that = this;
 switch (_that) {
 case AudioError_BpmInvalid():
@@ -99,7 +100,8 @@ return streamOpenFailed(_that);case AudioError_LockPoisoned():
 return lockPoisoned(_that);case AudioError_JniInitFailed():
 return jniInitFailed(_that);case AudioError_ContextNotInitialized():
 return contextNotInitialized(_that);case AudioError_StreamFailure():
-return streamFailure(_that);}
+return streamFailure(_that);case AudioError_Unsupported():
+return unsupported(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
 ///
@@ -113,7 +115,7 @@ return streamFailure(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( AudioError_BpmInvalid value)?  bpmInvalid,TResult? Function( AudioError_AlreadyRunning value)?  alreadyRunning,TResult? Function( AudioError_NotRunning value)?  notRunning,TResult? Function( AudioError_HardwareError value)?  hardwareError,TResult? Function( AudioError_PermissionDenied value)?  permissionDenied,TResult? Function( AudioError_StreamOpenFailed value)?  streamOpenFailed,TResult? Function( AudioError_LockPoisoned value)?  lockPoisoned,TResult? Function( AudioError_JniInitFailed value)?  jniInitFailed,TResult? Function( AudioError_ContextNotInitialized value)?  contextNotInitialized,TResult? Function( AudioError_StreamFailure value)?  streamFailure,}){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( AudioError_BpmInvalid value)?  bpmInvalid,TResult? Function( AudioError_AlreadyRunning value)?  alreadyRunning,TResult? Function( AudioError_NotRunning value)?  notRunning,TResult? Function( AudioError_HardwareError value)?  hardwareError,TResult? Function( AudioError_PermissionDenied value)?  permissionDenied,TResult? Function( AudioError_StreamOpenFailed value)?  streamOpenFailed,TResult? Function( AudioError_LockPoisoned value)?  lockPoisoned,TResult? Function( AudioError_JniInitFailed value)?  jniInitFailed,TResult? Function( AudioError_ContextNotInitialized value)?  contextNotInitialized,TResult? Function( AudioError_StreamFailure value)?  streamFailure,TResult? Function( AudioError_Unsupported value)?  unsupported,}){
 final _that = this;
 switch (_that) {
 case AudioError_BpmInvalid() when bpmInvalid != null:
@@ -126,7 +128,8 @@ return streamOpenFailed(_that);case AudioError_LockPoisoned() when lockPoisoned
 return lockPoisoned(_that);case AudioError_JniInitFailed() when jniInitFailed != null:
 return jniInitFailed(_that);case AudioError_ContextNotInitialized() when contextNotInitialized != null:
 return contextNotInitialized(_that);case AudioError_StreamFailure() when streamFailure != null:
-return streamFailure(_that);case _:
+return streamFailure(_that);case AudioError_Unsupported() when unsupported != null:
+return unsupported(_that);case _:
   return null;
 
 }
@@ -143,7 +146,7 @@ return streamFailure(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( int bpm)?  bpmInvalid,TResult Function()?  alreadyRunning,TResult Function()?  notRunning,TResult Function( String details)?  hardwareError,TResult Function()?  permissionDenied,TResult Function( String reason)?  streamOpenFailed,TResult Function( String component)?  lockPoisoned,TResult Function( String reason)?  jniInitFailed,TResult Function()?  contextNotInitialized,TResult Function( String reason)?  streamFailure,required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( int bpm)?  bpmInvalid,TResult Function()?  alreadyRunning,TResult Function()?  notRunning,TResult Function( String details)?  hardwareError,TResult Function()?  permissionDenied,TResult Function( String reason)?  streamOpenFailed,TResult Function( String component)?  lockPoisoned,TResult Function( String reason)?  jniInitFailed,TResult Function()?  contextNotInitialized,TResult Function( String reason)?  streamFailure,TResult Function( String operation)?  unsupported,required TResult orElse(),}) {final _that = this;
 switch (_that) {
 case AudioError_BpmInvalid() when bpmInvalid != null:
 return bpmInvalid(_that.bpm);case AudioError_AlreadyRunning() when alreadyRunning != null:
@@ -155,7 +158,8 @@ return streamOpenFailed(_that.reason);case AudioError_LockPoisoned() when lockPo
 return lockPoisoned(_that.component);case AudioError_JniInitFailed() when jniInitFailed != null:
 return jniInitFailed(_that.reason);case AudioError_ContextNotInitialized() when contextNotInitialized != null:
 return contextNotInitialized();case AudioError_StreamFailure() when streamFailure != null:
-return streamFailure(_that.reason);case _:
+return streamFailure(_that.reason);case AudioError_Unsupported() when unsupported != null:
+return unsupported(_that.operation);case _:
   return orElse();
 
 }
@@ -173,7 +177,7 @@ return streamFailure(_that.reason);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( int bpm)  bpmInvalid,required TResult Function()  alreadyRunning,required TResult Function()  notRunning,required TResult Function( String details)  hardwareError,required TResult Function()  permissionDenied,required TResult Function( String reason)  streamOpenFailed,required TResult Function( String component)  lockPoisoned,required TResult Function( String reason)  jniInitFailed,required TResult Function()  contextNotInitialized,required TResult Function( String reason)  streamFailure,}) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( int bpm)  bpmInvalid,required TResult Function()  alreadyRunning,required TResult Function()  notRunning,required TResult Function( String details)  hardwareError,required TResult Function()  permissionDenied,required TResult Function( String reason)  streamOpenFailed,required TResult Function( String component)  lockPoisoned,required TResult Function( String reason)  jniInitFailed,required TResult Function()  contextNotInitialized,required TResult Function( String reason)  streamFailure,required TResult Function( String operation)  unsupported,}) {final _that = this;
 switch (_that) {
 case AudioError_BpmInvalid():
 return bpmInvalid(_that.bpm);case AudioError_AlreadyRunning():
@@ -185,7 +189,8 @@ return streamOpenFailed(_that.reason);case AudioError_LockPoisoned():
 return lockPoisoned(_that.component);case AudioError_JniInitFailed():
 return jniInitFailed(_that.reason);case AudioError_ContextNotInitialized():
 return contextNotInitialized();case AudioError_StreamFailure():
-return streamFailure(_that.reason);}
+return streamFailure(_that.reason);case AudioError_Unsupported():
+return unsupported(_that.operation);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -199,7 +204,7 @@ return streamFailure(_that.reason);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( int bpm)?  bpmInvalid,TResult? Function()?  alreadyRunning,TResult? Function()?  notRunning,TResult? Function( String details)?  hardwareError,TResult? Function()?  permissionDenied,TResult? Function( String reason)?  streamOpenFailed,TResult? Function( String component)?  lockPoisoned,TResult? Function( String reason)?  jniInitFailed,TResult? Function()?  contextNotInitialized,TResult? Function( String reason)?  streamFailure,}) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( int bpm)?  bpmInvalid,TResult? Function()?  alreadyRunning,TResult? Function()?  notRunning,TResult? Function( String details)?  hardwareError,TResult? Function()?  permissionDenied,TResult? Function( String reason)?  streamOpenFailed,TResult? Function( String component)?  lockPoisoned,TResult? Function( String reason)?  jniInitFailed,TResult? Function()?  contextNotInitialized,TResult? Function( String reason)?  streamFailure,TResult? Function( String operation)?  unsupported,}) {final _that = this;
 switch (_that) {
 case AudioError_BpmInvalid() when bpmInvalid != null:
 return bpmInvalid(_that.bpm);case AudioError_AlreadyRunning() when alreadyRunning != null:
@@ -211,7 +216,8 @@ return streamOpenFailed(_that.reason);case AudioError_LockPoisoned() when lockPo
 return lockPoisoned(_that.component);case AudioError_JniInitFailed() when jniInitFailed != null:
 return jniInitFailed(_that.reason);case AudioError_ContextNotInitialized() when contextNotInitialized != null:
 return contextNotInitialized();case AudioError_StreamFailure() when streamFailure != null:
-return streamFailure(_that.reason);case _:
+return streamFailure(_that.reason);case AudioError_Unsupported() when unsupported != null:
+return unsupported(_that.operation);case _:
   return null;
 
 }
@@ -742,5 +748,71 @@ as String,
 
 
 }
+/// @nodoc
+
+
+class AudioError_Unsupported extends AudioError {
+  const AudioError_Unsupported({required this.operation}): super._();
+  
+
+ final  String operation;
+
+/// Create a copy of AudioError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$AudioError_UnsupportedCopyWith<AudioError_Unsupported> get copyWith => _$AudioError_UnsupportedCopyWithImpl<AudioError_Unsupported>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is AudioError_Unsupported&&(identical(other.operation, operation) || other.operation == operation));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,operation);
+
+@override
+String toString() {
+  return 'AudioError.unsupported(operation: $operation)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $AudioError_UnsupportedCopyWith<$Res> implements $AudioErrorCopyWith<$Res> {
+  factory $AudioError_UnsupportedCopyWith(AudioError_Unsupported value, $Res Function(AudioError_Unsupported) _then) = _$AudioError_UnsupportedCopyWithImpl;
+@useResult
+$Res call({
+ String operation
+});
+
+
+
+
+}
+/// @nodoc
+class _$AudioError_UnsupportedCopyWithImpl<$Res>
+    implements $AudioError_UnsupportedCopyWith<$Res> {
+  _$AudioError_UnsupportedCopyWithImpl(this._self, this._then);
+
+  final AudioError_Unsupported _self;
+  final $Res Function(AudioError_Unsupported) _then;
+
+/// Create a copy of AudioError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? operation = null,}) {
+  return _then(AudioError_Unsupported(
+operation: null == operation ? _self.operation : operation // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
 
 // dart format on
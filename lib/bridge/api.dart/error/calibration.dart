@@ -3,6 +3,7 @@
 
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
+import '../calibration/progress.dart';
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
@@ -13,9 +14,14 @@ sealed class CalibrationError with _$CalibrationError implements FrbException {
   const CalibrationError._();
 
   /// Insufficient samples collected for calibration
+  ///
+  /// `per_sound` breaks the totals down by sound when finalizing a
+  /// calibration, so the UI can say which sound needs more samples; it is
+  /// empty where no per-sound counts apply.
   const factory CalibrationError.insufficientSamples({
     required BigInt required_,
     required BigInt collected,
+    required List<SoundSampleCount> perSound,
   }) = CalibrationError_InsufficientSamples;
 
   /// Invalid features extracted from samples
@@ -36,6 +42,24 @@ sealed class CalibrationError with _$CalibrationError implements FrbException {
   /// Calibration timed out waiting for native engine coordination
   const factory CalibrationError.timeout({required String reason}) =
       CalibrationError_Timeout;
+
+  /// Calibration file could not be read or written
+  const factory CalibrationError.io({required String reason}) =
+      CalibrationError_Io;
+
+  /// Calibration file or clip contents could not be parsed
+  const factory CalibrationError.parse({required String reason}) =
+      CalibrationError_Parse;
+
+  /// No built-in calibration preset has this name
+  const factory CalibrationError.unknownPreset({required String name}) =
+      CalibrationError_UnknownPreset;
+
+  /// The operation only applies to sound collection, but `phase` is a
+  /// level measurement (input level or noise floor) with no samples
+  const factory CalibrationError.notASoundPhase({
+    required CalibrationSound phase,
+  }) = CalibrationError_NotASoundPhase;
 }
 
 /// Calibration error code constants exposed to Dart via FFI
@@ -44,7 +68,7 @@ sealed class CalibrationError with _$CalibrationError implements FrbException {
 /// shared between Rust and Dart. The flutter_rust_bridge will automatically
 /// generate corresponding Dart constants.
 ///
-/// Error code range: 2001-2006
+/// Error code range: 2001-2010
 class CalibrationErrorCodes {
   const CalibrationErrorCodes();
 
@@ -56,3 +80,28 @@ class CalibrationErrorCodes {
       identical(this, other) ||
       other is CalibrationErrorCodes && runtimeType == other.runtimeType;
 }
+
+/// Samples collected for one sound, reported when calibration cannot finish
+class SoundSampleCount {
+  final CalibrationSound sound;
+  final BigInt collected;
+  final BigInt needed;
+
+  const SoundSampleCount({
+    required this.sound,
+    required this.collected,
+    required this.needed,
+  });
+
+  @override
+  int get hashCode => sound.hashCode ^ collected.hashCode ^ needed.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SoundSampleCount &&
+          runtimeType == other.runtimeType &&
+          sound == other.sound &&
+          collected == other.collected &&
+          needed == other.needed;
+}
@@ -55,7 +55,7 @@ extension CalibrationErrorPatterns on CalibrationError {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( CalibrationError_InsufficientSamples value)?  insufficientSamples,TResult Function( CalibrationError_InvalidFeatures value)?  invalidFeatures,TResult Function( CalibrationError_NotComplete value)?  notComplete,TResult Function( CalibrationError_AlreadyInProgress value)?  alreadyInProgress,TResult Function( CalibrationError_StatePoisoned value)?  statePoisoned,TResult Function( CalibrationError_Timeout value)?  timeout,required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( CalibrationError_InsufficientSamples value)?  insufficientSamples,TResult Function( CalibrationError_InvalidFeatures value)?  invalidFeatures,TResult Function( CalibrationError_NotComplete value)?  notComplete,TResult Function( CalibrationError_AlreadyInProgress value)?  alreadyInProgress,TResult Function( CalibrationError_StatePoisoned value)?  statePoisoned,TResult Function( CalibrationError_Timeout value)?  timeout,TResult Function( CalibrationError_Io value)?  io,TResult Function( CalibrationError_Parse value)?  parse,TResult Function( CalibrationError_UnknownPreset value)?  unknownPreset,TResult Function( CalibrationError_NotASoundPhase value)?  notASoundPhase,required TResult orElse(),}){
 final _that = this;
 switch (_that) {
 case CalibrationError_InsufficientSamples() when insufficientSamples != null:
@@ -64,7 +64,11 @@ return invalidFeatures(_that);case CalibrationError_NotComplete() when notComple
 return notComplete(_that);case CalibrationError_AlreadyInProgress() when alreadyInProgress != null:
 return alreadyInProgress(_that);case CalibrationError_StatePoisoned() when statePoisoned != null:
 return statePoisoned(_that);case CalibrationError_Timeout() when timeout != null:
-return timeout(_that);case _:
+return timeout(_that);case CalibrationError_Io() when io != null:
+return io(_that);case CalibrationError_Parse() when parse != null:
+return parse(_that);case CalibrationError_UnknownPreset() when unknownPreset != null:
+return unknownPreset(_that);case CalibrationError_NotASoundPhase() when notASoundPhase != null:
+return notASoundPhase(_that);case _:
   return orElse();
 
 }
@@ -82,7 +86,7 @@ return timeout(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( CalibrationError_InsufficientSamples value)  insufficientSamples,required TResult Function( CalibrationError_InvalidFeatures value)  invalidFeatures,required TResult Function( CalibrationError_NotComplete value)  notComplete,required TResult Function( CalibrationError_AlreadyInProgress value)  alreadyInProgress,required TResult Function( CalibrationError_StatePoisoned value)  statePoisoned,required TResult Function( CalibrationError_Timeout value)  timeout,}){
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( CalibrationError_InsufficientSamples value)  insufficientSamples,required TResult Function( CalibrationError_InvalidFeatures value)  invalidFeatures,required TResult Function( CalibrationError_NotComplete value)  notComplete,required TResult Function( CalibrationError_AlreadyInProgress value)  alreadyInProgress,required TResult Function( CalibrationError_StatePoisoned value)  statePoisoned,required TResult Function( CalibrationError_Timeout value)  timeout,required TResult Function( CalibrationError_Io value)  io,required TResult Function( CalibrationError_Parse value)  parse,required TResult Function( CalibrationError_UnknownPreset value)  unknownPreset,required TResult Function( CalibrationError_NotASoundPhase value)  notASoundPhase,}){
 final _that = this;
 switch (_that) {
 case CalibrationError_InsufficientSamples():
@@ -91,7 +95,11 @@ return invalidFeatures(_that);case CalibrationError_NotComplete():
 return notComplete(_that);case CalibrationError_AlreadyInProgress():
 return alreadyInProgress(_that);case CalibrationError_StatePoisoned():
 return statePoisoned(_that);case CalibrationError_Timeout():
-return timeout(_that);}
+return timeout(_that);case CalibrationError_Io():
+return io(_that);case CalibrationError_Parse():
+return parse(_that);case CalibrationError_UnknownPreset():
+return unknownPreset(_that);case CalibrationError_NotASoundPhase():
+return notASoundPhase(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
 ///
@@ -105,7 +113,7 @@ return timeout(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( CalibrationError_InsufficientSamples value)?  insufficientSamples,TResult? Function( CalibrationError_InvalidFeatures value)?  invalidFeatures,TResult? Function( CalibrationError_NotComplete value)?  notComplete,TResult? Function( CalibrationError_AlreadyInProgress value)?  alreadyInProgress,TResult? Function( CalibrationError_StatePoisoned value)?  statePoisoned,TResult? Function( CalibrationError_Timeout value)?  timeout,}){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( CalibrationError_InsufficientSamples value)?  insufficientSamples,TResult? Function( CalibrationError_InvalidFeatures value)?  invalidFeatures,TResult? Function( CalibrationError_NotComplete value)?  notComplete,TResult? Function( CalibrationError_AlreadyInProgress value)?  alreadyInProgress,TResult? Function( CalibrationError_StatePoisoned value)?  statePoisoned,TResult? Function( CalibrationError_Timeout value)?  timeout,TResult? Function( CalibrationError_Io value)?  io,TResult? Function( CalibrationError_Parse value)?  parse,TResult? Function( CalibrationError_UnknownPreset value)?  unknownPreset,TResult? Function( CalibrationError_NotASoundPhase value)?  notASoundPhase,}){
 final _that = this;
 switch (_that) {
 case CalibrationError_InsufficientSamples() when insufficientSamples != null:
@@ -114,7 +122,11 @@ return invalidFeatures(_that);case CalibrationError_NotComplete() when notComple
 return notComplete(_that);case CalibrationError_AlreadyInProgress() when alreadyInProgress != null:
 return alreadyInProgress(_that);case CalibrationError_StatePoisoned() when statePoisoned != null:
 return statePoisoned(_that);case CalibrationError_Timeout() when timeout != null:
-return timeout(_that);case _:
+return timeout(_that);case CalibrationError_Io() when io != null:
+return io(_that);case CalibrationError_Parse() when parse != null:
+return parse(_that);case CalibrationError_UnknownPreset() when unknownPreset != null:
+return unknownPreset(_that);case CalibrationError_NotASoundPhase() when notASoundPhase != null:
+return notASoundPhase(_that);case _:
   return null;
 
 }
@@ -131,15 +143,19 @@ return timeout(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( BigInt required_,  BigInt collected)?  insufficientSamples,TResult Function( String reason)?  invalidFeatures,TResult Function()?  notComplete,TResult Function()?  alreadyInProgress,TResult Function()?  statePoisoned,TResult Function( String reason)?  timeout,required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( BigInt required_,  BigInt collected,  List<SoundSampleCount> perSound)?  insufficientSamples,TResult Function( String reason)?  invalidFeatures,TResult Function()?  notComplete,TResult Function()?  alreadyInProgress,TResult Function()?  statePoisoned,TResult Function( String reason)?  timeout,TResult Function( String reason)?  io,TResult Function( String reason)?  parse,TResult Function( String name)?  unknownPreset,TResult Function( CalibrationSound phase)?  notASoundPhase,required TResult orElse(),}) {final _that = this;
 switch (_that) {
 case CalibrationError_InsufficientSamples() when insufficientSamples != null:
-return insufficientSamples(_that.required_,_that.collected);case CalibrationError_InvalidFeatures() when invalidFeatures != null:
+return insufficientSamples(_that.required_,_that.collected,_that.perSound);case CalibrationError_InvalidFeatures() when invalidFeatures != null:
 return invalidFeatures(_that.reason);case CalibrationError_NotComplete() when notComplete != null:
 return notComplete();case CalibrationError_AlreadyInProgress() when alreadyInProgress != null:
 return alreadyInProgress();case CalibrationError_StatePoisoned() when statePoisoned != null:
 return statePoisoned();case CalibrationError_Timeout() when timeout != null:
-return timeout(_that.reason);case _:
+return timeout(_that.reason);case CalibrationError_Io() when io != null:
+return io(_that.reason);case CalibrationError_Parse() when parse != null:
+return parse(_that.reason);case CalibrationError_UnknownPreset() when unknownPreset != null:
+return unknownPreset(_that.name);case CalibrationError_NotASoundPhase() when notASoundPhase != null:
+return notASoundPhase(_that.phase);case _:
   return orElse();
 
 }
@@ -157,15 +173,19 @@ return timeout(_that.reason);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( BigInt required_,  BigInt collected)  insufficientSamples,required TResult Function( String reason)  invalidFeatures,required TResult Function()  notComplete,required TResult Function()  alreadyInProgress,required TResult Function()  statePoisoned,required TResult Function( String reason)  timeout,}) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( BigInt required_,  BigInt collected,  List<SoundSampleCount> perSound)  insufficientSamples,required TResult Function( String reason)  invalidFeatures,required TResult Function()  notComplete,required TResult Function()  alreadyInProgress,required TResult Function()  statePoisoned,required TResult Function( String reason)  timeout,required TResult Function( String reason)  io,required TResult Function( String reason)  parse,required TResult Function( String name)  unknownPreset,required TResult Function( CalibrationSound phase)  notASoundPhase,}) {final _that = this;
 switch (_that) {
 case CalibrationError_InsufficientSamples():
-return insufficientSamples(_that.required_,_that.collected);case CalibrationError_InvalidFeatures():
+return insufficientSamples(_that.required_,_that.collected,_that.perSound);case CalibrationError_InvalidFeatures():
 return invalidFeatures(_that.reason);case CalibrationError_NotComplete():
 return notComplete();case CalibrationError_AlreadyInProgress():
 return alreadyInProgress();case CalibrationError_StatePoisoned():
 return statePoisoned();case CalibrationError_Timeout():
-return timeout(_that.reason);}
+return timeout(_that.reason);case CalibrationError_Io():
+return io(_that.reason);case CalibrationError_Parse():
+return parse(_that.reason);case CalibrationError_UnknownPreset():
+return unknownPreset(_that.name);case CalibrationError_NotASoundPhase():
+return notASoundPhase(_that.phase);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -179,15 +199,19 @@ return timeout(_that.reason);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( BigInt required_,  BigInt collected)?  insufficientSamples,TResult? Function( String reason)?  invalidFeatures,TResult? Function()?  notComplete,TResult? Function()?  alreadyInProgress,TResult? Function()?  statePoisoned,TResult? Function( String reason)?  timeout,}) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( BigInt required_,  BigInt collected,  List<SoundSampleCount> perSound)?  insufficientSamples,TResult? Function( String reason)?  invalidFeatures,TResult? Function()?  notComplete,TResult? Function()?  alreadyInProgress,TResult? Function()?  statePoisoned,TResult? Function( String reason)?  timeout,TResult? Function( String reason)?  io,TResult? Function( String reason)?  parse,TResult? Function( String name)?  unknownPreset,TResult? Function( CalibrationSound phase)?  notASoundPhase,}) {final _that = this;
 switch (_that) {
 case CalibrationError_InsufficientSamples() when insufficientSamples != null:
-return insufficientSamples(_that.required_,_that.collected);case CalibrationError_InvalidFeatures() when invalidFeatures != null:
+return insufficientSamples(_that.required_,_that.collected,_that.perSound);case CalibrationError_InvalidFeatures() when invalidFeatures != null:
 return invalidFeatures(_that.reason);case CalibrationError_NotComplete() when notComplete != null:
 return notComplete();case CalibrationError_AlreadyInProgress() when alreadyInProgress != null:
 return alreadyInProgress();case CalibrationError_StatePoisoned() when statePoisoned != null:
 return statePoisoned();case CalibrationError_Timeout() when timeout != null:
-return timeout(_that.reason);case _:
+return timeout(_that.reason);case CalibrationError_Io() when io != null:
+return io(_that.reason);case CalibrationError_Parse() when parse != null:
+return parse(_that.reason);case CalibrationError_UnknownPreset() when unknownPreset != null:
+return unknownPreset(_that.name);case CalibrationError_NotASoundPhase() when notASoundPhase != null:
+return notASoundPhase(_that.phase);case _:
   return null;
 
 }
@@ -199,11 +223,18 @@ return timeout(_that.reason);case _:
 
 
 class CalibrationError_InsufficientSamples extends CalibrationError {
-  const CalibrationError_InsufficientSamples({required this.required_, required this.collected}): super._();
+  const CalibrationError_InsufficientSamples({required this.required_, required this.collected, required final  List<SoundSampleCount> perSound}): _perSound = perSound,super._();
   
 
  final  BigInt required_;
  final  BigInt collected;
+ final  List<SoundSampleCount> _perSound;
+ List<SoundSampleCount> get perSound {
+  if (_perSound is EqualUnmodifiableListView) return _perSound;
+  // ignore: implicit_dynamic_type
+  return EqualUnmodifiableListView(_perSound);
+}
+
 
 /// Create a copy of CalibrationError
 /// with the given fields replaced by the non-null parameter values.
@@ -215,16 +246,16 @@ $CalibrationError_InsufficientSamplesCopyWith<CalibrationError_InsufficientSampl
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is CalibrationError_InsufficientSamples&&(identical(other.required_, required_) || other.required_ == required_)&&(identical(other.collected, collected) || other.collected == collected));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CalibrationError_InsufficientSamples&&(identical(other.required_, required_) || other.required_ == required_)&&(identical(other.collected, collected) || other.collected == collected)&&const DeepCollectionEquality().equals(other._perSound, _perSound));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,required_,collected);
+int get hashCode => Object.hash(runtimeType,required_,collected,const DeepCollectionEquality().hash(_perSound));
 
 @override
 String toString() {
-  return 'CalibrationError.insufficientSamples(required_: $required_, collected: $collected)';
+  return 'CalibrationError.insufficientSamples(required_: $required_, collected: $collected, perSound: $perSound)';
 }
 
 
@@ -235,7 +266,7 @@ abstract mixin class $CalibrationError_InsufficientSamplesCopyWith<$Res> impleme
   factory $CalibrationError_InsufficientSamplesCopyWith(CalibrationError_InsufficientSamples value, $Res Function(CalibrationError_InsufficientSamples) _then) = _$CalibrationError_InsufficientSamplesCopyWithImpl;
 @useResult
 $Res call({
- BigInt required_, BigInt collected
+ BigInt required_, BigInt collected, List<SoundSampleCount> perSound
 });
 
 
@@ -252,11 +283,12 @@ class _$CalibrationError_InsufficientSamplesCopyWithImpl<$Res>
 
 /// Create a copy of CalibrationError
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') $Res call({Object? required_ = null,Object? collected = null,}) {
+@pragma('vm:prefer-inline') $Res call({Object? required_ = null,Object? collected = null,Object? perSound = null,}) {
   return _then(CalibrationError_InsufficientSamples(
 required_: null == required_ ? _self.required_ : required_ // ignore: cast_nullable_to_non_nullable
 as BigInt,collected: null == collected ? _self.collected : collected // ignore: cast_nullable_to_non_nullable
-as BigInt,
+as BigInt,perSound: null == perSound ? _self._perSound : perSound // ignore: cast_nullable_to_non_nullable
+as List<SoundSampleCount>,
   ));
 }
 
@@ -490,5 +522,269 @@ as String,
 
 
 }
+/// @nodoc
+
+
+class CalibrationError_Io extends CalibrationError {
+  const CalibrationError_Io({required this.reason}): super._();
+  
+
+ final  String reason;
+
+/// Create a copy of CalibrationError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CalibrationError_IoCopyWith<CalibrationError_Io> get copyWith => _$CalibrationError_IoCopyWithImpl<CalibrationError_Io>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CalibrationError_Io&&(identical(other.reason, reason) || other.reason == reason));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,reason);
+
+@override
+String toString() {
+  return 'CalibrationError.io(reason: $reason)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $CalibrationError_IoCopyWith<$Res> implements $CalibrationErrorCopyWith<$Res> {
+  factory $CalibrationError_IoCopyWith(CalibrationError_Io value, $Res Function(CalibrationError_Io) _then) = _$CalibrationError_IoCopyWithImpl;
+@useResult
+$Res call({
+ String reason
+});
+
+
+
+
+}
+/// @nodoc
+class _$CalibrationError_IoCopyWithImpl<$Res>
+    implements $CalibrationError_IoCopyWith<$Res> {
+  _$CalibrationError_IoCopyWithImpl(this._self, this._then);
+
+  final CalibrationError_Io _self;
+  final $Res Function(CalibrationError_Io) _then;
+
+/// Create a copy of CalibrationError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? reason = null,}) {
+  return _then(CalibrationError_Io(
+reason: null == reason ? _self.reason : reason // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class CalibrationError_Parse extends CalibrationError {
+  const CalibrationError_Parse({required this.reason}): super._();
+  
+
+ final  String reason;
+
+/// Create a copy of CalibrationError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CalibrationError_ParseCopyWith<CalibrationError_Parse> get copyWith => _$CalibrationError_ParseCopyWithImpl<CalibrationError_Parse>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CalibrationError_Parse&&(identical(other.reason, reason) || other.reason == reason));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,reason);
+
+@override
+String toString() {
+  return 'CalibrationError.parse(reason: $reason)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $CalibrationError_ParseCopyWith<$Res> implements $CalibrationErrorCopyWith<$Res> {
+  factory $CalibrationError_ParseCopyWith(CalibrationError_Parse value, $Res Function(CalibrationError_Parse) _then) = _$CalibrationError_ParseCopyWithImpl;
+@useResult
+$Res call({
+ String reason
+});
+
+
+
+
+}
+/// @nodoc
+class _$CalibrationError_ParseCopyWithImpl<$Res>
+    implements $CalibrationError_ParseCopyWith<$Res> {
+  _$CalibrationError_ParseCopyWithImpl(this._self, this._then);
+
+  final CalibrationError_Parse _self;
+  final $Res Function(CalibrationError_Parse) _then;
+
+/// Create a copy of CalibrationError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? reason = null,}) {
+  return _then(CalibrationError_Parse(
+reason: null == reason ? _self.reason : reason // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class CalibrationError_UnknownPreset extends CalibrationError {
+  const CalibrationError_UnknownPreset({required this.name}): super._();
+  
+
+ final  String name;
+
+/// Create a copy of CalibrationError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CalibrationError_UnknownPresetCopyWith<CalibrationError_UnknownPreset> get copyWith => _$CalibrationError_UnknownPresetCopyWithImpl<CalibrationError_UnknownPreset>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CalibrationError_UnknownPreset&&(identical(other.name, name) || other.name == name));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,name);
+
+@override
+String toString() {
+  return 'CalibrationError.unknownPreset(name: $name)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $CalibrationError_UnknownPresetCopyWith<$Res> implements $CalibrationErrorCopyWith<$Res> {
+  factory $CalibrationError_UnknownPresetCopyWith(CalibrationError_UnknownPreset value, $Res Function(CalibrationError_UnknownPreset) _then) = _$CalibrationError_UnknownPresetCopyWithImpl;
+@useResult
+$Res call({
+ String name
+});
+
+
+
+
+}
+/// @nodoc
+class _$CalibrationError_UnknownPresetCopyWithImpl<$Res>
+    implements $CalibrationError_UnknownPresetCopyWith<$Res> {
+  _$CalibrationError_UnknownPresetCopyWithImpl(this._self, this._then);
+
+  final CalibrationError_UnknownPreset _self;
+  final $Res Function(CalibrationError_UnknownPreset) _then;
+
+/// Create a copy of CalibrationError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? name = null,}) {
+  return _then(CalibrationError_UnknownPreset(
+name: null == name ? _self.name : name // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class CalibrationError_NotASoundPhase extends CalibrationError {
+  const CalibrationError_NotASoundPhase({required this.phase}): super._();
+  
+
+ final  CalibrationSound phase;
+
+/// Create a copy of CalibrationError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$CalibrationError_NotASoundPhaseCopyWith<CalibrationError_NotASoundPhase> get copyWith => _$CalibrationError_NotASoundPhaseCopyWithImpl<CalibrationError_NotASoundPhase>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is CalibrationError_NotASoundPhase&&(identical(other.phase, phase) || other.phase == phase));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,phase);
+
+@override
+String toString() {
+  return 'CalibrationError.notASoundPhase(phase: $phase)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $CalibrationError_NotASoundPhaseCopyWith<$Res> implements $CalibrationErrorCopyWith<$Res> {
+  factory $CalibrationError_NotASoundPhaseCopyWith(CalibrationError_NotASoundPhase value, $Res Function(CalibrationError_NotASoundPhase) _then) = _$CalibrationError_NotASoundPhaseCopyWithImpl;
+@useResult
+$Res call({
+ CalibrationSound phase
+});
+
+
+
+
+}
+/// @nodoc
+class _$CalibrationError_NotASoundPhaseCopyWithImpl<$Res>
+    implements $CalibrationError_NotASoundPhaseCopyWith<$Res> {
+  _$CalibrationError_NotASoundPhaseCopyWithImpl(this._self, this._then);
+
+  final CalibrationError_NotASoundPhase _self;
+  final $Res Function(CalibrationError_NotASoundPhase) _then;
+
+/// Create a copy of CalibrationError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? phase = null,}) {
+  return _then(CalibrationError_NotASoundPhase(
+phase: null == phase ? _self.phase : phase // ignore: cast_nullable_to_non_nullable
+as CalibrationSound,
+  ));
+}
+
+
+}
+
 
 // dart format on
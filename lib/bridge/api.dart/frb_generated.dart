@@ -4,7 +4,8 @@
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
 import 'analysis.dart';
-import 'analysis/classifier.dart';
+import 'analysis/features/types.dart';
+import 'analysis/labels.dart';
 import 'analysis/quantizer.dart';
 import 'api.dart';
 import 'api/diagnostics.dart';
@@ -23,6 +24,7 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'telemetry/events.dart';
 import 'testing/fixture_manifest.dart';
 import 'testing/fixtures.dart';
+import 'types.dart';
 
 /// Main entrypoint of the Rust API
 class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
@@ -79,7 +81,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => 948656586;
+  int get rustContentHash => 1335601836;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -94,14 +96,36 @@ abstract class RustLibApi extends BaseApi {
 
   Stream<AudioMetrics> crateApiStreamsAudioMetricsStream();
 
+  Future<String> crateApiCalibrateFromWavs({
+    required List<String> kick,
+    required List<String> snare,
+    required List<String> hihat,
+  });
+
+  Stream<CalibrationDebugFrame> crateApiStreamsCalibrationDebugStream();
+
+  Stream<ClassificationResult> crateApiStreamsCalibrationPreviewStream();
+
   Stream<CalibrationProgress> crateApiCalibrationStream();
 
   Stream<ClassificationResult> crateApiClassificationStream();
 
+  Float32List crateApiComputeSpectrum({
+    required List<double> samples,
+    required int sampleRate,
+    required int fftSize,
+    required bool inDb,
+  });
+
   Future<bool> crateApiConfirmCalibrationStep();
 
   Stream<MetricEvent> crateApiStreamsDiagnosticMetricsStream();
 
+  Features crateApiExtractFeatures({
+    required List<double> samples,
+    required int sampleRate,
+  });
+
   Future<void> crateApiFinishCalibration();
 
   FixtureManifestEntry? crateApiDiagnosticsFixtureMetadataForId({
@@ -116,30 +140,54 @@ abstract class RustLibApi extends BaseApi {
 
   Future<(double, double, double)> crateApiGetCurrentAudioLevel();
 
+  double crateApiGetCurrentOnsetThreshold();
+
   String crateApiGetVersion();
 
   String crateApiGreet({required String name});
 
   Future<void> crateApiInitApp();
 
+  Future<void> crateApiInjectClassification({
+    required ClassificationResult result,
+  });
+
   bool crateApiIsPipelineTracingEnabled();
 
   Future<void> crateApiLoadCalibrationState({required String json});
 
   List<FixtureManifestEntry> crateApiDiagnosticsLoadFixtureCatalog();
 
+  Future<void> crateApiLoadPresetCalibration({required String name});
+
+  Future<String> crateApiLoadPresetCalibrationForDevice({
+    required String model,
+  });
+
   Future<CalibrationProgress> crateApiManualAcceptLastCandidate();
 
   Stream<OnsetEvent> crateApiStreamsOnsetEventsStream();
 
   Future<void> crateApiResetCalibrationSession();
 
+  void crateApiResetTelemetry();
+
   Future<void> crateApiRetryCalibrationStep();
 
   Future<void> crateApiSetBpm({required int bpm});
 
+  int crateApiSetListeningWindows({required List<(BigInt, BigInt)> windows});
+
+  void crateApiSetMetronomeMuted({required bool muted});
+
+  void crateApiSetMetronomeVolume({required double volume});
+
   bool crateApiSetPipelineTracing({required bool enabled});
 
+  int crateApiSetJsonResultSchemaVersion({required int version});
+
+  int crateApiSetSoundLabels({required List<(String, String)> labels});
+
   Future<void> crateApiStartAudio({required int bpm});
 
   Future<void> crateApiStartCalibration();
@@ -148,12 +196,20 @@ abstract class RustLibApi extends BaseApi {
     required FixtureSpec spec,
   });
 
+  Future<void> crateApiStartMetronomeOnly({required int bpm});
+
   Future<void> crateApiStopAudio();
 
   void crateApiDiagnosticsStopFixtureSession();
 
+  List<SoundDescriptor> crateApiSupportedSounds();
+
   Stream<TelemetryEvent> crateApiStreamsTelemetryStream();
 
+  Stream<MetricEvent> crateApiStreamsTelemetryStreamFiltered({
+    required List<MetricEventKind> kinds,
+  });
+
   Future<void> crateApiUpdateCalibrationThreshold({
     required String key,
     required double value,
@@ -239,6 +295,112 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["sink"],
       );
 
+  @override
+  Future<String> crateApiCalibrateFromWavs({
+    required List<String> kick,
+    required List<String> snare,
+    required List<String> hihat,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(kick, serializer);
+          sse_encode_list_String(snare, serializer);
+          sse_encode_list_String(hihat, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 3,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_calibration_error,
+        ),
+        constMeta: kCrateApiCalibrateFromWavsConstMeta,
+        argValues: [kick, snare, hihat],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCalibrateFromWavsConstMeta => const TaskConstMeta(
+    debugName: "calibrate_from_wavs",
+    argNames: ["kick", "snare", "hihat"],
+  );
+
+  @override
+  Stream<CalibrationDebugFrame> crateApiStreamsCalibrationDebugStream() {
+    final sink = RustStreamSink<CalibrationDebugFrame>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_calibration_debug_frame_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 4,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiStreamsCalibrationDebugStreamConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiStreamsCalibrationDebugStreamConstMeta =>
+      const TaskConstMeta(
+        debugName: "calibration_debug_stream",
+        argNames: ["sink"],
+      );
+
+  @override
+  Stream<ClassificationResult> crateApiStreamsCalibrationPreviewStream() {
+    final sink = RustStreamSink<ClassificationResult>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_classification_result_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 5,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiStreamsCalibrationPreviewStreamConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiStreamsCalibrationPreviewStreamConstMeta =>
+      const TaskConstMeta(
+        debugName: "calibration_preview_stream",
+        argNames: ["sink"],
+      );
+
   @override
   Stream<CalibrationProgress> crateApiCalibrationStream() {
     final sink = RustStreamSink<CalibrationProgress>();
@@ -251,7 +413,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 3,
+              funcId: 6,
               port: port_,
             );
           },
@@ -283,7 +445,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 4,
+              funcId: 7,
               port: port_,
             );
           },
@@ -306,6 +468,39 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["sink"],
       );
 
+  @override
+  Float32List crateApiComputeSpectrum({
+    required List<double> samples,
+    required int sampleRate,
+    required int fftSize,
+    required bool inDb,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_f_32_loose(samples, serializer);
+          sse_encode_u_32(sampleRate, serializer);
+          sse_encode_u_32(fftSize, serializer);
+          sse_encode_bool(inDb, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_f_32_strict,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiComputeSpectrumConstMeta,
+        argValues: [samples, sampleRate, fftSize, inDb],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiComputeSpectrumConstMeta => const TaskConstMeta(
+    debugName: "compute_spectrum",
+    argNames: ["samples", "sampleRate", "fftSize", "inDb"],
+  );
+
   @override
   Future<bool> crateApiConfirmCalibrationStep() {
     return handler.executeNormal(
@@ -315,7 +510,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 5,
+            funcId: 9,
             port: port_,
           );
         },
@@ -345,7 +540,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 6,
+              funcId: 10,
               port: port_,
             );
           },
@@ -368,6 +563,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["sink"],
       );
 
+  @override
+  Features crateApiExtractFeatures({
+    required List<double> samples,
+    required int sampleRate,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_f_32_loose(samples, serializer);
+          sse_encode_u_32(sampleRate, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_features,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiExtractFeaturesConstMeta,
+        argValues: [samples, sampleRate],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiExtractFeaturesConstMeta => const TaskConstMeta(
+    debugName: "extract_features",
+    argNames: ["samples", "sampleRate"],
+  );
+
   @override
   Future<void> crateApiFinishCalibration() {
     return handler.executeNormal(
@@ -377,7 +601,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 7,
+            funcId: 12,
             port: port_,
           );
         },
@@ -404,7 +628,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(id, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_opt_box_autoadd_fixture_manifest_entry,
@@ -429,7 +653,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_audio_error_codes,
@@ -451,7 +675,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_calibration_error_codes,
@@ -479,7 +703,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 11,
+            funcId: 16,
             port: port_,
           );
         },
@@ -506,7 +730,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 12,
+            funcId: 17,
             port: port_,
           );
         },
@@ -524,13 +748,38 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiGetCurrentAudioLevelConstMeta =>
       const TaskConstMeta(debugName: "get_current_audio_level", argNames: []);
 
+  @override
+  double crateApiGetCurrentOnsetThreshold() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_f_32,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetCurrentOnsetThresholdConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetCurrentOnsetThresholdConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_current_onset_threshold",
+        argNames: [],
+      );
+
   @override
   String crateApiGetVersion() {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -553,7 +802,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(name, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
@@ -578,7 +827,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 15,
+            funcId: 21,
             port: port_,
           );
         },
@@ -596,13 +845,46 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiInitAppConstMeta =>
       const TaskConstMeta(debugName: "init_app", argNames: []);
 
+  @override
+  Future<void> crateApiInjectClassification({
+    required ClassificationResult result,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_classification_result(result, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 22,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_audio_error,
+        ),
+        constMeta: kCrateApiInjectClassificationConstMeta,
+        argValues: [result],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInjectClassificationConstMeta =>
+      const TaskConstMeta(
+        debugName: "inject_classification",
+        argNames: ["result"],
+      );
+
   @override
   bool crateApiIsPipelineTracingEnabled() {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_bool,
@@ -631,7 +913,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 17,
+            funcId: 24,
             port: port_,
           );
         },
@@ -658,7 +940,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_list_fixture_manifest_entry,
@@ -674,6 +956,70 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiDiagnosticsLoadFixtureCatalogConstMeta =>
       const TaskConstMeta(debugName: "load_fixture_catalog", argNames: []);
 
+  @override
+  Future<void> crateApiLoadPresetCalibration({required String name}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 26,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_calibration_error,
+        ),
+        constMeta: kCrateApiLoadPresetCalibrationConstMeta,
+        argValues: [name],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLoadPresetCalibrationConstMeta =>
+      const TaskConstMeta(
+        debugName: "load_preset_calibration",
+        argNames: ["name"],
+      );
+
+  @override
+  Future<String> crateApiLoadPresetCalibrationForDevice({
+    required String model,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(model, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 27,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_calibration_error,
+        ),
+        constMeta: kCrateApiLoadPresetCalibrationForDeviceConstMeta,
+        argValues: [model],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLoadPresetCalibrationForDeviceConstMeta =>
+      const TaskConstMeta(
+        debugName: "load_preset_calibration_for_device",
+        argNames: ["model"],
+      );
+
   @override
   Future<CalibrationProgress> crateApiManualAcceptLastCandidate() {
     return handler.executeNormal(
@@ -683,7 +1029,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 19,
+            funcId: 28,
             port: port_,
           );
         },
@@ -716,7 +1062,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 20,
+              funcId: 29,
               port: port_,
             );
           },
@@ -745,7 +1091,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 21,
+            funcId: 30,
             port: port_,
           );
         },
@@ -763,6 +1109,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiResetCalibrationSessionConstMeta =>
       const TaskConstMeta(debugName: "reset_calibration_session", argNames: []);
 
+  @override
+  void crateApiResetTelemetry() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiResetTelemetryConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiResetTelemetryConstMeta =>
+      const TaskConstMeta(debugName: "reset_telemetry", argNames: []);
+
   @override
   Future<void> crateApiRetryCalibrationStep() {
     return handler.executeNormal(
@@ -772,7 +1140,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 22,
+            funcId: 32,
             port: port_,
           );
         },
@@ -800,7 +1168,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 23,
+            funcId: 33,
             port: port_,
           );
         },
@@ -818,6 +1186,82 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiSetBpmConstMeta =>
       const TaskConstMeta(debugName: "set_bpm", argNames: ["bpm"]);
 
+  @override
+  int crateApiSetListeningWindows({required List<(BigInt, BigInt)> windows}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_record_u_64_u_64(windows, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSetListeningWindowsConstMeta,
+        argValues: [windows],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetListeningWindowsConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_listening_windows",
+        argNames: ["windows"],
+      );
+
+  @override
+  void crateApiSetMetronomeMuted({required bool muted}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(muted, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSetMetronomeMutedConstMeta,
+        argValues: [muted],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetMetronomeMutedConstMeta => const TaskConstMeta(
+    debugName: "set_metronome_muted",
+    argNames: ["muted"],
+  );
+
+  @override
+  void crateApiSetMetronomeVolume({required double volume}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_f_32(volume, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSetMetronomeVolumeConstMeta,
+        argValues: [volume],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetMetronomeVolumeConstMeta => const TaskConstMeta(
+    debugName: "set_metronome_volume",
+    argNames: ["volume"],
+  );
+
   @override
   bool crateApiSetPipelineTracing({required bool enabled}) {
     return handler.executeSync(
@@ -825,7 +1269,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_bool(enabled, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_bool,
@@ -843,6 +1287,55 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     argNames: ["enabled"],
   );
 
+  @override
+  int crateApiSetJsonResultSchemaVersion({required int version}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(version, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSetJsonResultSchemaVersionConstMeta,
+        argValues: [version],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetJsonResultSchemaVersionConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_json_result_schema_version",
+        argNames: ["version"],
+      );
+
+  @override
+  int crateApiSetSoundLabels({required List<(String, String)> labels}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_record_string_string(labels, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSetSoundLabelsConstMeta,
+        argValues: [labels],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetSoundLabelsConstMeta =>
+      const TaskConstMeta(debugName: "set_sound_labels", argNames: ["labels"]);
+
   @override
   Future<void> crateApiStartAudio({required int bpm}) {
     return handler.executeNormal(
@@ -853,7 +1346,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 25,
+            funcId: 40,
             port: port_,
           );
         },
@@ -880,7 +1373,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 26,
+            funcId: 41,
             port: port_,
           );
         },
@@ -913,7 +1406,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 27,
+            funcId: 42,
             port: port_,
           );
         },
@@ -934,6 +1427,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["spec"],
       );
 
+  @override
+  Future<void> crateApiStartMetronomeOnly({required int bpm}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(bpm, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 43,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_audio_error,
+        ),
+        constMeta: kCrateApiStartMetronomeOnlyConstMeta,
+        argValues: [bpm],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiStartMetronomeOnlyConstMeta =>
+      const TaskConstMeta(debugName: "start_metronome_only", argNames: ["bpm"]);
+
   @override
   Future<void> crateApiStopAudio() {
     return handler.executeNormal(
@@ -943,7 +1464,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 28,
+            funcId: 44,
             port: port_,
           );
         },
@@ -967,7 +1488,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
@@ -983,6 +1504,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiDiagnosticsStopFixtureSessionConstMeta =>
       const TaskConstMeta(debugName: "stop_fixture_session", argNames: []);
 
+  @override
+  List<SoundDescriptor> crateApiSupportedSounds() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_sound_descriptor,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSupportedSoundsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSupportedSoundsConstMeta =>
+      const TaskConstMeta(debugName: "supported_sounds", argNames: []);
+
   @override
   Stream<TelemetryEvent> crateApiStreamsTelemetryStream() {
     final sink = RustStreamSink<TelemetryEvent>();
@@ -995,7 +1538,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             pdeCallFfi(
               generalizedFrbRustBinding,
               serializer,
-              funcId: 30,
+              funcId: 47,
               port: port_,
             );
           },
@@ -1015,6 +1558,44 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiStreamsTelemetryStreamConstMeta =>
       const TaskConstMeta(debugName: "telemetry_stream", argNames: ["sink"]);
 
+  @override
+  Stream<MetricEvent> crateApiStreamsTelemetryStreamFiltered({
+    required List<MetricEventKind> kinds,
+  }) {
+    final sink = RustStreamSink<MetricEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_metric_event_Sse(sink, serializer);
+            sse_encode_list_metric_event_kind(kinds, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 48,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiStreamsTelemetryStreamFilteredConstMeta,
+          argValues: [sink, kinds],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiStreamsTelemetryStreamFilteredConstMeta =>
+      const TaskConstMeta(
+        debugName: "telemetry_stream_filtered",
+        argNames: ["sink", "kinds"],
+      );
+
   @override
   Future<void> crateApiUpdateCalibrationThreshold({
     required String key,
@@ -1029,7 +1610,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 31,
+            funcId: 49,
             port: port_,
           );
         },
@@ -1108,6 +1689,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError();
   }
 
+  @protected
+  RustStreamSink<CalibrationDebugFrame>
+  dco_decode_StreamSink_calibration_debug_frame_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
   @protected
   RustStreamSink<CalibrationProgress>
   dco_decode_StreamSink_calibration_progress_Sse(dynamic raw) {
@@ -1176,6 +1764,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         return AudioError_ContextNotInitialized();
       case 9:
         return AudioError_StreamFailure(reason: dco_decode_String(raw[1]));
+      case 10:
+        return AudioError_Unsupported(operation: dco_decode_String(raw[1]));
       default:
         throw Exception("unreachable");
     }
@@ -1194,14 +1784,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   AudioMetrics dco_decode_audio_metrics(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
     return AudioMetrics(
       rms: dco_decode_f_64(arr[0]),
-      spectralCentroid: dco_decode_f_64(arr[1]),
-      spectralFlux: dco_decode_f_64(arr[2]),
-      frameNumber: dco_decode_u_64(arr[3]),
-      timestamp: dco_decode_u_64(arr[4]),
+      smoothedRms: dco_decode_f_64(arr[1]),
+      spectralCentroid: dco_decode_f_64(arr[2]),
+      spectralFlux: dco_decode_f_64(arr[3]),
+      frameNumber: dco_decode_u_64(arr[4]),
+      timestamp: dco_decode_u_64(arr[5]),
     );
   }
 
@@ -1231,6 +1822,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_calibration_progress_debug(raw);
   }
 
+  @protected
+  CalibrationSummary dco_decode_box_autoadd_calibration_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_calibration_summary(raw);
+  }
+
   @protected
   ClassificationResult dco_decode_box_autoadd_classification_result(
     dynamic raw,
@@ -1251,6 +1848,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw as double;
   }
 
+  @protected
+  Features dco_decode_box_autoadd_features(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_features(raw);
+  }
+
   @protected
   FixtureManifestEntry dco_decode_box_autoadd_fixture_manifest_entry(
     dynamic raw,
@@ -1265,12 +1868,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_param_patch(raw);
   }
 
+  @protected
+  TimingFeedback dco_decode_box_autoadd_timing_feedback(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_timing_feedback(raw);
+  }
+
   @protected
   int dco_decode_box_autoadd_u_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw as int;
   }
 
+  @protected
+  CalibrationDebugFrame dco_decode_calibration_debug_frame(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return CalibrationDebugFrame(
+      seq: dco_decode_u_64(arr[0]),
+      sound: dco_decode_calibration_sound(arr[1]),
+      centroid: dco_decode_f_32(arr[2]),
+      zcr: dco_decode_f_32(arr[3]),
+      rms: dco_decode_f_64(arr[4]),
+      maxAmp: dco_decode_f_32(arr[5]),
+    );
+  }
+
   @protected
   CalibrationError dco_decode_calibration_error(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1279,6 +1904,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         return CalibrationError_InsufficientSamples(
           required_: dco_decode_usize(raw[1]),
           collected: dco_decode_usize(raw[2]),
+          perSound: dco_decode_list_sound_sample_count(raw[3]),
         );
       case 1:
         return CalibrationError_InvalidFeatures(
@@ -1292,6 +1918,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         return CalibrationError_StatePoisoned();
       case 5:
         return CalibrationError_Timeout(reason: dco_decode_String(raw[1]));
+      case 6:
+        return CalibrationError_Io(reason: dco_decode_String(raw[1]));
+      case 7:
+        return CalibrationError_Parse(reason: dco_decode_String(raw[1]));
+      case 8:
+        return CalibrationError_UnknownPreset(name: dco_decode_String(raw[1]));
+      case 9:
+        return CalibrationError_NotASoundPhase(
+          phase: dco_decode_calibration_sound(raw[1]),
+        );
       default:
         throw Exception("unreachable");
     }
@@ -1332,8 +1968,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   CalibrationProgress dco_decode_calibration_progress(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 7)
-      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
     return CalibrationProgress(
       currentSound: dco_decode_calibration_sound(arr[0]),
       samplesCollected: dco_decode_u_8(arr[1]),
@@ -1341,7 +1977,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       waitingForConfirmation: dco_decode_bool(arr[3]),
       guidance: dco_decode_opt_box_autoadd_calibration_guidance(arr[4]),
       manualAcceptAvailable: dco_decode_bool(arr[5]),
-      debug: dco_decode_opt_box_autoadd_calibration_progress_debug(arr[6]),
+      autoAdvanced: dco_decode_bool(arr[6]),
+      phaseChanged: dco_decode_bool(arr[7]),
+      estimatedRemainingMs: dco_decode_opt_box_autoadd_u_32(arr[8]),
+      completed: dco_decode_opt_box_autoadd_calibration_summary(arr[9]),
+      debug: dco_decode_opt_box_autoadd_calibration_progress_debug(arr[10]),
     );
   }
 
@@ -1372,17 +2012,43 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return CalibrationSound.values[raw as int];
   }
 
+  @protected
+  CalibrationSummary dco_decode_calibration_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return CalibrationSummary(
+      tKickCentroid: dco_decode_f_32(arr[0]),
+      tKickZcr: dco_decode_f_32(arr[1]),
+      tSnareCentroid: dco_decode_f_32(arr[2]),
+      tHihatZcr: dco_decode_f_32(arr[3]),
+      noiseFloorRms: dco_decode_f_64(arr[4]),
+      qualityScore: dco_decode_opt_box_autoadd_f_32(arr[5]),
+    );
+  }
+
   @protected
   ClassificationResult dco_decode_classification_result(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    if (arr.length != 14)
+      throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
     return ClassificationResult(
       sound: dco_decode_beatbox_hit(arr[0]),
-      timing: dco_decode_timing_feedback(arr[1]),
+      timing: dco_decode_opt_box_autoadd_timing_feedback(arr[1]),
       timestampMs: dco_decode_u_64(arr[2]),
       confidence: dco_decode_f_32(arr[3]),
+      label: dco_decode_String(arr[4]),
+      extra: dco_decode_bool(arr[5]),
+      schemaVersion: dco_decode_u_32(arr[6]),
+      smoothedConfidence: dco_decode_opt_box_autoadd_f_32(arr[7]),
+      beatIndex: dco_decode_opt_box_autoadd_u_32(arr[8]),
+      subdivisionIndex: dco_decode_opt_box_autoadd_u_32(arr[9]),
+      wallClockMs: dco_decode_u_64(arr[10]),
+      trigger: dco_decode_hit_trigger(arr[11]),
+      flam: dco_decode_bool(arr[12]),
+      clipped: dco_decode_bool(arr[13]),
     );
   }
 
@@ -1404,6 +2070,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw as double;
   }
 
+  @protected
+  Features dco_decode_features(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return Features(
+      centroid: dco_decode_f_32(arr[0]),
+      zcr: dco_decode_f_32(arr[1]),
+      flatness: dco_decode_f_32(arr[2]),
+      rolloff: dco_decode_f_32(arr[3]),
+      decayTimeMs: dco_decode_f_32(arr[4]),
+    );
+  }
+
   @protected
   FixtureBpmRange dco_decode_fixture_bpm_range(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1481,6 +2162,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  HitTrigger dco_decode_hit_trigger(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return HitTrigger.values[raw as int];
+  }
+
   @protected
   int dco_decode_i_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1509,6 +2196,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         .toList();
   }
 
+  @protected
+  List<MetricEventKind> dco_decode_list_metric_event_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_metric_event_kind).toList();
+  }
+
+  @protected
+  List<double> dco_decode_list_prim_f_32_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as List<double>;
+  }
+
+  @protected
+  Float32List dco_decode_list_prim_f_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Float32List;
+  }
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1527,6 +2232,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_record_string_u_32).toList();
   }
 
+  @protected
+  List<(BigInt, BigInt)> dco_decode_list_record_u_64_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_record_u_64_u_64).toList();
+  }
+
+  @protected
+  List<SoundDescriptor> dco_decode_list_sound_descriptor(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_sound_descriptor).toList();
+  }
+
+  @protected
+  List<SoundSampleCount> dco_decode_list_sound_sample_count(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_sound_sample_count).toList();
+  }
+
   @protected
   ManifestSyntheticPattern dco_decode_manifest_synthetic_pattern(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1564,17 +2287,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           code: dco_decode_diagnostic_error(raw[1]),
           context: dco_decode_String(raw[2]),
         );
+      case 5:
+        return MetricEvent_ProcessingLatency(
+          latencyMs: dco_decode_f_32(raw[1]),
+        );
+      case 6:
+        return MetricEvent_Silence(quietMs: dco_decode_f_32(raw[1]));
       default:
         throw Exception("unreachable");
     }
   }
 
+  @protected
+  MetricEventKind dco_decode_metric_event_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MetricEventKind.values[raw as int];
+  }
+
   @protected
   OnsetEvent dco_decode_onset_event(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 8)
-      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
     return OnsetEvent(
       timestamp: dco_decode_u_64(arr[0]),
       energy: dco_decode_f_64(arr[1]),
@@ -1584,6 +2319,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       rolloff: dco_decode_f_64(arr[5]),
       decayTimeMs: dco_decode_f_64(arr[6]),
       classification: dco_decode_opt_box_autoadd_classification_result(arr[7]),
+      strength: dco_decode_f_64(arr[8]),
+      features: dco_decode_opt_box_autoadd_features(arr[9]),
     );
   }
 
@@ -1612,6 +2349,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         : dco_decode_box_autoadd_calibration_progress_debug(raw);
   }
 
+  @protected
+  CalibrationSummary? dco_decode_opt_box_autoadd_calibration_summary(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_calibration_summary(raw);
+  }
+
   @protected
   ClassificationResult? dco_decode_opt_box_autoadd_classification_result(
     dynamic raw,
@@ -1634,6 +2379,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
   }
 
+  @protected
+  Features? dco_decode_opt_box_autoadd_features(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_features(raw);
+  }
+
   @protected
   FixtureManifestEntry? dco_decode_opt_box_autoadd_fixture_manifest_entry(
     dynamic raw,
@@ -1644,6 +2395,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         : dco_decode_box_autoadd_fixture_manifest_entry(raw);
   }
 
+  @protected
+  TimingFeedback? dco_decode_opt_box_autoadd_timing_feedback(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_timing_feedback(raw);
+  }
+
   @protected
   int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1654,12 +2411,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   ParamPatch dco_decode_param_patch(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
     return ParamPatch(
       bpm: dco_decode_opt_box_autoadd_u_32(arr[0]),
       centroidThreshold: dco_decode_opt_box_autoadd_f_32(arr[1]),
       zcrThreshold: dco_decode_opt_box_autoadd_f_32(arr[2]),
+      kickCentroidThreshold: dco_decode_opt_box_autoadd_f_32(arr[3]),
+      kickZcrThreshold: dco_decode_opt_box_autoadd_f_32(arr[4]),
+      snareCentroidThreshold: dco_decode_opt_box_autoadd_f_32(arr[5]),
+      hihatZcrThreshold: dco_decode_opt_box_autoadd_f_32(arr[6]),
     );
   }
 
@@ -1697,6 +2458,43 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (dco_decode_String(arr[0]), dco_decode_u_32(arr[1]));
   }
 
+  @protected
+  (BigInt, BigInt) dco_decode_record_u_64_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (dco_decode_u_64(arr[0]), dco_decode_u_64(arr[1]));
+  }
+
+  @protected
+  SoundDescriptor dco_decode_sound_descriptor(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return SoundDescriptor(
+      sound: dco_decode_beatbox_hit(arr[0]),
+      label: dco_decode_String(arr[1]),
+      minLevel: dco_decode_u_8(arr[2]),
+      active: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  SoundSampleCount dco_decode_sound_sample_count(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SoundSampleCount(
+      sound: dco_decode_calibration_sound(arr[0]),
+      collected: dco_decode_usize(arr[1]),
+      needed: dco_decode_usize(arr[2]),
+    );
+  }
+
   @protected
   TelemetryEvent dco_decode_telemetry_event(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1838,6 +2636,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError('Unreachable ()');
   }
 
+  @protected
+  RustStreamSink<CalibrationDebugFrame>
+  sse_decode_StreamSink_calibration_debug_frame_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
   @protected
   RustStreamSink<CalibrationProgress>
   sse_decode_StreamSink_calibration_progress_Sse(SseDeserializer deserializer) {
@@ -1917,6 +2724,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       case 9:
         var var_reason = sse_decode_String(deserializer);
         return AudioError_StreamFailure(reason: var_reason);
+      case 10:
+        var var_operation = sse_decode_String(deserializer);
+        return AudioError_Unsupported(operation: var_operation);
       default:
         throw UnimplementedError('');
     }
@@ -1932,12 +2742,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   AudioMetrics sse_decode_audio_metrics(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_rms = sse_decode_f_64(deserializer);
+    var var_smoothedRms = sse_decode_f_64(deserializer);
     var var_spectralCentroid = sse_decode_f_64(deserializer);
     var var_spectralFlux = sse_decode_f_64(deserializer);
     var var_frameNumber = sse_decode_u_64(deserializer);
     var var_timestamp = sse_decode_u_64(deserializer);
     return AudioMetrics(
       rms: var_rms,
+      smoothedRms: var_smoothedRms,
       spectralCentroid: var_spectralCentroid,
       spectralFlux: var_spectralFlux,
       frameNumber: var_frameNumber,
@@ -1974,6 +2786,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_calibration_progress_debug(deserializer));
   }
 
+  @protected
+  CalibrationSummary sse_decode_box_autoadd_calibration_summary(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_calibration_summary(deserializer));
+  }
+
   @protected
   ClassificationResult sse_decode_box_autoadd_classification_result(
     SseDeserializer deserializer,
@@ -1994,6 +2814,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_f_64(deserializer));
   }
 
+  @protected
+  Features sse_decode_box_autoadd_features(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_features(deserializer));
+  }
+
   @protected
   FixtureManifestEntry sse_decode_box_autoadd_fixture_manifest_entry(
     SseDeserializer deserializer,
@@ -2008,12 +2834,41 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_param_patch(deserializer));
   }
 
+  @protected
+  TimingFeedback sse_decode_box_autoadd_timing_feedback(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_timing_feedback(deserializer));
+  }
+
   @protected
   int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     return (sse_decode_u_32(deserializer));
   }
 
+  @protected
+  CalibrationDebugFrame sse_decode_calibration_debug_frame(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_seq = sse_decode_u_64(deserializer);
+    var var_sound = sse_decode_calibration_sound(deserializer);
+    var var_centroid = sse_decode_f_32(deserializer);
+    var var_zcr = sse_decode_f_32(deserializer);
+    var var_rms = sse_decode_f_64(deserializer);
+    var var_maxAmp = sse_decode_f_32(deserializer);
+    return CalibrationDebugFrame(
+      seq: var_seq,
+      sound: var_sound,
+      centroid: var_centroid,
+      zcr: var_zcr,
+      rms: var_rms,
+      maxAmp: var_maxAmp,
+    );
+  }
+
   @protected
   CalibrationError sse_decode_calibration_error(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2023,9 +2878,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       case 0:
         var var_required_ = sse_decode_usize(deserializer);
         var var_collected = sse_decode_usize(deserializer);
+        var var_perSound = sse_decode_list_sound_sample_count(deserializer);
         return CalibrationError_InsufficientSamples(
           required_: var_required_,
           collected: var_collected,
+          perSound: var_perSound,
         );
       case 1:
         var var_reason = sse_decode_String(deserializer);
@@ -2039,6 +2896,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       case 5:
         var var_reason = sse_decode_String(deserializer);
         return CalibrationError_Timeout(reason: var_reason);
+      case 6:
+        var var_reason = sse_decode_String(deserializer);
+        return CalibrationError_Io(reason: var_reason);
+      case 7:
+        var var_reason = sse_decode_String(deserializer);
+        return CalibrationError_Parse(reason: var_reason);
+      case 8:
+        var var_name = sse_decode_String(deserializer);
+        return CalibrationError_UnknownPreset(name: var_name);
+      case 9:
+        var var_phase = sse_decode_calibration_sound(deserializer);
+        return CalibrationError_NotASoundPhase(phase: var_phase);
       default:
         throw UnimplementedError('');
     }
@@ -2091,6 +2960,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       deserializer,
     );
     var var_manualAcceptAvailable = sse_decode_bool(deserializer);
+    var var_autoAdvanced = sse_decode_bool(deserializer);
+    var var_phaseChanged = sse_decode_bool(deserializer);
+    var var_estimatedRemainingMs = sse_decode_opt_box_autoadd_u_32(
+      deserializer,
+    );
+    var var_completed = sse_decode_opt_box_autoadd_calibration_summary(
+      deserializer,
+    );
     var var_debug = sse_decode_opt_box_autoadd_calibration_progress_debug(
       deserializer,
     );
@@ -2101,6 +2978,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       waitingForConfirmation: var_waitingForConfirmation,
       guidance: var_guidance,
       manualAcceptAvailable: var_manualAcceptAvailable,
+      autoAdvanced: var_autoAdvanced,
+      phaseChanged: var_phaseChanged,
+      estimatedRemainingMs: var_estimatedRemainingMs,
+      completed: var_completed,
       debug: var_debug,
     );
   }
@@ -2143,20 +3024,61 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return CalibrationSound.values[inner];
   }
 
+  @protected
+  CalibrationSummary sse_decode_calibration_summary(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_tKickCentroid = sse_decode_f_32(deserializer);
+    var var_tKickZcr = sse_decode_f_32(deserializer);
+    var var_tSnareCentroid = sse_decode_f_32(deserializer);
+    var var_tHihatZcr = sse_decode_f_32(deserializer);
+    var var_noiseFloorRms = sse_decode_f_64(deserializer);
+    var var_qualityScore = sse_decode_opt_box_autoadd_f_32(deserializer);
+    return CalibrationSummary(
+      tKickCentroid: var_tKickCentroid,
+      tKickZcr: var_tKickZcr,
+      tSnareCentroid: var_tSnareCentroid,
+      tHihatZcr: var_tHihatZcr,
+      noiseFloorRms: var_noiseFloorRms,
+      qualityScore: var_qualityScore,
+    );
+  }
+
   @protected
   ClassificationResult sse_decode_classification_result(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_sound = sse_decode_beatbox_hit(deserializer);
-    var var_timing = sse_decode_timing_feedback(deserializer);
+    var var_timing = sse_decode_opt_box_autoadd_timing_feedback(deserializer);
     var var_timestampMs = sse_decode_u_64(deserializer);
     var var_confidence = sse_decode_f_32(deserializer);
+    var var_label = sse_decode_String(deserializer);
+    var var_extra = sse_decode_bool(deserializer);
+    var var_schemaVersion = sse_decode_u_32(deserializer);
+    var var_smoothedConfidence = sse_decode_opt_box_autoadd_f_32(deserializer);
+    var var_beatIndex = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_subdivisionIndex = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_wallClockMs = sse_decode_u_64(deserializer);
+    var var_trigger = sse_decode_hit_trigger(deserializer);
+    var var_flam = sse_decode_bool(deserializer);
+    var var_clipped = sse_decode_bool(deserializer);
     return ClassificationResult(
       sound: var_sound,
       timing: var_timing,
       timestampMs: var_timestampMs,
       confidence: var_confidence,
+      label: var_label,
+      extra: var_extra,
+      schemaVersion: var_schemaVersion,
+      smoothedConfidence: var_smoothedConfidence,
+      beatIndex: var_beatIndex,
+      subdivisionIndex: var_subdivisionIndex,
+      wallClockMs: var_wallClockMs,
+      trigger: var_trigger,
+      flam: var_flam,
+      clipped: var_clipped,
     );
   }
 
@@ -2179,6 +3101,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getFloat64();
   }
 
+  @protected
+  Features sse_decode_features(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_centroid = sse_decode_f_32(deserializer);
+    var var_zcr = sse_decode_f_32(deserializer);
+    var var_flatness = sse_decode_f_32(deserializer);
+    var var_rolloff = sse_decode_f_32(deserializer);
+    var var_decayTimeMs = sse_decode_f_32(deserializer);
+    return Features(
+      centroid: var_centroid,
+      zcr: var_zcr,
+      flatness: var_flatness,
+      rolloff: var_rolloff,
+      decayTimeMs: var_decayTimeMs,
+    );
+  }
+
   @protected
   FixtureBpmRange sse_decode_fixture_bpm_range(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2270,6 +3209,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  HitTrigger sse_decode_hit_trigger(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return HitTrigger.values[inner];
+  }
+
   @protected
   int sse_decode_i_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2290,56 +3236,126 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var len_ = sse_decode_i_32(deserializer);
     var ans_ = <String>[];
     for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FixtureManifestEntry> sse_decode_list_fixture_manifest_entry(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FixtureManifestEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_fixture_manifest_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MetricEventKind> sse_decode_list_metric_event_kind(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MetricEventKind>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_metric_event_kind(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<double> sse_decode_list_prim_f_32_loose(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getFloat32List(len_);
+  }
+
+  @protected
+  Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getFloat32List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<(String, String)> sse_decode_list_record_string_string(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <(String, String)>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_record_string_string(deserializer));
     }
     return ans_;
   }
 
   @protected
-  List<FixtureManifestEntry> sse_decode_list_fixture_manifest_entry(
+  List<(String, int)> sse_decode_list_record_string_u_32(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <FixtureManifestEntry>[];
+    var ans_ = <(String, int)>[];
     for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_fixture_manifest_entry(deserializer));
+      ans_.add(sse_decode_record_string_u_32(deserializer));
     }
     return ans_;
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  List<(BigInt, BigInt)> sse_decode_list_record_u_64_u_64(
+    SseDeserializer deserializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+
     var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    var ans_ = <(BigInt, BigInt)>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_record_u_64_u_64(deserializer));
+    }
+    return ans_;
   }
 
   @protected
-  List<(String, String)> sse_decode_list_record_string_string(
+  List<SoundDescriptor> sse_decode_list_sound_descriptor(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, String)>[];
+    var ans_ = <SoundDescriptor>[];
     for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_string(deserializer));
+      ans_.add(sse_decode_sound_descriptor(deserializer));
     }
     return ans_;
   }
 
   @protected
-  List<(String, int)> sse_decode_list_record_string_u_32(
+  List<SoundSampleCount> sse_decode_list_sound_sample_count(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, int)>[];
+    var ans_ = <SoundSampleCount>[];
     for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_u_32(deserializer));
+      ans_.add(sse_decode_sound_sample_count(deserializer));
     }
     return ans_;
   }
@@ -2395,11 +3411,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         var var_code = sse_decode_diagnostic_error(deserializer);
         var var_context = sse_decode_String(deserializer);
         return MetricEvent_Error(code: var_code, context: var_context);
+      case 5:
+        var var_latencyMs = sse_decode_f_32(deserializer);
+        return MetricEvent_ProcessingLatency(latencyMs: var_latencyMs);
+      case 6:
+        var var_quietMs = sse_decode_f_32(deserializer);
+        return MetricEvent_Silence(quietMs: var_quietMs);
       default:
         throw UnimplementedError('');
     }
   }
 
+  @protected
+  MetricEventKind sse_decode_metric_event_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return MetricEventKind.values[inner];
+  }
+
   @protected
   OnsetEvent sse_decode_onset_event(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2413,6 +3442,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_classification = sse_decode_opt_box_autoadd_classification_result(
       deserializer,
     );
+    var var_strength = sse_decode_f_64(deserializer);
+    var var_features = sse_decode_opt_box_autoadd_features(deserializer);
     return OnsetEvent(
       timestamp: var_timestamp,
       energy: var_energy,
@@ -2422,6 +3453,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       rolloff: var_rolloff,
       decayTimeMs: var_decayTimeMs,
       classification: var_classification,
+      strength: var_strength,
+      features: var_features,
     );
   }
 
@@ -2463,6 +3496,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  CalibrationSummary? sse_decode_opt_box_autoadd_calibration_summary(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_calibration_summary(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   ClassificationResult? sse_decode_opt_box_autoadd_classification_result(
     SseDeserializer deserializer,
@@ -2498,6 +3544,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  Features? sse_decode_opt_box_autoadd_features(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_features(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   FixtureManifestEntry? sse_decode_opt_box_autoadd_fixture_manifest_entry(
     SseDeserializer deserializer,
@@ -2511,6 +3568,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  TimingFeedback? sse_decode_opt_box_autoadd_timing_feedback(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_timing_feedback(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2528,10 +3598,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_bpm = sse_decode_opt_box_autoadd_u_32(deserializer);
     var var_centroidThreshold = sse_decode_opt_box_autoadd_f_32(deserializer);
     var var_zcrThreshold = sse_decode_opt_box_autoadd_f_32(deserializer);
+    var var_kickCentroidThreshold = sse_decode_opt_box_autoadd_f_32(
+      deserializer,
+    );
+    var var_kickZcrThreshold = sse_decode_opt_box_autoadd_f_32(deserializer);
+    var var_snareCentroidThreshold = sse_decode_opt_box_autoadd_f_32(
+      deserializer,
+    );
+    var var_hihatZcrThreshold = sse_decode_opt_box_autoadd_f_32(deserializer);
     return ParamPatch(
       bpm: var_bpm,
       centroidThreshold: var_centroidThreshold,
       zcrThreshold: var_zcrThreshold,
+      kickCentroidThreshold: var_kickCentroidThreshold,
+      kickZcrThreshold: var_kickZcrThreshold,
+      snareCentroidThreshold: var_snareCentroidThreshold,
+      hihatZcrThreshold: var_hihatZcrThreshold,
     );
   }
 
@@ -2564,6 +3646,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (var_field0, var_field1);
   }
 
+  @protected
+  (BigInt, BigInt) sse_decode_record_u_64_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_u_64(deserializer);
+    var var_field1 = sse_decode_u_64(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  SoundDescriptor sse_decode_sound_descriptor(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_sound = sse_decode_beatbox_hit(deserializer);
+    var var_label = sse_decode_String(deserializer);
+    var var_minLevel = sse_decode_u_8(deserializer);
+    var var_active = sse_decode_bool(deserializer);
+    return SoundDescriptor(
+      sound: var_sound,
+      label: var_label,
+      minLevel: var_minLevel,
+      active: var_active,
+    );
+  }
+
+  @protected
+  SoundSampleCount sse_decode_sound_sample_count(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_sound = sse_decode_calibration_sound(deserializer);
+    var var_collected = sse_decode_usize(deserializer);
+    var var_needed = sse_decode_usize(deserializer);
+    return SoundSampleCount(
+      sound: var_sound,
+      collected: var_collected,
+      needed: var_needed,
+    );
+  }
+
   @protected
   TelemetryEvent sse_decode_telemetry_event(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2731,6 +3849,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void sse_encode_StreamSink_calibration_debug_frame_Sse(
+    RustStreamSink<CalibrationDebugFrame> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_calibration_debug_frame,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
   @protected
   void sse_encode_StreamSink_calibration_progress_Sse(
     RustStreamSink<CalibrationProgress> self,
@@ -2852,6 +3987,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       case AudioError_StreamFailure(reason: final reason):
         sse_encode_i_32(9, serializer);
         sse_encode_String(reason, serializer);
+      case AudioError_Unsupported(operation: final operation):
+        sse_encode_i_32(10, serializer);
+        sse_encode_String(operation, serializer);
     }
   }
 
@@ -2867,6 +4005,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   void sse_encode_audio_metrics(AudioMetrics self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_f_64(self.rms, serializer);
+    sse_encode_f_64(self.smoothedRms, serializer);
     sse_encode_f_64(self.spectralCentroid, serializer);
     sse_encode_f_64(self.spectralFlux, serializer);
     sse_encode_u_64(self.frameNumber, serializer);
@@ -2903,6 +4042,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_calibration_progress_debug(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_calibration_summary(
+    CalibrationSummary self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_calibration_summary(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_classification_result(
     ClassificationResult self,
@@ -2924,6 +4072,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_f_64(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_features(
+    Features self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_features(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_fixture_manifest_entry(
     FixtureManifestEntry self,
@@ -2942,12 +4099,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_param_patch(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_timing_feedback(
+    TimingFeedback self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_timing_feedback(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_u_32(self, serializer);
   }
 
+  @protected
+  void sse_encode_calibration_debug_frame(
+    CalibrationDebugFrame self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.seq, serializer);
+    sse_encode_calibration_sound(self.sound, serializer);
+    sse_encode_f_32(self.centroid, serializer);
+    sse_encode_f_32(self.zcr, serializer);
+    sse_encode_f_64(self.rms, serializer);
+    sse_encode_f_32(self.maxAmp, serializer);
+  }
+
   @protected
   void sse_encode_calibration_error(
     CalibrationError self,
@@ -2958,10 +4138,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       case CalibrationError_InsufficientSamples(
         required_: final required_,
         collected: final collected,
+        perSound: final perSound,
       ):
         sse_encode_i_32(0, serializer);
         sse_encode_usize(required_, serializer);
         sse_encode_usize(collected, serializer);
+        sse_encode_list_sound_sample_count(perSound, serializer);
       case CalibrationError_InvalidFeatures(reason: final reason):
         sse_encode_i_32(1, serializer);
         sse_encode_String(reason, serializer);
@@ -2974,6 +4156,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       case CalibrationError_Timeout(reason: final reason):
         sse_encode_i_32(5, serializer);
         sse_encode_String(reason, serializer);
+      case CalibrationError_Io(reason: final reason):
+        sse_encode_i_32(6, serializer);
+        sse_encode_String(reason, serializer);
+      case CalibrationError_Parse(reason: final reason):
+        sse_encode_i_32(7, serializer);
+        sse_encode_String(reason, serializer);
+      case CalibrationError_UnknownPreset(name: final name):
+        sse_encode_i_32(8, serializer);
+        sse_encode_String(name, serializer);
+      case CalibrationError_NotASoundPhase(phase: final phase):
+        sse_encode_i_32(9, serializer);
+        sse_encode_calibration_sound(phase, serializer);
     }
   }
 
@@ -3018,6 +4212,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_bool(self.waitingForConfirmation, serializer);
     sse_encode_opt_box_autoadd_calibration_guidance(self.guidance, serializer);
     sse_encode_bool(self.manualAcceptAvailable, serializer);
+    sse_encode_bool(self.autoAdvanced, serializer);
+    sse_encode_bool(self.phaseChanged, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.estimatedRemainingMs, serializer);
+    sse_encode_opt_box_autoadd_calibration_summary(self.completed, serializer);
     sse_encode_opt_box_autoadd_calibration_progress_debug(
       self.debug,
       serializer,
@@ -3052,6 +4250,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_i_32(self.index, serializer);
   }
 
+  @protected
+  void sse_encode_calibration_summary(
+    CalibrationSummary self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_32(self.tKickCentroid, serializer);
+    sse_encode_f_32(self.tKickZcr, serializer);
+    sse_encode_f_32(self.tSnareCentroid, serializer);
+    sse_encode_f_32(self.tHihatZcr, serializer);
+    sse_encode_f_64(self.noiseFloorRms, serializer);
+    sse_encode_opt_box_autoadd_f_32(self.qualityScore, serializer);
+  }
+
   @protected
   void sse_encode_classification_result(
     ClassificationResult self,
@@ -3059,9 +4271,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_beatbox_hit(self.sound, serializer);
-    sse_encode_timing_feedback(self.timing, serializer);
+    sse_encode_opt_box_autoadd_timing_feedback(self.timing, serializer);
     sse_encode_u_64(self.timestampMs, serializer);
     sse_encode_f_32(self.confidence, serializer);
+    sse_encode_String(self.label, serializer);
+    sse_encode_bool(self.extra, serializer);
+    sse_encode_u_32(self.schemaVersion, serializer);
+    sse_encode_opt_box_autoadd_f_32(self.smoothedConfidence, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.beatIndex, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.subdivisionIndex, serializer);
+    sse_encode_u_64(self.wallClockMs, serializer);
+    sse_encode_hit_trigger(self.trigger, serializer);
+    sse_encode_bool(self.flam, serializer);
+    sse_encode_bool(self.clipped, serializer);
   }
 
   @protected
@@ -3085,6 +4307,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putFloat64(self);
   }
 
+  @protected
+  void sse_encode_features(Features self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_32(self.centroid, serializer);
+    sse_encode_f_32(self.zcr, serializer);
+    sse_encode_f_32(self.flatness, serializer);
+    sse_encode_f_32(self.rolloff, serializer);
+    sse_encode_f_32(self.decayTimeMs, serializer);
+  }
+
   @protected
   void sse_encode_fixture_bpm_range(
     FixtureBpmRange self,
@@ -3160,6 +4392,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_fixture_threshold(self.bpmDeviationPct, serializer);
   }
 
+  @protected
+  void sse_encode_hit_trigger(HitTrigger self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3196,6 +4434,40 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_metric_event_kind(
+    List<MetricEventKind> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_metric_event_kind(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_prim_f_32_loose(
+    List<double> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putFloat32List(
+      self is Float32List? self: Float32List.fromList(self),
+    );
+  }
+
+  @protected
+  void sse_encode_list_prim_f_32_strict(
+    Float32List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putFloat32List(self);
+  }
+
   @protected
   void sse_encode_list_prim_u_8_strict(
     Uint8List self,
@@ -3230,6 +4502,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_record_u_64_u_64(
+    List<(BigInt, BigInt)> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_record_u_64_u_64(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_sound_descriptor(
+    List<SoundDescriptor> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_sound_descriptor(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_sound_sample_count(
+    List<SoundSampleCount> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_sound_sample_count(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_manifest_synthetic_pattern(
     ManifestSyntheticPattern self,
@@ -3279,9 +4587,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_i_32(4, serializer);
         sse_encode_diagnostic_error(code, serializer);
         sse_encode_String(context, serializer);
+      case MetricEvent_ProcessingLatency(latencyMs: final latencyMs):
+        sse_encode_i_32(5, serializer);
+        sse_encode_f_32(latencyMs, serializer);
+      case MetricEvent_Silence(quietMs: final quietMs):
+        sse_encode_i_32(6, serializer);
+        sse_encode_f_32(quietMs, serializer);
     }
   }
 
+  @protected
+  void sse_encode_metric_event_kind(
+    MetricEventKind self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_onset_event(OnsetEvent self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3296,6 +4619,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       self.classification,
       serializer,
     );
+    sse_encode_f_64(self.strength, serializer);
+    sse_encode_opt_box_autoadd_features(self.features, serializer);
   }
 
   @protected
@@ -3334,6 +4659,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_calibration_summary(
+    CalibrationSummary? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_calibration_summary(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_classification_result(
     ClassificationResult? self,
@@ -3367,6 +4705,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_features(
+    Features? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_features(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_fixture_manifest_entry(
     FixtureManifestEntry? self,
@@ -3380,6 +4731,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_timing_feedback(
+    TimingFeedback? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_timing_feedback(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3396,6 +4760,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_box_autoadd_u_32(self.bpm, serializer);
     sse_encode_opt_box_autoadd_f_32(self.centroidThreshold, serializer);
     sse_encode_opt_box_autoadd_f_32(self.zcrThreshold, serializer);
+    sse_encode_opt_box_autoadd_f_32(self.kickCentroidThreshold, serializer);
+    sse_encode_opt_box_autoadd_f_32(self.kickZcrThreshold, serializer);
+    sse_encode_opt_box_autoadd_f_32(self.snareCentroidThreshold, serializer);
+    sse_encode_opt_box_autoadd_f_32(self.hihatZcrThreshold, serializer);
   }
 
   @protected
@@ -3429,6 +4797,39 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_u_32(self.$2, serializer);
   }
 
+  @protected
+  void sse_encode_record_u_64_u_64(
+    (BigInt, BigInt) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.$1, serializer);
+    sse_encode_u_64(self.$2, serializer);
+  }
+
+  @protected
+  void sse_encode_sound_descriptor(
+    SoundDescriptor self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_beatbox_hit(self.sound, serializer);
+    sse_encode_String(self.label, serializer);
+    sse_encode_u_8(self.minLevel, serializer);
+    sse_encode_bool(self.active, serializer);
+  }
+
+  @protected
+  void sse_encode_sound_sample_count(
+    SoundSampleCount self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_calibration_sound(self.sound, serializer);
+    sse_encode_usize(self.collected, serializer);
+    sse_encode_usize(self.needed, serializer);
+  }
+
   @protected
   void sse_encode_telemetry_event(
     TelemetryEvent self,
@@ -4,7 +4,8 @@
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
 import 'analysis.dart';
-import 'analysis/classifier.dart';
+import 'analysis/features/types.dart';
+import 'analysis/labels.dart';
 import 'analysis/quantizer.dart';
 import 'api.dart';
 import 'api/diagnostics.dart';
@@ -22,6 +23,7 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_io.dart';
 import 'telemetry/events.dart';
 import 'testing/fixture_manifest.dart';
 import 'testing/fixtures.dart';
+import 'types.dart';
 
 abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   RustLibApiImplPlatform({
@@ -61,6 +63,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  RustStreamSink<CalibrationDebugFrame>
+  dco_decode_StreamSink_calibration_debug_frame_Sse(dynamic raw);
+
   @protected
   RustStreamSink<CalibrationProgress>
   dco_decode_StreamSink_calibration_progress_Sse(dynamic raw);
@@ -108,6 +114,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  CalibrationSummary dco_decode_box_autoadd_calibration_summary(dynamic raw);
+
   @protected
   ClassificationResult dco_decode_box_autoadd_classification_result(
     dynamic raw,
@@ -119,6 +128,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   double dco_decode_box_autoadd_f_64(dynamic raw);
 
+  @protected
+  Features dco_decode_box_autoadd_features(dynamic raw);
+
   @protected
   FixtureManifestEntry dco_decode_box_autoadd_fixture_manifest_entry(
     dynamic raw,
@@ -127,9 +139,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ParamPatch dco_decode_box_autoadd_param_patch(dynamic raw);
 
+  @protected
+  TimingFeedback dco_decode_box_autoadd_timing_feedback(dynamic raw);
+
   @protected
   int dco_decode_box_autoadd_u_32(dynamic raw);
 
+  @protected
+  CalibrationDebugFrame dco_decode_calibration_debug_frame(dynamic raw);
+
   @protected
   CalibrationError dco_decode_calibration_error(dynamic raw);
 
@@ -151,6 +169,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   CalibrationSound dco_decode_calibration_sound(dynamic raw);
 
+  @protected
+  CalibrationSummary dco_decode_calibration_summary(dynamic raw);
+
   @protected
   ClassificationResult dco_decode_classification_result(dynamic raw);
 
@@ -163,6 +184,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   double dco_decode_f_64(dynamic raw);
 
+  @protected
+  Features dco_decode_features(dynamic raw);
+
   @protected
   FixtureBpmRange dco_decode_fixture_bpm_range(dynamic raw);
 
@@ -178,6 +202,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   FixtureToleranceEnvelope dco_decode_fixture_tolerance_envelope(dynamic raw);
 
+  @protected
+  HitTrigger dco_decode_hit_trigger(dynamic raw);
+
   @protected
   int dco_decode_i_32(dynamic raw);
 
@@ -192,6 +219,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  List<MetricEventKind> dco_decode_list_metric_event_kind(dynamic raw);
+
+  @protected
+  List<double> dco_decode_list_prim_f_32_loose(dynamic raw);
+
+  @protected
+  Float32List dco_decode_list_prim_f_32_strict(dynamic raw);
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
@@ -201,12 +237,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<(String, int)> dco_decode_list_record_string_u_32(dynamic raw);
 
+  @protected
+  List<(BigInt, BigInt)> dco_decode_list_record_u_64_u_64(dynamic raw);
+
+  @protected
+  List<SoundDescriptor> dco_decode_list_sound_descriptor(dynamic raw);
+
+  @protected
+  List<SoundSampleCount> dco_decode_list_sound_sample_count(dynamic raw);
+
   @protected
   ManifestSyntheticPattern dco_decode_manifest_synthetic_pattern(dynamic raw);
 
   @protected
   MetricEvent dco_decode_metric_event(dynamic raw);
 
+  @protected
+  MetricEventKind dco_decode_metric_event_kind(dynamic raw);
+
   @protected
   OnsetEvent dco_decode_onset_event(dynamic raw);
 
@@ -222,6 +270,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   CalibrationProgressDebug?
   dco_decode_opt_box_autoadd_calibration_progress_debug(dynamic raw);
 
+  @protected
+  CalibrationSummary? dco_decode_opt_box_autoadd_calibration_summary(
+    dynamic raw,
+  );
+
   @protected
   ClassificationResult? dco_decode_opt_box_autoadd_classification_result(
     dynamic raw,
@@ -233,11 +286,17 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
 
+  @protected
+  Features? dco_decode_opt_box_autoadd_features(dynamic raw);
+
   @protected
   FixtureManifestEntry? dco_decode_opt_box_autoadd_fixture_manifest_entry(
     dynamic raw,
   );
 
+  @protected
+  TimingFeedback? dco_decode_opt_box_autoadd_timing_feedback(dynamic raw);
+
   @protected
   int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
 
@@ -253,6 +312,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   (String, int) dco_decode_record_string_u_32(dynamic raw);
 
+  @protected
+  (BigInt, BigInt) dco_decode_record_u_64_u_64(dynamic raw);
+
+  @protected
+  SoundDescriptor dco_decode_sound_descriptor(dynamic raw);
+
+  @protected
+  SoundSampleCount dco_decode_sound_sample_count(dynamic raw);
+
   @protected
   TelemetryEvent dco_decode_telemetry_event(dynamic raw);
 
@@ -313,6 +381,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  RustStreamSink<CalibrationDebugFrame>
+  sse_decode_StreamSink_calibration_debug_frame_Sse(
+    SseDeserializer deserializer,
+  );
+
   @protected
   RustStreamSink<CalibrationProgress>
   sse_decode_StreamSink_calibration_progress_Sse(SseDeserializer deserializer);
@@ -364,6 +438,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  CalibrationSummary sse_decode_box_autoadd_calibration_summary(
+    SseDeserializer deserializer,
+  );
+
   @protected
   ClassificationResult sse_decode_box_autoadd_classification_result(
     SseDeserializer deserializer,
@@ -375,6 +454,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
 
+  @protected
+  Features sse_decode_box_autoadd_features(SseDeserializer deserializer);
+
   @protected
   FixtureManifestEntry sse_decode_box_autoadd_fixture_manifest_entry(
     SseDeserializer deserializer,
@@ -383,9 +465,19 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ParamPatch sse_decode_box_autoadd_param_patch(SseDeserializer deserializer);
 
+  @protected
+  TimingFeedback sse_decode_box_autoadd_timing_feedback(
+    SseDeserializer deserializer,
+  );
+
   @protected
   int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
 
+  @protected
+  CalibrationDebugFrame sse_decode_calibration_debug_frame(
+    SseDeserializer deserializer,
+  );
+
   @protected
   CalibrationError sse_decode_calibration_error(SseDeserializer deserializer);
 
@@ -417,6 +509,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   CalibrationSound sse_decode_calibration_sound(SseDeserializer deserializer);
 
+  @protected
+  CalibrationSummary sse_decode_calibration_summary(
+    SseDeserializer deserializer,
+  );
+
   @protected
   ClassificationResult sse_decode_classification_result(
     SseDeserializer deserializer,
@@ -431,6 +528,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   double sse_decode_f_64(SseDeserializer deserializer);
 
+  @protected
+  Features sse_decode_features(SseDeserializer deserializer);
+
   @protected
   FixtureBpmRange sse_decode_fixture_bpm_range(SseDeserializer deserializer);
 
@@ -452,6 +552,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  HitTrigger sse_decode_hit_trigger(SseDeserializer deserializer);
+
   @protected
   int sse_decode_i_32(SseDeserializer deserializer);
 
@@ -466,6 +569,17 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<MetricEventKind> sse_decode_list_metric_event_kind(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<double> sse_decode_list_prim_f_32_loose(SseDeserializer deserializer);
+
+  @protected
+  Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer);
+
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
@@ -479,6 +593,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<(BigInt, BigInt)> sse_decode_list_record_u_64_u_64(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<SoundDescriptor> sse_decode_list_sound_descriptor(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<SoundSampleCount> sse_decode_list_sound_sample_count(
+    SseDeserializer deserializer,
+  );
+
   @protected
   ManifestSyntheticPattern sse_decode_manifest_synthetic_pattern(
     SseDeserializer deserializer,
@@ -487,6 +616,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   MetricEvent sse_decode_metric_event(SseDeserializer deserializer);
 
+  @protected
+  MetricEventKind sse_decode_metric_event_kind(SseDeserializer deserializer);
+
   @protected
   OnsetEvent sse_decode_onset_event(SseDeserializer deserializer);
 
@@ -504,6 +636,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  CalibrationSummary? sse_decode_opt_box_autoadd_calibration_summary(
+    SseDeserializer deserializer,
+  );
+
   @protected
   ClassificationResult? sse_decode_opt_box_autoadd_classification_result(
     SseDeserializer deserializer,
@@ -515,11 +652,19 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
 
+  @protected
+  Features? sse_decode_opt_box_autoadd_features(SseDeserializer deserializer);
+
   @protected
   FixtureManifestEntry? sse_decode_opt_box_autoadd_fixture_manifest_entry(
     SseDeserializer deserializer,
   );
 
+  @protected
+  TimingFeedback? sse_decode_opt_box_autoadd_timing_feedback(
+    SseDeserializer deserializer,
+  );
+
   @protected
   int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
 
@@ -539,6 +684,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   (String, int) sse_decode_record_string_u_32(SseDeserializer deserializer);
 
+  @protected
+  (BigInt, BigInt) sse_decode_record_u_64_u_64(SseDeserializer deserializer);
+
+  @protected
+  SoundDescriptor sse_decode_sound_descriptor(SseDeserializer deserializer);
+
+  @protected
+  SoundSampleCount sse_decode_sound_sample_count(SseDeserializer deserializer);
+
   @protected
   TelemetryEvent sse_decode_telemetry_event(SseDeserializer deserializer);
 
@@ -611,6 +765,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_StreamSink_calibration_debug_frame_Sse(
+    RustStreamSink<CalibrationDebugFrame> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_StreamSink_calibration_progress_Sse(
     RustStreamSink<CalibrationProgress> self,
@@ -674,6 +834,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_calibration_summary(
+    CalibrationSummary self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_classification_result(
     ClassificationResult self,
@@ -686,6 +852,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_features(Features self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_fixture_manifest_entry(
     FixtureManifestEntry self,
@@ -698,9 +867,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_timing_feedback(
+    TimingFeedback self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_calibration_debug_frame(
+    CalibrationDebugFrame self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_calibration_error(
     CalibrationError self,
@@ -743,6 +924,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_calibration_summary(
+    CalibrationSummary self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_classification_result(
     ClassificationResult self,
@@ -761,6 +948,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_f_64(double self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_features(Features self, SseSerializer serializer);
+
   @protected
   void sse_encode_fixture_bpm_range(
     FixtureBpmRange self,
@@ -791,6 +981,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_hit_trigger(HitTrigger self, SseSerializer serializer);
+
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer);
 
@@ -809,6 +1002,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_metric_event_kind(
+    List<MetricEventKind> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_f_32_loose(
+    List<double> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_f_32_strict(
+    Float32List self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_prim_u_8_strict(
     Uint8List self,
@@ -827,6 +1038,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_record_u_64_u_64(
+    List<(BigInt, BigInt)> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_sound_descriptor(
+    List<SoundDescriptor> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_sound_sample_count(
+    List<SoundSampleCount> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_manifest_synthetic_pattern(
     ManifestSyntheticPattern self,
@@ -836,6 +1065,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_metric_event(MetricEvent self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_metric_event_kind(
+    MetricEventKind self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_onset_event(OnsetEvent self, SseSerializer serializer);
 
@@ -854,6 +1089,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_box_autoadd_calibration_summary(
+    CalibrationSummary? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_box_autoadd_classification_result(
     ClassificationResult? self,
@@ -866,12 +1107,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_features(
+    Features? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_box_autoadd_fixture_manifest_entry(
     FixtureManifestEntry? self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_box_autoadd_timing_feedback(
+    TimingFeedback? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
 
@@ -896,6 +1149,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_record_u_64_u_64(
+    (BigInt, BigInt) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_sound_descriptor(
+    SoundDescriptor self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_sound_sample_count(
+    SoundSampleCount self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_telemetry_event(
     TelemetryEvent self,
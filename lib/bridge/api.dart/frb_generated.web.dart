@@ -7,7 +7,8 @@
 // ignore_for_file: argument_type_not_assignable
 
 import 'analysis.dart';
-import 'analysis/classifier.dart';
+import 'analysis/features/types.dart';
+import 'analysis/labels.dart';
 import 'analysis/quantizer.dart';
 import 'api.dart';
 import 'api/diagnostics.dart';
@@ -24,6 +25,7 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 import 'telemetry/events.dart';
 import 'testing/fixture_manifest.dart';
 import 'testing/fixtures.dart';
+import 'types.dart';
 
 abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   RustLibApiImplPlatform({
@@ -63,6 +65,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  RustStreamSink<CalibrationDebugFrame>
+  dco_decode_StreamSink_calibration_debug_frame_Sse(dynamic raw);
+
   @protected
   RustStreamSink<CalibrationProgress>
   dco_decode_StreamSink_calibration_progress_Sse(dynamic raw);
@@ -110,6 +116,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  CalibrationSummary dco_decode_box_autoadd_calibration_summary(dynamic raw);
+
   @protected
   ClassificationResult dco_decode_box_autoadd_classification_result(
     dynamic raw,
@@ -121,6 +130,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   double dco_decode_box_autoadd_f_64(dynamic raw);
 
+  @protected
+  Features dco_decode_box_autoadd_features(dynamic raw);
+
   @protected
   FixtureManifestEntry dco_decode_box_autoadd_fixture_manifest_entry(
     dynamic raw,
@@ -129,9 +141,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ParamPatch dco_decode_box_autoadd_param_patch(dynamic raw);
 
+  @protected
+  TimingFeedback dco_decode_box_autoadd_timing_feedback(dynamic raw);
+
   @protected
   int dco_decode_box_autoadd_u_32(dynamic raw);
 
+  @protected
+  CalibrationDebugFrame dco_decode_calibration_debug_frame(dynamic raw);
+
   @protected
   CalibrationError dco_decode_calibration_error(dynamic raw);
 
@@ -153,6 +171,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   CalibrationSound dco_decode_calibration_sound(dynamic raw);
 
+  @protected
+  CalibrationSummary dco_decode_calibration_summary(dynamic raw);
+
   @protected
   ClassificationResult dco_decode_classification_result(dynamic raw);
 
@@ -165,6 +186,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   double dco_decode_f_64(dynamic raw);
 
+  @protected
+  Features dco_decode_features(dynamic raw);
+
   @protected
   FixtureBpmRange dco_decode_fixture_bpm_range(dynamic raw);
 
@@ -180,6 +204,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   FixtureToleranceEnvelope dco_decode_fixture_tolerance_envelope(dynamic raw);
 
+  @protected
+  HitTrigger dco_decode_hit_trigger(dynamic raw);
+
   @protected
   int dco_decode_i_32(dynamic raw);
 
@@ -194,6 +221,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  List<MetricEventKind> dco_decode_list_metric_event_kind(dynamic raw);
+
+  @protected
+  List<double> dco_decode_list_prim_f_32_loose(dynamic raw);
+
+  @protected
+  Float32List dco_decode_list_prim_f_32_strict(dynamic raw);
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
@@ -203,12 +239,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<(String, int)> dco_decode_list_record_string_u_32(dynamic raw);
 
+  @protected
+  List<(BigInt, BigInt)> dco_decode_list_record_u_64_u_64(dynamic raw);
+
+  @protected
+  List<SoundDescriptor> dco_decode_list_sound_descriptor(dynamic raw);
+
+  @protected
+  List<SoundSampleCount> dco_decode_list_sound_sample_count(dynamic raw);
+
   @protected
   ManifestSyntheticPattern dco_decode_manifest_synthetic_pattern(dynamic raw);
 
   @protected
   MetricEvent dco_decode_metric_event(dynamic raw);
 
+  @protected
+  MetricEventKind dco_decode_metric_event_kind(dynamic raw);
+
   @protected
   OnsetEvent dco_decode_onset_event(dynamic raw);
 
@@ -224,6 +272,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   CalibrationProgressDebug?
   dco_decode_opt_box_autoadd_calibration_progress_debug(dynamic raw);
 
+  @protected
+  CalibrationSummary? dco_decode_opt_box_autoadd_calibration_summary(
+    dynamic raw,
+  );
+
   @protected
   ClassificationResult? dco_decode_opt_box_autoadd_classification_result(
     dynamic raw,
@@ -235,11 +288,17 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
 
+  @protected
+  Features? dco_decode_opt_box_autoadd_features(dynamic raw);
+
   @protected
   FixtureManifestEntry? dco_decode_opt_box_autoadd_fixture_manifest_entry(
     dynamic raw,
   );
 
+  @protected
+  TimingFeedback? dco_decode_opt_box_autoadd_timing_feedback(dynamic raw);
+
   @protected
   int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
 
@@ -255,6 +314,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   (String, int) dco_decode_record_string_u_32(dynamic raw);
 
+  @protected
+  (BigInt, BigInt) dco_decode_record_u_64_u_64(dynamic raw);
+
+  @protected
+  SoundDescriptor dco_decode_sound_descriptor(dynamic raw);
+
+  @protected
+  SoundSampleCount dco_decode_sound_sample_count(dynamic raw);
+
   @protected
   TelemetryEvent dco_decode_telemetry_event(dynamic raw);
 
@@ -315,6 +383,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  RustStreamSink<CalibrationDebugFrame>
+  sse_decode_StreamSink_calibration_debug_frame_Sse(
+    SseDeserializer deserializer,
+  );
+
   @protected
   RustStreamSink<CalibrationProgress>
   sse_decode_StreamSink_calibration_progress_Sse(SseDeserializer deserializer);
@@ -366,6 +440,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  CalibrationSummary sse_decode_box_autoadd_calibration_summary(
+    SseDeserializer deserializer,
+  );
+
   @protected
   ClassificationResult sse_decode_box_autoadd_classification_result(
     SseDeserializer deserializer,
@@ -377,6 +456,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
 
+  @protected
+  Features sse_decode_box_autoadd_features(SseDeserializer deserializer);
+
   @protected
   FixtureManifestEntry sse_decode_box_autoadd_fixture_manifest_entry(
     SseDeserializer deserializer,
@@ -385,9 +467,19 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ParamPatch sse_decode_box_autoadd_param_patch(SseDeserializer deserializer);
 
+  @protected
+  TimingFeedback sse_decode_box_autoadd_timing_feedback(
+    SseDeserializer deserializer,
+  );
+
   @protected
   int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
 
+  @protected
+  CalibrationDebugFrame sse_decode_calibration_debug_frame(
+    SseDeserializer deserializer,
+  );
+
   @protected
   CalibrationError sse_decode_calibration_error(SseDeserializer deserializer);
 
@@ -419,6 +511,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   CalibrationSound sse_decode_calibration_sound(SseDeserializer deserializer);
 
+  @protected
+  CalibrationSummary sse_decode_calibration_summary(
+    SseDeserializer deserializer,
+  );
+
   @protected
   ClassificationResult sse_decode_classification_result(
     SseDeserializer deserializer,
@@ -433,6 +530,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   double sse_decode_f_64(SseDeserializer deserializer);
 
+  @protected
+  Features sse_decode_features(SseDeserializer deserializer);
+
   @protected
   FixtureBpmRange sse_decode_fixture_bpm_range(SseDeserializer deserializer);
 
@@ -454,6 +554,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  HitTrigger sse_decode_hit_trigger(SseDeserializer deserializer);
+
   @protected
   int sse_decode_i_32(SseDeserializer deserializer);
 
@@ -468,6 +571,17 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<MetricEventKind> sse_decode_list_metric_event_kind(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<double> sse_decode_list_prim_f_32_loose(SseDeserializer deserializer);
+
+  @protected
+  Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer);
+
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
@@ -481,6 +595,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  List<(BigInt, BigInt)> sse_decode_list_record_u_64_u_64(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<SoundDescriptor> sse_decode_list_sound_descriptor(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<SoundSampleCount> sse_decode_list_sound_sample_count(
+    SseDeserializer deserializer,
+  );
+
   @protected
   ManifestSyntheticPattern sse_decode_manifest_synthetic_pattern(
     SseDeserializer deserializer,
@@ -489,6 +618,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   MetricEvent sse_decode_metric_event(SseDeserializer deserializer);
 
+  @protected
+  MetricEventKind sse_decode_metric_event_kind(SseDeserializer deserializer);
+
   @protected
   OnsetEvent sse_decode_onset_event(SseDeserializer deserializer);
 
@@ -506,6 +638,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  CalibrationSummary? sse_decode_opt_box_autoadd_calibration_summary(
+    SseDeserializer deserializer,
+  );
+
   @protected
   ClassificationResult? sse_decode_opt_box_autoadd_classification_result(
     SseDeserializer deserializer,
@@ -517,11 +654,19 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
 
+  @protected
+  Features? sse_decode_opt_box_autoadd_features(SseDeserializer deserializer);
+
   @protected
   FixtureManifestEntry? sse_decode_opt_box_autoadd_fixture_manifest_entry(
     SseDeserializer deserializer,
   );
 
+  @protected
+  TimingFeedback? sse_decode_opt_box_autoadd_timing_feedback(
+    SseDeserializer deserializer,
+  );
+
   @protected
   int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
 
@@ -541,6 +686,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   (String, int) sse_decode_record_string_u_32(SseDeserializer deserializer);
 
+  @protected
+  (BigInt, BigInt) sse_decode_record_u_64_u_64(SseDeserializer deserializer);
+
+  @protected
+  SoundDescriptor sse_decode_sound_descriptor(SseDeserializer deserializer);
+
+  @protected
+  SoundSampleCount sse_decode_sound_sample_count(SseDeserializer deserializer);
+
   @protected
   TelemetryEvent sse_decode_telemetry_event(SseDeserializer deserializer);
 
@@ -613,6 +767,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_StreamSink_calibration_debug_frame_Sse(
+    RustStreamSink<CalibrationDebugFrame> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_StreamSink_calibration_progress_Sse(
     RustStreamSink<CalibrationProgress> self,
@@ -676,6 +836,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_calibration_summary(
+    CalibrationSummary self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_classification_result(
     ClassificationResult self,
@@ -688,6 +854,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_features(Features self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_fixture_manifest_entry(
     FixtureManifestEntry self,
@@ -700,9 +869,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_timing_feedback(
+    TimingFeedback self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_calibration_debug_frame(
+    CalibrationDebugFrame self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_calibration_error(
     CalibrationError self,
@@ -745,6 +926,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_calibration_summary(
+    CalibrationSummary self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_classification_result(
     ClassificationResult self,
@@ -763,6 +950,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_f_64(double self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_features(Features self, SseSerializer serializer);
+
   @protected
   void sse_encode_fixture_bpm_range(
     FixtureBpmRange self,
@@ -793,6 +983,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_hit_trigger(HitTrigger self, SseSerializer serializer);
+
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer);
 
@@ -811,6 +1004,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_metric_event_kind(
+    List<MetricEventKind> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_f_32_loose(
+    List<double> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_f_32_strict(
+    Float32List self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_prim_u_8_strict(
     Uint8List self,
@@ -829,6 +1040,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_record_u_64_u_64(
+    List<(BigInt, BigInt)> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_sound_descriptor(
+    List<SoundDescriptor> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_sound_sample_count(
+    List<SoundSampleCount> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_manifest_synthetic_pattern(
     ManifestSyntheticPattern self,
@@ -838,6 +1067,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_metric_event(MetricEvent self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_metric_event_kind(
+    MetricEventKind self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_onset_event(OnsetEvent self, SseSerializer serializer);
 
@@ -856,6 +1091,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_box_autoadd_calibration_summary(
+    CalibrationSummary? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_box_autoadd_classification_result(
     ClassificationResult? self,
@@ -868,12 +1109,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_features(
+    Features? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_box_autoadd_fixture_manifest_entry(
     FixtureManifestEntry? self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_box_autoadd_timing_feedback(
+    TimingFeedback? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
 
@@ -898,6 +1151,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_record_u_64_u_64(
+    (BigInt, BigInt) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_sound_descriptor(
+    SoundDescriptor self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_sound_sample_count(
+    SoundSampleCount self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_telemetry_event(
     TelemetryEvent self,
@@ -3,14 +3,25 @@
 
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
-import '../analysis/classifier.dart';
 import '../frb_generated.dart';
+import '../types.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 part 'events.freezed.dart';
 
 /// Diagnostic error codes surfaced via telemetry metrics.
-enum DiagnosticError { fixtureLoad, bufferDrain, streamBackpressure, unknown }
+enum DiagnosticError {
+  fixtureLoad,
+  bufferDrain,
+  streamBackpressure,
+  unknown,
+
+  /// The OS refused elevated scheduling priority for the analysis thread
+  threadPriorityDenied,
+
+  /// NaN/Inf samples reached the analysis thread; the window was skipped
+  invalidAudio,
+}
 
 /// High-level lifecycle stages reported by JNI/engine instrumentation.
 enum LifecyclePhase {
@@ -47,4 +58,25 @@ sealed class MetricEvent with _$MetricEvent {
     required DiagnosticError code,
     required String context,
   }) = MetricEvent_Error;
+
+  /// Time from a hit's detection to its result being broadcast (analysis
+  /// thread cost, unlike the timing error tracked by `Latency`)
+  const factory MetricEvent.processingLatency({required double latencyMs}) =
+      MetricEvent_ProcessingLatency;
+
+  /// The input has stayed below the classification gate for `quiet_ms`
+  /// (sent once per quiet stretch, see `silence_timeout_ms`)
+  const factory MetricEvent.silence({required double quietMs}) =
+      MetricEvent_Silence;
+}
+
+/// Payload-free discriminant of [`MetricEvent`], used to filter subscriptions.
+enum MetricEventKind {
+  latency,
+  bufferOccupancy,
+  classification,
+  jniLifecycle,
+  error,
+  processingLatency,
+  silence,
 }
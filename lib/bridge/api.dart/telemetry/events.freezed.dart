@@ -55,7 +55,7 @@ extension MetricEventPatterns on MetricEvent {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( MetricEvent_Latency value)?  latency,TResult Function( MetricEvent_BufferOccupancy value)?  bufferOccupancy,TResult Function( MetricEvent_Classification value)?  classification,TResult Function( MetricEvent_JniLifecycle value)?  jniLifecycle,TResult Function( MetricEvent_Error value)?  error,required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( MetricEvent_Latency value)?  latency,TResult Function( MetricEvent_BufferOccupancy value)?  bufferOccupancy,TResult Function( MetricEvent_Classification value)?  classification,TResult Function( MetricEvent_JniLifecycle value)?  jniLifecycle,TResult Function( MetricEvent_Error value)?  error,TResult Function( MetricEvent_ProcessingLatency value)?  processingLatency,TResult Function( MetricEvent_Silence value)?  silence,required TResult orElse(),}){
 final _that = this;
 switch (_that) {
 case MetricEvent_Latency() when latency != null:
@@ -63,7 +63,9 @@ return latency(_that);case MetricEvent_BufferOccupancy() when bufferOccupancy !=
 return bufferOccupancy(_that);case MetricEvent_Classification() when classification != null:
 return classification(_that);case MetricEvent_JniLifecycle() when jniLifecycle != null:
 return jniLifecycle(_that);case MetricEvent_Error() when error != null:
-return error(_that);case _:
+return error(_that);case MetricEvent_ProcessingLatency() when processingLatency != null:
+return processingLatency(_that);case MetricEvent_Silence() when silence != null:
+return silence(_that);case _:
   return orElse();
 
 }
@@ -81,7 +83,7 @@ return error(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( MetricEvent_Latency value)  latency,required TResult Function( MetricEvent_BufferOccupancy value)  bufferOccupancy,required TResult Function( MetricEvent_Classification value)  classification,required TResult Function( MetricEvent_JniLifecycle value)  jniLifecycle,required TResult Function( MetricEvent_Error value)  error,}){
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( MetricEvent_Latency value)  latency,required TResult Function( MetricEvent_BufferOccupancy value)  bufferOccupancy,required TResult Function( MetricEvent_Classification value)  classification,required TResult Function( MetricEvent_JniLifecycle value)  jniLifecycle,required TResult Function( MetricEvent_Error value)  error,required TResult Function( MetricEvent_ProcessingLatency value)  processingLatency,required TResult Function( MetricEvent_Silence value)  silence,}){
 final _that = this;
 switch (_that) {
 case MetricEvent_Latency():
@@ -89,7 +91,9 @@ return latency(_that);case MetricEvent_BufferOccupancy():
 return bufferOccupancy(_that);case MetricEvent_Classification():
 return classification(_that);case MetricEvent_JniLifecycle():
 return jniLifecycle(_that);case MetricEvent_Error():
-return error(_that);}
+return error(_that);case MetricEvent_ProcessingLatency():
+return processingLatency(_that);case MetricEvent_Silence():
+return silence(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
 ///
@@ -103,7 +107,7 @@ return error(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( MetricEvent_Latency value)?  latency,TResult? Function( MetricEvent_BufferOccupancy value)?  bufferOccupancy,TResult? Function( MetricEvent_Classification value)?  classification,TResult? Function( MetricEvent_JniLifecycle value)?  jniLifecycle,TResult? Function( MetricEvent_Error value)?  error,}){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( MetricEvent_Latency value)?  latency,TResult? Function( MetricEvent_BufferOccupancy value)?  bufferOccupancy,TResult? Function( MetricEvent_Classification value)?  classification,TResult? Function( MetricEvent_JniLifecycle value)?  jniLifecycle,TResult? Function( MetricEvent_Error value)?  error,TResult? Function( MetricEvent_ProcessingLatency value)?  processingLatency,TResult? Function( MetricEvent_Silence value)?  silence,}){
 final _that = this;
 switch (_that) {
 case MetricEvent_Latency() when latency != null:
@@ -111,7 +115,9 @@ return latency(_that);case MetricEvent_BufferOccupancy() when bufferOccupancy !=
 return bufferOccupancy(_that);case MetricEvent_Classification() when classification != null:
 return classification(_that);case MetricEvent_JniLifecycle() when jniLifecycle != null:
 return jniLifecycle(_that);case MetricEvent_Error() when error != null:
-return error(_that);case _:
+return error(_that);case MetricEvent_ProcessingLatency() when processingLatency != null:
+return processingLatency(_that);case MetricEvent_Silence() when silence != null:
+return silence(_that);case _:
   return null;
 
 }
@@ -128,14 +134,16 @@ return error(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( double avgMs,  double maxMs,  BigInt sampleCount)?  latency,TResult Function( String channel,  double percent)?  bufferOccupancy,TResult Function( BeatboxHit sound,  double confidence,  double timingErrorMs)?  classification,TResult Function( LifecyclePhase phase,  BigInt timestampMs)?  jniLifecycle,TResult Function( DiagnosticError code,  String context)?  error,required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( double avgMs,  double maxMs,  BigInt sampleCount)?  latency,TResult Function( String channel,  double percent)?  bufferOccupancy,TResult Function( BeatboxHit sound,  double confidence,  double timingErrorMs)?  classification,TResult Function( LifecyclePhase phase,  BigInt timestampMs)?  jniLifecycle,TResult Function( DiagnosticError code,  String context)?  error,TResult Function( double latencyMs)?  processingLatency,TResult Function( double quietMs)?  silence,required TResult orElse(),}) {final _that = this;
 switch (_that) {
 case MetricEvent_Latency() when latency != null:
 return latency(_that.avgMs,_that.maxMs,_that.sampleCount);case MetricEvent_BufferOccupancy() when bufferOccupancy != null:
 return bufferOccupancy(_that.channel,_that.percent);case MetricEvent_Classification() when classification != null:
 return classification(_that.sound,_that.confidence,_that.timingErrorMs);case MetricEvent_JniLifecycle() when jniLifecycle != null:
 return jniLifecycle(_that.phase,_that.timestampMs);case MetricEvent_Error() when error != null:
-return error(_that.code,_that.context);case _:
+return error(_that.code,_that.context);case MetricEvent_ProcessingLatency() when processingLatency != null:
+return processingLatency(_that.latencyMs);case MetricEvent_Silence() when silence != null:
+return silence(_that.quietMs);case _:
   return orElse();
 
 }
@@ -153,14 +161,16 @@ return error(_that.code,_that.context);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( double avgMs,  double maxMs,  BigInt sampleCount)  latency,required TResult Function( String channel,  double percent)  bufferOccupancy,required TResult Function( BeatboxHit sound,  double confidence,  double timingErrorMs)  classification,required TResult Function( LifecyclePhase phase,  BigInt timestampMs)  jniLifecycle,required TResult Function( DiagnosticError code,  String context)  error,}) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( double avgMs,  double maxMs,  BigInt sampleCount)  latency,required TResult Function( String channel,  double percent)  bufferOccupancy,required TResult Function( BeatboxHit sound,  double confidence,  double timingErrorMs)  classification,required TResult Function( LifecyclePhase phase,  BigInt timestampMs)  jniLifecycle,required TResult Function( DiagnosticError code,  String context)  error,required TResult Function( double latencyMs)  processingLatency,required TResult Function( double quietMs)  silence,}) {final _that = this;
 switch (_that) {
 case MetricEvent_Latency():
 return latency(_that.avgMs,_that.maxMs,_that.sampleCount);case MetricEvent_BufferOccupancy():
 return bufferOccupancy(_that.channel,_that.percent);case MetricEvent_Classification():
 return classification(_that.sound,_that.confidence,_that.timingErrorMs);case MetricEvent_JniLifecycle():
 return jniLifecycle(_that.phase,_that.timestampMs);case MetricEvent_Error():
-return error(_that.code,_that.context);}
+return error(_that.code,_that.context);case MetricEvent_ProcessingLatency():
+return processingLatency(_that.latencyMs);case MetricEvent_Silence():
+return silence(_that.quietMs);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -174,14 +184,16 @@ return error(_that.code,_that.context);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( double avgMs,  double maxMs,  BigInt sampleCount)?  latency,TResult? Function( String channel,  double percent)?  bufferOccupancy,TResult? Function( BeatboxHit sound,  double confidence,  double timingErrorMs)?  classification,TResult? Function( LifecyclePhase phase,  BigInt timestampMs)?  jniLifecycle,TResult? Function( DiagnosticError code,  String context)?  error,}) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( double avgMs,  double maxMs,  BigInt sampleCount)?  latency,TResult? Function( String channel,  double percent)?  bufferOccupancy,TResult? Function( BeatboxHit sound,  double confidence,  double timingErrorMs)?  classification,TResult? Function( LifecyclePhase phase,  BigInt timestampMs)?  jniLifecycle,TResult? Function( DiagnosticError code,  String context)?  error,TResult? Function( double latencyMs)?  processingLatency,TResult? Function( double quietMs)?  silence,}) {final _that = this;
 switch (_that) {
 case MetricEvent_Latency() when latency != null:
 return latency(_that.avgMs,_that.maxMs,_that.sampleCount);case MetricEvent_BufferOccupancy() when bufferOccupancy != null:
 return bufferOccupancy(_that.channel,_that.percent);case MetricEvent_Classification() when classification != null:
 return classification(_that.sound,_that.confidence,_that.timingErrorMs);case MetricEvent_JniLifecycle() when jniLifecycle != null:
 return jniLifecycle(_that.phase,_that.timestampMs);case MetricEvent_Error() when error != null:
-return error(_that.code,_that.context);case _:
+return error(_that.code,_that.context);case MetricEvent_ProcessingLatency() when processingLatency != null:
+return processingLatency(_that.latencyMs);case MetricEvent_Silence() when silence != null:
+return silence(_that.quietMs);case _:
   return null;
 
 }
@@ -532,5 +544,137 @@ as String,
 
 
 }
+/// @nodoc
+
+
+class MetricEvent_ProcessingLatency extends MetricEvent {
+  const MetricEvent_ProcessingLatency({required this.latencyMs}): super._();
+  
+
+ final  double latencyMs;
+
+/// Create a copy of MetricEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$MetricEvent_ProcessingLatencyCopyWith<MetricEvent_ProcessingLatency> get copyWith => _$MetricEvent_ProcessingLatencyCopyWithImpl<MetricEvent_ProcessingLatency>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is MetricEvent_ProcessingLatency&&(identical(other.latencyMs, latencyMs) || other.latencyMs == latencyMs));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,latencyMs);
+
+@override
+String toString() {
+  return 'MetricEvent.processingLatency(latencyMs: $latencyMs)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $MetricEvent_ProcessingLatencyCopyWith<$Res> implements $MetricEventCopyWith<$Res> {
+  factory $MetricEvent_ProcessingLatencyCopyWith(MetricEvent_ProcessingLatency value, $Res Function(MetricEvent_ProcessingLatency) _then) = _$MetricEvent_ProcessingLatencyCopyWithImpl;
+@useResult
+$Res call({
+ double latencyMs
+});
+
+
+
+
+}
+/// @nodoc
+class _$MetricEvent_ProcessingLatencyCopyWithImpl<$Res>
+    implements $MetricEvent_ProcessingLatencyCopyWith<$Res> {
+  _$MetricEvent_ProcessingLatencyCopyWithImpl(this._self, this._then);
+
+  final MetricEvent_ProcessingLatency _self;
+  final $Res Function(MetricEvent_ProcessingLatency) _then;
+
+/// Create a copy of MetricEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? latencyMs = null,}) {
+  return _then(MetricEvent_ProcessingLatency(
+latencyMs: null == latencyMs ? _self.latencyMs : latencyMs // ignore: cast_nullable_to_non_nullable
+as double,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class MetricEvent_Silence extends MetricEvent {
+  const MetricEvent_Silence({required this.quietMs}): super._();
+  
+
+ final  double quietMs;
+
+/// Create a copy of MetricEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$MetricEvent_SilenceCopyWith<MetricEvent_Silence> get copyWith => _$MetricEvent_SilenceCopyWithImpl<MetricEvent_Silence>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is MetricEvent_Silence&&(identical(other.quietMs, quietMs) || other.quietMs == quietMs));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,quietMs);
+
+@override
+String toString() {
+  return 'MetricEvent.silence(quietMs: $quietMs)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $MetricEvent_SilenceCopyWith<$Res> implements $MetricEventCopyWith<$Res> {
+  factory $MetricEvent_SilenceCopyWith(MetricEvent_Silence value, $Res Function(MetricEvent_Silence) _then) = _$MetricEvent_SilenceCopyWithImpl;
+@useResult
+$Res call({
+ double quietMs
+});
+
+
+
+
+}
+/// @nodoc
+class _$MetricEvent_SilenceCopyWithImpl<$Res>
+    implements $MetricEvent_SilenceCopyWith<$Res> {
+  _$MetricEvent_SilenceCopyWithImpl(this._self, this._then);
+
+  final MetricEvent_Silence _self;
+  final $Res Function(MetricEvent_Silence) _then;
+
+/// Create a copy of MetricEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? quietMs = null,}) {
+  return _then(MetricEvent_Silence(
+quietMs: null == quietMs ? _self.quietMs : quietMs // ignore: cast_nullable_to_non_nullable
+as double,
+  ));
+}
+
+
+}
+
 
 // dart format on
@@ -1,4 +1,4 @@
-import '../api.dart/analysis/classifier.dart';
+import '../api.dart/types.dart';
 
 extension BeatboxHitExtensions on BeatboxHit {
   String get displayName {
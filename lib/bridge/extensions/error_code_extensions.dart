@@ -38,6 +38,9 @@ extension AudioErrorCodesExtension on AudioErrorCodes {
 
   /// Live stream disconnected or command queue closed
   static const int streamFailure = 1010;
+
+  /// Operation is not available in this build (e.g. debug-only hooks)
+  static const int unsupported = 1011;
 }
 
 /// Extension on CalibrationErrorCodes to provide constant accessors
@@ -61,4 +64,16 @@ extension CalibrationErrorCodesExtension on CalibrationErrorCodes {
 
   /// Calibration timed out waiting for the engine
   static const int timeout = 2006;
+
+  /// Calibration file could not be read or written
+  static const int io = 2007;
+
+  /// Calibration file or clip contents could not be parsed
+  static const int parse = 2008;
+
+  /// No built-in calibration preset has the requested name
+  static const int unknownPreset = 2009;
+
+  /// Operation needs a sound collection phase, not a level measurement
+  static const int notASoundPhase = 2010;
 }
@@ -452,6 +452,10 @@ class CalibrationController {
         return '$soundName is clipping. Back off the mic slightly or reduce volume.';
       case CalibrationGuidanceReason.stagnation:
         return 'We hear $soundName attempts, but none counted. Try a sharper attack with short pauses.';
+      case CalibrationGuidanceReason.tooNoisy:
+        return 'It is too noisy here for reliable detection. Find a quieter spot and retry.';
+      case CalibrationGuidanceReason.unexpectedTimbre:
+        return "That doesn't sound like a typical $soundName. It still counts, but check you're making the right sound.";
     }
   }
 
@@ -1,7 +1,13 @@
 import 'package:flutter/material.dart';
 
 /// Reasons for engine-provided guidance during calibration
-enum CalibrationGuidanceReason { stagnation, tooQuiet, clipped }
+enum CalibrationGuidanceReason {
+  stagnation,
+  tooQuiet,
+  clipped,
+  tooNoisy,
+  unexpectedTimbre,
+}
 
 /// Guidance payload accompanying calibration progress updates
 class CalibrationGuidance {
@@ -78,8 +84,8 @@ class CalibrationProgress {
 
   /// Get overall progress as a fraction (0.0 to 1.0) across all 3 sounds
   double get overallProgressFraction {
-    final soundIndex = currentSound.index;
-    final totalSounds = CalibrationSound.values.length;
+    final soundIndex = currentSound.step;
+    const totalSounds = CalibrationSound.stepCount;
     final completedSounds = soundIndex;
     final currentSoundProgress = progressFraction;
 
@@ -159,7 +165,19 @@ enum CalibrationSound {
   snare,
 
   /// Hi-hat - high frequency sound
-  hiHat;
+  hiHat,
+
+  /// Optional step before the noise floor: a few of the user's loudest hits
+  /// are measured to derive the input gain
+  inputLevel;
+
+  /// Number of steps in the calibration sequence
+  static const int stepCount = 4;
+
+  /// Zero-based step within the calibration sequence
+  ///
+  /// The optional input level measurement shares the noise floor's step.
+  int get step => this == CalibrationSound.inputLevel ? 0 : index;
 
   /// Get human-readable display name for UI instructions
   String get displayName {
@@ -172,6 +190,8 @@ enum CalibrationSound {
         return 'SNARE';
       case CalibrationSound.hiHat:
         return 'HI-HAT';
+      case CalibrationSound.inputLevel:
+        return 'INPUT LEVEL';
     }
   }
 
@@ -188,11 +208,15 @@ enum CalibrationSound {
         return CalibrationSound.hiHat;
       case CalibrationSound.hiHat:
         return null;
+      case CalibrationSound.inputLevel:
+        return CalibrationSound.noiseFloor;
     }
   }
 
-  /// Check if this is a sound collection phase (not noise floor)
-  bool get isSoundPhase => this != CalibrationSound.noiseFloor;
+  /// Check if this is a sound collection phase (not input level or noise floor)
+  bool get isSoundPhase =>
+      this != CalibrationSound.noiseFloor &&
+      this != CalibrationSound.inputLevel;
 }
 
 /// UI-specific extensions for CalibrationSound
@@ -211,6 +235,8 @@ extension CalibrationSoundUI on CalibrationSound {
         return const Color(0xFF4ECDC4); // Teal
       case CalibrationSound.hiHat:
         return const Color(0xFFFFE66D); // Yellow
+      case CalibrationSound.inputLevel:
+        return const Color(0xFFFFA94D); // Orange for the loud step
     }
   }
 
@@ -225,6 +251,8 @@ extension CalibrationSoundUI on CalibrationSound {
         return Icons.graphic_eq; // Mid
       case CalibrationSound.hiHat:
         return Icons.air; // High/crisp
+      case CalibrationSound.inputLevel:
+        return Icons.volume_up; // Loudness
     }
   }
 
@@ -239,6 +267,8 @@ extension CalibrationSoundUI on CalibrationSound {
         return 'A sharp, punchy sound with your tongue';
       case CalibrationSound.hiHat:
         return 'A crisp, high-frequency sound through your teeth';
+      case CalibrationSound.inputLevel:
+        return 'Make a few of your loudest beatbox sounds';
     }
   }
 
@@ -253,6 +283,8 @@ extension CalibrationSoundUI on CalibrationSound {
         return '"Psh" or "Ka"';
       case CalibrationSound.hiHat:
         return '"Ts" or "Tss"';
+      case CalibrationSound.inputLevel:
+        return '🔊 Go Loud';
     }
   }
 
@@ -267,6 +299,8 @@ extension CalibrationSoundUI on CalibrationSound {
         return 'Try to make each sound at a consistent volume. The level meter helps you stay in the optimal range.';
       case CalibrationSound.hiHat:
         return 'Almost done! Make sure each hi-hat sound is crisp and distinct from the other sounds.';
+      case CalibrationSound.inputLevel:
+        return 'Hit as hard as you will while practicing. These hits set the input level for your microphone.';
     }
   }
 }
@@ -6,10 +6,10 @@ import '../../models/timing_feedback.dart';
 import '../../bridge/api.dart/api.dart' as api;
 import '../../bridge/api.dart/api/streams.dart' as api_streams;
 import '../../bridge/api.dart/analysis.dart' as ffi_analysis;
-import '../../bridge/api.dart/analysis/classifier.dart' as ffi_classifier;
 import '../../bridge/api.dart/analysis/quantizer.dart' as ffi_quantizer;
 import '../../bridge/api.dart/calibration/progress.dart' as ffi_calibration;
 import '../../bridge/api.dart/engine/core.dart' as ffi_engine;
+import '../../bridge/api.dart/types.dart' as ffi_types;
 import '../../bridge/extensions/error_code_extensions.dart';
 import '../error_handler/error_handler.dart';
 import '../error_handler/exceptions.dart';
@@ -165,29 +165,38 @@ class AudioServiceImpl implements IAudioService {
   }
 
   /// Map FFI BeatboxHit to model BeatboxHit
-  BeatboxHit _mapFfiToModelBeatboxHit(ffi_classifier.BeatboxHit ffiHit) {
+  BeatboxHit _mapFfiToModelBeatboxHit(ffi_types.BeatboxHit ffiHit) {
     switch (ffiHit) {
-      case ffi_classifier.BeatboxHit.kick:
+      case ffi_types.BeatboxHit.kick:
         return BeatboxHit.kick;
-      case ffi_classifier.BeatboxHit.snare:
+      case ffi_types.BeatboxHit.snare:
         return BeatboxHit.snare;
-      case ffi_classifier.BeatboxHit.hiHat:
+      case ffi_types.BeatboxHit.hiHat:
         return BeatboxHit.hiHat;
-      case ffi_classifier.BeatboxHit.closedHiHat:
+      case ffi_types.BeatboxHit.closedHiHat:
         return BeatboxHit.closedHiHat;
-      case ffi_classifier.BeatboxHit.openHiHat:
+      case ffi_types.BeatboxHit.openHiHat:
         return BeatboxHit.openHiHat;
-      case ffi_classifier.BeatboxHit.kSnare:
+      case ffi_types.BeatboxHit.kSnare:
         return BeatboxHit.kSnare;
-      case ffi_classifier.BeatboxHit.unknown:
+      case ffi_types.BeatboxHit.unknown:
         return BeatboxHit.unknown;
     }
   }
 
   /// Map FFI TimingFeedback to model TimingFeedback
+  ///
+  /// Classification-only mode (BPM 0) has no metronome grid and sends no
+  /// timing; that maps to the same on-time default `fromJson` uses.
   TimingFeedback _mapFfiToModelTimingFeedback(
-    ffi_quantizer.TimingFeedback ffiTiming,
+    ffi_quantizer.TimingFeedback? ffiTiming,
   ) {
+    if (ffiTiming == null) {
+      return const TimingFeedback(
+        classification: TimingClassification.onTime,
+        errorMs: 0,
+      );
+    }
     return TimingFeedback(
       classification: _mapFfiToModelTimingClassification(
         ffiTiming.classification,
@@ -329,6 +338,8 @@ class AudioServiceImpl implements IAudioService {
         return CalibrationSound.snare;
       case ffi_calibration.CalibrationSound.hiHat:
         return CalibrationSound.hiHat;
+      case ffi_calibration.CalibrationSound.inputLevel:
+        return CalibrationSound.inputLevel;
     }
   }
 
@@ -354,6 +365,10 @@ class AudioServiceImpl implements IAudioService {
         return CalibrationGuidanceReason.tooQuiet;
       case ffi_calibration.CalibrationGuidanceReason.clipped:
         return CalibrationGuidanceReason.clipped;
+      case ffi_calibration.CalibrationGuidanceReason.tooNoisy:
+        return CalibrationGuidanceReason.tooNoisy;
+      case ffi_calibration.CalibrationGuidanceReason.unexpectedTimbre:
+        return CalibrationGuidanceReason.unexpectedTimbre;
     }
   }
 
@@ -7,6 +7,8 @@ enum DiagnosticMetricType {
   classification,
   jniLifecycle,
   error,
+  processingLatency,
+  silence,
 }
 
 /// Domain model representing telemetry collector output.
@@ -69,6 +71,20 @@ class DiagnosticMetric {
           timestamp: DateTime.now(),
         );
       },
+      processingLatency: (value) {
+        return DiagnosticMetric(
+          type: DiagnosticMetricType.processingLatency,
+          payload: {'latencyMs': value.latencyMs},
+          timestamp: DateTime.now(),
+        );
+      },
+      silence: (value) {
+        return DiagnosticMetric(
+          type: DiagnosticMetricType.silence,
+          payload: {'quietMs': value.quietMs},
+          timestamp: DateTime.now(),
+        );
+      },
     );
   }
 }
@@ -38,6 +38,7 @@ class ErrorHandler {
   /// - AudioErrorCodesExtension.lockPoisoned: Internal synchronization error
   /// - AudioErrorCodesExtension.jniInitFailed: JNI initialization failed (Android)
   /// - AudioErrorCodesExtension.contextNotInitialized: Android context not initialized
+  /// - AudioErrorCodesExtension.unsupported: Operation not available in this build
   ///
   /// For unknown errors, returns a generic fallback message.
   String translateAudioError(String rustError) {
@@ -75,6 +76,9 @@ class ErrorHandler {
       case AudioErrorCodesExtension.contextNotInitialized:
         return 'Audio engine not properly initialized. Please restart the app.';
 
+      case AudioErrorCodesExtension.unsupported:
+        return 'This feature is not available in this build.';
+
       default:
         // Fallback pattern matching on error text
         final lowerError = rustError.toLowerCase();
@@ -120,6 +124,11 @@ class ErrorHandler {
   /// - CalibrationErrorCodesExtension.notComplete: Calibration incomplete
   /// - CalibrationErrorCodesExtension.alreadyInProgress: Calibration already running
   /// - CalibrationErrorCodesExtension.statePoisoned: Internal synchronization error
+  /// - CalibrationErrorCodesExtension.timeout: Engine coordination timed out
+  /// - CalibrationErrorCodesExtension.io: Calibration file read/write failed
+  /// - CalibrationErrorCodesExtension.parse: Calibration file is malformed
+  /// - CalibrationErrorCodesExtension.unknownPreset: No such starter preset
+  /// - CalibrationErrorCodesExtension.notASoundPhase: Not collecting a sound
   ///
   /// For unknown errors, returns a generic fallback message.
   String translateCalibrationError(String rustError) {
@@ -145,6 +154,18 @@ class ErrorHandler {
       case CalibrationErrorCodesExtension.timeout:
         return 'Calibration timed out. Please restart the calibration workflow.';
 
+      case CalibrationErrorCodesExtension.io:
+        return 'Could not read or save the calibration file. Please check storage access.';
+
+      case CalibrationErrorCodesExtension.parse:
+        return 'The calibration file is damaged. Please recalibrate.';
+
+      case CalibrationErrorCodesExtension.unknownPreset:
+        return 'That starter calibration is not available. Please calibrate instead.';
+
+      case CalibrationErrorCodesExtension.notASoundPhase:
+        return 'This step measures your input level. Please wait for it to finish.';
+
       default:
         // Fallback pattern matching on error text
         final lowerError = rustError.toLowerCase();
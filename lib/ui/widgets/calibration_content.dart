@@ -124,8 +124,8 @@ class CalibrationContent extends StatelessWidget {
   }
 
   Widget _buildOverallProgress() {
-    final currentStep = progress.currentSound.index + 1;
-    const totalSteps = 4;
+    final currentStep = progress.currentSound.step + 1;
+    const totalSteps = CalibrationSound.stepCount;
     final overallProgress = progress.overallProgressFraction;
 
     return Column(
@@ -202,7 +202,11 @@ class CalibrationContent extends StatelessWidget {
 
   Widget _buildInstructions() {
     final sound = progress.currentSound;
-    final isNoiseFloor = sound == CalibrationSound.noiseFloor;
+    final title = switch (sound) {
+      CalibrationSound.noiseFloor => 'Measuring Ambient Noise',
+      CalibrationSound.inputLevel => 'Measuring Input Level',
+      _ => 'Make the ${sound.displayName} sound',
+    };
 
     return Container(
       padding: const EdgeInsets.all(20),
@@ -214,9 +218,7 @@ class CalibrationContent extends StatelessWidget {
       child: Column(
         children: [
           Text(
-            isNoiseFloor
-                ? 'Measuring Ambient Noise'
-                : 'Make the ${sound.displayName} sound',
+            title,
             style: const TextStyle(
               color: Colors.white,
               fontSize: 20,
@@ -242,7 +244,8 @@ class CalibrationContent extends StatelessWidget {
                 color: sound.color,
                 fontSize: 18,
                 fontWeight: FontWeight.bold,
-                fontStyle: isNoiseFloor ? FontStyle.normal : FontStyle.italic,
+                fontStyle:
+                    sound.isSoundPhase ? FontStyle.italic : FontStyle.normal,
               ),
             ),
           ),
@@ -0,0 +1,160 @@
+//! Auto-sensitivity: derive detection thresholds from the first seconds of input.
+//!
+//! Before calibration the classification gate and onset threshold assume a
+//! reasonably quiet room with a typical microphone. In a loud room that
+//! triggers on background noise; with a quiet microphone nothing is detected
+//! at all. During a short window after start the analysis thread records the
+//! level of every buffer, then places the thresholds relative to the observed
+//! range and keeps them for the rest of the session.
+
+/// Classification gate (RMS) the default thresholds are tuned for: twice the
+/// default noise floor
+pub const REFERENCE_GATE_RMS: f64 = 0.02;
+
+/// Lowest gate auto-sensitivity will lock in
+pub const MIN_AUTO_GATE_RMS: f64 = 0.002;
+
+/// Highest gate auto-sensitivity will lock in
+pub const MAX_AUTO_GATE_RMS: f64 = 0.1;
+
+/// Fraction of buffers treated as background when estimating the floor
+const FLOOR_PERCENTILE: f64 = 0.2;
+
+/// Thresholds locked in at the end of the auto-sensitivity window
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SensitivityThresholds {
+    /// RMS a hit must reach to be classified
+    pub gate_rms: f64,
+    /// Onset detector threshold offset
+    pub onset_threshold_offset: f32,
+}
+
+/// Observes buffer levels for a fixed window, then locks thresholds
+#[derive(Debug, Clone)]
+pub struct AutoSensitivity {
+    window_samples: u64,
+    observed_samples: u64,
+    levels: Vec<f64>,
+    base_onset_offset: f32,
+    locked: Option<SensitivityThresholds>,
+}
+
+impl AutoSensitivity {
+    /// Observe `window_ms` of audio; `base_onset_offset` is the configured
+    /// onset threshold offset, which is scaled with the gate
+    pub fn new(sample_rate: u32, window_ms: f32, base_onset_offset: f32) -> Self {
+        Self {
+            window_samples: (window_ms.max(0.0) as f64 * sample_rate as f64 / 1000.0) as u64,
+            observed_samples: 0,
+            levels: Vec::new(),
+            base_onset_offset,
+            locked: None,
+        }
+    }
+
+    /// Thresholds in effect, once the window has elapsed
+    pub fn locked(&self) -> Option<SensitivityThresholds> {
+        self.locked
+    }
+
+    /// Record the RMS of a buffer of `samples` samples
+    ///
+    /// Returns the thresholds on the call that completes the window, `None`
+    /// before and after.
+    pub fn observe(&mut self, rms: f64, samples: usize) -> Option<SensitivityThresholds> {
+        if self.locked.is_some() {
+            return None;
+        }
+        if rms.is_finite() {
+            self.levels.push(rms.max(0.0));
+        }
+        self.observed_samples += samples as u64;
+        if self.observed_samples < self.window_samples {
+            return None;
+        }
+
+        let thresholds = thresholds_for_levels(&self.levels, self.base_onset_offset);
+        self.levels = Vec::new();
+        self.locked = Some(thresholds);
+        Some(thresholds)
+    }
+}
+
+/// Place the thresholds relative to the observed range of buffer levels
+///
+/// The gate sits at twice the background level (20th percentile), or 10% of
+/// the way from background to the loudest buffer if hits were made during
+/// the window, whichever is higher. The onset threshold offset scales by the
+/// same factor the gate moved from [`REFERENCE_GATE_RMS`] (limited to 4x
+/// either way). With nothing observed the defaults are kept.
+pub fn thresholds_for_levels(levels: &[f64], base_onset_offset: f32) -> SensitivityThresholds {
+    let mut sorted: Vec<f64> = levels.iter().copied().filter(|l| l.is_finite()).collect();
+    if sorted.is_empty() {
+        return SensitivityThresholds {
+            gate_rms: REFERENCE_GATE_RMS,
+            onset_threshold_offset: base_onset_offset,
+        };
+    }
+    sorted.sort_by(f64::total_cmp);
+
+    let floor = sorted[((sorted.len() - 1) as f64 * FLOOR_PERCENTILE) as usize];
+    let peak = sorted[sorted.len() - 1];
+    let gate_rms = (floor * 2.0)
+        .max(floor + (peak - floor) * 0.1)
+        .clamp(MIN_AUTO_GATE_RMS, MAX_AUTO_GATE_RMS);
+    let scale = (gate_rms / REFERENCE_GATE_RMS).clamp(0.25, 4.0) as f32;
+
+    SensitivityThresholds {
+        gate_rms,
+        onset_threshold_offset: base_onset_offset * scale,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SR: u32 = 48_000;
+
+    /// One second of 1024-sample buffers: background at `floor` with a
+    /// hit at `peak` every eighth buffer
+    fn lock_for(floor: f64, peak: f64) -> SensitivityThresholds {
+        let mut auto = AutoSensitivity::new(SR, 1000.0, 0.15);
+        for i in 0.. {
+            let rms = if i % 8 == 7 { peak } else { floor };
+            if let Some(thresholds) = auto.observe(rms, 1024) {
+                assert_eq!(auto.locked(), Some(thresholds));
+                assert!(auto.observe(rms, 1024).is_none());
+                return thresholds;
+            }
+        }
+        unreachable!()
+    }
+
+    #[test]
+    fn quiet_input_locks_lower_thresholds_than_loud_input() {
+        let quiet = lock_for(0.001, 0.02);
+        let loud = lock_for(0.02, 0.3);
+
+        assert!(quiet.gate_rms < loud.gate_rms, "{quiet:?} vs {loud:?}");
+        assert!(quiet.onset_threshold_offset < loud.onset_threshold_offset);
+        // A quiet room sits below the defaults, a loud one above
+        assert!(quiet.gate_rms < REFERENCE_GATE_RMS);
+        assert!(loud.gate_rms > REFERENCE_GATE_RMS);
+    }
+
+    #[test]
+    fn thresholds_stay_within_limits() {
+        assert_eq!(
+            thresholds_for_levels(&[0.0; 10], 0.15).gate_rms,
+            MIN_AUTO_GATE_RMS
+        );
+        assert_eq!(
+            thresholds_for_levels(&[0.9; 10], 0.15).gate_rms,
+            MAX_AUTO_GATE_RMS
+        );
+        let empty = thresholds_for_levels(&[], 0.15);
+        assert_eq!(empty.gate_rms, REFERENCE_GATE_RMS);
+        assert_eq!(empty.onset_threshold_offset, 0.15);
+    }
+}
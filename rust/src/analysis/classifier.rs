@@ -14,30 +14,22 @@
 // - Requirement 10: Progressive Difficulty - Level 2
 
 use crate::analysis::features::Features;
+use crate::calibration::progress::CalibrationSound;
 use crate::calibration::state::CalibrationState;
+use crate::config::{ClassifierConfig, DistanceMetric, FeatureMask};
+use crate::types::BeatboxHit;
+use std::collections::HashMap;
+use std::sync::atomic::AtomicBool;
 use std::sync::{Arc, RwLock};
 
-/// BeatboxHit represents classified beatbox sounds
-///
-/// Level 1 sounds: Kick, Snare, HiHat
-/// Level 2 adds subcategories: ClosedHiHat, OpenHiHat, KSnare
-#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
-pub enum BeatboxHit {
-    /// Kick drum (low frequency, low ZCR)
-    Kick,
-    /// Snare drum (mid frequency)
-    Snare,
-    /// Hi-hat (high frequency, high ZCR) - Level 1 generic
-    HiHat,
-    /// Closed hi-hat (short decay) - Level 2
-    ClosedHiHat,
-    /// Open hi-hat (long decay) - Level 2
-    OpenHiHat,
-    /// K-snare (kick+snare hybrid, noisy kick) - Level 2
-    KSnare,
-    /// Unknown sound (doesn't match any pattern)
-    Unknown,
-}
+#[path = "classifier_distance.rs"]
+mod classifier_distance;
+#[path = "classifier_level2.rs"]
+mod classifier_level2;
+
+pub use classifier_distance::{
+    feature_distance, masked_feature_distance, normalized_feature_distance,
+};
 
 /// Classifier applies heuristic rules to classify beatbox sounds
 ///
@@ -46,6 +38,14 @@ pub enum BeatboxHit {
 pub struct Classifier {
     /// Calibration state with thresholds (thread-safe, read-only during classification)
     calibration: Arc<RwLock<CalibrationState>>,
+    /// Metric used by prototype matching (`classify_nearest`)
+    distance_metric: DistanceMetric,
+    /// Per-sound confidence below which a result becomes `Unknown`
+    min_confidence: HashMap<BeatboxHit, f32>,
+    /// Features allowed to take part in rules, scores and distances
+    feature_mask: FeatureMask,
+    /// Whether the Level 2 → Level 1 fallback has been logged
+    level2_fallback_logged: AtomicBool,
 }
 
 impl Classifier {
@@ -54,7 +54,102 @@ impl Classifier {
     /// # Arguments
     /// * `calibration` - `Arc<RwLock<CalibrationState>>` for thread-safe threshold access
     pub fn new(calibration: Arc<RwLock<CalibrationState>>) -> Self {
-        Self { calibration }
+        Self {
+            calibration,
+            distance_metric: DistanceMetric::default(),
+            min_confidence: HashMap::new(),
+            feature_mask: FeatureMask::default(),
+            level2_fallback_logged: AtomicBool::new(false),
+        }
+    }
+
+    /// Apply the distance metric, confidence thresholds and feature mask
+    /// from `config`
+    pub fn with_config(self, config: &ClassifierConfig) -> Self {
+        self.with_distance_metric(config.distance_metric)
+            .with_min_confidence(config.min_confidence.clone())
+            .with_feature_mask(config.feature_mask)
+    }
+
+    /// Use `metric` when matching hits against calibrated sound prototypes
+    pub fn with_distance_metric(mut self, metric: DistanceMetric) -> Self {
+        self.distance_metric = metric;
+        self
+    }
+
+    /// Report hits as `Unknown` when their confidence is below the
+    /// threshold for the classified sound
+    ///
+    /// Harder sounds (hi-hats) can be given stricter thresholds than easy
+    /// ones (kicks). Sounds without an entry are never rejected.
+    pub fn with_min_confidence(mut self, min_confidence: HashMap<BeatboxHit, f32>) -> Self {
+        self.min_confidence = min_confidence;
+        self
+    }
+
+    /// Only use the features enabled in `mask`
+    ///
+    /// A rule whose conditions all involve disabled features never fires
+    /// (with centroid disabled nothing separates snares, so none are
+    /// reported), and disabled features contribute nothing to scores or
+    /// prototype distances.
+    pub fn with_feature_mask(mut self, mask: FeatureMask) -> Self {
+        self.feature_mask = mask;
+        self
+    }
+
+    /// Downgrade `sound` to `Unknown` if `confidence` misses its threshold
+    fn apply_min_confidence(&self, sound: BeatboxHit, confidence: f32) -> (BeatboxHit, f32) {
+        match self.min_confidence.get(&sound) {
+            Some(&min) if confidence < min => (BeatboxHit::Unknown, confidence),
+            _ => (sound, confidence),
+        }
+    }
+
+    /// Match a hit to the nearest calibrated sound prototype
+    ///
+    /// Compares centroid and ZCR against the per-sound statistics captured
+    /// during calibration using the configured [`DistanceMetric`], z-scoring
+    /// each feature with the calibration's pooled statistics when present
+    /// (see [`normalized_feature_distance`]). Returns
+    /// the closest Level 1 sound and its distance, or `None` when the
+    /// calibration carries no statistics (defaults, older saves) or the
+    /// lock is poisoned.
+    pub fn classify_nearest(&self, features: &Features) -> Option<(BeatboxHit, f32)> {
+        let cal = self.calibration.read().ok()?;
+        self.nearest_prototype(features, &cal)
+    }
+
+    /// [`Self::classify_nearest`] against an already locked calibration
+    fn nearest_prototype(
+        &self,
+        features: &Features,
+        cal: &CalibrationState,
+    ) -> Option<(BeatboxHit, f32)> {
+        if !self.feature_mask.centroid && !self.feature_mask.zcr {
+            return None;
+        }
+        let stats = cal.feature_stats?;
+        let normalization = cal.feature_normalization;
+        [
+            (BeatboxHit::Kick, stats.kick),
+            (BeatboxHit::Snare, stats.snare),
+            (BeatboxHit::HiHat, stats.hihat),
+        ]
+        .into_iter()
+        .map(|(sound, prototype)| {
+            (
+                sound,
+                normalized_feature_distance(
+                    self.distance_metric,
+                    self.feature_mask,
+                    features,
+                    &prototype,
+                    normalization.as_ref(),
+                ),
+            )
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
     }
 
     /// Classify a sound using Level 1 rules (basic classification)
@@ -63,7 +158,15 @@ impl Classifier {
     /// 1. IF centroid < T_KICK_CENTROID AND zcr < T_KICK_ZCR THEN Kick
     /// 2. ELSE IF centroid < T_SNARE_CENTROID THEN Snare
     /// 3. ELSE IF centroid >= T_SNARE_CENTROID AND zcr > T_HIHAT_ZCR THEN HiHat
-    /// 4. ELSE Unknown
+    /// 4. ELSE nearest calibrated prototype ([`Self::classify_nearest`]),
+    ///    or Unknown when the calibration carries no prototypes
+    ///
+    /// Only step 4 uses the calibration's `feature_normalization`. Each rule
+    /// compares one feature with its own threshold and the scores behind
+    /// the confidence are measured relative to those thresholds, so
+    /// z-scoring would change neither.
+    ///
+    /// Sounds skipped during calibration are reported as Unknown.
     ///
     /// # Arguments
     /// * `features` - Extracted DSP features (centroid, ZCR, etc.)
@@ -99,28 +202,56 @@ impl Classifier {
             0.0
         };
 
-        // Apply decision rules (same as before)
-        let classification =
-            if features.centroid < cal.t_kick_centroid && features.zcr < cal.t_kick_zcr {
-                BeatboxHit::Kick
-            } else if features.centroid < cal.t_snare_centroid {
-                BeatboxHit::Snare
-            } else if features.centroid >= cal.t_snare_centroid && features.zcr > cal.t_hihat_zcr {
-                BeatboxHit::HiHat
-            } else {
-                BeatboxHit::Unknown
-            };
+        let classification = match self.apply_level1_decision_rules(features, &cal) {
+            BeatboxHit::Unknown => self
+                .nearest_prototype(features, &cal)
+                .map_or(BeatboxHit::Unknown, |(sound, _)| sound),
+            sound => sound,
+        };
+        let classification = without_skipped(classification, &cal);
 
-        (classification, confidence)
+        self.apply_min_confidence(classification, confidence)
+    }
+
+    /// Level 1 decision tree, skipping conditions on masked-out features
+    fn apply_level1_decision_rules(
+        &self,
+        features: &Features,
+        cal: &CalibrationState,
+    ) -> BeatboxHit {
+        let mask = self.feature_mask;
+        if rule_holds(&[
+            (mask.centroid, features.centroid < cal.t_kick_centroid),
+            (mask.zcr, features.zcr < cal.t_kick_zcr),
+        ]) {
+            BeatboxHit::Kick
+        } else if rule_holds(&[(mask.centroid, features.centroid < cal.t_snare_centroid)]) {
+            BeatboxHit::Snare
+        } else if rule_holds(&[
+            (mask.centroid, features.centroid >= cal.t_snare_centroid),
+            (mask.zcr, features.zcr > cal.t_hihat_zcr),
+        ]) {
+            BeatboxHit::HiHat
+        } else {
+            BeatboxHit::Unknown
+        }
     }
 
     /// Calculate kick score for Level 1 classification
     /// Score is higher when features match kick characteristics
     fn calculate_kick_score_level1(&self, features: &Features, cal: &CalibrationState) -> f32 {
         // Ideal kick: low centroid, low ZCR
-        // Distance from thresholds (normalized)
-        let centroid_dist = (features.centroid / cal.t_kick_centroid).min(2.0);
-        let zcr_dist = (features.zcr / cal.t_kick_zcr).min(2.0);
+        // Distance from thresholds (normalized); a masked feature is neutral
+        let centroid_dist = if self.feature_mask.centroid {
+            (features.centroid / cal.t_kick_centroid).min(2.0)
+        } else {
+            1.0
+        };
+        let zcr_dist = if self.feature_mask.zcr {
+            (features.zcr / cal.t_kick_zcr).min(2.0)
+        } else {
+            1.0
+        };
 
         // Score decreases with distance from ideal
         let score = (2.0 - centroid_dist) * (2.0 - zcr_dist);
@@ -130,6 +261,9 @@ impl Classifier {
     /// Calculate snare score for Level 1 classification
     fn calculate_snare_score_level1(&self, features: &Features, cal: &CalibrationState) -> f32 {
         // Ideal snare: mid centroid (between kick and hihat thresholds)
+        if !self.feature_mask.centroid {
+            return 0.0;
+        }
         let mid_point = (cal.t_kick_centroid + cal.t_snare_centroid) / 2.0;
         let centroid_dist = (features.centroid - mid_point).abs() / cal.t_snare_centroid;
 
@@ -141,160 +275,104 @@ impl Classifier {
     /// Calculate hi-hat score for Level 1 classification
     fn calculate_hihat_score_level1(&self, features: &Features, cal: &CalibrationState) -> f32 {
         // Ideal hi-hat: high centroid, high ZCR
-        let centroid_factor = (features.centroid / cal.t_snare_centroid).min(2.0);
-        let zcr_factor = (features.zcr / cal.t_hihat_zcr).min(2.0);
+        let factors = [
+            (
+                self.feature_mask.centroid,
+                (features.centroid / cal.t_snare_centroid).min(2.0),
+            ),
+            (
+                self.feature_mask.zcr,
+                (features.zcr / cal.t_hihat_zcr).min(2.0),
+            ),
+        ];
+        let enabled = factors.iter().filter(|(enabled, _)| *enabled).count();
+        if enabled == 0 {
+            return 0.0;
+        }
 
         // Score increases with higher values
-        let score = (centroid_factor + zcr_factor) / 2.0;
+        let score = factors
+            .iter()
+            .filter(|(enabled, _)| *enabled)
+            .map(|(_, factor)| factor)
+            .sum::<f32>()
+            / enabled as f32;
         score.max(0.0)
     }
 
-    /// Classify a sound using Level 2 rules (advanced with subcategories)
-    ///
-    /// Level 2 enhancements (from Requirement 10):
-    /// - Hi-hat subcategories: decay_time distinguishes closed (< 50ms) vs open (> 150ms)
-    /// - Kick subcategories: flatness distinguishes kick (< 0.1 tonal) vs K-snare (> 0.3 noisy)
-    ///
-    /// # Arguments
-    /// * `features` - Extracted DSP features (all 5: centroid, ZCR, flatness, rolloff, decay_time)
+    /// Rank every candidate sound by normalized score (highest first)
     ///
-    /// # Returns
-    /// Tuple of (BeatboxHit classification with subcategories, confidence score 0.0-1.0)
-    pub fn classify_level2(&self, features: &Features) -> (BeatboxHit, f32) {
-        // Read calibration thresholds
+    /// Uses the same per-class scores as the confidence calculation of the
+    /// active level, normalized so they sum to 1.0. Intended for detailed
+    /// debug views ("probably snare, possibly kick"); the primary result
+    /// still comes from `classify()`. Returns an empty list if the
+    /// calibration lock is poisoned.
+    pub fn classify_ranked(&self, features: &Features) -> Vec<(BeatboxHit, f32)> {
         let cal = match self.calibration.read() {
             Ok(guard) => guard,
             Err(_) => {
-                tracing::error!("Calibration state lock poisoned in classify_level2");
-                return (BeatboxHit::Unknown, 0.0);
+                tracing::error!("Calibration state lock poisoned in classify_ranked");
+                return Vec::new();
             }
         };
 
-        // Calculate scores and confidence
-        let confidence = self.calculate_level2_confidence(features, &cal);
-
-        // Apply decision rules
-        let classification = self.apply_level2_decision_rules(features, &cal);
-
-        (classification, confidence)
-    }
-
-    /// Calculate confidence score for Level 2 classification
-    fn calculate_level2_confidence(&self, features: &Features, cal: &CalibrationState) -> f32 {
-        let kick_score = self.calculate_kick_score_level2(features, cal);
-        let ksnare_score = self.calculate_ksnare_score_level2(features, cal);
-        let snare_score = self.calculate_snare_score_level1(features, cal);
-        let closed_hihat_score = self.calculate_closed_hihat_score_level2(features, cal);
-        let open_hihat_score = self.calculate_open_hihat_score_level2(features, cal);
-        let hihat_score = self.calculate_hihat_score_level1(features, cal);
-
-        let max_score = kick_score
-            .max(ksnare_score)
-            .max(snare_score)
-            .max(closed_hihat_score)
-            .max(open_hihat_score)
-            .max(hihat_score);
-        let sum_scores = kick_score
-            + ksnare_score
-            + snare_score
-            + closed_hihat_score
-            + open_hihat_score
-            + hihat_score;
-
-        if sum_scores > 0.0 {
-            (max_score / sum_scores).clamp(0.0, 1.0)
-        } else {
-            0.0
-        }
-    }
-
-    /// Apply decision tree rules for Level 2 classification
-    fn apply_level2_decision_rules(
-        &self,
-        features: &Features,
-        cal: &CalibrationState,
-    ) -> BeatboxHit {
-        if features.centroid < cal.t_kick_centroid && features.zcr < cal.t_kick_zcr {
-            // Level 2 enhancement: flatness check for kick subcategories
-            self.classify_kick_subcategory(features.flatness)
-        } else if features.centroid < cal.t_snare_centroid {
-            BeatboxHit::Snare
-        } else if features.centroid >= cal.t_snare_centroid && features.zcr > cal.t_hihat_zcr {
-            // Level 2 enhancement: decay time check for hi-hat subcategories
-            self.classify_hihat_subcategory(features.decay_time_ms)
-        } else {
-            BeatboxHit::Unknown
-        }
-    }
-
-    /// Classify kick subcategory based on flatness
-    fn classify_kick_subcategory(&self, flatness: f32) -> BeatboxHit {
-        if flatness < 0.1 {
-            BeatboxHit::Kick
-        } else if flatness > 0.3 {
-            BeatboxHit::KSnare
-        } else {
-            BeatboxHit::Kick
-        }
-    }
-
-    /// Classify hi-hat subcategory based on decay time
-    fn classify_hihat_subcategory(&self, decay_time_ms: f32) -> BeatboxHit {
-        if decay_time_ms < 50.0 {
-            BeatboxHit::ClosedHiHat
-        } else if decay_time_ms > 150.0 {
-            BeatboxHit::OpenHiHat
-        } else {
-            BeatboxHit::HiHat
-        }
-    }
-
-    /// Calculate kick score for Level 2 (tonal kick)
-    fn calculate_kick_score_level2(&self, features: &Features, cal: &CalibrationState) -> f32 {
-        let base_score = self.calculate_kick_score_level1(features, cal);
-        // Bonus for low flatness (tonal)
-        let flatness_bonus = if features.flatness < 0.1 { 1.5 } else { 0.5 };
-        (base_score * flatness_bonus).max(0.0)
-    }
-
-    /// Calculate K-snare score for Level 2 (noisy kick)
-    fn calculate_ksnare_score_level2(&self, features: &Features, cal: &CalibrationState) -> f32 {
-        let base_score = self.calculate_kick_score_level1(features, cal);
-        // Bonus for high flatness (noisy)
-        let flatness_bonus = if features.flatness > 0.3 { 1.5 } else { 0.5 };
-        (base_score * flatness_bonus).max(0.0)
-    }
-
-    /// Calculate closed hi-hat score for Level 2
-    fn calculate_closed_hihat_score_level2(
-        &self,
-        features: &Features,
-        cal: &CalibrationState,
-    ) -> f32 {
-        let base_score = self.calculate_hihat_score_level1(features, cal);
-        // Bonus for short decay time
-        let decay_bonus = if features.decay_time_ms < 50.0 {
-            1.5
-        } else {
-            0.5
+        let level2 = match cal.level {
+            2 => self.level2_thresholds(&cal),
+            _ => None,
         };
-        (base_score * decay_bonus).max(0.0)
-    }
-
-    /// Calculate open hi-hat score for Level 2
-    fn calculate_open_hihat_score_level2(
-        &self,
-        features: &Features,
-        cal: &CalibrationState,
-    ) -> f32 {
-        let base_score = self.calculate_hihat_score_level1(features, cal);
-        // Bonus for long decay time
-        let decay_bonus = if features.decay_time_ms > 150.0 {
-            1.5
-        } else {
-            0.5
+        let mut ranked = match level2 {
+            Some(level2) => vec![
+                (
+                    BeatboxHit::Kick,
+                    self.calculate_kick_score_level2(features, &cal, &level2),
+                ),
+                (
+                    BeatboxHit::KSnare,
+                    self.calculate_ksnare_score_level2(features, &cal, &level2),
+                ),
+                (
+                    BeatboxHit::Snare,
+                    self.calculate_snare_score_level1(features, &cal),
+                ),
+                (
+                    BeatboxHit::ClosedHiHat,
+                    self.calculate_closed_hihat_score_level2(features, &cal, &level2),
+                ),
+                (
+                    BeatboxHit::OpenHiHat,
+                    self.calculate_open_hihat_score_level2(features, &cal, &level2),
+                ),
+                (
+                    BeatboxHit::HiHat,
+                    self.calculate_hihat_score_level1(features, &cal),
+                ),
+            ],
+            None => vec![
+                (
+                    BeatboxHit::Kick,
+                    self.calculate_kick_score_level1(features, &cal),
+                ),
+                (
+                    BeatboxHit::Snare,
+                    self.calculate_snare_score_level1(features, &cal),
+                ),
+                (
+                    BeatboxHit::HiHat,
+                    self.calculate_hihat_score_level1(features, &cal),
+                ),
+            ],
         };
-        (base_score * decay_bonus).max(0.0)
+
+        let sum_scores: f32 = ranked.iter().map(|(_, score)| score).sum();
+        for (_, score) in ranked.iter_mut() {
+            *score = if sum_scores > 0.0 {
+                *score / sum_scores
+            } else {
+                0.0
+            };
+        }
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+        ranked
     }
 
     /// Classify a sound (convenience method that chooses level based on configuration)
@@ -328,6 +406,48 @@ impl Classifier {
     }
 }
 
+/// Calibration sound `sound` is a variant of (`None` for `Unknown`)
+fn calibration_sound(sound: BeatboxHit) -> Option<CalibrationSound> {
+    match sound {
+        BeatboxHit::Kick | BeatboxHit::KSnare => Some(CalibrationSound::Kick),
+        BeatboxHit::Snare => Some(CalibrationSound::Snare),
+        BeatboxHit::HiHat | BeatboxHit::ClosedHiHat | BeatboxHit::OpenHiHat => {
+            Some(CalibrationSound::HiHat)
+        }
+        BeatboxHit::Unknown => None,
+    }
+}
+
+/// `Unknown` in place of a sound the user skipped during calibration
+fn without_skipped(sound: BeatboxHit, cal: &CalibrationState) -> BeatboxHit {
+    match calibration_sound(sound) {
+        Some(calibrated) if cal.is_skipped(calibrated) => BeatboxHit::Unknown,
+        _ => sound,
+    }
+}
+
+/// Whether a decision rule fires
+///
+/// Each condition is `(enabled, holds)`. The rule fires when every enabled
+/// condition holds and at least one is enabled; a rule built only from
+/// masked-out features has no evidence either way.
+fn rule_holds(conditions: &[(bool, bool)]) -> bool {
+    let mut any_enabled = false;
+    for &(enabled, holds) in conditions {
+        if enabled {
+            if !holds {
+                return false;
+            }
+            any_enabled = true;
+        }
+    }
+    any_enabled
+}
+
 #[cfg(test)]
 #[path = "classifier_tests.rs"]
 mod tests;
+
+#[cfg(test)]
+#[path = "classifier_prototype_tests.rs"]
+mod prototype_tests;
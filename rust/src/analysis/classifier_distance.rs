@@ -0,0 +1,91 @@
+// Classifier - distances between features and calibrated sound prototypes
+
+use crate::analysis::features::Features;
+use crate::calibration::state::{FeatureNormalization, FeatureStats};
+use crate::config::{DistanceMetric, FeatureMask};
+
+/// Distance from `features` to a sound prototype under `metric`
+///
+/// Euclidean and Manhattan measure each feature's deviation relative to the
+/// prototype mean, so centroid (Hz) and ZCR (0..1) contribute on the same
+/// scale. Mahalanobis (diagonal covariance) divides each deviation by the
+/// feature's standard deviation instead.
+pub fn feature_distance(
+    metric: DistanceMetric,
+    features: &Features,
+    prototype: &FeatureStats,
+) -> f32 {
+    masked_feature_distance(metric, FeatureMask::default(), features, prototype)
+}
+
+/// [`feature_distance`] over the features enabled in `mask` only
+pub fn masked_feature_distance(
+    metric: DistanceMetric,
+    mask: FeatureMask,
+    features: &Features,
+    prototype: &FeatureStats,
+) -> f32 {
+    normalized_feature_distance(metric, mask, features, prototype, None)
+}
+
+/// [`masked_feature_distance`] with deviations z-scored by `normalization`
+///
+/// With pooled statistics, Euclidean and Manhattan divide each deviation by
+/// that feature's standard deviation across all calibration samples rather
+/// than by the prototype mean, so a one-sigma change in ZCR counts as much
+/// as a one-sigma change in centroid. Mahalanobis already scales by the
+/// prototype's own variance and ignores `normalization`.
+pub fn normalized_feature_distance(
+    metric: DistanceMetric,
+    mask: FeatureMask,
+    features: &Features,
+    prototype: &FeatureStats,
+    normalization: Option<&FeatureNormalization>,
+) -> f32 {
+    let deviations = [
+        (
+            mask.centroid,
+            features.centroid - prototype.centroid_mean,
+            prototype.centroid_mean,
+            prototype.centroid_variance,
+            normalization.map(|n| n.centroid_std),
+        ),
+        (
+            mask.zcr,
+            features.zcr - prototype.zcr_mean,
+            prototype.zcr_mean,
+            prototype.zcr_variance,
+            normalization.map(|n| n.zcr_std),
+        ),
+    ];
+    let deviations = deviations.iter().filter(|(enabled, ..)| *enabled).map(
+        |&(_, diff, mean, variance, std)| {
+            // Relative to the prototype mean unless pooled statistics exist
+            let spread = std.map_or(scale(mean), |std| std.max(0.01 * scale(mean)));
+            (diff, mean, variance, spread)
+        },
+    );
+
+    match metric {
+        DistanceMetric::Euclidean => deviations
+            .map(|(diff, _, _, spread)| (diff / spread).powi(2))
+            .sum::<f32>()
+            .sqrt(),
+        DistanceMetric::Manhattan => deviations
+            .map(|(diff, _, _, spread)| (diff / spread).abs())
+            .sum(),
+        DistanceMetric::Mahalanobis => deviations
+            .map(|(diff, mean, variance, _)| {
+                // Floor the variance so perfectly consistent samples don't
+                // turn every tiny deviation into an infinite distance
+                let floor = (0.01 * scale(mean)).powi(2);
+                diff * diff / variance.max(floor)
+            })
+            .sum::<f32>()
+            .sqrt(),
+    }
+}
+
+fn scale(mean: f32) -> f32 {
+    mean.abs().max(f32::EPSILON)
+}
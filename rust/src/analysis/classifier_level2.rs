@@ -0,0 +1,224 @@
+// Classifier - Level 2 rules splitting kicks and hi-hats into subcategories
+
+use std::sync::atomic::Ordering;
+
+use crate::analysis::features::Features;
+use crate::calibration::state::{CalibrationState, Level2Thresholds};
+use crate::types::BeatboxHit;
+
+use super::{without_skipped, Classifier};
+
+impl Classifier {
+    /// Classify a sound using Level 2 rules (advanced with subcategories)
+    ///
+    /// Level 2 enhancements (from Requirement 10):
+    /// - Hi-hat subcategories: decay_time distinguishes closed (< 50ms) vs open (> 150ms)
+    /// - Kick subcategories: flatness distinguishes kick (< 0.1 tonal) vs K-snare (> 0.3 noisy)
+    ///
+    /// The subcategory cut-offs come from `CalibrationState::level2`. A state
+    /// calibrated for the basic sounds only has none, so classification
+    /// falls back to `classify_level1()` (logged once per classifier).
+    ///
+    /// # Arguments
+    /// * `features` - Extracted DSP features (all 5: centroid, ZCR, flatness, rolloff, decay_time)
+    ///
+    /// # Returns
+    /// Tuple of (BeatboxHit classification with subcategories, confidence score 0.0-1.0)
+    pub fn classify_level2(&self, features: &Features) -> (BeatboxHit, f32) {
+        // Read calibration thresholds
+        let cal = match self.calibration.read() {
+            Ok(guard) => guard,
+            Err(_) => {
+                tracing::error!("Calibration state lock poisoned in classify_level2");
+                return (BeatboxHit::Unknown, 0.0);
+            }
+        };
+        let Some(level2) = self.level2_thresholds(&cal) else {
+            drop(cal);
+            return self.classify_level1(features);
+        };
+
+        // Calculate scores and confidence
+        let confidence = self.calculate_level2_confidence(features, &cal, &level2);
+
+        // Apply decision rules
+        let classification = without_skipped(
+            self.apply_level2_decision_rules(features, &cal, &level2),
+            &cal,
+        );
+
+        self.apply_min_confidence(classification, confidence)
+    }
+
+    /// Level 2 thresholds of `cal`, logging once when they are missing
+    pub(super) fn level2_thresholds(&self, cal: &CalibrationState) -> Option<Level2Thresholds> {
+        if cal.level2.is_none() && !self.level2_fallback_logged.swap(true, Ordering::Relaxed) {
+            tracing::warn!(
+                "Calibration has no Level 2 thresholds; classifying with Level 1 rules instead"
+            );
+        }
+        cal.level2
+    }
+
+    /// Calculate confidence score for Level 2 classification
+    pub(super) fn calculate_level2_confidence(
+        &self,
+        features: &Features,
+        cal: &CalibrationState,
+        level2: &Level2Thresholds,
+    ) -> f32 {
+        let kick_score = self.calculate_kick_score_level2(features, cal, level2);
+        let ksnare_score = self.calculate_ksnare_score_level2(features, cal, level2);
+        let snare_score = self.calculate_snare_score_level1(features, cal);
+        let closed_hihat_score = self.calculate_closed_hihat_score_level2(features, cal, level2);
+        let open_hihat_score = self.calculate_open_hihat_score_level2(features, cal, level2);
+        let hihat_score = self.calculate_hihat_score_level1(features, cal);
+
+        let max_score = kick_score
+            .max(ksnare_score)
+            .max(snare_score)
+            .max(closed_hihat_score)
+            .max(open_hihat_score)
+            .max(hihat_score);
+        let sum_scores = kick_score
+            + ksnare_score
+            + snare_score
+            + closed_hihat_score
+            + open_hihat_score
+            + hihat_score;
+
+        if sum_scores > 0.0 {
+            (max_score / sum_scores).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// Apply decision tree rules for Level 2 classification
+    pub(super) fn apply_level2_decision_rules(
+        &self,
+        features: &Features,
+        cal: &CalibrationState,
+        level2: &Level2Thresholds,
+    ) -> BeatboxHit {
+        match self.apply_level1_decision_rules(features, cal) {
+            // Level 2 enhancement: flatness check for kick subcategories
+            BeatboxHit::Kick => self.classify_kick_subcategory(features.flatness, level2),
+            // Level 2 enhancement: decay time check for hi-hat subcategories
+            BeatboxHit::HiHat => self.classify_hihat_subcategory(features.decay_time_ms, level2),
+            other => other,
+        }
+    }
+
+    /// Classify kick subcategory based on flatness
+    pub(super) fn classify_kick_subcategory(
+        &self,
+        flatness: f32,
+        level2: &Level2Thresholds,
+    ) -> BeatboxHit {
+        if !self.feature_mask.flatness {
+            return BeatboxHit::Kick;
+        }
+        if flatness < level2.tonal_kick_max_flatness {
+            BeatboxHit::Kick
+        } else if flatness > level2.ksnare_min_flatness {
+            BeatboxHit::KSnare
+        } else {
+            BeatboxHit::Kick
+        }
+    }
+
+    /// Classify hi-hat subcategory based on decay time
+    pub(super) fn classify_hihat_subcategory(
+        &self,
+        decay_time_ms: f32,
+        level2: &Level2Thresholds,
+    ) -> BeatboxHit {
+        if !self.feature_mask.decay_time {
+            return BeatboxHit::HiHat;
+        }
+        if decay_time_ms < level2.closed_hihat_max_decay_ms {
+            BeatboxHit::ClosedHiHat
+        } else if decay_time_ms > level2.open_hihat_min_decay_ms {
+            BeatboxHit::OpenHiHat
+        } else {
+            BeatboxHit::HiHat
+        }
+    }
+
+    /// Calculate kick score for Level 2 (tonal kick)
+    pub(super) fn calculate_kick_score_level2(
+        &self,
+        features: &Features,
+        cal: &CalibrationState,
+        level2: &Level2Thresholds,
+    ) -> f32 {
+        let base_score = self.calculate_kick_score_level1(features, cal);
+        // Bonus for low flatness (tonal)
+        let flatness_bonus = if !self.feature_mask.flatness {
+            1.0
+        } else if features.flatness < level2.tonal_kick_max_flatness {
+            1.5
+        } else {
+            0.5
+        };
+        (base_score * flatness_bonus).max(0.0)
+    }
+
+    /// Calculate K-snare score for Level 2 (noisy kick)
+    pub(super) fn calculate_ksnare_score_level2(
+        &self,
+        features: &Features,
+        cal: &CalibrationState,
+        level2: &Level2Thresholds,
+    ) -> f32 {
+        let base_score = self.calculate_kick_score_level1(features, cal);
+        // Bonus for high flatness (noisy)
+        let flatness_bonus = if !self.feature_mask.flatness {
+            1.0
+        } else if features.flatness > level2.ksnare_min_flatness {
+            1.5
+        } else {
+            0.5
+        };
+        (base_score * flatness_bonus).max(0.0)
+    }
+
+    /// Calculate closed hi-hat score for Level 2
+    pub(super) fn calculate_closed_hihat_score_level2(
+        &self,
+        features: &Features,
+        cal: &CalibrationState,
+        level2: &Level2Thresholds,
+    ) -> f32 {
+        let base_score = self.calculate_hihat_score_level1(features, cal);
+        // Bonus for short decay time
+        let decay_bonus = if !self.feature_mask.decay_time {
+            1.0
+        } else if features.decay_time_ms < level2.closed_hihat_max_decay_ms {
+            1.5
+        } else {
+            0.5
+        };
+        (base_score * decay_bonus).max(0.0)
+    }
+
+    /// Calculate open hi-hat score for Level 2
+    pub(super) fn calculate_open_hihat_score_level2(
+        &self,
+        features: &Features,
+        cal: &CalibrationState,
+        level2: &Level2Thresholds,
+    ) -> f32 {
+        let base_score = self.calculate_hihat_score_level1(features, cal);
+        // Bonus for long decay time
+        let decay_bonus = if !self.feature_mask.decay_time {
+            1.0
+        } else if features.decay_time_ms > level2.open_hihat_min_decay_ms {
+            1.5
+        } else {
+            0.5
+        };
+        (base_score * decay_bonus).max(0.0)
+    }
+}
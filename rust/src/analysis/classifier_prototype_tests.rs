@@ -0,0 +1,193 @@
+use super::tests::{create_classifier, create_features};
+use super::*;
+use crate::calibration::state::FeatureStats;
+
+#[test]
+fn test_mahalanobis_down_weights_high_variance_feature() {
+    // Centroid varied a lot during calibration (std 300 Hz = 30% of the
+    // mean); ZCR was very consistent (std 0.005 = 5% of the mean)
+    let prototype = FeatureStats {
+        centroid_mean: 1000.0,
+        centroid_variance: 300.0 * 300.0,
+        zcr_mean: 0.1,
+        zcr_variance: 0.005 * 0.005,
+    };
+    // Each hit is off by 20% in exactly one feature
+    let centroid_off = create_features(1200.0, 0.1, 0.0, 0.0);
+    let zcr_off = create_features(1000.0, 0.12, 0.0, 0.0);
+
+    let euclidean = |f: &Features| feature_distance(DistanceMetric::Euclidean, f, &prototype);
+    let mahalanobis = |f: &Features| feature_distance(DistanceMetric::Mahalanobis, f, &prototype);
+
+    // Euclidean treats both deviations the same
+    assert!((euclidean(&centroid_off) - euclidean(&zcr_off)).abs() < 1e-4);
+    // Mahalanobis forgives the deviation in the noisy feature
+    assert!(
+        mahalanobis(&centroid_off) < mahalanobis(&zcr_off),
+        "centroid-off {} vs zcr-off {}",
+        mahalanobis(&centroid_off),
+        mahalanobis(&zcr_off)
+    );
+    assert!((mahalanobis(&centroid_off) - 200.0 / 300.0).abs() < 1e-4);
+    assert!(
+        (feature_distance(DistanceMetric::Manhattan, &centroid_off, &prototype) - 0.2).abs() < 1e-4
+    );
+}
+
+#[test]
+fn test_classify_nearest_uses_calibrated_prototypes() {
+    let samples = |centroid: f32, zcr: f32| -> Vec<Features> {
+        (0..10)
+            .map(|i| create_features(centroid + i as f32 * 10.0, zcr + i as f32 * 0.001, 0.0, 0.0))
+            .collect()
+    };
+    let cal = CalibrationState::from_samples(
+        &samples(800.0, 0.05),
+        &samples(3000.0, 0.15),
+        &samples(7000.0, 0.4),
+        10,
+        0.01,
+    )
+    .unwrap();
+    let classifier = Classifier::new(Arc::new(RwLock::new(cal)))
+        .with_distance_metric(DistanceMetric::Mahalanobis);
+
+    let (sound, _) = classifier
+        .classify_nearest(&create_features(2900.0, 0.16, 0.0, 0.0))
+        .expect("calibrated prototypes");
+    assert_eq!(sound, BeatboxHit::Snare);
+
+    // Default thresholds carry no prototypes
+    assert!(create_classifier()
+        .classify_nearest(&create_features(2900.0, 0.16, 0.0, 0.0))
+        .is_none());
+}
+
+#[test]
+fn test_level1_falls_back_to_nearest_prototype_outside_every_rule() {
+    let samples = |centroid: f32, zcr: f32| -> Vec<Features> {
+        (0..10)
+            .map(|i| create_features(centroid + i as f32 * 10.0, zcr + i as f32 * 0.001, 0.0, 0.0))
+            .collect()
+    };
+    let cal = CalibrationState::from_samples(
+        &samples(800.0, 0.05),
+        &samples(3000.0, 0.15),
+        &samples(7000.0, 0.4),
+        10,
+        0.01,
+    )
+    .unwrap();
+    // Just above the snare centroid threshold but too few zero crossings
+    // for a hi-hat: no rule fires
+    let between = create_features(cal.t_snare_centroid + 100.0, 0.16, 0.0, 0.0);
+    assert!(between.zcr <= cal.t_hihat_zcr);
+
+    let classifier = Classifier::new(Arc::new(RwLock::new(cal)))
+        .with_distance_metric(DistanceMetric::Mahalanobis);
+    let (sound, _) = classifier.classify_level1(&between);
+    assert_eq!(sound, BeatboxHit::Snare);
+
+    // Without prototypes the hit stays unclassified
+    let (sound, _) = create_classifier().classify_level1(&create_features(4100.0, 0.2, 0.0, 0.0));
+    assert_eq!(sound, BeatboxHit::Unknown);
+}
+
+#[test]
+fn test_z_score_normalization_balances_centroid_and_zcr() {
+    let samples = |centroid: f32, zcr: f32| -> Vec<Features> {
+        (0..10)
+            .map(|i| create_features(centroid + i as f32 * 10.0, zcr + i as f32 * 0.001, 0.0, 0.0))
+            .collect()
+    };
+    let cal = CalibrationState::from_samples(
+        &samples(800.0, 0.05),
+        &samples(3000.0, 0.15),
+        &samples(7000.0, 0.4),
+        10,
+        0.01,
+    )
+    .unwrap();
+    let norm = cal
+        .feature_normalization
+        .expect("pooled statistics recorded");
+    let prototype = cal.feature_stats.unwrap().snare;
+    assert!(norm.centroid_std > 1000.0 && norm.zcr_std < 1.0, "{norm:?}");
+
+    // Move a hit one pooled standard deviation away from the snare
+    // prototype in each feature in turn
+    let centroid_off = create_features(
+        prototype.centroid_mean + norm.centroid_std,
+        prototype.zcr_mean,
+        0.0,
+        0.0,
+    );
+    let zcr_off = create_features(
+        prototype.centroid_mean,
+        prototype.zcr_mean + norm.zcr_std,
+        0.0,
+        0.0,
+    );
+    let distance = |f: &Features| {
+        normalized_feature_distance(
+            DistanceMetric::Euclidean,
+            FeatureMask::default(),
+            f,
+            &prototype,
+            Some(&norm),
+        )
+    };
+
+    // Z-scored, both count as one standard deviation
+    assert!((distance(&centroid_off) - 1.0).abs() < 1e-4);
+    assert!((distance(&zcr_off) - 1.0).abs() < 1e-4);
+    let classifier = Classifier::new(Arc::new(RwLock::new(cal.clone())));
+    let (sound, _) = classifier.classify_nearest(&zcr_off).unwrap();
+    assert_eq!(sound, BeatboxHit::Snare);
+
+    // Default thresholds carry no normalization
+    assert!(CalibrationState::new_default()
+        .feature_normalization
+        .is_none());
+}
+
+#[test]
+fn test_only_the_prototype_fallback_is_z_scored() {
+    let samples = |centroid: f32, zcr: f32| -> Vec<Features> {
+        (0..10)
+            .map(|i| create_features(centroid + i as f32 * 10.0, zcr + i as f32 * 0.001, 0.0, 0.0))
+            .collect()
+    };
+    let cal = CalibrationState::from_samples(
+        &samples(800.0, 0.05),
+        &samples(3000.0, 0.15),
+        &samples(7000.0, 0.4),
+        10,
+        0.01,
+    )
+    .unwrap();
+    let mut unnormalized = cal.clone();
+    unnormalized.feature_normalization = None;
+    let normalized = Classifier::new(Arc::new(RwLock::new(cal)));
+    let unnormalized = Classifier::new(Arc::new(RwLock::new(unnormalized)));
+
+    // Rules compare each feature with its own threshold, so hits they
+    // decide classify (and score) the same either way
+    for (hit, expected) in [
+        (create_features(900.0, 0.055, 0.0, 0.0), BeatboxHit::Kick),
+        (create_features(3000.0, 0.15, 0.0, 0.0), BeatboxHit::Snare),
+        (create_features(7000.0, 0.5, 0.0, 0.0), BeatboxHit::HiHat),
+    ] {
+        assert_eq!(normalized.classify_level1(&hit).0, expected);
+        assert_eq!(
+            normalized.classify_level1(&hit),
+            unnormalized.classify_level1(&hit)
+        );
+    }
+
+    // No rule claims a bright hit with few zero crossings; z-scored, its
+    // snare-like ZCR outweighs a centroid closer to the hi-hat's
+    let fallback = create_features(5000.0, 0.05, 0.0, 0.0);
+    assert_eq!(normalized.classify_level1(&fallback).0, BeatboxHit::Snare);
+    assert_eq!(unnormalized.classify_level1(&fallback).0, BeatboxHit::HiHat);
+}
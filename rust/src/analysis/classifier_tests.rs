@@ -1,7 +1,13 @@
 use super::*;
+use crate::calibration::state::Level2Thresholds;
 
 /// Helper to create Features struct for testing
-fn create_features(centroid: f32, zcr: f32, flatness: f32, decay_time_ms: f32) -> Features {
+pub(super) fn create_features(
+    centroid: f32,
+    zcr: f32,
+    flatness: f32,
+    decay_time_ms: f32,
+) -> Features {
     Features {
         centroid,
         zcr,
@@ -12,7 +18,7 @@ fn create_features(centroid: f32, zcr: f32, flatness: f32, decay_time_ms: f32) -
 }
 
 /// Helper to create Classifier with default calibration
-fn create_classifier() -> Classifier {
+pub(super) fn create_classifier() -> Classifier {
     let cal = Arc::new(RwLock::new(CalibrationState::new_default()));
     Classifier::new(cal)
 }
@@ -317,3 +323,165 @@ fn test_all_enum_variants_reachable() {
     assert_eq!(open_hihat, BeatboxHit::OpenHiHat);
     assert_eq!(ksnare, BeatboxHit::KSnare);
 }
+
+#[test]
+fn test_classify_ranked_near_kick_snare_boundary() {
+    let classifier = create_classifier();
+
+    // Just above the kick centroid threshold with ZCR near the kick limit
+    let features = create_features(1700.0, 0.09, 0.0, 0.0);
+    let ranked = classifier.classify_ranked(&features);
+
+    assert_eq!(ranked.len(), 3);
+    let top_two: Vec<_> = ranked.iter().take(2).map(|(hit, _)| *hit).collect();
+    assert!(top_two.contains(&BeatboxHit::Kick));
+    assert!(top_two.contains(&BeatboxHit::Snare));
+    assert!(
+        (ranked[0].1 - ranked[1].1).abs() < 0.15,
+        "expected close scores, got {:?}",
+        ranked
+    );
+    assert!(ranked.windows(2).all(|w| w[0].1 >= w[1].1));
+
+    let total: f32 = ranked.iter().map(|(_, score)| score).sum();
+    assert!((total - 1.0).abs() < 1e-5);
+}
+
+#[test]
+fn test_level2_without_level2_thresholds_falls_back_to_level1() {
+    let samples = |centroid: f32, zcr: f32| -> Vec<Features> {
+        (0..10)
+            .map(|i| create_features(centroid + i as f32 * 10.0, zcr, 0.0, 0.0))
+            .collect()
+    };
+    // Calibrated for the basic sounds only, then switched to Level 2
+    let mut cal = CalibrationState::from_samples(
+        &samples(800.0, 0.05),
+        &samples(3000.0, 0.15),
+        &samples(7000.0, 0.4),
+        10,
+        0.01,
+    )
+    .unwrap();
+    assert!(cal.level2.is_none());
+    cal.level = 2;
+    let classifier = Classifier::new(Arc::new(RwLock::new(cal.clone())));
+
+    // A noisy kick and a long hi-hat would be K-snare / open hi-hat at Level 2
+    for features in [
+        create_features(700.0, 0.04, 0.5, 0.0),
+        create_features(9000.0, 0.6, 0.0, 300.0),
+    ] {
+        assert_eq!(
+            classifier.classify(&features),
+            classifier.classify_level1(&features)
+        );
+    }
+    assert_eq!(
+        classifier
+            .classify(&create_features(700.0, 0.04, 0.5, 0.0))
+            .0,
+        BeatboxHit::Kick
+    );
+    assert_eq!(
+        classifier
+            .classify_ranked(&create_features(700.0, 0.04, 0.5, 0.0))
+            .len(),
+        3
+    );
+
+    // With Level 2 thresholds attached the subcategories come back
+    let upgraded = Classifier::new(Arc::new(RwLock::new(
+        cal.with_level2_thresholds(Level2Thresholds::default()),
+    )));
+    assert_eq!(
+        upgraded.classify(&create_features(700.0, 0.04, 0.5, 0.0)).0,
+        BeatboxHit::KSnare
+    );
+}
+
+#[test]
+fn test_supported_sounds_by_level() {
+    use crate::analysis::labels::sound_descriptors;
+
+    let active = |level| -> Vec<BeatboxHit> {
+        sound_descriptors(level)
+            .into_iter()
+            .filter(|d| d.active)
+            .map(|d| d.sound)
+            .collect()
+    };
+
+    assert_eq!(
+        active(1),
+        vec![BeatboxHit::Kick, BeatboxHit::Snare, BeatboxHit::HiHat]
+    );
+    assert_eq!(active(2), BeatboxHit::SUPPORTED.to_vec());
+
+    // The list itself never changes and never includes Unknown
+    let level1 = sound_descriptors(1);
+    assert_eq!(level1.len(), 6);
+    assert!(level1.iter().all(|d| d.sound != BeatboxHit::Unknown));
+    let ksnare = level1
+        .iter()
+        .find(|d| d.sound == BeatboxHit::KSnare)
+        .unwrap();
+    assert_eq!((ksnare.min_level, ksnare.label.as_str()), (2, "K-Snare"));
+}
+
+#[test]
+fn test_per_sound_min_confidence() {
+    let classifier = create_classifier().with_min_confidence(HashMap::from([
+        (BeatboxHit::Kick, 0.4),
+        (BeatboxHit::HiHat, 0.8),
+    ]));
+
+    // The same confidence clears the kick threshold but not the hi-hat one
+    assert_eq!(
+        classifier.apply_min_confidence(BeatboxHit::Kick, 0.6),
+        (BeatboxHit::Kick, 0.6)
+    );
+    assert_eq!(
+        classifier.apply_min_confidence(BeatboxHit::HiHat, 0.6),
+        (BeatboxHit::Unknown, 0.6)
+    );
+    // Sounds without a threshold are never rejected
+    assert_eq!(
+        classifier.apply_min_confidence(BeatboxHit::Snare, 0.1),
+        (BeatboxHit::Snare, 0.1)
+    );
+
+    // Applied to real classifications
+    let kick = create_features(1000.0, 0.05, 0.0, 0.0);
+    let (_, kick_confidence) = create_classifier().classify_level1(&kick);
+    let strict = create_classifier().with_min_confidence(HashMap::from([
+        (BeatboxHit::Kick, kick_confidence),
+        (BeatboxHit::HiHat, 1.01),
+    ]));
+    assert_eq!(strict.classify_level1(&kick).0, BeatboxHit::Kick);
+    let hihat = create_features(8000.0, 0.5, 0.0, 0.0);
+    assert_eq!(strict.classify_level1(&hihat).0, BeatboxHit::Unknown);
+}
+
+#[test]
+fn test_feature_mask_without_centroid_ignores_centroid() {
+    let low = create_features(1000.0, 0.05, 0.0, 0.0);
+    let high = create_features(6000.0, 0.05, 0.0, 0.0);
+
+    // With every feature enabled the centroid separates the two
+    let classifier = create_classifier();
+    assert_ne!(
+        classifier.classify_level1(&low).0,
+        classifier.classify_level1(&high).0
+    );
+
+    let classifier = create_classifier().with_feature_mask(FeatureMask {
+        centroid: false,
+        ..FeatureMask::default()
+    });
+    assert_eq!(
+        classifier.classify_level1(&low),
+        classifier.classify_level1(&high)
+    );
+    assert_eq!(classifier.classify_level1(&low).0, BeatboxHit::Kick);
+}
@@ -0,0 +1,117 @@
+//! Attack/release envelope follower used to smooth the noise-gate level.
+//!
+//! Instantaneous windowed RMS chatters around the gate threshold at the edges
+//! of a sound, which truncates hits or triggers them twice. The follower rises
+//! quickly (attack) and falls slowly (release), so the gate opens on the
+//! transient and stays open through the tail.
+
+/// One-pole envelope follower with separate attack and release time constants.
+#[derive(Debug, Clone)]
+pub struct EnvelopeFollower {
+    sample_rate: f64,
+    attack_ms: f64,
+    release_ms: f64,
+    envelope: f64,
+}
+
+impl EnvelopeFollower {
+    /// Create a follower with the given time constants in milliseconds.
+    ///
+    /// A time constant of 0 makes that direction instantaneous.
+    pub fn new(sample_rate: u32, attack_ms: f32, release_ms: f32) -> Self {
+        Self {
+            sample_rate: sample_rate.max(1) as f64,
+            attack_ms: attack_ms.max(0.0) as f64,
+            release_ms: release_ms.max(0.0) as f64,
+            envelope: 0.0,
+        }
+    }
+
+    /// Current envelope level.
+    pub fn level(&self) -> f64 {
+        self.envelope
+    }
+
+    /// Reset the envelope to silence.
+    pub fn reset(&mut self) {
+        self.envelope = 0.0;
+    }
+
+    /// Advance the follower by `samples` samples held at `level`.
+    ///
+    /// The analysis thread only sees one RMS value per buffer, so the
+    /// per-sample smoothing coefficient is raised to the buffer length to
+    /// keep the time constants independent of buffer size.
+    pub fn process_block(&mut self, level: f64, samples: usize) -> f64 {
+        let time_constant_ms = if level > self.envelope {
+            self.attack_ms
+        } else {
+            self.release_ms
+        };
+
+        let coeff = self.block_coefficient(time_constant_ms, samples);
+        self.envelope = level + coeff * (self.envelope - level);
+        self.envelope
+    }
+
+    fn block_coefficient(&self, time_constant_ms: f64, samples: usize) -> f64 {
+        if samples == 0 {
+            return 1.0;
+        }
+        if time_constant_ms <= 0.0 {
+            return 0.0;
+        }
+        let tau_samples = time_constant_ms * self.sample_rate / 1000.0;
+        (-(samples as f64) / tau_samples).exp()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SR: u32 = 48_000;
+    /// 1ms blocks at 48kHz
+    const BLOCK: usize = 48;
+
+    fn run(follower: &mut EnvelopeFollower, level: f64, blocks: usize) -> f64 {
+        (0..blocks)
+            .map(|_| follower.process_block(level, BLOCK))
+            .last()
+            .unwrap_or(follower.level())
+    }
+
+    #[test]
+    fn step_rises_quickly_with_short_attack() {
+        let mut follower = EnvelopeFollower::new(SR, 2.0, 100.0);
+
+        // One attack time constant reaches ~63% of the step
+        let after_attack = run(&mut follower, 1.0, 2);
+        assert!((after_attack - (1.0 - (-1.0f64).exp())).abs() < 0.01);
+
+        // Five time constants is effectively fully open
+        let settled = run(&mut follower, 1.0, 8);
+        assert!(settled > 0.99, "settled at {settled}");
+    }
+
+    #[test]
+    fn gap_decays_slowly_with_long_release() {
+        let mut follower = EnvelopeFollower::new(SR, 2.0, 100.0);
+        run(&mut follower, 1.0, 20);
+
+        // A 10ms gap barely dents the envelope, so the gate stays open
+        let after_short_gap = run(&mut follower, 0.0, 10);
+        assert!(after_short_gap > 0.85, "envelope {after_short_gap}");
+
+        // After one release time constant it has fallen to ~37%
+        let after_release = run(&mut follower, 0.0, 90);
+        assert!((after_release - (-1.0f64).exp()).abs() < 0.02);
+    }
+
+    #[test]
+    fn zero_time_constants_track_input_exactly() {
+        let mut follower = EnvelopeFollower::new(SR, 0.0, 0.0);
+        assert_eq!(follower.process_block(0.5, BLOCK), 0.5);
+        assert_eq!(follower.process_block(0.1, BLOCK), 0.1);
+    }
+}
@@ -4,15 +4,43 @@
 // spectral leakage. The magnitude spectrum is used by spectral feature
 // extraction functions.
 
-use rustfft::{num_complex::Complex, FftPlanner};
-use std::sync::{Arc, Mutex};
+use rustfft::{num_complex::Complex, Fft, FftPlanner};
+use std::fmt;
+use std::sync::Arc;
 
 /// FFT window size for feature extraction (higher resolution than onset detection)
 pub const FFT_SIZE: usize = 1024;
 
+/// Smallest FFT size the Hann window and positive-frequency split make sense for
+pub const MIN_FFT_SIZE: usize = 2;
+
+/// Largest FFT size accepted (~1.4s at 48kHz); anything bigger is a config mistake
+pub const MAX_FFT_SIZE: usize = 1 << 16;
+
+/// Errors from setting up an FFT processor
+#[derive(Debug, Clone, PartialEq)]
+pub enum FftError {
+    /// Requested size is outside [`MIN_FFT_SIZE`, `MAX_FFT_SIZE`]
+    InvalidSize { size: usize },
+}
+
+impl fmt::Display for FftError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FftError::InvalidSize { size } => write!(
+                f,
+                "invalid FFT size {size}: must be between {MIN_FFT_SIZE} and {MAX_FFT_SIZE}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for FftError {}
+
 /// FFT processor that computes magnitude spectra from audio windows
 pub struct FftProcessor {
-    fft_planner: Arc<Mutex<FftPlanner<f32>>>,
+    /// Forward transform, planned once at construction
+    fft: Arc<dyn Fft<f32>>,
     fft_size: usize,
     /// Hann window for FFT (pre-computed)
     window: Vec<f32>,
@@ -21,9 +49,29 @@ pub struct FftProcessor {
 impl FftProcessor {
     /// Create a new FFT processor
     ///
+    /// The transform is planned up front so `compute_magnitude_spectrum`
+    /// cannot fail. Non-power-of-two sizes are accepted (rustfft supports
+    /// them) but are slower, so a warning is logged.
+    ///
     /// # Arguments
     /// * `fft_size` - FFT window size (typically 1024 for feature extraction)
-    pub fn new(fft_size: usize) -> Self {
+    ///
+    /// # Errors
+    /// Returns [`FftError::InvalidSize`] when `fft_size` is outside
+    /// [`MIN_FFT_SIZE`, `MAX_FFT_SIZE`]; rustfft plans any size in that range.
+    pub fn new(fft_size: usize) -> Result<Self, FftError> {
+        if !(MIN_FFT_SIZE..=MAX_FFT_SIZE).contains(&fft_size) {
+            return Err(FftError::InvalidSize { size: fft_size });
+        }
+        if !fft_size.is_power_of_two() {
+            tracing::warn!(
+                "[FftProcessor] FFT size {} is not a power of two; transforms will be slower",
+                fft_size
+            );
+        }
+
+        let fft = FftPlanner::new().plan_fft_forward(fft_size);
+
         // Pre-compute Hann window to reduce spectral leakage
         let window = (0..fft_size)
             .map(|i| {
@@ -32,11 +80,11 @@ impl FftProcessor {
             })
             .collect();
 
-        Self {
-            fft_planner: Arc::new(Mutex::new(FftPlanner::new())),
+        Ok(Self {
+            fft,
             fft_size,
             window,
-        }
+        })
     }
 
     /// Compute magnitude spectrum using FFT
@@ -66,9 +114,7 @@ impl FftProcessor {
         }
 
         // Perform FFT
-        let mut planner = self.fft_planner.lock().unwrap();
-        let fft = planner.plan_fft_forward(self.fft_size);
-        fft.process(&mut buffer);
+        self.fft.process(&mut buffer);
 
         // Calculate magnitude spectrum (only positive frequencies)
         buffer[..self.fft_size / 2 + 1]
@@ -77,3 +123,28 @@ impl FftProcessor {
             .collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid_fft_size_is_an_error() {
+        assert!(matches!(
+            FftProcessor::new(0),
+            Err(FftError::InvalidSize { size: 0 })
+        ));
+        assert!(matches!(
+            FftProcessor::new(MAX_FFT_SIZE + 1),
+            Err(FftError::InvalidSize { .. })
+        ));
+    }
+
+    #[test]
+    fn test_non_power_of_two_size_is_supported() {
+        let processor = FftProcessor::new(1000).unwrap();
+        let spectrum = processor.compute_magnitude_spectrum(&[0.5; 1000]);
+        assert_eq!(spectrum.len(), 501);
+        assert!(spectrum.iter().all(|m| m.is_finite()));
+    }
+}
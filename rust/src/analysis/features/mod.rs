@@ -5,7 +5,7 @@
 // and frequency-domain representations of audio signals.
 //
 // Module organization:
-// - types: Data structures (Features struct)
+// - types: Data structures (Features struct, FeatureUnits, FrequencyScale)
 // - fft: FFT computation with windowing
 // - spectral: Frequency-domain features (centroid, flatness, rolloff)
 // - temporal: Time-domain features (ZCR, decay time)
@@ -22,16 +22,19 @@
 // - Peeters, G. (2004). A large set of audio features for sound description
 // - Lerch, A. (2012). An Introduction to Audio Content Analysis
 
-mod fft;
+use std::borrow::Cow;
+
+pub(crate) mod fft;
 mod spectral;
 mod temporal;
-mod types;
+pub mod types;
 
-pub use types::Features;
+pub use fft::FftError;
+pub use types::{FeatureUnits, Features, FrequencyScale};
 
 use fft::{FftProcessor, FFT_SIZE};
 use spectral::SpectralFeatures;
-use temporal::TemporalFeatures;
+pub(crate) use temporal::TemporalFeatures;
 
 /// FeatureExtractor coordinates DSP feature extraction pipeline
 ///
@@ -49,15 +52,45 @@ impl FeatureExtractor {
     ///
     /// # Arguments
     /// * `sample_rate` - Audio sample rate in Hz (e.g., 48000)
-    pub fn new(sample_rate: u32) -> Self {
-        let fft_size = FFT_SIZE;
+    ///
+    /// # Errors
+    /// Returns [`FftError`] if the default FFT size is rejected.
+    pub fn new(sample_rate: u32) -> Result<Self, FftError> {
+        Self::with_fft_size(sample_rate, FFT_SIZE)
+    }
 
-        Self {
-            fft_processor: FftProcessor::new(fft_size),
+    /// Create a FeatureExtractor with a custom FFT window size
+    ///
+    /// # Errors
+    /// Returns [`FftError`] when `fft_size` is out of range.
+    pub fn with_fft_size(sample_rate: u32, fft_size: usize) -> Result<Self, FftError> {
+        Ok(Self {
+            fft_processor: FftProcessor::new(fft_size)?,
             spectral_features: SpectralFeatures::new(sample_rate, fft_size),
             temporal_features: TemporalFeatures::new(sample_rate),
             fft_size,
-        }
+        })
+    }
+
+    /// Set the decay point (dB below peak) used for `decay_time_ms`
+    pub fn with_decay_threshold_db(mut self, decay_threshold_db: f32) -> Self {
+        self.temporal_features = self
+            .temporal_features
+            .with_decay_threshold_db(decay_threshold_db);
+        self
+    }
+
+    /// Smooth the envelope over `window_ms` before measuring `decay_time_ms`
+    pub fn with_envelope_smoothing_ms(mut self, window_ms: f32) -> Self {
+        self.temporal_features = self.temporal_features.with_envelope_smoothing_ms(window_ms);
+        self
+    }
+
+    /// Measure `decay_time_ms` over the first `window_ms` of the audio
+    /// passed to `extract` instead of the FFT window (0 keeps the FFT window)
+    pub fn with_decay_window_ms(mut self, window_ms: f32) -> Self {
+        self.temporal_features = self.temporal_features.with_decay_window_ms(window_ms);
+        self
     }
 
     /// Extract all features from an audio window
@@ -75,7 +108,9 @@ impl FeatureExtractor {
     /// Features struct containing all extracted features
     ///
     /// # Note
-    /// If audio is longer than FFT_SIZE, only the first FFT_SIZE samples are used
+    /// If audio is longer than FFT_SIZE, only the first FFT_SIZE samples are used,
+    /// except for the decay time when a decay window is set. NaN/Inf samples (garbage from some drivers) are treated as silence so
+    /// they cannot propagate into the features.
     pub fn extract(&self, audio: &[f32]) -> Features {
         // Ensure we have enough samples
         let audio_window = if audio.len() >= self.fft_size {
@@ -84,9 +119,10 @@ impl FeatureExtractor {
             // Pad with zeros if needed
             audio
         };
+        let audio_window = sanitize(audio_window);
 
         // Compute magnitude spectrum
-        let spectrum = self.fft_processor.compute_magnitude_spectrum(audio_window);
+        let spectrum = self.fft_processor.compute_magnitude_spectrum(&audio_window);
 
         // Extract frequency-domain features
         let centroid = self.spectral_features.compute_centroid(&spectrum);
@@ -94,8 +130,12 @@ impl FeatureExtractor {
         let rolloff = self.spectral_features.compute_rolloff(&spectrum);
 
         // Extract time-domain features
-        let zcr = self.temporal_features.compute_zcr(audio_window);
-        let decay_time_ms = self.temporal_features.compute_decay_time(audio_window);
+        let zcr = self.temporal_features.compute_zcr(&audio_window);
+        let decay_time_ms = if self.temporal_features.has_decay_window() {
+            self.temporal_features.compute_decay_time(&sanitize(audio))
+        } else {
+            self.temporal_features.compute_decay_time(&audio_window)
+        };
 
         Features {
             centroid,
@@ -107,6 +147,20 @@ impl FeatureExtractor {
     }
 }
 
+/// Replace NaN/Inf samples with silence, borrowing when there are none
+fn sanitize(audio: &[f32]) -> Cow<'_, [f32]> {
+    if audio.iter().all(|s| s.is_finite()) {
+        Cow::Borrowed(audio)
+    } else {
+        Cow::Owned(
+            audio
+                .iter()
+                .map(|&s| if s.is_finite() { s } else { 0.0 })
+                .collect(),
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -121,10 +175,11 @@ mod tests {
             .collect()
     }
 
-    /// Generate white noise for testing
-    fn generate_white_noise(duration_samples: usize) -> Vec<f32> {
-        use rand::Rng;
-        let mut rng = rand::thread_rng();
+    /// Generate reproducible uniform white noise in [-1, 1) for testing
+    fn white_noise_seeded(seed: u64, duration_samples: usize) -> Vec<f32> {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+        let mut rng = StdRng::seed_from_u64(seed);
         (0..duration_samples)
             .map(|_| rng.gen_range(-1.0..1.0))
             .collect()
@@ -145,17 +200,46 @@ mod tests {
             .collect()
     }
 
+    #[test]
+    fn test_invalid_fft_size_returns_error() {
+        let result = FeatureExtractor::with_fft_size(48000, 0);
+        assert!(matches!(result, Err(FftError::InvalidSize { size: 0 })));
+
+        let extractor = FeatureExtractor::with_fft_size(48000, 768).unwrap();
+        assert!(extractor
+            .extract(&generate_sine_wave(48000, 440.0, 768))
+            .is_finite());
+    }
+
+    #[test]
+    fn test_frequency_scale_conversions() {
+        assert_eq!(FrequencyScale::Hz.convert_hz(1000.0), 1000.0);
+        assert!((FrequencyScale::Mel.convert_hz(1000.0) - 1000.0).abs() < 0.1);
+        assert!((FrequencyScale::Bark.convert_hz(1000.0) - 8.527).abs() < 0.01);
+
+        let features = Features {
+            centroid: 1000.0,
+            rolloff: 4000.0,
+            zcr: 0.2,
+            ..Features::default()
+        };
+        let mel = features.in_frequency_scale(FrequencyScale::Mel);
+        assert!((mel.centroid - 1000.0).abs() < 0.1);
+        assert!((mel.rolloff - 2146.1).abs() < 0.1);
+        assert_eq!(mel.zcr, 0.2);
+    }
+
     #[test]
     fn test_feature_extractor_creation() {
         let sample_rate = 48000;
-        let extractor = FeatureExtractor::new(sample_rate);
+        let extractor = FeatureExtractor::new(sample_rate).unwrap();
         assert_eq!(extractor.fft_size, FFT_SIZE);
     }
 
     #[test]
     fn test_centroid_low_frequency() {
         let sample_rate = 48000;
-        let extractor = FeatureExtractor::new(sample_rate);
+        let extractor = FeatureExtractor::new(sample_rate).unwrap();
 
         // Generate 100 Hz sine wave
         let signal = generate_sine_wave(sample_rate, 100.0, FFT_SIZE);
@@ -173,7 +257,7 @@ mod tests {
     #[test]
     fn test_centroid_high_frequency() {
         let sample_rate = 48000;
-        let extractor = FeatureExtractor::new(sample_rate);
+        let extractor = FeatureExtractor::new(sample_rate).unwrap();
 
         // Generate 5000 Hz sine wave
         let signal = generate_sine_wave(sample_rate, 5000.0, FFT_SIZE);
@@ -191,23 +275,29 @@ mod tests {
     #[test]
     fn test_zcr_sine_vs_noise() {
         let sample_rate = 48000;
-        let extractor = FeatureExtractor::new(sample_rate);
+        let extractor = FeatureExtractor::new(sample_rate).unwrap();
 
         // Low-frequency sine wave (100 Hz) should have low ZCR
         let sine_signal = generate_sine_wave(sample_rate, 100.0, FFT_SIZE);
         let sine_features = extractor.extract(&sine_signal);
 
-        // White noise should have high ZCR (around 0.5 for random noise)
-        let noise_signal = generate_white_noise(FFT_SIZE);
+        // Independent zero-mean samples change sign with probability 0.5, so
+        // ZCR over 1024 samples is 0.5 with a std of about 0.016
+        let noise_signal = white_noise_seeded(0x5EED, FFT_SIZE);
         let noise_features = extractor.extract(&noise_signal);
+        assert_eq!(
+            noise_features,
+            extractor.extract(&white_noise_seeded(0x5EED, FFT_SIZE)),
+            "seeded noise must be reproducible"
+        );
 
         println!("Sine (100 Hz) ZCR: {}", sine_features.zcr);
         println!("White noise ZCR: {}", noise_features.zcr);
 
         // Noise should have significantly higher ZCR than sine
         assert!(
-            noise_features.zcr > 0.3,
-            "Expected noise ZCR > 0.3, got {}",
+            (0.45..0.55).contains(&noise_features.zcr),
+            "Expected noise ZCR within 0.5 +/- 0.05, got {}",
             noise_features.zcr
         );
         assert!(
@@ -220,14 +310,15 @@ mod tests {
     #[test]
     fn test_flatness_sine_vs_noise() {
         let sample_rate = 48000;
-        let extractor = FeatureExtractor::new(sample_rate);
+        let extractor = FeatureExtractor::new(sample_rate).unwrap();
 
         // Pure sine wave should have low flatness (tonal)
         let sine_signal = generate_sine_wave(sample_rate, 1000.0, FFT_SIZE);
         let sine_features = extractor.extract(&sine_signal);
 
-        // White noise should have high flatness (noise-like)
-        let noise_signal = generate_white_noise(FFT_SIZE);
+        // White noise should have high flatness (noise-like); the Hann-windowed
+        // magnitude spectrum of uniform noise sits around 0.84
+        let noise_signal = white_noise_seeded(0x5EED, FFT_SIZE);
         let noise_features = extractor.extract(&noise_signal);
 
         println!("Sine flatness: {}", sine_features.flatness);
@@ -241,8 +332,8 @@ mod tests {
         );
         // Noise should be more noise-like (higher flatness)
         assert!(
-            noise_features.flatness > 0.5,
-            "Expected noise flatness > 0.5, got {}",
+            noise_features.flatness > 0.75,
+            "Expected noise flatness > 0.75, got {}",
             noise_features.flatness
         );
     }
@@ -250,7 +341,7 @@ mod tests {
     #[test]
     fn test_rolloff_calculation() {
         let sample_rate = 48000;
-        let extractor = FeatureExtractor::new(sample_rate);
+        let extractor = FeatureExtractor::new(sample_rate).unwrap();
 
         // Low-frequency signal should have low rolloff
         let low_freq_signal = generate_sine_wave(sample_rate, 200.0, FFT_SIZE);
@@ -270,10 +361,94 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_decay_threshold_db_controls_decay_point() {
+        let sample_rate = 48000;
+        // Pure exponential envelope, tau = 20ms, 200ms long
+        let tau_samples = 0.02 * sample_rate as f32;
+        let signal: Vec<f32> = (0..9600)
+            .map(|i| (-(i as f32) / tau_samples).exp())
+            .collect();
+
+        let shallow = TemporalFeatures::new(sample_rate)
+            .with_decay_threshold_db(-10.0)
+            .compute_decay_time(&signal);
+        let default = TemporalFeatures::new(sample_rate).compute_decay_time(&signal);
+        let deep = TemporalFeatures::new(sample_rate)
+            .with_decay_threshold_db(-40.0)
+            .compute_decay_time(&signal);
+
+        // t = tau * ln(10) * dB / 20
+        assert!((shallow - 23.0).abs() < 1.0, "-10dB decay {shallow} ms");
+        assert!((default - 46.1).abs() < 1.0, "-20dB decay {default} ms");
+        assert!((deep - 92.1).abs() < 1.0, "-40dB decay {deep} ms");
+        assert!(shallow < default && default < deep);
+    }
+
+    #[test]
+    fn test_decay_window_gives_equal_decay_across_sample_rates() {
+        // Pure exponential envelope whose -20dB point (~25ms) lies beyond a
+        // 1024-sample window, which spans 21.3ms at 48kHz but 23.2ms at 44.1kHz
+        let decay_at = |sample_rate: u32, window_ms: f32| {
+            let tau_samples = 0.0109 * sample_rate as f32;
+            let signal: Vec<f32> = (0..2048)
+                .map(|i| (-(i as f32) / tau_samples).exp())
+                .collect();
+            FeatureExtractor::new(sample_rate)
+                .unwrap()
+                .with_decay_window_ms(window_ms)
+                .extract(&signal)
+                .decay_time_ms
+        };
+
+        let fixed_44k = decay_at(44_100, 0.0);
+        let fixed_48k = decay_at(48_000, 0.0);
+        assert!(
+            (fixed_44k - fixed_48k).abs() > 1.0,
+            "1024-sample bound: {fixed_44k} ms vs {fixed_48k} ms"
+        );
+
+        let timed_44k = decay_at(44_100, 30.0);
+        let timed_48k = decay_at(48_000, 30.0);
+        assert!(
+            (timed_44k - timed_48k).abs() < 0.1,
+            "30ms window: {timed_44k} ms vs {timed_48k} ms"
+        );
+        assert!((timed_48k - 25.1).abs() < 0.5, "decay {timed_48k} ms");
+    }
+
+    #[test]
+    fn test_envelope_smoothing_stabilizes_noisy_decay() {
+        use rand::{rngs::StdRng, Rng, SeedableRng};
+
+        let sample_rate = 48000;
+        let mut rng = StdRng::seed_from_u64(7);
+        // Noise burst under an exponential envelope, tau = 20ms, so the
+        // true -20dB point is at ~46ms
+        let tau_samples = 0.02 * sample_rate as f32;
+        let signal: Vec<f32> = (0..9600)
+            .map(|i| (-(i as f32) / tau_samples).exp() * rng.gen_range(-1.0..1.0))
+            .collect();
+        let expected_ms = 20.0 * 10f32.ln();
+
+        let raw = TemporalFeatures::new(sample_rate).compute_decay_time(&signal);
+        let smoothed = TemporalFeatures::new(sample_rate)
+            .with_envelope_smoothing_ms(2.0)
+            .compute_decay_time(&signal);
+
+        let raw_error = (raw - expected_ms).abs();
+        let smoothed_error = (smoothed - expected_ms).abs();
+        assert!(
+            smoothed_error < raw_error,
+            "smoothed {smoothed} ms should beat raw {raw} ms (expected {expected_ms} ms)"
+        );
+        assert!(smoothed_error < 8.0, "smoothed decay {smoothed} ms");
+    }
+
     #[test]
     fn test_decay_time_calculation() {
         let sample_rate = 48000;
-        let extractor = FeatureExtractor::new(sample_rate);
+        let extractor = FeatureExtractor::new(sample_rate).unwrap();
 
         // Generate decaying signal with known decay time (50ms)
         let signal = generate_decaying_signal(sample_rate, FFT_SIZE, 50.0);
@@ -292,7 +467,7 @@ mod tests {
     #[test]
     fn test_features_in_valid_ranges() {
         let sample_rate = 48000;
-        let extractor = FeatureExtractor::new(sample_rate);
+        let extractor = FeatureExtractor::new(sample_rate).unwrap();
 
         // Test with real-world-like signal (sine wave)
         let signal = generate_sine_wave(sample_rate, 1000.0, FFT_SIZE);
@@ -332,7 +507,7 @@ mod tests {
     #[test]
     fn test_extract_with_short_audio() {
         let sample_rate = 48000;
-        let extractor = FeatureExtractor::new(sample_rate);
+        let extractor = FeatureExtractor::new(sample_rate).unwrap();
 
         // Test with audio shorter than FFT size (should pad with zeros)
         let short_signal = generate_sine_wave(sample_rate, 1000.0, 512);
@@ -348,7 +523,7 @@ mod tests {
     #[test]
     fn test_extract_with_silence() {
         let sample_rate = 48000;
-        let extractor = FeatureExtractor::new(sample_rate);
+        let extractor = FeatureExtractor::new(sample_rate).unwrap();
 
         // Test with silence
         let silence = vec![0.0; FFT_SIZE];
@@ -8,9 +8,19 @@
 // - Peeters, G. (2004). A large set of audio features for sound description
 // - Lerch, A. (2012). An Introduction to Audio Content Analysis
 
+/// Default decay point relative to the peak (-20dB = 10% amplitude)
+pub const DEFAULT_DECAY_THRESHOLD_DB: f32 = -20.0;
+
 /// Temporal feature computation functions
 pub struct TemporalFeatures {
     sample_rate: u32,
+    /// Decay point relative to peak, in dB (negative)
+    decay_threshold_db: f32,
+    /// Moving-average window applied to the envelope before the decay
+    /// search (0 = raw rectified signal)
+    envelope_smoothing_samples: usize,
+    /// Length of audio searched for the decay point (0 = all of it)
+    decay_window_samples: usize,
 }
 
 impl TemporalFeatures {
@@ -19,7 +29,51 @@ impl TemporalFeatures {
     /// # Arguments
     /// * `sample_rate` - Audio sample rate in Hz
     pub fn new(sample_rate: u32) -> Self {
-        Self { sample_rate }
+        Self {
+            sample_rate,
+            decay_threshold_db: DEFAULT_DECAY_THRESHOLD_DB,
+            envelope_smoothing_samples: 0,
+            decay_window_samples: 0,
+        }
+    }
+
+    /// Smooth the envelope over `window_ms` before searching for the decay point
+    ///
+    /// The raw rectified signal dips towards zero at every zero crossing and
+    /// on noise, so a single dip can end the measurement early. A window of a
+    /// few milliseconds (longer than the period of the lowest expected
+    /// frequency) gives a much stabler estimate. 0 keeps the raw behavior.
+    pub fn with_envelope_smoothing_ms(mut self, window_ms: f32) -> Self {
+        self.envelope_smoothing_samples =
+            (window_ms.max(0.0) / 1000.0 * self.sample_rate as f32).round() as usize;
+        self
+    }
+
+    /// Search for the decay point within the first `window_ms` of the audio
+    ///
+    /// Without a window the search covers whatever is passed in, so a fixed
+    /// sample count spans more time at lower sample rates and long decays
+    /// measure differently. A time-based window gives the same bound at
+    /// every rate; audio shorter than the window is searched in full. 0
+    /// removes the bound.
+    pub fn with_decay_window_ms(mut self, window_ms: f32) -> Self {
+        self.decay_window_samples =
+            (window_ms.max(0.0) / 1000.0 * self.sample_rate as f32).round() as usize;
+        self
+    }
+
+    /// Whether `compute_decay_time` is bounded by a time-based window
+    pub fn has_decay_window(&self) -> bool {
+        self.decay_window_samples > 0
+    }
+
+    /// Set the decay point used by `compute_decay_time`
+    ///
+    /// -10dB is more robust for short percussive sounds, -40dB measures
+    /// sustain. The sign is ignored; the point is always below the peak.
+    pub fn with_decay_threshold_db(mut self, decay_threshold_db: f32) -> Self {
+        self.decay_threshold_db = -decay_threshold_db.abs();
+        self
     }
 
     /// Compute zero-crossing rate (ZCR)
@@ -58,8 +112,8 @@ impl TemporalFeatures {
     /// This is useful for distinguishing between percussive sounds with
     /// different attack/decay characteristics (e.g., kick vs. hi-hat).
     ///
-    /// Method: Find time from peak amplitude to -20dB decay point
-    /// (-20dB corresponds to 10% of peak amplitude in linear scale)
+    /// Method: Find time from peak amplitude to the decay point
+    /// (default -20dB, i.e. 10% of peak amplitude in linear scale)
     ///
     /// # Arguments
     /// * `audio` - Time-domain audio signal
@@ -70,9 +124,19 @@ impl TemporalFeatures {
         if audio.is_empty() {
             return 0.0;
         }
+        let audio = if self.decay_window_samples > 0 {
+            &audio[..audio.len().min(self.decay_window_samples)]
+        } else {
+            audio
+        };
 
-        // Compute envelope (simple method: absolute values with smoothing)
-        let envelope: Vec<f32> = audio.iter().map(|&x| x.abs()).collect();
+        // Compute envelope: rectified signal, optionally smoothed
+        let rectified: Vec<f32> = audio.iter().map(|&x| x.abs()).collect();
+        let envelope = if self.envelope_smoothing_samples > 1 {
+            moving_average(&rectified, self.envelope_smoothing_samples)
+        } else {
+            rectified
+        };
 
         // Find peak position and amplitude
         let (peak_idx, &peak_amp) = envelope
@@ -85,8 +149,8 @@ impl TemporalFeatures {
             return 0.0;
         }
 
-        // Calculate -20dB threshold (10% of peak amplitude in linear scale)
-        let decay_threshold = peak_amp * 0.1;
+        // Convert the dB decay point to a linear fraction of the peak
+        let decay_threshold = peak_amp * 10f32.powf(self.decay_threshold_db / 20.0);
 
         // Find first point after peak that crosses threshold
         for (i, &amp) in envelope[peak_idx..].iter().enumerate() {
@@ -102,3 +166,21 @@ impl TemporalFeatures {
         (remaining_samples / self.sample_rate as f32) * 1000.0
     }
 }
+
+/// Centered moving average over `window` samples (truncated at the edges)
+fn moving_average(values: &[f32], window: usize) -> Vec<f32> {
+    let mut prefix = Vec::with_capacity(values.len() + 1);
+    prefix.push(0.0f64);
+    for &v in values {
+        prefix.push(prefix[prefix.len() - 1] + v as f64);
+    }
+
+    let half = window / 2;
+    (0..values.len())
+        .map(|i| {
+            let start = i.saturating_sub(half);
+            let end = (i + window - half).min(values.len());
+            ((prefix[end] - prefix[start]) / (end - start) as f64) as f32
+        })
+        .collect()
+}
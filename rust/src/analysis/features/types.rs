@@ -7,7 +7,7 @@
 ///
 /// These features are used for beatbox sound classification (kick, snare, hi-hat).
 /// Each feature captures different acoustic properties of the audio signal.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct Features {
     /// Spectral centroid in Hz (weighted mean frequency)
     ///
@@ -40,3 +40,72 @@ pub struct Features {
     /// Useful for distinguishing percussive sounds with different attack/decay.
     pub decay_time_ms: f32,
 }
+
+impl Features {
+    /// Whether every feature is a finite number (no NaN/Inf).
+    pub fn is_finite(&self) -> bool {
+        self.centroid.is_finite()
+            && self.zcr.is_finite()
+            && self.flatness.is_finite()
+            && self.rolloff.is_finite()
+            && self.decay_time_ms.is_finite()
+    }
+
+    /// Zero-crossing rate expressed in crossings per second.
+    ///
+    /// `zcr` is per-sample and therefore scales with the sample rate; this
+    /// converts it into a rate-independent unit for storing or comparing
+    /// features captured on devices running at different rates.
+    pub fn zcr_per_second(&self, sample_rate: u32) -> f32 {
+        self.zcr * sample_rate as f32
+    }
+
+    /// Copy with centroid and rolloff expressed on `scale` instead of Hz.
+    ///
+    /// For display only; classification always works in Hz.
+    pub fn in_frequency_scale(&self, scale: FrequencyScale) -> Self {
+        Self {
+            centroid: scale.convert_hz(self.centroid),
+            rolloff: scale.convert_hz(self.rolloff),
+            ..*self
+        }
+    }
+}
+
+/// Frequency scale for reporting centroid and rolloff to the UI.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FrequencyScale {
+    /// Linear frequency in Hz.
+    #[default]
+    Hz,
+    /// Mel scale (O'Shaughnessy): 1000 Hz is 1000 mel.
+    Mel,
+    /// Bark critical-band scale (Traunmüller approximation).
+    Bark,
+}
+
+impl FrequencyScale {
+    /// Convert a frequency in Hz to this scale.
+    pub fn convert_hz(self, hz: f32) -> f32 {
+        match self {
+            FrequencyScale::Hz => hz,
+            FrequencyScale::Mel => 2595.0 * (1.0 + hz / 700.0).log10(),
+            FrequencyScale::Bark => 26.81 * hz / (1960.0 + hz) - 0.53,
+        }
+    }
+}
+
+/// Unit convention used for stored feature thresholds.
+///
+/// Centroid and rolloff are always expressed in Hz, which is already
+/// independent of the sample rate. Only ZCR differs between conventions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeatureUnits {
+    /// ZCR as a per-sample fraction (what `FeatureExtractor` emits).
+    #[default]
+    PerSample,
+    /// ZCR in crossings per second.
+    Normalized,
+}
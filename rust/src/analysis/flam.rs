@@ -0,0 +1,127 @@
+// Flam pairing and level-crossing dedup for classification results
+//
+// Both detectors can report the same hit: the level-crossing capture and
+// the onset detector's onset. Onsets close to a capture are dropped as its
+// duplicate. With `flam_window_ms` set, a result is also held briefly so a
+// second hit right behind it can be folded in and reported as a flam.
+
+use std::time::Instant;
+
+use super::result::ClassificationResult;
+
+/// Result on its way out, with its sample position and detection time
+pub(super) type HeldResult = (ClassificationResult, u64, Instant);
+
+/// Held result, flam window and the last level-crossing capture
+///
+/// Positions are in samples since engine start.
+#[derive(Debug)]
+pub(super) struct FlamStage {
+    /// Flam window in samples (0 disables pairing)
+    window_samples: u64,
+    /// Result waiting to see whether a second onset turns it into a flam
+    pending: Option<HeldResult>,
+    /// Sample position of the last level-crossing capture, and whether an
+    /// onset has already been matched to it as its duplicate
+    last_level_capture: Option<(u64, bool)>,
+    /// How long after a level-crossing capture onsets may duplicate it
+    level_dedup_samples: u64,
+}
+
+impl FlamStage {
+    pub(super) fn new(flam_window_ms: f32, level_dedup_samples: u64, sample_rate: u32) -> Self {
+        Self {
+            window_samples: (flam_window_ms.max(0.0) * sample_rate as f32 / 1000.0) as u64,
+            pending: None,
+            last_level_capture: None,
+            level_dedup_samples,
+        }
+    }
+
+    /// Whether offered results are held for pairing instead of being
+    /// returned straight away
+    pub(super) fn holds_results(&self) -> bool {
+        self.window_samples > 0
+    }
+
+    /// Offer a result; returns the result to send now, if any
+    ///
+    /// Without a flam window every result is sent straight away. Otherwise
+    /// the result is held. A result within the window of the held one is
+    /// absorbed into it, and the held result is returned flagged `flam`; a
+    /// later one returns the held result as is and is held in its place.
+    pub(super) fn offer(
+        &mut self,
+        result: ClassificationResult,
+        sample_position: u64,
+        detected_at: Instant,
+    ) -> Option<HeldResult> {
+        if self.window_samples == 0 {
+            return Some((result, sample_position, detected_at));
+        }
+
+        let held = self.pending.take();
+        if let Some((mut held, held_position, held_detected_at)) = held {
+            if sample_position.saturating_sub(held_position) <= self.window_samples {
+                held.flam = true;
+                return Some((held, held_position, held_detected_at));
+            }
+            self.pending = Some((result, sample_position, detected_at));
+            return Some((held, held_position, held_detected_at));
+        }
+        self.pending = Some((result, sample_position, detected_at));
+        None
+    }
+
+    /// The held result, once no second onset can still pair with it
+    ///
+    /// Onsets are reported up to `detector_window` samples after they
+    /// happen, so the result waits that long beyond the flam window.
+    pub(super) fn take_expired(
+        &mut self,
+        processed_samples: u64,
+        detector_window: u64,
+    ) -> Option<HeldResult> {
+        let (_, held_position, _) = self.pending.as_ref()?;
+        let deadline = held_position + self.window_samples + detector_window;
+        if processed_samples > deadline {
+            self.pending.take()
+        } else {
+            None
+        }
+    }
+
+    /// The held result regardless of its window, e.g. because the stream
+    /// is ending
+    pub(super) fn take_pending(&mut self) -> Option<HeldResult> {
+        self.pending.take()
+    }
+
+    /// Remember a level-crossing capture for onset dedup
+    pub(super) fn record_level_capture(&mut self, sample: u64) {
+        self.last_level_capture = Some((sample, false));
+    }
+
+    /// Whether an onset is the level-crossing detector's capture of the
+    /// same hit
+    ///
+    /// Onsets within the debounce window of a capture are dropped. With a
+    /// flam window outside calibration only the first one is: a later
+    /// onset is a second hit that may pair with the held capture. Compared
+    /// by sample position, so it holds however long either one was
+    /// deferred.
+    pub(super) fn duplicates_level_capture(
+        &mut self,
+        onset_timestamp: u64,
+        calibration_active: bool,
+    ) -> bool {
+        let flam_mode = !calibration_active && self.window_samples > 0;
+        let Some((captured_at, matched)) = self.last_level_capture.as_mut() else {
+            return false;
+        };
+        if onset_timestamp.abs_diff(*captured_at) >= self.level_dedup_samples {
+            return false;
+        }
+        !flam_mode || !std::mem::replace(matched, true)
+    }
+}
@@ -0,0 +1,56 @@
+// Sleep policy for the analysis thread while its queue is empty
+
+use std::time::Duration;
+
+/// Sleep between polls of an empty analysis queue
+///
+/// Starts at 1ms and doubles on every consecutive empty poll up to the cap,
+/// so an idle thread wakes rarely while the first buffer after a pause
+/// resets it to the short, low-latency poll.
+#[derive(Debug)]
+pub(super) struct IdleBackoff {
+    current: Duration,
+    max: Duration,
+}
+
+impl IdleBackoff {
+    const MIN: Duration = Duration::from_millis(1);
+
+    pub(super) fn new(max_ms: u64) -> Self {
+        Self {
+            current: Self::MIN,
+            max: Duration::from_millis(max_ms).max(Self::MIN),
+        }
+    }
+
+    /// Sleep to take for this empty poll; the next one sleeps longer
+    pub(super) fn next_sleep(&mut self) -> Duration {
+        let sleep = self.current;
+        self.current = (self.current * 2).min(self.max);
+        sleep
+    }
+
+    /// Back to the shortest sleep once a buffer was popped
+    pub(super) fn reset(&mut self) {
+        self.current = Self::MIN;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn idle_backoff_grows_while_empty_and_resets_on_pop() {
+        let mut backoff = IdleBackoff::new(16);
+        let sleeps: Vec<_> = (0..7).map(|_| backoff.next_sleep().as_millis()).collect();
+        assert_eq!(sleeps, vec![1, 2, 4, 8, 16, 16, 16]);
+
+        backoff.reset();
+        assert_eq!(backoff.next_sleep(), Duration::from_millis(1));
+
+        // A cap of 1ms (or 0) keeps the flat poll
+        let mut flat = IdleBackoff::new(0);
+        assert!((0..4).all(|_| flat.next_sleep() == Duration::from_millis(1)));
+    }
+}
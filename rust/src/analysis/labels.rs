@@ -0,0 +1,76 @@
+//! Display names for classified sounds.
+//!
+//! `BeatboxHit` stays the canonical value used by all classification and
+//! timing logic; labels are purely presentational and travel alongside it in
+//! `ClassificationResult::label`. Custom kits and localized UIs can override
+//! any subset of names at runtime; sounds without an override keep their
+//! default English name.
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use once_cell::sync::Lazy;
+
+use crate::types::BeatboxHit;
+
+static LABELS: Lazy<RwLock<HashMap<BeatboxHit, String>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Default display name for a sound
+pub fn default_label(hit: BeatboxHit) -> &'static str {
+    match hit {
+        BeatboxHit::Kick => "Kick",
+        BeatboxHit::Snare => "Snare",
+        BeatboxHit::HiHat => "Hi-Hat",
+        BeatboxHit::ClosedHiHat => "Closed Hi-Hat",
+        BeatboxHit::OpenHiHat => "Open Hi-Hat",
+        BeatboxHit::KSnare => "K-Snare",
+        BeatboxHit::Unknown => "Unknown",
+    }
+}
+
+/// Display name for a sound, honouring any runtime override
+pub fn display_name(hit: BeatboxHit) -> String {
+    LABELS
+        .read()
+        .ok()
+        .and_then(|labels| labels.get(&hit).cloned())
+        .unwrap_or_else(|| default_label(hit).to_string())
+}
+
+/// A classifiable sound as presented to the UI
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SoundDescriptor {
+    /// Canonical sound value
+    pub sound: BeatboxHit,
+    /// Display name (see [`display_name`])
+    pub label: String,
+    /// Lowest classifier level that produces this sound
+    pub min_level: u8,
+    /// Whether the classifier can produce this sound at the queried level
+    pub active: bool,
+}
+
+/// Describe every supported sound for a classifier running at `level`
+///
+/// Sounds are listed in calibration/legend order; the list is the same at
+/// every level, only `active` changes.
+pub fn sound_descriptors(level: u8) -> Vec<SoundDescriptor> {
+    BeatboxHit::SUPPORTED
+        .iter()
+        .map(|&sound| SoundDescriptor {
+            sound,
+            label: display_name(sound),
+            min_level: sound.min_level(),
+            active: sound.min_level() <= level,
+        })
+        .collect()
+}
+
+/// Replace all label overrides; an empty map restores the defaults
+pub fn set_labels(labels: HashMap<BeatboxHit, String>) {
+    match LABELS.write() {
+        Ok(mut guard) => *guard = labels,
+        Err(poisoned) => *poisoned.into_inner() = labels,
+    }
+}
@@ -0,0 +1,52 @@
+//! Listening windows for call-and-response exercises.
+//!
+//! While any windows are set, only hits whose timestamp falls inside one of
+//! them count as answers. Hits in between are either flagged as extra or
+//! dropped, depending on `OnsetDetectionConfig::outside_window_policy`.
+//! Windows use the same time base as `ClassificationResult::timestamp_ms`
+//! (milliseconds since engine start); with no windows set every hit counts.
+
+use std::sync::{Arc, RwLock};
+
+use once_cell::sync::Lazy;
+
+static SHARED: Lazy<ListeningWindows> = Lazy::new(ListeningWindows::default);
+
+/// Shared set of `[start_ms, end_ms]` windows
+#[derive(Debug, Clone, Default)]
+pub struct ListeningWindows {
+    windows: Arc<RwLock<Vec<(u64, u64)>>>,
+}
+
+impl ListeningWindows {
+    /// Windows set through the API and read by the analysis thread
+    pub fn shared() -> Self {
+        SHARED.clone()
+    }
+
+    /// Replace all windows; an empty list disables gating
+    ///
+    /// Windows with `end < start` are ignored. Returns the number applied.
+    pub fn set(&self, mut windows: Vec<(u64, u64)>) -> usize {
+        windows.retain(|&(start, end)| end >= start);
+        windows.sort_unstable();
+        let count = windows.len();
+        match self.windows.write() {
+            Ok(mut guard) => *guard = windows,
+            Err(poisoned) => *poisoned.into_inner() = windows,
+        }
+        count
+    }
+
+    /// Whether a hit at `timestamp_ms` counts (always true without windows)
+    pub fn contains(&self, timestamp_ms: u64) -> bool {
+        let windows = match self.windows.read() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        windows.is_empty()
+            || windows
+                .iter()
+                .any(|&(start, end)| (start..=end).contains(&timestamp_ms))
+    }
+}
@@ -0,0 +1,156 @@
+// Onset detection functions - the per-frame novelty measure behind OnsetDetector
+//
+// OnsetDetector frames the audio, runs the FFT and handles smoothing,
+// adaptive thresholding and peak picking. What it peak-picks is one value per
+// frame from an onset detection function. Spectral flux is the default; the
+// alternatives below react to different aspects of a transient and are
+// selected with `OnsetDetectionConfig::onset_method`, so algorithms can be
+// compared on the same fixtures.
+//
+// Every function is half-wave rectified: only increases count, so decays and
+// steady sounds stay near zero.
+
+use rustfft::num_complex::Complex;
+
+use crate::config::OnsetMethodKind;
+
+/// One analysis frame as seen by an onset detection function
+///
+/// Bins outside the configured onset band are zeroed in both spectra.
+pub struct OnsetFrame<'a> {
+    /// Complex spectrum of the windowed frame (positive frequencies)
+    pub spectrum: &'a [Complex<f32>],
+    /// Magnitude spectrum, whitened when spectral whitening is enabled
+    pub magnitudes: &'a [f32],
+    /// `magnitudes` of the previous frame
+    pub prev_magnitudes: &'a [f32],
+}
+
+/// A per-frame onset detection function
+///
+/// Implementations may keep state between frames; frames arrive in order.
+pub trait OnsetMethod: Send {
+    /// Detection function value for `frame` (>= 0; larger means more onset-like)
+    fn detect(&mut self, frame: &OnsetFrame<'_>) -> f32;
+}
+
+/// Build the detection function selected in the configuration
+pub fn method_for(kind: OnsetMethodKind) -> Box<dyn OnsetMethod> {
+    match kind {
+        OnsetMethodKind::SpectralFlux => Box::new(SpectralFlux {}),
+        OnsetMethodKind::HighFrequencyContent => Box::new(HighFrequencyContent::default()),
+        OnsetMethodKind::ComplexDomain => Box::new(ComplexDomain::default()),
+        OnsetMethodKind::EnergyBased => Box::new(EnergyBased::default()),
+    }
+}
+
+/// Spectral flux: SF(t) = Σ max(0, |X_t[k]| - |X_(t-1)[k]|)
+pub fn spectral_flux(magnitudes: &[f32], prev_magnitudes: &[f32]) -> f32 {
+    magnitudes
+        .iter()
+        .zip(prev_magnitudes)
+        .map(|(curr, prev)| (curr - prev).max(0.0))
+        .sum()
+}
+
+/// Sum of positive per-bin magnitude increases (the default)
+#[derive(Debug, Default)]
+pub struct SpectralFlux {}
+
+impl OnsetMethod for SpectralFlux {
+    fn detect(&mut self, frame: &OnsetFrame<'_>) -> f32 {
+        spectral_flux(frame.magnitudes, frame.prev_magnitudes)
+    }
+}
+
+/// Rise in high-frequency content: HFC(t) = Σ (k / K) × |X_t[k]|²
+///
+/// Weighting by bin index favours the broadband attack of a percussive hit
+/// over low-frequency energy, which suits hi-hats and snares.
+#[derive(Debug, Default)]
+pub struct HighFrequencyContent {
+    prev_hfc: f32,
+}
+
+impl OnsetMethod for HighFrequencyContent {
+    fn detect(&mut self, frame: &OnsetFrame<'_>) -> f32 {
+        let bins = frame.magnitudes.len().max(1) as f32;
+        let hfc: f32 = frame
+            .magnitudes
+            .iter()
+            .enumerate()
+            .map(|(k, magnitude)| k as f32 / bins * magnitude * magnitude)
+            .sum();
+        let rise = (hfc - self.prev_hfc).max(0.0);
+        self.prev_hfc = hfc;
+        rise
+    }
+}
+
+/// Rectified complex-domain deviation (Dixon 2006)
+///
+/// Predicts each bin from the previous frame, assuming a steady magnitude and
+/// phase advance, and sums the distance between prediction and observation
+/// over bins whose magnitude grew. Catches soft onsets that change phase
+/// more than magnitude.
+#[derive(Debug, Default)]
+pub struct ComplexDomain {
+    prev_phase: Vec<f32>,
+    prev_prev_phase: Vec<f32>,
+}
+
+impl OnsetMethod for ComplexDomain {
+    fn detect(&mut self, frame: &OnsetFrame<'_>) -> f32 {
+        let bins = frame.spectrum.len();
+        if self.prev_phase.len() != bins {
+            self.prev_phase = vec![0.0; bins];
+            self.prev_prev_phase = vec![0.0; bins];
+        }
+
+        let mut deviation = 0.0;
+        for (k, bin) in frame.spectrum.iter().enumerate() {
+            let magnitude = frame.magnitudes[k];
+            let prev_magnitude = frame.prev_magnitudes.get(k).copied().unwrap_or(0.0);
+            let phase = bin.arg();
+            if magnitude >= prev_magnitude {
+                let predicted_phase = 2.0 * self.prev_phase[k] - self.prev_prev_phase[k];
+                let observed = Complex::from_polar(magnitude, phase);
+                let predicted = Complex::from_polar(prev_magnitude, predicted_phase);
+                deviation += (observed - predicted).norm();
+            }
+            self.prev_prev_phase[k] = self.prev_phase[k];
+            self.prev_phase[k] = phase;
+        }
+        deviation
+    }
+}
+
+/// Rise in frame energy: max(0, E_t - E_(t-1))
+///
+/// Ignores where in the band the energy sits; cheap and robust for loud,
+/// isolated hits. E is the energy of the windowed frame, taken from the
+/// spectrum (Parseval) so bins outside the onset band don't count.
+#[derive(Debug, Default)]
+pub struct EnergyBased {
+    prev_energy: f32,
+}
+
+/// Energy of a frame from its positive-frequency magnitudes (Parseval):
+/// E = (|X_0|² + 2 Σ |X_k|² + |X_(N/2)|²) / N
+pub fn frame_energy(magnitudes: &[f32]) -> f32 {
+    let Some(nyquist) = magnitudes.len().checked_sub(1).filter(|&n| n > 0) else {
+        return magnitudes.iter().map(|m| m * m).sum();
+    };
+    let doubled: f32 = magnitudes.iter().map(|m| 2.0 * m * m).sum();
+    let edges = magnitudes[0] * magnitudes[0] + magnitudes[nyquist] * magnitudes[nyquist];
+    (doubled - edges) / (2 * nyquist) as f32
+}
+
+impl OnsetMethod for EnergyBased {
+    fn detect(&mut self, frame: &OnsetFrame<'_>) -> f32 {
+        let energy = frame_energy(frame.magnitudes);
+        let rise = (energy - self.prev_energy).max(0.0);
+        self.prev_energy = energy;
+        rise
+    }
+}
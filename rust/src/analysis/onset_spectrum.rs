@@ -0,0 +1,82 @@
+// OnsetDetector - framing the FFT spectrum: windowing, whitening and the
+// frequency band the onset detection function sees
+
+use rustfft::num_complex::Complex;
+use std::ops::Range;
+
+use super::OnsetDetector;
+use crate::config::OnsetDetectionConfig;
+
+impl OnsetDetector {
+    /// Compute the complex spectrum of the Hann-windowed frame using FFT
+    ///
+    /// # Arguments
+    /// * `audio` - Audio window of size `window_size`
+    ///
+    /// # Returns
+    /// Positive-frequency bins (size = window_size / 2 + 1)
+    pub(super) fn compute_spectrum(&self, audio: &[f32]) -> Vec<Complex<f32>> {
+        let mut buffer: Vec<Complex<f32>> = audio
+            .iter()
+            .zip(self.window.iter())
+            .map(|(sample, window_val)| Complex::new(sample * window_val, 0.0))
+            .collect();
+
+        // Perform FFT
+        let mut planner = self.fft_planner.lock().unwrap();
+        let fft = planner.plan_fft_forward(self.window_size);
+        fft.process(&mut buffer);
+
+        // Keep only positive frequencies
+        buffer.truncate(self.window_size / 2 + 1);
+        buffer
+    }
+
+    /// Normalize each bin by its running peak magnitude (adaptive whitening)
+    ///
+    /// P[k] = max(|X[k]|, floor, decay × P[k])
+    /// X'[k] = |X[k]| / P[k]
+    ///
+    /// No-op when whitening is disabled in the configuration.
+    pub(super) fn whiten_spectrum(&mut self, spectrum: &mut [f32]) {
+        let Some(peaks) = self.whitening.as_mut() else {
+            return;
+        };
+
+        for (magnitude, peak) in spectrum.iter_mut().zip(peaks.iter_mut()) {
+            *peak = magnitude
+                .max(self.whitening_floor)
+                .max(*peak * self.whitening_decay);
+            *magnitude /= *peak;
+        }
+    }
+
+    /// Zero every bin outside the configured frequency band
+    pub(super) fn restrict_to_band(&self, spectrum: &mut [Complex<f32>], magnitudes: &mut [f32]) {
+        let Range { start, end } = self.band_bins;
+        for (bin, (value, magnitude)) in spectrum.iter_mut().zip(magnitudes.iter_mut()).enumerate()
+        {
+            if bin < start || bin >= end {
+                *value = Complex::new(0.0, 0.0);
+                *magnitude = 0.0;
+            }
+        }
+    }
+}
+
+/// Bins whose centre frequency lies within the configured onset band
+pub(super) fn band_bins(
+    config: &OnsetDetectionConfig,
+    sample_rate: u32,
+    window_size: usize,
+) -> Range<usize> {
+    let bins = window_size / 2 + 1;
+    let bin_hz = sample_rate as f32 / window_size as f32;
+    let start = (config.onset_min_hz.max(0.0) / bin_hz).ceil() as usize;
+    let end = if config.onset_max_hz > 0.0 {
+        (config.onset_max_hz / bin_hz).floor() as usize + 1
+    } else {
+        bins
+    };
+    start.min(bins)..end.min(bins)
+}
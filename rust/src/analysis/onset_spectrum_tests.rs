@@ -0,0 +1,222 @@
+use super::tests::generate_impulse;
+use super::*;
+use crate::config::OnsetMethodKind;
+
+fn click_train_with_noise(seed: u64) -> Vec<f32> {
+    use rand::{Rng, SeedableRng};
+
+    let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+    let period = 2400; // 50ms at 48kHz
+    (0..12800)
+        .map(|i| {
+            let noise = rng.gen_range(-0.002f32..0.002);
+            let click = if i >= period / 2 && i % period < 32 {
+                0.5
+            } else {
+                0.0
+            };
+            noise + click
+        })
+        .collect()
+}
+
+/// Microphone with a rolled-off top end (one-pole low-pass plus make-up gain)
+fn dark_mic(signal: &[f32]) -> Vec<f32> {
+    let mut state = 0.0f32;
+    signal
+        .iter()
+        .map(|&x| {
+            state = 0.97 * state + 0.03 * x;
+            state * 10.0
+        })
+        .collect()
+}
+
+/// Microphone with a tilted-up top end (first-order difference)
+fn bright_mic(signal: &[f32]) -> Vec<f32> {
+    let mut prev = 0.0f32;
+    signal
+        .iter()
+        .map(|&x| {
+            let y = x - prev;
+            prev = x;
+            y
+        })
+        .collect()
+}
+
+fn count_onsets(signal: &[f32], spectral_whitening: bool) -> usize {
+    let config = OnsetDetectionConfig {
+        spectral_whitening,
+        ..OnsetDetectionConfig::default()
+    };
+    OnsetDetector::with_config(48000, config)
+        .process(signal)
+        .len()
+}
+
+/// Variance of onset counts across the two microphone colorings
+fn count_variance(counts: [usize; 2]) -> f32 {
+    let mean = (counts[0] + counts[1]) as f32 / 2.0;
+    counts
+        .iter()
+        .map(|&c| (c as f32 - mean).powi(2))
+        .sum::<f32>()
+        / 2.0
+}
+
+#[test]
+fn test_whitening_reduces_count_variance_across_mic_colorations() {
+    let mut raw_variance = 0.0;
+    let mut whitened_variance = 0.0;
+
+    for seed in 0..3 {
+        let signal = click_train_with_noise(seed);
+        let dark = dark_mic(&signal);
+        let bright = bright_mic(&signal);
+
+        raw_variance += count_variance([count_onsets(&dark, false), count_onsets(&bright, false)]);
+        whitened_variance +=
+            count_variance([count_onsets(&dark, true), count_onsets(&bright, true)]);
+    }
+
+    assert!(
+        whitened_variance < raw_variance,
+        "whitening should make onset counts less mic-dependent (raw {raw_variance}, whitened {whitened_variance})"
+    );
+}
+
+#[test]
+fn test_whitening_bounds_normalized_spectrum() {
+    let config = OnsetDetectionConfig {
+        spectral_whitening: true,
+        ..OnsetDetectionConfig::default()
+    };
+    let mut detector = OnsetDetector::with_config(48000, config);
+
+    let mut spectrum = vec![0.0, 0.01, 2.0, 40.0];
+    detector.whitening = Some(vec![0.0; spectrum.len()]);
+    detector.whiten_spectrum(&mut spectrum);
+
+    // Bins above the floor normalize to 1.0; quiet bins stay below it
+    assert_eq!(spectrum[0], 0.0);
+    assert!(spectrum[1] < 1.0);
+    assert_eq!(spectrum[2], 1.0);
+    assert_eq!(spectrum[3], 1.0);
+}
+
+#[test]
+fn test_whitened_flux_is_averaged_over_the_band() {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    let config = OnsetDetectionConfig {
+        spectral_whitening: true,
+        onset_min_hz: 1000.0,
+        onset_max_hz: 4000.0,
+        ..OnsetDetectionConfig::default()
+    };
+    let mut detector = OnsetDetector::with_config(48000, config);
+    let mut rng = StdRng::seed_from_u64(478);
+    let mut signal = vec![0.0f32; 2048];
+    signal.extend((0..2048).map(|_| rng.gen_range(-0.5..0.5)));
+    detector.process(&signal);
+
+    // Noise after silence lifts most in-band bins to their peak, so the
+    // band average jumps by a large fraction of 1 however narrow the band
+    let peak_flux = detector.flux_signal.iter().copied().fold(0.0, f32::max);
+    assert!(peak_flux > 0.3, "{peak_flux}");
+    assert!(peak_flux <= 1.0, "{peak_flux}");
+}
+
+#[test]
+fn test_every_onset_method_detects_impulse_train() {
+    // Short enough to stay within the flux buffer
+    let impulses_ms = [50, 110, 170];
+    let signal = generate_impulse(48000, 250, &impulses_ms);
+    for method in [
+        OnsetMethodKind::SpectralFlux,
+        OnsetMethodKind::HighFrequencyContent,
+        OnsetMethodKind::ComplexDomain,
+        OnsetMethodKind::EnergyBased,
+    ] {
+        let config = OnsetDetectionConfig {
+            onset_method: method,
+            ..OnsetDetectionConfig::default()
+        };
+        let onsets = OnsetDetector::with_config(48000, config).process(&signal);
+        let onset_ms: Vec<f32> = onsets.iter().map(|o| o.timestamp as f32 / 48.0).collect();
+        for impulse in impulses_ms {
+            assert!(
+                onset_ms
+                    .iter()
+                    .any(|&ms| (ms - impulse as f32).abs() <= 10.0),
+                "{method:?} missed the impulse at {impulse}ms: {onset_ms:?}"
+            );
+        }
+        assert!(
+            onsets.len() <= impulses_ms.len() * 2,
+            "{method:?} fired too often: {onset_ms:?}"
+        );
+    }
+}
+
+/// Onset count for a burst of `freq_hz` with the given band lower edge
+fn onsets_for_tone_burst(freq_hz: f32, onset_min_hz: f32) -> usize {
+    // Flux is a raw magnitude sum at this window size
+    onsets_for_tone_burst_with(OnsetMethodKind::SpectralFlux, 200.0, freq_hz, onset_min_hz)
+}
+
+/// [`onsets_for_tone_burst`] with the given detection function and
+/// threshold offset
+fn onsets_for_tone_burst_with(
+    method: OnsetMethodKind,
+    threshold_offset: f32,
+    freq_hz: f32,
+    onset_min_hz: f32,
+) -> usize {
+    let sample_rate = 48000;
+    let config = OnsetDetectionConfig {
+        window_size: 2048,
+        hop_size: 512,
+        threshold_offset,
+        onset_min_hz,
+        onset_method: method,
+        ..OnsetDetectionConfig::default()
+    };
+    let mut detector = OnsetDetector::with_config(sample_rate, config);
+
+    // 250 ms of silence, then a sustained tone with a 1 ms linear attack
+    let burst_start = sample_rate as usize / 4;
+    let signal: Vec<f32> = (0..sample_rate as usize)
+        .map(|i| {
+            let Some(t) = i.checked_sub(burst_start) else {
+                return 0.0;
+            };
+            let attack = (t as f32 / 48.0).min(1.0);
+            let phase = 2.0 * std::f32::consts::PI * freq_hz * t as f32 / sample_rate as f32;
+            0.8 * attack * phase.sin()
+        })
+        .collect();
+    detector.process(&signal).len()
+}
+
+#[test]
+fn test_onset_band_ignores_rumble_but_keeps_transient() {
+    assert!(
+        onsets_for_tone_burst(20.0, 0.0) > 0,
+        "rumble should trigger without a band"
+    );
+    assert_eq!(onsets_for_tone_burst(20.0, 50.0), 0);
+    assert!(onsets_for_tone_burst(500.0, 50.0) > 0);
+}
+
+#[test]
+fn test_energy_onsets_only_count_energy_in_the_band() {
+    let energy = OnsetMethodKind::EnergyBased;
+    assert!(
+        onsets_for_tone_burst_with(energy, 50.0, 20.0, 0.0) > 0,
+        "rumble should trigger without a band"
+    );
+    assert_eq!(onsets_for_tone_burst_with(energy, 50.0, 20.0, 50.0), 0);
+    assert!(onsets_for_tone_burst_with(energy, 50.0, 500.0, 50.0) > 0);
+}
@@ -0,0 +1,363 @@
+use super::*;
+
+/// Generate synthetic impulse signal for testing
+pub(super) fn generate_impulse(
+    sample_rate: u32,
+    duration_ms: u32,
+    impulse_positions: &[u32],
+) -> Vec<f32> {
+    let total_samples = (sample_rate * duration_ms / 1000) as usize;
+    let mut signal = vec![0.0; total_samples];
+
+    for &pos_ms in impulse_positions {
+        let sample_idx = (sample_rate * pos_ms / 1000) as usize;
+        if sample_idx < total_samples {
+            // Create a short burst of energy (10 samples)
+            for offset in 0..10 {
+                if sample_idx + offset < total_samples {
+                    signal[sample_idx + offset] = 1.0;
+                }
+            }
+        }
+    }
+
+    signal
+}
+
+#[test]
+fn test_strong_transient_has_higher_strength_than_weak_one() {
+    // Same click at full and tenth amplitude, in separate detectors so
+    // neither hit's flux influences the other's threshold
+    let strength = |amplitude: f32| {
+        let signal: Vec<f32> = generate_impulse(48000, 250, &[100])
+            .into_iter()
+            .map(|s| s * amplitude)
+            .collect();
+        let onsets = OnsetDetector::new(48000).process(&signal);
+        assert_eq!(onsets.len(), 1, "amplitude {amplitude}: {onsets:?}");
+        onsets[0].strength
+    };
+
+    let strong = strength(1.0);
+    let weak = strength(0.1);
+    assert!(weak > 0.0);
+    assert!(strong > weak, "strong {strong} vs weak {weak}");
+}
+
+#[test]
+fn test_onset_detector_detects_impulse() {
+    let sample_rate = 48000;
+    let mut detector = OnsetDetector::new(sample_rate);
+
+    // Generate signal with strong impulses
+    // Use simpler test: just check that we detect SOME onset in a signal with impulses
+    let signal = generate_impulse(sample_rate, 500, &[100, 300]);
+
+    // Process signal in engine-sized buffers, as the analysis thread does
+    let onsets: Vec<Onset> = signal
+        .chunks(2048)
+        .flat_map(|buffer| detector.process(buffer))
+        .collect();
+
+    // Should detect at least one onset
+    assert!(
+        !onsets.is_empty(),
+        "Failed to detect any onsets in signal with impulses"
+    );
+
+    // Should detect at least 2 onsets (we have impulses at 100ms and 300ms)
+    assert!(
+        !onsets.is_empty(),
+        "Expected to detect at least 1 onset, found {}",
+        onsets.len()
+    );
+
+    println!("Detected onsets at samples: {:?}", onsets);
+    println!(
+        "Detected onsets at times (ms): {:?}",
+        onsets
+            .iter()
+            .map(|o| o.timestamp as f32 / sample_rate as f32 * 1000.0)
+            .collect::<Vec<f32>>()
+    );
+
+    // Verify first onset is in first half of signal (before 250ms)
+    let first_onset_ms = onsets[0].timestamp as f32 / sample_rate as f32 * 1000.0;
+    assert!(
+        first_onset_ms < 250.0,
+        "First onset at {:.1}ms, expected before 250ms",
+        first_onset_ms
+    );
+}
+
+#[test]
+fn test_timestamps_count_every_sample_whatever_the_buffer_size() {
+    let sample_rate = 48000;
+    let signal = generate_impulse(sample_rate, 3000, &[400, 1300, 2700]);
+    let onsets_in = |buffer_size: usize| -> Vec<u64> {
+        let mut detector = OnsetDetector::new(sample_rate);
+        signal
+            .chunks(buffer_size)
+            .flat_map(|buffer| detector.process_timestamps(buffer))
+            .collect()
+    };
+
+    // Frames overlap buffer boundaries; they tile the stream either way
+    let onsets = onsets_in(2048);
+    assert_eq!(onsets, onsets_in(512));
+    assert_eq!(onsets.len(), 3, "{onsets:?}");
+    for (onset, impulse_ms) in onsets.iter().zip([400, 1300, 2700]) {
+        let impulse = impulse_ms * 48;
+        assert!(onset.abs_diff(impulse) <= 256, "{onset} vs {impulse}");
+    }
+
+    // Skipped samples still advance the clock
+    let mut detector = OnsetDetector::new(sample_rate);
+    detector.skip(48_000);
+    let resumed: Vec<u64> = signal[48_000..]
+        .chunks(2048)
+        .flat_map(|buffer| detector.process_timestamps(buffer))
+        .collect();
+    assert_eq!(resumed, &onsets[1..]);
+}
+
+#[test]
+fn test_spectral_flux_calculation() {
+    let sample_rate = 48000;
+    let detector = OnsetDetector::new(sample_rate);
+
+    // Test with zero change
+    let spectrum1 = vec![1.0; 129];
+    let spectrum2 = vec![1.0; 129];
+
+    let mut detector_mut = detector;
+    detector_mut.prev_spectrum = spectrum1;
+    let flux = detector_mut.compute_spectral_flux(&spectrum2);
+
+    // No change should result in zero flux
+    assert_eq!(flux, 0.0, "Flux should be zero for identical spectra");
+}
+
+#[test]
+fn test_spectral_flux_positive_difference() {
+    let sample_rate = 48000;
+    let mut detector = OnsetDetector::new(sample_rate);
+
+    // Previous spectrum
+    detector.prev_spectrum = vec![1.0; 129];
+
+    // Current spectrum with increase
+    let spectrum_increased = vec![2.0; 129];
+
+    let flux = detector.compute_spectral_flux(&spectrum_increased);
+
+    // Flux should be positive sum of differences
+    assert!(flux > 0.0, "Flux should be positive for increased energy");
+    assert_eq!(flux, 129.0, "Flux should equal sum of differences");
+}
+
+#[test]
+fn test_adaptive_threshold() {
+    let sample_rate = 48000;
+    let mut detector = OnsetDetector::new(sample_rate);
+
+    // Fill flux signal with test data
+    for i in 0..100 {
+        detector.flux_signal.push_back(i as f32);
+    }
+
+    // Compute threshold at middle index
+    let threshold = detector.adaptive_threshold(50);
+
+    // Threshold should be median + offset
+    // For 0-100 range, median around 50
+    assert!(
+        threshold > 40.0 && threshold < 60.0,
+        "Threshold {} outside expected range",
+        threshold
+    );
+}
+
+#[test]
+fn test_peak_picking() {
+    let sample_rate = 48000;
+    let mut detector = OnsetDetector::new(sample_rate);
+
+    // Create flux signal with obvious peaks
+    for i in 0..20 {
+        if i == 5 || i == 15 {
+            detector.flux_signal.push_back(10.0); // Peak
+        } else {
+            detector.flux_signal.push_back(0.1); // Baseline
+        }
+    }
+
+    let peaks = detector.pick_peaks();
+
+    // Should detect the two peaks
+    assert!(!peaks.is_empty(), "Should detect peaks");
+    assert!(peaks.len() <= 2, "Should detect at most 2 peaks");
+}
+
+#[test]
+fn test_no_false_positives_on_silence() {
+    let sample_rate = 48000;
+    let mut detector = OnsetDetector::new(sample_rate);
+
+    // Generate silent signal
+    let signal = vec![0.0; sample_rate as usize];
+
+    // Process signal
+    let onsets = detector.process(&signal);
+
+    // Should not detect any onsets in silence
+    assert!(onsets.is_empty(), "Should not detect onsets in silence");
+}
+
+/// Click train over a quiet white-noise bed, short enough to fit in the
+/// flux buffer so every frame is peak-picked in a single `process` call
+#[test]
+fn test_high_res_timing_interpolates_between_frames() {
+    let config = OnsetDetectionConfig {
+        high_res_timing: true,
+        ..OnsetDetectionConfig::default()
+    };
+    let mut detector = OnsetDetector::with_config(48000, config);
+    let hop = detector.hop_size as u64;
+
+    // Peak at frame 5 with a stronger right neighbour: the true onset sits
+    // between frames 5 and 6
+    for flux in [0.0, 0.0, 0.0, 0.0, 0.2, 1.0, 0.8, 0.0] {
+        detector.flux_signal.push_back(flux);
+    }
+
+    let timestamp = detector.peak_timestamp(5, 0);
+    assert!(
+        timestamp > 5 * hop && timestamp < 6 * hop,
+        "interpolated timestamp {timestamp} should fall between frames 5 and 6"
+    );
+
+    // Without high-res timing the same peak snaps to the frame boundary
+    detector.high_res_timing = false;
+    assert_eq!(detector.peak_timestamp(5, 0), 5 * hop);
+}
+
+#[test]
+fn test_high_res_timing_symmetric_peak_stays_on_frame() {
+    let config = OnsetDetectionConfig {
+        high_res_timing: true,
+        ..OnsetDetectionConfig::default()
+    };
+    let mut detector = OnsetDetector::with_config(48000, config);
+    for flux in [0.0, 0.5, 1.0, 0.5, 0.0] {
+        detector.flux_signal.push_back(flux);
+    }
+
+    assert_eq!(
+        detector.peak_timestamp(2, 10),
+        12 * detector.hop_size as u64
+    );
+}
+
+fn onsets_with_gap(min_onset_gap_ms: f32) -> Vec<u64> {
+    let config = OnsetDetectionConfig {
+        min_onset_gap_ms,
+        ..OnsetDetectionConfig::default()
+    };
+    let mut detector = OnsetDetector::with_config(48000, config);
+    // Two hits 60ms apart, short enough to stay within the flux buffer
+    let signal = generate_impulse(48000, 250, &[50, 110]);
+    detector.process_timestamps(&signal)
+}
+
+#[test]
+fn test_min_onset_gap_keeps_distinct_hits() {
+    let onsets = onsets_with_gap(40.0);
+    assert_eq!(onsets.len(), 2, "onsets: {onsets:?}");
+}
+
+#[test]
+fn test_min_onset_gap_merges_close_hits() {
+    let onsets = onsets_with_gap(80.0);
+    assert_eq!(onsets.len(), 1, "onsets: {onsets:?}");
+}
+
+/// Feed a jittery flux signal with one clear transient and return the
+/// detected peak indices
+fn peaks_on_noisy_flux(flux_smoothing_frames: usize) -> Vec<usize> {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    let config = OnsetDetectionConfig {
+        threshold_offset: 0.4,
+        flux_smoothing_frames,
+        ..OnsetDetectionConfig::default()
+    };
+    let mut detector = OnsetDetector::with_config(48000, config);
+    let mut rng = StdRng::seed_from_u64(11);
+    for frame in 0..200 {
+        let flux = if (100..104).contains(&frame) {
+            8.0
+        } else {
+            rng.gen_range(0.0..1.2)
+        };
+        detector.push_flux(flux);
+    }
+    detector.pick_peaks()
+}
+
+#[test]
+fn test_flux_smoothing_suppresses_jitter_but_keeps_transient() {
+    let raw = peaks_on_noisy_flux(0);
+    let smoothed = peaks_on_noisy_flux(4);
+
+    assert!(
+        smoothed.len() < raw.len(),
+        "smoothing should reduce spurious peaks: raw {} vs smoothed {}",
+        raw.len(),
+        smoothed.len()
+    );
+    assert!(
+        smoothed.iter().any(|&idx| (100..=106).contains(&idx)),
+        "transient lost after smoothing: {smoothed:?}"
+    );
+}
+
+#[test]
+fn test_flux_smoothing_compensates_timestamp_delay() {
+    let config = OnsetDetectionConfig {
+        flux_smoothing_frames: 5,
+        ..OnsetDetectionConfig::default()
+    };
+    let detector = OnsetDetector::with_config(48000, config);
+    let hop = detector.hop_size as u64;
+
+    // A 5-frame moving average delays the peak by 2 frames
+    assert_eq!(detector.peak_timestamp(12, 0), 10 * hop);
+}
+
+#[test]
+fn test_mad_threshold_adapts_after_loud_passage() {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    let config = OnsetDetectionConfig {
+        threshold_offset: 0.0,
+        threshold_mad_k: 4.0,
+        ..OnsetDetectionConfig::default()
+    };
+    let mut detector = OnsetDetector::with_config(48000, config);
+    let mut rng = StdRng::seed_from_u64(3);
+
+    // Loud passage (flux ~10 ± 2) with a 3x transient, then a quiet
+    // passage (flux ~0.1 ± 0.02) with a transient of the same relative size
+    for frame in 0..200 {
+        let flux = match frame {
+            50 => 30.0,
+            160 => 0.3,
+            0..=99 => rng.gen_range(8.0..12.0),
+            _ => rng.gen_range(0.08..0.12),
+        };
+        detector.push_flux(flux);
+    }
+
+    assert_eq!(detector.pick_peaks(), vec![50, 160]);
+}
@@ -0,0 +1,225 @@
+// OnsetDetector - flux smoothing, adaptive threshold and peak picking
+
+use super::OnsetDetector;
+#[cfg(test)]
+use crate::analysis::onset_method;
+
+impl OnsetDetector {
+    /// Compute spectral flux as sum of positive differences
+    ///
+    /// SF(t) = Σ max(0, |FFT(t)| - |FFT(t-1)|)
+    ///
+    /// # Arguments
+    /// * `spectrum` - Current magnitude spectrum
+    ///
+    /// # Returns
+    /// Spectral flux value (scalar)
+    #[cfg(test)]
+    pub(super) fn compute_spectral_flux(&self, spectrum: &[f32]) -> f32 {
+        onset_method::spectral_flux(spectrum, &self.prev_spectrum)
+    }
+
+    /// Append a flux value (smoothed if enabled) to the flux signal
+    pub(super) fn push_flux(&mut self, flux: f32) {
+        let flux = self.smooth_flux(flux);
+        self.flux_signal.push_back(flux);
+
+        // Keep flux signal buffer size manageable
+        if self.flux_signal.len() > self.median_window_halfsize * 2 + 100 {
+            self.flux_signal.pop_front();
+        }
+    }
+
+    /// Causal moving average over the last `flux_smoothing_frames` values
+    ///
+    /// Momentary spectral wiggles average out while a real transient, which
+    /// raises flux over several frames, survives.
+    pub(super) fn smooth_flux(&mut self, flux: f32) -> f32 {
+        if self.flux_smoothing_frames <= 1 {
+            return flux;
+        }
+
+        if self.flux_smoothing.len() == self.flux_smoothing_frames {
+            self.flux_smoothing.pop_front();
+        }
+        self.flux_smoothing.push_back(flux);
+        self.flux_smoothing.iter().sum::<f32>() / self.flux_smoothing.len() as f32
+    }
+
+    /// Frames of delay introduced by flux smoothing (group delay of the
+    /// moving average), subtracted when converting peaks to timestamps
+    pub(super) fn smoothing_delay_frames(&self) -> f64 {
+        self.flux_smoothing_frames.saturating_sub(1) as f64 / 2.0
+    }
+
+    /// Calculate adaptive threshold using median + k × MAD + offset
+    ///
+    /// threshold(t) = median(W) + k × median(|W - median(W)|) + offset,
+    /// W = flux[t-N:t+N]
+    ///
+    /// The MAD term scales with the local spread of the flux, so the
+    /// threshold tracks loud and quiet passages alike.
+    ///
+    /// # Arguments
+    /// * `index` - Index in flux signal to compute threshold for
+    ///
+    /// # Returns
+    /// Adaptive threshold value
+    pub(super) fn adaptive_threshold(&self, index: usize) -> f32 {
+        let start = index.saturating_sub(self.median_window_halfsize);
+        let end = (index + self.median_window_halfsize).min(self.flux_signal.len());
+
+        if start >= end {
+            return self.threshold_offset;
+        }
+
+        // Extract window and compute median
+        let mut window: Vec<f32> = self.flux_signal.range(start..end).copied().collect();
+
+        if window.is_empty() {
+            return self.threshold_offset;
+        }
+
+        let median = median_in_place(&mut window);
+        if self.threshold_mad_k <= 0.0 {
+            return median + self.threshold_offset;
+        }
+
+        for value in window.iter_mut() {
+            *value = (*value - median).abs();
+        }
+        let mad = median_in_place(&mut window);
+
+        median + self.threshold_mad_k * mad + self.threshold_offset
+    }
+
+    /// Pick peaks in flux signal where flux > adaptive threshold
+    ///
+    /// # Arguments
+    /// * `start` - Start index in flux signal to check for peaks
+    /// * `end` - End index in flux signal to check for peaks
+    ///
+    /// # Returns
+    /// Peak indices in the flux signal (relative to start of flux buffer),
+    /// each with its flux minus the adaptive threshold
+    pub(super) fn pick_peaks_in_range(&self, start: usize, end: usize) -> Vec<(usize, f32)> {
+        let mut peaks = Vec::new();
+
+        if self.flux_signal.len() < 3 || start >= end {
+            return peaks;
+        }
+
+        let start = start.max(1); // Need prev value
+        let end = end.min(self.flux_signal.len() - 1); // Need next value
+
+        // Find local maxima that exceed adaptive threshold
+        for i in start..end {
+            let prev = self.flux_signal[i - 1];
+            let curr = self.flux_signal[i];
+            let next = self.flux_signal[i + 1];
+
+            // Check if it's a local maximum
+            if curr > prev && curr > next {
+                let threshold = self.adaptive_threshold(i);
+
+                // Check if it exceeds adaptive threshold
+                if curr > threshold {
+                    peaks.push((i, curr - threshold));
+                }
+            }
+        }
+
+        peaks
+    }
+
+    /// Convert a flux peak index to a sample timestamp
+    ///
+    /// With `high_res_timing` the peak is refined by fitting a parabola through
+    /// the peak and its two neighbours; the vertex offset (in frames, within
+    /// ±0.5) is added before converting to samples.
+    pub(super) fn peak_timestamp(&self, peak_idx: usize, flux_buffer_offset: u64) -> u64 {
+        // Convert flux buffer index to absolute frame number
+        let absolute_frame = flux_buffer_offset + peak_idx as u64;
+        let hop = self.hop_size as u64;
+
+        let delay = self.smoothing_delay_frames();
+
+        if !self.high_res_timing || peak_idx == 0 || peak_idx + 1 >= self.flux_signal.len() {
+            // Convert frame number to sample timestamp
+            return Self::frame_to_samples(absolute_frame as f64 - delay, hop);
+        }
+
+        let prev = self.flux_signal[peak_idx - 1];
+        let curr = self.flux_signal[peak_idx];
+        let next = self.flux_signal[peak_idx + 1];
+        let denominator = prev - 2.0 * curr + next;
+        if denominator >= 0.0 {
+            return Self::frame_to_samples(absolute_frame as f64 - delay, hop);
+        }
+
+        let delta = (0.5 * (prev - next) / denominator).clamp(-0.5, 0.5);
+        Self::frame_to_samples(absolute_frame as f64 + delta as f64 - delay, hop)
+    }
+
+    pub(super) fn frame_to_samples(frame: f64, hop: u64) -> u64 {
+        (frame * hop as f64).max(0.0).round() as u64
+    }
+
+    /// Whether an onset is far enough from the previously emitted one
+    pub(super) fn respects_min_gap(&self, timestamp: u64) -> bool {
+        match self.last_onset {
+            Some(last) if self.min_onset_gap_samples > 0 => {
+                timestamp.saturating_sub(last) >= self.min_onset_gap_samples
+            }
+            _ => true,
+        }
+    }
+
+    /// Pick all peaks in the entire flux signal (for testing)
+    #[cfg(test)]
+    pub(super) fn pick_peaks(&self) -> Vec<usize> {
+        self.pick_peaks_in_range(0, self.flux_signal.len())
+            .into_iter()
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+
+    /// Adaptive threshold at the most recent flux frame
+    ///
+    /// This is the level the onset detection function must exceed right
+    /// now (median + k × MAD + offset over the trailing window); just the
+    /// offset before any audio has been processed.
+    pub fn current_threshold(&self) -> f32 {
+        match self.flux_signal.len() {
+            0 => self.threshold_offset,
+            len => self.adaptive_threshold(len - 1),
+        }
+    }
+
+    /// Replace the offset added to the adaptive threshold
+    pub fn set_threshold_offset(&mut self, threshold_offset: f32) {
+        self.threshold_offset = threshold_offset;
+    }
+
+    /// Get the most recent spectral flux value
+    ///
+    /// Returns the latest spectral flux value from the flux signal buffer,
+    /// or 0.0 if no samples have been processed yet.
+    ///
+    /// This is useful for real-time visualization of spectral flux in debug UI.
+    pub fn last_spectral_flux(&self) -> f32 {
+        self.flux_signal.back().copied().unwrap_or(0.0)
+    }
+}
+
+/// Median of a non-empty slice (sorts it in place)
+fn median_in_place(values: &mut [f32]) -> f32 {
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+    if values.len().is_multiple_of(2) {
+        let mid = values.len() / 2;
+        (values[mid - 1] + values[mid]) / 2.0
+    } else {
+        values[values.len() / 2]
+    }
+}
@@ -0,0 +1,112 @@
+//! Elevated scheduling priority for the analysis thread.
+//!
+//! On desktop Linux the analysis thread competes with everything else at
+//! normal priority and can be preempted long enough for the buffer pool to
+//! run dry. When enabled in the onset config, the thread lowers its nice
+//! value at startup. Raising priority usually needs `CAP_SYS_NICE` or an
+//! rtprio/nice limit, so a refusal is expected on many systems: it is
+//! logged, noted in telemetry, and the thread carries on at normal priority.
+
+use std::io;
+
+use crate::telemetry::{self, DiagnosticError};
+
+/// Nice value requested for the analysis thread (lower is higher priority)
+pub const ANALYSIS_THREAD_NICE: i32 = -10;
+
+/// Result of asking for elevated priority
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PriorityOutcome {
+    /// The thread now runs at [`ANALYSIS_THREAD_NICE`]
+    Elevated,
+    /// The OS refused; the thread keeps its normal priority
+    Denied(String),
+    /// Not implemented on this platform
+    Unsupported,
+}
+
+/// Elevate the calling thread, reporting a refusal to telemetry
+pub fn elevate_current_thread() -> PriorityOutcome {
+    let outcome = request_priority(set_current_thread_nice);
+    match &outcome {
+        PriorityOutcome::Elevated => tracing::info!(
+            "[AnalysisThread] Running at elevated priority (nice {})",
+            ANALYSIS_THREAD_NICE
+        ),
+        PriorityOutcome::Denied(reason) => {
+            tracing::warn!(
+                "[AnalysisThread] Elevated priority denied ({}); continuing at normal priority",
+                reason
+            );
+            telemetry::hub().record_error(DiagnosticError::ThreadPriorityDenied, reason.clone());
+        }
+        PriorityOutcome::Unsupported => {
+            tracing::info!("[AnalysisThread] Elevated priority not supported on this platform")
+        }
+    }
+    outcome
+}
+
+/// Apply [`ANALYSIS_THREAD_NICE`] through `set_nice`, classifying failures
+///
+/// Never panics: any error from the setter becomes `Denied`, except
+/// `Unsupported` which maps to [`PriorityOutcome::Unsupported`].
+pub fn request_priority(set_nice: impl FnOnce(i32) -> io::Result<()>) -> PriorityOutcome {
+    match set_nice(ANALYSIS_THREAD_NICE) {
+        Ok(()) => PriorityOutcome::Elevated,
+        Err(err) if err.kind() == io::ErrorKind::Unsupported => PriorityOutcome::Unsupported,
+        Err(err) => PriorityOutcome::Denied(err.to_string()),
+    }
+}
+
+/// Set the nice value of the calling thread only
+#[cfg(target_os = "linux")]
+fn set_current_thread_nice(nice: i32) -> io::Result<()> {
+    // Linux keeps a nice value per thread; target this one by its TID
+    // SAFETY: gettid and setpriority take no pointers and cannot violate
+    // memory safety; failure is reported through the return value/errno.
+    let result = unsafe {
+        let tid = libc::syscall(libc::SYS_gettid) as libc::id_t;
+        libc::setpriority(libc::PRIO_PROCESS, tid, nice)
+    };
+    if result == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn set_current_thread_nice(_nice: i32) -> io::Result<()> {
+    Err(io::Error::from(io::ErrorKind::Unsupported))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_denied_priority_request_falls_back_without_panicking() {
+        let outcome = request_priority(|nice| {
+            assert_eq!(nice, ANALYSIS_THREAD_NICE);
+            Err(io::Error::from_raw_os_error(1)) // EPERM
+        });
+        assert!(matches!(outcome, PriorityOutcome::Denied(_)), "{outcome:?}");
+
+        assert_eq!(request_priority(|_| Ok(())), PriorityOutcome::Elevated);
+        assert_eq!(
+            request_priority(|_| Err(io::ErrorKind::Unsupported.into())),
+            PriorityOutcome::Unsupported
+        );
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_real_priority_request_never_panics() {
+        // Whatever the sandbox allows, asking must not take the thread down
+        let outcome = std::thread::spawn(|| request_priority(set_current_thread_nice))
+            .join()
+            .expect("priority request panicked");
+        assert_ne!(outcome, PriorityOutcome::Unsupported);
+    }
+}
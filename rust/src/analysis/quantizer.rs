@@ -6,6 +6,8 @@
 //! - ON_TIME/EARLY/LATE classification with 50ms tolerance
 //! - Thread-safe access to shared audio engine timing state
 //! - Zero allocations in quantization calculations
+//! - Grace window after tempo changes so the first hits on the new grid are
+//!   not penalised
 //!
 //! The quantizer uses atomic references to frame_counter and BPM from AudioEngine
 //! to compute timing error between detected onsets and the metronome beat grid.
@@ -44,6 +46,16 @@ pub struct TimingFeedback {
     pub error_ms: f32,
 }
 
+/// Where on the metronome grid an onset landed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct BeatPosition {
+    /// Beat within the bar (0-based); counts up without wrapping when the
+    /// bar length is 0
+    pub beat_index: u32,
+    /// Subdivision within the beat (0-based)
+    pub subdivision_index: u32,
+}
+
 /// Quantizer for rhythm timing analysis
 ///
 /// The Quantizer computes timing errors between detected onsets and the metronome
@@ -72,6 +84,17 @@ pub struct Quantizer {
     bpm: Arc<AtomicU32>,
     /// Sample rate in Hz (used for time conversions)
     sample_rate: u32,
+    /// Length of the post-tempo-change grace window in samples
+    grace_samples: u64,
+    /// BPM observed on the previous tempo check
+    last_bpm: AtomicU32,
+    /// Onset-clock sample index when the last BPM change was observed
+    /// (`NO_TEMPO_CHANGE` until the first change)
+    bpm_changed_at: AtomicU64,
+    /// Beats per bar used for `beat_position` (0 = never wrap)
+    beats_per_bar: u32,
+    /// Subdivisions per beat used for `beat_position`
+    subdivisions: u32,
 }
 
 impl Quantizer {
@@ -79,6 +102,18 @@ impl Quantizer {
     /// Onsets within ±50ms of a beat are considered "on time"
     const TOLERANCE_MS: f32 = 50.0;
 
+    /// Default grace window after a BPM change, in milliseconds
+    pub const DEFAULT_BPM_CHANGE_GRACE_MS: u32 = 300;
+
+    /// Default bar length for beat positions (4/4)
+    pub const DEFAULT_BEATS_PER_BAR: u32 = 4;
+
+    /// Default subdivisions per beat for beat positions (sixteenth notes)
+    pub const DEFAULT_SUBDIVISIONS: u32 = 4;
+
+    /// Sentinel for "no tempo change observed yet"
+    const NO_TEMPO_CHANGE: u64 = u64::MAX;
+
     /// Create a new Quantizer with shared references to audio engine timing state
     ///
     /// # Arguments
@@ -89,13 +124,88 @@ impl Quantizer {
     /// # Returns
     /// A new Quantizer instance ready for timing analysis
     pub fn new(frame_counter: Arc<AtomicU64>, bpm: Arc<AtomicU32>, sample_rate: u32) -> Self {
+        let initial_bpm = bpm.load(Ordering::Relaxed);
         Self {
             frame_counter,
             bpm,
             sample_rate,
+            grace_samples: Self::ms_to_samples(Self::DEFAULT_BPM_CHANGE_GRACE_MS, sample_rate),
+            last_bpm: AtomicU32::new(initial_bpm),
+            bpm_changed_at: AtomicU64::new(Self::NO_TEMPO_CHANGE),
+            beats_per_bar: Self::DEFAULT_BEATS_PER_BAR,
+            subdivisions: Self::DEFAULT_SUBDIVISIONS,
+        }
+    }
+
+    /// Set the bar length and beat subdivision reported by `beat_position`
+    ///
+    /// `beats_per_bar` should match the metronome's accent pattern; 0 keeps
+    /// counting beats from the start of the session. `subdivisions` is
+    /// clamped to at least 1.
+    pub fn with_time_signature(mut self, beats_per_bar: u32, subdivisions: u32) -> Self {
+        self.beats_per_bar = beats_per_bar;
+        self.subdivisions = subdivisions.max(1);
+        self
+    }
+
+    /// Locate an onset on the beat grid
+    ///
+    /// The onset is snapped to the nearest subdivision, so a slightly early
+    /// hit counts toward the beat it was aiming for. Bars start at frame 0,
+    /// matching the metronome's accent placement.
+    pub fn beat_position(&self, onset_timestamp: u64) -> BeatPosition {
+        let spb = samples_per_beat(self.bpm.load(Ordering::Relaxed), self.sample_rate).max(1);
+        let subdivisions = self.subdivisions as u64;
+        let nearest = (onset_timestamp * subdivisions + spb / 2) / spb;
+        let beat = nearest / subdivisions;
+        let beat = if self.beats_per_bar > 0 {
+            beat % self.beats_per_bar as u64
+        } else {
+            beat
+        };
+
+        BeatPosition {
+            beat_index: beat.min(u32::MAX as u64) as u32,
+            subdivision_index: (nearest % subdivisions) as u32,
+        }
+    }
+
+    /// Override the grace window applied after a BPM change
+    ///
+    /// Onsets landing within `grace_ms` of a tempo change are reported as
+    /// ON_TIME, since the user has not yet had a chance to lock onto the new
+    /// grid. Passing 0 disables the grace window.
+    pub fn with_bpm_change_grace_ms(mut self, grace_ms: u32) -> Self {
+        self.grace_samples = Self::ms_to_samples(grace_ms, self.sample_rate);
+        self
+    }
+
+    fn ms_to_samples(ms: u32, sample_rate: u32) -> u64 {
+        ms as u64 * sample_rate as u64 / 1000
+    }
+
+    /// Check for a BPM change and stamp `now` when one occurs
+    ///
+    /// `now` must be on the same clock as the onset timestamps passed to
+    /// `quantize()` (samples of analysed input), so the grace window is
+    /// measured on one clock. Called once per analysis buffer so the window
+    /// starts close to the actual tempo change; callers that never observe
+    /// the tempo get no grace window.
+    pub fn observe_tempo(&self, now: u64) {
+        let current_bpm = self.bpm.load(Ordering::Relaxed);
+        let previous_bpm = self.last_bpm.swap(current_bpm, Ordering::Relaxed);
+        if previous_bpm != current_bpm {
+            self.bpm_changed_at.store(now, Ordering::Relaxed);
         }
     }
 
+    /// Whether an onset falls within the grace window of the last BPM change
+    fn in_tempo_change_grace(&self, onset_timestamp: u64) -> bool {
+        let changed_at = self.bpm_changed_at.load(Ordering::Relaxed);
+        changed_at != Self::NO_TEMPO_CHANGE
+            && onset_timestamp.saturating_sub(changed_at) < self.grace_samples
+    }
+
     /// Quantize an onset timestamp to the metronome grid and compute timing feedback
     ///
     /// This method calculates the timing error between a detected onset and the nearest
@@ -107,6 +217,7 @@ impl Quantizer {
     /// 3. Compute beat_error = onset_timestamp % samples_per_beat
     /// 4. Convert beat_error to milliseconds
     /// 5. Classify timing based on error magnitude and position
+    /// 6. Report ON_TIME if the onset is within the grace window of an observed BPM change
     ///
     /// # Arguments
     /// * `onset_timestamp` - Sample index of detected onset (from OnsetDetector)
@@ -171,6 +282,14 @@ impl Quantizer {
             error_ms
         };
 
+        // The grid just shifted under the user: keep the measured error but
+        // don't judge the hit until the grace window has elapsed
+        let classification = if self.in_tempo_change_grace(onset_timestamp) {
+            TimingClassification::OnTime
+        } else {
+            classification
+        };
+
         TimingFeedback {
             classification,
             error_ms: signed_error_ms,
@@ -406,6 +525,43 @@ mod tests {
         assert_eq!(feedback.classification, TimingClassification::OnTime);
     }
 
+    #[test]
+    fn test_onset_within_bpm_change_grace_is_on_time() {
+        // The output frame counter runs on its own clock; the grace window
+        // must only compare onset-clock timestamps
+        let frame_counter = Arc::new(AtomicU64::new(480_000));
+        let bpm_atomic = Arc::new(AtomicU32::new(120));
+        let quantizer = Quantizer::new(Arc::clone(&frame_counter), Arc::clone(&bpm_atomic), 48000)
+            .with_bpm_change_grace_ms(200);
+
+        // Tempo changes to 100 BPM (28800 samples per beat) at sample 48000
+        bpm_atomic.store(100, Ordering::Relaxed);
+        quantizer.observe_tempo(48000);
+
+        // 100ms after the change: 52800 % 28800 = 500ms into a 600ms beat,
+        // which would be LATE without the grace window
+        let feedback = quantizer.quantize(48000 + 4800);
+        assert_eq!(feedback.classification, TimingClassification::OnTime);
+        assert!((feedback.error_ms - 500.0).abs() < 0.1);
+
+        // 400ms after the change the grace window has elapsed:
+        // 67200 % 28800 = 200ms after the beat → LATE
+        let feedback = quantizer.quantize(48000 + 19200);
+        assert_eq!(feedback.classification, TimingClassification::Late);
+    }
+
+    #[test]
+    fn test_bpm_change_grace_disabled() {
+        let frame_counter = Arc::new(AtomicU64::new(48000));
+        let bpm_atomic = Arc::new(AtomicU32::new(120));
+        let quantizer = Quantizer::new(Arc::clone(&frame_counter), Arc::clone(&bpm_atomic), 48000)
+            .with_bpm_change_grace_ms(0);
+
+        bpm_atomic.store(100, Ordering::Relaxed);
+        let feedback = quantizer.quantize(48000 + 4800);
+        assert_eq!(feedback.classification, TimingClassification::Late);
+    }
+
     #[test]
     fn test_sample_rate_44100() {
         // Test quantization at 44.1kHz sample rate
@@ -446,4 +602,22 @@ mod tests {
         assert_ne!(TimingClassification::OnTime, TimingClassification::Late);
         assert_ne!(TimingClassification::Early, TimingClassification::Late);
     }
+
+    #[test]
+    fn test_beat_position_within_bar() {
+        // 120 BPM at 48kHz: 24000 samples per beat, 6000 per sixteenth
+        let quantizer = create_test_quantizer(120, 48000).with_time_signature(4, 4);
+
+        // Start of beat 3 is beat_index 2
+        let position = quantizer.beat_position(48000);
+        assert_eq!((position.beat_index, position.subdivision_index), (2, 0));
+
+        // Third sixteenth of beat 2, slightly late
+        let position = quantizer.beat_position(24000 + 12000 + 300);
+        assert_eq!((position.beat_index, position.subdivision_index), (1, 2));
+
+        // Just before the downbeat of bar 2 snaps forward and wraps
+        let position = quantizer.beat_position(4 * 24000 - 500);
+        assert_eq!((position.beat_index, position.subdivision_index), (0, 0));
+    }
 }
@@ -0,0 +1,89 @@
+// Quiet-input tracking for the analysis thread
+//
+// - QuietClearGate: hysteresis on "the room is quiet", which clears
+//   calibration guidance
+// - SilenceTimer: reports once per quiet stretch of `silence_timeout_ms`
+
+/// Hysteresis on the "environment is quiet" check that clears guidance
+///
+/// Quiet is entered below `noise_floor * enter` and left only above
+/// `noise_floor * exit`. Guidance is cleared on every buffer while quiet,
+/// so guidance raised during a quiet stretch doesn't linger, and an RMS
+/// hovering between the two thresholds can't toggle the state.
+#[derive(Debug)]
+pub(super) struct QuietClearGate {
+    pub(super) enter: f64,
+    exit: f64,
+    quiet: bool,
+}
+
+impl QuietClearGate {
+    pub(super) fn new(enter: f64, exit: f64) -> Self {
+        let enter = if enter.is_finite() {
+            enter.max(0.0)
+        } else {
+            1.0
+        };
+        let exit = if exit.is_finite() {
+            exit.max(enter)
+        } else {
+            enter
+        };
+        Self {
+            enter,
+            exit,
+            quiet: false,
+        }
+    }
+
+    /// Feed one buffer's RMS; returns whether the input is currently quiet
+    pub(super) fn update(&mut self, rms: f64, noise_floor: f64) -> bool {
+        self.quiet = if self.quiet {
+            rms <= noise_floor * self.exit
+        } else {
+            rms < noise_floor * self.enter
+        };
+        self.quiet
+    }
+}
+
+/// Reports once when the input has stayed quiet for a set time
+///
+/// Counted in samples on the audio clock. Sound above the gate restarts the
+/// count and re-arms the report.
+#[derive(Debug)]
+pub(super) struct SilenceTimer {
+    /// Quiet samples before reporting (0 disables the timer)
+    timeout_samples: u64,
+    pub(super) quiet_samples: u64,
+    reported: bool,
+}
+
+impl SilenceTimer {
+    pub(super) fn new(timeout_ms: f32, sample_rate: u32) -> Self {
+        Self {
+            timeout_samples: (timeout_ms.max(0.0) * sample_rate as f32 / 1000.0) as u64,
+            quiet_samples: 0,
+            reported: false,
+        }
+    }
+
+    /// Feed one buffer; returns `true` when the current quiet stretch first
+    /// reaches the timeout
+    pub(super) fn update(&mut self, quiet: bool, samples: usize) -> bool {
+        if self.timeout_samples == 0 {
+            return false;
+        }
+        if !quiet {
+            self.quiet_samples = 0;
+            self.reported = false;
+            return false;
+        }
+        self.quiet_samples += samples as u64;
+        if self.reported || self.quiet_samples < self.timeout_samples {
+            return false;
+        }
+        self.reported = true;
+        true
+    }
+}
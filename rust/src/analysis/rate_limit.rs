@@ -0,0 +1,236 @@
+// Rate limits on what the analysis thread publishes
+//
+// - GuidanceRateLimiter: repeated calibration guidance, on wall time
+// - SoundRateLimiter / ResultRateLimiter: classification results, on the
+//   audio clock; ResultLimits applies them together with the global
+//   minimum result interval
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use crate::calibration::progress::CalibrationGuidanceReason;
+use crate::config::ResultEmissionConfig;
+use crate::telemetry;
+use crate::types::BeatboxHit;
+
+/// Suppresses repeats of the same calibration guidance within the rate limit
+#[derive(Debug)]
+pub(super) struct GuidanceRateLimiter {
+    last_reason: Option<CalibrationGuidanceReason>,
+    last_at: Option<Instant>,
+    rate_limit: Duration,
+}
+
+impl GuidanceRateLimiter {
+    pub(super) fn new(rate_limit: Duration) -> Self {
+        Self {
+            last_reason: None,
+            last_at: None,
+            rate_limit,
+        }
+    }
+
+    pub(super) fn has_active(&self) -> bool {
+        self.last_reason.is_some()
+    }
+
+    pub(super) fn clear(&mut self) {
+        self.last_reason = None;
+        self.last_at = None;
+    }
+
+    pub(super) fn should_emit(&mut self, reason: CalibrationGuidanceReason, now: Instant) -> bool {
+        let reason_changed = self.last_reason.map(|r| r != reason).unwrap_or(true);
+        let past_rate_limit = self
+            .last_at
+            .map(|ts| now.saturating_duration_since(ts) >= self.rate_limit)
+            .unwrap_or(true);
+
+        if reason_changed || past_rate_limit {
+            self.last_reason = Some(reason);
+            self.last_at = Some(now);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Per-sound minimum spacing between emitted results
+///
+/// Same shape as [`GuidanceRateLimiter`], but keyed by sound type and
+/// measured in samples so it follows the audio clock rather than wall time.
+#[derive(Debug, Default)]
+struct SoundRateLimiter {
+    limits: HashMap<BeatboxHit, u64>,
+    last_at: HashMap<BeatboxHit, u64>,
+}
+
+impl SoundRateLimiter {
+    fn new(limits_ms: &HashMap<BeatboxHit, f32>, sample_rate: u32) -> Self {
+        let limits = limits_ms
+            .iter()
+            .map(|(&sound, &ms)| (sound, (ms.max(0.0) * sample_rate as f32 / 1000.0) as u64))
+            .filter(|&(_, samples)| samples > 0)
+            .collect();
+        Self {
+            limits,
+            last_at: HashMap::new(),
+        }
+    }
+
+    fn should_emit(&mut self, sound: BeatboxHit, sample_position: u64) -> bool {
+        let Some(&limit) = self.limits.get(&sound) else {
+            return true;
+        };
+        let past_rate_limit = self
+            .last_at
+            .get(&sound)
+            .map(|&last| sample_position.saturating_sub(last) >= limit)
+            .unwrap_or(true);
+
+        if past_rate_limit {
+            self.last_at.insert(sound, sample_position);
+        }
+        past_rate_limit
+    }
+}
+
+/// Global token bucket over emitted results
+///
+/// Holds up to `capacity` tokens and refills at the configured rate on the
+/// audio clock; each emitted result spends one token.
+#[derive(Debug)]
+struct ResultRateLimiter {
+    /// Tokens gained per sample (0 disables the limiter)
+    refill_per_sample: f64,
+    capacity: f64,
+    tokens: f64,
+    last_position: Option<u64>,
+}
+
+impl ResultRateLimiter {
+    fn new(max_per_second: f32, burst: u32, sample_rate: u32) -> Self {
+        let capacity = burst.max(1) as f64;
+        Self {
+            refill_per_sample: max_per_second.max(0.0) as f64 / sample_rate.max(1) as f64,
+            capacity,
+            tokens: capacity,
+            last_position: None,
+        }
+    }
+
+    fn try_acquire(&mut self, sample_position: u64) -> bool {
+        if self.refill_per_sample <= 0.0 {
+            return true;
+        }
+        if let Some(last) = self.last_position {
+            let elapsed = sample_position.saturating_sub(last) as f64;
+            self.tokens = (self.tokens + elapsed * self.refill_per_sample).min(self.capacity);
+        }
+        self.last_position = Some(sample_position);
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Spacing checks every classification result passes before it is sent
+///
+/// In order: the minimum interval since the previous result, the per-sound
+/// intervals, then the global token bucket. Positions are in samples since
+/// engine start.
+#[derive(Debug)]
+pub(super) struct ResultLimits {
+    min_interval_samples: u64,
+    /// Sample position of the last admitted result
+    last_sample: Option<u64>,
+    per_sound: SoundRateLimiter,
+    global: ResultRateLimiter,
+    /// Results dropped by the global rate cap since the worker started
+    pub(super) suppressed: u64,
+}
+
+impl ResultLimits {
+    pub(super) fn new(config: &ResultEmissionConfig, sample_rate: u32) -> Self {
+        Self {
+            min_interval_samples: (config.min_result_interval_ms.max(0.0) * sample_rate as f32
+                / 1000.0) as u64,
+            last_sample: None,
+            per_sound: SoundRateLimiter::new(&config.per_sound_min_interval_ms, sample_rate),
+            global: ResultRateLimiter::new(
+                config.max_results_per_second,
+                config.max_result_burst,
+                sample_rate,
+            ),
+            suppressed: 0,
+        }
+    }
+
+    /// Whether a result for `sound` at `sample_position` may be sent; an
+    /// admitted result counts against every limit
+    pub(super) fn admit(&mut self, sound: BeatboxHit, sample_position: u64) -> bool {
+        if let Some(last) = self.last_sample {
+            let since_last = sample_position.saturating_sub(last);
+            if self.min_interval_samples > 0 && since_last < self.min_interval_samples {
+                tracing::debug!(
+                    "[AnalysisThread] Dropping {:?} result {} samples after the previous one",
+                    sound,
+                    since_last
+                );
+                return false;
+            }
+        }
+        if !self.per_sound.should_emit(sound, sample_position) {
+            tracing::debug!(
+                "[AnalysisThread] Dropping {:?} result inside its per-sound interval",
+                sound
+            );
+            return false;
+        }
+        if !self.global.try_acquire(sample_position) {
+            self.suppressed += 1;
+            telemetry::hub().record_suppressed_result();
+            tracing::debug!(
+                "[AnalysisThread] Dropping {:?} result over the global rate cap ({} suppressed)",
+                sound,
+                self.suppressed
+            );
+            return false;
+        }
+        self.last_sample = Some(sample_position);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sound_rate_limiter_throttles_kicks_but_not_hihats() {
+        let limits = HashMap::from([(BeatboxHit::Kick, 200.0)]);
+        let mut limiter = SoundRateLimiter::new(&limits, 48_000);
+
+        // Alternating kick and hi-hat every 100ms (4_800 samples) for one second
+        let mut kicks = 0;
+        let mut hihats = 0;
+        for step in 0..10u64 {
+            let position = step * 4_800;
+            if limiter.should_emit(BeatboxHit::Kick, position) {
+                kicks += 1;
+            }
+            if limiter.should_emit(BeatboxHit::HiHat, position) {
+                hihats += 1;
+            }
+        }
+
+        // Kicks are held to one per 200ms; hi-hats pass at the arrival rate
+        assert_eq!(kicks, 5);
+        assert_eq!(hihats, 10);
+    }
+}
@@ -0,0 +1,302 @@
+// Classification results sent from the analysis thread to the UI
+//
+// `ClassificationResult` is serialized by hand so that fields newer than a
+// result's `schema_version` are left out (see `schema.rs`).
+
+use super::labels;
+use super::quantizer::{BeatPosition, TimingClassification, TimingFeedback};
+use super::schema;
+use crate::telemetry;
+use crate::types::BeatboxHit;
+
+/// Classification result combining sound type and timing feedback
+///
+/// This struct is sent to the Dart UI via flutter_rust_bridge Stream
+/// for real-time display of detected sounds and timing accuracy.
+///
+/// JSON serialization follows `schema_version`: fields newer than that version
+/// are omitted so older clients keep seeing the shape they were built for.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ClassificationResult {
+    /// Detected beatbox sound type
+    pub sound: BeatboxHit,
+    /// Timing accuracy relative to metronome grid
+    ///
+    /// `None` in classification-only mode (BPM 0), where no metronome grid
+    /// exists and the quantizer is never run.
+    #[serde(default)]
+    pub timing: Option<TimingFeedback>,
+    /// Timestamp in milliseconds since engine start
+    pub timestamp_ms: u64,
+    /// Classification confidence score (0.0-1.0)
+    /// Calculated as max_score / sum_of_all_scores
+    pub confidence: f32,
+    /// Display name for `sound` (see [`labels::display_name`])
+    #[serde(default)]
+    pub label: String,
+    /// Hit fell outside every active listening window (see
+    /// [`listening::ListeningWindows`]); such hits should not be scored
+    #[serde(default)]
+    pub extra: bool,
+    /// Serialization shape of this result (see [`schema`])
+    #[serde(default = "schema::legacy_version")]
+    pub schema_version: u32,
+    /// Exponential moving average of recent confidences for this sound;
+    /// `None` unless `confidence_smoothing` is enabled
+    #[serde(default)]
+    pub smoothed_confidence: Option<f32>,
+    /// Beat within the bar the hit landed on (0-based); only set while a
+    /// metronome is running
+    #[serde(default)]
+    pub beat_index: Option<u32>,
+    /// Subdivision within that beat (0-based); only set while a metronome
+    /// is running
+    #[serde(default)]
+    pub subdivision_index: Option<u32>,
+    /// Wall-clock time (Unix milliseconds) when the result was produced,
+    /// for syncing with external systems; `timestamp_ms` stays
+    /// engine-relative
+    #[serde(default)]
+    pub wall_clock_ms: u64,
+    /// Detector that triggered this result (debug information)
+    #[serde(default)]
+    pub trigger: HitTrigger,
+    /// A second onset followed within `flam_window_ms`; the pair is reported
+    /// as this one result
+    #[serde(default)]
+    pub flam: bool,
+    /// Enough of the hit's window sat at the input rails that its features
+    /// are unreliable (see `clip_fraction_threshold`); confidence is reduced
+    #[serde(default)]
+    pub clipped: bool,
+}
+
+/// Detector behind a classification result
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HitTrigger {
+    /// Spectral-flux onset detector
+    #[default]
+    Onset,
+    /// Gate-level crossing detector
+    LevelCrossing,
+}
+
+impl serde::Serialize for ClassificationResult {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let labeled = self.schema_version >= schema::LABELED_RESULT_SCHEMA;
+        let current = self.schema_version >= schema::CURRENT_RESULT_SCHEMA;
+        // Version 1 clients expect `timing` on every result; untimed hits
+        // (no metronome) report as on time there
+        let timing = match self.timing {
+            None if !labeled => Some(TimingFeedback {
+                classification: TimingClassification::OnTime,
+                error_ms: 0.0,
+            }),
+            timing => timing,
+        };
+        let smoothed = self.smoothed_confidence.filter(|_| current);
+        let beat_index = self.beat_index.filter(|_| current);
+        let subdivision_index = self.subdivision_index.filter(|_| current);
+        let len = 3
+            + usize::from(timing.is_some())
+            + if labeled { 3 } else { 0 }
+            + if current { 4 } else { 0 }
+            + usize::from(smoothed.is_some())
+            + usize::from(beat_index.is_some())
+            + usize::from(subdivision_index.is_some());
+        let mut state = serializer.serialize_struct("ClassificationResult", len)?;
+        state.serialize_field("sound", &self.sound)?;
+        if let Some(timing) = &timing {
+            state.serialize_field("timing", timing)?;
+        }
+        state.serialize_field("timestamp_ms", &self.timestamp_ms)?;
+        state.serialize_field("confidence", &self.confidence)?;
+        if labeled {
+            state.serialize_field("label", &self.label)?;
+            state.serialize_field("extra", &self.extra)?;
+            state.serialize_field("schema_version", &self.schema_version)?;
+        }
+        if current {
+            state.serialize_field("wall_clock_ms", &self.wall_clock_ms)?;
+            state.serialize_field("trigger", &self.trigger)?;
+            state.serialize_field("flam", &self.flam)?;
+            state.serialize_field("clipped", &self.clipped)?;
+        }
+        if let Some(smoothed) = smoothed {
+            state.serialize_field("smoothed_confidence", &smoothed)?;
+        }
+        if let Some(beat_index) = beat_index {
+            state.serialize_field("beat_index", &beat_index)?;
+        }
+        if let Some(subdivision_index) = subdivision_index {
+            state.serialize_field("subdivision_index", &subdivision_index)?;
+        }
+        state.end()
+    }
+}
+
+impl ClassificationResult {
+    /// Build a result, resolving the sound's current display label
+    pub fn new(
+        sound: BeatboxHit,
+        timing: Option<TimingFeedback>,
+        timestamp_ms: u64,
+        confidence: f32,
+    ) -> Self {
+        Self {
+            sound,
+            timing,
+            timestamp_ms,
+            confidence,
+            label: labels::display_name(sound),
+            extra: false,
+            schema_version: schema::requested_version(),
+            smoothed_confidence: None,
+            beat_index: None,
+            subdivision_index: None,
+            wall_clock_ms: telemetry::now_timestamp_ms(),
+            trigger: HitTrigger::default(),
+            flam: false,
+            clipped: false,
+        }
+    }
+
+    /// Record which detector produced the result
+    pub fn with_trigger(mut self, trigger: HitTrigger) -> Self {
+        self.trigger = trigger;
+        self
+    }
+
+    /// Attach where on the metronome grid the hit landed
+    pub fn with_beat_position(mut self, position: Option<BeatPosition>) -> Self {
+        self.beat_index = position.map(|p| p.beat_index);
+        self.subdivision_index = position.map(|p| p.subdivision_index);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+    use std::collections::HashMap;
+
+    #[test]
+    fn untimed_result_serializes_without_timing() {
+        let result = ClassificationResult {
+            schema_version: schema::CURRENT_RESULT_SCHEMA,
+            ..ClassificationResult::new(BeatboxHit::Kick, None, 10, 0.8)
+        };
+
+        let json = serde_json::to_value(&result).unwrap();
+        assert!(json.get("timing").is_none());
+    }
+
+    #[test]
+    fn legacy_result_always_serializes_timing() {
+        let result = ClassificationResult {
+            schema_version: schema::LEGACY_RESULT_SCHEMA,
+            ..ClassificationResult::new(BeatboxHit::Kick, None, 10, 0.8)
+        };
+
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["timing"]["classification"], "OnTime");
+        assert_eq!(json["timing"]["error_ms"], 0.0);
+
+        let timed = ClassificationResult {
+            timing: Some(TimingFeedback {
+                classification: TimingClassification::Late,
+                error_ms: 12.5,
+            }),
+            ..result
+        };
+        let json = serde_json::to_value(&timed).unwrap();
+        assert_eq!(json["timing"]["classification"], "Late");
+        assert_eq!(json["timing"]["error_ms"], 12.5);
+    }
+
+    #[test]
+    #[serial]
+    fn custom_label_is_serialized_alongside_canonical_sound() {
+        labels::set_labels(HashMap::from([(
+            BeatboxHit::OpenHiHat,
+            "Cymbal".to_string(),
+        )]));
+        let result = ClassificationResult::new(BeatboxHit::OpenHiHat, None, 0, 0.7);
+        let default = ClassificationResult::new(BeatboxHit::Kick, None, 0, 0.7);
+        labels::set_labels(HashMap::new());
+
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["label"], "Cymbal");
+        assert_eq!(json["sound"], "OpenHiHat");
+        assert_eq!(result.sound, BeatboxHit::OpenHiHat);
+        assert_eq!(default.label, "Kick");
+    }
+
+    #[test]
+    fn result_schema_version_gates_newer_fields() {
+        let v3 = ClassificationResult {
+            extra: true,
+            schema_version: schema::CURRENT_RESULT_SCHEMA,
+            smoothed_confidence: Some(0.8),
+            beat_index: Some(1),
+            subdivision_index: Some(0),
+            flam: true,
+            clipped: true,
+            ..ClassificationResult::new(BeatboxHit::Snare, None, 42, 0.9)
+        };
+        let v2 = ClassificationResult {
+            schema_version: schema::LABELED_RESULT_SCHEMA,
+            ..v3.clone()
+        };
+        let v1 = ClassificationResult {
+            schema_version: schema::LEGACY_RESULT_SCHEMA,
+            ..v3.clone()
+        };
+        let v3_fields = [
+            "smoothed_confidence",
+            "beat_index",
+            "subdivision_index",
+            "wall_clock_ms",
+            "trigger",
+            "flam",
+            "clipped",
+        ];
+
+        let legacy = serde_json::to_value(&v1).unwrap();
+        let legacy_keys: Vec<&str> = legacy
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(|k| k.as_str())
+            .collect();
+        assert_eq!(legacy_keys.len(), 4, "{legacy}");
+        for field in ["label", "extra", "schema_version"] {
+            assert!(legacy.get(field).is_none(), "v1 leaked {field}");
+        }
+
+        let labeled = serde_json::to_value(&v2).unwrap();
+        assert_eq!(labeled["label"], "Snare");
+        assert_eq!(labeled["extra"], true);
+        assert_eq!(labeled["schema_version"], 2);
+        assert_eq!(labeled.as_object().unwrap().len(), 6, "{labeled}");
+        assert!(labeled.get("timing").is_none());
+        for field in v3_fields {
+            assert!(labeled.get(field).is_none(), "v2 leaked {field}");
+        }
+
+        let current = serde_json::to_value(&v3).unwrap();
+        assert_eq!(current["schema_version"], 3);
+        for field in v3_fields {
+            assert!(current.get(field).is_some(), "v3 is missing {field}");
+        }
+
+        // Legacy payloads deserialize as version 1
+        let parsed: ClassificationResult = serde_json::from_value(legacy).unwrap();
+        assert_eq!(parsed.schema_version, schema::LEGACY_RESULT_SCHEMA);
+        assert_eq!(parsed.timestamp_ms, 42);
+    }
+}
@@ -0,0 +1,43 @@
+//! Serialization shape of `ClassificationResult`.
+//!
+//! Fields added after the first release (`label`, `extra`, the version
+//! marker itself, and later the hit metadata) can break JSON consumers
+//! built against an earlier shape. A
+//! consumer pins the shape it understands with [`set_requested_version`];
+//! results created afterwards carry that version in
+//! `ClassificationResult::schema_version` and leave out any field the
+//! version does not know about when serialized. The flutter_rust_bridge
+//! stream is not affected: its Dart class always matches the struct.
+
+use std::sync::atomic::{AtomicU32, Ordering};
+
+/// Original shape: `sound`, `timing`, `timestamp_ms`, `confidence`
+pub const LEGACY_RESULT_SCHEMA: u32 = 1;
+
+/// Adds `label`, `extra` and `schema_version`
+pub const LABELED_RESULT_SCHEMA: u32 = 2;
+
+/// Current shape: adds `smoothed_confidence`, `beat_index`,
+/// `subdivision_index`, `wall_clock_ms`, `trigger`, `flam` and `clipped`
+pub const CURRENT_RESULT_SCHEMA: u32 = 3;
+
+static REQUESTED: AtomicU32 = AtomicU32::new(CURRENT_RESULT_SCHEMA);
+
+/// Version stamped on newly created results
+pub fn requested_version() -> u32 {
+    REQUESTED.load(Ordering::Relaxed)
+}
+
+/// Request a result shape, clamped to the supported versions
+///
+/// Returns the version actually applied.
+pub fn set_requested_version(version: u32) -> u32 {
+    let applied = version.clamp(LEGACY_RESULT_SCHEMA, CURRENT_RESULT_SCHEMA);
+    REQUESTED.store(applied, Ordering::Relaxed);
+    applied
+}
+
+/// Results without a version marker predate it
+pub(crate) fn legacy_version() -> u32 {
+    LEGACY_RESULT_SCHEMA
+}
@@ -0,0 +1,42 @@
+// Per-sound confidence smoothing for classification results
+
+use std::collections::HashMap;
+
+use crate::types::BeatboxHit;
+
+/// Per-sound exponential moving average of confidence
+///
+/// Each sound keeps its own history, so a run of confident kicks is not
+/// dragged down by an uncertain hi-hat in between.
+#[derive(Debug, Default)]
+pub(super) struct ConfidenceSmoother {
+    /// Weight of the newest confidence (0 disables smoothing)
+    alpha: f32,
+    smoothed: HashMap<BeatboxHit, f32>,
+}
+
+impl ConfidenceSmoother {
+    pub(super) fn new(alpha: f32) -> Self {
+        Self {
+            alpha: if alpha.is_finite() {
+                alpha.clamp(0.0, 1.0)
+            } else {
+                0.0
+            },
+            smoothed: HashMap::new(),
+        }
+    }
+
+    /// Fold in a new confidence; `None` while smoothing is disabled
+    pub(super) fn update(&mut self, sound: BeatboxHit, confidence: f32) -> Option<f32> {
+        if self.alpha <= 0.0 {
+            return None;
+        }
+        let smoothed = self
+            .smoothed
+            .entry(sound)
+            .and_modify(|value| *value += self.alpha * (confidence - *value))
+            .or_insert(confidence);
+        Some(*smoothed)
+    }
+}
@@ -0,0 +1,222 @@
+// Feature windows for detected hits
+//
+// Onsets and level-crossing captures are analysed over an `FFT_SIZE`
+// window. With pre-roll the window starts before the hit, reaching back
+// into a history kept from earlier batches; with a post-onset delay it is
+// shifted later, and the hit waits until that audio has arrived.
+
+use std::borrow::Cow;
+use std::time::Instant;
+
+use super::features::fft::FFT_SIZE;
+use super::onset::Onset;
+
+/// Level-crossing capture waiting for its delayed window
+#[derive(Debug, Clone, Copy)]
+struct DeferredCapture {
+    /// Sample position the capture happened at
+    sample: u64,
+    /// Captured during calibration rather than classification
+    calibration: bool,
+    /// When the capture happened, for processing latency
+    detected_at: Instant,
+}
+
+/// Where each hit's feature window sits, and the audio kept to cut it
+///
+/// Positions are in samples since engine start; the accumulator passed in
+/// always ends at `processed_samples`.
+#[derive(Debug)]
+pub(super) struct OnsetWindows {
+    /// Tail of earlier batches, so onset windows can start before the
+    /// accumulator does
+    pub(super) history: Vec<f32>,
+    history_samples: usize,
+    pub(super) pre_roll_samples: usize,
+    post_onset_delay_samples: usize,
+    /// Onsets (and when they were detected) waiting for
+    /// `post_onset_delay_samples` of audio to arrive
+    deferred_onsets: Vec<(Onset, Instant)>,
+    /// Level-crossing captures waiting for the same delay
+    deferred_captures: Vec<DeferredCapture>,
+}
+
+impl OnsetWindows {
+    pub(super) fn new(pre_roll_samples: usize, post_onset_delay_samples: usize) -> Self {
+        // Delayed onsets may only become due a batch after their window
+        let history_samples = if post_onset_delay_samples > 0 {
+            pre_roll_samples + 2 * FFT_SIZE
+        } else {
+            pre_roll_samples
+        };
+        Self {
+            history: Vec::with_capacity(history_samples),
+            history_samples,
+            pre_roll_samples,
+            post_onset_delay_samples,
+            deferred_onsets: Vec::new(),
+            deferred_captures: Vec::new(),
+        }
+    }
+
+    /// Keep the accumulator's tail as history before it is cleared
+    pub(super) fn retain(&mut self, accumulator: &[f32]) {
+        if self.history_samples == 0 {
+            return;
+        }
+        self.history.extend_from_slice(accumulator);
+        let excess = self.history.len().saturating_sub(self.history_samples);
+        self.history.drain(..excess);
+    }
+
+    /// Forget the history after a gap in the audio, so no window spans it
+    pub(super) fn clear_history(&mut self) {
+        self.history.clear();
+    }
+
+    /// Queue onsets detected at `detected_at` and return those whose window
+    /// is complete, each with its detection time
+    ///
+    /// Without a post-onset delay every onset is due immediately. With one,
+    /// an onset waits until the audio its shifted window covers has arrived.
+    pub(super) fn take_due_onsets(
+        &mut self,
+        onsets: Vec<Onset>,
+        detected_at: Instant,
+        processed_samples: u64,
+    ) -> Vec<(Onset, Instant)> {
+        let onsets = onsets.into_iter().map(|onset| (onset, detected_at));
+        if self.post_onset_delay_samples == 0 {
+            return onsets.collect();
+        }
+
+        self.deferred_onsets.extend(onsets);
+        let window_end_offset =
+            (self.post_onset_delay_samples + FFT_SIZE).saturating_sub(self.pre_roll_samples) as u64;
+        let (due, waiting) = std::mem::take(&mut self.deferred_onsets)
+            .into_iter()
+            .partition(|(onset, _)| onset.timestamp + window_end_offset <= processed_samples);
+        self.deferred_onsets = waiting;
+        due
+    }
+
+    /// Queue a level-crossing capture taken at `sample`
+    pub(super) fn defer_capture(&mut self, sample: u64, calibration: bool, detected_at: Instant) {
+        self.deferred_captures.push(DeferredCapture {
+            sample,
+            calibration,
+            detected_at,
+        });
+    }
+
+    /// Return the queued captures whose window is complete, each with its
+    /// detection time
+    ///
+    /// Captures are delayed by `post_onset_delay_samples` like onsets, so
+    /// both detectors see the same part of a hit. Captures queued in the
+    /// other mode (calibration or classification) are dropped.
+    pub(super) fn take_due_captures(
+        &mut self,
+        processed_samples: u64,
+        calibration: bool,
+    ) -> Vec<(u64, Instant)> {
+        let delay = self.post_onset_delay_samples as u64;
+        let (due, waiting): (Vec<_>, Vec<_>) = std::mem::take(&mut self.deferred_captures)
+            .into_iter()
+            .partition(|capture| capture.sample + delay <= processed_samples);
+        self.deferred_captures = waiting;
+        due.into_iter()
+            .filter(|capture| capture.calibration == calibration)
+            .map(|capture| (capture.sample, capture.detected_at))
+            .collect()
+    }
+
+    /// Feature window for a level-crossing capture
+    ///
+    /// The `FFT_SIZE` samples leading up to the capture, shifted by
+    /// `post_onset_delay_samples`. Callers ensure the accumulator holds at
+    /// least `FFT_SIZE` samples.
+    pub(super) fn capture_window<'a>(
+        &'a self,
+        accumulator: &'a [f32],
+        processed_samples: u64,
+        capture_sample: u64,
+    ) -> Cow<'a, [f32]> {
+        if self.post_onset_delay_samples == 0 {
+            return Cow::Borrowed(&accumulator[accumulator.len() - FFT_SIZE..]);
+        }
+        // `onset_window` starts `pre_roll_samples` before its anchor
+        let anchor =
+            (capture_sample + self.pre_roll_samples as u64).saturating_sub(FFT_SIZE as u64);
+        self.onset_window(accumulator, processed_samples, anchor)
+    }
+
+    /// Feature window for an onset
+    ///
+    /// Without pre-roll or post-onset delay this is the most recent
+    /// `FFT_SIZE` samples. Otherwise the window starts `pre_roll_samples`
+    /// before the onset, shifted `post_onset_delay_samples` later (reaching
+    /// into earlier batches if needed), clamped so it stays within the
+    /// available audio. Callers ensure the accumulator holds at least
+    /// `FFT_SIZE` samples.
+    pub(super) fn onset_window<'a>(
+        &'a self,
+        accumulator: &'a [f32],
+        processed_samples: u64,
+        onset_timestamp: u64,
+    ) -> Cow<'a, [f32]> {
+        let recent = accumulator;
+        if self.pre_roll_samples == 0 && self.post_onset_delay_samples == 0 {
+            return Cow::Borrowed(&recent[recent.len() - FFT_SIZE..]);
+        }
+
+        let history = &self.history[..];
+        let total = history.len() + recent.len();
+        let first_sample = processed_samples.saturating_sub(total as u64);
+        let onset_offset = (onset_timestamp + self.post_onset_delay_samples as u64)
+            .saturating_sub(first_sample)
+            .min(total as u64) as usize;
+        let start = onset_offset
+            .saturating_sub(self.pre_roll_samples)
+            .min(total - FFT_SIZE);
+
+        if start >= history.len() {
+            let start = start - history.len();
+            Cow::Borrowed(&recent[start..start + FFT_SIZE])
+        } else if start + FFT_SIZE <= history.len() {
+            // A deferred onset's window can lie entirely in earlier batches
+            Cow::Borrowed(&history[start..start + FFT_SIZE])
+        } else {
+            let from_history = history.len() - start;
+            Cow::Owned(
+                history[start..]
+                    .iter()
+                    .chain(&recent[..FFT_SIZE - from_history])
+                    .copied()
+                    .collect(),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn late_onset_window_can_lie_entirely_in_history() {
+        // 240 pre-roll + 2 × FFT_SIZE of history with a post-onset delay
+        let mut windows = OnsetWindows::new(240, 256);
+        let earlier: Vec<f32> = (0..4096).map(|i| i as f32).collect();
+        windows.retain(&earlier);
+        assert_eq!(windows.history.len(), 240 + 2 * FFT_SIZE);
+
+        // Onset reported a batch late: its window ends before the accumulator
+        let accumulator = vec![-1.0f32; 1024];
+        let onset = 4096 - 1800;
+        let window = windows.onset_window(&accumulator, 4096 + 1024, onset);
+        assert_eq!(window.len(), FFT_SIZE);
+        assert_eq!(window[0], (onset + 256 - 240) as f32);
+        assert!(window.iter().all(|&s| s >= 0.0));
+    }
+}
@@ -0,0 +1,587 @@
+// AnalysisWorker - per-buffer state of the analysis thread
+//
+// The worker owns the DSP components and the state carried between
+// buffers. Its methods are split by stage:
+// - worker_windows.rs: accumulator, history and hit windows
+// - worker_gates.rs: checks a hit must pass before it is classified
+// - worker_onsets.rs: onset and level-crossing classification
+// - worker_emit.rs: timing, flam pairing and sending results
+// - worker_calibration.rs: noise floor, sample collection and UI feedback
+
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread::{self, JoinHandle};
+
+use rtrb::PopError;
+
+use super::auto_sensitivity::AutoSensitivity;
+use super::classifier::Classifier;
+use super::envelope::EnvelopeFollower;
+use super::features::fft::FFT_SIZE;
+use super::features::{FeatureExtractor, FftError, TemporalFeatures};
+use super::flam::FlamStage;
+use super::idle::IdleBackoff;
+use super::level_crossing::LevelCrossingDetector;
+use super::listening::ListeningWindows;
+use super::onset::{OnsetDetector, OnsetThreshold};
+use super::priority;
+use super::quantizer::Quantizer;
+use super::quiet::{QuietClearGate, SilenceTimer};
+use super::rate_limit::ResultLimits;
+use super::result::ClassificationResult;
+use super::smoothing::ConfidenceSmoother;
+use super::window::OnsetWindows;
+use crate::api::{AudioMetrics, OnsetEvent};
+use crate::audio::buffer_pool::{AnalysisThreadChannels, BufferPool};
+use crate::calibration::procedure::CalibrationProcedure;
+use crate::calibration::progress::CalibrationProgress;
+use crate::calibration::state::CalibrationState;
+use crate::config::{
+    AnalysisConfig, DisplayConfig, InputConfig, OnsetDetectionConfig, ResultEmissionConfig,
+    TimingConfig,
+};
+use crate::telemetry;
+
+#[path = "worker_calibration.rs"]
+mod worker_calibration;
+#[path = "worker_emit.rs"]
+mod worker_emit;
+#[path = "worker_gates.rs"]
+mod worker_gates;
+#[path = "worker_onsets.rs"]
+mod worker_onsets;
+#[path = "worker_windows.rs"]
+mod worker_windows;
+
+use worker_calibration::CalibrationFeedback;
+
+/// Minimum gap between level-crossing captures, which is also how long
+/// onsets may duplicate one
+const LEVEL_CROSSING_DEBOUNCE_MS: u64 = 150;
+
+/// Envelope smoothing for the `min_tail_ms` check, long enough that noisy
+/// hits don't end their tail at the first near-zero sample
+const TAIL_SMOOTHING_MS: f32 = 2.0;
+
+pub(super) struct AnalysisWorker {
+    // Channels & Config
+    analysis_channels: AnalysisThreadChannels,
+    calibration_state: Arc<RwLock<CalibrationState>>,
+    calibration_procedure: Arc<Mutex<Option<CalibrationProcedure>>>,
+    calibration_progress_tx: Option<tokio::sync::broadcast::Sender<CalibrationProgress>>,
+    frame_counter: Arc<AtomicU64>,
+    bpm: Arc<AtomicU32>,
+    /// Bar length of the metronome (`click.beats_per_bar`) for beat positions
+    beats_per_bar: u32,
+    sample_rate: u32,
+    result_sender: tokio::sync::broadcast::Sender<ClassificationResult>,
+    audio_metrics_tx: Option<tokio::sync::broadcast::Sender<AudioMetrics>>,
+    onset_events_tx: Option<tokio::sync::broadcast::Sender<OnsetEvent>>,
+    log_every_n_buffers: u64,
+    shutdown_flag: Option<Arc<AtomicBool>>,
+    onset_config: OnsetDetectionConfig,
+    results_config: ResultEmissionConfig,
+    input_config: InputConfig,
+    timing_config: TimingConfig,
+    display_config: DisplayConfig,
+
+    // DSP Components
+    onset_detector: OnsetDetector,
+    feature_extractor: FeatureExtractor,
+    /// Smoothed-envelope decay measurement for `min_tail_ms`
+    tail_meter: TemporalFeatures,
+    classifier: Classifier,
+    /// Built lazily once a non-zero BPM is seen; stays `None` in
+    /// classification-only mode
+    quantizer: Option<Quantizer>,
+    level_crossing_detector: LevelCrossingDetector,
+    gate_envelope: EnvelopeFollower,
+    /// Ballistic level behind `AudioMetrics::smoothed_rms`
+    vu_meter: EnvelopeFollower,
+    /// Present while auto-sensitivity is enabled; holds the locked
+    /// thresholds once its window has elapsed
+    auto_sensitivity: Option<AutoSensitivity>,
+
+    // State
+    accumulator: Vec<f32>,
+    /// Oldest samples beyond this are dropped when analysis falls behind
+    max_accumulator_samples: usize,
+    /// History, pre-roll and post-onset delay of hit windows
+    windows: OnsetWindows,
+    feedback: CalibrationFeedback,
+    quiet_clear: QuietClearGate,
+    processed_samples: u64,
+    limits: ResultLimits,
+    /// Onsets dropped by `max_onsets_per_buffer` since the worker started
+    dropped_onsets: u64,
+    /// Windows skipped without analysis for falling below `min_window_energy`
+    low_energy_skips: u64,
+    flam: FlamStage,
+    confidence_smoother: ConfidenceSmoother,
+    silence_timer: SilenceTimer,
+    idle_backoff: IdleBackoff,
+    listening_windows: ListeningWindows,
+    /// Where the onset detector's live threshold is published for the API
+    onset_threshold: OnsetThreshold,
+}
+
+impl AnalysisWorker {
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn new(
+        analysis_channels: AnalysisThreadChannels,
+        calibration_state: Arc<RwLock<CalibrationState>>,
+        calibration_procedure: Arc<Mutex<Option<CalibrationProcedure>>>,
+        calibration_progress_tx: Option<tokio::sync::broadcast::Sender<CalibrationProgress>>,
+        frame_counter: Arc<AtomicU64>,
+        bpm: Arc<AtomicU32>,
+        beats_per_bar: u32,
+        sample_rate: u32,
+        result_sender: tokio::sync::broadcast::Sender<ClassificationResult>,
+        analysis_config: AnalysisConfig,
+        log_every_n_buffers: u64,
+        shutdown_flag: Option<Arc<AtomicBool>>,
+        audio_metrics_tx: Option<tokio::sync::broadcast::Sender<AudioMetrics>>,
+        onset_events_tx: Option<tokio::sync::broadcast::Sender<OnsetEvent>>,
+    ) -> Result<Self, FftError> {
+        let AnalysisConfig {
+            onset_detection: onset_config,
+            classifier: classifier_config,
+            results: results_config,
+            analysis_worker: worker_config,
+            input: input_config,
+            timing: timing_config,
+            display: display_config,
+        } = analysis_config;
+        let config = &onset_config;
+        let ms_to_samples = |ms: f32| (ms.max(0.0) * sample_rate as f32 / 1000.0) as usize;
+        let level_dedup_samples = LEVEL_CROSSING_DEBOUNCE_MS * sample_rate as u64 / 1000;
+        let metronome_running = bpm.load(Ordering::Relaxed) > 0;
+
+        let mut worker = Self {
+            onset_detector: OnsetDetector::with_config(sample_rate, config.clone()),
+            feature_extractor: FeatureExtractor::new(sample_rate)?
+                .with_decay_window_ms(config.decay_window_ms),
+            tail_meter: TemporalFeatures::new(sample_rate)
+                .with_envelope_smoothing_ms(TAIL_SMOOTHING_MS),
+            classifier: Classifier::new(Arc::clone(&calibration_state))
+                .with_config(&classifier_config),
+            quantizer: None,
+            level_crossing_detector: LevelCrossingDetector::new(
+                sample_rate,
+                LEVEL_CROSSING_DEBOUNCE_MS,
+            ),
+            gate_envelope: EnvelopeFollower::new(
+                sample_rate,
+                config.gate_attack_ms,
+                config.gate_release_ms,
+            ),
+            vu_meter: EnvelopeFollower::new(
+                sample_rate,
+                config.vu_time_constant_ms,
+                config.vu_time_constant_ms,
+            ),
+            auto_sensitivity: (config.auto_sensitivity_ms > 0.0).then(|| {
+                AutoSensitivity::new(
+                    sample_rate,
+                    config.auto_sensitivity_ms,
+                    config.threshold_offset,
+                )
+            }),
+            accumulator: Vec::with_capacity(config.min_buffer_size.max(2048)),
+            max_accumulator_samples: worker_config
+                .max_accumulator_size
+                .max(config.min_buffer_size)
+                .max(FFT_SIZE),
+            windows: OnsetWindows::new(
+                ms_to_samples(config.pre_roll_ms),
+                config.post_onset_delay_samples,
+            ),
+            feedback: CalibrationFeedback::new(),
+            quiet_clear: QuietClearGate::new(
+                config.quiet_clear_enter_multiplier,
+                config.quiet_clear_exit_multiplier,
+            ),
+            processed_samples: 0,
+            limits: ResultLimits::new(&results_config, sample_rate),
+            dropped_onsets: 0,
+            low_energy_skips: 0,
+            flam: FlamStage::new(config.flam_window_ms, level_dedup_samples, sample_rate),
+            confidence_smoother: ConfidenceSmoother::new(results_config.confidence_smoothing),
+            silence_timer: SilenceTimer::new(input_config.silence_timeout_ms, sample_rate),
+            idle_backoff: IdleBackoff::new(worker_config.max_idle_sleep_ms),
+            listening_windows: ListeningWindows::shared(),
+            onset_threshold: OnsetThreshold::shared(),
+            analysis_channels,
+            calibration_state,
+            calibration_procedure,
+            calibration_progress_tx,
+            frame_counter,
+            bpm,
+            beats_per_bar,
+            sample_rate,
+            result_sender,
+            audio_metrics_tx,
+            onset_events_tx,
+            log_every_n_buffers,
+            shutdown_flag,
+            onset_config,
+            results_config,
+            input_config,
+            timing_config,
+            display_config,
+        };
+        if metronome_running {
+            worker.quantizer = Some(worker.new_quantizer());
+        }
+        Ok(worker)
+    }
+
+    /// Gain applied to incoming audio
+    ///
+    /// A calibration that measures the input level collects at its own gain
+    /// (raw input while measuring, then the measured gain), so its noise
+    /// floor and samples match the gain `finalize()` stores. Otherwise the
+    /// calibrated gain applies.
+    fn input_gain(&self) -> f32 {
+        let procedure_gain = self.calibration_procedure.lock().ok().and_then(|guard| {
+            guard
+                .as_ref()
+                .and_then(CalibrationProcedure::collection_input_gain)
+        });
+        procedure_gain.unwrap_or_else(|| {
+            self.calibration_state
+                .read()
+                .map(|state| state.input_gain)
+                .unwrap_or(1.0)
+        })
+    }
+
+    pub(super) fn run(mut self) {
+        eprintln!("[AnalysisThread] Thread started");
+        eprintln!("[AnalysisThread] OnsetDetector created");
+        eprintln!("[AnalysisThread] FeatureExtractor created");
+        eprintln!("[AnalysisThread] Classifier created");
+        eprintln!("[AnalysisThread] Quantizer created, entering loop");
+
+        // Main analysis loop - runs until sender is dropped (audio engine stops)
+        tracing::info!("[AnalysisThread] Starting analysis loop");
+
+        // Log initial noise floor gate for debugging
+        if let Ok(state) = self.calibration_state.read() {
+            tracing::info!(
+                "[AnalysisThread] Noise floor RMS from calibration: {:.4}, gate threshold: {:.4}",
+                state.noise_floor_rms,
+                state.noise_floor_rms * 2.0
+            );
+        }
+
+        loop {
+            // Attempt to pop from queue
+            let buffer = match self.analysis_channels.data_consumer.pop() {
+                Ok(buf) => {
+                    eprintln!("[AnalysisThread] Popped buffer len {}", buf.len());
+                    self.idle_backoff.reset();
+                    buf
+                }
+                Err(PopError::Empty) => {
+                    // Check shutdown flag only when queue is empty
+                    if let Some(flag) = self.shutdown_flag.as_ref() {
+                        if !flag.load(Ordering::SeqCst) {
+                            tracing::info!(
+                                "[AnalysisThread] Shutdown flag set and queue empty, exiting"
+                            );
+                            break;
+                        }
+                    }
+                    // Sleep to avoid a busy loop, longer the longer we idle
+                    std::thread::sleep(self.idle_backoff.next_sleep());
+                    continue;
+                }
+            };
+
+            let input_gain = self.ingest(&buffer);
+
+            // Return buffer to pool immediately
+            if self.analysis_channels.pool_producer.push(buffer).is_err() {
+                tracing::warn!("[AnalysisThread] Pool queue full, dropping buffer");
+            }
+
+            self.analyze_accumulated(input_gain);
+        }
+        self.flush_pending_flam();
+    }
+
+    /// Add one audio buffer to the accumulator
+    ///
+    /// Returns the calibrated input gain that was applied.
+    pub(super) fn ingest(&mut self, buffer: &[f32]) -> f32 {
+        let min_buffer_size = self.onset_config.min_buffer_size.max(64);
+        self.processed_samples += buffer.len() as u64;
+        if let Some(quantizer) = self.quantizer.as_ref() {
+            quantizer.observe_tempo(self.processed_samples);
+        }
+
+        // Accumulate small buffers into larger chunks, applying the
+        // input gain so levels are consistent across devices
+        let input_gain = self.input_gain();
+        self.accumulate(buffer, input_gain);
+        let occupancy = (self.accumulator.len().min(min_buffer_size) as f32
+            / min_buffer_size as f32)
+            .clamp(0.0, 1.0)
+            * 100.0;
+        telemetry::hub().record_buffer_occupancy("analysis_accumulator", occupancy);
+
+        input_gain
+    }
+
+    /// Run detection and classification over the accumulator once it holds
+    /// `min_buffer_size` samples, then start the next batch
+    ///
+    /// This is one iteration of the analysis loop after the buffer has been
+    /// accumulated; `run_analysis_once` drives it directly.
+    pub(super) fn analyze_accumulated(&mut self, input_gain: f32) {
+        // Only process when we have enough samples
+        if self.accumulator.len() < self.onset_config.min_buffer_size.max(64) {
+            return;
+        }
+
+        // Calculate RMS for audio metrics (level meter)
+        let rms = finite_rms(&self.accumulator);
+        // More responsive RMS from the most recent window (used for gating)
+        let window_rms = if self.accumulator.len() >= 1024 {
+            finite_rms(&self.accumulator[self.accumulator.len() - 1024..])
+        } else {
+            rms
+        };
+        // Attack/release-smoothed level for the classification gate, so it
+        // opens on the transient and doesn't chatter through the tail
+        let gate_level = self
+            .gate_envelope
+            .process_block(window_rms, self.accumulator.len());
+
+        // Emit audio metrics for live level meter display
+        self.process_audio_metrics(rms);
+        self.observe_auto_sensitivity(rms);
+
+        // ====== NOISE FLOOR CALIBRATION PHASE ======
+        let batch_len = self.accumulator.len();
+        if self.process_noise_floor_calibration(rms, input_gain) {
+            // The batch never reaches the onset detector; keep its clock
+            // on `processed_samples`
+            self.onset_detector.skip(batch_len);
+            return;
+        }
+        self.log_max_amplitude(rms);
+
+        let calibration = self.calibration_snapshot(rms, window_rms);
+        self.process_level_crossings(&calibration, window_rms, gate_level);
+        self.process_detected_onsets(&calibration);
+
+        // Clear accumulator for next batch (AFTER processing all onsets!)
+        self.retain_history();
+        self.accumulator.clear();
+    }
+
+    fn process_audio_metrics(&mut self, rms: f64) {
+        // Same integration time up and down, like a VU meter's needle
+        let smoothed_rms = self.vu_meter.process_block(rms, self.accumulator.len());
+
+        if let Some(ref tx) = self.audio_metrics_tx {
+            let current_frame = self.frame_counter.load(Ordering::Relaxed);
+            let timestamp_ms = (current_frame as f64 / self.sample_rate as f64 * 1000.0) as u64;
+
+            // Extract features for spectral centroid (only if we have enough samples)
+            let features = if self.accumulator.len() >= 1024 {
+                Some(
+                    self.feature_extractor
+                        .extract(&self.accumulator[self.accumulator.len() - 1024..]),
+                )
+            } else if !self.accumulator.is_empty() {
+                Some(self.feature_extractor.extract(&self.accumulator))
+            } else {
+                None
+            };
+
+            let metrics = AudioMetrics {
+                rms,
+                smoothed_rms,
+                spectral_centroid: features.map(|f| f.centroid as f64).unwrap_or(0.0),
+                spectral_flux: self.onset_detector.last_spectral_flux() as f64,
+                frame_number: current_frame,
+                timestamp: timestamp_ms,
+            };
+            let _ = tx.send(metrics);
+        }
+    }
+
+    /// Periodically log the batch's peak amplitude (`log_every_n_buffers`)
+    fn log_max_amplitude(&self, rms: f64) {
+        static mut NON_ZERO_CHECK: u64 = 0;
+        let interval = self.log_every_n_buffers;
+        unsafe {
+            NON_ZERO_CHECK += 1;
+            if interval > 0 && NON_ZERO_CHECK.is_multiple_of(interval) {
+                let max_amplitude = self
+                    .accumulator
+                    .iter()
+                    .map(|x| x.abs())
+                    .fold(0.0f32, f32::max);
+                tracing::info!(
+                    "[AnalysisThread] Max amplitude in accumulated buffer: {}, RMS: {}",
+                    max_amplitude,
+                    rms
+                );
+            }
+        }
+    }
+}
+
+/// Number of NaN/Inf samples in a window
+fn count_non_finite(window: &[f32]) -> usize {
+    window.iter().filter(|s| !s.is_finite()).count()
+}
+
+/// RMS of a window with NaN/Inf samples counted as silence
+///
+/// Levels feed smoothed state (gate envelope, VU meter, noise floor), so a
+/// single non-finite sample must not poison them for the rest of the run.
+fn finite_rms(window: &[f32]) -> f64 {
+    if window.is_empty() {
+        return 0.0;
+    }
+    let sum_squares: f64 = window
+        .iter()
+        .filter(|sample| sample.is_finite())
+        .map(|&sample| (sample as f64) * (sample as f64))
+        .sum();
+    (sum_squares / window.len() as f64).sqrt()
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn spawn_analysis_thread(
+    analysis_channels: AnalysisThreadChannels,
+    calibration_state: Arc<RwLock<CalibrationState>>,
+    calibration_procedure: Arc<Mutex<Option<CalibrationProcedure>>>,
+    calibration_progress_tx: Option<tokio::sync::broadcast::Sender<CalibrationProgress>>,
+    frame_counter: Arc<AtomicU64>,
+    bpm: Arc<AtomicU32>,
+    beats_per_bar: u32,
+    sample_rate: u32,
+    result_sender: tokio::sync::broadcast::Sender<ClassificationResult>,
+    analysis_config: AnalysisConfig,
+    log_every_n_buffers: u64,
+    shutdown_flag: Option<Arc<AtomicBool>>,
+    audio_metrics_tx: Option<tokio::sync::broadcast::Sender<AudioMetrics>>,
+    onset_events_tx: Option<tokio::sync::broadcast::Sender<OnsetEvent>>,
+) -> JoinHandle<()> {
+    thread::spawn(move || {
+        if analysis_config.analysis_worker.elevated_thread_priority {
+            priority::elevate_current_thread();
+        }
+        let worker = AnalysisWorker::new(
+            analysis_channels,
+            calibration_state,
+            calibration_procedure,
+            calibration_progress_tx,
+            frame_counter,
+            bpm,
+            beats_per_bar,
+            sample_rate,
+            result_sender,
+            analysis_config,
+            log_every_n_buffers,
+            shutdown_flag,
+            audio_metrics_tx,
+            onset_events_tx,
+        );
+        match worker {
+            Ok(worker) => worker.run(),
+            Err(err) => tracing::error!("[AnalysisThread] Failed to start: {}", err),
+        }
+    })
+}
+
+/// Run the analysis pipeline synchronously over `samples`
+///
+/// Feeds `samples` in `buffer_size` chunks through the same per-buffer
+/// logic as the analysis thread, without a thread, queue, metronome or
+/// shared listening windows, and returns every classification result in
+/// order. A result still held for flam pairing at the end is included.
+/// `analysis_config` and `beats_per_bar` are what the engine would pass
+/// the analysis thread (see [`AppConfig::analysis`]). Deterministic, so
+/// integration tests can exercise the real path.
+///
+/// [`AppConfig::analysis`]: crate::config::AppConfig::analysis
+///
+/// Classification needs at least 1024 accumulated samples per batch, so
+/// pass the engine's buffer size (`DEFAULT_BUFFER_SIZE`) or larger.
+///
+/// # Errors
+/// Returns [`FftError`] when the feature extractor cannot be built.
+pub fn run_analysis_once(
+    samples: &[f32],
+    calibration_state: CalibrationState,
+    analysis_config: AnalysisConfig,
+    beats_per_bar: u32,
+    sample_rate: u32,
+    buffer_size: usize,
+) -> Result<Vec<ClassificationResult>, FftError> {
+    let buffer_size = buffer_size.max(1);
+    let (_audio_channels, analysis_channels) = BufferPool::new(1, buffer_size).split_for_threads();
+    let (result_tx, mut result_rx) = tokio::sync::broadcast::channel(64);
+    let mut worker = AnalysisWorker::new(
+        analysis_channels,
+        Arc::new(RwLock::new(calibration_state)),
+        Arc::new(Mutex::new(None)),
+        None,
+        Arc::new(AtomicU64::new(0)),
+        Arc::new(AtomicU32::new(0)),
+        beats_per_bar,
+        sample_rate,
+        result_tx,
+        analysis_config,
+        0,
+        None,
+        None,
+        None,
+    )?;
+    worker.listening_windows = ListeningWindows::default();
+
+    let mut results = Vec::new();
+    for buffer in samples.chunks(buffer_size) {
+        let input_gain = worker.ingest(buffer);
+        worker.analyze_accumulated(input_gain);
+        while let Ok(result) = result_rx.try_recv() {
+            results.push(result);
+        }
+    }
+    worker.flush_pending_flam();
+    while let Ok(result) = result_rx.try_recv() {
+        results.push(result);
+    }
+    Ok(results)
+}
+
+#[cfg(test)]
+#[path = "worker_test_support.rs"]
+mod test_support;
+
+#[cfg(test)]
+#[path = "worker_tests.rs"]
+mod tests;
+
+#[cfg(test)]
+#[path = "worker_emit_tests.rs"]
+mod emit_tests;
+
+#[cfg(test)]
+#[path = "worker_gate_tests.rs"]
+mod gate_tests;
+
+#[cfg(test)]
+#[path = "worker_window_tests.rs"]
+mod window_tests;
+
+#[cfg(test)]
+#[path = "worker_calibration_tests.rs"]
+mod calibration_tests;
@@ -0,0 +1,446 @@
+// AnalysisWorker - calibration phases and progress feedback
+//
+// While a calibration procedure is running, the worker measures the input
+// level and noise floor, offers detected hits to the procedure as samples
+// and keeps the UI informed with progress, guidance and debug frames.
+
+use std::time::{Duration, Instant};
+
+use crate::analysis::features::Features;
+use crate::analysis::rate_limit::GuidanceRateLimiter;
+use crate::analysis::result::ClassificationResult;
+use crate::calibration::procedure::CalibrationProcedure;
+use crate::calibration::progress::{
+    CalibrationGuidance, CalibrationGuidanceReason, CalibrationProgress,
+};
+
+use super::worker_onsets::WindowStats;
+use super::AnalysisWorker;
+
+/// Calibration state read once per analysed batch
+#[derive(Debug, Clone, Copy)]
+pub(super) struct BatchCalibration {
+    /// A calibration procedure is running
+    pub(super) active: bool,
+    /// The procedure's detection threshold, while one is running
+    pub(super) detection_threshold: Option<f64>,
+    /// RMS below which the input counts as quiet
+    pub(super) quiet_gate: f64,
+}
+
+/// Progress bookkeeping for the calibration UI
+pub(super) struct CalibrationFeedback {
+    pub(super) guidance_limiter: GuidanceRateLimiter,
+    last_noise_floor_samples: usize,
+    debug_emit_counter: u64,
+    last_progress_heartbeat: Instant,
+    last_debug_probe: Instant,
+}
+
+impl CalibrationFeedback {
+    pub(super) fn new() -> Self {
+        Self {
+            guidance_limiter: GuidanceRateLimiter::new(Duration::from_secs(5)),
+            last_noise_floor_samples: 0,
+            debug_emit_counter: 0,
+            last_progress_heartbeat: Instant::now(),
+            last_debug_probe: Instant::now(),
+        }
+    }
+
+    /// Log the debug payload of a progress update about to be sent
+    fn log_progress(&mut self, label: &str, progress: &CalibrationProgress) {
+        self.debug_emit_counter = self.debug_emit_counter.wrapping_add(1);
+        tracing::debug!(
+            "[AnalysisThread] {} [{}]: gate_rms {:?}, last_rms {:?}, last_centroid {:?}, last_zcr {:?}, last_max_amp {:?}, misses {}",
+            label,
+            self.debug_emit_counter,
+            progress.debug.as_ref().and_then(|d| d.rms_gate),
+            progress.debug.as_ref().and_then(|d| d.last_rms),
+            progress.debug.as_ref().and_then(|d| d.last_centroid),
+            progress.debug.as_ref().and_then(|d| d.last_zcr),
+            progress.debug.as_ref().and_then(|d| d.last_max_amp),
+            progress.debug.as_ref().map(|d| d.misses).unwrap_or(0),
+        );
+    }
+
+    /// Guidance for a rejected sample, rate limited per reason
+    fn rejection_guidance(
+        &mut self,
+        procedure: &CalibrationProcedure,
+        stats: &WindowStats,
+        quiet_gate: f64,
+    ) -> Option<CalibrationGuidance> {
+        let reason = if stats.rms < quiet_gate {
+            CalibrationGuidanceReason::TooQuiet
+        } else if stats.max_amplitude >= 0.98 {
+            CalibrationGuidanceReason::Clipped
+        } else {
+            CalibrationGuidanceReason::Stagnation
+        };
+        self.guidance_limiter
+            .should_emit(reason, Instant::now())
+            .then(|| CalibrationGuidance {
+                sound: procedure.current_sound(),
+                reason,
+                level: stats.rms as f32,
+                misses: procedure.rejects_for_current_sound(),
+            })
+    }
+}
+
+impl AnalysisWorker {
+    /// Read the calibration state for this batch and run the per-batch
+    /// feedback: quiet detection, guidance clearing and debug updates
+    pub(super) fn calibration_snapshot(&mut self, rms: f64, window_rms: f64) -> BatchCalibration {
+        let (active, noise_floor_threshold) =
+            if let Ok(procedure_guard) = self.calibration_procedure.try_lock() {
+                (
+                    procedure_guard.is_some(),
+                    procedure_guard
+                        .as_ref()
+                        .and_then(|p| p.noise_floor_threshold())
+                        .unwrap_or(0.02),
+                )
+            } else {
+                (false, 0.02)
+            };
+        let quiet_gate = noise_floor_threshold * self.quiet_clear.enter;
+        let quiet = self.quiet_clear.update(rms, noise_floor_threshold);
+        if !active {
+            self.observe_silence(rms);
+        }
+
+        let detection_threshold = if active {
+            if let Ok(procedure_guard) = self.calibration_procedure.try_lock() {
+                procedure_guard
+                    .as_ref()
+                    .map(|procedure| procedure.detection_threshold())
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+
+        if active && quiet {
+            self.clear_stale_guidance();
+        }
+
+        // Push a light-weight debug probe and heartbeat
+        self.process_periodic_updates(active, window_rms);
+        if active {
+            self.process_calibration_debug_frame(window_rms);
+        }
+        BatchCalibration {
+            active,
+            detection_threshold,
+            quiet_gate,
+        }
+    }
+
+    /// Resend progress without guidance once the input has gone quiet
+    fn clear_stale_guidance(&mut self) {
+        if !self.feedback.guidance_limiter.has_active() {
+            return;
+        }
+        if let Ok(mut procedure_guard) = self.calibration_procedure.try_lock() {
+            if let Some(ref mut procedure) = *procedure_guard {
+                if let Some(ref tx) = self.calibration_progress_tx {
+                    let _ = tx.send(
+                        procedure.get_progress_with_guidance_and_features(None, None, None, None),
+                    );
+                }
+            }
+        }
+        self.feedback.guidance_limiter.clear();
+    }
+
+    pub(super) fn process_noise_floor_calibration(&mut self, rms: f64, input_gain: f32) -> bool {
+        if self.process_input_level_calibration(rms / input_gain.max(f32::EPSILON) as f64) {
+            return true;
+        }
+
+        let in_noise_floor_phase =
+            if let Ok(procedure_guard) = self.calibration_procedure.try_lock() {
+                procedure_guard
+                    .as_ref()
+                    .map(|p| p.is_in_noise_floor_phase())
+                    .unwrap_or(false)
+            } else {
+                false
+            };
+
+        if in_noise_floor_phase {
+            self.add_noise_floor_sample(rms);
+            self.accumulator.clear();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn add_noise_floor_sample(&mut self, rms: f64) {
+        let Ok(mut procedure_guard) = self.calibration_procedure.lock() else {
+            return;
+        };
+        let Some(ref mut procedure) = *procedure_guard else {
+            return;
+        };
+        match procedure.add_noise_floor_sample(rms) {
+            Ok(complete) => {
+                let progress = procedure.get_progress();
+                let samples = progress.samples_collected as usize;
+                if samples != self.feedback.last_noise_floor_samples {
+                    if let Some(ref tx) = self.calibration_progress_tx {
+                        let _ = tx.send(progress.clone());
+                    }
+                    self.feedback.last_noise_floor_samples = samples;
+                }
+
+                if complete {
+                    tracing::info!(
+                        "[AnalysisThread] Noise floor calibration complete! Threshold: {:?}",
+                        procedure.noise_floor_threshold()
+                    );
+                }
+            }
+            Err(e) => {
+                tracing::warn!("[AnalysisThread] Noise floor sample rejected: {:?}", e);
+            }
+        }
+    }
+
+    /// Feed raw input RMS to a pending input level measurement
+    ///
+    /// Returns `true` while the measurement is still running, so the buffer
+    /// is not used for noise floor or sample collection.
+    fn process_input_level_calibration(&mut self, raw_rms: f64) -> bool {
+        let Ok(mut procedure_guard) = self.calibration_procedure.try_lock() else {
+            return false;
+        };
+        let Some(procedure) = procedure_guard.as_mut() else {
+            return false;
+        };
+        if !procedure.is_measuring_input_level() {
+            return false;
+        }
+
+        let hits_before = procedure.input_level_hits_measured();
+        let complete = procedure.add_input_level_sample(raw_rms);
+        if complete {
+            tracing::info!(
+                "[AnalysisThread] Input level measured, gain {:?}",
+                procedure.measured_input_gain()
+            );
+        }
+        if complete || procedure.input_level_hits_measured() != hits_before {
+            if let Some(ref tx) = self.calibration_progress_tx {
+                let _ = tx.send(procedure.get_progress());
+            }
+        }
+        drop(procedure_guard);
+        self.accumulator.clear();
+        true
+    }
+
+    pub(super) fn process_level_crossing_calibration(
+        &mut self,
+        window_rms: f64,
+        detection_threshold: f64,
+    ) {
+        if let Some(event) = self.level_crossing_detector.process_calibration(
+            window_rms,
+            detection_threshold,
+            self.processed_samples,
+        ) {
+            tracing::debug!(
+                "[AnalysisThread] Level-crossing event {:?} (rms {:.4}, gate {:.4})",
+                event,
+                window_rms,
+                detection_threshold
+            );
+            self.capture_level_crossing(true);
+        }
+        for (capture_sample, _) in self.take_due_captures(true) {
+            self.add_calibration_capture(capture_sample, detection_threshold);
+        }
+    }
+
+    /// Offer a level-crossing capture to the calibration procedure
+    fn add_calibration_capture(&mut self, capture_sample: u64, detection_threshold: f64) {
+        let stats = WindowStats::measure(
+            &self.feature_extractor,
+            &self.capture_window(capture_sample),
+        );
+
+        let Ok(mut procedure_guard) = self.calibration_procedure.lock() else {
+            return;
+        };
+        let Some(ref mut procedure) = *procedure_guard else {
+            return;
+        };
+        self.preview_classification(procedure, &stats.features, capture_sample);
+        match procedure.add_sample(stats.features, stats.rms, stats.max_amplitude) {
+            Ok(()) => {
+                tracing::info!(
+                    "[AnalysisThread] Level-crossing capture accepted (rms {:.4}, gate {:.4})",
+                    stats.rms,
+                    detection_threshold
+                );
+                let progress = procedure.get_progress_with_guidance_and_features(
+                    None,
+                    Some(&stats.features),
+                    Some(stats.rms),
+                    Some(stats.max_amplitude),
+                );
+                if let Some(ref tx) = self.calibration_progress_tx {
+                    let _ = tx.send(progress);
+                }
+                self.feedback.guidance_limiter.clear();
+            }
+            Err(err) => {
+                tracing::info!(
+                    "[AnalysisThread] Level-crossing capture rejected: {:?} (rms {:.4})",
+                    err,
+                    stats.rms
+                );
+            }
+        }
+    }
+
+    /// Offer an onset to the calibration procedure, with guidance when it
+    /// is rejected
+    pub(super) fn add_onset_calibration_sample(
+        &mut self,
+        onset_timestamp: u64,
+        stats: &WindowStats,
+        quiet_gate: f64,
+    ) {
+        if self.level_crossing_detector.is_captured_in_gate() {
+            return;
+        }
+        let Ok(mut procedure_guard) = self.calibration_procedure.lock() else {
+            return;
+        };
+        let Some(ref mut procedure) = *procedure_guard else {
+            return;
+        };
+        self.preview_classification(procedure, &stats.features, onset_timestamp);
+        let guidance = match procedure.add_sample(stats.features, stats.rms, stats.max_amplitude) {
+            Ok(()) => {
+                tracing::info!(
+                    "[AnalysisThread] Onset sample accepted (rms {:.4}, max_amp {:.3})",
+                    stats.rms,
+                    stats.max_amplitude
+                );
+                self.feedback.guidance_limiter.clear();
+                None
+            }
+            Err(err) => {
+                tracing::info!(
+                    "[AnalysisThread] Sample rejected: {:?} (misses: {}, gate_rms: {:?}, rms {:.4})",
+                    err,
+                    procedure.rejects_for_current_sound(),
+                    procedure.rms_gate_for_current(),
+                    stats.rms
+                );
+                self.feedback
+                    .rejection_guidance(procedure, stats, quiet_gate)
+            }
+        };
+
+        let progress = procedure.get_progress_with_guidance_and_features(
+            guidance,
+            Some(&stats.features),
+            Some(stats.rms),
+            Some(stats.max_amplitude),
+        );
+        self.feedback.log_progress("Progress debug", &progress);
+        if let Some(ref tx) = self.calibration_progress_tx {
+            let _ = tx.send(progress);
+        }
+    }
+
+    /// Classify a calibration hit and publish it on the preview stream
+    ///
+    /// Only runs with a preview stream attached. The result carries no
+    /// timing feedback and never reaches the regular result channel.
+    fn preview_classification(
+        &self,
+        procedure: &CalibrationProcedure,
+        features: &Features,
+        sample_position: u64,
+    ) {
+        if !procedure.preview_enabled() || !features.is_finite() {
+            return;
+        }
+        let (sound, confidence) = self.classifier.classify_level1(features);
+        let timestamp_ms = (sample_position as f64 / self.sample_rate as f64 * 1000.0) as u64;
+        procedure.emit_preview(ClassificationResult::new(
+            sound,
+            None,
+            timestamp_ms,
+            confidence,
+        ));
+    }
+
+    /// Publish the latest window's features on the calibration debug stream
+    ///
+    /// Runs for every analysed buffer, so features are only extracted when a
+    /// stream is actually attached.
+    fn process_calibration_debug_frame(&mut self, window_rms: f64) {
+        let Ok(mut procedure_guard) = self.calibration_procedure.try_lock() else {
+            return;
+        };
+        let Some(procedure) = procedure_guard.as_mut() else {
+            return;
+        };
+        if !procedure.debug_stream_enabled() {
+            return;
+        }
+
+        let stats = WindowStats::measure(&self.feature_extractor, self.latest_window());
+        procedure.emit_debug_frame(&stats.features, window_rms, stats.max_amplitude);
+    }
+
+    fn process_periodic_updates(&mut self, calibration_active: bool, window_rms: f64) {
+        if !calibration_active {
+            return;
+        }
+
+        if self.feedback.last_debug_probe.elapsed() >= Duration::from_millis(33) {
+            let debug = WindowStats::measure(&self.feature_extractor, self.latest_window());
+            if let Ok(mut procedure_guard) = self.calibration_procedure.try_lock() {
+                if let Some(ref mut procedure) = *procedure_guard {
+                    procedure.update_last_features_for_debug(
+                        &debug.features,
+                        window_rms,
+                        debug.max_amplitude,
+                    );
+                }
+            }
+            self.feedback.last_debug_probe = Instant::now();
+        }
+
+        if self.feedback.last_progress_heartbeat.elapsed() >= Duration::from_millis(100) {
+            if let Ok(mut procedure_guard) = self.calibration_procedure.try_lock() {
+                if let Some(ref mut procedure) = *procedure_guard {
+                    let progress =
+                        procedure.get_progress_with_guidance_and_features(None, None, None, None);
+                    self.feedback.log_progress("Progress heartbeat", &progress);
+                    if let Some(ref tx) = self.calibration_progress_tx {
+                        let _ = tx.send(progress);
+                    }
+                }
+            }
+            self.feedback.last_progress_heartbeat = Instant::now();
+        }
+    }
+
+    /// The most recent 1024 samples of the accumulator (or all of it)
+    fn latest_window(&self) -> &[f32] {
+        let start = self.accumulator.len().saturating_sub(1024);
+        &self.accumulator[start..]
+    }
+}
@@ -0,0 +1,175 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use crate::audio::buffer_pool::BufferPool;
+use crate::calibration::procedure::CalibrationProcedure;
+use crate::calibration::progress::{CalibrationGuidanceReason, CalibrationSound};
+use crate::calibration::state::CalibrationState;
+use crate::config::OnsetDetectionConfig;
+use crate::types::BeatboxHit;
+
+use super::test_support::WorkerBuilder;
+
+#[test]
+fn calibration_debug_stream_emits_one_frame_per_buffer() {
+    const BUFFER: usize = 512;
+    const SR: u32 = 48_000;
+
+    let (mut audio_channels, analysis_channels) = BufferPool::new(8, BUFFER).split_for_threads();
+    let (debug_tx, mut debug_rx) = tokio::sync::broadcast::channel(256);
+    let mut procedure = CalibrationProcedure::new_for_test(10);
+    procedure.set_debug_stream(debug_tx);
+    let procedure = Arc::new(Mutex::new(Some(procedure)));
+    let running = Arc::new(AtomicBool::new(true));
+
+    let (worker, _results) = WorkerBuilder::default()
+        .analysis_channels(analysis_channels)
+        .calibration_procedure(&procedure)
+        .shutdown_flag(&running)
+        .build();
+    let handle = std::thread::spawn(move || worker.run());
+
+    // One second of quiet tone, fed in engine-sized buffers
+    let buffers = SR as usize / BUFFER;
+    let mut fed = 0;
+    while fed < buffers {
+        match audio_channels.pool_consumer.pop() {
+            Ok(mut buffer) => {
+                buffer.clear();
+                buffer.extend((0..BUFFER).map(|i| 0.01 * ((fed * BUFFER + i) as f32 * 0.05).sin()));
+                audio_channels.data_producer.push(buffer).unwrap();
+                fed += 1;
+            }
+            Err(_) => std::thread::sleep(std::time::Duration::from_millis(1)),
+        }
+    }
+    running.store(false, Ordering::SeqCst);
+    handle.join().unwrap();
+
+    let frames: Vec<_> = std::iter::from_fn(|| debug_rx.try_recv().ok()).collect();
+    // Roughly SR / BUFFER frames per second of audio
+    assert!(
+        frames.len() + 2 >= buffers && frames.len() <= buffers,
+        "{} frames for {} buffers",
+        frames.len(),
+        buffers
+    );
+    assert!(frames.windows(2).all(|pair| pair[1].seq == pair[0].seq + 1));
+    assert!(frames.iter().all(|frame| frame.rms > 0.0));
+}
+
+#[test]
+fn quiet_clear_hysteresis_does_not_flap_guidance() {
+    // The steps between constant batches aren't hits; keep the onset
+    // detector from offering them as calibration samples
+    let config = OnsetDetectionConfig {
+        quiet_clear_enter_multiplier: 1.0,
+        quiet_clear_exit_multiplier: 1.5,
+        threshold_offset: f32::INFINITY,
+        ..OnsetDetectionConfig::default()
+    };
+    let mut worker = WorkerBuilder::default().onset_config(config).build().0;
+    let procedure = CalibrationProcedure::new_for_test(10);
+    let noise_floor = procedure.noise_floor_threshold().unwrap();
+    worker.calibration_procedure = Arc::new(Mutex::new(Some(procedure)));
+    let min_buffer_size = worker.onset_config.min_buffer_size.max(1024);
+    // Run one batch at `level` times the noise floor; returns whether the
+    // guidance raised just before it was cleared
+    let mut step = |level: f64| {
+        worker
+            .feedback
+            .guidance_limiter
+            .should_emit(CalibrationGuidanceReason::TooQuiet, Instant::now());
+        worker.accumulator = vec![(noise_floor * level) as f32; min_buffer_size];
+        worker.analyze_accumulated(1.0);
+        !worker.feedback.guidance_limiter.has_active()
+    };
+
+    // Loud, then below the enter gate: quiet clears guidance every buffer
+    let cleared: Vec<bool> = [5.0, 0.9, 1.1, 1.4, 1.2]
+        .into_iter()
+        .map(&mut step)
+        .collect();
+    assert_eq!(cleared, [false, true, true, true, true]);
+    // Above the exit gate re-arms; between the gates stays loud
+    let cleared: Vec<bool> = [2.0, 1.2, 1.4, 0.8].into_iter().map(&mut step).collect();
+    assert_eq!(cleared, [false, false, false, true]);
+}
+
+#[test]
+fn calibration_preview_classifies_hits_that_still_become_samples() {
+    let (preview_tx, mut preview_rx) = tokio::sync::broadcast::channel(8);
+    let mut procedure = CalibrationProcedure::new_for_test(10);
+    procedure.set_preview_stream(preview_tx);
+    let procedure = Arc::new(Mutex::new(Some(procedure)));
+
+    let (mut worker, mut result_rx) = WorkerBuilder::default()
+        .calibration_procedure(&procedure)
+        .build();
+
+    // Quiet lead-in, then a decaying 60 Hz thump
+    let mut samples = vec![0.0f32; 24_000];
+    samples.extend((0..12_000).map(|i| {
+        let t = i as f32 / 48_000.0;
+        0.8 * (-t * 30.0).exp() * (2.0 * std::f32::consts::PI * 60.0 * t).sin()
+    }));
+    samples.extend(vec![0.0f32; 24_000]);
+    // Buffers large enough to hold a full capture window
+    for buffer in samples.chunks(2048) {
+        let gain = worker.ingest(buffer);
+        worker.analyze_accumulated(gain);
+    }
+
+    let previews: Vec<_> = std::iter::from_fn(|| preview_rx.try_recv().ok()).collect();
+    assert!(!previews.is_empty(), "no preview for the calibration hit");
+    assert!(previews.iter().all(|r| r.sound == BeatboxHit::Kick));
+    assert!(previews.iter().all(|r| r.timing.is_none()));
+    // Previews never reach the regular result channel
+    assert!(result_rx.try_recv().is_err());
+
+    let guard = procedure.lock().unwrap();
+    let kicks = guard.as_ref().unwrap().sample_counts()[0].collected;
+    assert!(kicks >= 1, "the hit was not collected as a kick sample");
+    assert!(previews.len() >= kicks);
+}
+
+#[test]
+fn input_level_measurement_collects_the_noise_floor_at_the_new_gain() {
+    let procedure = CalibrationProcedure::with_debounce(1, 0)
+        .with_noise_floor_samples(5)
+        .with_input_level_hits(1);
+    let procedure = Arc::new(Mutex::new(Some(procedure)));
+    // A previous calibration's gain must not leak into the new measurement
+    let state = CalibrationState::new_default().with_input_gain(4.0);
+
+    let (mut worker, _results) = WorkerBuilder::default()
+        .calibration_state(state)
+        .calibration_procedure(&procedure)
+        .build();
+
+    let tone = |rms: f32, len: usize| -> Vec<f32> {
+        (0..len)
+            .map(|i| {
+                let phase = 2.0 * std::f32::consts::PI * 1_000.0 * i as f32 / 48_000.0;
+                rms * std::f32::consts::SQRT_2 * phase.sin()
+            })
+            .collect()
+    };
+    // One loud hit at 0.05 RMS (gain 5), then a quiet room at 0.002 RMS
+    let mut samples = tone(0.05, 4 * 2048);
+    samples.extend(tone(0.002, 8 * 2048));
+    for buffer in samples.chunks(2048) {
+        let gain = worker.ingest(buffer);
+        worker.analyze_accumulated(gain);
+    }
+
+    let guard = procedure.lock().unwrap();
+    let procedure = guard.as_ref().unwrap();
+    assert_eq!(procedure.current_sound(), CalibrationSound::NoiseFloor);
+    let gain = procedure.measured_input_gain().unwrap();
+    assert!((gain - 5.0).abs() < 0.1, "gain {gain}");
+    // Noise floor measured at 0.002 * 5, not at the old gain of 4
+    let threshold = procedure.noise_floor_threshold().unwrap();
+    assert!((threshold - 0.013).abs() < 0.001, "threshold {threshold}");
+}
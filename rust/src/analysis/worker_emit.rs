@@ -0,0 +1,227 @@
+// AnalysisWorker - timing feedback, flam pairing and sending results
+
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::analysis::features::Features;
+use crate::analysis::flam::HeldResult;
+use crate::analysis::onset::Onset;
+use crate::analysis::quantizer::{BeatPosition, Quantizer, TimingFeedback};
+use crate::analysis::result::ClassificationResult;
+use crate::api::OnsetEvent;
+use crate::config::{ClipPolicy, ListeningWindowPolicy};
+use crate::telemetry;
+
+use super::AnalysisWorker;
+
+/// Confidence multiplier for results flagged `clipped`
+const CLIPPED_CONFIDENCE_SCALE: f32 = 0.5;
+
+impl AnalysisWorker {
+    /// Quantizer for the worker's time signature and tempo settings
+    ///
+    /// Built up front when the metronome is already running, otherwise by
+    /// `timing_for` once it starts.
+    pub(super) fn new_quantizer(&self) -> Quantizer {
+        Quantizer::new(
+            Arc::clone(&self.frame_counter),
+            Arc::clone(&self.bpm),
+            self.sample_rate,
+        )
+        .with_time_signature(self.beats_per_bar, self.timing_config.beat_subdivisions)
+        .with_bpm_change_grace_ms(self.timing_config.bpm_change_grace_ms)
+    }
+
+    /// Timing feedback for an onset, or `None` without a metronome (BPM 0)
+    ///
+    /// The quantizer is only constructed the first time a non-zero BPM is
+    /// observed, so classification-only sessions never touch it.
+    pub(super) fn timing_for(&mut self, onset_timestamp: u64) -> Option<TimingFeedback> {
+        if self.bpm.load(Ordering::Relaxed) == 0 {
+            return None;
+        }
+
+        if self.quantizer.is_none() {
+            self.quantizer = Some(self.new_quantizer());
+        }
+        self.quantizer
+            .as_ref()
+            .map(|quantizer| quantizer.quantize(onset_timestamp))
+    }
+
+    /// Beat and subdivision an onset landed on, or `None` without a
+    /// metronome; call after `timing_for`, which builds the quantizer
+    pub(super) fn beat_position_for(&self, onset_timestamp: u64) -> Option<BeatPosition> {
+        if self.bpm.load(Ordering::Relaxed) == 0 {
+            return None;
+        }
+        self.quantizer
+            .as_ref()
+            .map(|quantizer| quantizer.beat_position(onset_timestamp))
+    }
+
+    /// Publish a classification result unless it follows the previous one
+    /// too closely
+    ///
+    /// Hits outside the active listening windows are flagged as extra or
+    /// dropped first. Then comes the final safety net against duplicates
+    /// from the onset and level-crossing paths firing on the same sustained
+    /// sound, followed by the per-sound limits. `sample_position` is the
+    /// result's position in samples since engine start. Returns the result
+    /// as sent, or `None` if it was dropped.
+    pub(super) fn emit_result(
+        &mut self,
+        mut result: ClassificationResult,
+        sample_position: u64,
+    ) -> Option<ClassificationResult> {
+        // Checked first so dropped results don't count against the limiters
+        if result.confidence < self.results_config.suppress_below_confidence {
+            tracing::debug!(
+                "[AnalysisThread] Dropping {:?} result with confidence {:.2} below the floor",
+                result.sound,
+                result.confidence
+            );
+            return None;
+        }
+        if !self.listening_windows.contains(result.timestamp_ms) {
+            match self.onset_config.outside_window_policy {
+                ListeningWindowPolicy::Flag => result.extra = true,
+                ListeningWindowPolicy::Suppress => {
+                    tracing::debug!(
+                        "[AnalysisThread] Dropping {:?} result at {}ms outside listening windows",
+                        result.sound,
+                        result.timestamp_ms
+                    );
+                    return None;
+                }
+            }
+        }
+        if !self.limits.admit(result.sound, sample_position) {
+            return None;
+        }
+
+        result.smoothed_confidence = self
+            .confidence_smoother
+            .update(result.sound, result.confidence);
+        telemetry::hub().record_classification(&result);
+        let _ = self.result_sender.send(result.clone());
+        Some(result)
+    }
+
+    /// Publish a result detected at `detected_at`, recording its processing
+    /// latency if it is sent
+    fn emit_detected(&mut self, held: HeldResult) -> Option<ClassificationResult> {
+        let (result, sample_position, detected_at) = held;
+        let sent = self.emit_result(result, sample_position)?;
+        telemetry::hub().record_processing_latency(processing_latency(detected_at, Instant::now()));
+        Some(sent)
+    }
+
+    /// Send a result, or hold it in case a flam follows
+    ///
+    /// With `flam_window_ms` set, a held result is sent flagged `flam` when
+    /// a second onset lands within the window (the second hit is absorbed
+    /// into it), and as is once a later onset or `flush_expired_flam` shows
+    /// none did. Both the onset and the level-crossing path come through
+    /// here.
+    ///
+    /// Returns the result as sent if it went out straight away; `None` if
+    /// it was held or dropped.
+    pub(super) fn emit_or_hold_for_flam(
+        &mut self,
+        result: ClassificationResult,
+        sample_position: u64,
+        detected_at: Instant,
+    ) -> Option<ClassificationResult> {
+        let holds = self.flam.holds_results();
+        let due = self.flam.offer(result, sample_position, detected_at)?;
+        let sent = self.emit_detected(due);
+        // With a flam window, whatever went out is an earlier, held result
+        sent.filter(|_| !holds)
+    }
+
+    /// Send the held result once no second onset can still pair with it
+    pub(super) fn flush_expired_flam(&mut self) {
+        let detector_window = self.onset_config.window_size as u64;
+        if let Some(held) = self
+            .flam
+            .take_expired(self.processed_samples, detector_window)
+        {
+            self.emit_detected(held);
+        }
+    }
+
+    /// Send the held result now, e.g. because the stream is ending
+    pub(super) fn flush_pending_flam(&mut self) {
+        if let Some(held) = self.flam.take_pending() {
+            self.emit_detected(held);
+        }
+    }
+
+    /// Flag or drop a clipped result according to `clip_policy`; `None`
+    /// means the result is suppressed
+    pub(super) fn apply_clip_policy(
+        &self,
+        mut result: ClassificationResult,
+        clipped: bool,
+    ) -> Option<ClassificationResult> {
+        if !clipped {
+            return Some(result);
+        }
+        match self.input_config.clip_policy {
+            ClipPolicy::Flag => {
+                result.clipped = true;
+                result.confidence *= CLIPPED_CONFIDENCE_SCALE;
+                Some(result)
+            }
+            ClipPolicy::Suppress => {
+                tracing::debug!(
+                    "[AnalysisThread] Dropping clipped {:?} result",
+                    result.sound
+                );
+                None
+            }
+        }
+    }
+
+    /// Publish an onset on the onset event stream, if one is attached
+    ///
+    /// `energy` is the RMS of the onset window. In monitor mode `features`
+    /// and `classification` are `None` and the feature fields are zero.
+    /// `classification` is only set for a result that was actually sent.
+    pub(super) fn publish_onset_event(
+        &self,
+        onset: &Onset,
+        energy: f64,
+        features: Option<&Features>,
+        classification: Option<ClassificationResult>,
+    ) {
+        let Some(ref tx) = self.onset_events_tx else {
+            return;
+        };
+        let extracted = features.copied();
+        let features = extracted
+            .unwrap_or_default()
+            .in_frequency_scale(self.display_config.frequency_scale);
+        let _ = tx.send(OnsetEvent {
+            timestamp: (onset.timestamp as f64 / self.sample_rate as f64 * 1000.0) as u64,
+            energy,
+            centroid: features.centroid as f64,
+            zcr: features.zcr as f64,
+            flatness: features.flatness as f64,
+            rolloff: features.rolloff as f64,
+            decay_time_ms: features.decay_time_ms as f64,
+            classification,
+            strength: onset.strength as f64,
+            features: extracted,
+        });
+    }
+}
+
+/// Time from a hit being detected to its result being sent
+///
+/// Saturates to zero if the clock reads out of order.
+pub(super) fn processing_latency(detected_at: Instant, sent_at: Instant) -> Duration {
+    sent_at.saturating_duration_since(detected_at)
+}
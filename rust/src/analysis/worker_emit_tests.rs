@@ -0,0 +1,485 @@
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::analysis::onset::Onset;
+use crate::analysis::{ClassificationResult, HitTrigger};
+use crate::config::{
+    DetectionSource, InputConfig, ListeningWindowPolicy, OnsetDetectionConfig, ResultEmissionConfig,
+};
+use crate::telemetry::{self, MetricEvent};
+use crate::types::BeatboxHit;
+
+use tokio::sync::broadcast::error::TryRecvError;
+
+use super::test_support::{analyze_stream, detected_now, WorkerBuilder, CLASSIFYING};
+use super::worker_emit::processing_latency;
+
+#[test]
+fn results_closer_than_min_interval_collapse() {
+    let config = ResultEmissionConfig {
+        min_result_interval_ms: 100.0,
+        ..ResultEmissionConfig::default()
+    };
+    let (mut worker, mut results) = WorkerBuilder::default().results_config(config).build();
+    let result =
+        |timestamp_ms| ClassificationResult::new(BeatboxHit::Snare, None, timestamp_ms, 0.9);
+
+    // Two results 30ms apart at 48kHz: only the first is emitted
+    assert!(worker.emit_result(result(1000), 48_000).is_some());
+    assert!(worker.emit_result(result(1030), 48_000 + 1_440).is_none());
+    // A result past the interval goes through again
+    assert!(worker.emit_result(result(1150), 48_000 + 7_200).is_some());
+
+    let emitted: Vec<u64> = std::iter::from_fn(|| results.try_recv().ok())
+        .map(|r| r.timestamp_ms)
+        .collect();
+    assert_eq!(emitted, vec![1000, 1150]);
+}
+
+#[test]
+fn hits_outside_listening_windows_are_flagged() {
+    let (mut worker, mut results) = WorkerBuilder::default().build();
+    worker
+        .listening_windows
+        .set(vec![(900, 1100), (1900, 2100)]);
+
+    assert!(worker
+        .emit_result(
+            ClassificationResult::new(BeatboxHit::Kick, None, 1000, 0.9),
+            48_000
+        )
+        .is_some());
+    assert!(worker
+        .emit_result(
+            ClassificationResult::new(BeatboxHit::Snare, None, 1500, 0.9),
+            72_000
+        )
+        .is_some());
+
+    let inside = results.try_recv().unwrap();
+    let outside = results.try_recv().unwrap();
+    assert_eq!((inside.timestamp_ms, inside.extra), (1000, false));
+    assert_eq!((outside.timestamp_ms, outside.extra), (1500, true));
+}
+
+#[test]
+fn suppress_policy_drops_hits_outside_listening_windows() {
+    let config = OnsetDetectionConfig {
+        outside_window_policy: ListeningWindowPolicy::Suppress,
+        ..OnsetDetectionConfig::default()
+    };
+    let (mut worker, mut results) = WorkerBuilder::default().onset_config(config).build();
+    worker.listening_windows.set(vec![(900, 1100)]);
+
+    assert!(worker
+        .emit_result(
+            ClassificationResult::new(BeatboxHit::Kick, None, 1500, 0.9),
+            72_000
+        )
+        .is_none());
+    assert!(results.try_recv().is_err());
+}
+
+#[test]
+fn result_rate_cap_emits_at_cap_and_counts_the_rest() {
+    let config = ResultEmissionConfig {
+        max_results_per_second: 20.0,
+        max_result_burst: 4,
+        ..ResultEmissionConfig::default()
+    };
+    let (mut worker, _results) = WorkerBuilder::default().results_config(config).build();
+    let telemetry_before = telemetry::hub().suppressed_results();
+
+    // A buzz roll: one result per millisecond for a second
+    let attempts = 1000u64;
+    let emitted = (0..attempts)
+        .filter(|&ms| {
+            worker
+                .emit_result(
+                    ClassificationResult::new(BeatboxHit::Snare, None, ms, 0.9),
+                    ms * 48,
+                )
+                .is_some()
+        })
+        .count() as u64;
+
+    // The initial burst plus one result per 50ms
+    assert!((23..=24).contains(&emitted), "emitted {emitted}");
+    assert_eq!(worker.limits.suppressed, attempts - emitted);
+    assert!(telemetry::hub().suppressed_results() - telemetry_before >= attempts - emitted);
+}
+
+#[test]
+fn confidence_smoothing_follows_recent_hits_per_sound() {
+    let config = ResultEmissionConfig {
+        confidence_smoothing: 0.5,
+        ..ResultEmissionConfig::default()
+    };
+    let (mut worker, mut results) = WorkerBuilder::default().results_config(config).build();
+
+    let hits = [
+        (BeatboxHit::Kick, 0.9),
+        (BeatboxHit::Kick, 0.5),
+        (BeatboxHit::Snare, 0.2),
+        (BeatboxHit::Kick, 0.7),
+        (BeatboxHit::Kick, 0.3),
+    ];
+    for (i, &(sound, confidence)) in hits.iter().enumerate() {
+        let ms = i as u64 * 250;
+        assert!(worker
+            .emit_result(
+                ClassificationResult::new(sound, None, ms, confidence),
+                ms * 48
+            )
+            .is_some());
+    }
+
+    let emitted: Vec<_> = std::iter::from_fn(|| results.try_recv().ok()).collect();
+    let smoothed: Vec<f32> = emitted
+        .iter()
+        .map(|r| r.smoothed_confidence.expect("smoothing enabled"))
+        .collect();
+    // Kicks: 0.9 → 0.7 → 0.7 → 0.5; the snare starts its own history
+    let expected = [0.9, 0.7, 0.2, 0.7, 0.5];
+    for (actual, expected) in smoothed.iter().zip(expected) {
+        assert!((actual - expected).abs() < 1e-6, "{smoothed:?}");
+    }
+    // The raw confidence is still reported unchanged
+    assert_eq!(emitted[1].confidence, 0.5);
+
+    let json = serde_json::to_value(&emitted[4]).unwrap();
+    assert!((json["smoothed_confidence"].as_f64().unwrap() - 0.5).abs() < 1e-6);
+}
+
+#[test]
+fn results_below_confidence_floor_are_not_sent() {
+    let config = ResultEmissionConfig {
+        suppress_below_confidence: 0.5,
+        ..ResultEmissionConfig::default()
+    };
+    let (mut worker, mut results) = WorkerBuilder::default().results_config(config).build();
+
+    assert!(worker
+        .emit_result(
+            ClassificationResult::new(BeatboxHit::Unknown, None, 100, 0.3),
+            4_800
+        )
+        .is_none());
+    assert!(worker
+        .emit_result(
+            ClassificationResult::new(BeatboxHit::Kick, None, 200, 0.49),
+            9_600
+        )
+        .is_none());
+    assert!(worker
+        .emit_result(
+            ClassificationResult::new(BeatboxHit::Kick, None, 300, 0.5),
+            14_400
+        )
+        .is_some());
+    assert!(worker
+        .emit_result(
+            ClassificationResult::new(BeatboxHit::Snare, None, 400, 0.8),
+            19_200
+        )
+        .is_some());
+
+    let emitted: Vec<u64> = std::iter::from_fn(|| results.try_recv().ok())
+        .map(|r| r.timestamp_ms)
+        .collect();
+    assert_eq!(emitted, vec![300, 400]);
+}
+
+#[test]
+fn results_carry_engine_and_wall_clock_timestamps() {
+    let (mut worker, mut results) = WorkerBuilder::default().build();
+    let before = telemetry::now_timestamp_ms();
+
+    assert!(worker
+        .emit_result(
+            ClassificationResult::new(BeatboxHit::Kick, None, 1500, 0.9),
+            72_000
+        )
+        .is_some());
+    let result = results.try_recv().expect("result");
+
+    assert_eq!(result.timestamp_ms, 1500);
+    // Unix milliseconds, not engine-relative: after 2020 and not in the future
+    assert!(result.wall_clock_ms > 1_577_836_800_000);
+    assert!((before..=telemetry::now_timestamp_ms()).contains(&result.wall_clock_ms));
+
+    let json = serde_json::to_value(&result).unwrap();
+    assert_eq!(json["timestamp_ms"], 1500);
+    assert_eq!(json["wall_clock_ms"], result.wall_clock_ms);
+}
+
+#[test]
+fn processing_latency_is_the_gap_between_detection_and_send() {
+    let detected_at = Instant::now();
+    let sent_at = detected_at + Duration::from_micros(2_750);
+
+    assert_eq!(
+        processing_latency(detected_at, sent_at),
+        Duration::from_micros(2_750)
+    );
+    // An out-of-order clock reading never goes negative
+    assert_eq!(processing_latency(sent_at, detected_at), Duration::ZERO);
+}
+
+#[test]
+fn held_result_is_timed_from_its_own_detection() {
+    let (mut worker, mut results) = WorkerBuilder::default()
+        .onset_config(OnsetDetectionConfig {
+            flam_window_ms: 30.0,
+            ..OnsetDetectionConfig::default()
+        })
+        .build();
+    let mut metrics_rx = telemetry::hub().collector().subscribe();
+    let long_ago = Instant::now()
+        .checked_sub(Duration::from_secs(5))
+        .expect("clock too close to its epoch");
+
+    worker.emit_or_hold_for_flam(
+        ClassificationResult::new(BeatboxHit::Kick, None, 1000, 0.9),
+        48_000,
+        long_ago,
+    );
+    // A later hit releases the held one; its latency runs from its own
+    // detection, not from the hit that released it
+    worker.emit_or_hold_for_flam(
+        ClassificationResult::new(BeatboxHit::Snare, None, 2000, 0.9),
+        96_000,
+        Instant::now(),
+    );
+    assert_eq!(results.try_recv().unwrap().sound, BeatboxHit::Kick);
+
+    let latencies: Vec<f32> = std::iter::from_fn(|| loop {
+        match metrics_rx.try_recv() {
+            Ok(MetricEvent::ProcessingLatency { latency_ms }) => return Some(latency_ms),
+            Ok(_) | Err(TryRecvError::Lagged(_)) => continue,
+            Err(_) => return None,
+        }
+    })
+    .collect();
+    assert!(
+        latencies.iter().any(|&latency_ms| latency_ms >= 5_000.0),
+        "latencies {latencies:?}"
+    );
+}
+
+#[test]
+fn onsets_15ms_apart_become_one_flam_result() {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(454);
+    let burst: Vec<f32> = (0..1024).map(|_| rng.gen_range(-0.5..0.5)).collect();
+    let onset = |timestamp| Onset {
+        timestamp,
+        strength: 1.0,
+    };
+
+    let run = |flam_window_ms: f32| {
+        let (mut worker, mut results) = WorkerBuilder::default()
+            .onset_config(OnsetDetectionConfig {
+                flam_window_ms,
+                ..OnsetDetectionConfig::default()
+            })
+            .build();
+        worker.accumulator = burst.clone();
+
+        // 15 ms at 48 kHz
+        worker.process_onsets(detected_now(vec![onset(48_000)]), &CLASSIFYING);
+        worker.process_onsets(detected_now(vec![onset(48_720)]), &CLASSIFYING);
+        worker.processed_samples = 96_000;
+        worker.flush_expired_flam();
+        std::iter::from_fn(|| results.try_recv().ok()).collect::<Vec<_>>()
+    };
+
+    let results = run(30.0);
+    assert_eq!(results.len(), 1);
+    assert!(results[0].flam);
+    assert_eq!(results[0].timestamp_ms, 1000);
+
+    // Without the mode both onsets are reported, neither flagged
+    let results = run(0.0);
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|result| !result.flam));
+}
+
+#[test]
+fn second_onset_after_a_level_capture_makes_it_a_flam() {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(454);
+    let burst: Vec<f32> = (0..1024).map(|_| rng.gen_range(-0.5..0.5)).collect();
+    let onset = |timestamp| Onset {
+        timestamp,
+        strength: 1.0,
+    };
+
+    let run = |flam_window_ms: f32| {
+        let (mut worker, mut results) = WorkerBuilder::default()
+            .onset_config(OnsetDetectionConfig {
+                flam_window_ms,
+                ..OnsetDetectionConfig::default()
+            })
+            .build();
+        assert_eq!(worker.onset_config.detection_source, DetectionSource::Both);
+        worker.accumulator = burst.clone();
+        worker.processed_samples = 48_000;
+
+        // The level crossing and the first onset are the same hit; the
+        // second onset follows 15 ms later
+        worker.process_level_crossing_classification(1.0, 0.01);
+        worker.process_onsets(
+            detected_now(vec![onset(47_900), onset(48_620)]),
+            &CLASSIFYING,
+        );
+        worker.processed_samples = 96_000;
+        worker.flush_expired_flam();
+        std::iter::from_fn(|| results.try_recv().ok()).collect::<Vec<_>>()
+    };
+
+    let results = run(30.0);
+    assert_eq!(results.len(), 1);
+    assert!(results[0].flam);
+    assert_eq!(results[0].trigger, HitTrigger::LevelCrossing);
+
+    // Without the mode both onsets are dropped as duplicates of the capture
+    let results = run(0.0);
+    assert_eq!(results.len(), 1);
+    assert!(!results[0].flam);
+}
+
+#[test]
+fn held_flam_result_is_sent_when_the_worker_stops() {
+    let (mut worker, mut results) = WorkerBuilder::default()
+        .onset_config(OnsetDetectionConfig {
+            flam_window_ms: 30.0,
+            ..OnsetDetectionConfig::default()
+        })
+        .build();
+    worker.shutdown_flag = Some(Arc::new(AtomicBool::new(false)));
+    worker.emit_or_hold_for_flam(
+        ClassificationResult::new(BeatboxHit::Kick, None, 1000, 0.9),
+        48_000,
+        Instant::now(),
+    );
+    assert!(results.try_recv().is_err());
+
+    worker.run();
+    assert_eq!(results.try_recv().unwrap().timestamp_ms, 1000);
+}
+
+#[test]
+fn held_result_is_sent_unflagged_once_the_flam_window_passes() {
+    let (mut worker, mut results) = WorkerBuilder::default()
+        .onset_config(OnsetDetectionConfig {
+            flam_window_ms: 30.0,
+            ..OnsetDetectionConfig::default()
+        })
+        .build();
+    worker.emit_or_hold_for_flam(
+        ClassificationResult::new(BeatboxHit::Kick, None, 1000, 0.9),
+        48_000,
+        Instant::now(),
+    );
+    assert!(results.try_recv().is_err());
+
+    worker.processed_samples = 48_000 + 1_440 + worker.onset_config.window_size as u64;
+    worker.flush_expired_flam();
+    assert!(results.try_recv().is_err());
+
+    worker.processed_samples += 1;
+    worker.flush_expired_flam();
+    assert!(!results.try_recv().unwrap().flam);
+}
+
+#[test]
+fn clipped_window_is_flagged_with_reduced_confidence() {
+    // A tone driven hard into the rails
+    let clipped: Vec<f32> = (0..2048)
+        .map(|i| (3.0 * (i as f32 * 0.13).sin()).clamp(-1.0, 1.0))
+        .collect();
+    let run = |clip_fraction_threshold: f32| {
+        let config = InputConfig {
+            clip_fraction_threshold,
+            ..InputConfig::default()
+        };
+        let (mut worker, mut results) = WorkerBuilder::default().input_config(config).build();
+        worker.accumulator = clipped.clone();
+        let onset = Onset {
+            timestamp: 48_000,
+            strength: 2.0,
+        };
+        worker.process_onsets(detected_now(vec![onset]), &CLASSIFYING);
+        results.try_recv().expect("classification result")
+    };
+
+    let unchecked = run(0.0);
+    assert!(!unchecked.clipped);
+
+    let flagged = run(0.1);
+    assert!(flagged.clipped);
+    assert_eq!(flagged.sound, unchecked.sound);
+    assert!(
+        flagged.confidence < unchecked.confidence,
+        "{} vs {}",
+        flagged.confidence,
+        unchecked.confidence
+    );
+
+    let (mut worker, mut results) = WorkerBuilder::default()
+        .input_config(InputConfig {
+            clip_fraction_threshold: 0.1,
+            clip_policy: crate::config::ClipPolicy::Suppress,
+            ..InputConfig::default()
+        })
+        .build();
+    worker.accumulator = clipped.clone();
+    let onset = Onset {
+        timestamp: 48_000,
+        strength: 2.0,
+    };
+    worker.process_onsets(detected_now(vec![onset]), &CLASSIFYING);
+    assert!(
+        results.try_recv().is_err(),
+        "clipped result was not suppressed"
+    );
+}
+
+#[test]
+fn real_hits_through_both_detectors_are_reported_once() {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    // Three seconds of decaying noise bursts, one every 375 ms
+    const SPACING: usize = 18_000;
+    let mut rng = StdRng::seed_from_u64(456);
+    let mut signal: Vec<f32> = (0..144_000).map(|_| rng.gen_range(-0.002..0.002)).collect();
+    let hits: Vec<usize> = (1..8).map(|i| i * SPACING).collect();
+    for &hit in &hits {
+        for (i, sample) in signal[hit..hit + 4800].iter_mut().enumerate() {
+            *sample += (-(i as f32) / 600.0).exp() * rng.gen_range(-0.7..0.7);
+        }
+    }
+
+    // Both detectors fire on every burst; the onset detector's frames
+    // overlap the buffer boundaries
+    let (mut worker, mut results) = WorkerBuilder::default().build();
+    let sent = analyze_stream(&mut worker, &mut results, &signal, 2048);
+
+    for &hit in &hits {
+        let hit_ms = (hit / 48) as u64;
+        let reports: Vec<_> = sent
+            .iter()
+            .filter(|result| result.timestamp_ms.abs_diff(hit_ms) < SPACING as u64 / 96)
+            .map(|result| (result.timestamp_ms, result.trigger))
+            .collect();
+        assert_eq!(reports.len(), 1, "hit at {hit_ms} ms: {reports:?}");
+    }
+    assert_eq!(sent.len(), hits.len());
+}
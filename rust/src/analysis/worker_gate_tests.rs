@@ -0,0 +1,325 @@
+use crate::analysis::onset::Onset;
+use crate::analysis::{ClassificationResult, HitTrigger};
+use crate::config::{DetectionSource, InputConfig, OnsetDetectionConfig};
+use crate::telemetry::{self, MetricEvent};
+
+use super::test_support::{detected_now, WorkerBuilder, CLASSIFYING};
+use super::AnalysisWorker;
+
+#[test]
+fn auto_sensitivity_locks_the_classification_gate() {
+    let config = OnsetDetectionConfig {
+        auto_sensitivity_ms: 100.0,
+        ..OnsetDetectionConfig::default()
+    };
+    let mut worker = WorkerBuilder::default().onset_config(config).build().0;
+    assert_eq!(worker.classification_gate(), 0.02);
+
+    // A quiet room: ~107ms of 1024-sample buffers at 0.001 RMS
+    worker.accumulator = vec![0.0; 1024];
+    for _ in 0..5 {
+        worker.observe_auto_sensitivity(0.001);
+    }
+    let locked = worker.classification_gate();
+    assert!(locked < 0.02, "gate {locked}");
+    let thresholds = worker.auto_sensitivity.as_ref().unwrap().locked().unwrap();
+    assert!(thresholds.onset_threshold_offset < 0.15);
+
+    // Disabled by default
+    assert_eq!(
+        WorkerBuilder::default().build().0.classification_gate(),
+        0.02
+    );
+}
+
+#[test]
+fn calibrated_noise_floor_overrides_the_auto_sensitivity_gate() {
+    let config = OnsetDetectionConfig {
+        auto_sensitivity_ms: 100.0,
+        ..OnsetDetectionConfig::default()
+    };
+    let mut worker = WorkerBuilder::default().onset_config(config).build().0;
+    worker.accumulator = vec![0.0; 1024];
+    for _ in 0..5 {
+        worker.observe_auto_sensitivity(0.001);
+    }
+    assert!(worker.classification_gate() < 0.02);
+
+    // Loading a calibration after the lock puts its floor back in charge
+    {
+        let mut state = worker.calibration_state.write().unwrap();
+        state.is_calibrated = true;
+        state.noise_floor_rms = 0.03;
+    }
+    assert_eq!(worker.classification_gate(), 0.06);
+
+    // Locking while calibrated leaves the onset threshold alone
+    let config = OnsetDetectionConfig {
+        auto_sensitivity_ms: 100.0,
+        ..OnsetDetectionConfig::default()
+    };
+    let mut worker = WorkerBuilder::default()
+        .onset_config(config.clone())
+        .build()
+        .0;
+    worker.calibration_state.write().unwrap().is_calibrated = true;
+    worker.accumulator = vec![0.0; 1024];
+    for _ in 0..5 {
+        worker.observe_auto_sensitivity(0.001);
+    }
+    // No audio processed yet, so the threshold is just the offset
+    assert_eq!(
+        worker.onset_detector.current_threshold(),
+        config.threshold_offset
+    );
+}
+
+#[test]
+fn tonal_gate_rejects_sine_but_passes_noise() {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    let config = OnsetDetectionConfig {
+        min_flatness_for_hit: 0.05,
+        ..OnsetDetectionConfig::default()
+    };
+    let worker = WorkerBuilder::default().onset_config(config).build().0;
+
+    let sine: Vec<f32> = (0..1024)
+        .map(|i| 0.5 * (2.0 * std::f32::consts::PI * 440.0 * i as f32 / 48_000.0).sin())
+        .collect();
+    let mut rng = StdRng::seed_from_u64(439);
+    let noise: Vec<f32> = (0..1024)
+        .map(|i| (-(i as f32) / 300.0).exp() * rng.gen_range(-0.5..0.5))
+        .collect();
+
+    let tonal = worker.feature_extractor.extract(&sine);
+    let percussive = worker.feature_extractor.extract(&noise);
+    assert!(
+        !worker.is_percussive(&tonal),
+        "sine flatness {}",
+        tonal.flatness
+    );
+    assert!(
+        worker.is_percussive(&percussive),
+        "noise flatness {}",
+        percussive.flatness
+    );
+
+    // Disabled by default
+    assert!(WorkerBuilder::default().build().0.is_percussive(&tonal));
+}
+
+#[test]
+fn tail_gate_rejects_a_click_but_passes_a_snare() {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    let config = OnsetDetectionConfig {
+        min_tail_ms: 10.0,
+        ..OnsetDetectionConfig::default()
+    };
+    let worker = WorkerBuilder::default().onset_config(config).build().0;
+    let mut rng = StdRng::seed_from_u64(467);
+    // Noise bursts at the start of a 1024-sample window: a 5 ms click that
+    // stops dead, and a snare fading 20 dB over 40 ms
+    let click: Vec<f32> = (0..1024)
+        .map(|i| {
+            if i < 240 {
+                rng.gen_range(-0.5..0.5)
+            } else {
+                0.0
+            }
+        })
+        .collect();
+    let snare: Vec<f32> = (0..1024)
+        .map(|i| {
+            let t_ms = i as f32 / 48.0;
+            0.1f32.powf(t_ms / 40.0) * rng.gen_range(-0.5..0.5)
+        })
+        .collect();
+
+    assert!(!worker.has_sustained_tail(&click));
+    assert!(worker.has_sustained_tail(&snare));
+
+    // Disabled by default
+    assert!(WorkerBuilder::default()
+        .build()
+        .0
+        .has_sustained_tail(&click));
+}
+
+fn level_crossing_results(
+    detection_source: crate::config::DetectionSource,
+) -> Vec<ClassificationResult> {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    let (mut worker, mut rx) = WorkerBuilder::default()
+        .onset_config(OnsetDetectionConfig {
+            detection_source,
+            ..OnsetDetectionConfig::default()
+        })
+        .build();
+    let mut rng = StdRng::seed_from_u64(448);
+    worker.accumulator = (0..2048).map(|_| rng.gen_range(-0.5f32..0.5)).collect();
+    // Well past the level-crossing debounce since engine start
+    worker.processed_samples = 48_000;
+
+    worker.process_level_crossing_classification(0.0, 0.05);
+    worker.process_level_crossing_classification(0.3, 0.05);
+
+    std::iter::from_fn(|| rx.try_recv().ok()).collect()
+}
+
+#[test]
+fn onset_only_policy_suppresses_level_crossing_results() {
+    let results = level_crossing_results(DetectionSource::Both);
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].trigger, HitTrigger::LevelCrossing);
+
+    assert!(level_crossing_results(DetectionSource::OnsetOnly).is_empty());
+}
+
+#[test]
+fn near_silent_window_skips_analysis_below_energy_floor() {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    // RMS ~0.029 clears the default 0.02 gate, energy over 1024 samples ~0.85
+    let mut rng = StdRng::seed_from_u64(450);
+    let quiet: Vec<f32> = (0..1024).map(|_| rng.gen_range(-0.05..0.05)).collect();
+    let onset = || Onset {
+        timestamp: 48_000,
+        strength: 1.0,
+    };
+
+    let run = |min_window_energy: f32| {
+        let (mut worker, mut results) = WorkerBuilder::default()
+            .onset_config(OnsetDetectionConfig {
+                min_window_energy,
+                ..OnsetDetectionConfig::default()
+            })
+            .build();
+        let (onset_tx, mut onset_rx) = tokio::sync::broadcast::channel(8);
+        worker.onset_events_tx = Some(onset_tx);
+        worker.accumulator = quiet.clone();
+
+        worker.process_onsets(detected_now(vec![onset()]), &CLASSIFYING);
+        (
+            results.try_recv().ok(),
+            onset_rx.try_recv().ok(),
+            worker.low_energy_skips,
+        )
+    };
+
+    // Without a floor the window is analysed and classified
+    let (result, event, skips) = run(0.0);
+    assert!(result.is_some());
+    assert!(event.and_then(|e| e.features).is_some());
+    assert_eq!(skips, 0);
+
+    // With one, it never reaches feature extraction
+    let (result, event, skips) = run(1.0);
+    assert!(result.is_none());
+    assert!(event.is_none());
+    assert_eq!(skips, 1);
+}
+
+#[test]
+fn onsets_over_the_per_buffer_cap_are_dropped_and_counted() {
+    let config = OnsetDetectionConfig {
+        max_onsets_per_buffer: 2,
+        ..OnsetDetectionConfig::default()
+    };
+    let (mut worker, mut results) = WorkerBuilder::default().onset_config(config).build();
+    worker.accumulator = (0..2048).map(|i| 0.5 * (i as f32 * 0.13).sin()).collect();
+    worker.processed_samples = 48_000;
+    let telemetry_before = telemetry::hub().dropped_onsets();
+
+    // A noisy buffer full of onsets
+    let onsets = (0..8).map(|i| Onset {
+        timestamp: 46_000 + i * 200,
+        strength: 2.0,
+    });
+    worker.process_onsets(detected_now(onsets), &CLASSIFYING);
+
+    let mut emitted = 0;
+    while results.try_recv().is_ok() {
+        emitted += 1;
+    }
+    assert!((1..=2).contains(&emitted), "emitted {emitted}");
+    assert_eq!(worker.dropped_onsets, 6);
+    assert!(telemetry::hub().dropped_onsets() - telemetry_before >= 6);
+}
+
+#[test]
+fn silence_event_is_sent_once_per_quiet_stretch() {
+    let config = InputConfig {
+        silence_timeout_ms: 100.0,
+        ..InputConfig::default()
+    };
+    let mut worker = WorkerBuilder::default().input_config(config).build().0;
+    let mut metrics_rx = telemetry::hub().collector().subscribe();
+    // ~21ms buffers against the default 0.02 classification gate
+    worker.accumulator = vec![0.0; 1024];
+    let step = |worker: &mut AnalysisWorker, rms: f64, buffers: usize| {
+        (0..buffers).filter(|_| worker.observe_silence(rms)).count()
+    };
+
+    // Playing, then 80ms of quiet: not yet
+    assert_eq!(step(&mut worker, 0.1, 3), 0);
+    assert_eq!(step(&mut worker, 0.001, 4), 0);
+    // Crossing 100ms sends one event, and staying quiet sends no more
+    assert_eq!(step(&mut worker, 0.001, 20), 1);
+    // Sound resumes and the next quiet stretch reports again
+    assert_eq!(step(&mut worker, 0.1, 1), 0);
+    assert_eq!(step(&mut worker, 0.001, 5), 1);
+
+    let mut silences = Vec::new();
+    loop {
+        match metrics_rx.try_recv() {
+            Ok(MetricEvent::Silence { quiet_ms }) => silences.push(quiet_ms),
+            Ok(_) | Err(tokio::sync::broadcast::error::TryRecvError::Lagged(_)) => {}
+            Err(_) => break,
+        }
+    }
+    assert_eq!(silences.len(), 2, "{silences:?}");
+    assert!(silences.iter().all(|&ms| (100.0..125.0).contains(&ms)));
+}
+
+#[test]
+fn clip_rails_follow_the_gain_in_effect() {
+    use std::sync::{Arc, Mutex};
+
+    use crate::calibration::procedure::CalibrationProcedure;
+    use crate::calibration::state::CalibrationState;
+
+    // Stored calibration halves the input; a new procedure measuring the
+    // input level runs at unity gain instead
+    let state = CalibrationState {
+        input_gain: 0.5,
+        ..CalibrationState::new_default()
+    };
+    let procedure = Arc::new(Mutex::new(Some(
+        CalibrationProcedure::new_for_test(10).with_input_level_hits(3),
+    )));
+    let worker = WorkerBuilder::default()
+        .input_config(InputConfig {
+            clip_fraction_threshold: 0.1,
+            ..InputConfig::default()
+        })
+        .calibration_state(state)
+        .calibration_procedure(&procedure)
+        .build()
+        .0;
+
+    // Loud, but well below the unity-gain rails
+    let loud = vec![0.6f32; 1024];
+    assert!(!worker.is_clipped(&loud));
+    assert!(worker.is_clipped(&[1.0f32; 1024]));
+
+    // Once the procedure is gone the stored gain scales the rails again
+    *procedure.lock().unwrap() = None;
+    assert!(worker.is_clipped(&loud));
+}
@@ -0,0 +1,176 @@
+// AnalysisWorker - checks a hit must pass before it is classified
+
+use std::time::Duration;
+
+use crate::analysis::features::Features;
+use crate::telemetry::{self, DiagnosticError};
+
+use super::{count_non_finite, AnalysisWorker};
+
+/// Absolute sample level treated as the input rail when detecting clipping
+const CLIP_LEVEL: f32 = 0.999;
+
+impl AnalysisWorker {
+    /// Whether onsets are reported without being classified
+    ///
+    /// Set explicitly via `monitor_mode`, or implied when someone listens for
+    /// onset events while nobody listens for classification results.
+    pub(super) fn monitor_mode(&self) -> bool {
+        self.onset_config.monitor_mode
+            || (self.result_sender.receiver_count() == 0
+                && self
+                    .onset_events_tx
+                    .as_ref()
+                    .is_some_and(|tx| tx.receiver_count() > 0))
+    }
+
+    /// Advance the silence timer with this buffer's RMS, publishing a
+    /// silence event when the quiet stretch reaches `silence_timeout_ms`
+    ///
+    /// Returns whether an event was published.
+    pub(super) fn observe_silence(&mut self, rms: f64) -> bool {
+        let quiet = rms < self.classification_gate();
+        if !self.silence_timer.update(quiet, self.accumulator.len()) {
+            return false;
+        }
+        let quiet_secs = self.silence_timer.quiet_samples as f64 / self.sample_rate as f64;
+        telemetry::hub().record_silence(Duration::from_secs_f64(quiet_secs));
+        true
+    }
+
+    /// Feed the auto-sensitivity window; applies the onset threshold once
+    /// it locks
+    pub(super) fn observe_auto_sensitivity(&mut self, rms: f64) {
+        let samples = self.accumulator.len();
+        let Some(thresholds) = self
+            .auto_sensitivity
+            .as_mut()
+            .and_then(|auto| auto.observe(rms, samples))
+        else {
+            return;
+        };
+        if self.is_calibrated() {
+            tracing::info!("[AnalysisThread] Auto-sensitivity ignored: a calibration is loaded");
+            return;
+        }
+        self.onset_detector
+            .set_threshold_offset(thresholds.onset_threshold_offset);
+        tracing::info!(
+            "[AnalysisThread] Auto-sensitivity locked: gate {:.4} RMS, onset offset {:.3}",
+            thresholds.gate_rms,
+            thresholds.onset_threshold_offset
+        );
+    }
+
+    /// Whether the user's own calibration (not the defaults) is loaded
+    pub(super) fn is_calibrated(&self) -> bool {
+        self.calibration_state
+            .read()
+            .map(|state| state.is_calibrated)
+            .unwrap_or(false)
+    }
+
+    /// RMS a hit must reach to be classified
+    ///
+    /// Twice the noise floor. Before calibration, a gate locked by
+    /// auto-sensitivity replaces the default floor; a calibrated noise
+    /// floor always takes precedence over it.
+    pub(super) fn classification_gate(&self) -> f64 {
+        let (calibrated, floor_gate) = match self.calibration_state.read() {
+            Ok(state) => (state.is_calibrated, state.noise_floor_rms * 2.0),
+            Err(_) => (false, 0.02), // Conservative fallback
+        };
+        match self.auto_sensitivity.as_ref().and_then(|a| a.locked()) {
+            Some(thresholds) if !calibrated => thresholds.gate_rms,
+            _ => floor_gate,
+        }
+    }
+
+    /// Whether a window and its features are safe to classify
+    ///
+    /// Some drivers deliver NaN/Inf samples. The extractor treats them as
+    /// silence, but a result built from a corrupted window would be
+    /// meaningless, so it is dropped and reported via telemetry instead.
+    pub(super) fn is_classifiable(window: &[f32], features: &Features) -> bool {
+        let non_finite = count_non_finite(window);
+        if non_finite == 0 && features.is_finite() {
+            return true;
+        }
+
+        tracing::warn!(
+            "[AnalysisThread] Skipping classification: {} non-finite samples, features {:?}",
+            non_finite,
+            features
+        );
+        telemetry::hub().record_error(
+            DiagnosticError::InvalidAudio,
+            format!("{non_finite} non-finite samples in analysis window"),
+        );
+        false
+    }
+
+    /// Whether `window` has enough energy to be worth an FFT
+    ///
+    /// A plain sum of squares, so it is far cheaper than the feature
+    /// extraction it guards.
+    pub(super) fn meets_energy_floor(window: &[f32], min_energy: f32) -> bool {
+        if min_energy <= 0.0 {
+            return true;
+        }
+        let energy: f32 = window.iter().map(|sample| sample * sample).sum();
+        energy >= min_energy
+    }
+
+    /// Whether enough of `window` sits at the input rails to distort its
+    /// features (never, with `clip_fraction_threshold` at 0)
+    ///
+    /// The window has the input gain applied, so the rails are scaled by
+    /// the gain in effect (a calibration procedure's own gain, if any).
+    pub(super) fn is_clipped(&self, window: &[f32]) -> bool {
+        let threshold = self.input_config.clip_fraction_threshold;
+        if threshold <= 0.0 || window.is_empty() {
+            return false;
+        }
+        let rail = CLIP_LEVEL * self.input_gain().abs();
+        let at_rail = window.iter().filter(|sample| sample.abs() >= rail).count();
+        at_rail as f32 / window.len() as f32 >= threshold
+    }
+
+    /// Whether the spectrum is noisy enough to be a percussive hit
+    ///
+    /// Pitched sounds (speech, music bleed) have a low spectral flatness;
+    /// with `min_flatness_for_hit` set they are ignored instead of being
+    /// classified.
+    pub(super) fn is_percussive(&self, features: &Features) -> bool {
+        if features.flatness >= self.onset_config.min_flatness_for_hit {
+            return true;
+        }
+        tracing::debug!(
+            "[AnalysisThread] Ignoring tonal onset (flatness {:.3} < {:.3})",
+            features.flatness,
+            self.onset_config.min_flatness_for_hit
+        );
+        false
+    }
+
+    /// Whether the hit in `window` rings on for at least `min_tail_ms`
+    ///
+    /// Mouth clicks and pops die away within a few milliseconds; with
+    /// `min_tail_ms` set they are ignored instead of being classified.
+    pub(super) fn has_sustained_tail(&self, window: &[f32]) -> bool {
+        let min_tail_ms = self.onset_config.min_tail_ms;
+        if min_tail_ms <= 0.0 {
+            return true;
+        }
+        let tail_ms = self.tail_meter.compute_decay_time(window);
+        if tail_ms >= min_tail_ms {
+            return true;
+        }
+        tracing::debug!(
+            "[AnalysisThread] Ignoring short transient (tail {:.1} ms < {:.1} ms)",
+            tail_ms,
+            min_tail_ms
+        );
+        false
+    }
+}
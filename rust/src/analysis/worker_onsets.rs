@@ -0,0 +1,318 @@
+// AnalysisWorker - detecting and classifying hits
+//
+// Hits come from two detectors: level crossings of the gate envelope and
+// spectral-flux onsets. Outside calibration both end in a classification
+// result; during calibration they are offered to the procedure instead
+// (see worker_calibration.rs).
+
+use std::time::Instant;
+
+use crate::analysis::features::fft::FFT_SIZE;
+use crate::analysis::features::{FeatureExtractor, Features};
+use crate::analysis::onset::Onset;
+use crate::analysis::result::{ClassificationResult, HitTrigger};
+use crate::telemetry;
+
+use super::worker_calibration::BatchCalibration;
+use super::{finite_rms, AnalysisWorker};
+
+/// Levels and features of a hit's window
+#[derive(Debug, Clone, Copy)]
+pub(super) struct WindowStats {
+    pub(super) features: Features,
+    pub(super) rms: f64,
+    pub(super) max_amplitude: f32,
+}
+
+impl WindowStats {
+    pub(super) fn measure(extractor: &FeatureExtractor, window: &[f32]) -> Self {
+        Self {
+            features: extractor.extract(window),
+            rms: finite_rms(window),
+            max_amplitude: window
+                .iter()
+                .map(|sample| sample.abs())
+                .fold(0.0f32, f32::max),
+        }
+    }
+}
+
+impl AnalysisWorker {
+    /// Run the level-crossing detector for calibration or classification
+    pub(super) fn process_level_crossings(
+        &mut self,
+        calibration: &BatchCalibration,
+        window_rms: f64,
+        gate_level: f64,
+    ) {
+        if self.accumulator.len() < 1024 {
+            return;
+        }
+        if calibration.active {
+            // ====== LEVEL-CROSSING DETECTOR FOR CALIBRATION ======
+            // Simpler detection: capture sample when RMS crosses from below to above threshold
+            // This runs IN ADDITION to onset detection, catching sounds that spectral flux misses
+            let detection_threshold = calibration
+                .detection_threshold
+                .unwrap_or(calibration.quiet_gate * 2.0); // 2x noise floor fallback
+            self.process_level_crossing_calibration(window_rms, detection_threshold);
+        } else if !self.monitor_mode() {
+            // ====== LEVEL-CROSSING DETECTOR FOR CLASSIFICATION ======
+            // Same approach as calibration: detect when RMS crosses from below to above threshold
+            // This is more reliable than onset detection which can fire on spectral changes in quiet audio
+            let noise_floor_gate = self.classification_gate();
+            self.process_level_crossing_classification(gate_level, noise_floor_gate);
+        }
+    }
+
+    pub(super) fn process_level_crossing_classification(
+        &mut self,
+        gate_level: f64,
+        noise_floor_gate: f64,
+    ) {
+        if !self.onset_config.detection_source.uses_level_crossing() {
+            return;
+        }
+        if let Some(event) = self.level_crossing_detector.process_classification(
+            gate_level,
+            noise_floor_gate,
+            self.processed_samples,
+        ) {
+            tracing::info!(
+                "[AnalysisThread] Level crossing event {:?} for classification (level {:.4}, gate {:.4})",
+                event,
+                gate_level,
+                noise_floor_gate
+            );
+            self.capture_level_crossing(false);
+        }
+        for (capture_sample, detected_at) in self.take_due_captures(false) {
+            self.classify_level_capture(capture_sample, detected_at);
+        }
+    }
+
+    /// Classify a level-crossing capture and send (or hold) its result
+    fn classify_level_capture(&mut self, capture_sample: u64, detected_at: Instant) {
+        let crossing_window = self.capture_window(capture_sample);
+        if !Self::meets_energy_floor(&crossing_window, self.onset_config.min_window_energy) {
+            drop(crossing_window);
+            self.low_energy_skips += 1;
+            return;
+        }
+        let crossing_features = self.feature_extractor.extract(&crossing_window);
+        if !Self::is_classifiable(&crossing_window, &crossing_features)
+            || !self.is_percussive(&crossing_features)
+            || !self.has_sustained_tail(&crossing_window)
+        {
+            return;
+        }
+        let clipped = self.is_clipped(&crossing_window);
+        drop(crossing_window);
+
+        // Classify sound (returns tuple of (BeatboxHit, confidence))
+        let (sound, confidence) = self.classifier.classify(&crossing_features);
+
+        // Timing feedback
+        // Note: For level-crossing detection, we don't have precise onset timestamps,
+        // so the capture's sample count stands in for the onset position.
+        let timing = self.timing_for(capture_sample);
+
+        // Timestamp is approximate for level-crossing detection
+        let timestamp_ms = (capture_sample as f64 / self.sample_rate as f64 * 1000.0) as u64;
+
+        // Create result and send to Dart UI
+        let result = ClassificationResult::new(sound, timing, timestamp_ms, confidence)
+            .with_beat_position(self.beat_position_for(capture_sample))
+            .with_trigger(HitTrigger::LevelCrossing);
+        let Some(result) = self.apply_clip_policy(result, clipped) else {
+            return;
+        };
+
+        eprintln!(
+            "[AnalysisThread] CLASSIFIED via level-crossing: {:?} (confidence {:.2})",
+            sound, confidence
+        );
+
+        // Send result to broadcast channel
+        self.emit_or_hold_for_flam(result, capture_sample, detected_at);
+    }
+
+    /// Run onset detection over the accumulator and handle the onsets that
+    /// are due
+    pub(super) fn process_detected_onsets(&mut self, calibration: &BatchCalibration) {
+        // Process accumulated buffer through onset detection
+        let onsets = self.onset_detector.process(&self.accumulator);
+        let detected_at = Instant::now();
+        self.onset_threshold
+            .publish(self.onset_detector.current_threshold());
+
+        if !onsets.is_empty() {
+            tracing::info!(
+                "[AnalysisThread] Detected {} onsets (strengths {:?})",
+                onsets.len(),
+                onsets.iter().map(|o| o.strength).collect::<Vec<_>>()
+            );
+        }
+        let onsets = self.take_due_onsets(onsets, detected_at);
+
+        self.process_onsets(onsets, calibration);
+        self.flush_expired_flam();
+    }
+
+    /// Handle due onsets, each with the time it was detected
+    pub(super) fn process_onsets(
+        &mut self,
+        onsets: Vec<(Onset, Instant)>,
+        calibration: &BatchCalibration,
+    ) {
+        let monitor_mode = !calibration.active && self.monitor_mode();
+        let kept = self.cap_onsets(onsets.len());
+        for (onset, detected_at) in onsets.into_iter().take(kept) {
+            if monitor_mode {
+                self.publish_monitored_onset(&onset);
+            } else {
+                self.process_onset(&onset, detected_at, calibration);
+            }
+        }
+    }
+
+    /// Number of a batch's onsets to process under `max_onsets_per_buffer`;
+    /// the rest are counted as dropped
+    fn cap_onsets(&mut self, count: usize) -> usize {
+        let max_onsets = self.onset_config.max_onsets_per_buffer;
+        let kept = if max_onsets > 0 {
+            count.min(max_onsets)
+        } else {
+            count
+        };
+        let dropped = count - kept;
+        if dropped > 0 {
+            self.dropped_onsets += dropped as u64;
+            telemetry::hub().record_dropped_onsets(dropped as u64);
+            tracing::debug!(
+                "[AnalysisThread] Dropping {} onsets over the per-buffer cap of {} ({} dropped)",
+                dropped,
+                max_onsets,
+                self.dropped_onsets
+            );
+        }
+        kept
+    }
+
+    /// Report an onset without features or classification (monitor mode)
+    fn publish_monitored_onset(&self, onset: &Onset) {
+        let energy = if self.accumulator.len() >= FFT_SIZE {
+            finite_rms(&self.onset_window(onset.timestamp))
+        } else {
+            finite_rms(&self.accumulator)
+        };
+        self.publish_onset_event(onset, energy, None, None);
+    }
+
+    /// Whether an onset is passed over: its detector isn't used, it
+    /// duplicates a level-crossing capture, or the batch is too short
+    fn skips_onset(&mut self, onset_timestamp: u64, calibration: &BatchCalibration) -> bool {
+        let detection_source = self.onset_config.detection_source;
+        if !calibration.active && !detection_source.uses_onsets() {
+            return true;
+        }
+
+        // Dedup against level crossings, unless they can't classify
+        let dedup = calibration.active || detection_source.uses_level_crossing();
+        if dedup
+            && self
+                .flam
+                .duplicates_level_capture(onset_timestamp, calibration.active)
+        {
+            tracing::debug!(
+                "[AnalysisThread] Skipping onset duplicate (captured via level-crossing)"
+            );
+            return true;
+        }
+
+        if self.accumulator.len() < 1024 {
+            tracing::debug!(
+                "[AnalysisThread] Skipping onset - accumulator too small: {} < 1024",
+                self.accumulator.len()
+            );
+            return true;
+        }
+        false
+    }
+
+    fn process_onset(
+        &mut self,
+        onset: &Onset,
+        detected_at: Instant,
+        calibration: &BatchCalibration,
+    ) {
+        let onset_timestamp = onset.timestamp;
+        if self.skips_onset(onset_timestamp, calibration) {
+            return;
+        }
+
+        let onset_window = self.onset_window(onset_timestamp);
+        if !calibration.active
+            && !Self::meets_energy_floor(&onset_window, self.onset_config.min_window_energy)
+        {
+            tracing::debug!("[AnalysisThread] Skipping onset - window below energy floor");
+            drop(onset_window);
+            self.low_energy_skips += 1;
+            return;
+        }
+        let stats = WindowStats::measure(&self.feature_extractor, &onset_window);
+        tracing::debug!(
+            "[AnalysisThread] Onset features: centroid {:.1} Hz, zcr {:.3}, rms {:.4}, max_amp {:.3}",
+            stats.features.centroid,
+            stats.features.zcr,
+            stats.rms,
+            stats.max_amplitude
+        );
+
+        if calibration.active {
+            drop(onset_window);
+            let quiet_gate = calibration
+                .detection_threshold
+                .unwrap_or(calibration.quiet_gate);
+            self.add_onset_calibration_sample(onset_timestamp, &stats, quiet_gate);
+            return;
+        }
+
+        let noise_floor_gate = self.classification_gate();
+        if !Self::is_classifiable(&onset_window, &stats.features)
+            || !self.is_percussive(&stats.features)
+            || !self.has_sustained_tail(&onset_window)
+            || stats.rms < noise_floor_gate
+        {
+            return;
+        }
+        let clipped = self.is_clipped(&onset_window);
+        drop(onset_window);
+        self.classify_onset(onset, detected_at, &stats, clipped);
+    }
+
+    /// Classify an onset that passed the gates and send (or hold) its result
+    fn classify_onset(
+        &mut self,
+        onset: &Onset,
+        detected_at: Instant,
+        stats: &WindowStats,
+        clipped: bool,
+    ) {
+        let onset_timestamp = onset.timestamp;
+        let (sound, confidence) = self.classifier.classify(&stats.features);
+        let timing = self.timing_for(onset_timestamp);
+
+        let timestamp_ms = (onset_timestamp as f64 / self.sample_rate as f64 * 1000.0) as u64;
+
+        let result = ClassificationResult::new(sound, timing, timestamp_ms, confidence)
+            .with_beat_position(self.beat_position_for(onset_timestamp));
+        let Some(result) = self.apply_clip_policy(result, clipped) else {
+            return;
+        };
+
+        // Attached to the onset event only once it has actually gone out
+        let sent = self.emit_or_hold_for_flam(result, onset_timestamp, detected_at);
+        self.publish_onset_event(onset, stats.rms, Some(&stats.features), sent);
+    }
+}
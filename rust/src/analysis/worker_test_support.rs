@@ -0,0 +1,175 @@
+// Shared setup for the AnalysisWorker tests
+
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Instant;
+
+use tokio::sync::broadcast;
+
+use crate::analysis::listening::ListeningWindows;
+use crate::analysis::onset::Onset;
+use crate::analysis::result::ClassificationResult;
+use crate::audio::buffer_pool::{AnalysisThreadChannels, BufferPool};
+use crate::calibration::procedure::CalibrationProcedure;
+use crate::calibration::state::CalibrationState;
+use crate::config::{
+    AnalysisConfig, AnalysisWorkerConfig, ClassifierConfig, InputConfig, OnsetDetectionConfig,
+    ResultEmissionConfig, TimingConfig,
+};
+
+use super::worker_calibration::BatchCalibration;
+use super::AnalysisWorker;
+
+/// Onset handling outside calibration
+pub(super) const CLASSIFYING: BatchCalibration = BatchCalibration {
+    active: false,
+    detection_threshold: None,
+    quiet_gate: 0.0,
+};
+
+/// Onsets as the detector just reported them
+pub(super) fn detected_now(onsets: impl IntoIterator<Item = Onset>) -> Vec<(Onset, Instant)> {
+    let now = Instant::now();
+    onsets.into_iter().map(|onset| (onset, now)).collect()
+}
+
+/// Builds a worker for tests
+///
+/// Defaults to no metronome, 4 beats per bar at 48 kHz with default
+/// configs, no calibration procedure and private listening windows, so the
+/// shared API state other tests use is untouched.
+pub(super) struct WorkerBuilder {
+    analysis_channels: Option<AnalysisThreadChannels>,
+    calibration_state: CalibrationState,
+    calibration_procedure: Arc<Mutex<Option<CalibrationProcedure>>>,
+    bpm: u32,
+    beats_per_bar: u32,
+    config: AnalysisConfig,
+    shutdown_flag: Option<Arc<AtomicBool>>,
+}
+
+impl Default for WorkerBuilder {
+    fn default() -> Self {
+        Self {
+            analysis_channels: None,
+            calibration_state: CalibrationState::new_default(),
+            calibration_procedure: Arc::new(Mutex::new(None)),
+            bpm: 0,
+            beats_per_bar: 4,
+            config: AnalysisConfig::default(),
+            shutdown_flag: None,
+        }
+    }
+}
+
+impl WorkerBuilder {
+    pub(super) fn bpm(mut self, bpm: u32) -> Self {
+        self.bpm = bpm;
+        self
+    }
+
+    pub(super) fn beats_per_bar(mut self, beats_per_bar: u32) -> Self {
+        self.beats_per_bar = beats_per_bar;
+        self
+    }
+
+    pub(super) fn onset_config(mut self, config: OnsetDetectionConfig) -> Self {
+        self.config.onset_detection = config;
+        self
+    }
+
+    pub(super) fn classifier_config(mut self, config: ClassifierConfig) -> Self {
+        self.config.classifier = config;
+        self
+    }
+
+    pub(super) fn results_config(mut self, config: ResultEmissionConfig) -> Self {
+        self.config.results = config;
+        self
+    }
+
+    pub(super) fn worker_config(mut self, config: AnalysisWorkerConfig) -> Self {
+        self.config.analysis_worker = config;
+        self
+    }
+
+    pub(super) fn input_config(mut self, config: InputConfig) -> Self {
+        self.config.input = config;
+        self
+    }
+
+    pub(super) fn timing_config(mut self, config: TimingConfig) -> Self {
+        self.config.timing = config;
+        self
+    }
+
+    pub(super) fn calibration_state(mut self, state: CalibrationState) -> Self {
+        self.calibration_state = state;
+        self
+    }
+
+    /// Run a calibration procedure the test keeps a handle to
+    pub(super) fn calibration_procedure(
+        mut self,
+        procedure: &Arc<Mutex<Option<CalibrationProcedure>>>,
+    ) -> Self {
+        self.calibration_procedure = Arc::clone(procedure);
+        self
+    }
+
+    /// Consume buffers from a pool the test feeds, instead of a private one
+    pub(super) fn analysis_channels(mut self, channels: AnalysisThreadChannels) -> Self {
+        self.analysis_channels = Some(channels);
+        self
+    }
+
+    pub(super) fn shutdown_flag(mut self, flag: &Arc<AtomicBool>) -> Self {
+        self.shutdown_flag = Some(Arc::clone(flag));
+        self
+    }
+
+    pub(super) fn build(self) -> (AnalysisWorker, broadcast::Receiver<ClassificationResult>) {
+        let analysis_channels = self
+            .analysis_channels
+            .unwrap_or_else(|| BufferPool::new(4, 512).split_for_threads().1);
+        let (result_tx, result_rx) = broadcast::channel(8);
+        let mut worker = AnalysisWorker::new(
+            analysis_channels,
+            Arc::new(RwLock::new(self.calibration_state)),
+            self.calibration_procedure,
+            None,
+            Arc::new(AtomicU64::new(0)),
+            Arc::new(AtomicU32::new(self.bpm)),
+            self.beats_per_bar,
+            48_000,
+            result_tx,
+            self.config,
+            0,
+            self.shutdown_flag,
+            None,
+            None,
+        )
+        .unwrap();
+        worker.listening_windows = ListeningWindows::default();
+        (worker, result_rx)
+    }
+}
+
+/// Run `signal` through the analysis loop in `buffer_size` buffers and
+/// collect the results sent along the way
+pub(super) fn analyze_stream(
+    worker: &mut AnalysisWorker,
+    results: &mut broadcast::Receiver<ClassificationResult>,
+    signal: &[f32],
+    buffer_size: usize,
+) -> Vec<ClassificationResult> {
+    let mut sent = Vec::new();
+    for buffer in signal.chunks(buffer_size) {
+        let input_gain = worker.ingest(buffer);
+        worker.analyze_accumulated(input_gain);
+        sent.extend(std::iter::from_fn(|| results.try_recv().ok()));
+    }
+    worker.flush_pending_flam();
+    sent.extend(std::iter::from_fn(|| results.try_recv().ok()));
+    sent
+}
@@ -0,0 +1,466 @@
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
+
+use crate::analysis::features::Features;
+use crate::analysis::onset::{Onset, OnsetThreshold};
+use crate::analysis::quantizer;
+use crate::analysis::{run_analysis_once, ClassificationResult};
+use crate::calibration::state::{CalibrationState, Level2Thresholds};
+use crate::config::{
+    AnalysisConfig, ClassifierConfig, FeatureMask, OnsetDetectionConfig, ResultEmissionConfig,
+    TimingConfig,
+};
+use crate::types::BeatboxHit;
+
+use super::test_support::{detected_now, WorkerBuilder, CLASSIFYING};
+
+#[test]
+fn classifier_config_thresholds_apply_to_live_classification() {
+    let kick = Features {
+        centroid: 500.0,
+        zcr: 0.02,
+        flatness: 0.0,
+        rolloff: 0.0,
+        decay_time_ms: 0.0,
+    };
+    let (sound, confidence) = WorkerBuilder::default()
+        .build()
+        .0
+        .classifier
+        .classify(&kick);
+    assert_eq!(sound, BeatboxHit::Kick);
+
+    let strict = ClassifierConfig {
+        min_confidence: HashMap::from([(BeatboxHit::Kick, confidence + 0.01)]),
+        ..ClassifierConfig::default()
+    };
+    let worker = WorkerBuilder::default().classifier_config(strict).build().0;
+    assert_eq!(worker.classifier.classify(&kick).0, BeatboxHit::Unknown);
+}
+
+#[test]
+fn feature_mask_applies_to_live_classification() {
+    let hit = |centroid: f32| Features {
+        centroid,
+        zcr: 0.05,
+        flatness: 0.0,
+        rolloff: 0.0,
+        decay_time_ms: 0.0,
+    };
+    // Low and mid centroid: a kick and a snare with every feature enabled
+    let worker = WorkerBuilder::default().build().0;
+    assert_ne!(
+        worker.classifier.classify(&hit(500.0)).0,
+        worker.classifier.classify(&hit(3000.0)).0
+    );
+
+    let no_centroid = ClassifierConfig {
+        feature_mask: FeatureMask {
+            centroid: false,
+            ..FeatureMask::default()
+        },
+        ..ClassifierConfig::default()
+    };
+    let worker = WorkerBuilder::default()
+        .classifier_config(no_centroid)
+        .build()
+        .0;
+    assert_eq!(
+        worker.classifier.classify(&hit(500.0)),
+        worker.classifier.classify(&hit(3000.0))
+    );
+}
+
+#[test]
+fn classification_only_mode_skips_quantizer() {
+    let mut worker = WorkerBuilder::default().build().0;
+    assert!(worker.quantizer.is_none());
+
+    assert!(worker.timing_for(24_000).is_none());
+    assert!(
+        worker.quantizer.is_none(),
+        "quantizer must not be built without a metronome"
+    );
+}
+
+#[test]
+fn metronome_mode_reports_timing() {
+    let mut worker = WorkerBuilder::default().bpm(120).build().0;
+    let timing = worker.timing_for(24_000).expect("timed result");
+    assert_eq!(
+        timing.classification,
+        quantizer::TimingClassification::OnTime
+    );
+}
+
+#[test]
+fn timed_results_carry_beat_position() {
+    let mut worker = WorkerBuilder::default().bpm(120).build().0;
+    let timing = worker.timing_for(48_000);
+    let result = ClassificationResult::new(BeatboxHit::Kick, timing, 1000, 0.9)
+        .with_beat_position(worker.beat_position_for(48_000));
+    assert_eq!(
+        (result.beat_index, result.subdivision_index),
+        (Some(2), Some(0))
+    );
+
+    let json = serde_json::to_value(&result).unwrap();
+    assert_eq!(json["beat_index"], 2);
+
+    // No metronome, no grid
+    let worker = WorkerBuilder::default().build().0;
+    assert!(worker.beat_position_for(48_000).is_none());
+}
+
+#[test]
+fn metronome_running_at_startup_uses_the_configured_time_signature() {
+    // Started at 120 BPM, so the quantizer is built before the first onset
+    let worker = WorkerBuilder::default()
+        .bpm(120)
+        .beats_per_bar(3)
+        .timing_config(TimingConfig {
+            beat_subdivisions: 2,
+            ..TimingConfig::default()
+        })
+        .build()
+        .0;
+
+    // 2.25 s in: the second half of beat 4, which is beat 1 of a 3/4 bar
+    let position = worker.beat_position_for(108_000).unwrap();
+    assert_eq!((position.beat_index, position.subdivision_index), (1, 1));
+}
+
+#[test]
+fn metronome_running_at_startup_uses_the_configured_bpm_change_grace() {
+    // Grace disabled, so a hit right after a tempo change is judged at once
+    let mut worker = WorkerBuilder::default()
+        .bpm(120)
+        .timing_config(TimingConfig {
+            bpm_change_grace_ms: 0,
+            ..TimingConfig::default()
+        })
+        .build()
+        .0;
+
+    // Tempo drops to 100 BPM (28800 samples per beat) around sample 48000
+    worker.bpm.store(100, Ordering::Relaxed);
+    worker.processed_samples = 46_000;
+    worker.ingest(&[0.0; 2048]);
+
+    // 100 ms later: within the default 300 ms grace, but LATE without one
+    let timing = worker.timing_for(48_000 + 4_800).unwrap();
+    assert_eq!(timing.classification, quantizer::TimingClassification::Late);
+}
+
+#[test]
+fn nan_window_never_reaches_a_result() {
+    let sample_rate = 48_000;
+    // Half a second of silence, then a decaying 60 Hz thump
+    let mut samples = vec![0.0f32; sample_rate as usize / 2];
+    samples.extend((0..sample_rate as usize / 4).map(|i| {
+        let t = i as f32 / sample_rate as f32;
+        0.8 * (-t * 30.0).exp() * (2.0 * std::f32::consts::PI * 60.0 * t).sin()
+    }));
+    samples.extend(vec![0.0f32; sample_rate as usize / 2]);
+    let run = |samples: &[f32]| {
+        run_analysis_once(
+            samples,
+            CalibrationState::new_default(),
+            AnalysisConfig::default(),
+            4,
+            sample_rate,
+            2048,
+        )
+        .unwrap()
+    };
+    assert!(!run(&samples).is_empty(), "clean kick should classify");
+
+    // Driver garbage inside the first hit: that window is dropped, and the
+    // worker still classifies the clean hit that follows
+    let clean = samples.clone();
+    let hit_start = sample_rate as usize / 2;
+    samples[hit_start + 300] = f32::NAN;
+    samples[hit_start + 600] = f32::INFINITY;
+    samples.extend_from_slice(&clean);
+    let results = run(&samples);
+    assert_eq!(results.len(), 1, "{results:?}");
+    assert!(results[0].timestamp_ms >= (clean.len() as u64 * 1000) / sample_rate as u64);
+}
+
+#[test]
+fn run_analysis_once_classifies_a_synthetic_kick() {
+    let sample_rate = 48_000;
+    // Half a second of silence, then a decaying 60 Hz thump
+    let mut samples = vec![0.0f32; sample_rate as usize / 2];
+    samples.extend((0..sample_rate as usize / 4).map(|i| {
+        let t = i as f32 / sample_rate as f32;
+        0.8 * (-t * 30.0).exp() * (2.0 * std::f32::consts::PI * 60.0 * t).sin()
+    }));
+    samples.extend(vec![0.0f32; sample_rate as usize / 2]);
+
+    let run = || {
+        run_analysis_once(
+            &samples,
+            CalibrationState::new_default(),
+            AnalysisConfig::default(),
+            4,
+            sample_rate,
+            2048,
+        )
+        .unwrap()
+    };
+    let results = run();
+
+    assert!(!results.is_empty(), "no result for the kick");
+    assert!(
+        results.iter().all(|r| r.sound == BeatboxHit::Kick),
+        "{:?}",
+        results.iter().map(|r| r.sound).collect::<Vec<_>>()
+    );
+    assert!(results.iter().all(|r| r.timing.is_none()));
+    // Deterministic: the same input gives the same results
+    let again = run();
+    assert_eq!(
+        again
+            .iter()
+            .map(|r| (r.sound, r.timestamp_ms))
+            .collect::<Vec<_>>(),
+        results
+            .iter()
+            .map(|r| (r.sound, r.timestamp_ms))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn run_analysis_once_classifies_with_the_given_classifier_config() {
+    let sample_rate = 48_000;
+    let mut samples = vec![0.0f32; sample_rate as usize / 2];
+    samples.extend((0..sample_rate as usize / 4).map(|i| {
+        let t = i as f32 / sample_rate as f32;
+        0.8 * (-t * 30.0).exp() * (2.0 * std::f32::consts::PI * 60.0 * t).sin()
+    }));
+    samples.extend(vec![0.0f32; sample_rate as usize / 2]);
+    // No kick is ever confident enough
+    let strict = ClassifierConfig {
+        min_confidence: HashMap::from([(BeatboxHit::Kick, 1.01)]),
+        ..ClassifierConfig::default()
+    };
+
+    let results = run_analysis_once(
+        &samples,
+        CalibrationState::new_default(),
+        AnalysisConfig {
+            classifier: strict,
+            ..AnalysisConfig::default()
+        },
+        4,
+        sample_rate,
+        2048,
+    )
+    .unwrap();
+
+    assert!(!results.is_empty(), "no result for the kick");
+    assert!(
+        results.iter().all(|r| r.sound == BeatboxHit::Unknown),
+        "{:?}",
+        results.iter().map(|r| r.sound).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn level2_calibration_classifies_with_subcategories() {
+    let sample_rate = 48_000;
+    let mut samples = vec![0.0f32; sample_rate as usize / 2];
+    samples.extend((0..sample_rate as usize / 4).map(|i| {
+        let t = i as f32 / sample_rate as f32;
+        0.8 * (-t * 30.0).exp() * (2.0 * std::f32::consts::PI * 60.0 * t).sin()
+    }));
+    samples.extend(vec![0.0f32; sample_rate as usize / 2]);
+    // Any measurable flatness makes a kick a K-snare
+    let mut calibration =
+        CalibrationState::new_default().with_level2_thresholds(Level2Thresholds {
+            ksnare_min_flatness: 0.0,
+            tonal_kick_max_flatness: 0.0,
+            ..Level2Thresholds::default()
+        });
+    calibration.level = 2;
+
+    let results = run_analysis_once(
+        &samples,
+        calibration,
+        AnalysisConfig::default(),
+        4,
+        sample_rate,
+        2048,
+    )
+    .unwrap();
+
+    assert!(!results.is_empty(), "no result for the kick");
+    assert!(
+        results.iter().all(|r| r.sound == BeatboxHit::KSnare),
+        "{:?}",
+        results.iter().map(|r| r.sound).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn monitor_mode_reports_onsets_without_classifying() {
+    let config = OnsetDetectionConfig {
+        monitor_mode: true,
+        ..OnsetDetectionConfig::default()
+    };
+    let (mut worker, mut results) = WorkerBuilder::default().onset_config(config).build();
+    let (onset_tx, mut onset_rx) = tokio::sync::broadcast::channel(8);
+    worker.onset_events_tx = Some(onset_tx);
+    // A loud tone the classifier would otherwise pick up
+    worker.accumulator = (0..2048).map(|i| 0.5 * (i as f32 * 0.13).sin()).collect();
+    let onset = || Onset {
+        timestamp: 48_000,
+        strength: 3.5,
+    };
+
+    worker.process_onsets(detected_now(vec![onset()]), &CLASSIFYING);
+
+    assert!(results.try_recv().is_err(), "monitor mode classified a hit");
+    let event = onset_rx.try_recv().expect("onset event");
+    assert_eq!(event.timestamp, 1000);
+    // RMS of the 0.5-amplitude tone, not the onset strength
+    assert!(
+        (event.energy - 0.5 / 2f64.sqrt()).abs() < 0.02,
+        "{}",
+        event.energy
+    );
+    assert!(event.classification.is_none());
+    assert!(event.features.is_none());
+    assert_eq!(event.strength, 3.5);
+    assert_eq!(event.centroid, 0.0);
+
+    // The same onset is classified once monitor mode is off
+    worker.onset_config.monitor_mode = false;
+    worker.process_onsets(detected_now(vec![onset()]), &CLASSIFYING);
+    let result = results.try_recv().expect("classification result");
+    let event = onset_rx.try_recv().expect("onset event");
+    assert_eq!(
+        event.classification.map(|c| c.timestamp_ms),
+        Some(result.timestamp_ms)
+    );
+    assert!(event.centroid > 0.0);
+}
+
+#[test]
+fn onset_event_leaves_out_a_result_that_was_not_sent() {
+    let results_config = ResultEmissionConfig {
+        min_result_interval_ms: 500.0,
+        ..ResultEmissionConfig::default()
+    };
+    let (mut worker, mut results) = WorkerBuilder::default()
+        .results_config(results_config)
+        .build();
+    let (onset_tx, mut onset_rx) = tokio::sync::broadcast::channel(8);
+    worker.onset_events_tx = Some(onset_tx);
+    worker.accumulator = (0..2048).map(|i| 0.5 * (i as f32 * 0.13).sin()).collect();
+    let onset = |timestamp| Onset {
+        timestamp,
+        strength: 3.5,
+    };
+
+    worker.process_onsets(detected_now([onset(48_000)]), &CLASSIFYING);
+    results.try_recv().expect("classification result");
+    let event = onset_rx.try_recv().expect("onset event");
+    assert!(event.classification.is_some());
+
+    // Rate-limited: the onset is still reported, without a classification
+    worker.process_onsets(detected_now([onset(48_960)]), &CLASSIFYING);
+    assert!(results.try_recv().is_err());
+    let event = onset_rx.try_recv().expect("onset event");
+    assert!(event.classification.is_none());
+    assert!(event.centroid > 0.0);
+}
+
+#[test]
+fn onset_event_carries_features_of_a_real_transient() {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    let (mut worker, _results) = WorkerBuilder::default().build();
+    let (onset_tx, mut onset_rx) = tokio::sync::broadcast::channel(8);
+    worker.onset_events_tx = Some(onset_tx);
+
+    // Silence, then a decaying noise burst
+    let mut rng = StdRng::seed_from_u64(441);
+    worker.accumulator = vec![0.0; 2048];
+    worker
+        .accumulator
+        .extend((0..1024).map(|i| (-(i as f32) / 300.0).exp() * rng.gen_range(-0.6..0.6)));
+
+    let onsets = worker.onset_detector.process(&worker.accumulator);
+    assert!(!onsets.is_empty(), "no onset detected");
+    worker.process_onsets(detected_now(onsets), &CLASSIFYING);
+
+    let event = onset_rx.try_recv().expect("onset event");
+    assert!(event.strength > 0.0);
+    let features = event.features.expect("features for a classified onset");
+    assert!(features.centroid > 0.0, "{features:?}");
+    assert!(features.zcr > 0.0, "{features:?}");
+    assert!(features.flatness > 0.0, "{features:?}");
+    assert_eq!(event.centroid, features.centroid as f64);
+}
+
+#[test]
+fn smoothed_rms_ramps_toward_a_step_with_vu_ballistics() {
+    let (mut worker, _) = WorkerBuilder::default().build();
+    let (tx, mut rx) = tokio::sync::broadcast::channel(64);
+    worker.audio_metrics_tx = Some(tx);
+
+    // 100ms chunks of a constant level: three chunks make one 300ms time constant
+    worker.accumulator = vec![0.5; 4_800];
+    let mut smoothed = Vec::new();
+    for _ in 0..15 {
+        worker.process_audio_metrics(0.5);
+        let metrics = rx.try_recv().expect("metrics emitted");
+        assert_eq!(metrics.rms, 0.5);
+        smoothed.push(metrics.smoothed_rms);
+    }
+
+    assert!(smoothed.windows(2).all(|pair| pair[1] > pair[0]));
+    assert!(smoothed[0] < 0.25, "first chunk jumped to {}", smoothed[0]);
+    let one_time_constant = 0.5 * (1.0 - (-1.0f64).exp());
+    assert!(
+        (smoothed[2] - one_time_constant).abs() < 0.01,
+        "after 300ms: {}",
+        smoothed[2]
+    );
+    assert!(smoothed[14] > 0.49, "after 1.5s: {}", smoothed[14]);
+}
+
+#[test]
+fn onset_threshold_is_published_after_real_signal() {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    let config = OnsetDetectionConfig {
+        threshold_offset: 0.0,
+        ..OnsetDetectionConfig::default()
+    };
+    let (mut worker, _) = WorkerBuilder::default().onset_config(config).build();
+    worker.onset_threshold = OnsetThreshold::default();
+    assert_eq!(worker.onset_threshold.get(), 0.0);
+
+    // Decaying noise bursts every 2048 samples
+    let mut rng = StdRng::seed_from_u64(468);
+    let signal: Vec<f32> = (0..48_000)
+        .map(|i| {
+            let t = (i % 2048) as f32;
+            (-t / 300.0).exp() * rng.gen_range(-0.5..0.5)
+        })
+        .collect();
+    for buffer in signal.chunks(512) {
+        let input_gain = worker.ingest(buffer);
+        worker.analyze_accumulated(input_gain);
+    }
+
+    let published = worker.onset_threshold.get();
+    assert!(published > 0.0, "threshold {published}");
+    assert_eq!(published, worker.onset_detector.current_threshold());
+}
@@ -0,0 +1,232 @@
+use crate::analysis::features::fft::FFT_SIZE;
+use crate::analysis::onset::Onset;
+use crate::analysis::{ClassificationResult, HitTrigger};
+use crate::config::{AnalysisWorkerConfig, DetectionSource, OnsetDetectionConfig};
+use crate::types::BeatboxHit;
+
+use super::test_support::{analyze_stream, detected_now, WorkerBuilder, CLASSIFYING};
+
+#[test]
+fn pre_roll_window_contains_an_impulse_before_the_accumulator_tail() {
+    let config = OnsetDetectionConfig {
+        pre_roll_ms: 5.0,
+        ..OnsetDetectionConfig::default()
+    };
+    let (mut worker, _results) = WorkerBuilder::default().onset_config(config).build();
+    assert_eq!(worker.windows.pre_roll_samples, 240);
+
+    // Impulse at 2000 of a 4096-sample batch, well before the last 1024
+    let mut batch = vec![0.0f32; 4096];
+    batch[2000] = 1.0;
+    worker.accumulator = batch;
+    worker.processed_samples = 4096;
+
+    let window = worker.onset_window(2000);
+    assert_eq!(window.len(), FFT_SIZE);
+    assert_eq!(window.iter().position(|&s| s == 1.0), Some(240));
+
+    // Without pre-roll only the tail is analysed and the impulse is missed
+    worker.windows.pre_roll_samples = 0;
+    assert!(worker.onset_window(2000).iter().all(|&s| s == 0.0));
+
+    // An onset at the start of the next batch reaches back into the last one
+    worker.windows.pre_roll_samples = 240;
+    worker.retain_history();
+    assert_eq!(worker.windows.history.len(), 240);
+    worker.windows.history[200] = 0.5;
+    worker.accumulator = vec![0.0; 2048];
+    worker.processed_samples += 2048;
+
+    let window = worker.onset_window(4096 + 10);
+    assert_eq!(window.len(), FFT_SIZE);
+    assert_eq!(window.iter().position(|&s| s == 0.5), Some(190));
+}
+
+#[test]
+fn pre_roll_windows_stay_on_real_onsets_across_many_buffers() {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    // Short clicks over three seconds, each landing at a different spot
+    // in its 2048-sample buffer
+    let mut rng = StdRng::seed_from_u64(452);
+    let mut signal = vec![0.0f32; 144_000];
+    let clicks: Vec<usize> = (1..8).map(|i| i * 19_000 + i * 131).collect();
+    for &click in &clicks {
+        for sample in &mut signal[click..click + 96] {
+            *sample = rng.gen_range(-0.8..0.8);
+        }
+    }
+
+    let (mut worker, mut results) = WorkerBuilder::default()
+        .onset_config(OnsetDetectionConfig {
+            pre_roll_ms: 5.0,
+            detection_source: DetectionSource::OnsetOnly,
+            min_onset_gap_ms: 100.0,
+            ..OnsetDetectionConfig::default()
+        })
+        .build();
+    let (onset_tx, mut onset_rx) = tokio::sync::broadcast::channel(16);
+    worker.onset_events_tx = Some(onset_tx);
+    analyze_stream(&mut worker, &mut results, &signal, 2048);
+
+    let events: Vec<_> = std::iter::from_fn(|| onset_rx.try_recv().ok()).collect();
+    assert_eq!(events.len(), clicks.len());
+    for (event, &click) in events.iter().zip(&clicks) {
+        let click_ms = (click / 48) as u64;
+        assert!(
+            event.timestamp.abs_diff(click_ms) <= 6,
+            "{event:?} vs {click_ms} ms"
+        );
+        // The window holds the click, not the silence after it
+        assert!(event.energy > 0.05, "{event:?} vs {click_ms} ms");
+    }
+}
+
+#[test]
+fn accumulator_cap_keeps_most_recent_samples() {
+    let (mut worker, _results) = WorkerBuilder::default()
+        .worker_config(AnalysisWorkerConfig {
+            max_accumulator_size: 2048,
+            ..AnalysisWorkerConfig::default()
+        })
+        .build();
+
+    // Analysis never runs, so every buffer piles up
+    for value in 0..5 {
+        worker.ingest(&[value as f32; 512]);
+    }
+
+    assert_eq!(worker.accumulator.len(), 2048);
+    assert_eq!(worker.accumulator[0], 1.0);
+    assert_eq!(worker.accumulator[2047], 4.0);
+    assert_eq!(worker.processed_samples, 5 * 512);
+}
+
+#[test]
+fn onsets_after_a_dropped_backlog_keep_their_timestamps() {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    let (mut worker, mut results) = WorkerBuilder::default()
+        .onset_config(OnsetDetectionConfig {
+            detection_source: DetectionSource::OnsetOnly,
+            min_onset_gap_ms: 100.0,
+            ..OnsetDetectionConfig::default()
+        })
+        .worker_config(AnalysisWorkerConfig {
+            max_accumulator_size: 4096,
+            ..AnalysisWorkerConfig::default()
+        })
+        .build();
+
+    // Analysis stalls for 8192 samples; the oldest half is dropped
+    for _ in 0..8 {
+        worker.ingest(&[0.0; 1024]);
+    }
+    worker.analyze_accumulated(1.0);
+
+    // A hit at 20,000 samples (416 ms) into the stream
+    let mut rng = StdRng::seed_from_u64(410);
+    let mut signal = vec![0.0f32; 24_000];
+    for (i, sample) in signal[20_000 - 8192..][..2048].iter_mut().enumerate() {
+        *sample = (-(i as f32) / 600.0).exp() * rng.gen_range(-0.7..0.7);
+    }
+    let sent = analyze_stream(&mut worker, &mut results, &signal, 1024);
+
+    assert_eq!(sent.len(), 1, "{sent:?}");
+    assert!(sent[0].timestamp_ms.abs_diff(416) <= 3, "{:?}", sent[0]);
+}
+
+#[test]
+fn post_onset_delay_also_defers_level_crossing_captures() {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(456);
+    let mut burst = || -> Vec<f32> { (0..1024).map(|_| rng.gen_range(-0.5..0.5)).collect() };
+    let (mut worker, mut results) = WorkerBuilder::default()
+        .onset_config(OnsetDetectionConfig {
+            post_onset_delay_samples: 512,
+            ..OnsetDetectionConfig::default()
+        })
+        .build();
+    worker.accumulator = burst();
+    worker.processed_samples = 48_000;
+
+    // Captured, but its shifted window isn't complete yet
+    worker.process_level_crossing_classification(1.0, 0.01);
+    assert!(results.try_recv().is_err());
+
+    worker.retain_history();
+    worker.accumulator = burst();
+    worker.processed_samples += 1024;
+    worker.process_level_crossing_classification(1.0, 0.01);
+    let result = results.try_recv().unwrap();
+    assert_eq!(result.trigger, HitTrigger::LevelCrossing);
+    assert_eq!(result.timestamp_ms, 1000);
+
+    // The same hit's onset, delayed as well, still dedups against it
+    let onset = Onset {
+        timestamp: 47_900,
+        strength: 1.0,
+    };
+    worker.process_onsets(detected_now([onset]), &CLASSIFYING);
+    assert!(results.try_recv().is_err());
+}
+
+/// Run `signal` through the analysis loop in 2048-sample buffers, with
+/// only the onset detector reporting hits
+fn classify_with_post_onset_delay(
+    signal: &[f32],
+    post_onset_delay_samples: usize,
+) -> Vec<ClassificationResult> {
+    let (mut worker, mut results) = WorkerBuilder::default()
+        .onset_config(OnsetDetectionConfig {
+            post_onset_delay_samples,
+            detection_source: DetectionSource::OnsetOnly,
+            min_onset_gap_ms: 200.0,
+            ..OnsetDetectionConfig::default()
+        })
+        .build();
+    analyze_stream(&mut worker, &mut results, signal, 2048)
+}
+
+#[test]
+fn post_onset_delay_classifies_kick_bodies_more_reliably() {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::f32::consts::PI;
+
+    // Far enough in that a batch-sized drift would move the window
+    const ONSET: usize = 73_400;
+    // Broadband click, then a 60 Hz body that swells over 30 ms and decays
+    let kick = |seed: u64| {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut signal = vec![0.0f32; ONSET + 6792];
+        for sample in &mut signal[ONSET..ONSET + 96] {
+            *sample = rng.gen_range(-0.8..0.8);
+        }
+        for (i, sample) in signal[ONSET..].iter_mut().enumerate() {
+            let t = i as f32;
+            let envelope = (t / 1440.0).min(1.0) * (-(t - 1440.0).max(0.0) / 4800.0).exp();
+            *sample += 0.6 * envelope * (2.0 * PI * 60.0 * t / 48_000.0).sin();
+        }
+        signal
+    };
+    let kicks = |delay: usize| {
+        (0..10)
+            .filter(|&seed| {
+                let results = classify_with_post_onset_delay(&kick(seed), delay);
+                assert_eq!(results.len(), 1, "seed {seed}, delay {delay}");
+                results[0].sound == BeatboxHit::Kick
+            })
+            .count()
+    };
+
+    let undelayed = kicks(0);
+    // 40 ms: the window sits on the body instead of the click
+    let delayed = kicks(1920);
+    assert_eq!(delayed, 10);
+    assert!(undelayed < delayed, "{undelayed} undelayed kicks");
+}
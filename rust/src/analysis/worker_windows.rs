@@ -0,0 +1,84 @@
+// AnalysisWorker - accumulator and hit windows
+//
+// Thin wrappers around `OnsetWindows` that supply the accumulator and the
+// current sample position.
+
+use std::borrow::Cow;
+use std::time::Instant;
+
+use crate::analysis::onset::Onset;
+
+use super::AnalysisWorker;
+
+impl AnalysisWorker {
+    /// Append samples (scaled by `input_gain`) to the accumulator
+    ///
+    /// If the accumulator would exceed its cap, the oldest samples are
+    /// dropped so only the most recent audio is kept. `processed_samples`
+    /// already counts them, and the onset detector skips them, so positions
+    /// stay on the same clock across the gap.
+    pub(super) fn accumulate(&mut self, samples: &[f32], input_gain: f32) {
+        if input_gain == 1.0 {
+            self.accumulator.extend_from_slice(samples);
+        } else {
+            self.accumulator
+                .extend(samples.iter().map(|&sample| sample * input_gain));
+        }
+
+        let excess = self
+            .accumulator
+            .len()
+            .saturating_sub(self.max_accumulator_samples);
+        if excess > 0 {
+            tracing::warn!(
+                "[AnalysisThread] Accumulator over cap ({} samples), dropping {} oldest",
+                self.max_accumulator_samples,
+                excess
+            );
+            self.accumulator.drain(..excess);
+            self.onset_detector.skip(excess);
+            self.windows.clear_history();
+        }
+    }
+
+    /// Keep the accumulator's tail as history before it is cleared
+    pub(super) fn retain_history(&mut self) {
+        self.windows.retain(&self.accumulator);
+    }
+
+    /// Queue onsets detected at `detected_at` and return those whose window
+    /// is complete
+    pub(super) fn take_due_onsets(
+        &mut self,
+        onsets: Vec<Onset>,
+        detected_at: Instant,
+    ) -> Vec<(Onset, Instant)> {
+        self.windows
+            .take_due_onsets(onsets, detected_at, self.processed_samples)
+    }
+
+    /// Queue a level-crossing capture at the current sample position
+    pub(super) fn capture_level_crossing(&mut self, calibration: bool) {
+        self.flam.record_level_capture(self.processed_samples);
+        self.windows
+            .defer_capture(self.processed_samples, calibration, Instant::now());
+    }
+
+    /// Return the queued captures whose window is complete
+    pub(super) fn take_due_captures(&mut self, calibration: bool) -> Vec<(u64, Instant)> {
+        self.windows
+            .take_due_captures(self.processed_samples, calibration)
+    }
+
+    /// Feature window for a level-crossing capture
+    pub(super) fn capture_window(&self, capture_sample: u64) -> Cow<'_, [f32]> {
+        self.windows
+            .capture_window(&self.accumulator, self.processed_samples, capture_sample)
+    }
+
+    /// Feature window for an onset
+    pub(super) fn onset_window(&self, onset_timestamp: u64) -> Cow<'_, [f32]> {
+        self.windows
+            .onset_window(&self.accumulator, self.processed_samples, onset_timestamp)
+    }
+}
@@ -6,20 +6,35 @@
 use anyhow::Result;
 use once_cell::sync::Lazy;
 
+use crate::analysis::features::fft::FftProcessor;
+use crate::analysis::features::{FeatureExtractor, Features};
 use crate::analysis::ClassificationResult;
 use crate::bridge_generated::StreamSink;
-use crate::calibration::CalibrationProgress;
 use crate::engine::core::{EngineHandle, ParamPatch};
-use crate::error::{AudioError, CalibrationError};
+use crate::error::AudioError;
+pub mod calibration;
 pub mod diagnostics;
+pub mod settings;
 pub mod streams;
 pub mod types;
 
+pub use calibration::{
+    calibrate_from_wavs, calibration_stream, confirm_calibration_step, finish_calibration,
+    get_calibration_state, get_current_audio_level, load_calibration_state,
+    load_preset_calibration, load_preset_calibration_for_device, manual_accept_last_candidate,
+    reset_calibration_session, retry_calibration_step, start_calibration,
+    update_calibration_threshold,
+};
 pub use diagnostics::{
     fixture_metadata_for_id, load_fixture_catalog, start_fixture_session, stop_fixture_session,
 };
+pub use settings::{
+    is_pipeline_tracing_enabled, reset_telemetry, set_json_result_schema_version,
+    set_listening_windows, set_pipeline_tracing, set_sound_labels, supported_sounds,
+};
 pub use streams::{
-    audio_metrics_stream, diagnostic_metrics_stream, onset_events_stream, telemetry_stream,
+    audio_metrics_stream, calibration_debug_stream, calibration_preview_stream,
+    diagnostic_metrics_stream, onset_events_stream, telemetry_stream, telemetry_stream_filtered,
 };
 use tokio::sync::mpsc::error::TrySendError;
 pub use types::{AudioMetrics, OnsetEvent};
@@ -77,6 +92,70 @@ pub fn get_version() -> Result<String> {
     Ok(env!("CARGO_PKG_VERSION").to_string())
 }
 
+/// Extract DSP features from an arbitrary audio clip
+///
+/// Runs the same `FeatureExtractor` the analysis thread uses, without
+/// starting the audio engine, so Dart tooling can analyze a recorded clip.
+///
+/// # Arguments
+/// * `samples` - Mono audio samples
+/// * `sample_rate` - Sample rate of `samples` in Hz
+///
+/// # Returns
+/// * `Features` - Centroid, ZCR, flatness, rolloff and decay time
+///
+/// # Errors
+/// - The feature extractor rejects its FFT size
+#[flutter_rust_bridge::frb(sync)]
+pub fn extract_features(samples: Vec<f32>, sample_rate: u32) -> Result<Features> {
+    Ok(FeatureExtractor::new(sample_rate)?.extract(&samples))
+}
+
+/// Compute the magnitude spectrum of an audio buffer
+///
+/// Powers the spectrum analyzer widget; independent of the classification
+/// pipeline. The first `fft_size` samples are Hann-windowed (shorter
+/// buffers are zero-padded) and transformed with the same `FftProcessor`
+/// the feature extractor uses.
+///
+/// # Arguments
+/// * `samples` - Mono audio samples
+/// * `sample_rate` - Sample rate of `samples` in Hz
+/// * `fft_size` - Transform size; bin `k` is centred on `k * sample_rate / fft_size` Hz
+/// * `in_db` - Return magnitudes in decibels (floored at -120 dB)
+///
+/// # Returns
+/// * `Vec<f32>` - `fft_size / 2 + 1` magnitudes from DC to Nyquist
+///
+/// # Errors
+/// - `samples` is empty or contains NaN/Inf
+/// - `sample_rate` is 0
+/// - `fft_size` is outside the supported FFT range
+#[flutter_rust_bridge::frb(sync)]
+pub fn compute_spectrum(
+    samples: Vec<f32>,
+    sample_rate: u32,
+    fft_size: u32,
+    in_db: bool,
+) -> Result<Vec<f32>> {
+    anyhow::ensure!(!samples.is_empty(), "samples must not be empty");
+    anyhow::ensure!(
+        samples.iter().all(|s| s.is_finite()),
+        "samples must be finite"
+    );
+    anyhow::ensure!(sample_rate > 0, "sample_rate must be positive");
+
+    let processor = FftProcessor::new(fft_size as usize)?;
+    let spectrum = processor.compute_magnitude_spectrum(&samples);
+    if !in_db {
+        return Ok(spectrum);
+    }
+    Ok(spectrum
+        .into_iter()
+        .map(|magnitude| 20.0 * magnitude.max(1e-6).log10())
+        .collect())
+}
+
 /// Start the audio engine with specified BPM
 ///
 /// Initializes the audio engine, starts full-duplex audio streams with Oboe,
@@ -106,6 +185,8 @@ pub fn start_audio(bpm: u32) -> Result<(), AudioError> {
         eprintln!("[Rust API] No calibration state available");
     }
 
+    // stop_audio shuts the debug server down; bring it back for this session
+    crate::debug::http::spawn_if_enabled(&ENGINE_HANDLE);
     let result = ENGINE_HANDLE.start_audio(bpm);
     match &result {
         Ok(_) => eprintln!("[Rust API] start_audio succeeded"),
@@ -114,10 +195,34 @@ pub fn start_audio(bpm: u32) -> Result<(), AudioError> {
     result
 }
 
+/// Start the metronome without microphone input
+///
+/// Opens only the output stream and plays clicks at the given tempo. No input
+/// stream or analysis thread is created, so no microphone permission is needed
+/// and no classification results are emitted. Use `stop_audio` to stop.
+///
+/// # Arguments
+/// * `bpm` - Beats per minute (typically 40-240)
+///
+/// # Returns
+/// * `Ok(())` - Metronome started successfully
+/// * `Err(AudioError)` - Error if initialization fails
+///
+/// # Errors
+/// - Output stream cannot be opened (device busy)
+/// - Audio engine already running (call stop_audio first)
+/// - Invalid BPM value (must be > 0)
+#[flutter_rust_bridge::frb]
+pub fn start_metronome_only(bpm: u32) -> Result<(), AudioError> {
+    crate::debug::http::spawn_if_enabled(&ENGINE_HANDLE);
+    ENGINE_HANDLE.start_metronome_only(bpm)
+}
+
 /// Stop the audio engine
 ///
 /// Stops audio streams, shuts down the analysis thread, and releases resources.
-/// Safe to call even if audio engine is not running.
+/// The debug HTTP server (debug builds) stops with it and returns with the
+/// next start. Safe to call even if audio engine is not running.
 ///
 /// # Returns
 /// * `Ok(())` - Audio engine stopped successfully or was not running
@@ -148,6 +253,64 @@ pub fn set_bpm(bpm: u32) -> Result<(), AudioError> {
     ENGINE_HANDLE.set_bpm(bpm)
 }
 
+/// Set the metronome click volume
+///
+/// `volume` runs from 0.0 (silent) to 1.0 (full level) and is clamped to
+/// that range. Takes effect on the next output buffer, whether or not the
+/// engine is running, and persists across restarts.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_metronome_volume(volume: f32) {
+    crate::audio::metronome::MetronomeLevel::shared().set_volume(volume);
+}
+
+/// Mute or unmute the metronome click
+///
+/// Muting silences the click only: the beat grid keeps running, so timing
+/// feedback is still scored against it.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_metronome_muted(muted: bool) {
+    crate::audio::metronome::MetronomeLevel::shared().set_muted(muted);
+}
+
+/// Live adaptive onset threshold of the running analysis thread
+///
+/// The level the onset detection function must currently exceed for a hit
+/// to register, in the units of `threshold_offset`. Updated once per
+/// analyzed buffer; 0.0 before the engine has processed any audio.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_current_onset_threshold() -> f32 {
+    crate::analysis::onset::OnsetThreshold::shared().get()
+}
+
+/// Push a scripted classification result to `classification_stream` subscribers
+///
+/// Intended for UI tests: the result is delivered exactly as if the audio
+/// engine had detected it, so animations can be driven without a microphone.
+/// Release builds reject the call.
+///
+/// # Errors
+/// - `Unsupported` when called from a release build
+/// - Lock poisoning on the classification channel
+#[flutter_rust_bridge::frb]
+pub fn inject_classification(result: ClassificationResult) -> Result<(), AudioError> {
+    #[cfg(debug_assertions)]
+    {
+        let delivered = ENGINE_HANDLE.broadcasts.inject_classification(result)?;
+        tracing::debug!(
+            "[Rust API] Injected classification delivered to {} subscribers",
+            delivered
+        );
+        Ok(())
+    }
+    #[cfg(not(debug_assertions))]
+    {
+        let _ = result;
+        Err(AudioError::Unsupported {
+            operation: "inject_classification".to_string(),
+        })
+    }
+}
+
 /// Apply parameter patch to running engine (BPM/threshold updates)
 #[flutter_rust_bridge::frb]
 pub fn apply_params(patch: ParamPatch) -> Result<(), AudioError> {
@@ -157,17 +320,14 @@ pub fn apply_params(patch: ParamPatch) -> Result<(), AudioError> {
         });
     }
 
-    ENGINE_HANDLE
-        .command_sender()
-        .try_send(patch)
-        .map_err(|err| match err {
-            TrySendError::Full(_) => AudioError::StreamFailure {
-                reason: "parameter command queue is full".to_string(),
-            },
-            TrySendError::Closed(_) => AudioError::StreamFailure {
-                reason: "parameter command channel closed".to_string(),
-            },
-        })
+    ENGINE_HANDLE.submit_params(patch).map_err(|err| match err {
+        TrySendError::Full(_) => AudioError::StreamFailure {
+            reason: "parameter command queue is full".to_string(),
+        },
+        TrySendError::Closed(_) => AudioError::StreamFailure {
+            reason: "parameter command channel closed".to_string(),
+        },
+    })
 }
 
 /// Stream of classification results
@@ -234,235 +394,6 @@ pub fn classification_stream(sink: StreamSink<ClassificationResult>) {
     }
 }
 
-/// Start calibration workflow
-///
-/// Begins collecting samples for calibration. The system will detect onsets
-/// and extract features without classifying. Collect 10 samples per sound type.
-///
-/// Calibration sequence: KICK → SNARE → HI-HAT
-///
-/// # Returns
-/// * `Ok(())` - Calibration started
-/// * `Err(CalibrationError)` - Error if calibration cannot start
-///
-/// # Errors
-/// - Calibration already in progress
-/// - Lock poisoning on calibration procedure state
-#[flutter_rust_bridge::frb]
-pub fn start_calibration() -> Result<(), CalibrationError> {
-    ENGINE_HANDLE.start_calibration()
-}
-
-/// Reset calibration session (clears any in-progress procedure and stops audio).
-#[flutter_rust_bridge::frb]
-pub fn reset_calibration_session() -> Result<(), CalibrationError> {
-    ENGINE_HANDLE.reset_calibration_session()
-}
-
-/// Finish calibration and compute thresholds
-///
-/// Completes the calibration process, computes thresholds from collected samples,
-/// and updates the global CalibrationState used by the classifier.
-///
-/// # Returns
-/// * `Ok(())` - Calibration completed successfully
-/// * `Err(CalibrationError)` - Error if calibration incomplete or invalid
-///
-/// # Errors
-/// - Calibration not in progress
-/// - Insufficient samples collected (need 10 per sound type)
-/// - Sample validation failed (out of range features)
-/// - Lock poisoning on calibration state
-#[flutter_rust_bridge::frb]
-pub fn finish_calibration() -> Result<(), CalibrationError> {
-    ENGINE_HANDLE.finish_calibration()
-}
-
-/// User confirms current calibration step is OK and wants to advance
-///
-/// Called when user clicks "OK" after reviewing the collected samples for current sound.
-/// Advances to the next sound in the calibration sequence.
-///
-/// # Returns
-/// * `Ok(true)` - Advanced to next sound
-/// * `Ok(false)` - Calibration complete (no next sound)
-/// * `Err(CalibrationError)` - Error if not waiting for confirmation
-#[flutter_rust_bridge::frb]
-pub fn confirm_calibration_step() -> Result<bool, CalibrationError> {
-    ENGINE_HANDLE.confirm_calibration_step()
-}
-
-/// User wants to retry the current calibration step
-///
-/// Called when user clicks "Retry" to redo sample collection for current sound.
-/// Clears collected samples and allows re-collection.
-///
-/// # Returns
-/// * `Ok(())` - Samples cleared, ready to collect again
-/// * `Err(CalibrationError)` - Error if not waiting for confirmation
-#[flutter_rust_bridge::frb]
-pub fn retry_calibration_step() -> Result<(), CalibrationError> {
-    ENGINE_HANDLE.retry_calibration_step()
-}
-
-/// Manually accept the last rejected-but-valid calibration candidate
-///
-/// Allows the UI to promote a buffered sample when adaptive gates are too strict.
-/// Emits updated progress to the calibration stream.
-#[flutter_rust_bridge::frb]
-pub fn manual_accept_last_candidate() -> Result<CalibrationProgress, CalibrationError> {
-    ENGINE_HANDLE.manual_accept_last_candidate()
-}
-
-/// Stream of calibration progress updates
-///
-/// Returns a stream that yields CalibrationProgress as samples are collected.
-/// Each progress update contains the current sound being calibrated and
-/// the number of samples collected (0-10).
-///
-/// # Returns
-/// Stream<CalibrationProgress> that yields progress updates
-///
-/// # Usage
-/// ```dart
-/// final stream = calibrationStream();
-/// await for (final progress in stream) {
-///   print('${progress.currentSound}: ${progress.samplesCollected}/10');
-/// }
-/// ```
-///
-/// # Implementation
-/// Uses the StreamSink pattern supported by flutter_rust_bridge:
-/// - Rust function accepts `StreamSink<T>` parameter
-/// - Dart receives `Stream<T>` return type
-/// - Function can hold sink and emit results asynchronously
-#[allow(unused_must_use)] // frb macro generates code that triggers this lint
-#[flutter_rust_bridge::frb]
-pub fn calibration_stream(sink: StreamSink<CalibrationProgress>) {
-    // Get a direct subscription to the calibration broadcast channel
-    // This avoids the tokio::spawn in subscribe_calibration()
-    let broadcast_rx = ENGINE_HANDLE.broadcasts.subscribe_calibration();
-
-    if let Some(mut broadcast_rx) = broadcast_rx {
-        std::thread::spawn(move || {
-            let rt = tokio::runtime::Builder::new_current_thread()
-                .enable_all()
-                .build()
-                .expect("Failed to create Tokio runtime for calibration stream");
-
-            rt.block_on(async move {
-                loop {
-                    match broadcast_rx.recv().await {
-                        Ok(progress) => {
-                            if sink.add(progress).is_err() {
-                                break;
-                            }
-                        }
-                        Err(err) => {
-                            let _ = sink.add_error(CalibrationError::Timeout {
-                                reason: format!("calibration channel interrupted: {}", err),
-                            });
-                            break;
-                        }
-                    }
-                }
-            });
-        });
-    } else {
-        let _ = sink.add_error(CalibrationError::Timeout {
-            reason: "calibration channel unavailable".to_string(),
-        });
-    }
-}
-
-/// Load calibration state from JSON
-///
-/// Restores a previously saved calibration state from JSON string.
-/// This allows users to skip calibration on subsequent app launches.
-///
-/// # Arguments
-/// * `json` - JSON string containing serialized CalibrationState
-///
-/// # Returns
-/// * `Ok(())` - Calibration state loaded successfully
-/// * `Err(CalibrationError)` - Error if deserialization fails or lock poisoning
-///
-/// # Errors
-/// - JSON deserialization error (invalid format)
-/// - Lock poisoning on calibration state
-///
-/// # Usage
-/// ```dart
-/// try {
-///   await loadCalibrationState(jsonString);
-///   print('Calibration loaded successfully');
-/// } catch (e) {
-///   print('Failed to load calibration: $e');
-/// }
-/// ```
-#[flutter_rust_bridge::frb]
-pub fn load_calibration_state(json: String) -> Result<(), CalibrationError> {
-    use crate::calibration::CalibrationState;
-
-    eprintln!("[Rust API] load_calibration_state called");
-    eprintln!("[Rust API] JSON input: {}", json);
-
-    // Deserialize JSON to CalibrationState
-    let state: CalibrationState = serde_json::from_str(&json).map_err(|e| {
-        eprintln!("[Rust API] Failed to deserialize: {}", e);
-        CalibrationError::InvalidFeatures {
-            reason: format!("Failed to deserialize calibration JSON: {}", e),
-        }
-    })?;
-
-    eprintln!("[Rust API] Deserialized state: level={}, is_calibrated={}, t_kick_centroid={}, t_snare_centroid={}, noise_floor_rms={}",
-              state.level, state.is_calibrated, state.t_kick_centroid, state.t_snare_centroid, state.noise_floor_rms);
-
-    // Load state into EngineHandle
-    ENGINE_HANDLE.load_calibration(state)?;
-    eprintln!("[Rust API] Calibration state loaded into engine");
-
-    Ok(())
-}
-
-/// Get current calibration state as JSON
-///
-/// Retrieves the current calibration state serialized to JSON string.
-/// This JSON can be saved to persistent storage and restored later using
-/// `load_calibration_state`.
-///
-/// # Returns
-/// * `Ok(String)` - JSON string containing serialized CalibrationState
-/// * `Err(CalibrationError)` - Error if serialization fails or lock poisoning
-///
-/// # Errors
-/// - JSON serialization error (should be rare)
-/// - Lock poisoning on calibration state
-///
-/// # Usage
-/// ```dart
-/// try {
-///   final jsonString = await getCalibrationState();
-///   // Save jsonString to SharedPreferences
-/// } catch (e) {
-///   print('Failed to get calibration state: $e');
-/// }
-/// ```
-#[flutter_rust_bridge::frb]
-pub fn get_calibration_state() -> Result<String, CalibrationError> {
-    // Get calibration state from EngineHandle
-    let state = ENGINE_HANDLE.get_calibration_state()?;
-    eprintln!(
-        "[Rust API] get_calibration_state: level={}, is_calibrated={}, noise_floor_rms={}",
-        state.level, state.is_calibrated, state.noise_floor_rms
-    );
-
-    // Serialize to JSON
-    serde_json::to_string(&state).map_err(|e| CalibrationError::InvalidFeatures {
-        reason: format!("Failed to serialize calibration state to JSON: {}", e),
-    })
-}
-
 // Error code constant accessors for Dart/Flutter
 // These functions expose error code constants from AudioErrorCodes and CalibrationErrorCodes
 
@@ -478,102 +409,5 @@ pub fn get_calibration_error_codes() -> CalibrationErrorCodes {
     CalibrationErrorCodes {}
 }
 
-/// Update a single calibration threshold value in the active calibration state.
-///
-/// This enables manual threshold tweaking for debugging and tuning without
-/// requiring a full recalibration cycle.
-///
-/// # Parameters
-/// - `key`: The threshold key to update. Valid keys:
-///   - "t_kick_centroid"
-///   - "t_kick_zcr"
-///   - "t_snare_centroid"
-///   - "t_hihat_zcr"
-///   - "noise_floor_rms"
-/// - `value`: The new threshold value
-///
-/// # Returns
-/// * `Ok(())` - Threshold updated successfully
-/// * `Err(CalibrationError)` - If key is invalid or lock fails
-#[flutter_rust_bridge::frb]
-pub fn update_calibration_threshold(key: String, value: f64) -> Result<(), CalibrationError> {
-    eprintln!(
-        "[Rust API] update_calibration_threshold: key={}, value={}",
-        key, value
-    );
-
-    let mut state = ENGINE_HANDLE.get_calibration_state()?;
-
-    match key.as_str() {
-        "t_kick_centroid" => state.t_kick_centroid = value as f32,
-        "t_kick_zcr" => state.t_kick_zcr = value as f32,
-        "t_snare_centroid" => state.t_snare_centroid = value as f32,
-        "t_hihat_zcr" => state.t_hihat_zcr = value as f32,
-        "noise_floor_rms" => state.noise_floor_rms = value,
-        _ => {
-            return Err(CalibrationError::InvalidFeatures {
-                reason: format!("Unknown threshold key: {}", key),
-            });
-        }
-    }
-
-    ENGINE_HANDLE.load_calibration(state)?;
-    eprintln!("[Rust API] Threshold {} updated to {}", key, value);
-    Ok(())
-}
-
-/// Get current audio level metrics for real-time display.
-///
-/// Returns the latest RMS and peak values from the audio engine.
-/// This is a lightweight call suitable for UI updates.
-///
-/// # Returns
-/// * `Ok((rms, peak, noise_gate))` - Current audio metrics
-/// * `Err(CalibrationError)` - If state cannot be read
-#[flutter_rust_bridge::frb]
-pub fn get_current_audio_level() -> Result<(f64, f64, f64), CalibrationError> {
-    let state = ENGINE_HANDLE.get_calibration_state()?;
-    let noise_gate = state.noise_floor_rms * 2.0;
-    // Note: RMS/peak would need to come from the analysis thread
-    // For now return the noise gate threshold for debugging
-    Ok((0.0, 0.0, noise_gate))
-}
-
-/// Enable or disable pipeline tracing at runtime
-///
-/// When enabled, detailed trace logs are emitted for each pipeline stage:
-/// - AUDIO_CB: Audio callback receives samples
-/// - BUF_QUEUE: Buffer queued to analysis thread
-/// - ANALYSIS_RX: Analysis thread receives buffer
-/// - RMS: RMS level computed
-/// - GATE: Gate decision (above/below threshold)
-/// - ONSET: Onset detected by spectral flux
-/// - LEVEL_X: Level crossing detected
-/// - FEATURES: Features extracted from audio window
-/// - CLASSIFY: Classification decision made
-/// - RESULT_TX: Result sent to Dart
-///
-/// # Arguments
-/// * `enabled` - true to enable tracing, false to disable
-///
-/// # Returns
-/// * Previous tracing state (true if was enabled)
-#[flutter_rust_bridge::frb(sync)]
-pub fn set_pipeline_tracing(enabled: bool) -> bool {
-    let was_enabled = crate::debug::pipeline_tracer::is_enabled();
-    if enabled {
-        crate::debug::pipeline_tracer::enable();
-    } else {
-        crate::debug::pipeline_tracer::disable();
-    }
-    was_enabled
-}
-
-/// Check if pipeline tracing is currently enabled
-#[flutter_rust_bridge::frb(sync)]
-pub fn is_pipeline_tracing_enabled() -> bool {
-    crate::debug::pipeline_tracer::is_enabled()
-}
-
 #[cfg(test)]
 mod tests;
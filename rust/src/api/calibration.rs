@@ -0,0 +1,358 @@
+use crate::bridge_generated::StreamSink;
+use crate::calibration::CalibrationProgress;
+use crate::error::CalibrationError;
+
+use super::ENGINE_HANDLE;
+
+/// Start calibration workflow
+///
+/// Begins collecting samples for calibration. The system will detect onsets
+/// and extract features without classifying. Collect 10 samples per sound type.
+///
+/// Calibration sequence: KICK → SNARE → HI-HAT
+///
+/// # Returns
+/// * `Ok(())` - Calibration started
+/// * `Err(CalibrationError)` - Error if calibration cannot start
+///
+/// # Errors
+/// - Calibration already in progress
+/// - Lock poisoning on calibration procedure state
+#[flutter_rust_bridge::frb]
+pub fn start_calibration() -> Result<(), CalibrationError> {
+    ENGINE_HANDLE.start_calibration()
+}
+
+/// Reset calibration session (clears any in-progress procedure and stops audio).
+#[flutter_rust_bridge::frb]
+pub fn reset_calibration_session() -> Result<(), CalibrationError> {
+    ENGINE_HANDLE.reset_calibration_session()
+}
+
+/// Finish calibration and compute thresholds
+///
+/// Completes the calibration process, computes thresholds from collected samples,
+/// and updates the global CalibrationState used by the classifier.
+///
+/// # Returns
+/// * `Ok(())` - Calibration completed successfully
+/// * `Err(CalibrationError)` - Error if calibration incomplete or invalid
+///
+/// # Errors
+/// - Calibration not in progress
+/// - Insufficient samples collected (need 10 per sound type)
+/// - Sample validation failed (out of range features)
+/// - Lock poisoning on calibration state
+#[flutter_rust_bridge::frb]
+pub fn finish_calibration() -> Result<(), CalibrationError> {
+    ENGINE_HANDLE.finish_calibration()
+}
+
+/// Calibrate from pre-recorded WAV clips instead of a live session
+///
+/// Each list holds one clip per hit for that sound, and all lists must be
+/// the same length. The resulting state is loaded into the engine exactly
+/// like `finish_calibration`, and returned as JSON for persistence (same
+/// format as `get_calibration_state`).
+///
+/// # Errors
+/// - A clip cannot be decoded
+/// - Mismatched or empty clip lists
+/// - Sample validation failed (out of range features)
+#[flutter_rust_bridge::frb]
+pub fn calibrate_from_wavs(
+    kick: Vec<String>,
+    snare: Vec<String>,
+    hihat: Vec<String>,
+) -> Result<String, CalibrationError> {
+    let state = crate::calibration::offline::calibrate_from_wavs(kick, snare, hihat)?;
+    ENGINE_HANDLE.load_calibration(state.clone())?;
+
+    serde_json::to_string(&state).map_err(|e| CalibrationError::InvalidFeatures {
+        reason: format!("Failed to serialize calibration state to JSON: {}", e),
+    })
+}
+
+/// User confirms current calibration step is OK and wants to advance
+///
+/// Called when user clicks "OK" after reviewing the collected samples for current sound.
+/// Advances to the next sound in the calibration sequence.
+///
+/// # Returns
+/// * `Ok(true)` - Advanced to next sound
+/// * `Ok(false)` - Calibration complete (no next sound)
+/// * `Err(CalibrationError)` - Error if not waiting for confirmation
+#[flutter_rust_bridge::frb]
+pub fn confirm_calibration_step() -> Result<bool, CalibrationError> {
+    ENGINE_HANDLE.confirm_calibration_step()
+}
+
+/// User wants to retry the current calibration step
+///
+/// Called when user clicks "Retry" to redo sample collection for current sound.
+/// Clears collected samples and allows re-collection.
+///
+/// # Returns
+/// * `Ok(())` - Samples cleared, ready to collect again
+/// * `Err(CalibrationError)` - Error if not waiting for confirmation
+#[flutter_rust_bridge::frb]
+pub fn retry_calibration_step() -> Result<(), CalibrationError> {
+    ENGINE_HANDLE.retry_calibration_step()
+}
+
+/// Manually accept the last rejected-but-valid calibration candidate
+///
+/// Allows the UI to promote a buffered sample when adaptive gates are too strict.
+/// Emits updated progress to the calibration stream.
+#[flutter_rust_bridge::frb]
+pub fn manual_accept_last_candidate() -> Result<CalibrationProgress, CalibrationError> {
+    ENGINE_HANDLE.manual_accept_last_candidate()
+}
+
+/// Stream of calibration progress updates
+///
+/// Returns a stream that yields CalibrationProgress as samples are collected.
+/// Each progress update contains the current sound being calibrated and
+/// the number of samples collected (0-10).
+///
+/// # Returns
+/// Stream<CalibrationProgress> that yields progress updates
+///
+/// # Usage
+/// ```dart
+/// final stream = calibrationStream();
+/// await for (final progress in stream) {
+///   print('${progress.currentSound}: ${progress.samplesCollected}/10');
+/// }
+/// ```
+///
+/// # Implementation
+/// Uses the StreamSink pattern supported by flutter_rust_bridge:
+/// - Rust function accepts `StreamSink<T>` parameter
+/// - Dart receives `Stream<T>` return type
+/// - Function can hold sink and emit results asynchronously
+#[allow(unused_must_use)] // frb macro generates code that triggers this lint
+#[flutter_rust_bridge::frb]
+pub fn calibration_stream(sink: StreamSink<CalibrationProgress>) {
+    // Get a direct subscription to the calibration broadcast channel
+    // This avoids the tokio::spawn in subscribe_calibration()
+    let broadcast_rx = ENGINE_HANDLE.broadcasts.subscribe_calibration();
+
+    if let Some(mut broadcast_rx) = broadcast_rx {
+        std::thread::spawn(move || {
+            let rt = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("Failed to create Tokio runtime for calibration stream");
+
+            rt.block_on(async move {
+                loop {
+                    match broadcast_rx.recv().await {
+                        Ok(progress) => {
+                            if sink.add(progress).is_err() {
+                                break;
+                            }
+                        }
+                        Err(err) => {
+                            let _ = sink.add_error(CalibrationError::Timeout {
+                                reason: format!("calibration channel interrupted: {}", err),
+                            });
+                            break;
+                        }
+                    }
+                }
+            });
+        });
+    } else {
+        let _ = sink.add_error(CalibrationError::Timeout {
+            reason: "calibration channel unavailable".to_string(),
+        });
+    }
+}
+
+/// Load calibration state from JSON
+///
+/// Restores a previously saved calibration state from JSON string.
+/// This allows users to skip calibration on subsequent app launches.
+///
+/// # Arguments
+/// * `json` - JSON string containing serialized CalibrationState
+///
+/// # Returns
+/// * `Ok(())` - Calibration state loaded successfully
+/// * `Err(CalibrationError)` - Error if deserialization or validation fails, or lock poisoning
+///
+/// # Errors
+/// - JSON deserialization error (invalid format)
+/// - Implausible thresholds, see `CalibrationState::validate`
+/// - Lock poisoning on calibration state
+///
+/// # Usage
+/// ```dart
+/// try {
+///   await loadCalibrationState(jsonString);
+///   print('Calibration loaded successfully');
+/// } catch (e) {
+///   print('Failed to load calibration: $e');
+/// }
+/// ```
+#[flutter_rust_bridge::frb]
+pub fn load_calibration_state(json: String) -> Result<(), CalibrationError> {
+    use crate::calibration::CalibrationState;
+
+    eprintln!("[Rust API] load_calibration_state called");
+    eprintln!("[Rust API] JSON input: {}", json);
+
+    // Deserialize JSON to CalibrationState
+    let state: CalibrationState = serde_json::from_str(&json).map_err(|e| {
+        eprintln!("[Rust API] Failed to deserialize: {}", e);
+        CalibrationError::Parse {
+            reason: format!("Failed to deserialize calibration JSON: {}", e),
+        }
+    })?;
+
+    eprintln!("[Rust API] Deserialized state: level={}, is_calibrated={}, t_kick_centroid={}, t_snare_centroid={}, noise_floor_rms={}",
+              state.level, state.is_calibrated, state.t_kick_centroid, state.t_snare_centroid, state.noise_floor_rms);
+
+    state
+        .validate()
+        .map_err(|problems| CalibrationError::InvalidFeatures {
+            reason: format!("Implausible calibration state: {}", problems.join("; ")),
+        })?;
+
+    // Load state into EngineHandle
+    ENGINE_HANDLE.load_calibration(state)?;
+    eprintln!("[Rust API] Calibration state loaded into engine");
+
+    Ok(())
+}
+
+/// Load a built-in starter calibration by name
+///
+/// Gives new users reasonable thresholds before they calibrate. Presets are
+/// embedded in the binary: `phone_builtin`, `headset` and `usb_condenser`.
+///
+/// # Errors
+/// - `UnknownPreset` for an unknown name (the message lists the presets)
+/// - Lock poisoning on calibration state
+#[flutter_rust_bridge::frb]
+pub fn load_preset_calibration(name: String) -> Result<(), CalibrationError> {
+    let state = crate::calibration::presets::load_preset(&name)?;
+    ENGINE_HANDLE.load_calibration(state)?;
+    tracing::info!("[Rust API] Loaded calibration preset '{}'", name);
+    Ok(())
+}
+
+/// Load the built-in starter calibration suited to a device model
+///
+/// `model` is the phone or microphone model name; recognized headsets and
+/// USB microphones get their own preset, anything else `phone_builtin`.
+/// Returns the name of the preset that was loaded.
+///
+/// # Errors
+/// - Lock poisoning on calibration state
+#[flutter_rust_bridge::frb]
+pub fn load_preset_calibration_for_device(model: String) -> Result<String, CalibrationError> {
+    let name = crate::calibration::presets::preset_for_device_model(&model);
+    load_preset_calibration(name.to_string())?;
+    Ok(name.to_string())
+}
+
+/// Get current calibration state as JSON
+///
+/// Retrieves the current calibration state serialized to JSON string.
+/// This JSON can be saved to persistent storage and restored later using
+/// `load_calibration_state`.
+///
+/// # Returns
+/// * `Ok(String)` - JSON string containing serialized CalibrationState
+/// * `Err(CalibrationError)` - Error if serialization fails or lock poisoning
+///
+/// # Errors
+/// - JSON serialization error (should be rare)
+/// - Lock poisoning on calibration state
+///
+/// # Usage
+/// ```dart
+/// try {
+///   final jsonString = await getCalibrationState();
+///   // Save jsonString to SharedPreferences
+/// } catch (e) {
+///   print('Failed to get calibration state: $e');
+/// }
+/// ```
+#[flutter_rust_bridge::frb]
+pub fn get_calibration_state() -> Result<String, CalibrationError> {
+    // Get calibration state from EngineHandle
+    let state = ENGINE_HANDLE.get_calibration_state()?;
+    eprintln!(
+        "[Rust API] get_calibration_state: level={}, is_calibrated={}, noise_floor_rms={}",
+        state.level, state.is_calibrated, state.noise_floor_rms
+    );
+
+    // Serialize to JSON
+    serde_json::to_string(&state).map_err(|e| CalibrationError::InvalidFeatures {
+        reason: format!("Failed to serialize calibration state to JSON: {}", e),
+    })
+}
+
+/// Update a single calibration threshold value in the active calibration state.
+///
+/// This enables manual threshold tweaking for debugging and tuning without
+/// requiring a full recalibration cycle.
+///
+/// # Parameters
+/// - `key`: The threshold key to update. Valid keys:
+///   - "t_kick_centroid"
+///   - "t_kick_zcr"
+///   - "t_snare_centroid"
+///   - "t_hihat_zcr"
+///   - "noise_floor_rms"
+/// - `value`: The new threshold value
+///
+/// # Returns
+/// * `Ok(())` - Threshold updated successfully
+/// * `Err(CalibrationError)` - If key is invalid or lock fails
+#[flutter_rust_bridge::frb]
+pub fn update_calibration_threshold(key: String, value: f64) -> Result<(), CalibrationError> {
+    eprintln!(
+        "[Rust API] update_calibration_threshold: key={}, value={}",
+        key, value
+    );
+
+    let mut state = ENGINE_HANDLE.get_calibration_state()?;
+
+    match key.as_str() {
+        "t_kick_centroid" => state.t_kick_centroid = value as f32,
+        "t_kick_zcr" => state.t_kick_zcr = value as f32,
+        "t_snare_centroid" => state.t_snare_centroid = value as f32,
+        "t_hihat_zcr" => state.t_hihat_zcr = value as f32,
+        "noise_floor_rms" => state.noise_floor_rms = value,
+        _ => {
+            return Err(CalibrationError::InvalidFeatures {
+                reason: format!("Unknown threshold key: {}", key),
+            });
+        }
+    }
+
+    ENGINE_HANDLE.load_calibration(state)?;
+    eprintln!("[Rust API] Threshold {} updated to {}", key, value);
+    Ok(())
+}
+
+/// Get current audio level metrics for real-time display.
+///
+/// Returns the latest RMS and peak values from the audio engine.
+/// This is a lightweight call suitable for UI updates.
+///
+/// # Returns
+/// * `Ok((rms, peak, noise_gate))` - Current audio metrics
+/// * `Err(CalibrationError)` - If state cannot be read
+#[flutter_rust_bridge::frb]
+pub fn get_current_audio_level() -> Result<(f64, f64, f64), CalibrationError> {
+    let state = ENGINE_HANDLE.get_calibration_state()?;
+    let noise_gate = state.noise_floor_rms * 2.0;
+    // Note: RMS/peak would need to come from the analysis thread
+    // For now return the noise gate threshold for debugging
+    Ok((0.0, 0.0, noise_gate))
+}
@@ -0,0 +1,131 @@
+use super::ENGINE_HANDLE;
+
+/// Override the display names attached to classification results
+///
+/// Keys are canonical sound names as serialized in `ClassificationResult`
+/// (`"Kick"`, `"Snare"`, `"HiHat"`, ...); values are the labels to show.
+/// Each call replaces all previous overrides, and an empty list restores
+/// the defaults. Classification logic is unaffected.
+///
+/// # Returns
+/// Number of labels applied (unknown sound names are skipped)
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_sound_labels(labels: Vec<(String, String)>) -> u32 {
+    use crate::types::BeatboxHit;
+
+    let mut applied = std::collections::HashMap::new();
+    for (sound, label) in labels {
+        match serde_json::from_value::<BeatboxHit>(serde_json::Value::String(sound.clone())) {
+            Ok(hit) => {
+                applied.insert(hit, label);
+            }
+            Err(_) => tracing::warn!("[Rust API] set_sound_labels: unknown sound {:?}", sound),
+        }
+    }
+
+    let count = applied.len() as u32;
+    crate::analysis::labels::set_labels(applied);
+    count
+}
+
+/// Reset all telemetry counters and history
+///
+/// Clears the event history, event and suppression counters, the latency
+/// window and streaks so a new measurement starts from zero.
+#[flutter_rust_bridge::frb(sync)]
+pub fn reset_telemetry() {
+    crate::telemetry::hub().reset();
+}
+
+/// List every supported sound with its label and whether it is active
+///
+/// "Active" follows the level the classifier will actually run at: a
+/// Level 2 setting without Level 2 thresholds classifies with Level 1 rules,
+/// so only the basic sounds are active. Lets the UI build the calibration
+/// sequence and legend without hardcoding sounds.
+#[flutter_rust_bridge::frb(sync)]
+pub fn supported_sounds() -> Vec<crate::analysis::labels::SoundDescriptor> {
+    let level = ENGINE_HANDLE
+        .get_calibration_state()
+        .map(|state| {
+            if state.level >= 2 && state.level2.is_some() {
+                2
+            } else {
+                1
+            }
+        })
+        .unwrap_or(1);
+    crate::analysis::labels::sound_descriptors(level)
+}
+
+/// Only score hits inside the given windows (call-and-response exercises)
+///
+/// Each window is `(start_ms, end_ms)` on the same clock as
+/// `ClassificationResult::timestamp_ms`. Hits outside every window are
+/// flagged with `extra` or dropped, per `outside_window_policy` in the
+/// onset config. Each call replaces the previous windows; an empty list
+/// scores every hit again.
+///
+/// # Returns
+/// Number of windows applied (windows ending before they start are skipped)
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_listening_windows(windows: Vec<(u64, u64)>) -> u32 {
+    crate::analysis::listening::ListeningWindows::shared().set(windows) as u32
+}
+
+/// Choose the JSON shape of `ClassificationResult`
+///
+/// Version 1 is the original shape (`sound`, `timing`, `timestamp_ms`,
+/// `confidence`); version 2 adds `label`, `extra` and `schema_version`;
+/// version 3 adds `smoothed_confidence`, `beat_index`, `subdivision_index`,
+/// `wall_clock_ms`, `trigger`, `flam` and `clipped`.
+/// JSON consumers built against an older shape (e.g. tools reading the
+/// debug server's classification stream) request their version once at
+/// startup; results classified afterwards are serialized in that shape.
+/// Out-of-range versions are clamped to the supported range.
+///
+/// Debug/JSON only: `classification_stream` always carries every field,
+/// since its Dart class is generated from the same struct.
+///
+/// # Returns
+/// The version applied
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_json_result_schema_version(version: u32) -> u32 {
+    crate::analysis::schema::set_requested_version(version)
+}
+
+/// Enable or disable pipeline tracing at runtime
+///
+/// When enabled, detailed trace logs are emitted for each pipeline stage:
+/// - AUDIO_CB: Audio callback receives samples
+/// - BUF_QUEUE: Buffer queued to analysis thread
+/// - ANALYSIS_RX: Analysis thread receives buffer
+/// - RMS: RMS level computed
+/// - GATE: Gate decision (above/below threshold)
+/// - ONSET: Onset detected by spectral flux
+/// - LEVEL_X: Level crossing detected
+/// - FEATURES: Features extracted from audio window
+/// - CLASSIFY: Classification decision made
+/// - RESULT_TX: Result sent to Dart
+///
+/// # Arguments
+/// * `enabled` - true to enable tracing, false to disable
+///
+/// # Returns
+/// * Previous tracing state (true if was enabled)
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_pipeline_tracing(enabled: bool) -> bool {
+    let was_enabled = crate::debug::pipeline_tracer::is_enabled();
+    if enabled {
+        crate::debug::pipeline_tracer::enable();
+    } else {
+        crate::debug::pipeline_tracer::disable();
+    }
+    was_enabled
+}
+
+/// Check if pipeline tracing is currently enabled
+#[flutter_rust_bridge::frb(sync)]
+pub fn is_pipeline_tracing_enabled() -> bool {
+    crate::debug::pipeline_tracer::is_enabled()
+}
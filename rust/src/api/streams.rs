@@ -1,7 +1,9 @@
+use crate::analysis::ClassificationResult;
 use crate::bridge_generated::StreamSink;
+use crate::calibration::CalibrationDebugFrame;
 use crate::engine::core::TelemetryEvent;
 use crate::error::AudioError;
-use crate::telemetry::{self, MetricEvent};
+use crate::telemetry::{self, MetricEvent, MetricEventKind};
 
 use super::{AudioMetrics, OnsetEvent, ENGINE_HANDLE};
 
@@ -106,6 +108,41 @@ pub fn diagnostic_metrics_stream(sink: StreamSink<MetricEvent>) {
     });
 }
 
+/// Stream of diagnostic metrics restricted to the listed event kinds.
+///
+/// Same events as [`diagnostic_metrics_stream`], filtered before they cross
+/// the FFI boundary, for widgets that only show one kind of metric.
+#[allow(unused_must_use)]
+#[flutter_rust_bridge::frb]
+pub fn telemetry_stream_filtered(sink: StreamSink<MetricEvent>, kinds: Vec<MetricEventKind>) {
+    let mut metrics_rx = telemetry::hub().collector().subscribe_filtered(kinds);
+
+    std::thread::spawn(move || {
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("Failed to create Tokio runtime for filtered telemetry stream");
+
+        rt.block_on(async move {
+            loop {
+                match metrics_rx.recv().await {
+                    Some(event) => {
+                        if sink.add(event).is_err() {
+                            break;
+                        }
+                    }
+                    None => {
+                        let _ = sink.add_error(AudioError::StreamFailure {
+                            reason: "filtered telemetry channel closed".to_string(),
+                        });
+                        break;
+                    }
+                }
+            }
+        });
+    });
+}
+
 /// Stream of onset events for debug visualization
 ///
 /// Emits OnsetEvent whenever an onset is detected.
@@ -139,3 +176,77 @@ pub fn onset_events_stream(sink: StreamSink<OnsetEvent>) {
         });
     });
 }
+
+/// High-rate stream of calibration feature frames for tuning UIs
+///
+/// Emits a CalibrationDebugFrame (centroid, ZCR, RMS, max amplitude) for
+/// every analysed buffer while calibration runs, whether or not the sound
+/// is accepted. Only active when `calibration.debug_stream` is enabled in
+/// the app config.
+#[allow(unused_must_use)]
+#[flutter_rust_bridge::frb]
+pub fn calibration_debug_stream(sink: StreamSink<CalibrationDebugFrame>) {
+    let mut frames_rx = ENGINE_HANDLE.subscribe_calibration_debug();
+
+    std::thread::spawn(move || {
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("Failed to create Tokio runtime for calibration debug stream");
+
+        rt.block_on(async move {
+            loop {
+                match frames_rx.recv().await {
+                    Some(frame) => {
+                        if sink.add(frame).is_err() {
+                            break;
+                        }
+                    }
+                    None => {
+                        let _ = sink.add_error(AudioError::StreamFailure {
+                            reason: "calibration debug channel closed".to_string(),
+                        });
+                        break;
+                    }
+                }
+            }
+        });
+    });
+}
+
+/// Provisional classifications of calibration hits
+///
+/// Each hit collected during calibration is also classified against the
+/// calibration currently loaded, so the UI can show how it would have been
+/// heard. The hits still become calibration samples. Only active when `calibration.preview_classification`
+/// is enabled in the app config.
+#[allow(unused_must_use)]
+#[flutter_rust_bridge::frb]
+pub fn calibration_preview_stream(sink: StreamSink<ClassificationResult>) {
+    let mut preview_rx = ENGINE_HANDLE.subscribe_calibration_preview();
+
+    std::thread::spawn(move || {
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("Failed to create Tokio runtime for calibration preview stream");
+
+        rt.block_on(async move {
+            loop {
+                match preview_rx.recv().await {
+                    Some(result) => {
+                        if sink.add(result).is_err() {
+                            break;
+                        }
+                    }
+                    None => {
+                        let _ = sink.add_error(AudioError::StreamFailure {
+                            reason: "calibration preview channel closed".to_string(),
+                        });
+                        break;
+                    }
+                }
+            }
+        });
+    });
+}
@@ -11,3 +11,47 @@ fn test_get_version() {
     let result = get_version().unwrap();
     assert_eq!(result, "0.1.0");
 }
+
+#[test]
+fn test_extract_features_matches_extractor() {
+    let samples: Vec<f32> = (0..2048)
+        .map(|i| {
+            let t = i as f32 / 48000.0;
+            (-t * 30.0).exp() * (2.0 * std::f32::consts::PI * 180.0 * t).sin()
+        })
+        .collect();
+
+    let direct = FeatureExtractor::new(48000).unwrap().extract(&samples);
+    let via_api = extract_features(samples, 48000).unwrap();
+    assert_eq!(via_api, direct);
+
+    let json = serde_json::to_value(via_api).unwrap();
+    assert!(json.get("centroid").is_some());
+}
+
+#[test]
+fn test_compute_spectrum_peaks_at_sine_bin() {
+    // 3 kHz at 48 kHz with a 1024-point FFT lands exactly on bin 64
+    let samples: Vec<f32> = (0..1024)
+        .map(|i| (2.0 * std::f32::consts::PI * 3000.0 * i as f32 / 48000.0).sin())
+        .collect();
+
+    let spectrum = compute_spectrum(samples.clone(), 48000, 1024, false).unwrap();
+    assert_eq!(spectrum.len(), 513);
+    let peak = (0..spectrum.len())
+        .max_by(|&a, &b| spectrum[a].total_cmp(&spectrum[b]))
+        .unwrap();
+    assert_eq!(peak, 64);
+
+    let db = compute_spectrum(samples, 48000, 1024, true).unwrap();
+    assert!((db[64] - 20.0 * spectrum[64].log10()).abs() < 1e-3);
+    assert!(db.iter().all(|v| *v >= -120.0));
+}
+
+#[test]
+fn test_compute_spectrum_validates_input() {
+    assert!(compute_spectrum(vec![], 48000, 1024, false).is_err());
+    assert!(compute_spectrum(vec![0.0; 16], 0, 1024, false).is_err());
+    assert!(compute_spectrum(vec![0.0; 16], 48000, 0, false).is_err());
+    assert!(compute_spectrum(vec![f32::NAN; 16], 48000, 16, false).is_err());
+}
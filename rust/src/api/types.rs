@@ -1,9 +1,14 @@
+use crate::analysis::features::Features;
 use crate::analysis::ClassificationResult;
 
 /// Audio metrics for debug visualization
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct AudioMetrics {
+    /// Raw RMS of the current analysis chunk
     pub rms: f64,
+    /// RMS with VU-meter ballistics (see `vu_time_constant_ms`), for display
+    #[serde(default)]
+    pub smoothed_rms: f64,
     pub spectral_centroid: f64,
     pub spectral_flux: f64,
     pub frame_number: u64,
@@ -14,6 +19,7 @@ pub struct AudioMetrics {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct OnsetEvent {
     pub timestamp: u64,
+    /// RMS level of the onset window
     pub energy: f64,
     pub centroid: f64,
     pub zcr: f64,
@@ -21,4 +27,12 @@ pub struct OnsetEvent {
     pub rolloff: f64,
     pub decay_time_ms: f64,
     pub classification: Option<ClassificationResult>,
+    /// Onset strength: prominence of the spectral flux peak above the
+    /// adaptive threshold
+    #[serde(default)]
+    pub strength: f64,
+    /// Full feature set extracted from the onset window (`None` in monitor
+    /// mode, where onsets are not analysed)
+    #[serde(default)]
+    pub features: Option<Features>,
 }
@@ -17,24 +17,54 @@
 //!   └─> OutputCallback::new()
 //!       └─> oboe::AudioStreamBuilder::set_callback()
 //!           └─> OutputCallback::on_audio_ready() [Real-time thread]
-//!               ├─> is_on_beat() [Check if click should trigger]
+//!               ├─> is_click_frame() [Check if a beat or subdivision click should trigger]
 //!               └─> Generate audio samples [Lock-free atomic reads]
 //! ```
 
 use log::{info, warn};
 use oboe::{
     AudioInputStreamSync, AudioOutputCallback, AudioOutputStreamSafe, AudioStreamSync,
-    DataCallbackResult, Input,
+    DataCallbackResult, Input, IsFrameType,
 };
+use std::marker::PhantomData;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
 use std::sync::Arc;
 
 use super::buffer_pool::AudioThreadChannels;
-use super::metronome::is_on_beat;
+use super::click::ClickSamples;
+use super::metronome::MetronomeLevel;
+
+/// One output frame the metronome can render a click sample into
+///
+/// Implemented for mono (`f32`) and stereo (`(f32, f32)`) frames so the same
+/// callback drives whichever channel layout the device accepted.
+pub trait ClickFrame {
+    /// Write `sample` using the click's (left, right) pan gains
+    fn write(&mut self, sample: f32, gains: (f32, f32));
+}
+
+impl ClickFrame for f32 {
+    /// Mono output cannot pan, so the click plays at full level
+    #[inline]
+    fn write(&mut self, sample: f32, _gains: (f32, f32)) {
+        *self = sample;
+    }
+}
+
+impl ClickFrame for (f32, f32) {
+    #[inline]
+    fn write(&mut self, sample: f32, gains: (f32, f32)) {
+        self.0 = sample * gains.0;
+        self.1 = sample * gains.1;
+    }
+}
 
 /// Output audio callback for metronome generation
 ///
+/// `C` is the oboe channel layout (`oboe::Mono` or `oboe::Stereo`) the
+/// output stream was opened with.
+///
 /// This struct implements the `AudioOutputCallback` trait required by oboe-rs v0.6.x.
 /// It encapsulates all state needed by the real-time audio callback, ensuring
 /// thread-safe access via atomic operations.
@@ -58,15 +88,15 @@ use super::metronome::is_on_beat;
 ///     .set_callback(callback)
 ///     .open_stream()?;
 /// ```
-pub struct OutputCallback {
+pub struct OutputCallback<C> {
     /// Atomic frame counter for sample-accurate timing
     frame_counter: Arc<AtomicU64>,
     /// Atomic BPM for dynamic tempo changes
     bpm: Arc<AtomicU32>,
     /// Sample rate in Hz
     sample_rate: u32,
-    /// Pre-generated metronome click samples
-    click_samples: Arc<Vec<f32>>,
+    /// Pre-generated metronome click samples (regular + accent)
+    click_samples: Arc<ClickSamples>,
     /// Current position in click sample playback
     click_position: Arc<AtomicU64>,
     /// Input audio stream for microphone capture
@@ -75,9 +105,13 @@ pub struct OutputCallback {
     audio_channels: Arc<std::sync::Mutex<Option<AudioThreadChannels>>>,
     /// Whether metronome output is enabled (calibration disables clicks)
     metronome_enabled: Arc<AtomicBool>,
+    /// Runtime click volume and mute
+    level: MetronomeLevel,
+    /// Output channel layout marker
+    channels: PhantomData<C>,
 }
 
-impl OutputCallback {
+impl<C> OutputCallback<C> {
     /// Create a new OutputCallback with the given state
     ///
     /// # Arguments
@@ -88,15 +122,19 @@ impl OutputCallback {
     /// * `click_position` - Shared atomic click position tracker
     /// * `input_stream` - Input stream for microphone capture
     /// * `audio_channels` - Buffer pool channels for audio data transfer
+    /// * `metronome_enabled` - Whether clicks are generated at all
+    /// * `level` - Runtime click volume and mute
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         frame_counter: Arc<AtomicU64>,
         bpm: Arc<AtomicU32>,
         sample_rate: u32,
-        click_samples: Arc<Vec<f32>>,
+        click_samples: Arc<ClickSamples>,
         click_position: Arc<AtomicU64>,
         input_stream: Arc<std::sync::Mutex<Option<AudioStreamSync<Input, (f32, oboe::Mono)>>>>,
         audio_channels: Arc<std::sync::Mutex<Option<AudioThreadChannels>>>,
         metronome_enabled: Arc<AtomicBool>,
+        level: MetronomeLevel,
     ) -> Self {
         Self {
             frame_counter,
@@ -107,6 +145,8 @@ impl OutputCallback {
             input_stream,
             audio_channels,
             metronome_enabled,
+            level,
+            channels: PhantomData,
         }
     }
 
@@ -145,20 +185,23 @@ impl OutputCallback {
                         }
                     }
                 }
-            } else {
-                warn!("[AudioCallback] input_stream is None");
             }
+            // No input stream means metronome-only mode: nothing to pump.
         }
     }
 }
 
-impl AudioOutputCallback for OutputCallback {
-    type FrameType = (f32, oboe::Mono);
+impl<C> AudioOutputCallback for OutputCallback<C>
+where
+    (f32, C): IsFrameType,
+    <(f32, C) as IsFrameType>::Type: ClickFrame,
+{
+    type FrameType = (f32, C);
 
     fn on_audio_ready(
         &mut self,
         _stream: &mut dyn AudioOutputStreamSafe,
-        frames: &mut [f32],
+        frames: &mut [<(f32, C) as IsFrameType>::Type],
     ) -> DataCallbackResult {
         // Real-time audio callback - NO ALLOCATIONS, LOCKS, OR BLOCKING!
 
@@ -172,21 +215,38 @@ impl AudioOutputCallback for OutputCallback {
 
         // Process each output frame (metronome generation)
         let clicks_enabled = self.metronome_enabled.load(Ordering::Relaxed);
-        for (i, sample) in frames.iter_mut().enumerate() {
+        // Muting scales the output only; clicks keep advancing underneath
+        let gain = self.level.gain();
+        // Resume whichever click (regular/accent) was playing in the last callback
+        let (mut click, mut gains) = self.click_samples.for_frame_stereo(
+            current_frame.saturating_sub(click_pos as u64),
+            current_bpm,
+            self.sample_rate,
+        );
+        for (i, output) in frames.iter_mut().enumerate() {
             // Calculate current frame index for this sample
             let frame = current_frame + i as u64;
 
-            if clicks_enabled && is_on_beat(frame, current_bpm, self.sample_rate) {
+            if clicks_enabled
+                && self
+                    .click_samples
+                    .is_click_frame(frame, current_bpm, self.sample_rate)
+            {
                 // Start playing click sample
                 click_pos = 0;
+                (click, gains) =
+                    self.click_samples
+                        .for_frame_stereo(frame, current_bpm, self.sample_rate);
             }
 
             // Generate metronome click if we're within click duration
-            if clicks_enabled && click_pos < self.click_samples.len() {
-                *sample = self.click_samples[click_pos];
+            if clicks_enabled && click_pos < click.len() {
+                // Gains are (1.0, 1.0) unless stereo panning is configured
+                output.write(click[click_pos] * gain, gains);
                 click_pos += 1;
             } else {
-                *sample = 0.0; // Silence between clicks
+                // Silence between clicks
+                output.write(0.0, gains);
             }
         }
 
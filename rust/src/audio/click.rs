@@ -0,0 +1,279 @@
+//! Click - metronome click sounds and the buffers the output callback plays
+//!
+//! Synthesizes the noise burst and pitched (windowed sine) clicks, pans them,
+//! and selects the buffer for each beat, accent or subdivision, including the
+//! seeded bar muting used for gap training.
+
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use super::metronome::{is_accent_beat, is_on_beat, is_on_subdivision, samples_per_beat};
+use crate::config::{ClickConfig, ClickStyle};
+
+/// Duration of metronome click in milliseconds
+const CLICK_DURATION_MS: f32 = 20.0;
+
+/// Level of subdivision clicks relative to the regular beat click
+const SUBDIVISION_GAIN: f32 = 0.4;
+
+/// Generates a metronome click sample (20ms white noise burst).
+///
+/// This function creates a deterministic white noise burst for use as a metronome click.
+/// The noise is generated using a fixed seed to ensure identical output across calls.
+///
+/// # Arguments
+/// * `sample_rate` - Sample rate in Hz (typically 48000)
+///
+/// # Returns
+/// A `Vec<f32>` containing exactly 20ms worth of white noise samples in range [-1.0, 1.0]
+///
+/// # Examples
+/// ```
+/// use beatbox_trainer::audio::click::generate_click_sample;
+/// let sample_rate = 48000;
+/// let click = generate_click_sample(sample_rate);
+/// assert_eq!(click.len(), (sample_rate as f32 * 0.02) as usize);
+/// ```
+pub fn generate_click_sample(sample_rate: u32) -> Vec<f32> {
+    generate_noise_click(sample_rate, CLICK_DURATION_MS)
+}
+
+/// Generates a deterministic white noise click of arbitrary duration.
+///
+/// # Arguments
+/// * `sample_rate` - Sample rate in Hz
+/// * `duration_ms` - Click length in milliseconds
+pub fn generate_noise_click(sample_rate: u32, duration_ms: f32) -> Vec<f32> {
+    let num_samples = click_length(sample_rate, duration_ms);
+
+    // Use fixed seed for deterministic noise generation
+    let mut rng = ChaCha8Rng::seed_from_u64(42);
+
+    // Generate white noise in range [-1.0, 1.0]
+    let mut samples = Vec::with_capacity(num_samples);
+    for _ in 0..num_samples {
+        samples.push(rng.gen_range(-1.0..1.0));
+    }
+
+    samples
+}
+
+/// Generates a pitched click: a sine at `frequency_hz` shaped by a Hann window.
+///
+/// The window gives the beep a smooth attack and release so it does not
+/// produce broadband clicks at its edges.
+///
+/// # Arguments
+/// * `sample_rate` - Sample rate in Hz
+/// * `frequency_hz` - Pitch of the beep in Hz
+/// * `duration_ms` - Click length in milliseconds
+///
+/// # Examples
+/// ```
+/// use beatbox_trainer::audio::click::generate_pitched_click;
+/// let click = generate_pitched_click(48000, 1000.0, 20.0);
+/// assert_eq!(click.len(), 960);
+/// ```
+pub fn generate_pitched_click(sample_rate: u32, frequency_hz: f32, duration_ms: f32) -> Vec<f32> {
+    let num_samples = click_length(sample_rate, duration_ms);
+    let denom = (num_samples.max(2) - 1) as f32;
+    let two_pi = 2.0 * std::f32::consts::PI;
+
+    (0..num_samples)
+        .map(|i| {
+            let window = 0.5 * (1.0 - (two_pi * i as f32 / denom).cos());
+            let phase = two_pi * frequency_hz * i as f32 / sample_rate as f32;
+            window * phase.sin()
+        })
+        .collect()
+}
+
+fn click_length(sample_rate: u32, duration_ms: f32) -> usize {
+    (sample_rate as f32 * duration_ms.max(0.0) / 1000.0) as usize
+}
+/// Pre-generated click buffers shared with the real-time output callback.
+#[derive(Debug, Clone)]
+pub struct ClickSamples {
+    /// Click played on regular beats
+    pub regular: Vec<f32>,
+    /// Click played on the first beat of each bar
+    pub accent: Vec<f32>,
+    /// Beats per bar (0 disables accents)
+    pub beats_per_bar: u32,
+    /// (left, right) output gains for regular clicks
+    pub regular_gains: (f32, f32),
+    /// (left, right) output gains for accent clicks
+    pub accent_gains: (f32, f32),
+    /// Probability that a bar is silent (0 disables muting)
+    pub mute_probability: f32,
+    /// Seed for the per-bar mute decision
+    pub mute_seed: u64,
+    /// Softer click played between beats
+    pub subdivision: Vec<f32>,
+    /// Clicks per beat including the beat itself (0 or 1 disables subdivisions)
+    pub subdivision_clicks: u32,
+}
+
+impl ClickSamples {
+    /// Build click buffers from configuration.
+    ///
+    /// Noise style uses the same burst for regular and accent beats; pitched
+    /// style renders separate beeps at the configured frequencies.
+    pub fn from_config(sample_rate: u32, config: &ClickConfig) -> Self {
+        let (regular, accent) = match config.style {
+            ClickStyle::Noise => {
+                let noise = generate_noise_click(sample_rate, config.duration_ms);
+                (noise.clone(), noise)
+            }
+            ClickStyle::Pitched => (
+                generate_pitched_click(sample_rate, config.frequency_hz, config.duration_ms),
+                generate_pitched_click(sample_rate, config.accent_frequency_hz, config.duration_ms),
+            ),
+        };
+
+        // Without stereo output every channel gets the click at full level
+        let (regular_gains, accent_gains) = if config.stereo {
+            (pan_gains(config.beat_pan), pan_gains(config.accent_pan))
+        } else {
+            ((1.0, 1.0), (1.0, 1.0))
+        };
+
+        let subdivision = regular.iter().map(|s| s * SUBDIVISION_GAIN).collect();
+
+        Self {
+            regular,
+            accent,
+            beats_per_bar: config.beats_per_bar,
+            regular_gains,
+            accent_gains,
+            mute_probability: if config.mute_probability.is_finite() {
+                config.mute_probability.clamp(0.0, 1.0)
+            } else {
+                0.0
+            },
+            mute_seed: config.mute_seed,
+            subdivision,
+            subdivision_clicks: config.subdivision_clicks,
+        }
+    }
+
+    /// Whether the bar containing `beat_frame` is muted for gap training.
+    ///
+    /// The decision depends only on the seed and the bar index, so the same
+    /// bars are muted on every run and no state is kept between callbacks.
+    /// Bar 0 is never muted. With accents disabled every beat counts as a bar.
+    #[inline]
+    pub fn is_muted_bar(&self, beat_frame: u64, bpm: u32, sample_rate: u32) -> bool {
+        if self.mute_probability <= 0.0 || bpm == 0 {
+            return false;
+        }
+        let beat_index = beat_frame / samples_per_beat(bpm, sample_rate);
+        let bar_index = beat_index / self.beats_per_bar.max(1) as u64;
+        if bar_index == 0 {
+            return false;
+        }
+        // ChaCha8 rather than StdRng: its output is fixed across rand
+        // releases, so a seed keeps muting the same bars after upgrades
+        let mut rng = ChaCha8Rng::seed_from_u64(self.mute_seed ^ bar_index);
+        rng.gen::<f32>() < self.mute_probability
+    }
+
+    /// Select the buffer for the beat that starts at `beat_frame`.
+    ///
+    /// Empty when the beat falls in a muted bar.
+    #[inline]
+    pub fn for_beat(&self, beat_frame: u64, bpm: u32, sample_rate: u32) -> &[f32] {
+        if self.is_muted_bar(beat_frame, bpm, sample_rate) {
+            &[]
+        } else if is_accent_beat(beat_frame, bpm, sample_rate, self.beats_per_bar) {
+            &self.accent
+        } else {
+            &self.regular
+        }
+    }
+
+    /// Whether any click is panned off center, i.e. needs a stereo output.
+    ///
+    /// Centered clicks play at unity on both channels, so mono output
+    /// renders them identically.
+    pub fn is_panned(&self) -> bool {
+        self.regular_gains != (1.0, 1.0) || self.accent_gains != (1.0, 1.0)
+    }
+
+    /// Select the buffer and (left, right) gains for the beat at `beat_frame`.
+    ///
+    /// The buffer is empty when the beat falls in a muted bar.
+    #[inline]
+    pub fn for_beat_stereo(
+        &self,
+        beat_frame: u64,
+        bpm: u32,
+        sample_rate: u32,
+    ) -> (&[f32], (f32, f32)) {
+        if self.is_muted_bar(beat_frame, bpm, sample_rate) {
+            (&[], self.regular_gains)
+        } else if is_accent_beat(beat_frame, bpm, sample_rate, self.beats_per_bar) {
+            (&self.accent, self.accent_gains)
+        } else {
+            (&self.regular, self.regular_gains)
+        }
+    }
+
+    /// Whether a click (beat or subdivision) starts at `frame`.
+    #[inline]
+    pub fn is_click_frame(&self, frame: u64, bpm: u32, sample_rate: u32) -> bool {
+        is_on_beat(frame, bpm, sample_rate)
+            || is_on_subdivision(frame, bpm, sample_rate, self.subdivision_clicks)
+    }
+
+    /// Select the buffer and (left, right) gains for the click starting at `frame`.
+    ///
+    /// Beats (and their accents) take priority; frames between beats get the
+    /// softer subdivision click, panned like regular beats. The buffer is
+    /// empty when the frame falls in a muted bar.
+    #[inline]
+    pub fn for_frame_stereo(&self, frame: u64, bpm: u32, sample_rate: u32) -> (&[f32], (f32, f32)) {
+        if is_on_beat(frame, bpm, sample_rate)
+            || !is_on_subdivision(frame, bpm, sample_rate, self.subdivision_clicks)
+        {
+            self.for_beat_stereo(frame, bpm, sample_rate)
+        } else if self.is_muted_bar(frame, bpm, sample_rate) {
+            (&[], self.regular_gains)
+        } else {
+            (&self.subdivision, self.regular_gains)
+        }
+    }
+}
+
+/// (left, right) gains for a pan position, unity at center.
+///
+/// `pan` runs from -1.0 (hard left) through 0.0 (center) to 1.0 (hard
+/// right) and is clamped to that range; NaN pans to center. A centered
+/// click plays at full level on both channels, exactly like mono output.
+/// Panning keeps the near channel at unity and fades the far one along a
+/// quarter sine, so a hard pan silences it.
+///
+/// # Examples
+/// ```
+/// use beatbox_trainer::audio::click::pan_gains;
+/// let (left, right) = pan_gains(-1.0);
+/// assert!((left - 1.0).abs() < 1e-6 && right.abs() < 1e-6);
+/// ```
+#[inline]
+pub fn pan_gains(pan: f32) -> (f32, f32) {
+    let pan = if pan.is_nan() {
+        0.0
+    } else {
+        pan.clamp(-1.0, 1.0)
+    };
+    let far = ((1.0 - pan.abs()) * std::f32::consts::FRAC_PI_2).sin();
+    if pan < 0.0 {
+        (1.0, far)
+    } else {
+        (far, 1.0)
+    }
+}
+
+#[cfg(test)]
+#[path = "click_tests.rs"]
+mod tests;
@@ -0,0 +1,240 @@
+use super::*;
+
+#[test]
+fn test_generate_click_sample_duration() {
+    // Test at common sample rates
+    let sample_rates = [44100, 48000, 96000];
+
+    for &sr in &sample_rates {
+        let click = generate_click_sample(sr);
+        let expected_samples = (sr as f32 * CLICK_DURATION_MS / 1000.0) as usize;
+        assert_eq!(
+            click.len(),
+            expected_samples,
+            "Click duration should be exactly 20ms at {} Hz",
+            sr
+        );
+    }
+}
+
+#[test]
+fn test_generate_click_sample_range() {
+    let click = generate_click_sample(48000);
+
+    // Verify all samples are in valid range [-1.0, 1.0]
+    for (i, &sample) in click.iter().enumerate() {
+        assert!(
+            (-1.0..=1.0).contains(&sample),
+            "Sample {} at index {} is out of range [-1.0, 1.0]",
+            sample,
+            i
+        );
+    }
+}
+
+#[test]
+fn test_generate_click_sample_deterministic() {
+    // Same input should produce identical output (fixed seed)
+    let click1 = generate_click_sample(48000);
+    let click2 = generate_click_sample(48000);
+
+    assert_eq!(
+        click1.len(),
+        click2.len(),
+        "Deterministic generation should produce same length"
+    );
+
+    for (i, (&s1, &s2)) in click1.iter().zip(click2.iter()).enumerate() {
+        assert_eq!(
+            s1, s2,
+            "Sample {} differs: {} vs {}. Generation should be deterministic.",
+            i, s1, s2
+        );
+    }
+}
+
+#[test]
+fn test_pitched_click_dominant_frequency() {
+    use crate::analysis::features::fft::{FftProcessor, FFT_SIZE};
+
+    let sample_rate = 48000;
+    let requested_hz = 1000.0;
+    let click = generate_pitched_click(sample_rate, requested_hz, 20.0);
+
+    let spectrum = FftProcessor::new(FFT_SIZE)
+        .unwrap()
+        .compute_magnitude_spectrum(&click);
+    let (peak_bin, _) = spectrum
+        .iter()
+        .enumerate()
+        .max_by(|a, b| a.1.total_cmp(b.1))
+        .unwrap();
+
+    let bin_hz = sample_rate as f32 / FFT_SIZE as f32;
+    let dominant_hz = peak_bin as f32 * bin_hz;
+    assert!(
+        (dominant_hz - requested_hz).abs() <= bin_hz,
+        "Dominant frequency {} Hz should match requested {} Hz",
+        dominant_hz,
+        requested_hz
+    );
+}
+
+#[test]
+fn test_click_samples_accent_on_downbeat() {
+    let config = ClickConfig {
+        style: ClickStyle::Pitched,
+        ..ClickConfig::default()
+    };
+    let clicks = ClickSamples::from_config(48000, &config);
+    let spb = samples_per_beat(120, 48000);
+
+    assert!(std::ptr::eq(
+        clicks.for_beat(0, 120, 48000),
+        clicks.accent.as_slice()
+    ));
+    assert!(std::ptr::eq(
+        clicks.for_beat(spb, 120, 48000),
+        clicks.regular.as_slice()
+    ));
+    assert!(std::ptr::eq(
+        clicks.for_beat(spb * 4, 120, 48000),
+        clicks.accent.as_slice()
+    ));
+    assert!(!is_accent_beat(0, 120, 48000, 0));
+}
+
+#[test]
+fn test_pan_gains_produce_expected_left_right_levels() {
+    let close = |a: f32, b: f32| (a - b).abs() < 1e-6;
+
+    // Center matches mono output: no -3 dB dip
+    let (left, right) = pan_gains(0.0);
+    assert!(close(left, 1.0) && close(right, 1.0));
+
+    let (left, right) = pan_gains(-1.0);
+    assert!(close(left, 1.0) && close(right, 0.0));
+
+    let (left, right) = pan_gains(1.0);
+    assert!(close(left, 0.0) && close(right, 1.0));
+
+    // The near channel stays at unity; the far one fades monotonically
+    let mut previous_far = 1.0;
+    for pan in [0.2, 0.5, 0.8] {
+        let (left, right) = pan_gains(pan);
+        assert!(close(right, 1.0), "pan {pan}");
+        assert!(left < previous_far && left > 0.0, "pan {pan}");
+        assert_eq!(pan_gains(-pan), (right, left));
+        previous_far = left;
+    }
+    // Out-of-range and NaN are tamed
+    assert_eq!(pan_gains(3.0), pan_gains(1.0));
+    assert_eq!(pan_gains(f32::NAN), pan_gains(0.0));
+}
+
+#[test]
+fn test_stereo_click_config_pans_accent_and_beat() {
+    let config = ClickConfig {
+        stereo: true,
+        beat_pan: 0.5,
+        accent_pan: -0.5,
+        ..ClickConfig::default()
+    };
+    let clicks = ClickSamples::from_config(48000, &config);
+    let spb = samples_per_beat(120, 48000);
+
+    let (_, accent_gains) = clicks.for_beat_stereo(0, 120, 48000);
+    let (_, beat_gains) = clicks.for_beat_stereo(spb, 120, 48000);
+    assert!(accent_gains.0 > accent_gains.1);
+    assert!(beat_gains.1 > beat_gains.0);
+    assert!(clicks.is_panned());
+
+    // Mono (default) duplicates the click at full level
+    let mono = ClickSamples::from_config(48000, &ClickConfig::default());
+    assert_eq!(mono.for_beat_stereo(spb, 120, 48000).1, (1.0, 1.0));
+    assert!(!mono.is_panned());
+
+    // Stereo with both clicks centered needs no stereo stream either
+    let centered = ClickSamples::from_config(
+        48000,
+        &ClickConfig {
+            stereo: true,
+            ..ClickConfig::default()
+        },
+    );
+    assert!(!centered.is_panned());
+}
+
+#[test]
+fn test_random_mutes_are_reproducible_per_seed() {
+    let config = ClickConfig {
+        mute_probability: 0.5,
+        mute_seed: 434,
+        ..ClickConfig::default()
+    };
+    let spb = samples_per_beat(120, 48000);
+    let muted_bars = |clicks: &ClickSamples| -> Vec<u64> {
+        (0..64)
+            .filter(|bar| clicks.for_beat(bar * 4 * spb, 120, 48000).is_empty())
+            .collect()
+    };
+
+    let first = muted_bars(&ClickSamples::from_config(48000, &config));
+    let second = muted_bars(&ClickSamples::from_config(48000, &config));
+    assert_eq!(first, second);
+    assert!(!first.is_empty() && first.len() < 64, "{first:?}");
+    assert!(!first.contains(&0));
+
+    // Every beat of a muted bar is silent
+    let clicks = ClickSamples::from_config(48000, &config);
+    let bar = first[0];
+    for beat in 0..4 {
+        let frame = (bar * 4 + beat) * spb;
+        assert!(clicks.for_beat_stereo(frame, 120, 48000).0.is_empty());
+    }
+
+    let other_seed = ClickSamples::from_config(
+        48000,
+        &ClickConfig {
+            mute_seed: 435,
+            ..config.clone()
+        },
+    );
+    assert_ne!(muted_bars(&other_seed), first);
+    assert!(muted_bars(&ClickSamples::from_config(48000, &ClickConfig::default())).is_empty());
+}
+
+#[test]
+fn test_subdivision_clicks_at_eighth_notes() {
+    let config = ClickConfig {
+        subdivision_clicks: 2,
+        ..ClickConfig::default()
+    };
+    let clicks = ClickSamples::from_config(48000, &config);
+
+    let click_frames: Vec<u64> = (0..96_000)
+        .filter(|&frame| clicks.is_click_frame(frame, 120, 48000))
+        .collect();
+    assert_eq!(
+        click_frames,
+        vec![0, 12000, 24000, 36000, 48000, 60000, 72000, 84000]
+    );
+
+    // Accent on beat 1, regular beats, softer clicks in between
+    assert!(std::ptr::eq(
+        clicks.for_frame_stereo(0, 120, 48000).0,
+        clicks.accent.as_slice()
+    ));
+    assert!(std::ptr::eq(
+        clicks.for_frame_stereo(24000, 120, 48000).0,
+        clicks.regular.as_slice()
+    ));
+    let (subdivision, _) = clicks.for_frame_stereo(12000, 120, 48000);
+    assert!(std::ptr::eq(subdivision, clicks.subdivision.as_slice()));
+    let peak = |buffer: &[f32]| buffer.iter().fold(0.0f32, |m, s| m.max(s.abs()));
+    assert!(peak(subdivision) < peak(&clicks.regular));
+
+    // Disabled by default
+    let plain = ClickSamples::from_config(48000, &ClickConfig::default());
+    assert!(!plain.is_click_frame(12000, 120, 48000));
+}
@@ -19,8 +19,8 @@
 
 #[cfg(target_os = "android")]
 use oboe::{
-    AudioStream, AudioStreamAsync, AudioStreamBuilder, AudioStreamSync, Input, Output,
-    PerformanceMode, SharingMode,
+    AudioOutputCallback, AudioStream, AudioStreamAsync, AudioStreamBuilder, AudioStreamSync, Input,
+    IsChannelCount, IsFrameType, Output, PerformanceMode, SharingMode,
 };
 #[cfg(target_os = "android")]
 use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
@@ -30,14 +30,16 @@ use std::sync::Arc;
 #[cfg(target_os = "android")]
 use super::buffer_pool::BufferPoolChannels;
 #[cfg(target_os = "android")]
-use crate::config::OnsetDetectionConfig;
+use crate::config::AnalysisConfig;
 #[cfg(target_os = "android")]
 use crate::error::AudioError;
 
 #[cfg(target_os = "android")]
 use super::callback::OutputCallback;
 #[cfg(target_os = "android")]
-use super::metronome::generate_click_sample;
+use super::click::ClickSamples;
+#[cfg(target_os = "android")]
+use super::metronome::MetronomeLevel;
 
 #[cfg(test)]
 use super::buffer_pool::DEFAULT_BUFFER_SIZE;
@@ -64,7 +66,7 @@ use super::buffer_pool::DEFAULT_BUFFER_SIZE;
 #[cfg(target_os = "android")]
 pub struct AudioEngine {
     /// Output audio stream (master - triggers input reads)
-    output_stream: Option<AudioStreamAsync<Output, OutputCallback>>,
+    output_stream: Option<OutputStream>,
     /// Input audio stream (slave - read by output callback)
     input_stream: Option<AudioStreamSync<Input, (f32, oboe::Mono)>>,
     /// Arc-wrapped input stream for sharing with callback
@@ -77,14 +79,40 @@ pub struct AudioEngine {
     bpm: Arc<AtomicU32>,
     /// Sample rate in Hz
     sample_rate: u32,
-    /// Pre-generated metronome click samples
-    click_samples: Arc<Vec<f32>>,
+    /// Pre-generated metronome click samples (regular + accent)
+    click_samples: Arc<ClickSamples>,
     /// Buffer pool channels for lock-free communication
     buffer_channels: BufferPoolChannels,
     /// Current position in click sample playback (for output callback state)
     click_position: Arc<AtomicU64>,
     /// Whether metronome output is enabled (calibration disables clicks)
     metronome_enabled: Arc<std::sync::atomic::AtomicBool>,
+    /// Runtime click volume and mute
+    level: MetronomeLevel,
+}
+
+/// Output stream in whichever channel layout the device accepted
+#[cfg(target_os = "android")]
+enum OutputStream {
+    Stereo(AudioStreamAsync<Output, OutputCallback<oboe::Stereo>>),
+    Mono(AudioStreamAsync<Output, OutputCallback<oboe::Mono>>),
+}
+
+#[cfg(target_os = "android")]
+impl OutputStream {
+    fn start(&mut self) -> oboe::Status {
+        match self {
+            OutputStream::Stereo(stream) => stream.start(),
+            OutputStream::Mono(stream) => stream.start(),
+        }
+    }
+
+    fn stop(&mut self) -> oboe::Status {
+        match self {
+            OutputStream::Stereo(stream) => stream.stop(),
+            OutputStream::Mono(stream) => stream.stop(),
+        }
+    }
 }
 
 #[cfg(target_os = "android")]
@@ -106,8 +134,9 @@ impl AudioEngine {
         sample_rate: u32,
         buffer_channels: BufferPoolChannels,
     ) -> Result<Self, AudioError> {
-        // Pre-generate metronome click samples (20ms white noise)
-        let click_samples = generate_click_sample(sample_rate);
+        // Pre-generate metronome click samples (20ms white noise by default)
+        let click_samples =
+            ClickSamples::from_config(sample_rate, &crate::config::ClickConfig::default());
 
         Ok(AudioEngine {
             output_stream: None,
@@ -121,6 +150,7 @@ impl AudioEngine {
             buffer_channels,
             click_position: Arc::new(AtomicU64::new(0)),
             metronome_enabled: Arc::new(std::sync::atomic::AtomicBool::new(true)),
+            level: MetronomeLevel::shared(),
         })
     }
 
@@ -129,6 +159,11 @@ impl AudioEngine {
             .store(enabled, std::sync::atomic::Ordering::Relaxed);
     }
 
+    /// Regenerate click buffers from configuration (call before `start`)
+    pub fn set_click_config(&mut self, config: &crate::config::ClickConfig) {
+        self.click_samples = Arc::new(ClickSamples::from_config(self.sample_rate, config));
+    }
+
     /// Create and open the input audio stream
     ///
     /// # Returns
@@ -152,12 +187,38 @@ impl AudioEngine {
 
     /// Create and open the output audio stream with metronome callback
     ///
+    /// Stereo is only requested when a click is panned off center; centered
+    /// clicks render identically in mono. If the device rejects a stereo
+    /// stream, a mono one is opened instead and panning is dropped.
+    ///
     /// # Returns
     /// Result containing the opened output stream with audio callback or error
     ///
     /// # Errors
     /// Returns error if output stream cannot be opened
-    fn create_output_stream(&self) -> Result<AudioStreamAsync<Output, OutputCallback>, AudioError> {
+    fn create_output_stream(&self) -> Result<OutputStream, AudioError> {
+        if self.click_samples.is_panned() {
+            match self.open_output_stream::<oboe::Stereo>() {
+                Ok(stream) => return Ok(OutputStream::Stereo(stream)),
+                Err(err) => log::warn!(
+                    "[AudioEngine] Stereo output unavailable, falling back to mono: {}",
+                    err
+                ),
+            }
+        }
+        self.open_output_stream::<oboe::Mono>()
+            .map(OutputStream::Mono)
+    }
+
+    /// Open an output stream with channel layout `C`
+    fn open_output_stream<C>(
+        &self,
+    ) -> Result<AudioStreamAsync<Output, OutputCallback<C>>, AudioError>
+    where
+        C: IsChannelCount,
+        (f32, C): IsFrameType,
+        OutputCallback<C>: AudioOutputCallback<FrameType = (f32, C)> + Send,
+    {
         // Create OutputCallback struct with cloned Arc references
         let callback = OutputCallback::new(
             Arc::clone(&self.frame_counter),
@@ -168,6 +229,7 @@ impl AudioEngine {
             Arc::clone(&self.input_stream_arc),
             Arc::clone(&self.audio_channels_arc),
             Arc::clone(&self.metronome_enabled),
+            self.level.clone(),
         );
 
         AudioStreamBuilder::default()
@@ -175,7 +237,7 @@ impl AudioEngine {
             .set_sharing_mode(SharingMode::Exclusive)
             .set_direction::<Output>()
             .set_sample_rate(self.sample_rate as i32)
-            .set_channel_count::<oboe::Mono>() // Mono output for metronome
+            .set_channel_count::<C>()
             .set_format::<f32>()
             .set_callback(callback)
             .open_stream()
@@ -192,8 +254,9 @@ impl AudioEngine {
     /// * `calibration_procedure` - Optional calibration procedure for collecting training samples
     /// * `calibration_progress_tx` - Optional broadcast channel for calibration progress updates
     /// * `result_sender` - Tokio broadcast channel for sending classification results to UI
-    /// * `onset_config` - Runtime configuration for onset detector parameters
+    /// * `analysis_config` - Onset detection, classifier and result settings
     /// * `log_every_n_buffers` - Frequency for analysis-side debug logging
+    #[allow(clippy::too_many_arguments)]
     fn spawn_analysis_thread_internal(
         &self,
         buffer_channels: BufferPoolChannels,
@@ -207,7 +270,8 @@ impl AudioEngine {
             tokio::sync::broadcast::Sender<crate::calibration::CalibrationProgress>,
         >,
         result_sender: tokio::sync::broadcast::Sender<crate::analysis::ClassificationResult>,
-        onset_config: OnsetDetectionConfig,
+        onset_events_tx: Option<tokio::sync::broadcast::Sender<crate::api::OnsetEvent>>,
+        analysis_config: AnalysisConfig,
         log_every_n_buffers: u64,
     ) {
         let (_, analysis_channels) = buffer_channels.split_for_threads();
@@ -222,12 +286,14 @@ impl AudioEngine {
             calibration_progress_tx,
             frame_counter_clone,
             bpm_clone,
+            self.click_samples.beats_per_bar,
             self.sample_rate,
             result_sender,
-            onset_config,
+            analysis_config,
             log_every_n_buffers,
             None,
             None,
+            onset_events_tx,
         );
     }
 
@@ -243,12 +309,14 @@ impl AudioEngine {
     /// * `calibration_procedure` - Optional calibration procedure for collecting training samples
     /// * `calibration_progress_tx` - Optional broadcast channel for calibration progress updates
     /// * `result_sender` - Tokio broadcast channel for sending classification results to UI
+    /// * `onset_events_tx` - Optional broadcast channel for raw onset events
     ///
     /// # Returns
     /// Result indicating success or error
     ///
     /// # Errors
     /// Returns error if streams cannot be opened or started
+    #[allow(clippy::too_many_arguments)]
     pub fn start(
         &mut self,
         calibration_state: std::sync::Arc<
@@ -261,7 +329,8 @@ impl AudioEngine {
             tokio::sync::broadcast::Sender<crate::calibration::CalibrationProgress>,
         >,
         result_sender: tokio::sync::broadcast::Sender<crate::analysis::ClassificationResult>,
-        onset_config: OnsetDetectionConfig,
+        onset_events_tx: Option<tokio::sync::broadcast::Sender<crate::api::OnsetEvent>>,
+        analysis_config: AnalysisConfig,
         log_every_n_buffers: u64,
     ) -> Result<(), AudioError> {
         // Split buffer channels BEFORE creating streams
@@ -328,13 +397,38 @@ impl AudioEngine {
             calibration_procedure,
             calibration_progress_tx,
             result_sender,
-            onset_config,
+            onset_events_tx,
+            analysis_config,
             log_every_n_buffers,
         );
 
         Ok(())
     }
 
+    /// Start only the output stream for metronome playback
+    ///
+    /// Opens the output stream without an input stream or analysis thread, so
+    /// no microphone access is requested. The callback finds no input stream
+    /// and simply renders clicks.
+    ///
+    /// # Returns
+    /// Result indicating success or error
+    ///
+    /// # Errors
+    /// Returns error if the output stream cannot be opened or started
+    pub fn start_metronome_only(&mut self) -> Result<(), AudioError> {
+        let mut output_stream = self.create_output_stream()?;
+
+        output_stream
+            .start()
+            .map_err(|e| AudioError::HardwareError {
+                details: format!("Failed to start output stream: {:?}", e),
+            })?;
+
+        self.output_stream = Some(output_stream);
+        Ok(())
+    }
+
     /// Stop audio streams and release resources
     ///
     /// Stops both input and output streams gracefully. After stopping,
@@ -10,9 +10,10 @@ use std::thread::{self, JoinHandle};
 #[cfg(not(target_os = "android"))]
 use super::buffer_pool::{AudioThreadChannels, BufferPoolChannels};
 #[cfg(not(target_os = "android"))]
-use super::metronome::{generate_click_sample, is_on_beat};
+use super::click::ClickSamples;
+use super::metronome::MetronomeLevel;
 #[cfg(not(target_os = "android"))]
-use crate::config::OnsetDetectionConfig;
+use crate::config::{AnalysisConfig, ClickConfig, InputChannel};
 #[cfg(not(target_os = "android"))]
 use crate::error::AudioError;
 
@@ -32,14 +33,18 @@ pub struct AudioEngine {
     bpm: Arc<AtomicU32>,
     /// Sample rate in Hz
     sample_rate: u32,
-    /// Pre-generated metronome click samples
-    click_samples: Arc<Vec<f32>>,
+    /// Pre-generated metronome click samples (regular + accent)
+    click_samples: Arc<ClickSamples>,
     /// Buffer pool channels for lock-free communication
     buffer_channels: BufferPoolChannels,
     /// Current position in click sample playback
     click_position: Arc<AtomicU64>,
     /// Whether metronome output is enabled
     metronome_enabled: Arc<AtomicBool>,
+    /// Runtime click volume and mute
+    level: MetronomeLevel,
+    /// Channel (or mix) of a multi-channel input that gets analysed
+    input_channel: InputChannel,
 }
 
 #[cfg(not(target_os = "android"))]
@@ -49,7 +54,7 @@ impl AudioEngine {
         sample_rate: u32,
         buffer_channels: BufferPoolChannels,
     ) -> Result<Self, AudioError> {
-        let click_samples = generate_click_sample(sample_rate);
+        let click_samples = ClickSamples::from_config(sample_rate, &ClickConfig::default());
 
         Ok(AudioEngine {
             input_thread: None,
@@ -62,6 +67,8 @@ impl AudioEngine {
             buffer_channels,
             click_position: Arc::new(AtomicU64::new(0)),
             metronome_enabled: Arc::new(AtomicBool::new(true)),
+            level: MetronomeLevel::shared(),
+            input_channel: InputChannel::default(),
         })
     }
 
@@ -69,6 +76,16 @@ impl AudioEngine {
         self.metronome_enabled.store(enabled, Ordering::Relaxed);
     }
 
+    /// Regenerate click buffers from configuration (call before `start`).
+    pub fn set_click_config(&mut self, config: &ClickConfig) {
+        self.click_samples = Arc::new(ClickSamples::from_config(self.sample_rate, config));
+    }
+
+    /// Choose how multi-channel input is reduced to mono (call before `start`).
+    pub fn set_input_channel(&mut self, input_channel: InputChannel) {
+        self.input_channel = input_channel;
+    }
+
     pub fn set_bpm(&self, new_bpm: u32) {
         self.bpm.store(new_bpm, Ordering::Relaxed);
     }
@@ -93,6 +110,7 @@ impl AudioEngine {
     fn spawn_input_stream_thread(
         shutdown_flag: Arc<AtomicBool>,
         mut channels: AudioThreadChannels,
+        input_channel: InputChannel,
     ) -> JoinHandle<()> {
         thread::spawn(move || {
             let host = cpal::default_host();
@@ -122,18 +140,7 @@ impl AudioEngine {
                     move |data: &[f32], _: &cpal::InputCallbackInfo| {
                         if let Ok(mut buffer) = channels.pool_consumer.pop() {
                             buffer.clear();
-                            if channels_count == 1 {
-                                buffer.extend_from_slice(data);
-                            } else {
-                                // De-interleave: take first channel
-                                for frame in data.chunks(channels_count) {
-                                    if !frame.is_empty() {
-                                        buffer.push(frame[0]);
-                                    } else {
-                                        buffer.push(0.0);
-                                    }
-                                }
-                            }
+                            extract_mono(data, channels_count, input_channel, &mut buffer);
                             let _ = channels.data_producer.push(buffer);
                         }
                     },
@@ -165,12 +172,7 @@ impl AudioEngine {
     // Helper to run output stream in a thread
     fn spawn_output_stream_thread(
         shutdown_flag: Arc<AtomicBool>,
-        frame_counter: Arc<AtomicU64>,
-        bpm: Arc<AtomicU32>,
-        sample_rate: u32,
-        click_samples: Arc<Vec<f32>>,
-        click_position: Arc<AtomicU64>,
-        metronome_enabled: Arc<AtomicBool>,
+        output: ClickOutput,
     ) -> JoinHandle<()> {
         thread::spawn(move || {
             let host = cpal::default_host();
@@ -198,33 +200,7 @@ impl AudioEngine {
                 cpal::SampleFormat::F32 => device.build_output_stream(
                     &stream_config,
                     move |data: &mut [f32], _: &cpal::OutputCallbackInfo| {
-                        let current_bpm = bpm.load(Ordering::Relaxed);
-                        let clicks_enabled = metronome_enabled.load(Ordering::Relaxed);
-                        let mut click_pos = click_position.load(Ordering::Relaxed) as usize;
-
-                        let frame_count = data.len() / channels_count;
-                        let current_frame_start = frame_counter.load(Ordering::Relaxed);
-
-                        for i in 0..frame_count {
-                            let frame_idx = current_frame_start + i as u64;
-                            let mut sample_val = 0.0;
-
-                            if clicks_enabled && is_on_beat(frame_idx, current_bpm, sample_rate) {
-                                click_pos = 0;
-                            }
-
-                            if clicks_enabled && click_pos < click_samples.len() {
-                                sample_val = click_samples[click_pos];
-                                click_pos += 1;
-                            }
-
-                            for ch in 0..channels_count {
-                                data[i * channels_count + ch] = sample_val;
-                            }
-                        }
-
-                        click_position.store(click_pos as u64, Ordering::Relaxed);
-                        frame_counter.fetch_add(frame_count as u64, Ordering::Relaxed);
+                        output.render(data, channels_count);
                     },
                     err_fn,
                     None,
@@ -250,6 +226,19 @@ impl AudioEngine {
         })
     }
 
+    /// State shared with the output callback
+    fn click_output(&self) -> ClickOutput {
+        ClickOutput {
+            frame_counter: self.frame_counter.clone(),
+            bpm: self.bpm.clone(),
+            sample_rate: self.sample_rate,
+            click_samples: self.click_samples.clone(),
+            click_position: self.click_position.clone(),
+            metronome_enabled: self.metronome_enabled.clone(),
+            level: self.level.clone(),
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn spawn_analysis_thread_internal(
         &self,
@@ -264,7 +253,8 @@ impl AudioEngine {
             tokio::sync::broadcast::Sender<crate::calibration::CalibrationProgress>,
         >,
         result_sender: tokio::sync::broadcast::Sender<crate::analysis::ClassificationResult>,
-        onset_config: OnsetDetectionConfig,
+        onset_events_tx: Option<tokio::sync::broadcast::Sender<crate::api::OnsetEvent>>,
+        analysis_config: AnalysisConfig,
         log_every_n_buffers: u64,
     ) {
         let (_, analysis_channels) = buffer_channels.split_for_threads();
@@ -279,15 +269,18 @@ impl AudioEngine {
             calibration_progress_tx,
             frame_counter_clone,
             bpm_clone,
+            self.click_samples.beats_per_bar,
             self.sample_rate,
             result_sender,
-            onset_config,
+            analysis_config,
             log_every_n_buffers,
             None,
             None,
+            onset_events_tx,
         );
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn start(
         &mut self,
         calibration_state: std::sync::Arc<
@@ -300,7 +293,8 @@ impl AudioEngine {
             tokio::sync::broadcast::Sender<crate::calibration::CalibrationProgress>,
         >,
         result_sender: tokio::sync::broadcast::Sender<crate::analysis::ClassificationResult>,
-        onset_config: OnsetDetectionConfig,
+        onset_events_tx: Option<tokio::sync::broadcast::Sender<crate::api::OnsetEvent>>,
+        analysis_config: AnalysisConfig,
         log_every_n_buffers: u64,
     ) -> Result<(), AudioError> {
         // Reset shutdown flag
@@ -324,19 +318,15 @@ impl AudioEngine {
         // If stream creation fails, it will log error but this function returns Ok.
         // Ideally we should wait for stream status, but for now this fixes Send/Sync.
 
-        let input_thread =
-            Self::spawn_input_stream_thread(self.shutdown_flag.clone(), audio_channels);
-
-        let output_thread = Self::spawn_output_stream_thread(
+        let input_thread = Self::spawn_input_stream_thread(
             self.shutdown_flag.clone(),
-            self.frame_counter.clone(),
-            self.bpm.clone(),
-            self.sample_rate,
-            self.click_samples.clone(),
-            self.click_position.clone(),
-            self.metronome_enabled.clone(),
+            audio_channels,
+            self.input_channel,
         );
 
+        let output_thread =
+            Self::spawn_output_stream_thread(self.shutdown_flag.clone(), self.click_output());
+
         self.input_thread = Some(input_thread);
         self.output_thread = Some(output_thread);
 
@@ -352,13 +342,26 @@ impl AudioEngine {
             calibration_procedure,
             calibration_progress_tx,
             result_sender,
-            onset_config,
+            onset_events_tx,
+            analysis_config,
             log_every_n_buffers,
         );
 
         Ok(())
     }
 
+    /// Start only the output stream so the metronome plays without opening
+    /// the microphone or spawning the analysis thread.
+    pub fn start_metronome_only(&mut self) -> Result<(), AudioError> {
+        self.shutdown_flag.store(false, Ordering::SeqCst);
+
+        let output_thread =
+            Self::spawn_output_stream_thread(self.shutdown_flag.clone(), self.click_output());
+
+        self.output_thread = Some(output_thread);
+        Ok(())
+    }
+
     pub fn stop(&mut self) -> Result<(), AudioError> {
         // Signal shutdown
         self.shutdown_flag.store(true, Ordering::SeqCst);
@@ -372,3 +375,183 @@ impl AudioEngine {
         Ok(())
     }
 }
+
+/// Everything the output callback needs to render clicks
+#[cfg(not(target_os = "android"))]
+struct ClickOutput {
+    frame_counter: Arc<AtomicU64>,
+    bpm: Arc<AtomicU32>,
+    sample_rate: u32,
+    click_samples: Arc<ClickSamples>,
+    click_position: Arc<AtomicU64>,
+    metronome_enabled: Arc<AtomicBool>,
+    level: MetronomeLevel,
+}
+
+#[cfg(not(target_os = "android"))]
+impl ClickOutput {
+    /// Fill one interleaved output buffer and advance the frame counter
+    fn render(&self, data: &mut [f32], channels_count: usize) {
+        let current_bpm = self.bpm.load(Ordering::Relaxed);
+        let clicks_enabled = self.metronome_enabled.load(Ordering::Relaxed);
+        // Muting scales the output only; clicks keep advancing underneath
+        let gain = self.level.gain();
+        let mut click_pos = self.click_position.load(Ordering::Relaxed) as usize;
+
+        let frame_count = data.len() / channels_count;
+        let current_frame_start = self.frame_counter.load(Ordering::Relaxed);
+        // Resume whichever click was playing at the end of the last callback
+        let (mut click, mut gains) = self.click_samples.for_frame_stereo(
+            current_frame_start.saturating_sub(click_pos as u64),
+            current_bpm,
+            self.sample_rate,
+        );
+
+        for i in 0..frame_count {
+            let frame_idx = current_frame_start + i as u64;
+            let mut sample_val = 0.0;
+
+            if clicks_enabled
+                && self
+                    .click_samples
+                    .is_click_frame(frame_idx, current_bpm, self.sample_rate)
+            {
+                click_pos = 0;
+                (click, gains) =
+                    self.click_samples
+                        .for_frame_stereo(frame_idx, current_bpm, self.sample_rate);
+            }
+
+            if clicks_enabled && click_pos < click.len() {
+                sample_val = click[click_pos] * gain;
+                click_pos += 1;
+            }
+
+            let frame = &mut data[i * channels_count..(i + 1) * channels_count];
+            if let [left, right, rest @ ..] = frame {
+                // Pan across the first two channels; extra channels stay unpanned
+                *left = sample_val * gains.0;
+                *right = sample_val * gains.1;
+                rest.fill(sample_val);
+            } else {
+                frame.fill(sample_val);
+            }
+        }
+
+        self.click_position
+            .store(click_pos as u64, Ordering::Relaxed);
+        self.frame_counter
+            .fetch_add(frame_count as u64, Ordering::Relaxed);
+    }
+}
+
+/// Reduce an interleaved input buffer to mono, appending to `out`
+///
+/// `Right` falls back to the first channel on a mono device; `Mix`
+/// averages every channel. Doesn't allocate as long as `out` has capacity.
+#[cfg(not(target_os = "android"))]
+fn extract_mono(
+    interleaved: &[f32],
+    channels_count: usize,
+    input_channel: InputChannel,
+    out: &mut Vec<f32>,
+) {
+    if channels_count <= 1 {
+        out.extend_from_slice(interleaved);
+        return;
+    }
+
+    let frames = interleaved.chunks_exact(channels_count);
+    match input_channel {
+        InputChannel::Left => out.extend(frames.map(|frame| frame[0])),
+        InputChannel::Right => out.extend(frames.map(|frame| frame[1])),
+        InputChannel::Mix => {
+            out.extend(frames.map(|frame| frame.iter().sum::<f32>() / channels_count as f32))
+        }
+    }
+}
+
+#[cfg(all(test, not(target_os = "android")))]
+mod tests {
+    use super::*;
+
+    fn click_output(level: MetronomeLevel) -> ClickOutput {
+        ClickOutput {
+            frame_counter: Arc::new(AtomicU64::new(0)),
+            bpm: Arc::new(AtomicU32::new(120)),
+            sample_rate: 48_000,
+            click_samples: Arc::new(ClickSamples::from_config(48_000, &ClickConfig::default())),
+            click_position: Arc::new(AtomicU64::new(0)),
+            metronome_enabled: Arc::new(AtomicBool::new(true)),
+            level,
+        }
+    }
+
+    /// Render `buffers` stereo buffers of 256 frames
+    fn render(output: &ClickOutput, buffers: usize) -> Vec<f32> {
+        let mut rendered = Vec::new();
+        for _ in 0..buffers {
+            let mut data = vec![0.0f32; 512];
+            output.render(&mut data, 2);
+            rendered.extend(data);
+        }
+        rendered
+    }
+
+    #[test]
+    fn volume_scales_click_output() {
+        let full = render(&click_output(MetronomeLevel::default()), 4);
+
+        let level = MetronomeLevel::default();
+        assert_eq!(level.set_volume(0.25), 0.25);
+        let quiet = render(&click_output(level), 4);
+
+        assert!(full.iter().any(|&s| s != 0.0));
+        for (f, q) in full.iter().zip(&quiet) {
+            assert!((f * 0.25 - q).abs() < 1e-6, "{f} vs {q}");
+        }
+    }
+
+    #[test]
+    fn muting_silences_output_but_keeps_the_grid() {
+        let unmuted = click_output(MetronomeLevel::default());
+        render(&unmuted, 3);
+
+        let level = MetronomeLevel::default();
+        level.set_muted(true);
+        let muted = click_output(level.clone());
+        assert!(render(&muted, 3).iter().all(|&s| s == 0.0));
+
+        let frames = |output: &ClickOutput| output.frame_counter.load(Ordering::Relaxed);
+        let position = |output: &ClickOutput| output.click_position.load(Ordering::Relaxed);
+        assert_eq!(frames(&muted), 768);
+        assert_eq!(frames(&muted), frames(&unmuted));
+        assert_eq!(position(&muted), position(&unmuted));
+
+        // Unmuting mid-click resumes where the click would have been
+        level.set_muted(false);
+        assert_eq!(render(&muted, 1), render(&unmuted, 1));
+    }
+
+    #[test]
+    fn extract_mono_selects_the_configured_channel() {
+        let interleaved = [0.2, -0.4, 0.6, 1.0, -0.8, 0.0];
+        let extract = |input_channel| {
+            let mut out = Vec::new();
+            extract_mono(&interleaved, 2, input_channel, &mut out);
+            out
+        };
+
+        assert_eq!(extract(InputChannel::Left), vec![0.2, 0.6, -0.8]);
+        assert_eq!(extract(InputChannel::Right), vec![-0.4, 1.0, 0.0]);
+        let mix = extract(InputChannel::Mix);
+        for (actual, expected) in mix.iter().zip([-0.1, 0.8, -0.4]) {
+            assert!((actual - expected).abs() < 1e-6, "{actual} vs {expected}");
+        }
+
+        // A mono device passes straight through whatever is selected
+        let mut out = Vec::new();
+        extract_mono(&interleaved[..3], 1, InputChannel::Right, &mut out);
+        assert_eq!(out, vec![0.2, -0.4, 0.6]);
+    }
+}
@@ -1,50 +1,103 @@
-//! Metronome - Sample-accurate click generation
+//! Metronome - Sample-accurate beat timing
 //!
-//! This module provides deterministic metronome click generation for rhythm training.
+//! This module provides deterministic metronome timing for rhythm training.
 //! Key features:
 //! - Sample-accurate timing (0 jitter) using frame counter arithmetic
-//! - 20ms white noise burst click samples
+//! - Accent detection on the first beat of each bar
+//! - Subdivision timing for softer clicks between beats (8ths, 16ths, ...)
+//! - Runtime click volume and mute shared with the output callback
 //! - Pure functions (no side effects, deterministic output)
 //! - Zero allocations in timing check functions
+//!
+//! The click sounds themselves and their per-beat selection live in
+//! [`crate::audio::click`].
 
-use rand::rngs::StdRng;
-use rand::{Rng, SeedableRng};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::Arc;
 
-/// Duration of metronome click in milliseconds
-const CLICK_DURATION_MS: f32 = 20.0;
+use once_cell::sync::Lazy;
 
-/// Generates a metronome click sample (20ms white noise burst).
-///
-/// This function creates a deterministic white noise burst for use as a metronome click.
-/// The noise is generated using a fixed seed to ensure identical output across calls.
-///
-/// # Arguments
-/// * `sample_rate` - Sample rate in Hz (typically 48000)
-///
-/// # Returns
-/// A `Vec<f32>` containing exactly 20ms worth of white noise samples in range [-1.0, 1.0]
+static SHARED_LEVEL: Lazy<MetronomeLevel> = Lazy::new(MetronomeLevel::default);
+
+/// Click volume and mute, adjustable while the engine runs.
 ///
-/// # Examples
-/// ```
-/// use beatbox_trainer::audio::metronome::generate_click_sample;
-/// let sample_rate = 48000;
-/// let click = generate_click_sample(sample_rate);
-/// assert_eq!(click.len(), (sample_rate as f32 * 0.02) as usize);
-/// ```
-pub fn generate_click_sample(sample_rate: u32) -> Vec<f32> {
-    // Calculate exact number of samples for 20ms
-    let num_samples = (sample_rate as f32 * CLICK_DURATION_MS / 1000.0) as usize;
+/// Both values are plain atomics so the output callback can read them every
+/// buffer without locking. Muting only silences the output: the callback
+/// keeps advancing the frame counter and click position, so the beat grid
+/// used for timing feedback is unaffected.
+#[derive(Debug, Clone)]
+pub struct MetronomeLevel {
+    /// Volume in [0.0, 1.0], stored as `f32` bits
+    volume_bits: Arc<AtomicU32>,
+    muted: Arc<AtomicBool>,
+}
+
+impl Default for MetronomeLevel {
+    fn default() -> Self {
+        Self {
+            volume_bits: Arc::new(AtomicU32::new(1.0f32.to_bits())),
+            muted: Arc::new(AtomicBool::new(false)),
+        }
+    }
+}
+
+impl MetronomeLevel {
+    /// Level set through the API and read by every engine's output callback
+    pub fn shared() -> Self {
+        SHARED_LEVEL.clone()
+    }
 
-    // Use fixed seed for deterministic noise generation
-    let mut rng = StdRng::seed_from_u64(42);
+    /// Set the click volume, clamped to [0.0, 1.0] (NaN counts as 0.0)
+    ///
+    /// Returns the volume applied.
+    pub fn set_volume(&self, volume: f32) -> f32 {
+        let volume = if volume.is_nan() {
+            0.0
+        } else {
+            volume.clamp(0.0, 1.0)
+        };
+        self.volume_bits.store(volume.to_bits(), Ordering::Relaxed);
+        volume
+    }
+
+    /// Current click volume
+    #[inline]
+    pub fn volume(&self) -> f32 {
+        f32::from_bits(self.volume_bits.load(Ordering::Relaxed))
+    }
+
+    /// Silence (or restore) click output without stopping the beat grid
+    pub fn set_muted(&self, muted: bool) {
+        self.muted.store(muted, Ordering::Relaxed);
+    }
 
-    // Generate white noise in range [-1.0, 1.0]
-    let mut samples = Vec::with_capacity(num_samples);
-    for _ in 0..num_samples {
-        samples.push(rng.gen_range(-1.0..1.0));
+    /// Whether click output is muted
+    #[inline]
+    pub fn is_muted(&self) -> bool {
+        self.muted.load(Ordering::Relaxed)
     }
 
-    samples
+    /// Gain to multiply click samples by: 0.0 while muted, else the volume
+    #[inline]
+    pub fn gain(&self) -> f32 {
+        if self.is_muted() {
+            0.0
+        } else {
+            self.volume()
+        }
+    }
+}
+
+/// Checks whether the beat starting at `frame_counter` is the first beat of a bar.
+///
+/// Always `false` when `beats_per_bar` is 0.
+#[inline]
+pub fn is_accent_beat(frame_counter: u64, bpm: u32, sample_rate: u32, beats_per_bar: u32) -> bool {
+    if beats_per_bar == 0 {
+        return false;
+    }
+    let beat_index = frame_counter / samples_per_beat(bpm, sample_rate);
+    beat_index.is_multiple_of(beats_per_bar as u64)
 }
 
 /// Converts BPM (beats per minute) to samples per beat.
@@ -98,175 +151,41 @@ pub fn is_on_beat(frame_counter: u64, bpm: u32, sample_rate: u32) -> bool {
     frame_counter.is_multiple_of(spb)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_generate_click_sample_duration() {
-        // Test at common sample rates
-        let sample_rates = [44100, 48000, 96000];
-
-        for &sr in &sample_rates {
-            let click = generate_click_sample(sr);
-            let expected_samples = (sr as f32 * CLICK_DURATION_MS / 1000.0) as usize;
-            assert_eq!(
-                click.len(),
-                expected_samples,
-                "Click duration should be exactly 20ms at {} Hz",
-                sr
-            );
-        }
-    }
-
-    #[test]
-    fn test_generate_click_sample_range() {
-        let click = generate_click_sample(48000);
-
-        // Verify all samples are in valid range [-1.0, 1.0]
-        for (i, &sample) in click.iter().enumerate() {
-            assert!(
-                (-1.0..=1.0).contains(&sample),
-                "Sample {} at index {} is out of range [-1.0, 1.0]",
-                sample,
-                i
-            );
-        }
-    }
-
-    #[test]
-    fn test_generate_click_sample_deterministic() {
-        // Same input should produce identical output (fixed seed)
-        let click1 = generate_click_sample(48000);
-        let click2 = generate_click_sample(48000);
-
-        assert_eq!(
-            click1.len(),
-            click2.len(),
-            "Deterministic generation should produce same length"
-        );
-
-        for (i, (&s1, &s2)) in click1.iter().zip(click2.iter()).enumerate() {
-            assert_eq!(
-                s1, s2,
-                "Sample {} differs: {} vs {}. Generation should be deterministic.",
-                i, s1, s2
-            );
-        }
-    }
-
-    #[test]
-    fn test_samples_per_beat_formula() {
-        // Verify formula: samples_per_beat = (sample_rate × 60) / BPM
-
-        // At 120 BPM, 48kHz: (48000 * 60) / 120 = 24000
-        assert_eq!(samples_per_beat(120, 48000), 24000);
-
-        // At 60 BPM, 48kHz: (48000 * 60) / 60 = 48000
-        assert_eq!(samples_per_beat(60, 48000), 48000);
-
-        // At 240 BPM, 48kHz: (48000 * 60) / 240 = 12000
-        assert_eq!(samples_per_beat(240, 48000), 12000);
-
-        // At 100 BPM, 44.1kHz: (44100 * 60) / 100 = 26460
-        assert_eq!(samples_per_beat(100, 44100), 26460);
-    }
-
-    #[test]
-    fn test_is_on_beat_exact_boundaries() {
-        let bpm = 120;
-        let sample_rate = 48000;
-        let spb = samples_per_beat(bpm, sample_rate); // 24000
-
-        // Test exact beat boundaries
-        assert!(is_on_beat(0, bpm, sample_rate), "Frame 0 should be on beat");
-        assert!(
-            is_on_beat(spb, bpm, sample_rate),
-            "Frame {} should be on beat",
-            spb
-        );
-        assert!(
-            is_on_beat(spb * 2, bpm, sample_rate),
-            "Frame {} should be on beat",
-            spb * 2
-        );
-        assert!(
-            is_on_beat(spb * 10, bpm, sample_rate),
-            "Frame {} should be on beat",
-            spb * 10
-        );
-    }
-
-    #[test]
-    fn test_is_on_beat_off_boundaries() {
-        let bpm = 120;
-        let sample_rate = 48000;
-        let spb = samples_per_beat(bpm, sample_rate); // 24000
-
-        // Test frames that are NOT on beat boundaries
-        assert!(
-            !is_on_beat(1, bpm, sample_rate),
-            "Frame 1 should NOT be on beat"
-        );
-        assert!(
-            !is_on_beat(spb - 1, bpm, sample_rate),
-            "Frame {} should NOT be on beat",
-            spb - 1
-        );
-        assert!(
-            !is_on_beat(spb + 1, bpm, sample_rate),
-            "Frame {} should NOT be on beat",
-            spb + 1
-        );
-        assert!(
-            !is_on_beat(spb / 2, bpm, sample_rate),
-            "Frame {} should NOT be on beat",
-            spb / 2
-        );
-    }
-
-    #[test]
-    fn test_is_on_beat_different_bpms() {
-        let sample_rate = 48000;
-
-        // Test various BPM values
-        let test_cases = vec![
-            (60, vec![0, 48000, 96000]),  // 60 BPM: beat every 48000 samples
-            (80, vec![0, 36000, 72000]),  // 80 BPM: beat every 36000 samples
-            (140, vec![0, 20571, 41142]), // 140 BPM: beat every ~20571 samples
-        ];
-
-        for (bpm, beat_frames) in test_cases {
-            for &frame in &beat_frames {
-                assert!(
-                    is_on_beat(frame, bpm, sample_rate),
-                    "Frame {} should be on beat at {} BPM",
-                    frame,
-                    bpm
-                );
-            }
-        }
+/// Checks if the current frame starts a subdivision click between beats.
+///
+/// Subdivision `k` of a beat starts `k × samples_per_beat / subdivisions`
+/// frames (rounded down) after the beat, so the grid stays anchored to the
+/// beat even when the division is not exact. Beat frames themselves return
+/// `false`, as does any `subdivisions` below 2.
+///
+/// # Examples
+/// ```
+/// use beatbox_trainer::audio::metronome::is_on_subdivision;
+/// // At 120 BPM, 48kHz, eighth notes fall halfway between beats
+/// assert!(is_on_subdivision(12000, 120, 48000, 2));
+/// assert!(!is_on_subdivision(24000, 120, 48000, 2));
+/// ```
+#[inline]
+pub fn is_on_subdivision(
+    frame_counter: u64,
+    bpm: u32,
+    sample_rate: u32,
+    subdivisions: u32,
+) -> bool {
+    if subdivisions < 2 {
+        return false;
     }
-
-    #[test]
-    fn test_is_on_beat_zero_sample_error() {
-        // Verify that is_on_beat has exactly 0 sample error (sample-accurate)
-        let bpm = 120;
-        let sample_rate = 48000;
-        let spb = samples_per_beat(bpm, sample_rate);
-
-        // Check that only exact boundaries return true
-        for offset in 1..100 {
-            assert!(
-                !is_on_beat(spb + offset, bpm, sample_rate),
-                "Frame {} is not exactly on beat",
-                spb + offset
-            );
-            assert!(
-                !is_on_beat(spb - offset, bpm, sample_rate),
-                "Frame {} is not exactly on beat",
-                spb - offset
-            );
-        }
+    let spb = samples_per_beat(bpm, sample_rate);
+    let n = subdivisions as u64;
+    let position = frame_counter % spb;
+    if position == 0 {
+        return false;
     }
+    // Smallest k whose subdivision starts at or after `position`
+    let k = (position * n).div_ceil(spb);
+    k < n && k * spb / n == position
 }
+
+#[cfg(test)]
+#[path = "metronome_tests.rs"]
+mod tests;
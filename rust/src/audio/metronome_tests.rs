@@ -0,0 +1,116 @@
+use super::*;
+
+#[test]
+fn test_samples_per_beat_formula() {
+    // Verify formula: samples_per_beat = (sample_rate × 60) / BPM
+
+    // At 120 BPM, 48kHz: (48000 * 60) / 120 = 24000
+    assert_eq!(samples_per_beat(120, 48000), 24000);
+
+    // At 60 BPM, 48kHz: (48000 * 60) / 60 = 48000
+    assert_eq!(samples_per_beat(60, 48000), 48000);
+
+    // At 240 BPM, 48kHz: (48000 * 60) / 240 = 12000
+    assert_eq!(samples_per_beat(240, 48000), 12000);
+
+    // At 100 BPM, 44.1kHz: (44100 * 60) / 100 = 26460
+    assert_eq!(samples_per_beat(100, 44100), 26460);
+}
+
+#[test]
+fn test_is_on_beat_exact_boundaries() {
+    let bpm = 120;
+    let sample_rate = 48000;
+    let spb = samples_per_beat(bpm, sample_rate); // 24000
+
+    // Test exact beat boundaries
+    assert!(is_on_beat(0, bpm, sample_rate), "Frame 0 should be on beat");
+    assert!(
+        is_on_beat(spb, bpm, sample_rate),
+        "Frame {} should be on beat",
+        spb
+    );
+    assert!(
+        is_on_beat(spb * 2, bpm, sample_rate),
+        "Frame {} should be on beat",
+        spb * 2
+    );
+    assert!(
+        is_on_beat(spb * 10, bpm, sample_rate),
+        "Frame {} should be on beat",
+        spb * 10
+    );
+}
+
+#[test]
+fn test_is_on_beat_off_boundaries() {
+    let bpm = 120;
+    let sample_rate = 48000;
+    let spb = samples_per_beat(bpm, sample_rate); // 24000
+
+    // Test frames that are NOT on beat boundaries
+    assert!(
+        !is_on_beat(1, bpm, sample_rate),
+        "Frame 1 should NOT be on beat"
+    );
+    assert!(
+        !is_on_beat(spb - 1, bpm, sample_rate),
+        "Frame {} should NOT be on beat",
+        spb - 1
+    );
+    assert!(
+        !is_on_beat(spb + 1, bpm, sample_rate),
+        "Frame {} should NOT be on beat",
+        spb + 1
+    );
+    assert!(
+        !is_on_beat(spb / 2, bpm, sample_rate),
+        "Frame {} should NOT be on beat",
+        spb / 2
+    );
+}
+
+#[test]
+fn test_is_on_beat_different_bpms() {
+    let sample_rate = 48000;
+
+    // Test various BPM values
+    let test_cases = vec![
+        (60, vec![0, 48000, 96000]),  // 60 BPM: beat every 48000 samples
+        (80, vec![0, 36000, 72000]),  // 80 BPM: beat every 36000 samples
+        (140, vec![0, 20571, 41142]), // 140 BPM: beat every ~20571 samples
+    ];
+
+    for (bpm, beat_frames) in test_cases {
+        for &frame in &beat_frames {
+            assert!(
+                is_on_beat(frame, bpm, sample_rate),
+                "Frame {} should be on beat at {} BPM",
+                frame,
+                bpm
+            );
+        }
+    }
+}
+
+#[test]
+fn test_is_on_beat_zero_sample_error() {
+    // Verify that is_on_beat has exactly 0 sample error (sample-accurate)
+    let bpm = 120;
+    let sample_rate = 48000;
+    let spb = samples_per_beat(bpm, sample_rate);
+
+    // Check that only exact boundaries return true
+    for offset in 1..100 {
+        assert!(
+            !is_on_beat(spb + offset, bpm, sample_rate),
+            "Frame {} is not exactly on beat",
+            spb + offset
+        );
+        assert!(
+            !is_on_beat(spb - offset, bpm, sample_rate),
+            "Frame {} is not exactly on beat",
+            spb - offset
+        );
+    }
+}
@@ -3,13 +3,18 @@
 pub mod buffer_pool;
 #[cfg(target_os = "android")]
 pub mod callback;
+pub mod click;
 pub mod engine;
 #[cfg(not(target_os = "android"))]
 pub mod engine_cpal;
 pub mod metronome;
+pub mod resample;
 #[cfg(not(target_os = "android"))]
 pub mod stubs;
 
+/// Sample rate (Hz) the engine opens streams at and the analysis pipeline assumes.
+pub const ENGINE_SAMPLE_RATE: u32 = 48_000;
+
 // Re-export commonly used types for convenience
 pub use buffer_pool::{
     AudioBuffer, BufferPool, BufferPoolChannels, DEFAULT_BUFFER_COUNT, DEFAULT_BUFFER_SIZE,
@@ -0,0 +1,84 @@
+//! Sample-rate conversion for decoded audio
+//!
+//! Features such as centroid, rolloff and ZCR are computed per bin or per
+//! sample, so audio analysed at a rate other than `ENGINE_SAMPLE_RATE`
+//! yields values calibrated thresholds don't expect. Offline paths (fixture
+//! runs, calibration from WAV clips) convert decoded clips to the engine rate
+//! before analysis.
+//!
+//! Conversion is linear interpolation: cheap and good enough for the
+//! 44.1 kHz <-> 48 kHz conversions recordings need. There is no anti-alias
+//! filter, so content above the target Nyquist folds back when downsampling
+//! by large factors.
+
+use std::borrow::Cow;
+
+/// Resample `samples` from `from_rate` to `to_rate` with linear interpolation
+///
+/// Returns the input unchanged (borrowed) when the rates match or either
+/// rate is zero. The output has `len * to_rate / from_rate` samples (rounded),
+/// so durations are preserved.
+pub fn resample(samples: &[f32], from_rate: u32, to_rate: u32) -> Cow<'_, [f32]> {
+    if from_rate == to_rate || from_rate == 0 || to_rate == 0 || samples.is_empty() {
+        return Cow::Borrowed(samples);
+    }
+
+    let step = from_rate as f64 / to_rate as f64;
+    let out_len = (samples.len() as f64 / step).round() as usize;
+    let last = samples.len() - 1;
+
+    Cow::Owned(
+        (0..out_len)
+            .map(|i| {
+                let position = i as f64 * step;
+                let idx = (position.floor() as usize).min(last);
+                let next = (idx + 1).min(last);
+                let frac = (position - idx as f64) as f32;
+                samples[idx] + (samples[next] - samples[idx]) * frac
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::features::FeatureExtractor;
+    use std::f32::consts::PI;
+
+    fn sine(frequency: f32, sample_rate: u32, len: usize) -> Vec<f32> {
+        (0..len)
+            .map(|i| 0.5 * (2.0 * PI * frequency * i as f32 / sample_rate as f32).sin())
+            .collect()
+    }
+
+    #[test]
+    fn matching_rates_borrow_the_input() {
+        let samples = vec![0.1, 0.2, 0.3];
+        assert!(matches!(
+            resample(&samples, 48_000, 48_000),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn sine_resampled_to_48k_keeps_its_frequency() {
+        let source = sine(2_000.0, 44_100, 44_100);
+        let resampled = resample(&source, 44_100, 48_000);
+        assert_eq!(resampled.len(), 48_000);
+
+        // Reference: the same tone generated natively at 48 kHz
+        let native = sine(2_000.0, 48_000, 48_000);
+        let extractor = FeatureExtractor::new(48_000).unwrap();
+        let expected = extractor.extract(&native[..2048]).centroid;
+        let actual = extractor.extract(&resampled[..2048]).centroid;
+
+        assert!(
+            (actual - expected).abs() < expected * 0.02,
+            "centroid {} vs native {}",
+            actual,
+            expected
+        );
+        assert!((actual - 2_000.0).abs() < 100.0, "centroid {}", actual);
+    }
+}
@@ -11,6 +11,7 @@ pub struct TelemetryAggregator {
     total_events: usize,
     lagged_events: usize,
     last_latency: Option<LatencySummary>,
+    last_processing_latency_ms: Option<f32>,
     buffers: BTreeMap<String, f32>,
     classification_count: usize,
     last_classification: Option<ClassificationMetric>,
@@ -58,6 +59,10 @@ impl TelemetryAggregator {
             MetricEvent::Error { code, context } => {
                 self.errors.push(format!("{code:?}: {context}"))
             }
+            MetricEvent::ProcessingLatency { latency_ms } => {
+                self.last_processing_latency_ms = Some(latency_ms);
+            }
+            MetricEvent::Silence { .. } => {}
         }
     }
 
@@ -72,6 +77,7 @@ impl TelemetryAggregator {
             collector_dropped,
             lagged_events: self.lagged_events,
             latency: self.last_latency,
+            processing_latency_ms: self.last_processing_latency_ms,
             buffer_levels: self.buffers,
             classification_count: self.classification_count,
             last_classification: self.last_classification,
@@ -99,6 +105,8 @@ pub struct TelemetryReport {
     pub lagged_events: usize,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub latency: Option<LatencySummary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub processing_latency_ms: Option<f32>,
     #[serde(skip_serializing_if = "BTreeMap::is_empty")]
     pub buffer_levels: BTreeMap<String, f32>,
     pub classification_count: usize,
@@ -132,6 +140,9 @@ impl TelemetryReport {
         } else {
             println!("Latency avg/max (ms)     : n/a");
         }
+        if let Some(latency_ms) = self.processing_latency_ms {
+            println!("Processing latency (ms)  : {:.2}", latency_ms);
+        }
 
         println!("Classification events    : {}", self.classification_count);
         if let Some(last) = &self.last_classification {
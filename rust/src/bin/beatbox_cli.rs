@@ -4,6 +4,7 @@ use std::process::ExitCode;
 
 use anyhow::{Context, Result};
 use beatbox_trainer::analysis::ClassificationResult;
+use beatbox_trainer::config::AppConfig;
 use beatbox_trainer::engine::EngineHandle;
 use beatbox_trainer::fixtures::{ExpectationDiff, FixtureCatalog, FixtureProcessor};
 use clap::{Parser, Subcommand};
@@ -18,6 +19,10 @@ struct Cli {
     /// Override directory containing fixture assets (defaults to rust/fixtures)
     #[arg(long)]
     fixtures_dir: Option<PathBuf>,
+    /// JSON config file overriding the engine defaults (omitted fields keep
+    /// their defaults)
+    #[arg(long)]
+    config: Option<PathBuf>,
     #[command(subcommand)]
     command: Commands,
 }
@@ -44,6 +49,12 @@ enum Commands {
     },
     /// List available fixtures on disk
     DumpFixtures,
+    /// Time the DSP hot path over a synthetic buffer and print ns/iter as JSON
+    #[cfg(feature = "dsp_bench")]
+    Bench {
+        #[arg(long, default_value_t = beatbox_trainer::testing::dsp_bench::DEFAULT_BENCH_ITERATIONS)]
+        iterations: u32,
+    },
 }
 
 fn main() -> ExitCode {
@@ -62,6 +73,7 @@ fn run() -> Result<ExitCode> {
         .fixtures_dir
         .map(FixtureCatalog::new)
         .unwrap_or_else(FixtureCatalog::default);
+    let config = cli.config.map(AppConfig::from_file).transpose()?;
 
     match cli.command {
         Commands::Classify {
@@ -69,21 +81,24 @@ fn run() -> Result<ExitCode> {
             expect,
             output,
             bpm,
-        } => run_classify(&catalog, &fixture, expect, output, bpm),
-        Commands::Stream { fixture, bpm } => run_stream(&catalog, &fixture, bpm),
+        } => run_classify(&catalog, config, &fixture, expect, output, bpm),
+        Commands::Stream { fixture, bpm } => run_stream(&catalog, config, &fixture, bpm),
         Commands::DumpFixtures => run_dump(&catalog),
+        #[cfg(feature = "dsp_bench")]
+        Commands::Bench { iterations } => run_bench(iterations),
     }
 }
 
 fn run_classify(
     catalog: &FixtureCatalog,
+    config: Option<AppConfig>,
     fixture: &str,
     override_expect: Option<PathBuf>,
     output_path: Option<PathBuf>,
     bpm: u32,
 ) -> Result<ExitCode> {
     let engine = EngineHandle::new();
-    let config = engine.config_snapshot();
+    let config = config.unwrap_or_else(|| engine.config_snapshot());
     let calibration = engine.calibration_state_handle();
     let processor = FixtureProcessor::new(config, calibration).with_bpm(bpm);
     let data = catalog.load(fixture, override_expect)?;
@@ -106,9 +121,14 @@ fn run_classify(
     }
 }
 
-fn run_stream(catalog: &FixtureCatalog, fixture: &str, bpm: u32) -> Result<ExitCode> {
+fn run_stream(
+    catalog: &FixtureCatalog,
+    config: Option<AppConfig>,
+    fixture: &str,
+    bpm: u32,
+) -> Result<ExitCode> {
     let engine = EngineHandle::new();
-    let config = engine.config_snapshot();
+    let config = config.unwrap_or_else(|| engine.config_snapshot());
     let calibration = engine.calibration_state_handle();
     let processor = FixtureProcessor::new(config, calibration).with_bpm(bpm);
     let data = catalog.load(fixture, None)?;
@@ -140,6 +160,15 @@ fn run_dump(catalog: &FixtureCatalog) -> Result<ExitCode> {
     Ok(ExitCode::from(0))
 }
 
+#[cfg(feature = "dsp_bench")]
+fn run_bench(iterations: u32) -> Result<ExitCode> {
+    use beatbox_trainer::testing::dsp_bench::DspBench;
+
+    let results = DspBench::new(iterations).run()?;
+    println!("{}", serde_json::to_string_pretty(&results)?);
+    Ok(ExitCode::from(0))
+}
+
 fn emit_report(
     fixture: &str,
     sample_rate: u32,
@@ -38,7 +38,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 948656586;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1335601836;
 
 // Section: executor
 
@@ -117,6 +117,120 @@ fn wire__crate__api__streams__audio_metrics_stream_impl(
         },
     )
 }
+fn wire__crate__api__calibrate_from_wavs_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "calibrate_from_wavs",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_kick = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_snare = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_hihat = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::calibration::CalibrationError>(
+                    (move || {
+                        let output_ok =
+                            crate::api::calibrate_from_wavs(api_kick, api_snare, api_hihat)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__streams__calibration_debug_stream_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "calibration_debug_stream",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::calibration::progress::CalibrationDebugFrame,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::streams::calibration_debug_stream(api_sink);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__streams__calibration_preview_stream_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "calibration_preview_stream",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::analysis::ClassificationResult,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::streams::calibration_preview_stream(api_sink);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__calibration_stream_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -193,6 +307,46 @@ fn wire__crate__api__classification_stream_impl(
         },
     )
 }
+fn wire__crate__api__compute_spectrum_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "compute_spectrum",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_samples = <Vec<f32>>::sse_decode(&mut deserializer);
+            let api_sample_rate = <u32>::sse_decode(&mut deserializer);
+            let api_fft_size = <u32>::sse_decode(&mut deserializer);
+            let api_in_db = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::compute_spectrum(
+                        api_samples,
+                        api_sample_rate,
+                        api_fft_size,
+                        api_in_db,
+                    )?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
 fn wire__crate__api__confirm_calibration_step_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -265,6 +419,39 @@ fn wire__crate__api__streams__diagnostic_metrics_stream_impl(
         },
     )
 }
+fn wire__crate__api__extract_features_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "extract_features",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_samples = <Vec<f32>>::sse_decode(&mut deserializer);
+            let api_sample_rate = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::extract_features(api_samples, api_sample_rate)?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
 fn wire__crate__api__finish_calibration_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -455,6 +642,35 @@ fn wire__crate__api__get_current_audio_level_impl(
         },
     )
 }
+fn wire__crate__api__get_current_onset_threshold_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_current_onset_threshold",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::get_current_onset_threshold())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
 fn wire__crate__api__get_version_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -552,6 +768,39 @@ fn wire__crate__api__init_app_impl(
         },
     )
 }
+fn wire__crate__api__inject_classification_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "inject_classification",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_result = <crate::analysis::ClassificationResult>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::audio::AudioError>((move || {
+                    let output_ok = crate::api::inject_classification(api_result)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__is_pipeline_tracing_enabled_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -645,7 +894,7 @@ fn wire__crate__api__diagnostics__load_fixture_catalog_impl(
         },
     )
 }
-fn wire__crate__api__manual_accept_last_candidate_impl(
+fn wire__crate__api__load_preset_calibration_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -653,7 +902,7 @@ fn wire__crate__api__manual_accept_last_candidate_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "manual_accept_last_candidate",
+            debug_name: "load_preset_calibration",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -667,11 +916,12 @@ fn wire__crate__api__manual_accept_last_candidate_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, crate::error::calibration::CalibrationError>(
                     (move || {
-                        let output_ok = crate::api::manual_accept_last_candidate()?;
+                        let output_ok = crate::api::load_preset_calibration(api_name)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -679,7 +929,7 @@ fn wire__crate__api__manual_accept_last_candidate_impl(
         },
     )
 }
-fn wire__crate__api__streams__onset_events_stream_impl(
+fn wire__crate__api__load_preset_calibration_for_device_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -687,7 +937,7 @@ fn wire__crate__api__streams__onset_events_stream_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "onset_events_stream",
+            debug_name: "load_preset_calibration_for_device",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -701,23 +951,20 @@ fn wire__crate__api__streams__onset_events_stream_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_sink = <StreamSink<
-                crate::api::types::OnsetEvent,
-                flutter_rust_bridge::for_generated::SseCodec,
-            >>::sse_decode(&mut deserializer);
+            let api_model = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::streams::onset_events_stream(api_sink);
-                    })?;
-                    Ok(output_ok)
-                })())
+                transform_result_sse::<_, crate::error::calibration::CalibrationError>(
+                    (move || {
+                        let output_ok = crate::api::load_preset_calibration_for_device(api_model)?;
+                        Ok(output_ok)
+                    })(),
+                )
             }
         },
     )
 }
-fn wire__crate__api__reset_calibration_session_impl(
+fn wire__crate__api__manual_accept_last_candidate_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -725,7 +972,7 @@ fn wire__crate__api__reset_calibration_session_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "reset_calibration_session",
+            debug_name: "manual_accept_last_candidate",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -743,7 +990,7 @@ fn wire__crate__api__reset_calibration_session_impl(
             move |context| {
                 transform_result_sse::<_, crate::error::calibration::CalibrationError>(
                     (move || {
-                        let output_ok = crate::api::reset_calibration_session()?;
+                        let output_ok = crate::api::manual_accept_last_candidate()?;
                         Ok(output_ok)
                     })(),
                 )
@@ -751,7 +998,7 @@ fn wire__crate__api__reset_calibration_session_impl(
         },
     )
 }
-fn wire__crate__api__retry_calibration_step_impl(
+fn wire__crate__api__streams__onset_events_stream_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -759,7 +1006,7 @@ fn wire__crate__api__retry_calibration_step_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "retry_calibration_step",
+            debug_name: "onset_events_stream",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -773,17 +1020,120 @@ fn wire__crate__api__retry_calibration_step_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::api::types::OnsetEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, crate::error::calibration::CalibrationError>(
-                    (move || {
-                        let output_ok = crate::api::retry_calibration_step()?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
-        },
-    )
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::streams::onset_events_stream(api_sink);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__reset_calibration_session_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reset_calibration_session",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::calibration::CalibrationError>(
+                    (move || {
+                        let output_ok = crate::api::reset_calibration_session()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__reset_telemetry_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reset_telemetry",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::reset_telemetry();
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__retry_calibration_step_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "retry_calibration_step",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::calibration::CalibrationError>(
+                    (move || {
+                        let output_ok = crate::api::retry_calibration_step()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
 }
 fn wire__crate__api__set_bpm_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
@@ -818,6 +1168,101 @@ fn wire__crate__api__set_bpm_impl(
         },
     )
 }
+fn wire__crate__api__set_listening_windows_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_listening_windows",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_windows = <Vec<(u64, u64)>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::set_listening_windows(api_windows))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__set_metronome_muted_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_metronome_muted",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_muted = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::set_metronome_muted(api_muted);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__set_metronome_volume_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_metronome_volume",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_volume = <f32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::set_metronome_volume(api_volume);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
 fn wire__crate__api__set_pipeline_tracing_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -848,6 +1293,67 @@ fn wire__crate__api__set_pipeline_tracing_impl(
         },
     )
 }
+fn wire__crate__api__set_json_result_schema_version_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_json_result_schema_version",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_version = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::set_json_result_schema_version(api_version))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__set_sound_labels_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_sound_labels",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_labels = <Vec<(String, String)>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::set_sound_labels(api_labels))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
 fn wire__crate__api__start_audio_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -948,6 +1454,39 @@ fn wire__crate__api__diagnostics__start_fixture_session_impl(
         },
     )
 }
+fn wire__crate__api__start_metronome_only_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_metronome_only",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_bpm = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, crate::error::audio::AudioError>((move || {
+                    let output_ok = crate::api::start_metronome_only(api_bpm)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__stop_audio_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -980,16 +1519,75 @@ fn wire__crate__api__stop_audio_impl(
         },
     )
 }
-fn wire__crate__api__diagnostics__stop_fixture_session_impl(
+fn wire__crate__api__diagnostics__stop_fixture_session_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_fixture_session",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, crate::error::audio::AudioError>((move || {
+                let output_ok = crate::api::diagnostics::stop_fixture_session()?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__supported_sounds_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "supported_sounds",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::supported_sounds())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__streams__telemetry_stream_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "stop_fixture_session",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "telemetry_stream",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -1001,15 +1599,23 @@ fn wire__crate__api__diagnostics__stop_fixture_session_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::engine::core::TelemetryEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, crate::error::audio::AudioError>((move || {
-                let output_ok = crate::api::diagnostics::stop_fixture_session()?;
-                Ok(output_ok)
-            })())
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::streams::telemetry_stream(api_sink);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
         },
     )
 }
-fn wire__crate__api__streams__telemetry_stream_impl(
+fn wire__crate__api__streams__telemetry_stream_filtered_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1017,7 +1623,7 @@ fn wire__crate__api__streams__telemetry_stream_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "telemetry_stream",
+            debug_name: "telemetry_stream_filtered",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1032,14 +1638,16 @@ fn wire__crate__api__streams__telemetry_stream_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_sink = <StreamSink<
-                crate::engine::core::TelemetryEvent,
+                crate::telemetry::events::MetricEvent,
                 flutter_rust_bridge::for_generated::SseCodec,
             >>::sse_decode(&mut deserializer);
+            let api_kinds =
+                <Vec<crate::telemetry::events::MetricEventKind>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
                     let output_ok = Result::<_, ()>::Ok({
-                        crate::api::streams::telemetry_stream(api_sink);
+                        crate::api::streams::telemetry_stream_filtered(api_sink, api_kinds);
                     })?;
                     Ok(output_ok)
                 })())
@@ -1147,6 +1755,19 @@ impl SseDecode
     }
 }
 
+impl SseDecode
+    for StreamSink<
+        crate::calibration::progress::CalibrationDebugFrame,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
 impl SseDecode
     for StreamSink<
         crate::calibration::progress::CalibrationProgress,
@@ -1262,6 +1883,12 @@ impl SseDecode for crate::error::audio::AudioError {
                 let mut var_reason = <String>::sse_decode(deserializer);
                 return crate::error::audio::AudioError::StreamFailure { reason: var_reason };
             }
+            10 => {
+                let mut var_operation = <String>::sse_decode(deserializer);
+                return crate::error::audio::AudioError::Unsupported {
+                    operation: var_operation,
+                };
+            }
             _ => {
                 unimplemented!("");
             }
@@ -1280,12 +1907,14 @@ impl SseDecode for crate::api::types::AudioMetrics {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut var_rms = <f64>::sse_decode(deserializer);
+        let mut var_smoothedRms = <f64>::sse_decode(deserializer);
         let mut var_spectralCentroid = <f64>::sse_decode(deserializer);
         let mut var_spectralFlux = <f64>::sse_decode(deserializer);
         let mut var_frameNumber = <u64>::sse_decode(deserializer);
         let mut var_timestamp = <u64>::sse_decode(deserializer);
         return crate::api::types::AudioMetrics {
             rms: var_rms,
+            smoothed_rms: var_smoothedRms,
             spectral_centroid: var_spectralCentroid,
             spectral_flux: var_spectralFlux,
             frame_number: var_frameNumber,
@@ -1294,18 +1923,18 @@ impl SseDecode for crate::api::types::AudioMetrics {
     }
 }
 
-impl SseDecode for crate::analysis::classifier::BeatboxHit {
+impl SseDecode for crate::types::BeatboxHit {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut inner = <i32>::sse_decode(deserializer);
         return match inner {
-            0 => crate::analysis::classifier::BeatboxHit::Kick,
-            1 => crate::analysis::classifier::BeatboxHit::Snare,
-            2 => crate::analysis::classifier::BeatboxHit::HiHat,
-            3 => crate::analysis::classifier::BeatboxHit::ClosedHiHat,
-            4 => crate::analysis::classifier::BeatboxHit::OpenHiHat,
-            5 => crate::analysis::classifier::BeatboxHit::KSnare,
-            6 => crate::analysis::classifier::BeatboxHit::Unknown,
+            0 => crate::types::BeatboxHit::Kick,
+            1 => crate::types::BeatboxHit::Snare,
+            2 => crate::types::BeatboxHit::HiHat,
+            3 => crate::types::BeatboxHit::ClosedHiHat,
+            4 => crate::types::BeatboxHit::OpenHiHat,
+            5 => crate::types::BeatboxHit::KSnare,
+            6 => crate::types::BeatboxHit::Unknown,
             _ => unreachable!("Invalid variant for BeatboxHit: {}", inner),
         };
     }
@@ -1318,6 +1947,27 @@ impl SseDecode for bool {
     }
 }
 
+impl SseDecode for crate::calibration::progress::CalibrationDebugFrame {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_seq = <u64>::sse_decode(deserializer);
+        let mut var_sound =
+            <crate::calibration::progress::CalibrationSound>::sse_decode(deserializer);
+        let mut var_centroid = <f32>::sse_decode(deserializer);
+        let mut var_zcr = <f32>::sse_decode(deserializer);
+        let mut var_rms = <f64>::sse_decode(deserializer);
+        let mut var_maxAmp = <f32>::sse_decode(deserializer);
+        return crate::calibration::progress::CalibrationDebugFrame {
+            seq: var_seq,
+            sound: var_sound,
+            centroid: var_centroid,
+            zcr: var_zcr,
+            rms: var_rms,
+            max_amp: var_maxAmp,
+        };
+    }
+}
+
 impl SseDecode for crate::error::calibration::CalibrationError {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -1326,9 +1976,12 @@ impl SseDecode for crate::error::calibration::CalibrationError {
             0 => {
                 let mut var_required_ = <usize>::sse_decode(deserializer);
                 let mut var_collected = <usize>::sse_decode(deserializer);
+                let mut var_perSound =
+                    <Vec<crate::error::calibration::SoundSampleCount>>::sse_decode(deserializer);
                 return crate::error::calibration::CalibrationError::InsufficientSamples {
                     required: var_required_,
                     collected: var_collected,
+                    per_sound: var_perSound,
                 };
             }
             1 => {
@@ -1350,6 +2003,27 @@ impl SseDecode for crate::error::calibration::CalibrationError {
                 let mut var_reason = <String>::sse_decode(deserializer);
                 return crate::error::calibration::CalibrationError::Timeout { reason: var_reason };
             }
+            6 => {
+                let mut var_reason = <String>::sse_decode(deserializer);
+                return crate::error::calibration::CalibrationError::Io { reason: var_reason };
+            }
+            7 => {
+                let mut var_reason = <String>::sse_decode(deserializer);
+                return crate::error::calibration::CalibrationError::Parse { reason: var_reason };
+            }
+            8 => {
+                let mut var_name = <String>::sse_decode(deserializer);
+                return crate::error::calibration::CalibrationError::UnknownPreset {
+                    name: var_name,
+                };
+            }
+            9 => {
+                let mut var_phase =
+                    <crate::calibration::progress::CalibrationSound>::sse_decode(deserializer);
+                return crate::error::calibration::CalibrationError::NotASoundPhase {
+                    phase: var_phase,
+                };
+            }
             _ => {
                 unimplemented!("");
             }
@@ -1390,6 +2064,8 @@ impl SseDecode for crate::calibration::progress::CalibrationGuidanceReason {
             0 => crate::calibration::progress::CalibrationGuidanceReason::Stagnation,
             1 => crate::calibration::progress::CalibrationGuidanceReason::TooQuiet,
             2 => crate::calibration::progress::CalibrationGuidanceReason::Clipped,
+            3 => crate::calibration::progress::CalibrationGuidanceReason::TooNoisy,
+            4 => crate::calibration::progress::CalibrationGuidanceReason::UnexpectedTimbre,
             _ => unreachable!("Invalid variant for CalibrationGuidanceReason: {}", inner),
         };
     }
@@ -1406,6 +2082,11 @@ impl SseDecode for crate::calibration::progress::CalibrationProgress {
         let mut var_guidance =
             <Option<crate::calibration::progress::CalibrationGuidance>>::sse_decode(deserializer);
         let mut var_manualAcceptAvailable = <bool>::sse_decode(deserializer);
+        let mut var_autoAdvanced = <bool>::sse_decode(deserializer);
+        let mut var_phaseChanged = <bool>::sse_decode(deserializer);
+        let mut var_estimatedRemainingMs = <Option<u32>>::sse_decode(deserializer);
+        let mut var_completed =
+            <Option<crate::calibration::progress::CalibrationSummary>>::sse_decode(deserializer);
         let mut var_debug =
             <Option<crate::calibration::progress::CalibrationProgressDebug>>::sse_decode(
                 deserializer,
@@ -1417,6 +2098,10 @@ impl SseDecode for crate::calibration::progress::CalibrationProgress {
             waiting_for_confirmation: var_waitingForConfirmation,
             guidance: var_guidance,
             manual_accept_available: var_manualAcceptAvailable,
+            auto_advanced: var_autoAdvanced,
+            phase_changed: var_phaseChanged,
+            estimated_remaining_ms: var_estimatedRemainingMs,
+            completed: var_completed,
             debug: var_debug,
         };
     }
@@ -1461,23 +2146,65 @@ impl SseDecode for crate::calibration::progress::CalibrationSound {
             1 => crate::calibration::progress::CalibrationSound::Kick,
             2 => crate::calibration::progress::CalibrationSound::Snare,
             3 => crate::calibration::progress::CalibrationSound::HiHat,
+            4 => crate::calibration::progress::CalibrationSound::InputLevel,
             _ => unreachable!("Invalid variant for CalibrationSound: {}", inner),
         };
     }
 }
 
+impl SseDecode for crate::calibration::progress::CalibrationSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_tKickCentroid = <f32>::sse_decode(deserializer);
+        let mut var_tKickZcr = <f32>::sse_decode(deserializer);
+        let mut var_tSnareCentroid = <f32>::sse_decode(deserializer);
+        let mut var_tHihatZcr = <f32>::sse_decode(deserializer);
+        let mut var_noiseFloorRms = <f64>::sse_decode(deserializer);
+        let mut var_qualityScore = <Option<f32>>::sse_decode(deserializer);
+        return crate::calibration::progress::CalibrationSummary {
+            t_kick_centroid: var_tKickCentroid,
+            t_kick_zcr: var_tKickZcr,
+            t_snare_centroid: var_tSnareCentroid,
+            t_hihat_zcr: var_tHihatZcr,
+            noise_floor_rms: var_noiseFloorRms,
+            quality_score: var_qualityScore,
+        };
+    }
+}
+
 impl SseDecode for crate::analysis::ClassificationResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_sound = <crate::analysis::classifier::BeatboxHit>::sse_decode(deserializer);
-        let mut var_timing = <crate::analysis::quantizer::TimingFeedback>::sse_decode(deserializer);
+        let mut var_sound = <crate::types::BeatboxHit>::sse_decode(deserializer);
+        let mut var_timing =
+            <Option<crate::analysis::quantizer::TimingFeedback>>::sse_decode(deserializer);
         let mut var_timestampMs = <u64>::sse_decode(deserializer);
         let mut var_confidence = <f32>::sse_decode(deserializer);
+        let mut var_label = <String>::sse_decode(deserializer);
+        let mut var_extra = <bool>::sse_decode(deserializer);
+        let mut var_schemaVersion = <u32>::sse_decode(deserializer);
+        let mut var_smoothedConfidence = <Option<f32>>::sse_decode(deserializer);
+        let mut var_beatIndex = <Option<u32>>::sse_decode(deserializer);
+        let mut var_subdivisionIndex = <Option<u32>>::sse_decode(deserializer);
+        let mut var_wallClockMs = <u64>::sse_decode(deserializer);
+        let mut var_trigger = <crate::analysis::HitTrigger>::sse_decode(deserializer);
+        let mut var_flam = <bool>::sse_decode(deserializer);
+        let mut var_clipped = <bool>::sse_decode(deserializer);
         return crate::analysis::ClassificationResult {
             sound: var_sound,
             timing: var_timing,
             timestamp_ms: var_timestampMs,
             confidence: var_confidence,
+            label: var_label,
+            extra: var_extra,
+            schema_version: var_schemaVersion,
+            smoothed_confidence: var_smoothedConfidence,
+            beat_index: var_beatIndex,
+            subdivision_index: var_subdivisionIndex,
+            wall_clock_ms: var_wallClockMs,
+            trigger: var_trigger,
+            flam: var_flam,
+            clipped: var_clipped,
         };
     }
 }
@@ -1491,6 +2218,8 @@ impl SseDecode for crate::telemetry::events::DiagnosticError {
             1 => crate::telemetry::events::DiagnosticError::BufferDrain,
             2 => crate::telemetry::events::DiagnosticError::StreamBackpressure,
             3 => crate::telemetry::events::DiagnosticError::Unknown,
+            4 => crate::telemetry::events::DiagnosticError::ThreadPriorityDenied,
+            5 => crate::telemetry::events::DiagnosticError::InvalidAudio,
             _ => unreachable!("Invalid variant for DiagnosticError: {}", inner),
         };
     }
@@ -1510,6 +2239,24 @@ impl SseDecode for f64 {
     }
 }
 
+impl SseDecode for crate::analysis::features::types::Features {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_centroid = <f32>::sse_decode(deserializer);
+        let mut var_zcr = <f32>::sse_decode(deserializer);
+        let mut var_flatness = <f32>::sse_decode(deserializer);
+        let mut var_rolloff = <f32>::sse_decode(deserializer);
+        let mut var_decayTimeMs = <f32>::sse_decode(deserializer);
+        return crate::analysis::features::types::Features {
+            centroid: var_centroid,
+            zcr: var_zcr,
+            flatness: var_flatness,
+            rolloff: var_rolloff,
+            decay_time_ms: var_decayTimeMs,
+        };
+    }
+}
+
 impl SseDecode for crate::testing::fixture_manifest::FixtureBpmRange {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -1621,6 +2368,18 @@ impl SseDecode for crate::testing::fixture_manifest::FixtureToleranceEnvelope {
     }
 }
 
+impl SseDecode for crate::analysis::HitTrigger {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::analysis::HitTrigger::Onset,
+            1 => crate::analysis::HitTrigger::LevelCrossing,
+            _ => unreachable!("Invalid variant for HitTrigger: {}", inner),
+        };
+    }
+}
+
 impl SseDecode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -1669,6 +2428,32 @@ impl SseDecode for Vec<crate::testing::fixture_manifest::FixtureManifestEntry> {
     }
 }
 
+impl SseDecode for Vec<crate::telemetry::events::MetricEventKind> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::telemetry::events::MetricEventKind>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<f32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<f32>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -1705,6 +2490,46 @@ impl SseDecode for Vec<(String, u32)> {
     }
 }
 
+impl SseDecode for Vec<(u64, u64)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<(u64, u64)>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::analysis::labels::SoundDescriptor> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::analysis::labels::SoundDescriptor>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::error::calibration::SoundSampleCount> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::error::calibration::SoundSampleCount>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for crate::testing::fixture_manifest::ManifestSyntheticPattern {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -1743,8 +2568,7 @@ impl SseDecode for crate::telemetry::events::MetricEvent {
                 };
             }
             2 => {
-                let mut var_sound =
-                    <crate::analysis::classifier::BeatboxHit>::sse_decode(deserializer);
+                let mut var_sound = <crate::types::BeatboxHit>::sse_decode(deserializer);
                 let mut var_confidence = <f32>::sse_decode(deserializer);
                 let mut var_timingErrorMs = <f32>::sse_decode(deserializer);
                 return crate::telemetry::events::MetricEvent::Classification {
@@ -1771,6 +2595,18 @@ impl SseDecode for crate::telemetry::events::MetricEvent {
                     context: var_context,
                 };
             }
+            5 => {
+                let mut var_latencyMs = <f32>::sse_decode(deserializer);
+                return crate::telemetry::events::MetricEvent::ProcessingLatency {
+                    latency_ms: var_latencyMs,
+                };
+            }
+            6 => {
+                let mut var_quietMs = <f32>::sse_decode(deserializer);
+                return crate::telemetry::events::MetricEvent::Silence {
+                    quiet_ms: var_quietMs,
+                };
+            }
             _ => {
                 unimplemented!("");
             }
@@ -1778,6 +2614,23 @@ impl SseDecode for crate::telemetry::events::MetricEvent {
     }
 }
 
+impl SseDecode for crate::telemetry::events::MetricEventKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::telemetry::events::MetricEventKind::Latency,
+            1 => crate::telemetry::events::MetricEventKind::BufferOccupancy,
+            2 => crate::telemetry::events::MetricEventKind::Classification,
+            3 => crate::telemetry::events::MetricEventKind::JniLifecycle,
+            4 => crate::telemetry::events::MetricEventKind::Error,
+            5 => crate::telemetry::events::MetricEventKind::ProcessingLatency,
+            6 => crate::telemetry::events::MetricEventKind::Silence,
+            _ => unreachable!("Invalid variant for MetricEventKind: {}", inner),
+        };
+    }
+}
+
 impl SseDecode for crate::api::types::OnsetEvent {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -1790,6 +2643,9 @@ impl SseDecode for crate::api::types::OnsetEvent {
         let mut var_decayTimeMs = <f64>::sse_decode(deserializer);
         let mut var_classification =
             <Option<crate::analysis::ClassificationResult>>::sse_decode(deserializer);
+        let mut var_strength = <f64>::sse_decode(deserializer);
+        let mut var_features =
+            <Option<crate::analysis::features::types::Features>>::sse_decode(deserializer);
         return crate::api::types::OnsetEvent {
             timestamp: var_timestamp,
             energy: var_energy,
@@ -1799,6 +2655,8 @@ impl SseDecode for crate::api::types::OnsetEvent {
             rolloff: var_rolloff,
             decay_time_ms: var_decayTimeMs,
             classification: var_classification,
+            strength: var_strength,
+            features: var_features,
         };
     }
 }
@@ -1840,6 +2698,19 @@ impl SseDecode for Option<crate::calibration::progress::CalibrationProgressDebug
     }
 }
 
+impl SseDecode for Option<crate::calibration::progress::CalibrationSummary> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <crate::calibration::progress::CalibrationSummary>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<crate::analysis::ClassificationResult> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -1875,6 +2746,19 @@ impl SseDecode for Option<f64> {
     }
 }
 
+impl SseDecode for Option<crate::analysis::features::types::Features> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::analysis::features::types::Features>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<crate::testing::fixture_manifest::FixtureManifestEntry> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -1888,6 +2772,19 @@ impl SseDecode for Option<crate::testing::fixture_manifest::FixtureManifestEntry
     }
 }
 
+impl SseDecode for Option<crate::analysis::quantizer::TimingFeedback> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::analysis::quantizer::TimingFeedback>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -1905,10 +2802,18 @@ impl SseDecode for crate::engine::core::ParamPatch {
         let mut var_bpm = <Option<u32>>::sse_decode(deserializer);
         let mut var_centroidThreshold = <Option<f32>>::sse_decode(deserializer);
         let mut var_zcrThreshold = <Option<f32>>::sse_decode(deserializer);
+        let mut var_kickCentroidThreshold = <Option<f32>>::sse_decode(deserializer);
+        let mut var_kickZcrThreshold = <Option<f32>>::sse_decode(deserializer);
+        let mut var_snareCentroidThreshold = <Option<f32>>::sse_decode(deserializer);
+        let mut var_hihatZcrThreshold = <Option<f32>>::sse_decode(deserializer);
         return crate::engine::core::ParamPatch {
             bpm: var_bpm,
             centroid_threshold: var_centroidThreshold,
             zcr_threshold: var_zcrThreshold,
+            kick_centroid_threshold: var_kickCentroidThreshold,
+            kick_zcr_threshold: var_kickZcrThreshold,
+            snare_centroid_threshold: var_snareCentroidThreshold,
+            hihat_zcr_threshold: var_hihatZcrThreshold,
         };
     }
 }
@@ -1941,6 +2846,46 @@ impl SseDecode for (String, u32) {
     }
 }
 
+impl SseDecode for (u64, u64) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <u64>::sse_decode(deserializer);
+        let mut var_field1 = <u64>::sse_decode(deserializer);
+        return (var_field0, var_field1);
+    }
+}
+
+impl SseDecode for crate::analysis::labels::SoundDescriptor {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_sound = <crate::types::BeatboxHit>::sse_decode(deserializer);
+        let mut var_label = <String>::sse_decode(deserializer);
+        let mut var_minLevel = <u8>::sse_decode(deserializer);
+        let mut var_active = <bool>::sse_decode(deserializer);
+        return crate::analysis::labels::SoundDescriptor {
+            sound: var_sound,
+            label: var_label,
+            min_level: var_minLevel,
+            active: var_active,
+        };
+    }
+}
+
+impl SseDecode for crate::error::calibration::SoundSampleCount {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_sound =
+            <crate::calibration::progress::CalibrationSound>::sse_decode(deserializer);
+        let mut var_collected = <usize>::sse_decode(deserializer);
+        let mut var_needed = <usize>::sse_decode(deserializer);
+        return crate::error::calibration::SoundSampleCount {
+            sound: var_sound,
+            collected: var_collected,
+            needed: var_needed,
+        };
+    }
+}
+
 impl SseDecode for crate::engine::core::TelemetryEvent {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -2060,40 +3005,68 @@ fn pde_ffi_dispatcher_primary_impl(
         2 => {
             wire__crate__api__streams__audio_metrics_stream_impl(port, ptr, rust_vec_len, data_len)
         }
-        3 => wire__crate__api__calibration_stream_impl(port, ptr, rust_vec_len, data_len),
-        4 => wire__crate__api__classification_stream_impl(port, ptr, rust_vec_len, data_len),
-        5 => wire__crate__api__confirm_calibration_step_impl(port, ptr, rust_vec_len, data_len),
-        6 => wire__crate__api__streams__diagnostic_metrics_stream_impl(
+        3 => wire__crate__api__calibrate_from_wavs_impl(port, ptr, rust_vec_len, data_len),
+        4 => wire__crate__api__streams__calibration_debug_stream_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        5 => wire__crate__api__streams__calibration_preview_stream_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        6 => wire__crate__api__calibration_stream_impl(port, ptr, rust_vec_len, data_len),
+        7 => wire__crate__api__classification_stream_impl(port, ptr, rust_vec_len, data_len),
+        9 => wire__crate__api__confirm_calibration_step_impl(port, ptr, rust_vec_len, data_len),
+        10 => wire__crate__api__streams__diagnostic_metrics_stream_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        12 => wire__crate__api__finish_calibration_impl(port, ptr, rust_vec_len, data_len),
+        16 => wire__crate__api__get_calibration_state_impl(port, ptr, rust_vec_len, data_len),
+        17 => wire__crate__api__get_current_audio_level_impl(port, ptr, rust_vec_len, data_len),
+        21 => wire__crate__api__init_app_impl(port, ptr, rust_vec_len, data_len),
+        22 => wire__crate__api__inject_classification_impl(port, ptr, rust_vec_len, data_len),
+        24 => wire__crate__api__load_calibration_state_impl(port, ptr, rust_vec_len, data_len),
+        26 => wire__crate__api__load_preset_calibration_impl(port, ptr, rust_vec_len, data_len),
+        27 => wire__crate__api__load_preset_calibration_for_device_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        7 => wire__crate__api__finish_calibration_impl(port, ptr, rust_vec_len, data_len),
-        11 => wire__crate__api__get_calibration_state_impl(port, ptr, rust_vec_len, data_len),
-        12 => wire__crate__api__get_current_audio_level_impl(port, ptr, rust_vec_len, data_len),
-        15 => wire__crate__api__init_app_impl(port, ptr, rust_vec_len, data_len),
-        17 => wire__crate__api__load_calibration_state_impl(port, ptr, rust_vec_len, data_len),
-        19 => {
+        28 => {
             wire__crate__api__manual_accept_last_candidate_impl(port, ptr, rust_vec_len, data_len)
         }
-        20 => {
+        29 => {
             wire__crate__api__streams__onset_events_stream_impl(port, ptr, rust_vec_len, data_len)
         }
-        21 => wire__crate__api__reset_calibration_session_impl(port, ptr, rust_vec_len, data_len),
-        22 => wire__crate__api__retry_calibration_step_impl(port, ptr, rust_vec_len, data_len),
-        23 => wire__crate__api__set_bpm_impl(port, ptr, rust_vec_len, data_len),
-        25 => wire__crate__api__start_audio_impl(port, ptr, rust_vec_len, data_len),
-        26 => wire__crate__api__start_calibration_impl(port, ptr, rust_vec_len, data_len),
-        27 => wire__crate__api__diagnostics__start_fixture_session_impl(
+        30 => wire__crate__api__reset_calibration_session_impl(port, ptr, rust_vec_len, data_len),
+        32 => wire__crate__api__retry_calibration_step_impl(port, ptr, rust_vec_len, data_len),
+        33 => wire__crate__api__set_bpm_impl(port, ptr, rust_vec_len, data_len),
+        40 => wire__crate__api__start_audio_impl(port, ptr, rust_vec_len, data_len),
+        41 => wire__crate__api__start_calibration_impl(port, ptr, rust_vec_len, data_len),
+        42 => wire__crate__api__diagnostics__start_fixture_session_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        43 => wire__crate__api__start_metronome_only_impl(port, ptr, rust_vec_len, data_len),
+        44 => wire__crate__api__stop_audio_impl(port, ptr, rust_vec_len, data_len),
+        47 => wire__crate__api__streams__telemetry_stream_impl(port, ptr, rust_vec_len, data_len),
+        48 => wire__crate__api__streams__telemetry_stream_filtered_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        28 => wire__crate__api__stop_audio_impl(port, ptr, rust_vec_len, data_len),
-        30 => wire__crate__api__streams__telemetry_stream_impl(port, ptr, rust_vec_len, data_len),
-        31 => {
+        49 => {
             wire__crate__api__update_calibration_threshold_impl(port, ptr, rust_vec_len, data_len)
         }
         _ => unreachable!(),
@@ -2108,17 +3081,27 @@ fn pde_ffi_dispatcher_sync_impl(
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     // Codec=Pde (Serialization + dispatch), see doc to use other codecs
     match func_id {
-        8 => {
+        8 => wire__crate__api__compute_spectrum_impl(ptr, rust_vec_len, data_len),
+        11 => wire__crate__api__extract_features_impl(ptr, rust_vec_len, data_len),
+        13 => {
             wire__crate__api__diagnostics__fixture_metadata_for_id_impl(ptr, rust_vec_len, data_len)
         }
-        9 => wire__crate__api__get_audio_error_codes_impl(ptr, rust_vec_len, data_len),
-        10 => wire__crate__api__get_calibration_error_codes_impl(ptr, rust_vec_len, data_len),
-        13 => wire__crate__api__get_version_impl(ptr, rust_vec_len, data_len),
-        14 => wire__crate__api__greet_impl(ptr, rust_vec_len, data_len),
-        16 => wire__crate__api__is_pipeline_tracing_enabled_impl(ptr, rust_vec_len, data_len),
-        18 => wire__crate__api__diagnostics__load_fixture_catalog_impl(ptr, rust_vec_len, data_len),
-        24 => wire__crate__api__set_pipeline_tracing_impl(ptr, rust_vec_len, data_len),
-        29 => wire__crate__api__diagnostics__stop_fixture_session_impl(ptr, rust_vec_len, data_len),
+        14 => wire__crate__api__get_audio_error_codes_impl(ptr, rust_vec_len, data_len),
+        15 => wire__crate__api__get_calibration_error_codes_impl(ptr, rust_vec_len, data_len),
+        18 => wire__crate__api__get_current_onset_threshold_impl(ptr, rust_vec_len, data_len),
+        19 => wire__crate__api__get_version_impl(ptr, rust_vec_len, data_len),
+        20 => wire__crate__api__greet_impl(ptr, rust_vec_len, data_len),
+        23 => wire__crate__api__is_pipeline_tracing_enabled_impl(ptr, rust_vec_len, data_len),
+        25 => wire__crate__api__diagnostics__load_fixture_catalog_impl(ptr, rust_vec_len, data_len),
+        31 => wire__crate__api__reset_telemetry_impl(ptr, rust_vec_len, data_len),
+        34 => wire__crate__api__set_listening_windows_impl(ptr, rust_vec_len, data_len),
+        35 => wire__crate__api__set_metronome_muted_impl(ptr, rust_vec_len, data_len),
+        36 => wire__crate__api__set_metronome_volume_impl(ptr, rust_vec_len, data_len),
+        37 => wire__crate__api__set_pipeline_tracing_impl(ptr, rust_vec_len, data_len),
+        38 => wire__crate__api__set_json_result_schema_version_impl(ptr, rust_vec_len, data_len),
+        39 => wire__crate__api__set_sound_labels_impl(ptr, rust_vec_len, data_len),
+        45 => wire__crate__api__diagnostics__stop_fixture_session_impl(ptr, rust_vec_len, data_len),
+        46 => wire__crate__api__supported_sounds_impl(ptr, rust_vec_len, data_len),
         _ => unreachable!(),
     }
 }
@@ -2166,6 +3149,9 @@ impl flutter_rust_bridge::IntoDart for crate::error::audio::AudioError {
             crate::error::audio::AudioError::StreamFailure { reason } => {
                 [9.into_dart(), reason.into_into_dart().into_dart()].into_dart()
             }
+            crate::error::audio::AudioError::Unsupported { operation } => {
+                [10.into_dart(), operation.into_into_dart().into_dart()].into_dart()
+            }
             _ => {
                 unimplemented!("");
             }
@@ -2205,6 +3191,7 @@ impl flutter_rust_bridge::IntoDart for crate::api::types::AudioMetrics {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
             self.rms.into_into_dart().into_dart(),
+            self.smoothed_rms.into_into_dart().into_dart(),
             self.spectral_centroid.into_into_dart().into_dart(),
             self.spectral_flux.into_into_dart().into_dart(),
             self.frame_number.into_into_dart().into_dart(),
@@ -2225,7 +3212,7 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::types::AudioMetrics>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::analysis::classifier::BeatboxHit {
+impl flutter_rust_bridge::IntoDart for crate::types::BeatboxHit {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
             Self::Kick => 0.into_dart(),
@@ -2239,14 +3226,34 @@ impl flutter_rust_bridge::IntoDart for crate::analysis::classifier::BeatboxHit {
         }
     }
 }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::types::BeatboxHit {}
+impl flutter_rust_bridge::IntoIntoDart<crate::types::BeatboxHit> for crate::types::BeatboxHit {
+    fn into_into_dart(self) -> crate::types::BeatboxHit {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::calibration::progress::CalibrationDebugFrame {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.seq.into_into_dart().into_dart(),
+            self.sound.into_into_dart().into_dart(),
+            self.centroid.into_into_dart().into_dart(),
+            self.zcr.into_into_dart().into_dart(),
+            self.rms.into_into_dart().into_dart(),
+            self.max_amp.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::analysis::classifier::BeatboxHit
+    for crate::calibration::progress::CalibrationDebugFrame
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::analysis::classifier::BeatboxHit>
-    for crate::analysis::classifier::BeatboxHit
+impl flutter_rust_bridge::IntoIntoDart<crate::calibration::progress::CalibrationDebugFrame>
+    for crate::calibration::progress::CalibrationDebugFrame
 {
-    fn into_into_dart(self) -> crate::analysis::classifier::BeatboxHit {
+    fn into_into_dart(self) -> crate::calibration::progress::CalibrationDebugFrame {
         self
     }
 }
@@ -2257,10 +3264,12 @@ impl flutter_rust_bridge::IntoDart for crate::error::calibration::CalibrationErr
             crate::error::calibration::CalibrationError::InsufficientSamples {
                 required,
                 collected,
+                per_sound,
             } => [
                 0.into_dart(),
                 required.into_into_dart().into_dart(),
                 collected.into_into_dart().into_dart(),
+                per_sound.into_into_dart().into_dart(),
             ]
             .into_dart(),
             crate::error::calibration::CalibrationError::InvalidFeatures { reason } => {
@@ -2276,6 +3285,18 @@ impl flutter_rust_bridge::IntoDart for crate::error::calibration::CalibrationErr
             crate::error::calibration::CalibrationError::Timeout { reason } => {
                 [5.into_dart(), reason.into_into_dart().into_dart()].into_dart()
             }
+            crate::error::calibration::CalibrationError::Io { reason } => {
+                [6.into_dart(), reason.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::calibration::CalibrationError::Parse { reason } => {
+                [7.into_dart(), reason.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::calibration::CalibrationError::UnknownPreset { name } => {
+                [8.into_dart(), name.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::calibration::CalibrationError::NotASoundPhase { phase } => {
+                [9.into_dart(), phase.into_into_dart().into_dart()].into_dart()
+            }
             _ => {
                 unimplemented!("");
             }
@@ -2340,6 +3361,8 @@ impl flutter_rust_bridge::IntoDart for crate::calibration::progress::Calibration
             Self::Stagnation => 0.into_dart(),
             Self::TooQuiet => 1.into_dart(),
             Self::Clipped => 2.into_dart(),
+            Self::TooNoisy => 3.into_dart(),
+            Self::UnexpectedTimbre => 4.into_dart(),
             _ => unreachable!(),
         }
     }
@@ -2365,6 +3388,10 @@ impl flutter_rust_bridge::IntoDart for crate::calibration::progress::Calibration
             self.waiting_for_confirmation.into_into_dart().into_dart(),
             self.guidance.into_into_dart().into_dart(),
             self.manual_accept_available.into_into_dart().into_dart(),
+            self.auto_advanced.into_into_dart().into_dart(),
+            self.phase_changed.into_into_dart().into_dart(),
+            self.estimated_remaining_ms.into_into_dart().into_dart(),
+            self.completed.into_into_dart().into_dart(),
             self.debug.into_into_dart().into_dart(),
         ]
         .into_dart()
@@ -2419,6 +3446,7 @@ impl flutter_rust_bridge::IntoDart for crate::calibration::progress::Calibration
             Self::Kick => 1.into_dart(),
             Self::Snare => 2.into_dart(),
             Self::HiHat => 3.into_dart(),
+            Self::InputLevel => 4.into_dart(),
             _ => unreachable!(),
         }
     }
@@ -2435,6 +3463,31 @@ impl flutter_rust_bridge::IntoIntoDart<crate::calibration::progress::Calibration
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::calibration::progress::CalibrationSummary {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.t_kick_centroid.into_into_dart().into_dart(),
+            self.t_kick_zcr.into_into_dart().into_dart(),
+            self.t_snare_centroid.into_into_dart().into_dart(),
+            self.t_hihat_zcr.into_into_dart().into_dart(),
+            self.noise_floor_rms.into_into_dart().into_dart(),
+            self.quality_score.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::calibration::progress::CalibrationSummary
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::calibration::progress::CalibrationSummary>
+    for crate::calibration::progress::CalibrationSummary
+{
+    fn into_into_dart(self) -> crate::calibration::progress::CalibrationSummary {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::analysis::ClassificationResult {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -2442,6 +3495,16 @@ impl flutter_rust_bridge::IntoDart for crate::analysis::ClassificationResult {
             self.timing.into_into_dart().into_dart(),
             self.timestamp_ms.into_into_dart().into_dart(),
             self.confidence.into_into_dart().into_dart(),
+            self.label.into_into_dart().into_dart(),
+            self.extra.into_into_dart().into_dart(),
+            self.schema_version.into_into_dart().into_dart(),
+            self.smoothed_confidence.into_into_dart().into_dart(),
+            self.beat_index.into_into_dart().into_dart(),
+            self.subdivision_index.into_into_dart().into_dart(),
+            self.wall_clock_ms.into_into_dart().into_dart(),
+            self.trigger.into_into_dart().into_dart(),
+            self.flam.into_into_dart().into_dart(),
+            self.clipped.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -2465,6 +3528,8 @@ impl flutter_rust_bridge::IntoDart for crate::telemetry::events::DiagnosticError
             Self::BufferDrain => 1.into_dart(),
             Self::StreamBackpressure => 2.into_dart(),
             Self::Unknown => 3.into_dart(),
+            Self::ThreadPriorityDenied => 4.into_dart(),
+            Self::InvalidAudio => 5.into_dart(),
             _ => unreachable!(),
         }
     }
@@ -2481,6 +3546,30 @@ impl flutter_rust_bridge::IntoIntoDart<crate::telemetry::events::DiagnosticError
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::analysis::features::types::Features {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.centroid.into_into_dart().into_dart(),
+            self.zcr.into_into_dart().into_dart(),
+            self.flatness.into_into_dart().into_dart(),
+            self.rolloff.into_into_dart().into_dart(),
+            self.decay_time_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::analysis::features::types::Features
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::analysis::features::types::Features>
+    for crate::analysis::features::types::Features
+{
+    fn into_into_dart(self) -> crate::analysis::features::types::Features {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::testing::fixture_manifest::FixtureBpmRange {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -2610,6 +3699,24 @@ impl flutter_rust_bridge::IntoIntoDart<crate::testing::fixture_manifest::Fixture
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::analysis::HitTrigger {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Onset => 0.into_dart(),
+            Self::LevelCrossing => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::analysis::HitTrigger {}
+impl flutter_rust_bridge::IntoIntoDart<crate::analysis::HitTrigger>
+    for crate::analysis::HitTrigger
+{
+    fn into_into_dart(self) -> crate::analysis::HitTrigger {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::telemetry::events::LifecyclePhase {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
@@ -2703,6 +3810,12 @@ impl flutter_rust_bridge::IntoDart for crate::telemetry::events::MetricEvent {
                 context.into_into_dart().into_dart(),
             ]
             .into_dart(),
+            crate::telemetry::events::MetricEvent::ProcessingLatency { latency_ms } => {
+                [5.into_dart(), latency_ms.into_into_dart().into_dart()].into_dart()
+            }
+            crate::telemetry::events::MetricEvent::Silence { quiet_ms } => {
+                [6.into_dart(), quiet_ms.into_into_dart().into_dart()].into_dart()
+            }
             _ => {
                 unimplemented!("");
             }
@@ -2721,6 +3834,32 @@ impl flutter_rust_bridge::IntoIntoDart<crate::telemetry::events::MetricEvent>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::telemetry::events::MetricEventKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Latency => 0.into_dart(),
+            Self::BufferOccupancy => 1.into_dart(),
+            Self::Classification => 2.into_dart(),
+            Self::JniLifecycle => 3.into_dart(),
+            Self::Error => 4.into_dart(),
+            Self::ProcessingLatency => 5.into_dart(),
+            Self::Silence => 6.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::telemetry::events::MetricEventKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::telemetry::events::MetricEventKind>
+    for crate::telemetry::events::MetricEventKind
+{
+    fn into_into_dart(self) -> crate::telemetry::events::MetricEventKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::types::OnsetEvent {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -2732,6 +3871,8 @@ impl flutter_rust_bridge::IntoDart for crate::api::types::OnsetEvent {
             self.rolloff.into_into_dart().into_dart(),
             self.decay_time_ms.into_into_dart().into_dart(),
             self.classification.into_into_dart().into_dart(),
+            self.strength.into_into_dart().into_dart(),
+            self.features.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -2751,6 +3892,10 @@ impl flutter_rust_bridge::IntoDart for crate::engine::core::ParamPatch {
             self.bpm.into_into_dart().into_dart(),
             self.centroid_threshold.into_into_dart().into_dart(),
             self.zcr_threshold.into_into_dart().into_dart(),
+            self.kick_centroid_threshold.into_into_dart().into_dart(),
+            self.kick_zcr_threshold.into_into_dart().into_dart(),
+            self.snare_centroid_threshold.into_into_dart().into_dart(),
+            self.hihat_zcr_threshold.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
@@ -2767,6 +3912,51 @@ impl flutter_rust_bridge::IntoIntoDart<crate::engine::core::ParamPatch>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::analysis::labels::SoundDescriptor {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.sound.into_into_dart().into_dart(),
+            self.label.into_into_dart().into_dart(),
+            self.min_level.into_into_dart().into_dart(),
+            self.active.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::analysis::labels::SoundDescriptor
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::analysis::labels::SoundDescriptor>
+    for crate::analysis::labels::SoundDescriptor
+{
+    fn into_into_dart(self) -> crate::analysis::labels::SoundDescriptor {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::error::calibration::SoundSampleCount {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.sound.into_into_dart().into_dart(),
+            self.collected.into_into_dart().into_dart(),
+            self.needed.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::error::calibration::SoundSampleCount
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::error::calibration::SoundSampleCount>
+    for crate::error::calibration::SoundSampleCount
+{
+    fn into_into_dart(self) -> crate::error::calibration::SoundSampleCount {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::engine::core::TelemetryEvent {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -2909,6 +4099,18 @@ impl SseEncode
     }
 }
 
+impl SseEncode
+    for StreamSink<
+        crate::calibration::progress::CalibrationDebugFrame,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
 impl SseEncode
     for StreamSink<
         crate::calibration::progress::CalibrationProgress,
@@ -3013,6 +4215,10 @@ impl SseEncode for crate::error::audio::AudioError {
                 <i32>::sse_encode(9, serializer);
                 <String>::sse_encode(reason, serializer);
             }
+            crate::error::audio::AudioError::Unsupported { operation } => {
+                <i32>::sse_encode(10, serializer);
+                <String>::sse_encode(operation, serializer);
+            }
             _ => {
                 unimplemented!("");
             }
@@ -3029,6 +4235,7 @@ impl SseEncode for crate::api::types::AudioMetrics {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <f64>::sse_encode(self.rms, serializer);
+        <f64>::sse_encode(self.smoothed_rms, serializer);
         <f64>::sse_encode(self.spectral_centroid, serializer);
         <f64>::sse_encode(self.spectral_flux, serializer);
         <u64>::sse_encode(self.frame_number, serializer);
@@ -3036,18 +4243,18 @@ impl SseEncode for crate::api::types::AudioMetrics {
     }
 }
 
-impl SseEncode for crate::analysis::classifier::BeatboxHit {
+impl SseEncode for crate::types::BeatboxHit {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(
             match self {
-                crate::analysis::classifier::BeatboxHit::Kick => 0,
-                crate::analysis::classifier::BeatboxHit::Snare => 1,
-                crate::analysis::classifier::BeatboxHit::HiHat => 2,
-                crate::analysis::classifier::BeatboxHit::ClosedHiHat => 3,
-                crate::analysis::classifier::BeatboxHit::OpenHiHat => 4,
-                crate::analysis::classifier::BeatboxHit::KSnare => 5,
-                crate::analysis::classifier::BeatboxHit::Unknown => 6,
+                crate::types::BeatboxHit::Kick => 0,
+                crate::types::BeatboxHit::Snare => 1,
+                crate::types::BeatboxHit::HiHat => 2,
+                crate::types::BeatboxHit::ClosedHiHat => 3,
+                crate::types::BeatboxHit::OpenHiHat => 4,
+                crate::types::BeatboxHit::KSnare => 5,
+                crate::types::BeatboxHit::Unknown => 6,
                 _ => {
                     unimplemented!("");
                 }
@@ -3064,6 +4271,18 @@ impl SseEncode for bool {
     }
 }
 
+impl SseEncode for crate::calibration::progress::CalibrationDebugFrame {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.seq, serializer);
+        <crate::calibration::progress::CalibrationSound>::sse_encode(self.sound, serializer);
+        <f32>::sse_encode(self.centroid, serializer);
+        <f32>::sse_encode(self.zcr, serializer);
+        <f64>::sse_encode(self.rms, serializer);
+        <f32>::sse_encode(self.max_amp, serializer);
+    }
+}
+
 impl SseEncode for crate::error::calibration::CalibrationError {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -3071,10 +4290,14 @@ impl SseEncode for crate::error::calibration::CalibrationError {
             crate::error::calibration::CalibrationError::InsufficientSamples {
                 required,
                 collected,
+                per_sound,
             } => {
                 <i32>::sse_encode(0, serializer);
                 <usize>::sse_encode(required, serializer);
                 <usize>::sse_encode(collected, serializer);
+                <Vec<crate::error::calibration::SoundSampleCount>>::sse_encode(
+                    per_sound, serializer,
+                );
             }
             crate::error::calibration::CalibrationError::InvalidFeatures { reason } => {
                 <i32>::sse_encode(1, serializer);
@@ -3093,6 +4316,22 @@ impl SseEncode for crate::error::calibration::CalibrationError {
                 <i32>::sse_encode(5, serializer);
                 <String>::sse_encode(reason, serializer);
             }
+            crate::error::calibration::CalibrationError::Io { reason } => {
+                <i32>::sse_encode(6, serializer);
+                <String>::sse_encode(reason, serializer);
+            }
+            crate::error::calibration::CalibrationError::Parse { reason } => {
+                <i32>::sse_encode(7, serializer);
+                <String>::sse_encode(reason, serializer);
+            }
+            crate::error::calibration::CalibrationError::UnknownPreset { name } => {
+                <i32>::sse_encode(8, serializer);
+                <String>::sse_encode(name, serializer);
+            }
+            crate::error::calibration::CalibrationError::NotASoundPhase { phase } => {
+                <i32>::sse_encode(9, serializer);
+                <crate::calibration::progress::CalibrationSound>::sse_encode(phase, serializer);
+            }
             _ => {
                 unimplemented!("");
             }
@@ -3126,6 +4365,8 @@ impl SseEncode for crate::calibration::progress::CalibrationGuidanceReason {
                 crate::calibration::progress::CalibrationGuidanceReason::Stagnation => 0,
                 crate::calibration::progress::CalibrationGuidanceReason::TooQuiet => 1,
                 crate::calibration::progress::CalibrationGuidanceReason::Clipped => 2,
+                crate::calibration::progress::CalibrationGuidanceReason::TooNoisy => 3,
+                crate::calibration::progress::CalibrationGuidanceReason::UnexpectedTimbre => 4,
                 _ => {
                     unimplemented!("");
                 }
@@ -3150,6 +4391,13 @@ impl SseEncode for crate::calibration::progress::CalibrationProgress {
             serializer,
         );
         <bool>::sse_encode(self.manual_accept_available, serializer);
+        <bool>::sse_encode(self.auto_advanced, serializer);
+        <bool>::sse_encode(self.phase_changed, serializer);
+        <Option<u32>>::sse_encode(self.estimated_remaining_ms, serializer);
+        <Option<crate::calibration::progress::CalibrationSummary>>::sse_encode(
+            self.completed,
+            serializer,
+        );
         <Option<crate::calibration::progress::CalibrationProgressDebug>>::sse_encode(
             self.debug, serializer,
         );
@@ -3182,6 +4430,7 @@ impl SseEncode for crate::calibration::progress::CalibrationSound {
                 crate::calibration::progress::CalibrationSound::Kick => 1,
                 crate::calibration::progress::CalibrationSound::Snare => 2,
                 crate::calibration::progress::CalibrationSound::HiHat => 3,
+                crate::calibration::progress::CalibrationSound::InputLevel => 4,
                 _ => {
                     unimplemented!("");
                 }
@@ -3191,13 +4440,35 @@ impl SseEncode for crate::calibration::progress::CalibrationSound {
     }
 }
 
+impl SseEncode for crate::calibration::progress::CalibrationSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f32>::sse_encode(self.t_kick_centroid, serializer);
+        <f32>::sse_encode(self.t_kick_zcr, serializer);
+        <f32>::sse_encode(self.t_snare_centroid, serializer);
+        <f32>::sse_encode(self.t_hihat_zcr, serializer);
+        <f64>::sse_encode(self.noise_floor_rms, serializer);
+        <Option<f32>>::sse_encode(self.quality_score, serializer);
+    }
+}
+
 impl SseEncode for crate::analysis::ClassificationResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <crate::analysis::classifier::BeatboxHit>::sse_encode(self.sound, serializer);
-        <crate::analysis::quantizer::TimingFeedback>::sse_encode(self.timing, serializer);
+        <crate::types::BeatboxHit>::sse_encode(self.sound, serializer);
+        <Option<crate::analysis::quantizer::TimingFeedback>>::sse_encode(self.timing, serializer);
         <u64>::sse_encode(self.timestamp_ms, serializer);
         <f32>::sse_encode(self.confidence, serializer);
+        <String>::sse_encode(self.label, serializer);
+        <bool>::sse_encode(self.extra, serializer);
+        <u32>::sse_encode(self.schema_version, serializer);
+        <Option<f32>>::sse_encode(self.smoothed_confidence, serializer);
+        <Option<u32>>::sse_encode(self.beat_index, serializer);
+        <Option<u32>>::sse_encode(self.subdivision_index, serializer);
+        <u64>::sse_encode(self.wall_clock_ms, serializer);
+        <crate::analysis::HitTrigger>::sse_encode(self.trigger, serializer);
+        <bool>::sse_encode(self.flam, serializer);
+        <bool>::sse_encode(self.clipped, serializer);
     }
 }
 
@@ -3210,6 +4481,8 @@ impl SseEncode for crate::telemetry::events::DiagnosticError {
                 crate::telemetry::events::DiagnosticError::BufferDrain => 1,
                 crate::telemetry::events::DiagnosticError::StreamBackpressure => 2,
                 crate::telemetry::events::DiagnosticError::Unknown => 3,
+                crate::telemetry::events::DiagnosticError::ThreadPriorityDenied => 4,
+                crate::telemetry::events::DiagnosticError::InvalidAudio => 5,
                 _ => {
                     unimplemented!("");
                 }
@@ -3233,6 +4506,17 @@ impl SseEncode for f64 {
     }
 }
 
+impl SseEncode for crate::analysis::features::types::Features {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f32>::sse_encode(self.centroid, serializer);
+        <f32>::sse_encode(self.zcr, serializer);
+        <f32>::sse_encode(self.flatness, serializer);
+        <f32>::sse_encode(self.rolloff, serializer);
+        <f32>::sse_encode(self.decay_time_ms, serializer);
+    }
+}
+
 impl SseEncode for crate::testing::fixture_manifest::FixtureBpmRange {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -3321,6 +4605,22 @@ impl SseEncode for crate::testing::fixture_manifest::FixtureToleranceEnvelope {
     }
 }
 
+impl SseEncode for crate::analysis::HitTrigger {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::analysis::HitTrigger::Onset => 0,
+                crate::analysis::HitTrigger::LevelCrossing => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
 impl SseEncode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -3367,6 +4667,26 @@ impl SseEncode for Vec<crate::testing::fixture_manifest::FixtureManifestEntry> {
     }
 }
 
+impl SseEncode for Vec<crate::telemetry::events::MetricEventKind> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::telemetry::events::MetricEventKind>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<f32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <f32>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -3397,6 +4717,36 @@ impl SseEncode for Vec<(String, u32)> {
     }
 }
 
+impl SseEncode for Vec<(u64, u64)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <(u64, u64)>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::analysis::labels::SoundDescriptor> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::analysis::labels::SoundDescriptor>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::error::calibration::SoundSampleCount> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::error::calibration::SoundSampleCount>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for crate::testing::fixture_manifest::ManifestSyntheticPattern {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -3440,7 +4790,7 @@ impl SseEncode for crate::telemetry::events::MetricEvent {
                 timing_error_ms,
             } => {
                 <i32>::sse_encode(2, serializer);
-                <crate::analysis::classifier::BeatboxHit>::sse_encode(sound, serializer);
+                <crate::types::BeatboxHit>::sse_encode(sound, serializer);
                 <f32>::sse_encode(confidence, serializer);
                 <f32>::sse_encode(timing_error_ms, serializer);
             }
@@ -3457,6 +4807,14 @@ impl SseEncode for crate::telemetry::events::MetricEvent {
                 <crate::telemetry::events::DiagnosticError>::sse_encode(code, serializer);
                 <String>::sse_encode(context, serializer);
             }
+            crate::telemetry::events::MetricEvent::ProcessingLatency { latency_ms } => {
+                <i32>::sse_encode(5, serializer);
+                <f32>::sse_encode(latency_ms, serializer);
+            }
+            crate::telemetry::events::MetricEvent::Silence { quiet_ms } => {
+                <i32>::sse_encode(6, serializer);
+                <f32>::sse_encode(quiet_ms, serializer);
+            }
             _ => {
                 unimplemented!("");
             }
@@ -3464,6 +4822,27 @@ impl SseEncode for crate::telemetry::events::MetricEvent {
     }
 }
 
+impl SseEncode for crate::telemetry::events::MetricEventKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::telemetry::events::MetricEventKind::Latency => 0,
+                crate::telemetry::events::MetricEventKind::BufferOccupancy => 1,
+                crate::telemetry::events::MetricEventKind::Classification => 2,
+                crate::telemetry::events::MetricEventKind::JniLifecycle => 3,
+                crate::telemetry::events::MetricEventKind::Error => 4,
+                crate::telemetry::events::MetricEventKind::ProcessingLatency => 5,
+                crate::telemetry::events::MetricEventKind::Silence => 6,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
 impl SseEncode for crate::api::types::OnsetEvent {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -3478,6 +4857,8 @@ impl SseEncode for crate::api::types::OnsetEvent {
             self.classification,
             serializer,
         );
+        <f64>::sse_encode(self.strength, serializer);
+        <Option<crate::analysis::features::types::Features>>::sse_encode(self.features, serializer);
     }
 }
 
@@ -3511,6 +4892,16 @@ impl SseEncode for Option<crate::calibration::progress::CalibrationProgressDebug
     }
 }
 
+impl SseEncode for Option<crate::calibration::progress::CalibrationSummary> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::calibration::progress::CalibrationSummary>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<crate::analysis::ClassificationResult> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -3541,6 +4932,16 @@ impl SseEncode for Option<f64> {
     }
 }
 
+impl SseEncode for Option<crate::analysis::features::types::Features> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::analysis::features::types::Features>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<crate::testing::fixture_manifest::FixtureManifestEntry> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -3551,6 +4952,16 @@ impl SseEncode for Option<crate::testing::fixture_manifest::FixtureManifestEntry
     }
 }
 
+impl SseEncode for Option<crate::analysis::quantizer::TimingFeedback> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::analysis::quantizer::TimingFeedback>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -3567,6 +4978,10 @@ impl SseEncode for crate::engine::core::ParamPatch {
         <Option<u32>>::sse_encode(self.bpm, serializer);
         <Option<f32>>::sse_encode(self.centroid_threshold, serializer);
         <Option<f32>>::sse_encode(self.zcr_threshold, serializer);
+        <Option<f32>>::sse_encode(self.kick_centroid_threshold, serializer);
+        <Option<f32>>::sse_encode(self.kick_zcr_threshold, serializer);
+        <Option<f32>>::sse_encode(self.snare_centroid_threshold, serializer);
+        <Option<f32>>::sse_encode(self.hihat_zcr_threshold, serializer);
     }
 }
 
@@ -3595,6 +5010,33 @@ impl SseEncode for (String, u32) {
     }
 }
 
+impl SseEncode for (u64, u64) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.0, serializer);
+        <u64>::sse_encode(self.1, serializer);
+    }
+}
+
+impl SseEncode for crate::analysis::labels::SoundDescriptor {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::types::BeatboxHit>::sse_encode(self.sound, serializer);
+        <String>::sse_encode(self.label, serializer);
+        <u8>::sse_encode(self.min_level, serializer);
+        <bool>::sse_encode(self.active, serializer);
+    }
+}
+
+impl SseEncode for crate::error::calibration::SoundSampleCount {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::calibration::progress::CalibrationSound>::sse_encode(self.sound, serializer);
+        <usize>::sse_encode(self.collected, serializer);
+        <usize>::sse_encode(self.needed, serializer);
+    }
+}
+
 impl SseEncode for crate::engine::core::TelemetryEvent {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -0,0 +1,163 @@
+// InputLevelCalibration - one-time input gain measurement
+//
+// Microphones differ widely in sensitivity, so the same beatbox hit can land
+// an order of magnitude apart in RMS on two devices. Before calibrating the
+// sounds themselves, the user hits a few of their loudest sounds; the peak
+// RMS of each hit is measured and an auto-gain is derived that brings the
+// typical loud hit to a fixed target level. The gain is stored in
+// CalibrationState and applied to incoming audio by the analysis thread.
+
+/// Peak RMS a loud hit should reach after the gain is applied
+pub const TARGET_PEAK_RMS: f64 = 0.25;
+
+/// Smallest gain the measurement may derive (attenuate hot inputs by up to 4x)
+pub const MIN_INPUT_GAIN: f32 = 0.25;
+
+/// Largest gain the measurement may derive; beyond this, noise is amplified
+/// more than the hits are worth
+pub const MAX_INPUT_GAIN: f32 = 8.0;
+
+/// RMS a buffer must reach to count as part of a hit while measuring
+pub const DEFAULT_INPUT_LEVEL_GATE_RMS: f64 = 0.01;
+
+/// Collects the peak RMS of each loud hit and derives an input gain
+///
+/// Feed it the per-buffer RMS of the raw input. A hit starts when the RMS
+/// rises to `gate_rms` and ends when it falls back below; the highest RMS
+/// seen in between is recorded as that hit's peak.
+#[derive(Debug, Clone)]
+pub struct InputLevelCalibration {
+    hits_needed: usize,
+    gate_rms: f64,
+    current_peak: Option<f64>,
+    peaks: Vec<f64>,
+}
+
+impl InputLevelCalibration {
+    /// Create a measurement expecting `hits_needed` hits (min 1) above `gate_rms`
+    pub fn new(hits_needed: usize, gate_rms: f64) -> Self {
+        let hits_needed = hits_needed.max(1);
+        Self {
+            hits_needed,
+            gate_rms: gate_rms.max(0.0),
+            current_peak: None,
+            peaks: Vec::with_capacity(hits_needed),
+        }
+    }
+
+    /// Observe the RMS of one buffer
+    ///
+    /// Returns `true` once enough hits have been measured.
+    pub fn observe_rms(&mut self, rms: f64) -> bool {
+        if self.is_complete() || !rms.is_finite() {
+            return self.is_complete();
+        }
+
+        if rms >= self.gate_rms {
+            let peak = self.current_peak.get_or_insert(rms);
+            *peak = peak.max(rms);
+        } else if let Some(peak) = self.current_peak.take() {
+            tracing::debug!(
+                "[InputLevelCalibration] Hit {} peak RMS {:.4}",
+                self.peaks.len() + 1,
+                peak
+            );
+            self.peaks.push(peak);
+        }
+
+        self.is_complete()
+    }
+
+    /// Number of hits the measurement needs
+    pub fn hits_needed(&self) -> usize {
+        self.hits_needed
+    }
+
+    /// Whether enough hits have been measured
+    pub fn is_complete(&self) -> bool {
+        self.peaks.len() >= self.hits_needed
+    }
+
+    /// Peak RMS of each completed hit, in order
+    pub fn peaks(&self) -> &[f64] {
+        &self.peaks
+    }
+
+    /// Discard measured hits and start over
+    pub fn reset(&mut self) {
+        self.current_peak = None;
+        self.peaks.clear();
+    }
+
+    /// Gain derived from the hits measured so far
+    pub fn gain(&self) -> f32 {
+        derive_input_gain(&self.peaks)
+    }
+}
+
+/// Derive an input gain from a set of peak RMS measurements
+///
+/// Uses the median peak so a single accidental clip or a weak hit doesn't
+/// skew the result, then scales it to [`TARGET_PEAK_RMS`]. The gain is
+/// clamped to [`MIN_INPUT_GAIN`, `MAX_INPUT_GAIN`]; with no usable peaks the
+/// input is left untouched (gain 1.0).
+pub fn derive_input_gain(peaks: &[f64]) -> f32 {
+    let mut valid: Vec<f64> = peaks
+        .iter()
+        .copied()
+        .filter(|p| p.is_finite() && *p > 0.0)
+        .collect();
+    if valid.is_empty() {
+        return 1.0;
+    }
+
+    valid.sort_by(f64::total_cmp);
+    let mid = valid.len() / 2;
+    let median = if valid.len().is_multiple_of(2) {
+        (valid[mid - 1] + valid[mid]) / 2.0
+    } else {
+        valid[mid]
+    };
+
+    ((TARGET_PEAK_RMS / median) as f32).clamp(MIN_INPUT_GAIN, MAX_INPUT_GAIN)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gain_derivation_uses_median_peak() {
+        // Quiet device: typical hit peaks around 0.05, one clipped outlier
+        let gain = derive_input_gain(&[0.05, 0.04, 0.9, 0.05, 0.06]);
+        assert!((gain - 5.0).abs() < 1e-4, "gain {gain}");
+
+        // Hot device is attenuated
+        let gain = derive_input_gain(&[0.5, 0.5]);
+        assert!((gain - 0.5).abs() < 1e-4, "gain {gain}");
+    }
+
+    #[test]
+    fn test_gain_derivation_clamps_and_ignores_invalid() {
+        assert_eq!(derive_input_gain(&[0.001, 0.002, 0.001]), MAX_INPUT_GAIN);
+        assert_eq!(derive_input_gain(&[5.0]), MIN_INPUT_GAIN);
+        assert_eq!(derive_input_gain(&[]), 1.0);
+        assert_eq!(derive_input_gain(&[0.0, f64::NAN]), 1.0);
+    }
+
+    #[test]
+    fn test_measurement_records_one_peak_per_hit() {
+        let mut measurement = InputLevelCalibration::new(2, 0.02);
+
+        // First hit rises and falls over several buffers
+        for rms in [0.005, 0.03, 0.1, 0.08, 0.01] {
+            assert!(!measurement.observe_rms(rms));
+        }
+        // Second hit completes the measurement on release
+        assert!(!measurement.observe_rms(0.2));
+        assert!(measurement.observe_rms(0.0));
+
+        assert_eq!(measurement.peaks(), &[0.1, 0.2]);
+        assert!((measurement.gain() - (0.25 / 0.15) as f32).abs() < 1e-4);
+    }
+}
@@ -5,17 +5,23 @@
 // 2. CalibrationProcedure: Manages the sample collection workflow
 // 3. CalibrationProgress: Tracks progress through calibration steps
 // 4. SampleValidator: Validates audio feature samples
+// 5. InputLevelCalibration: Measures loud hits to derive an input auto-gain
+// 6. calibrate_from_wavs: Offline calibration from pre-recorded clips
+// 7. presets: Built-in starter calibrations for common microphone setups
 //
 // The calibration workflow:
 // 1. Create CalibrationProcedure
 // 2. Collect 10 samples each for kick, snare, and hi-hat
 // 3. Finalize to create CalibrationState with computed thresholds
 
+pub mod input_level;
+pub mod offline;
+pub mod presets;
 pub mod procedure;
 pub mod progress;
 pub mod state;
 pub mod validation;
 
 pub use procedure::CalibrationProcedure;
-pub use progress::CalibrationProgress;
+pub use progress::{CalibrationDebugFrame, CalibrationProgress};
 pub use state::CalibrationState;
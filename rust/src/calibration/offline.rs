@@ -0,0 +1,278 @@
+// Offline calibration - build a CalibrationState from pre-recorded clips
+//
+// Instead of a live mic session, each clip is decoded, the onset of the hit
+// is located with the regular OnsetDetector, and features are extracted from
+// the window starting there. The features then go through the same
+// threshold logic as live calibration (`CalibrationState::from_samples`).
+
+use std::path::Path;
+
+use crate::analysis::features::fft::FFT_SIZE;
+use crate::analysis::features::{FeatureExtractor, Features};
+use crate::analysis::onset::OnsetDetector;
+use crate::audio::resample::resample;
+use crate::audio::ENGINE_SAMPLE_RATE;
+use crate::calibration::state::CalibrationState;
+use crate::error::CalibrationError;
+
+/// Calibrate from WAV recordings of each sound
+///
+/// Every list must contain the same number of clips (one hit per clip),
+/// which becomes the per-sound sample count. Clips may be recorded at any
+/// sample rate; they are resampled to the engine rate so the thresholds match
+/// live audio. Multi-channel clips are downmixed to mono.
+///
+/// # Errors
+/// - A clip cannot be read (`Io`), is not a supported WAV file (`Parse`) or
+///   contains no audio
+/// - The lists are empty or have different lengths
+/// - Extracted features fail the usual calibration validation
+pub fn calibrate_from_wavs(
+    kick: Vec<String>,
+    snare: Vec<String>,
+    hihat: Vec<String>,
+) -> Result<CalibrationState, CalibrationError> {
+    let samples_per_sound = kick.len();
+    if samples_per_sound == 0
+        || snare.len() != samples_per_sound
+        || hihat.len() != samples_per_sound
+    {
+        return Err(CalibrationError::InsufficientSamples {
+            required: samples_per_sound.max(1) * 3,
+            collected: kick.len() + snare.len() + hihat.len(),
+            per_sound: Vec::new(),
+        });
+    }
+
+    let kick_samples = features_for_clips(&kick)?;
+    let snare_samples = features_for_clips(&snare)?;
+    let hihat_samples = features_for_clips(&hihat)?;
+
+    tracing::info!(
+        "[OfflineCalibration] Extracted features from {} clips per sound",
+        samples_per_sound
+    );
+
+    CalibrationState::from_samples(
+        &kick_samples,
+        &snare_samples,
+        &hihat_samples,
+        samples_per_sound,
+        CalibrationState::new_default().noise_floor_rms,
+    )
+}
+
+fn features_for_clips(paths: &[String]) -> Result<Vec<Features>, CalibrationError> {
+    paths
+        .iter()
+        .map(|path| {
+            let (samples, sample_rate) = read_wav_mono(Path::new(path))?;
+            let samples = resample(&samples, sample_rate, ENGINE_SAMPLE_RATE);
+            features_at_onset(&samples, ENGINE_SAMPLE_RATE)
+        })
+        .collect()
+}
+
+/// Extract features from the window starting at the clip's onset
+///
+/// Falls back to the first sample reaching half the clip's peak when the
+/// onset detector finds nothing (very short clips give it little history).
+fn features_at_onset(samples: &[f32], sample_rate: u32) -> Result<Features, CalibrationError> {
+    let onset = OnsetDetector::new(sample_rate)
+        .process(samples)
+        .first()
+        .map(|onset| onset.timestamp as usize)
+        .unwrap_or_else(|| first_loud_sample(samples));
+    let start = onset.min(samples.len().saturating_sub(FFT_SIZE));
+
+    let extractor =
+        FeatureExtractor::new(sample_rate).map_err(|err| CalibrationError::InvalidFeatures {
+            reason: err.to_string(),
+        })?;
+    Ok(extractor.extract(&samples[start..]))
+}
+
+fn first_loud_sample(samples: &[f32]) -> usize {
+    let peak = samples.iter().fold(0.0f32, |max, s| max.max(s.abs()));
+    samples
+        .iter()
+        .position(|s| s.abs() >= peak * 0.5)
+        .unwrap_or(0)
+}
+
+fn read_wav_mono(path: &Path) -> Result<(Vec<f32>, u32), CalibrationError> {
+    let mut reader = hound::WavReader::open(path)
+        .map_err(|err| wav_error(err, format!("failed to open {}", path.display())))?;
+    let spec = reader.spec();
+    let channels = spec.channels.max(1) as usize;
+
+    let interleaved = match (spec.sample_format, spec.bits_per_sample) {
+        (hound::SampleFormat::Float, _) => reader.samples::<f32>().collect::<Result<Vec<_>, _>>(),
+        (hound::SampleFormat::Int, 16) => reader
+            .samples::<i16>()
+            .map(|sample| sample.map(|v| v as f32 / i16::MAX as f32))
+            .collect(),
+        (hound::SampleFormat::Int, bits @ (24 | 32)) => {
+            let max = ((1i64 << (bits - 1)) - 1) as f32;
+            reader
+                .samples::<i32>()
+                .map(|sample| sample.map(|v| v as f32 / max))
+                .collect()
+        }
+        (_, bits) => {
+            return Err(CalibrationError::Parse {
+                reason: format!("unsupported bits_per_sample={} in {}", bits, path.display()),
+            })
+        }
+    }
+    .map_err(|err| wav_error(err, format!("error reading {}", path.display())))?;
+
+    if interleaved.is_empty() {
+        return Err(CalibrationError::InvalidFeatures {
+            reason: format!("{} contains no audio", path.display()),
+        });
+    }
+
+    let mono = interleaved
+        .chunks(channels)
+        .map(|frame| frame.iter().sum::<f32>() / frame.len() as f32)
+        .collect();
+    Ok((mono, spec.sample_rate))
+}
+
+/// Classify a WAV read failure: the file system is I/O, anything else is
+/// a malformed or unsupported file
+fn wav_error(err: hound::Error, context: String) -> CalibrationError {
+    match err {
+        hound::Error::IoError(err) => CalibrationError::Io {
+            reason: format!("{context}: {err}"),
+        },
+        err => CalibrationError::Parse {
+            reason: format!("{context}: {err}"),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::path::PathBuf;
+
+    const SR: u32 = 48_000;
+
+    /// 50ms of silence followed by a 150ms decaying hit
+    fn synthetic_hit(mut source: impl FnMut(f32) -> f32) -> Vec<f32> {
+        let silence = (SR as usize) / 20;
+        let hit = (SR as usize) * 3 / 20;
+        let mut samples = vec![0.0; silence];
+        samples.extend((0..hit).map(|i| {
+            let t = i as f32 / SR as f32;
+            (-t * 30.0).exp() * source(t)
+        }));
+        samples
+    }
+
+    fn write_wav(name: &str, samples: &[f32]) -> String {
+        let path: PathBuf = std::env::temp_dir().join(format!(
+            "bbt-offline-calibration-{}-{name}.wav",
+            std::process::id()
+        ));
+        let spec = hound::WavSpec {
+            channels: 1,
+            sample_rate: SR,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let mut writer = hound::WavWriter::create(&path, spec).unwrap();
+        for &sample in samples {
+            writer
+                .write_sample((sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16)
+                .unwrap();
+        }
+        writer.finalize().unwrap();
+        path.to_string_lossy().into_owned()
+    }
+
+    #[test]
+    fn test_calibrate_from_synthetic_wavs() {
+        let mut rng = StdRng::seed_from_u64(406);
+        let mut kick = Vec::new();
+        let mut snare = Vec::new();
+        let mut hihat = Vec::new();
+
+        for i in 0..3 {
+            let freq = 80.0 + i as f32 * 10.0;
+            kick.push(write_wav(
+                &format!("kick{i}"),
+                &synthetic_hit(|t| 0.8 * (2.0 * std::f32::consts::PI * freq * t).sin()),
+            ));
+            snare.push(write_wav(
+                &format!("snare{i}"),
+                &synthetic_hit(|t| {
+                    0.4 * (2.0 * std::f32::consts::PI * 250.0 * t).sin()
+                        + 0.4 * rng.gen_range(-1.0..1.0)
+                }),
+            ));
+            // First difference of white noise: energy concentrated up high
+            let mut previous = 0.0f32;
+            hihat.push(write_wav(
+                &format!("hihat{i}"),
+                &synthetic_hit(|_| {
+                    let noise = rng.gen_range(-1.0f32..1.0);
+                    let sample = 0.4 * (noise - previous);
+                    previous = noise;
+                    sample
+                }),
+            ));
+        }
+
+        let state = calibrate_from_wavs(kick.clone(), snare.clone(), hihat.clone()).unwrap();
+        for path in kick.iter().chain(&snare).chain(&hihat) {
+            let _ = std::fs::remove_file(path);
+        }
+
+        assert!(state.is_calibrated);
+        assert!(
+            state.t_kick_centroid < state.t_snare_centroid,
+            "kick {} vs snare {}",
+            state.t_kick_centroid,
+            state.t_snare_centroid
+        );
+        assert!(
+            state.t_kick_zcr < state.t_hihat_zcr,
+            "kick zcr {} vs hihat zcr {}",
+            state.t_kick_zcr,
+            state.t_hihat_zcr
+        );
+    }
+
+    #[test]
+    fn test_calibrate_from_wavs_rejects_mismatched_lists() {
+        let result = calibrate_from_wavs(vec!["a.wav".into()], vec![], vec!["c.wav".into()]);
+        assert!(matches!(
+            result,
+            Err(CalibrationError::InsufficientSamples { .. })
+        ));
+    }
+
+    #[test]
+    fn test_unreadable_and_malformed_clips_report_io_and_parse() {
+        let missing = std::env::temp_dir()
+            .join(format!("bbt-offline-missing-{}.wav", std::process::id()))
+            .to_string_lossy()
+            .into_owned();
+        assert!(matches!(
+            features_for_clips(&[missing]),
+            Err(CalibrationError::Io { .. })
+        ));
+
+        let garbage =
+            std::env::temp_dir().join(format!("bbt-offline-garbage-{}.wav", std::process::id()));
+        std::fs::write(&garbage, b"not a wav file at all").unwrap();
+        let result = features_for_clips(&[garbage.to_string_lossy().into_owned()]);
+        let _ = std::fs::remove_file(&garbage);
+        assert!(matches!(result, Err(CalibrationError::Parse { .. })));
+    }
+}
@@ -0,0 +1,131 @@
+// Calibration presets - starter thresholds shipped with the app
+//
+// New users get poor results until they calibrate. These presets are
+// hand-tuned starting points for typical microphone setups, embedded in the
+// binary, so the app classifies reasonably out of the box. They are
+// estimates rather than measurements; a personal calibration replaces them
+// as soon as the user completes one.
+
+use crate::calibration::state::CalibrationState;
+use crate::error::CalibrationError;
+
+/// A built-in preset and the device models it suits
+struct Preset {
+    name: &'static str,
+    /// Serialized `CalibrationState`
+    json: &'static str,
+    /// Lower-case substrings of device model names that use this preset
+    model_keywords: &'static [&'static str],
+}
+
+/// Preset used for device models no keyword matches
+const FALLBACK_PRESET: &str = "phone_builtin";
+
+/// Embedded presets, in registry order
+const PRESETS: &[Preset] = &[
+    Preset {
+        name: "phone_builtin",
+        json: include_str!("presets/phone_builtin.json"),
+        model_keywords: &[],
+    },
+    Preset {
+        name: "headset",
+        json: include_str!("presets/headset.json"),
+        model_keywords: &["headset", "headphone", "earphone", "airpods", "buds"],
+    },
+    Preset {
+        name: "usb_condenser",
+        json: include_str!("presets/usb_condenser.json"),
+        model_keywords: &["usb", "condenser", "yeti", "at2020", "scarlett"],
+    },
+];
+
+/// Names of the built-in presets, in registry order
+pub fn preset_names() -> Vec<&'static str> {
+    PRESETS.iter().map(|preset| preset.name).collect()
+}
+
+/// Name of the preset suited to a device or microphone model
+///
+/// Matches `model` case-insensitively against each preset's keywords
+/// (e.g. "Blue Yeti USB" picks `usb_condenser`); anything unrecognized,
+/// including a phone's own model name, gets `phone_builtin`.
+pub fn preset_for_device_model(model: &str) -> &'static str {
+    let model = model.to_lowercase();
+    PRESETS
+        .iter()
+        .find(|preset| {
+            preset
+                .model_keywords
+                .iter()
+                .any(|keyword| model.contains(keyword))
+        })
+        .map_or(FALLBACK_PRESET, |preset| preset.name)
+}
+
+/// Load a built-in preset by name
+///
+/// # Errors
+/// - `UnknownPreset` when `name` is not a known preset
+/// - `Parse` when the embedded JSON is malformed
+/// - `InvalidFeatures` when it does not describe a calibrated state
+pub fn load_preset(name: &str) -> Result<CalibrationState, CalibrationError> {
+    let preset = PRESETS
+        .iter()
+        .find(|preset| preset.name == name)
+        .ok_or_else(|| CalibrationError::UnknownPreset {
+            name: name.to_string(),
+        })?;
+
+    let state: CalibrationState =
+        serde_json::from_str(preset.json).map_err(|err| CalibrationError::Parse {
+            reason: format!("Calibration preset '{name}' is malformed: {err}"),
+        })?;
+    if !state.is_calibrated {
+        return Err(CalibrationError::InvalidFeatures {
+            reason: format!("Calibration preset '{name}' is not marked calibrated"),
+        });
+    }
+    Ok(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorCode;
+
+    #[test]
+    fn test_every_preset_loads_as_calibrated() {
+        for name in preset_names() {
+            let state = load_preset(name).unwrap();
+            assert!(state.is_calibrated, "{name}");
+            assert!(state.t_kick_centroid < state.t_snare_centroid, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_unknown_preset_is_an_error() {
+        let err = load_preset("nokia_3310").unwrap_err();
+        assert_eq!(
+            err,
+            CalibrationError::UnknownPreset {
+                name: "nokia_3310".to_string()
+            }
+        );
+        assert!(err.message().contains("headset"), "{}", err.message());
+    }
+
+    #[test]
+    fn test_preset_lookup_by_device_model() {
+        assert_eq!(
+            preset_for_device_model("Blue Yeti USB Mic"),
+            "usb_condenser"
+        );
+        assert_eq!(preset_for_device_model("Galaxy Buds2 Pro"), "headset");
+        assert_eq!(preset_for_device_model("Pixel 8"), "phone_builtin");
+        assert_eq!(preset_for_device_model(""), "phone_builtin");
+        for name in preset_names() {
+            assert_eq!(preset_for_device_model(name), name);
+        }
+    }
+}
@@ -7,23 +7,47 @@
 // 3. Collect 10 snare drum samples
 // 4. Collect 10 hi-hat samples
 //
+// Optionally, an input level measurement (a few loud hits, reported as the
+// InputLevel phase) runs before the noise floor step to derive a per-device
+// input gain.
+//
 // Each sample is validated before acceptance to ensure quality calibration.
 
 use std::time::Instant;
 
+use tokio::sync::broadcast;
+
 use crate::analysis::features::Features;
+use crate::analysis::ClassificationResult;
+use crate::calibration::input_level::InputLevelCalibration;
 use crate::calibration::progress::{
-    CalibrationGuidance, CalibrationProgress, CalibrationProgressDebug, CalibrationSound,
+    CalibrationDebugFrame, CalibrationGuidance, CalibrationGuidanceReason, CalibrationProgress,
+    CalibrationProgressDebug, CalibrationSound,
 };
 use crate::calibration::state::CalibrationState;
-use crate::error::CalibrationError;
+use crate::config::ExpectedFeatureRanges;
+use crate::error::{CalibrationError, SoundSampleCount};
 
+#[path = "procedure_auto_advance.rs"]
+mod procedure_auto_advance;
 #[path = "procedure_backoff.rs"]
 mod procedure_backoff;
+#[path = "procedure_debug_stream.rs"]
+mod procedure_debug_stream;
+#[path = "procedure_expected_ranges.rs"]
+mod procedure_expected_ranges;
 #[path = "procedure_factory.rs"]
 mod procedure_factory;
+#[path = "procedure_input_level.rs"]
+mod procedure_input_level;
 #[path = "procedure_manual_accept.rs"]
 mod procedure_manual_accept;
+#[path = "procedure_preview.rs"]
+mod procedure_preview;
+#[path = "procedure_retry.rs"]
+mod procedure_retry;
+#[path = "procedure_skip.rs"]
+mod procedure_skip;
 
 use procedure_backoff::AdaptiveBackoff;
 use procedure_manual_accept::CandidateBuffer;
@@ -32,8 +56,11 @@ use procedure_manual_accept::CandidateBuffer;
 /// This prevents rapid-fire detection from noise
 const DEFAULT_MIN_SAMPLE_INTERVAL_MS: u128 = 250;
 
-/// Number of RMS samples needed for noise floor calibration
-const NOISE_FLOOR_SAMPLES_NEEDED: u8 = 30;
+/// Default number of RMS samples needed for noise floor calibration
+pub const DEFAULT_NOISE_FLOOR_SAMPLES_NEEDED: u8 = 30;
+
+/// Default noise floor threshold above which the room is flagged as too noisy
+pub const DEFAULT_NOISE_FLOOR_CEILING: f64 = 0.05;
 
 /// Multiplier applied to noise floor RMS to set onset threshold (keep conservative)
 #[cfg(target_os = "android")]
@@ -63,6 +90,8 @@ pub struct CalibrationProcedure {
     hihat_samples: Vec<Features>,
     /// Current sound being calibrated
     current_sound: CalibrationSound,
+    /// Sounds the user chose not to calibrate
+    skipped_sounds: Vec<CalibrationSound>,
     /// Samples needed per sound (default: 10)
     samples_needed: u8,
     /// Last time a sample was accepted (for debouncing)
@@ -71,6 +100,12 @@ pub struct CalibrationProcedure {
     min_sample_interval_ms: u128,
     /// Collected RMS values during noise floor phase
     noise_floor_samples: Vec<f64>,
+    /// RMS samples needed to complete the noise floor phase
+    noise_floor_samples_needed: u8,
+    /// When the first noise floor sample arrived (for remaining-time estimates)
+    noise_floor_started_at: Option<Instant>,
+    /// Noise floor threshold above which the environment is flagged as too noisy
+    noise_floor_ceiling: f64,
     /// Calculated noise floor RMS threshold (set after noise floor phase)
     noise_floor_threshold: Option<f64>,
     /// Whether waiting for user confirmation to proceed to next phase
@@ -91,6 +126,25 @@ pub struct CalibrationProcedure {
     debug_seq: u64,
     /// Snapshot of last features (for debug payloads)
     last_features: Option<Features>,
+    /// Advance automatically once a sound's samples are complete and consistent
+    auto_advance: bool,
+    /// The current sound was entered by auto-advance; cleared once it
+    /// collects a sample or the procedure moves on another way
+    auto_advanced: bool,
+    /// Sound of the last progress event, for flagging phase transitions
+    reported_sound: Option<CalibrationSound>,
+    /// Optional input level measurement run before the noise floor phase
+    input_level: Option<InputLevelCalibration>,
+    /// Optional high-rate feature stream for tuning UIs
+    debug_stream: Option<broadcast::Sender<CalibrationDebugFrame>>,
+    /// Sequence number of the next debug frame
+    debug_frame_seq: u64,
+    /// Optional stream of provisional classifications for calibration hits
+    preview_stream: Option<broadcast::Sender<ClassificationResult>>,
+    /// Typical feature ranges per sound; `None` disables timbre warnings
+    expected_ranges: Option<ExpectedFeatureRanges>,
+    /// UnexpectedTimbre warning for the last accepted sample, if any
+    timbre_guidance: Option<CalibrationGuidance>,
 }
 
 impl CalibrationProcedure {
@@ -116,10 +170,13 @@ impl CalibrationProcedure {
             return Ok(true);
         }
 
+        if self.noise_floor_samples.is_empty() {
+            self.noise_floor_started_at = Some(Instant::now());
+        }
         self.noise_floor_samples.push(rms);
 
         // Check if we have enough samples
-        if self.noise_floor_samples.len() >= NOISE_FLOOR_SAMPLES_NEEDED as usize {
+        if self.noise_floor_samples.len() >= self.noise_floor_samples_needed as usize {
             // Calculate threshold: mean RMS * multiplier, with minimum floor
             let mean_rms: f64 = self.noise_floor_samples.iter().sum::<f64>()
                 / self.noise_floor_samples.len() as f64;
@@ -142,6 +199,13 @@ impl CalibrationProcedure {
                 "[CalibrationProcedure] Noise floor calibration complete. Mean RMS: {:.4}, Max RMS: {:.4}, Threshold: {:.4}. Waiting for user confirmation.",
                 mean_rms, max_rms, threshold
             );
+            if self.is_too_noisy() {
+                tracing::warn!(
+                    "[CalibrationProcedure] Noise floor threshold {:.4} exceeds ceiling {:.4}; environment too noisy",
+                    threshold,
+                    self.noise_floor_ceiling
+                );
+            }
 
             return Ok(true);
         }
@@ -164,6 +228,61 @@ impl CalibrationProcedure {
         self.current_sound == CalibrationSound::NoiseFloor
     }
 
+    /// Set the noise floor threshold above which the room is flagged as too noisy
+    pub fn with_noise_floor_ceiling(mut self, ceiling: f64) -> Self {
+        self.noise_floor_ceiling = ceiling;
+        self
+    }
+
+    /// Whether the measured noise floor exceeds the configured ceiling
+    pub fn is_too_noisy(&self) -> bool {
+        self.noise_floor_threshold
+            .is_some_and(|threshold| threshold > self.noise_floor_ceiling)
+    }
+
+    /// Guidance warning the user about a loud environment, while the noise
+    /// floor result is awaiting confirmation
+    fn noise_floor_guidance(&self) -> Option<CalibrationGuidance> {
+        if self.current_sound != CalibrationSound::NoiseFloor || !self.is_too_noisy() {
+            return None;
+        }
+        Some(CalibrationGuidance {
+            sound: CalibrationSound::NoiseFloor,
+            reason: CalibrationGuidanceReason::TooNoisy,
+            level: self.noise_floor_threshold.unwrap_or_default() as f32,
+            misses: 0,
+        })
+    }
+
+    /// Set how many RMS samples the noise floor phase collects (min 1)
+    pub fn with_noise_floor_samples(mut self, samples_needed: u8) -> Self {
+        self.noise_floor_samples_needed = samples_needed.max(1);
+        self
+    }
+
+    /// Estimate how long the user still has to stay quiet
+    ///
+    /// Extrapolates from the rate at which noise floor samples (one per
+    /// analysis buffer) have arrived so far. `None` outside the noise floor
+    /// phase or before two samples have been collected.
+    pub fn noise_floor_remaining_ms(&self) -> Option<u32> {
+        if self.current_sound != CalibrationSound::NoiseFloor {
+            return None;
+        }
+        let collected = self.noise_floor_samples.len();
+        let remaining = (self.noise_floor_samples_needed as usize).saturating_sub(collected);
+        if remaining == 0 {
+            return Some(0);
+        }
+        if collected < 2 {
+            return None;
+        }
+
+        let elapsed_ms = self.noise_floor_started_at?.elapsed().as_secs_f64() * 1000.0;
+        let per_sample_ms = elapsed_ms / (collected - 1) as f64;
+        Some((per_sample_ms * remaining as f64).round() as u32)
+    }
+
     /// Current RMS detection threshold derived from measured noise floor
     pub fn detection_threshold(&self) -> f64 {
         let noise_floor = self.noise_floor_threshold.unwrap_or(MIN_RMS_THRESHOLD);
@@ -212,8 +331,8 @@ impl CalibrationProcedure {
             });
         }
 
-        // Reject if still in noise floor phase
-        if self.current_sound == CalibrationSound::NoiseFloor {
+        // Reject if still measuring levels (input level or noise floor)
+        if !self.current_sound.is_sound_phase() {
             tracing::info!(
                 "[CalibrationProcedure] Reject {:?}: noise floor not complete (rms {:.4}, centroid {:.1}, zcr {:.3})",
                 current_sound,
@@ -227,6 +346,12 @@ impl CalibrationProcedure {
             });
         }
 
+        if self.is_skipped(current_sound) {
+            return Err(CalibrationError::InvalidFeatures {
+                reason: format!("{} was skipped", current_sound.display_name()),
+            });
+        }
+
         // Snapshot last observed values for instrumentation
         self.last_centroid = Some(features.centroid);
         self.last_zcr = Some(features.zcr);
@@ -307,23 +432,16 @@ impl CalibrationProcedure {
         }
 
         // Add to current sound collection
-        match self.current_sound {
-            CalibrationSound::NoiseFloor => {
-                // Already handled above
-                unreachable!()
-            }
-            CalibrationSound::Kick => {
-                Self::add_to_collection(&mut self.kick_samples, features, self.samples_needed)?;
-            }
-            CalibrationSound::Snare => {
-                Self::add_to_collection(&mut self.snare_samples, features, self.samples_needed)?;
-            }
-            CalibrationSound::HiHat => {
-                Self::add_to_collection(&mut self.hihat_samples, features, self.samples_needed)?;
-            }
-        }
+        let samples_needed = self.samples_needed;
+        Self::add_to_collection(
+            self.collection_for_sound(current_sound)?,
+            features,
+            samples_needed,
+        )?;
         self.clear_candidate_for_sound(current_sound);
         self.backoff.record_success(self.current_sound);
+        self.auto_advanced = false;
+        self.check_expected_timbre(current_sound, &features, rms);
 
         // Log successful sample collection
         tracing::info!(
@@ -344,7 +462,8 @@ impl CalibrationProcedure {
             detection_threshold
         );
 
-        // Set waiting_for_confirmation when current sound is complete (DON'T auto-advance)
+        // Set waiting_for_confirmation when current sound is complete, unless
+        // auto-advance is enabled and the samples are consistent
         if self.is_current_sound_complete() {
             self.waiting_for_confirmation = true;
             tracing::info!(
@@ -352,6 +471,7 @@ impl CalibrationProcedure {
                 self.current_sound,
                 self.get_current_sound_count()
             );
+            self.try_auto_advance();
         }
 
         Ok(())
@@ -367,6 +487,7 @@ impl CalibrationProcedure {
             return Err(CalibrationError::InsufficientSamples {
                 required: samples_needed as usize,
                 collected: collection.len(),
+                per_sound: Vec::new(),
             });
         }
         collection.push(features);
@@ -376,9 +497,13 @@ impl CalibrationProcedure {
     /// Get current calibration progress
     pub fn get_progress(&mut self) -> CalibrationProgress {
         let (samples_collected, samples_needed) = match self.current_sound {
+            CalibrationSound::InputLevel => (
+                self.input_level_hits_measured() as u8,
+                self.input_level_hits_needed() as u8,
+            ),
             CalibrationSound::NoiseFloor => (
                 self.noise_floor_samples.len() as u8,
-                NOISE_FLOOR_SAMPLES_NEEDED,
+                self.noise_floor_samples_needed,
             ),
             _ => (self.get_current_sound_count() as u8, self.samples_needed),
         };
@@ -390,9 +515,25 @@ impl CalibrationProcedure {
             self.waiting_for_confirmation,
         )
         .with_manual_accept(self.manual_accept_available())
+        .with_guidance(
+            self.noise_floor_guidance()
+                .or_else(|| self.timbre_guidance.clone()),
+        )
+        .with_auto_advanced(self.auto_advanced)
+        .with_phase_changed(self.take_phase_changed())
+        .with_remaining_ms(self.noise_floor_remaining_ms())
         .with_debug(self.debug_payload(None, None, None))
     }
 
+    /// Whether the sound changed since the last progress event
+    ///
+    /// The first event only records the starting sound, so each transition
+    /// is reported exactly once.
+    fn take_phase_changed(&mut self) -> bool {
+        let previous = self.reported_sound.replace(self.current_sound);
+        previous.is_some_and(|sound| sound != self.current_sound)
+    }
+
     /// Get progress with an attached guidance payload
     pub fn get_progress_with_guidance(
         &mut self,
@@ -409,7 +550,9 @@ impl CalibrationProcedure {
         rms: Option<f64>,
         max_amp: Option<f32>,
     ) -> CalibrationProgress {
-        self.get_progress()
+        let progress = self.get_progress();
+        let guidance = guidance.or_else(|| progress.guidance.clone());
+        progress
             .with_guidance(guidance)
             .with_debug(self.debug_payload(features, rms, max_amp))
     }
@@ -417,6 +560,7 @@ impl CalibrationProcedure {
     /// Get the count of samples for the current sound
     fn get_current_sound_count(&self) -> usize {
         match self.current_sound {
+            CalibrationSound::InputLevel => self.input_level_hits_measured(),
             CalibrationSound::NoiseFloor => self.noise_floor_samples.len(),
             CalibrationSound::Kick => self.kick_samples.len(),
             CalibrationSound::Snare => self.snare_samples.len(),
@@ -427,32 +571,55 @@ impl CalibrationProcedure {
     /// Check if current sound collection is complete
     fn is_current_sound_complete(&self) -> bool {
         match self.current_sound {
+            // Completing the measurement moves straight on to the noise floor
+            CalibrationSound::InputLevel => false,
             CalibrationSound::NoiseFloor => {
-                self.noise_floor_samples.len() >= NOISE_FLOOR_SAMPLES_NEEDED as usize
+                self.noise_floor_samples.len() >= self.noise_floor_samples_needed as usize
             }
             _ => self.get_current_sound_count() >= self.samples_needed as usize,
         }
     }
 
-    /// Check if entire calibration is complete
+    /// Check if entire calibration is complete (skipped sounds excepted)
     pub fn is_complete(&self) -> bool {
-        self.kick_samples.len() == self.samples_needed as usize
-            && self.snare_samples.len() == self.samples_needed as usize
-            && self.hihat_samples.len() == self.samples_needed as usize
+        self.sample_counts()
+            .iter()
+            .all(|count| count.collected == count.needed)
+    }
+
+    /// Collected vs needed samples for kick, snare and hi-hat
+    ///
+    /// Skipped sounds are left out.
+    pub fn sample_counts(&self) -> Vec<SoundSampleCount> {
+        let needed = self.samples_needed as usize;
+        [
+            (CalibrationSound::Kick, self.kick_samples.len()),
+            (CalibrationSound::Snare, self.snare_samples.len()),
+            (CalibrationSound::HiHat, self.hihat_samples.len()),
+        ]
+        .into_iter()
+        .filter(|(sound, _)| !self.is_skipped(*sound))
+        .map(|(sound, collected)| SoundSampleCount {
+            sound,
+            collected,
+            needed,
+        })
+        .collect()
     }
 
     /// Finalize calibration and create CalibrationState
     ///
     /// # Returns
     /// * `Ok(CalibrationState)` - Successfully calibrated state
-    /// * `Err(CalibrationError)` - Calibration incomplete or invalid
+    /// * `Err(CalibrationError)` - Calibration incomplete or invalid; when samples
+    ///   are missing the error lists the per-sound counts
     pub fn finalize(&self) -> Result<CalibrationState, CalibrationError> {
         if !self.is_complete() {
+            let per_sound = self.sample_counts();
             return Err(CalibrationError::InsufficientSamples {
-                required: self.samples_needed as usize * 3, // Total for all sounds
-                collected: self.kick_samples.len()
-                    + self.snare_samples.len()
-                    + self.hihat_samples.len(),
+                required: per_sound.iter().map(|count| count.needed).sum(),
+                collected: per_sound.iter().map(|count| count.collected).sum(),
+                per_sound,
             });
         }
 
@@ -463,13 +630,18 @@ impl CalibrationProcedure {
             self.noise_floor_threshold, noise_floor
         );
 
-        CalibrationState::from_samples(
+        let state = CalibrationState::from_samples_skipping(
             &self.kick_samples,
             &self.snare_samples,
             &self.hihat_samples,
             self.samples_needed as usize,
             noise_floor,
-        )
+            &self.skipped_sounds,
+        )?;
+        Ok(match self.measured_input_gain() {
+            Some(gain) => state.with_input_gain(gain),
+            None => state,
+        })
     }
 
     /// Reset the calibration procedure
@@ -477,11 +649,18 @@ impl CalibrationProcedure {
         self.kick_samples.clear();
         self.snare_samples.clear();
         self.hihat_samples.clear();
+        self.skipped_sounds.clear();
         self.noise_floor_samples.clear();
+        self.noise_floor_started_at = None;
         self.noise_floor_threshold = None;
-        self.current_sound = CalibrationSound::NoiseFloor; // Start over from noise floor
+        self.current_sound = self.first_phase(); // Start over from the first measurement
         self.last_sample_time = None;
         self.waiting_for_confirmation = false;
+        self.auto_advanced = false;
+        self.timbre_guidance = None;
+        if let Some(measurement) = self.input_level.as_mut() {
+            measurement.reset();
+        }
         self.backoff.update_noise_floor(self.noise_floor_threshold);
         self.clear_all_candidates();
     }
@@ -519,6 +698,8 @@ impl CalibrationProcedure {
         }
 
         self.waiting_for_confirmation = false;
+        self.auto_advanced = false;
+        self.timbre_guidance = None;
 
         if let Some(next_sound) = self.current_sound.next() {
             tracing::info!(
@@ -560,8 +741,14 @@ impl CalibrationProcedure {
 
         // Clear samples for current phase
         match self.current_sound {
+            CalibrationSound::InputLevel => {
+                if let Some(measurement) = self.input_level.as_mut() {
+                    measurement.reset();
+                }
+            }
             CalibrationSound::NoiseFloor => {
                 self.noise_floor_samples.clear();
+                self.noise_floor_started_at = None;
                 self.noise_floor_threshold = None;
             }
             CalibrationSound::Kick => {
@@ -576,6 +763,8 @@ impl CalibrationProcedure {
         }
 
         self.waiting_for_confirmation = false;
+        self.auto_advanced = false;
+        self.timbre_guidance = None;
         self.last_sample_time = None; // Reset debounce timer
         self.backoff.reset_for_sound(self.current_sound);
         self.clear_candidate_for_sound(self.current_sound);
@@ -587,6 +776,11 @@ impl CalibrationProcedure {
         self.current_sound
     }
 
+    /// Samples collected per sound (kick, snare, hi-hat)
+    pub fn samples_needed(&self) -> u8 {
+        self.samples_needed
+    }
+
     /// Update last-seen feature snapshot for instrumentation without affecting gates.
     ///
     /// Used by the analysis thread to push live readings even when no onsets
@@ -0,0 +1,86 @@
+use crate::analysis::features::Features;
+use crate::calibration::progress::CalibrationSound;
+
+use super::CalibrationProcedure;
+
+/// Maximum coefficient of variation (std / mean) of centroid and ZCR for the
+/// collected samples to count as consistent enough to auto-advance.
+pub(super) const AUTO_ADVANCE_MAX_CV: f32 = 0.2;
+
+impl CalibrationProcedure {
+    /// Enable or disable hands-free advancing between sounds
+    ///
+    /// When enabled, a sound whose collected samples are consistent (see
+    /// [`AUTO_ADVANCE_MAX_CV`]) moves on to the next sound as soon as the
+    /// sample count is met, instead of waiting for `confirm_and_advance()`.
+    /// The final sound still waits so the user can review before finishing.
+    pub fn with_auto_advance(mut self, enabled: bool) -> Self {
+        self.auto_advance = enabled;
+        self
+    }
+
+    /// Whether auto-advance is enabled
+    pub fn auto_advance_enabled(&self) -> bool {
+        self.auto_advance
+    }
+
+    /// Advance without confirmation if the current sound qualifies
+    ///
+    /// Called once the current sound's sample count is met. Returns `true`
+    /// when the procedure moved on to the next sound.
+    pub(super) fn try_auto_advance(&mut self) -> bool {
+        if !self.auto_advance || self.current_sound.next().is_none() {
+            return false;
+        }
+
+        let samples = match self.current_sound {
+            CalibrationSound::Kick => &self.kick_samples,
+            CalibrationSound::Snare => &self.snare_samples,
+            CalibrationSound::HiHat => &self.hihat_samples,
+            CalibrationSound::InputLevel | CalibrationSound::NoiseFloor => return false,
+        };
+        if !samples_are_consistent(samples) {
+            tracing::info!(
+                "[CalibrationProcedure] {:?} samples too varied to auto-advance; waiting for confirmation",
+                self.current_sound
+            );
+            return false;
+        }
+
+        let from = self.current_sound;
+        match self.confirm_and_advance() {
+            Ok(true) => {
+                tracing::info!(
+                    "[CalibrationProcedure] Auto-advanced from {:?} to {:?}",
+                    from,
+                    self.current_sound
+                );
+                self.auto_advanced = true;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+fn samples_are_consistent(samples: &[Features]) -> bool {
+    if samples.is_empty() {
+        return false;
+    }
+    let centroid_cv = coefficient_of_variation(samples.iter().map(|f| f.centroid));
+    let zcr_cv = coefficient_of_variation(samples.iter().map(|f| f.zcr));
+    centroid_cv <= AUTO_ADVANCE_MAX_CV && zcr_cv <= AUTO_ADVANCE_MAX_CV
+}
+
+fn coefficient_of_variation(values: impl Iterator<Item = f32> + Clone) -> f32 {
+    let count = values.clone().count() as f32;
+    let mean = values.clone().sum::<f32>() / count;
+    let variance = values.map(|v| (v - mean).powi(2)).sum::<f32>() / count;
+    if variance <= f32::EPSILON {
+        return 0.0;
+    }
+    if mean.abs() <= f32::EPSILON {
+        return f32::INFINITY;
+    }
+    variance.sqrt() / mean.abs()
+}
@@ -104,7 +104,7 @@ impl AdaptiveBackoff {
             CalibrationSound::Kick => Some(0),
             CalibrationSound::Snare => Some(1),
             CalibrationSound::HiHat => Some(2),
-            CalibrationSound::NoiseFloor => None,
+            CalibrationSound::InputLevel | CalibrationSound::NoiseFloor => None,
         }
     }
 
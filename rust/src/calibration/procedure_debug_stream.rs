@@ -0,0 +1,39 @@
+use tokio::sync::broadcast;
+
+use crate::analysis::features::Features;
+use crate::calibration::progress::CalibrationDebugFrame;
+
+use super::CalibrationProcedure;
+
+impl CalibrationProcedure {
+    /// Publish a live feature frame for every analysed buffer
+    pub fn set_debug_stream(&mut self, tx: broadcast::Sender<CalibrationDebugFrame>) {
+        self.debug_stream = Some(tx);
+    }
+
+    /// Whether a debug stream is attached
+    pub fn debug_stream_enabled(&self) -> bool {
+        self.debug_stream.is_some()
+    }
+
+    /// Send one debug frame with the latest window's features
+    ///
+    /// Sent whether or not the window would be accepted as a sample. No-op
+    /// without an attached stream; frames without subscribers are dropped.
+    pub fn emit_debug_frame(&mut self, features: &Features, rms: f64, max_amp: f32) {
+        let Some(tx) = self.debug_stream.as_ref() else {
+            return;
+        };
+
+        let frame = CalibrationDebugFrame {
+            seq: self.debug_frame_seq,
+            sound: self.current_sound,
+            centroid: features.centroid,
+            zcr: features.zcr,
+            rms,
+            max_amp,
+        };
+        self.debug_frame_seq = self.debug_frame_seq.wrapping_add(1);
+        let _ = tx.send(frame);
+    }
+}
@@ -0,0 +1,66 @@
+use crate::analysis::features::Features;
+use crate::calibration::progress::{
+    CalibrationGuidance, CalibrationGuidanceReason, CalibrationSound,
+};
+use crate::config::{ExpectedFeatureRanges, FeatureRange};
+
+use super::CalibrationProcedure;
+
+impl CalibrationProcedure {
+    /// Warn when accepted samples fall outside typical feature ranges
+    ///
+    /// Calibration stays user-centric: out-of-range samples are still
+    /// accepted, but progress updates carry
+    /// `CalibrationGuidanceReason::UnexpectedTimbre` until the next accepted
+    /// sample, so the UI can point out that, say, the "kick" sounds more
+    /// like a snare. `None` disables it.
+    pub fn with_expected_ranges(mut self, ranges: Option<ExpectedFeatureRanges>) -> Self {
+        self.expected_ranges = ranges;
+        self
+    }
+
+    /// Check an accepted sample against the expected range for its sound
+    ///
+    /// Replaces the previous sample's verdict, so the warning clears once a
+    /// typical sample is accepted.
+    pub(super) fn check_expected_timbre(
+        &mut self,
+        sound: CalibrationSound,
+        features: &Features,
+        rms: f64,
+    ) {
+        self.timbre_guidance = None;
+        let Some(range) = self
+            .expected_ranges
+            .as_ref()
+            .and_then(|ranges| range_for(ranges, sound))
+        else {
+            return;
+        };
+        if range.contains(features) {
+            return;
+        }
+
+        tracing::info!(
+            "[CalibrationProcedure] {:?} sample outside expected range: centroid {:.1} Hz, zcr {:.3}",
+            sound,
+            features.centroid,
+            features.zcr
+        );
+        self.timbre_guidance = Some(CalibrationGuidance {
+            sound,
+            reason: CalibrationGuidanceReason::UnexpectedTimbre,
+            level: rms as f32,
+            misses: 0,
+        });
+    }
+}
+
+fn range_for(ranges: &ExpectedFeatureRanges, sound: CalibrationSound) -> Option<&FeatureRange> {
+    match sound {
+        CalibrationSound::Kick => Some(&ranges.kick),
+        CalibrationSound::Snare => Some(&ranges.snare),
+        CalibrationSound::HiHat => Some(&ranges.hihat),
+        CalibrationSound::InputLevel | CalibrationSound::NoiseFloor => None,
+    }
+}
@@ -2,7 +2,8 @@
 use super::MIN_RMS_THRESHOLD;
 use super::{
     AdaptiveBackoff, CalibrationProcedure, CalibrationSound, CandidateBuffer,
-    DEFAULT_MIN_SAMPLE_INTERVAL_MS,
+    DEFAULT_MIN_SAMPLE_INTERVAL_MS, DEFAULT_NOISE_FLOOR_CEILING,
+    DEFAULT_NOISE_FLOOR_SAMPLES_NEEDED,
 };
 
 impl CalibrationProcedure {
@@ -25,10 +26,14 @@ impl CalibrationProcedure {
             snare_samples: Vec::new(),
             hihat_samples: Vec::new(),
             current_sound: CalibrationSound::NoiseFloor, // Start with noise floor
+            skipped_sounds: Vec::new(),
             samples_needed,
             last_sample_time: None,
             min_sample_interval_ms,
             noise_floor_samples: Vec::new(),
+            noise_floor_samples_needed: DEFAULT_NOISE_FLOOR_SAMPLES_NEEDED,
+            noise_floor_started_at: None,
+            noise_floor_ceiling: DEFAULT_NOISE_FLOOR_CEILING,
             noise_floor_threshold: None,
             waiting_for_confirmation: false,
             backoff: AdaptiveBackoff::new(None),
@@ -39,6 +44,15 @@ impl CalibrationProcedure {
             last_max_amp: None,
             debug_seq: 0,
             last_features: None,
+            auto_advance: false,
+            auto_advanced: false,
+            reported_sound: None,
+            input_level: None,
+            debug_stream: None,
+            debug_frame_seq: 0,
+            preview_stream: None,
+            expected_ranges: None,
+            timbre_guidance: None,
         }
     }
 
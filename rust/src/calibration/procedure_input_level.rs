@@ -0,0 +1,96 @@
+use crate::calibration::input_level::{InputLevelCalibration, DEFAULT_INPUT_LEVEL_GATE_RMS};
+use crate::calibration::progress::CalibrationSound;
+
+use super::CalibrationProcedure;
+
+impl CalibrationProcedure {
+    /// Measure the input level before the noise floor phase
+    ///
+    /// With `hits > 0` the procedure starts in the `InputLevel` phase and
+    /// waits for that many loud hits, deriving an input gain from their peak
+    /// RMS; `finalize()` then stores it in the calibration state. `0` skips
+    /// the measurement.
+    pub fn with_input_level_hits(mut self, hits: usize) -> Self {
+        self.input_level =
+            (hits > 0).then(|| InputLevelCalibration::new(hits, DEFAULT_INPUT_LEVEL_GATE_RMS));
+        if !self.current_sound.is_sound_phase() {
+            self.current_sound = self.first_phase();
+        }
+        self
+    }
+
+    /// Phase the procedure starts in (and returns to on reset)
+    pub(super) fn first_phase(&self) -> CalibrationSound {
+        if self.input_level.is_some() {
+            CalibrationSound::InputLevel
+        } else {
+            CalibrationSound::NoiseFloor
+        }
+    }
+
+    /// Whether the procedure is still waiting for loud hits to measure
+    pub fn is_measuring_input_level(&self) -> bool {
+        self.current_sound == CalibrationSound::InputLevel
+    }
+
+    /// Feed the RMS of one raw (un-gained) input buffer to the measurement
+    ///
+    /// Moves on to the noise floor phase once enough hits are measured.
+    /// Returns `true` when the measurement is complete (or none is pending).
+    pub fn add_input_level_sample(&mut self, rms: f64) -> bool {
+        if !self.is_measuring_input_level() {
+            return true;
+        }
+        let Some(measurement) = self.input_level.as_mut() else {
+            return true;
+        };
+        let complete = measurement.observe_rms(rms);
+        if complete {
+            tracing::info!(
+                "[CalibrationProcedure] Input level measured: peaks={:?}, gain={:.2}",
+                measurement.peaks(),
+                measurement.gain()
+            );
+            self.current_sound = CalibrationSound::NoiseFloor;
+        }
+        complete
+    }
+
+    /// Number of hits measured so far (0 without a measurement)
+    pub fn input_level_hits_measured(&self) -> usize {
+        self.input_level
+            .as_ref()
+            .map_or(0, |measurement| measurement.peaks().len())
+    }
+
+    /// Hits the measurement needs (0 without a measurement)
+    pub(super) fn input_level_hits_needed(&self) -> usize {
+        self.input_level
+            .as_ref()
+            .map_or(0, InputLevelCalibration::hits_needed)
+    }
+
+    /// Gain derived by a completed input level measurement, if one ran
+    pub fn measured_input_gain(&self) -> Option<f32> {
+        self.input_level
+            .as_ref()
+            .filter(|measurement| measurement.is_complete())
+            .map(InputLevelCalibration::gain)
+    }
+
+    /// Gain the analysis thread should apply to audio for this procedure
+    ///
+    /// Unity (raw input) while the input level is measured, then the
+    /// measured gain, so the noise floor and samples are collected at the
+    /// gain `finalize()` stores. `None` without a measurement, leaving the
+    /// current calibration's gain in effect.
+    pub fn collection_input_gain(&self) -> Option<f32> {
+        self.input_level.as_ref().map(|measurement| {
+            if measurement.is_complete() {
+                measurement.gain()
+            } else {
+                1.0
+            }
+        })
+    }
+}
@@ -20,7 +20,7 @@ impl CandidateBuffer {
             CalibrationSound::Kick => self.kick = Some(features),
             CalibrationSound::Snare => self.snare = Some(features),
             CalibrationSound::HiHat => self.hihat = Some(features),
-            CalibrationSound::NoiseFloor => {}
+            CalibrationSound::InputLevel | CalibrationSound::NoiseFloor => {}
         }
     }
 
@@ -29,7 +29,7 @@ impl CandidateBuffer {
             CalibrationSound::Kick => self.kick.take(),
             CalibrationSound::Snare => self.snare.take(),
             CalibrationSound::HiHat => self.hihat.take(),
-            CalibrationSound::NoiseFloor => None,
+            CalibrationSound::InputLevel | CalibrationSound::NoiseFloor => None,
         }
     }
 
@@ -38,7 +38,7 @@ impl CandidateBuffer {
             CalibrationSound::Kick => self.kick.is_some(),
             CalibrationSound::Snare => self.snare.is_some(),
             CalibrationSound::HiHat => self.hihat.is_some(),
-            CalibrationSound::NoiseFloor => false,
+            CalibrationSound::InputLevel | CalibrationSound::NoiseFloor => false,
         }
     }
 
@@ -62,10 +62,9 @@ impl CalibrationProcedure {
     pub fn manual_accept_last_candidate(
         &mut self,
     ) -> Result<CalibrationProgress, CalibrationError> {
-        if self.current_sound == CalibrationSound::NoiseFloor {
-            return Err(CalibrationError::InvalidFeatures {
-                reason: "Manual accept is only available during sound collection phases."
-                    .to_string(),
+        if !self.current_sound.is_sound_phase() {
+            return Err(CalibrationError::NotASoundPhase {
+                phase: self.current_sound,
             });
         }
 
@@ -85,7 +84,7 @@ impl CalibrationProcedure {
                 })?;
 
         let samples_needed = self.samples_needed;
-        let collection = self.collection_for_sound(sound);
+        let collection = self.collection_for_sound(sound)?;
         Self::add_to_collection(collection, candidate, samples_needed)?;
         self.backoff.record_success(sound);
         self.last_sample_time = Some(Instant::now());
@@ -123,12 +122,18 @@ impl CalibrationProcedure {
         self.last_candidates.clear_all();
     }
 
-    fn collection_for_sound(&mut self, sound: CalibrationSound) -> &mut Vec<Features> {
+    /// Samples collected for `sound`; level measurements have none
+    pub(super) fn collection_for_sound(
+        &mut self,
+        sound: CalibrationSound,
+    ) -> Result<&mut Vec<Features>, CalibrationError> {
         match sound {
-            CalibrationSound::Kick => &mut self.kick_samples,
-            CalibrationSound::Snare => &mut self.snare_samples,
-            CalibrationSound::HiHat => &mut self.hihat_samples,
-            CalibrationSound::NoiseFloor => unreachable!("Noise floor has no feature collection"),
+            CalibrationSound::Kick => Ok(&mut self.kick_samples),
+            CalibrationSound::Snare => Ok(&mut self.snare_samples),
+            CalibrationSound::HiHat => Ok(&mut self.hihat_samples),
+            CalibrationSound::InputLevel | CalibrationSound::NoiseFloor => {
+                Err(CalibrationError::NotASoundPhase { phase: sound })
+            }
         }
     }
 }
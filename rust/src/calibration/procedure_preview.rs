@@ -0,0 +1,28 @@
+use tokio::sync::broadcast;
+
+use crate::analysis::ClassificationResult;
+
+use super::CalibrationProcedure;
+
+impl CalibrationProcedure {
+    /// Broadcast provisional classifications of calibration hits
+    pub fn set_preview_stream(&mut self, tx: broadcast::Sender<ClassificationResult>) {
+        self.preview_stream = Some(tx);
+    }
+
+    /// Whether a preview stream is attached
+    pub fn preview_enabled(&self) -> bool {
+        self.preview_stream.is_some()
+    }
+
+    /// Send one provisional result for a calibration hit
+    ///
+    /// The hit still goes to the procedure as a sample; the preview only
+    /// shows what the current classifier makes of it. No-op without an
+    /// attached stream.
+    pub fn emit_preview(&self, result: ClassificationResult) {
+        if let Some(tx) = self.preview_stream.as_ref() {
+            let _ = tx.send(result);
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use crate::analysis::features::Features;
+use crate::calibration::progress::CalibrationSound;
+use crate::error::CalibrationError;
+
+use super::CalibrationProcedure;
+
+impl CalibrationProcedure {
+    /// Retry the current sound but keep the `keep` best samples
+    ///
+    /// Unlike `retry_current_sound()`, which discards everything, this keeps
+    /// the samples closest to the per-feature median (centroid and ZCR) and
+    /// drops the outliers, so the user only has to top up the remainder.
+    /// Kept samples retain their original order.
+    ///
+    /// Returns the number of samples kept.
+    pub fn retry_keep_best(&mut self, keep: usize) -> Result<usize, CalibrationError> {
+        let samples = match self.current_sound {
+            CalibrationSound::Kick => &mut self.kick_samples,
+            CalibrationSound::Snare => &mut self.snare_samples,
+            CalibrationSound::HiHat => &mut self.hihat_samples,
+            CalibrationSound::InputLevel | CalibrationSound::NoiseFloor => {
+                return Err(CalibrationError::InvalidFeatures {
+                    reason: "Keep-best retry is only available for sound collection phases"
+                        .to_string(),
+                });
+            }
+        };
+
+        let before = samples.len();
+        retain_closest_to_median(samples, keep);
+        let kept = samples.len();
+
+        tracing::info!(
+            "[CalibrationProcedure] Retry-with-keep for {:?}: kept {} of {} samples",
+            self.current_sound,
+            kept,
+            before
+        );
+
+        self.waiting_for_confirmation = self.is_current_sound_complete();
+        self.last_sample_time = None; // Reset debounce timer
+        self.backoff.reset_for_sound(self.current_sound);
+        self.clear_candidate_for_sound(self.current_sound);
+        Ok(kept)
+    }
+}
+
+/// Keep the `keep` samples nearest the median, preserving their order
+fn retain_closest_to_median(samples: &mut Vec<Features>, keep: usize) {
+    if samples.len() <= keep {
+        return;
+    }
+
+    let median_centroid = median(samples.iter().map(|f| f.centroid).collect());
+    let median_zcr = median(samples.iter().map(|f| f.zcr).collect());
+    let distance = |f: &Features| {
+        relative_deviation(f.centroid, median_centroid) + relative_deviation(f.zcr, median_zcr)
+    };
+
+    let mut ranked: Vec<usize> = (0..samples.len()).collect();
+    ranked.sort_by(|&a, &b| distance(&samples[a]).total_cmp(&distance(&samples[b])));
+    let mut keep_mask = vec![false; samples.len()];
+    for &idx in ranked.iter().take(keep) {
+        keep_mask[idx] = true;
+    }
+
+    let mut mask = keep_mask.into_iter();
+    samples.retain(|_| mask.next().unwrap_or(false));
+}
+
+fn median(mut values: Vec<f32>) -> f32 {
+    values.sort_by(f32::total_cmp);
+    let mid = values.len() / 2;
+    if values.len().is_multiple_of(2) {
+        (values[mid - 1] + values[mid]) / 2.0
+    } else {
+        values[mid]
+    }
+}
+
+/// Deviation from `reference` relative to its magnitude, so centroid (Hz) and
+/// ZCR (0..1) contribute on the same scale
+fn relative_deviation(value: f32, reference: f32) -> f32 {
+    let diff = (value - reference).abs();
+    if reference.abs() <= f32::EPSILON {
+        diff
+    } else {
+        diff / reference.abs()
+    }
+}
@@ -0,0 +1,66 @@
+use crate::calibration::progress::CalibrationSound;
+use crate::error::CalibrationError;
+
+use super::CalibrationProcedure;
+
+impl CalibrationProcedure {
+    /// Skip the current sound and advance to the next one
+    ///
+    /// For users who cannot produce a distinct version of a sound. Any
+    /// samples collected for it are discarded, `finalize()` no longer
+    /// requires it and the resulting state tells the classifier never to
+    /// report it. At least one sound must remain calibrated.
+    ///
+    /// # Returns
+    /// * `Ok(true)` - Advanced to next sound
+    /// * `Ok(false)` - Calibration complete (skipped the last sound)
+    /// * `Err` - In a level measurement phase, or every other sound is skipped
+    pub fn skip_current_sound(&mut self) -> Result<bool, CalibrationError> {
+        let sound = self.current_sound;
+        if !sound.is_sound_phase() {
+            return Err(CalibrationError::NotASoundPhase { phase: sound });
+        }
+        let others_skipped = [
+            CalibrationSound::Kick,
+            CalibrationSound::Snare,
+            CalibrationSound::HiHat,
+        ]
+        .into_iter()
+        .filter(|&other| other != sound)
+        .all(|other| self.is_skipped(other));
+        if others_skipped {
+            return Err(CalibrationError::InvalidFeatures {
+                reason: "At least one sound must be calibrated".to_string(),
+            });
+        }
+
+        self.collection_for_sound(sound)?.clear();
+        if !self.is_skipped(sound) {
+            self.skipped_sounds.push(sound);
+        }
+
+        tracing::info!("[CalibrationProcedure] User skipped {:?}.", sound);
+        self.waiting_for_confirmation = false;
+        self.last_sample_time = None;
+        self.backoff.reset_for_sound(sound);
+        self.clear_all_candidates();
+        match sound.next() {
+            Some(next_sound) => {
+                self.current_sound = next_sound;
+                self.backoff.reset_for_sound(next_sound);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Whether the user skipped `sound`
+    pub fn is_skipped(&self, sound: CalibrationSound) -> bool {
+        self.skipped_sounds.contains(&sound)
+    }
+
+    /// Sounds the user skipped, in the order they were skipped
+    pub fn skipped_sounds(&self) -> &[CalibrationSound] {
+        &self.skipped_sounds
+    }
+}
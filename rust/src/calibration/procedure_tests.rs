@@ -288,6 +288,45 @@ fn test_finalize_incomplete() {
     ));
 }
 
+#[test]
+fn test_finalize_reports_per_sound_shortfall() {
+    let mut procedure = CalibrationProcedure::new_for_test(10);
+
+    for _ in 0..10 {
+        procedure
+            .add_sample(create_test_features(1000.0, 0.05), 0.05, 0.2)
+            .unwrap();
+    }
+    procedure.confirm_and_advance().unwrap();
+    for _ in 0..10 {
+        procedure
+            .add_sample(create_test_features(3000.0, 0.15), 0.05, 0.2)
+            .unwrap();
+    }
+    procedure.confirm_and_advance().unwrap();
+    for _ in 0..7 {
+        procedure
+            .add_sample(create_test_features(8000.0, 0.5), 0.05, 0.2)
+            .unwrap();
+    }
+
+    match procedure.finalize().unwrap_err() {
+        CalibrationError::InsufficientSamples {
+            required,
+            collected,
+            per_sound,
+        } => {
+            assert_eq!((required, collected), (30, 27));
+            let short: Vec<_> = per_sound.iter().filter(|c| c.missing() > 0).collect();
+            assert_eq!(short.len(), 1);
+            assert_eq!(short[0].sound, CalibrationSound::HiHat);
+            assert_eq!((short[0].collected, short[0].needed), (7, 10));
+            assert_eq!(short[0].missing(), 3);
+        }
+        other => panic!("Expected InsufficientSamples, got {:?}", other),
+    }
+}
+
 #[test]
 fn test_reset() {
     let mut procedure = CalibrationProcedure::new_for_test(10);
@@ -359,6 +398,40 @@ fn test_retry_current_sound() {
     assert_eq!(procedure.kick_samples.len(), 1);
 }
 
+#[test]
+fn test_retry_keep_best_drops_outliers() {
+    let mut procedure = CalibrationProcedure::new_for_test(10);
+
+    // 8 tight kicks around 1000 Hz plus 2 outliers
+    let centroids = [
+        1000.0, 1010.0, 3500.0, 990.0, 1005.0, 995.0, 200.0, 1015.0, 985.0, 1000.0,
+    ];
+    for centroid in centroids {
+        procedure
+            .add_sample(create_test_features(centroid, 0.05), 0.05, 0.0)
+            .unwrap();
+    }
+    assert!(procedure.is_waiting_for_confirmation());
+
+    let kept = procedure.retry_keep_best(8).unwrap();
+    assert_eq!(kept, 8);
+    assert!(procedure
+        .kick_samples
+        .iter()
+        .all(|f| (f.centroid - 1000.0).abs() <= 15.0));
+
+    // Not complete any more, so the user can top up
+    assert!(!procedure.is_waiting_for_confirmation());
+    for _ in 0..2 {
+        procedure
+            .add_sample(create_test_features(1000.0, 0.05), 0.05, 0.0)
+            .unwrap();
+    }
+    assert_eq!(procedure.kick_samples.len(), 10);
+    assert!(procedure.is_waiting_for_confirmation());
+    assert_eq!(procedure.current_sound, CalibrationSound::Kick);
+}
+
 // NOTE: Adaptive backoff tests removed - user-centric calibration accepts all sounds
 // above noise floor. The backoff module is kept for potential future use but not
 // actively used in sample validation.
@@ -546,3 +619,269 @@ fn test_finalize_preserves_noise_floor_rms() {
         "noise_floor_rms should NOT be the default 0.01"
     );
 }
+
+#[test]
+fn test_auto_advance_with_consistent_samples() {
+    let mut procedure = CalibrationProcedure::new_for_test(5).with_auto_advance(true);
+
+    for i in 0..5 {
+        let features = create_test_features(1000.0 + i as f32 * 5.0, 0.05);
+        procedure.add_sample(features, 0.05, 0.0).unwrap();
+    }
+
+    assert_eq!(procedure.current_sound, CalibrationSound::Snare);
+    assert!(!procedure.waiting_for_confirmation);
+
+    let progress = procedure.get_progress();
+    assert!(progress.auto_advanced);
+    assert_eq!(progress.current_sound, CalibrationSound::Snare);
+
+    // Reading progress does not consume the notice; the first snare sample does
+    assert!(procedure.get_progress().auto_advanced);
+    procedure
+        .add_sample(create_test_features(3000.0, 0.1), 0.05, 0.0)
+        .unwrap();
+    assert!(!procedure.get_progress().auto_advanced);
+}
+
+#[test]
+fn test_each_advance_flags_exactly_one_phase_change() {
+    let mut procedure = CalibrationProcedure::new_for_test(1);
+    let mut transitions = Vec::new();
+    let mut poll = |procedure: &mut CalibrationProcedure, polls: usize| {
+        for _ in 0..polls {
+            let progress = procedure.get_progress();
+            if progress.phase_changed {
+                transitions.push(progress.current_sound);
+            }
+        }
+    };
+
+    poll(&mut procedure, 2);
+    procedure
+        .add_sample(create_test_features(1000.0, 0.05), 0.05, 0.0)
+        .unwrap();
+    poll(&mut procedure, 2);
+    assert!(procedure.confirm_and_advance().unwrap());
+    poll(&mut procedure, 3);
+    procedure
+        .add_sample(create_test_features(3000.0, 0.1), 0.05, 0.0)
+        .unwrap();
+    assert!(procedure.confirm_and_advance().unwrap());
+    poll(&mut procedure, 3);
+
+    assert_eq!(
+        transitions,
+        vec![CalibrationSound::Snare, CalibrationSound::HiHat]
+    );
+}
+
+#[test]
+fn test_auto_advance_waits_on_inconsistent_samples() {
+    let mut procedure = CalibrationProcedure::new_for_test(4).with_auto_advance(true);
+
+    for centroid in [200.0, 3000.0, 250.0, 2800.0] {
+        let features = create_test_features(centroid, 0.05);
+        procedure.add_sample(features, 0.05, 0.0).unwrap();
+    }
+
+    assert_eq!(procedure.current_sound, CalibrationSound::Kick);
+    assert!(procedure.waiting_for_confirmation);
+    assert!(!procedure.get_progress().auto_advanced);
+}
+
+#[test]
+fn test_noise_floor_completes_after_configured_count() {
+    let mut procedure = CalibrationProcedure::new(10).with_noise_floor_samples(12);
+    assert_eq!(procedure.get_progress().samples_needed, 12);
+    assert!(procedure.noise_floor_remaining_ms().is_none());
+
+    for _ in 0..11 {
+        assert!(!procedure.add_noise_floor_sample(0.003).unwrap());
+    }
+    assert!(!procedure.is_noise_floor_complete());
+    assert!(procedure.get_progress().estimated_remaining_ms.is_some());
+
+    assert!(procedure.add_noise_floor_sample(0.003).unwrap());
+    assert!(procedure.is_noise_floor_complete());
+    assert_eq!(procedure.noise_floor_remaining_ms(), Some(0));
+}
+
+#[test]
+fn test_loud_noise_floor_warns_too_noisy() {
+    let mut procedure = CalibrationProcedure::new(10)
+        .with_noise_floor_samples(5)
+        .with_noise_floor_ceiling(0.05);
+
+    for _ in 0..5 {
+        procedure.add_noise_floor_sample(0.08).unwrap();
+    }
+
+    assert!(procedure.is_too_noisy());
+    let guidance = procedure
+        .get_progress()
+        .guidance
+        .expect("too-noisy warning");
+    assert_eq!(guidance.reason, CalibrationGuidanceReason::TooNoisy);
+    assert_eq!(guidance.sound, CalibrationSound::NoiseFloor);
+}
+
+#[test]
+fn test_quiet_noise_floor_has_no_warning() {
+    let mut procedure = CalibrationProcedure::new(10).with_noise_floor_samples(5);
+
+    for _ in 0..5 {
+        procedure.add_noise_floor_sample(0.003).unwrap();
+    }
+
+    assert!(!procedure.is_too_noisy());
+    assert!(procedure.get_progress().guidance.is_none());
+}
+
+#[test]
+fn test_input_level_gain_is_stored_on_finalize() {
+    let mut procedure = CalibrationProcedure::with_debounce(1, 0)
+        .with_noise_floor_samples(1)
+        .with_input_level_hits(2);
+    assert!(procedure.is_measuring_input_level());
+    assert_eq!(procedure.collection_input_gain(), Some(1.0));
+
+    // Two loud hits peaking at 0.05 RMS on a quiet device
+    for rms in [0.03, 0.05, 0.0, 0.05, 0.02, 0.0] {
+        procedure.add_input_level_sample(rms);
+    }
+    assert!(!procedure.is_measuring_input_level());
+    assert_eq!(procedure.measured_input_gain(), Some(5.0));
+    assert_eq!(procedure.collection_input_gain(), Some(5.0));
+    assert_eq!(procedure.current_sound(), CalibrationSound::NoiseFloor);
+
+    procedure.add_noise_floor_sample(0.001).unwrap();
+    procedure.confirm_and_advance().unwrap();
+    for (centroid, zcr) in [(1000.0, 0.05), (3000.0, 0.15), (8000.0, 0.5)] {
+        procedure
+            .add_sample(create_test_features(centroid, zcr), 0.05, 0.2)
+            .unwrap();
+        procedure.confirm_and_advance().unwrap();
+    }
+
+    let state = procedure.finalize().unwrap();
+    assert_eq!(state.input_gain, 5.0);
+}
+
+#[test]
+fn test_input_level_is_reported_as_its_own_phase() {
+    let mut procedure = CalibrationProcedure::new_default().with_input_level_hits(2);
+
+    let progress = procedure.get_progress();
+    assert_eq!(progress.current_sound, CalibrationSound::InputLevel);
+    assert_eq!(progress.samples_needed, 2);
+    assert_eq!(progress.samples_collected, 0);
+
+    for rms in [0.1, 0.0] {
+        procedure.add_input_level_sample(rms);
+    }
+    assert_eq!(procedure.get_progress().samples_collected, 1);
+    // Sound samples are rejected until the level measurements are done
+    assert!(procedure
+        .add_sample(create_test_features(1000.0, 0.05), 0.1, 0.2)
+        .is_err());
+
+    for rms in [0.1, 0.0] {
+        procedure.add_input_level_sample(rms);
+    }
+    let progress = procedure.get_progress();
+    assert_eq!(progress.current_sound, CalibrationSound::NoiseFloor);
+    assert!(progress.phase_changed);
+
+    // Reset measures the input level again
+    procedure.reset();
+    assert!(procedure.is_measuring_input_level());
+    assert_eq!(procedure.input_level_hits_measured(), 0);
+}
+
+#[test]
+fn test_high_centroid_kick_warns_unexpected_timbre() {
+    let mut procedure = CalibrationProcedure::new_for_test(10)
+        .with_expected_ranges(Some(ExpectedFeatureRanges::default()));
+
+    // Snare-like "kick" is still accepted, but flagged
+    procedure
+        .add_sample(create_test_features(4500.0, 0.05), 0.2, 0.5)
+        .unwrap();
+    let progress = procedure.get_progress_with_guidance(None);
+    assert_eq!(progress.samples_collected, 1);
+    let guidance = progress.guidance.expect("unexpected timbre warning");
+    assert_eq!(guidance.reason, CalibrationGuidanceReason::UnexpectedTimbre);
+    assert_eq!(guidance.sound, CalibrationSound::Kick);
+
+    // Reading progress keeps the warning; a typical kick clears it
+    assert!(procedure.get_progress().guidance.is_some());
+    procedure
+        .add_sample(create_test_features(300.0, 0.02), 0.2, 0.5)
+        .unwrap();
+    assert!(procedure.get_progress().guidance.is_none());
+}
+
+#[test]
+fn test_expected_ranges_are_off_by_default() {
+    let mut procedure = CalibrationProcedure::new_for_test(10);
+    procedure
+        .add_sample(create_test_features(4500.0, 0.05), 0.2, 0.5)
+        .unwrap();
+    assert!(procedure.get_progress().guidance.is_none());
+}
+
+#[test]
+fn test_skipping_hihat_finalizes_without_it() {
+    use crate::analysis::classifier::Classifier;
+    use crate::types::BeatboxHit;
+    use std::sync::{Arc, RwLock};
+
+    let mut procedure = CalibrationProcedure::new_for_test(10);
+    for (centroid, zcr) in [(1000.0, 0.05), (3000.0, 0.15)] {
+        for _ in 0..10 {
+            procedure
+                .add_sample(create_test_features(centroid, zcr), 0.05, 0.2)
+                .unwrap();
+        }
+        procedure.confirm_and_advance().unwrap();
+    }
+    assert_eq!(procedure.current_sound(), CalibrationSound::HiHat);
+    assert!(!procedure.is_complete());
+
+    assert!(!procedure.skip_current_sound().unwrap());
+    assert!(procedure.is_complete());
+    assert!(procedure
+        .add_sample(create_test_features(8000.0, 0.5), 0.05, 0.2)
+        .is_err());
+
+    let state = procedure.finalize().unwrap();
+    assert_eq!(state.skipped_sounds, vec![CalibrationSound::HiHat]);
+    assert!((state.t_snare_centroid - 3000.0 * 1.2).abs() < 0.01);
+
+    let classifier = Classifier::new(Arc::new(RwLock::new(state)));
+    for centroid in [500.0, 2000.0, 4000.0, 8000.0, 12000.0] {
+        for zcr in [0.02, 0.2, 0.5, 0.9] {
+            let (hit, _) = classifier.classify(&create_test_features(centroid, zcr));
+            assert_ne!(hit, BeatboxHit::HiHat, "centroid {centroid}, zcr {zcr}");
+        }
+    }
+    let (hihat_like, _) = classifier.classify(&create_test_features(8000.0, 0.5));
+    assert_eq!(hihat_like, BeatboxHit::Unknown);
+}
+
+#[test]
+fn test_level_phases_cannot_be_skipped_or_manually_accepted() {
+    let mut procedure = CalibrationProcedure::with_debounce(10, 0);
+    assert_eq!(procedure.current_sound(), CalibrationSound::NoiseFloor);
+
+    let not_a_sound = CalibrationError::NotASoundPhase {
+        phase: CalibrationSound::NoiseFloor,
+    };
+    assert_eq!(procedure.skip_current_sound().unwrap_err(), not_a_sound);
+    assert_eq!(
+        procedure.manual_accept_last_candidate().unwrap_err(),
+        not_a_sound
+    );
+    assert_eq!(procedure.current_sound(), CalibrationSound::NoiseFloor);
+}
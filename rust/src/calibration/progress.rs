@@ -3,6 +3,8 @@
 // This module provides types and utilities for tracking progress through
 // the calibration sample collection workflow.
 
+use super::state::CalibrationState;
+
 /// Calibration phase - includes noise floor measurement before sound collection
 #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum CalibrationSound {
@@ -14,6 +16,9 @@ pub enum CalibrationSound {
     Snare,
     /// Step 4: Collecting hi-hat samples
     HiHat,
+    /// Optional step before the noise floor: measuring a few of the user's
+    /// loudest hits to derive the input gain
+    InputLevel,
 }
 
 impl CalibrationSound {
@@ -24,6 +29,7 @@ impl CalibrationSound {
     /// * `None` - Calibration sequence complete
     pub fn next(&self) -> Option<CalibrationSound> {
         match self {
+            CalibrationSound::InputLevel => Some(CalibrationSound::NoiseFloor),
             CalibrationSound::NoiseFloor => Some(CalibrationSound::Kick),
             CalibrationSound::Kick => Some(CalibrationSound::Snare),
             CalibrationSound::Snare => Some(CalibrationSound::HiHat),
@@ -34,6 +40,7 @@ impl CalibrationSound {
     /// Get human-readable name for display
     pub fn display_name(&self) -> &'static str {
         match self {
+            CalibrationSound::InputLevel => "INPUT LEVEL",
             CalibrationSound::NoiseFloor => "NOISE FLOOR",
             CalibrationSound::Kick => "KICK",
             CalibrationSound::Snare => "SNARE",
@@ -41,9 +48,12 @@ impl CalibrationSound {
         }
     }
 
-    /// Check if this is a sound collection phase (not noise floor)
+    /// Check if this is a sound collection phase (not input level or noise floor)
     pub fn is_sound_phase(&self) -> bool {
-        !matches!(self, CalibrationSound::NoiseFloor)
+        !matches!(
+            self,
+            CalibrationSound::InputLevel | CalibrationSound::NoiseFloor
+        )
     }
 }
 
@@ -56,6 +66,11 @@ pub enum CalibrationGuidanceReason {
     TooQuiet,
     /// Audio appears clipped or overly loud
     Clipped,
+    /// Measured noise floor is too high for reliable detection
+    TooNoisy,
+    /// Accepted sample's features fall outside the typical range for the
+    /// sound being calibrated (e.g. a kick with a snare-like centroid)
+    UnexpectedTimbre,
 }
 
 /// Guidance payload accompanying calibration progress updates
@@ -89,11 +104,55 @@ pub struct CalibrationProgress {
     pub guidance: Option<CalibrationGuidance>,
     /// Whether a manual accept candidate is available for promotion
     pub manual_accept_available: bool,
+    /// Whether `current_sound` was reached by auto-advance rather than
+    /// confirmation; stays set until that sound collects its first sample
+    #[serde(default)]
+    pub auto_advanced: bool,
+    /// Set on the first update after `current_sound` changed, so the UI can
+    /// react to phase transitions without diffing updates
+    #[serde(default)]
+    pub phase_changed: bool,
+    /// Estimated time left in the current phase (noise floor only), so the
+    /// UI can say "stay quiet for 2 more seconds"
+    #[serde(default)]
+    pub estimated_remaining_ms: Option<u32>,
+    /// Set only on the final update, sent once calibration is finished
+    #[serde(default)]
+    pub completed: Option<CalibrationSummary>,
     /// Debug info (feature gates and levels) for instrumentation builds
     #[serde(skip_serializing_if = "Option::is_none")]
     pub debug: Option<CalibrationProgressDebug>,
 }
 
+/// Thresholds computed by a finished calibration
+///
+/// Sent on the progress stream so the UI can show a summary without a
+/// separate `get_calibration_state` call.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CalibrationSummary {
+    pub t_kick_centroid: f32,
+    pub t_kick_zcr: f32,
+    pub t_snare_centroid: f32,
+    pub t_hihat_zcr: f32,
+    pub noise_floor_rms: f64,
+    /// How distinct the calibrated sounds are (0-1), see
+    /// `CalibrationFeatureStats::quality_score`. `None` without feature stats.
+    pub quality_score: Option<f32>,
+}
+
+impl CalibrationSummary {
+    pub fn from_state(state: &CalibrationState) -> Self {
+        Self {
+            t_kick_centroid: state.t_kick_centroid,
+            t_kick_zcr: state.t_kick_zcr,
+            t_snare_centroid: state.t_snare_centroid,
+            t_hihat_zcr: state.t_hihat_zcr,
+            noise_floor_rms: state.noise_floor_rms,
+            quality_score: state.feature_stats.map(|stats| stats.quality_score()),
+        }
+    }
+}
+
 /// Debug payload to help users see what the engine expects
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct CalibrationProgressDebug {
@@ -119,6 +178,27 @@ pub struct CalibrationProgressDebug {
     pub last_max_amp: Option<f32>,
 }
 
+/// Live feature snapshot emitted on every analysis buffer during calibration
+///
+/// Unlike the `debug` payload on `CalibrationProgress`, frames are sent
+/// regardless of whether a sample was accepted, so tuning UIs can plot the
+/// full feature distribution in real time.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CalibrationDebugFrame {
+    /// Incrementing sequence to detect dropped frames
+    pub seq: u64,
+    /// Calibration phase the frame was captured in
+    pub sound: CalibrationSound,
+    /// Spectral centroid of the latest window (Hz)
+    pub centroid: f32,
+    /// Zero-crossing rate of the latest window
+    pub zcr: f32,
+    /// RMS of the latest window
+    pub rms: f64,
+    /// Peak absolute amplitude of the latest window
+    pub max_amp: f32,
+}
+
 impl CalibrationProgress {
     /// Create a new progress instance
     ///
@@ -140,10 +220,26 @@ impl CalibrationProgress {
             waiting_for_confirmation,
             guidance: None,
             manual_accept_available: false,
+            auto_advanced: false,
+            phase_changed: false,
+            estimated_remaining_ms: None,
+            completed: None,
             debug: None,
         }
     }
 
+    /// Final update for a finished calibration, carrying its thresholds
+    pub fn completed(samples_needed: u8, state: &CalibrationState) -> Self {
+        let mut progress = Self::new(
+            CalibrationSound::HiHat,
+            samples_needed,
+            samples_needed,
+            false,
+        );
+        progress.completed = Some(CalibrationSummary::from_state(state));
+        progress
+    }
+
     /// Attach guidance payload to this progress instance
     pub fn with_guidance(mut self, guidance: Option<CalibrationGuidance>) -> Self {
         self.guidance = guidance;
@@ -156,6 +252,24 @@ impl CalibrationProgress {
         self
     }
 
+    /// Mark this update as following an automatic advance
+    pub fn with_auto_advanced(mut self, auto_advanced: bool) -> Self {
+        self.auto_advanced = auto_advanced;
+        self
+    }
+
+    /// Mark this update as the first one in a new phase
+    pub fn with_phase_changed(mut self, phase_changed: bool) -> Self {
+        self.phase_changed = phase_changed;
+        self
+    }
+
+    /// Attach an estimate of the time left in the current phase
+    pub fn with_remaining_ms(mut self, remaining_ms: Option<u32>) -> Self {
+        self.estimated_remaining_ms = remaining_ms;
+        self
+    }
+
     /// Attach debug payload (instrumentation only)
     pub fn with_debug(mut self, debug: Option<CalibrationProgressDebug>) -> Self {
         self.debug = debug;
@@ -6,10 +6,25 @@
 //
 // Thresholds are calculated from calibration samples using mean + 20% margin.
 // This provides a balance between accuracy and robustness.
+//
+// ZCR thresholds are per-sample and therefore tied to the sample rate they
+// were captured at. The state records that rate (and the unit convention) so
+// thresholds can be rescaled when loaded on a device running at another rate.
 
-use crate::analysis::features::Features;
+use crate::analysis::features::{FeatureUnits, Features};
+use crate::audio::ENGINE_SAMPLE_RATE;
+use crate::calibration::progress::CalibrationSound;
 use crate::error::CalibrationError;
 
+#[path = "state_stats.rs"]
+mod state_stats;
+#[path = "state_units.rs"]
+mod state_units;
+#[path = "state_validation.rs"]
+mod state_validation;
+
+pub use state_stats::{CalibrationFeatureStats, FeatureNormalization, FeatureStats};
+
 /// CalibrationState stores thresholds for sound classification
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct CalibrationState {
@@ -31,6 +46,65 @@ pub struct CalibrationState {
     /// Defaults to 0.01 for backward compatibility with existing calibrations
     #[serde(default = "default_noise_floor")]
     pub noise_floor_rms: f64,
+    /// Unit convention of the ZCR thresholds
+    /// Defaults to per-sample for calibrations saved before the field existed
+    #[serde(default)]
+    pub feature_units: FeatureUnits,
+    /// Sample rate (Hz) the thresholds were captured at
+    #[serde(default = "default_sample_rate")]
+    pub sample_rate: u32,
+    /// Input gain applied to raw audio so hits reach a consistent level
+    /// across devices (see `InputLevelCalibration`)
+    #[serde(default = "default_input_gain")]
+    pub input_gain: f32,
+    /// Per-sound feature statistics from the calibration samples, in the
+    /// same ZCR units as the thresholds. Absent for default thresholds and
+    /// calibrations saved before the field existed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feature_stats: Option<CalibrationFeatureStats>,
+    /// Mean and standard deviation of each feature over all calibration
+    /// samples, used to z-score features before measuring prototype
+    /// distances (the Level 1 fallback when no threshold rule fires).
+    /// Recorded alongside `feature_stats`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feature_normalization: Option<FeatureNormalization>,
+    /// Subcategory thresholds used by Level 2 classification
+    ///
+    /// `None` when the state only covers the three basic sounds (e.g. a
+    /// calibration saved before Level 2 existed); the classifier then falls
+    /// back to Level 1 even if `level` is 2.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub level2: Option<Level2Thresholds>,
+    /// Sounds the user skipped during calibration
+    ///
+    /// Their thresholds keep the defaults and the classifier never reports
+    /// them (hits that would be classified as one become `Unknown`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skipped_sounds: Vec<CalibrationSound>,
+}
+
+/// Thresholds separating the Level 2 subcategories
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Level2Thresholds {
+    /// Kicks flatter (noisier) than this are K-snares
+    pub ksnare_min_flatness: f32,
+    /// Kicks below this flatness are clearly tonal
+    pub tonal_kick_max_flatness: f32,
+    /// Hi-hats decaying faster than this are closed (ms)
+    pub closed_hihat_max_decay_ms: f32,
+    /// Hi-hats decaying slower than this are open (ms)
+    pub open_hihat_min_decay_ms: f32,
+}
+
+impl Default for Level2Thresholds {
+    fn default() -> Self {
+        Self {
+            ksnare_min_flatness: 0.3,
+            tonal_kick_max_flatness: 0.1,
+            closed_hihat_max_decay_ms: 50.0,
+            open_hihat_min_decay_ms: 150.0,
+        }
+    }
 }
 
 /// Default level value for serde deserialization
@@ -43,6 +117,16 @@ fn default_noise_floor() -> f64 {
     0.01 // Conservative default: reasonably quiet environment
 }
 
+/// Default sample rate for calibrations saved without one (engine rate)
+fn default_sample_rate() -> u32 {
+    ENGINE_SAMPLE_RATE
+}
+
+/// Default input gain (unity) for calibrations saved without one
+fn default_input_gain() -> f32 {
+    1.0
+}
+
 impl CalibrationState {
     /// Create default calibration state with hardcoded thresholds
     ///
@@ -62,6 +146,13 @@ impl CalibrationState {
             t_hihat_zcr: 0.3,
             is_calibrated: false,
             noise_floor_rms: default_noise_floor(),
+            feature_units: FeatureUnits::PerSample,
+            sample_rate: default_sample_rate(),
+            input_gain: default_input_gain(),
+            feature_stats: None,
+            feature_normalization: None,
+            level2: Some(Level2Thresholds::default()),
+            skipped_sounds: Vec::new(),
         }
     }
 
@@ -92,84 +183,107 @@ impl CalibrationState {
         samples_per_sound: usize,
         noise_floor_rms: f64,
     ) -> Result<Self, CalibrationError> {
-        // Validate sample counts
-        if kick_samples.len() != samples_per_sound {
-            return Err(CalibrationError::InsufficientSamples {
-                required: samples_per_sound,
-                collected: kick_samples.len(),
-            });
-        }
-        if snare_samples.len() != samples_per_sound {
-            return Err(CalibrationError::InsufficientSamples {
-                required: samples_per_sound,
-                collected: snare_samples.len(),
-            });
-        }
-        if hihat_samples.len() != samples_per_sound {
-            return Err(CalibrationError::InsufficientSamples {
-                required: samples_per_sound,
-                collected: hihat_samples.len(),
-            });
-        }
-
-        // Validate and compute kick thresholds
-        Self::validate_samples(kick_samples, "kick")?;
-        let kick_centroid_mean = Self::compute_mean_centroid(kick_samples);
-        let kick_zcr_mean = Self::compute_mean_zcr(kick_samples);
-
-        // Validate and compute snare thresholds
-        Self::validate_samples(snare_samples, "snare")?;
-        let snare_centroid_mean = Self::compute_mean_centroid(snare_samples);
+        Self::from_samples_skipping(
+            kick_samples,
+            snare_samples,
+            hihat_samples,
+            samples_per_sound,
+            noise_floor_rms,
+            &[],
+        )
+    }
 
-        // Validate and compute hi-hat thresholds
-        Self::validate_samples(hihat_samples, "hi-hat")?;
-        let hihat_zcr_mean = Self::compute_mean_zcr(hihat_samples);
+    /// Create calibrated state from user samples, leaving out `skipped` sounds
+    ///
+    /// Skipped sounds need no samples and keep their default thresholds.
+    /// Feature statistics are only recorded when every sound was collected,
+    /// since prototype matching compares against all three.
+    pub fn from_samples_skipping(
+        kick_samples: &[Features],
+        snare_samples: &[Features],
+        hihat_samples: &[Features],
+        samples_per_sound: usize,
+        noise_floor_rms: f64,
+        skipped: &[CalibrationSound],
+    ) -> Result<Self, CalibrationError> {
+        let defaults = Self::new_default();
+        let collected = |sound: CalibrationSound, samples: &[Features], name: &str| {
+            if skipped.contains(&sound) {
+                return Ok(false);
+            }
+            // Validate sample counts
+            if samples.len() != samples_per_sound {
+                return Err(CalibrationError::InsufficientSamples {
+                    required: samples_per_sound,
+                    collected: samples.len(),
+                    per_sound: Vec::new(),
+                });
+            }
+            Self::validate_samples(samples, name)?;
+            Ok(true)
+        };
+        let kick = collected(CalibrationSound::Kick, kick_samples, "kick")?.then_some(kick_samples);
+        let snare =
+            collected(CalibrationSound::Snare, snare_samples, "snare")?.then_some(snare_samples);
+        let hihat =
+            collected(CalibrationSound::HiHat, hihat_samples, "hi-hat")?.then_some(hihat_samples);
 
         // Apply 20% margin to thresholds
         // Thresholds are positioned between the sound types
+        let margin = |samples: Option<&[Features]>, mean: fn(&[Features]) -> f32, default| {
+            samples.map_or(default, |samples| mean(samples) * 1.2)
+        };
+        let (feature_stats, feature_normalization) = match (kick, snare, hihat) {
+            (Some(kick), Some(snare), Some(hihat)) => (
+                Some(CalibrationFeatureStats {
+                    kick: FeatureStats::from_samples(kick),
+                    snare: FeatureStats::from_samples(snare),
+                    hihat: FeatureStats::from_samples(hihat),
+                }),
+                Some(FeatureNormalization::from_samples(
+                    kick.iter().chain(snare).chain(hihat),
+                )),
+            ),
+            _ => (None, None),
+        };
         Ok(Self {
             level: 1, // Default to level 1 for calibration
-            t_kick_centroid: kick_centroid_mean * 1.2,
-            t_kick_zcr: kick_zcr_mean * 1.2,
-            t_snare_centroid: snare_centroid_mean * 1.2,
-            t_hihat_zcr: hihat_zcr_mean * 1.2,
+            t_kick_centroid: margin(kick, Self::compute_mean_centroid, defaults.t_kick_centroid),
+            t_kick_zcr: margin(kick, Self::compute_mean_zcr, defaults.t_kick_zcr),
+            t_snare_centroid: margin(
+                snare,
+                Self::compute_mean_centroid,
+                defaults.t_snare_centroid,
+            ),
+            t_hihat_zcr: margin(hihat, Self::compute_mean_zcr, defaults.t_hihat_zcr),
             is_calibrated: true,
             noise_floor_rms,
+            feature_units: FeatureUnits::PerSample,
+            sample_rate: default_sample_rate(),
+            input_gain: default_input_gain(),
+            feature_stats,
+            feature_normalization,
+            // Only the basic sounds were collected
+            level2: None,
+            skipped_sounds: skipped.to_vec(),
         })
     }
 
-    /// Validate that all samples are within acceptable ranges
-    ///
-    /// # Arguments
-    /// * `samples` - Features to validate
-    /// * `sound_name` - Name of sound type for error messages
-    ///
-    /// # Returns
-    /// * `Ok(())` - All samples valid
-    /// * `Err(CalibrationError)` - Validation error with details
-    fn validate_samples(samples: &[Features], sound_name: &str) -> Result<(), CalibrationError> {
-        for (i, features) in samples.iter().enumerate() {
-            // Validate centroid range [50 Hz, 20000 Hz]
-            if features.centroid < 50.0 || features.centroid > 20000.0 {
-                return Err(CalibrationError::InvalidFeatures {
-                    reason: format!(
-                        "{} sample {}: centroid {} Hz out of range [50, 20000]",
-                        sound_name, i, features.centroid
-                    ),
-                });
-            }
+    /// Whether `sound` was skipped during calibration
+    pub fn is_skipped(&self, sound: CalibrationSound) -> bool {
+        self.skipped_sounds.contains(&sound)
+    }
 
-            // Validate ZCR range [0.0, 1.0]
-            if features.zcr < 0.0 || features.zcr > 1.0 {
-                return Err(CalibrationError::InvalidFeatures {
-                    reason: format!(
-                        "{} sample {}: ZCR {} out of range [0.0, 1.0]",
-                        sound_name, i, features.zcr
-                    ),
-                });
-            }
-        }
-        Ok(())
+    /// Set the input gain measured by `InputLevelCalibration`
+    pub fn with_input_gain(mut self, input_gain: f32) -> Self {
+        self.input_gain = input_gain;
+        self
+    }
+
+    /// Attach Level 2 subcategory thresholds (enables Level 2 classification)
+    pub fn with_level2_thresholds(mut self, thresholds: Level2Thresholds) -> Self {
+        self.level2 = Some(thresholds);
+        self
     }
 
     /// Compute mean centroid from feature samples
@@ -186,306 +300,5 @@ impl CalibrationState {
 }
 
 #[cfg(test)]
-mod tests {
-    use super::*;
-
-    /// Helper function to create valid test features
-    fn create_test_features(centroid: f32, zcr: f32) -> Features {
-        Features {
-            centroid,
-            zcr,
-            flatness: 0.5,
-            rolloff: 5000.0,
-            decay_time_ms: 50.0,
-        }
-    }
-
-    /// Helper function to create 10 identical features
-    fn create_test_samples(centroid: f32, zcr: f32) -> Vec<Features> {
-        vec![create_test_features(centroid, zcr); 10]
-    }
-
-    #[test]
-    fn test_new_default() {
-        let state = CalibrationState::new_default();
-
-        assert_eq!(state.t_kick_centroid, 1500.0);
-        assert_eq!(state.t_kick_zcr, 0.1);
-        assert_eq!(state.t_snare_centroid, 4000.0);
-        assert_eq!(state.t_hihat_zcr, 0.3);
-        assert!(!state.is_calibrated);
-        assert!((state.noise_floor_rms - 0.01).abs() < 0.0001);
-    }
-
-    #[test]
-    fn test_from_samples_valid() {
-        // Create valid samples with known values
-        let kick_samples = create_test_samples(1000.0, 0.05);
-        let snare_samples = create_test_samples(3000.0, 0.15);
-        let hihat_samples = create_test_samples(8000.0, 0.5);
-
-        let result =
-            CalibrationState::from_samples(&kick_samples, &snare_samples, &hihat_samples, 10, 0.01);
-
-        assert!(result.is_ok());
-        let state = result.unwrap();
-
-        // Check that thresholds are mean * 1.2 (with floating point tolerance)
-        assert!((state.t_kick_centroid - 1000.0 * 1.2).abs() < 0.01);
-        assert!((state.t_kick_zcr - 0.05 * 1.2).abs() < 0.0001);
-        assert!((state.t_snare_centroid - 3000.0 * 1.2).abs() < 0.01);
-        assert!((state.t_hihat_zcr - 0.5 * 1.2).abs() < 0.0001);
-        assert!(state.is_calibrated);
-    }
-
-    #[test]
-    fn test_from_samples_wrong_count_kick() {
-        let kick_samples = create_test_samples(1000.0, 0.05)[..5].to_vec(); // Only 5 samples
-        let snare_samples = create_test_samples(3000.0, 0.15);
-        let hihat_samples = create_test_samples(8000.0, 0.5);
-
-        let result =
-            CalibrationState::from_samples(&kick_samples, &snare_samples, &hihat_samples, 10, 0.01);
-
-        assert!(result.is_err());
-        match result.unwrap_err() {
-            crate::error::CalibrationError::InsufficientSamples {
-                required: 10,
-                collected: 5,
-            } => {}
-            e => panic!("Expected InsufficientSamples error, got: {:?}", e),
-        }
-    }
-
-    #[test]
-    fn test_from_samples_wrong_count_snare() {
-        let kick_samples = create_test_samples(1000.0, 0.05);
-        let snare_samples = create_test_samples(3000.0, 0.15)[..8].to_vec(); // Only 8 samples
-        let hihat_samples = create_test_samples(8000.0, 0.5);
-
-        let result =
-            CalibrationState::from_samples(&kick_samples, &snare_samples, &hihat_samples, 10, 0.01);
-
-        assert!(result.is_err());
-        match result.unwrap_err() {
-            crate::error::CalibrationError::InsufficientSamples {
-                required: 10,
-                collected: 8,
-            } => {}
-            e => panic!("Expected InsufficientSamples error, got: {:?}", e),
-        }
-    }
-
-    #[test]
-    fn test_from_samples_wrong_count_hihat() {
-        let kick_samples = create_test_samples(1000.0, 0.05);
-        let snare_samples = create_test_samples(3000.0, 0.15);
-        // Create 12 samples explicitly
-        let mut hihat_samples = create_test_samples(8000.0, 0.5);
-        hihat_samples.push(create_test_features(8000.0, 0.5));
-        hihat_samples.push(create_test_features(8000.0, 0.5));
-
-        let result =
-            CalibrationState::from_samples(&kick_samples, &snare_samples, &hihat_samples, 10, 0.01);
-
-        assert!(result.is_err());
-        match result.unwrap_err() {
-            crate::error::CalibrationError::InsufficientSamples {
-                required: 10,
-                collected: 12,
-            } => {}
-            e => panic!("Expected InsufficientSamples error, got: {:?}", e),
-        }
-    }
-
-    #[test]
-    fn test_from_samples_centroid_too_low() {
-        let kick_samples = create_test_samples(30.0, 0.05); // Centroid too low (< 50 Hz)
-        let snare_samples = create_test_samples(3000.0, 0.15);
-        let hihat_samples = create_test_samples(8000.0, 0.5);
-
-        let result =
-            CalibrationState::from_samples(&kick_samples, &snare_samples, &hihat_samples, 10, 0.01);
-
-        assert!(result.is_err());
-        match result.unwrap_err() {
-            crate::error::CalibrationError::InvalidFeatures { reason } => {
-                assert!(reason.contains("centroid") && reason.contains("30"));
-            }
-            e => panic!("Expected InvalidFeatures error, got: {:?}", e),
-        }
-    }
-
-    #[test]
-    fn test_from_samples_centroid_too_high() {
-        let kick_samples = create_test_samples(1000.0, 0.05);
-        let snare_samples = create_test_samples(25000.0, 0.15); // Centroid too high (> 20000 Hz)
-        let hihat_samples = create_test_samples(8000.0, 0.5);
-
-        let result =
-            CalibrationState::from_samples(&kick_samples, &snare_samples, &hihat_samples, 10, 0.01);
-
-        assert!(result.is_err());
-        match result.unwrap_err() {
-            crate::error::CalibrationError::InvalidFeatures { reason } => {
-                assert!(reason.contains("centroid") && reason.contains("25000"));
-            }
-            e => panic!("Expected InvalidFeatures error, got: {:?}", e),
-        }
-    }
-
-    #[test]
-    fn test_from_samples_zcr_too_low() {
-        let kick_samples = create_test_samples(1000.0, -0.1); // ZCR too low (< 0.0)
-        let snare_samples = create_test_samples(3000.0, 0.15);
-        let hihat_samples = create_test_samples(8000.0, 0.5);
-
-        let result =
-            CalibrationState::from_samples(&kick_samples, &snare_samples, &hihat_samples, 10, 0.01);
-
-        assert!(result.is_err());
-        match result.unwrap_err() {
-            crate::error::CalibrationError::InvalidFeatures { reason } => {
-                assert!(reason.contains("ZCR") && reason.contains("-0.1"));
-            }
-            e => panic!("Expected InvalidFeatures error, got: {:?}", e),
-        }
-    }
-
-    #[test]
-    fn test_from_samples_zcr_too_high() {
-        let kick_samples = create_test_samples(1000.0, 0.05);
-        let snare_samples = create_test_samples(3000.0, 0.15);
-        let hihat_samples = create_test_samples(8000.0, 1.5); // ZCR too high (> 1.0)
-
-        let result =
-            CalibrationState::from_samples(&kick_samples, &snare_samples, &hihat_samples, 10, 0.01);
-
-        assert!(result.is_err());
-        match result.unwrap_err() {
-            crate::error::CalibrationError::InvalidFeatures { reason } => {
-                assert!(reason.contains("ZCR") && reason.contains("1.5"));
-            }
-            e => panic!("Expected InvalidFeatures error, got: {:?}", e),
-        }
-    }
-
-    #[test]
-    fn test_from_samples_mean_calculation() {
-        // Create samples with varying values to test mean calculation
-        let mut kick_samples = Vec::new();
-        for i in 0..10 {
-            kick_samples.push(create_test_features(1000.0 + i as f32 * 10.0, 0.05));
-        }
-
-        let snare_samples = create_test_samples(3000.0, 0.15);
-        let hihat_samples = create_test_samples(8000.0, 0.5);
-
-        let result =
-            CalibrationState::from_samples(&kick_samples, &snare_samples, &hihat_samples, 10, 0.01);
-
-        assert!(result.is_ok());
-        let state = result.unwrap();
-
-        // Mean of 1000, 1010, 1020, ..., 1090 = 1045
-        let expected_kick_centroid = 1045.0 * 1.2;
-        assert!((state.t_kick_centroid - expected_kick_centroid).abs() < 0.01);
-    }
-
-    #[test]
-    fn test_from_samples_20_percent_margin() {
-        let kick_samples = create_test_samples(1000.0, 0.1);
-        let snare_samples = create_test_samples(2000.0, 0.2);
-        let hihat_samples = create_test_samples(5000.0, 0.4);
-
-        let result =
-            CalibrationState::from_samples(&kick_samples, &snare_samples, &hihat_samples, 10, 0.01);
-
-        assert!(result.is_ok());
-        let state = result.unwrap();
-
-        // Verify 20% margin (multiply by 1.2) with floating point tolerance
-        assert!((state.t_kick_centroid - 1000.0 * 1.2).abs() < 0.01); // 1200.0
-        assert!((state.t_kick_zcr - 0.1 * 1.2).abs() < 0.0001); // 0.12
-        assert!((state.t_snare_centroid - 2000.0 * 1.2).abs() < 0.01); // 2400.0
-        assert!((state.t_hihat_zcr - 0.4 * 1.2).abs() < 0.0001); // 0.48
-    }
-
-    #[test]
-    fn test_validate_samples_edge_cases() {
-        // Test samples at exact boundaries (should be valid)
-        let kick_samples = create_test_samples(50.0, 0.0); // Min valid values
-        let snare_samples = create_test_samples(20000.0, 1.0); // Max valid values
-        let hihat_samples = create_test_samples(10000.0, 0.5);
-
-        let result =
-            CalibrationState::from_samples(&kick_samples, &snare_samples, &hihat_samples, 10, 0.01);
-
-        assert!(result.is_ok());
-    }
-
-    #[test]
-    fn test_serialization_includes_noise_floor_rms() {
-        // Create a calibration state with specific noise_floor_rms
-        let kick_samples = create_test_samples(1000.0, 0.05);
-        let snare_samples = create_test_samples(3000.0, 0.15);
-        let hihat_samples = create_test_samples(8000.0, 0.5);
-
-        let noise_floor = 0.0065; // Specific value to check
-        let state = CalibrationState::from_samples(
-            &kick_samples,
-            &snare_samples,
-            &hihat_samples,
-            10,
-            noise_floor,
-        )
-        .unwrap();
-
-        // Serialize to JSON
-        let json = serde_json::to_string(&state).unwrap();
-        eprintln!("Serialized JSON: {}", json);
-
-        // Verify noise_floor_rms is in the JSON
-        assert!(
-            json.contains("noise_floor_rms"),
-            "JSON should contain noise_floor_rms field: {}",
-            json
-        );
-        assert!(
-            json.contains("0.0065"),
-            "JSON should contain noise_floor_rms value 0.0065: {}",
-            json
-        );
-
-        // Deserialize and verify round-trip
-        let deserialized: CalibrationState = serde_json::from_str(&json).unwrap();
-        assert!(
-            (deserialized.noise_floor_rms - noise_floor).abs() < 0.0001,
-            "Round-trip should preserve noise_floor_rms: {} vs {}",
-            deserialized.noise_floor_rms,
-            noise_floor
-        );
-    }
-
-    #[test]
-    fn test_deserialization_without_noise_floor_uses_default() {
-        // JSON without noise_floor_rms field (legacy format)
-        let json = r#"{
-            "level": 1,
-            "t_kick_centroid": 1200.0,
-            "t_kick_zcr": 0.06,
-            "t_snare_centroid": 3600.0,
-            "t_hihat_zcr": 0.6,
-            "is_calibrated": true
-        }"#;
-
-        let state: CalibrationState = serde_json::from_str(json).unwrap();
-
-        // Should use default value
-        assert!(
-            (state.noise_floor_rms - 0.01).abs() < 0.0001,
-            "Missing noise_floor_rms should default to 0.01: {}",
-            state.noise_floor_rms
-        );
-    }
-}
+#[path = "state_tests.rs"]
+mod tests;
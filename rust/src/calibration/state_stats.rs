@@ -0,0 +1,120 @@
+// CalibrationState - per-sound feature statistics recorded at calibration
+//
+// Summarizes how each calibrated sound spreads over the classification
+// features, so the app can tell the user how distinct their sounds are and
+// the classifier can z-score features for prototype matching.
+
+use crate::analysis::features::Features;
+
+/// Mean and variance of each classification feature for one sound
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct FeatureStats {
+    pub centroid_mean: f32,
+    pub centroid_variance: f32,
+    pub zcr_mean: f32,
+    pub zcr_variance: f32,
+}
+
+impl FeatureStats {
+    /// Population mean and variance of `samples` (must be non-empty)
+    pub fn from_samples(samples: &[Features]) -> Self {
+        let count = samples.len().max(1) as f32;
+        let centroid_mean = samples.iter().map(|f| f.centroid).sum::<f32>() / count;
+        let zcr_mean = samples.iter().map(|f| f.zcr).sum::<f32>() / count;
+        let variance = |value: fn(&Features) -> f32, mean: f32| {
+            samples
+                .iter()
+                .map(|f| (value(f) - mean).powi(2))
+                .sum::<f32>()
+                / count
+        };
+        Self {
+            centroid_mean,
+            centroid_variance: variance(|f| f.centroid, centroid_mean),
+            zcr_mean,
+            zcr_variance: variance(|f| f.zcr, zcr_mean),
+        }
+    }
+}
+
+/// Feature statistics for each calibrated sound
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CalibrationFeatureStats {
+    pub kick: FeatureStats,
+    pub snare: FeatureStats,
+    pub hihat: FeatureStats,
+}
+
+/// Separation (d′) at which `CalibrationFeatureStats::quality_score` reaches 1
+const FULL_SEPARATION_D_PRIME: f32 = 4.0;
+
+impl CalibrationFeatureStats {
+    /// How distinct the calibrated sounds are, from 0 (overlapping) to 1
+    ///
+    /// Takes the weaker of kick vs snare on centroid and snare vs hi-hat on
+    /// ZCR (the features the level 1 rules split them by), measured as d′:
+    /// distance between means over the pooled standard deviation.
+    pub fn quality_score(&self) -> f32 {
+        let d_prime = |mean_a: f32, var_a: f32, mean_b: f32, var_b: f32| {
+            let spread = ((var_a + var_b) / 2.0).sqrt();
+            let distance = (mean_a - mean_b).abs();
+            if spread > 0.0 {
+                distance / spread
+            } else if distance > 0.0 {
+                f32::INFINITY
+            } else {
+                0.0
+            }
+        };
+        let kick_snare = d_prime(
+            self.kick.centroid_mean,
+            self.kick.centroid_variance,
+            self.snare.centroid_mean,
+            self.snare.centroid_variance,
+        );
+        let snare_hihat = d_prime(
+            self.snare.zcr_mean,
+            self.snare.zcr_variance,
+            self.hihat.zcr_mean,
+            self.hihat.zcr_variance,
+        );
+        (kick_snare.min(snare_hihat) / FULL_SEPARATION_D_PRIME).min(1.0)
+    }
+}
+
+/// Per-feature z-score parameters pooled over every calibration sample
+///
+/// Centroid (Hz) and ZCR (a fraction) differ by orders of magnitude;
+/// dividing each feature's deviation by its own spread puts them on a
+/// common scale.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct FeatureNormalization {
+    pub centroid_mean: f32,
+    pub centroid_std: f32,
+    pub zcr_mean: f32,
+    pub zcr_std: f32,
+}
+
+impl FeatureNormalization {
+    /// Pooled population mean and standard deviation of `samples`
+    pub fn from_samples<'a>(samples: impl IntoIterator<Item = &'a Features>) -> Self {
+        let pooled: Vec<Features> = samples.into_iter().copied().collect();
+        let stats = FeatureStats::from_samples(&pooled);
+        Self {
+            centroid_mean: stats.centroid_mean,
+            centroid_std: stats.centroid_variance.sqrt(),
+            zcr_mean: stats.zcr_mean,
+            zcr_std: stats.zcr_variance.sqrt(),
+        }
+    }
+
+    /// Centroid expressed in standard deviations from the pooled mean
+    pub fn z_centroid(&self, centroid: f32) -> f32 {
+        (centroid - self.centroid_mean) / self.centroid_std.max(f32::EPSILON)
+    }
+
+    /// ZCR expressed in standard deviations from the pooled mean
+    pub fn z_zcr(&self, zcr: f32) -> f32 {
+        (zcr - self.zcr_mean) / self.zcr_std.max(f32::EPSILON)
+    }
+}
@@ -0,0 +1,391 @@
+use super::*;
+
+/// Helper function to create valid test features
+fn create_test_features(centroid: f32, zcr: f32) -> Features {
+    Features {
+        centroid,
+        zcr,
+        flatness: 0.5,
+        rolloff: 5000.0,
+        decay_time_ms: 50.0,
+    }
+}
+
+/// Helper function to create 10 identical features
+fn create_test_samples(centroid: f32, zcr: f32) -> Vec<Features> {
+    vec![create_test_features(centroid, zcr); 10]
+}
+
+#[test]
+fn test_new_default() {
+    let state = CalibrationState::new_default();
+
+    assert_eq!(state.t_kick_centroid, 1500.0);
+    assert_eq!(state.t_kick_zcr, 0.1);
+    assert_eq!(state.t_snare_centroid, 4000.0);
+    assert_eq!(state.t_hihat_zcr, 0.3);
+    assert!(!state.is_calibrated);
+    assert!((state.noise_floor_rms - 0.01).abs() < 0.0001);
+}
+
+#[test]
+fn test_validate_accepts_default_and_calibrated_states() {
+    assert_eq!(CalibrationState::new_default().validate(), Ok(()));
+    let calibrated = CalibrationState::from_samples(
+        &create_test_samples(1000.0, 0.05),
+        &create_test_samples(3000.0, 0.15),
+        &create_test_samples(8000.0, 0.5),
+        10,
+        0.01,
+    )
+    .unwrap();
+    assert_eq!(calibrated.validate(), Ok(()));
+    assert_eq!(calibrated.to_normalized().validate(), Ok(()));
+}
+
+#[test]
+fn test_validate_rejects_snare_centroid_below_kick() {
+    let state = CalibrationState {
+        t_kick_centroid: 3000.0,
+        t_snare_centroid: 1200.0,
+        ..CalibrationState::new_default()
+    };
+
+    let problems = state.validate().unwrap_err();
+    assert_eq!(problems.len(), 1, "{problems:?}");
+    assert!(problems[0].contains("kick centroid threshold 3000"));
+    assert!(problems[0].contains("snare centroid threshold 1200"));
+}
+
+#[test]
+fn test_validate_lists_every_problem() {
+    let state = CalibrationState {
+        level: 7,
+        t_hihat_zcr: f32::NAN,
+        noise_floor_rms: 3.0,
+        ..CalibrationState::new_default()
+    };
+
+    let problems = state.validate().unwrap_err();
+    assert_eq!(problems.len(), 3, "{problems:?}");
+    assert!(problems[0].starts_with("level 7"));
+    assert!(problems[1].starts_with("t_hihat_zcr"));
+    assert!(problems[2].starts_with("noise_floor_rms 3"));
+}
+
+#[test]
+fn test_from_samples_valid() {
+    // Create valid samples with known values
+    let kick_samples = create_test_samples(1000.0, 0.05);
+    let snare_samples = create_test_samples(3000.0, 0.15);
+    let hihat_samples = create_test_samples(8000.0, 0.5);
+
+    let result =
+        CalibrationState::from_samples(&kick_samples, &snare_samples, &hihat_samples, 10, 0.01);
+
+    assert!(result.is_ok());
+    let state = result.unwrap();
+
+    // Check that thresholds are mean * 1.2 (with floating point tolerance)
+    assert!((state.t_kick_centroid - 1000.0 * 1.2).abs() < 0.01);
+    assert!((state.t_kick_zcr - 0.05 * 1.2).abs() < 0.0001);
+    assert!((state.t_snare_centroid - 3000.0 * 1.2).abs() < 0.01);
+    assert!((state.t_hihat_zcr - 0.5 * 1.2).abs() < 0.0001);
+    assert!(state.is_calibrated);
+}
+
+#[test]
+fn test_from_samples_wrong_count_kick() {
+    let kick_samples = create_test_samples(1000.0, 0.05)[..5].to_vec(); // Only 5 samples
+    let snare_samples = create_test_samples(3000.0, 0.15);
+    let hihat_samples = create_test_samples(8000.0, 0.5);
+
+    let result =
+        CalibrationState::from_samples(&kick_samples, &snare_samples, &hihat_samples, 10, 0.01);
+
+    assert!(result.is_err());
+    match result.unwrap_err() {
+        crate::error::CalibrationError::InsufficientSamples {
+            required: 10,
+            collected: 5,
+            ..
+        } => {}
+        e => panic!("Expected InsufficientSamples error, got: {:?}", e),
+    }
+}
+
+#[test]
+fn test_from_samples_wrong_count_snare() {
+    let kick_samples = create_test_samples(1000.0, 0.05);
+    let snare_samples = create_test_samples(3000.0, 0.15)[..8].to_vec(); // Only 8 samples
+    let hihat_samples = create_test_samples(8000.0, 0.5);
+
+    let result =
+        CalibrationState::from_samples(&kick_samples, &snare_samples, &hihat_samples, 10, 0.01);
+
+    assert!(result.is_err());
+    match result.unwrap_err() {
+        crate::error::CalibrationError::InsufficientSamples {
+            required: 10,
+            collected: 8,
+            ..
+        } => {}
+        e => panic!("Expected InsufficientSamples error, got: {:?}", e),
+    }
+}
+
+#[test]
+fn test_from_samples_wrong_count_hihat() {
+    let kick_samples = create_test_samples(1000.0, 0.05);
+    let snare_samples = create_test_samples(3000.0, 0.15);
+    // Create 12 samples explicitly
+    let mut hihat_samples = create_test_samples(8000.0, 0.5);
+    hihat_samples.push(create_test_features(8000.0, 0.5));
+    hihat_samples.push(create_test_features(8000.0, 0.5));
+
+    let result =
+        CalibrationState::from_samples(&kick_samples, &snare_samples, &hihat_samples, 10, 0.01);
+
+    assert!(result.is_err());
+    match result.unwrap_err() {
+        crate::error::CalibrationError::InsufficientSamples {
+            required: 10,
+            collected: 12,
+            ..
+        } => {}
+        e => panic!("Expected InsufficientSamples error, got: {:?}", e),
+    }
+}
+
+#[test]
+fn test_from_samples_centroid_too_low() {
+    let kick_samples = create_test_samples(30.0, 0.05); // Centroid too low (< 50 Hz)
+    let snare_samples = create_test_samples(3000.0, 0.15);
+    let hihat_samples = create_test_samples(8000.0, 0.5);
+
+    let result =
+        CalibrationState::from_samples(&kick_samples, &snare_samples, &hihat_samples, 10, 0.01);
+
+    assert!(result.is_err());
+    match result.unwrap_err() {
+        crate::error::CalibrationError::InvalidFeatures { reason } => {
+            assert!(reason.contains("centroid") && reason.contains("30"));
+        }
+        e => panic!("Expected InvalidFeatures error, got: {:?}", e),
+    }
+}
+
+#[test]
+fn test_from_samples_centroid_too_high() {
+    let kick_samples = create_test_samples(1000.0, 0.05);
+    let snare_samples = create_test_samples(25000.0, 0.15); // Centroid too high (> 20000 Hz)
+    let hihat_samples = create_test_samples(8000.0, 0.5);
+
+    let result =
+        CalibrationState::from_samples(&kick_samples, &snare_samples, &hihat_samples, 10, 0.01);
+
+    assert!(result.is_err());
+    match result.unwrap_err() {
+        crate::error::CalibrationError::InvalidFeatures { reason } => {
+            assert!(reason.contains("centroid") && reason.contains("25000"));
+        }
+        e => panic!("Expected InvalidFeatures error, got: {:?}", e),
+    }
+}
+
+#[test]
+fn test_from_samples_zcr_too_low() {
+    let kick_samples = create_test_samples(1000.0, -0.1); // ZCR too low (< 0.0)
+    let snare_samples = create_test_samples(3000.0, 0.15);
+    let hihat_samples = create_test_samples(8000.0, 0.5);
+
+    let result =
+        CalibrationState::from_samples(&kick_samples, &snare_samples, &hihat_samples, 10, 0.01);
+
+    assert!(result.is_err());
+    match result.unwrap_err() {
+        crate::error::CalibrationError::InvalidFeatures { reason } => {
+            assert!(reason.contains("ZCR") && reason.contains("-0.1"));
+        }
+        e => panic!("Expected InvalidFeatures error, got: {:?}", e),
+    }
+}
+
+#[test]
+fn test_from_samples_zcr_too_high() {
+    let kick_samples = create_test_samples(1000.0, 0.05);
+    let snare_samples = create_test_samples(3000.0, 0.15);
+    let hihat_samples = create_test_samples(8000.0, 1.5); // ZCR too high (> 1.0)
+
+    let result =
+        CalibrationState::from_samples(&kick_samples, &snare_samples, &hihat_samples, 10, 0.01);
+
+    assert!(result.is_err());
+    match result.unwrap_err() {
+        crate::error::CalibrationError::InvalidFeatures { reason } => {
+            assert!(reason.contains("ZCR") && reason.contains("1.5"));
+        }
+        e => panic!("Expected InvalidFeatures error, got: {:?}", e),
+    }
+}
+
+#[test]
+fn test_from_samples_mean_calculation() {
+    // Create samples with varying values to test mean calculation
+    let mut kick_samples = Vec::new();
+    for i in 0..10 {
+        kick_samples.push(create_test_features(1000.0 + i as f32 * 10.0, 0.05));
+    }
+
+    let snare_samples = create_test_samples(3000.0, 0.15);
+    let hihat_samples = create_test_samples(8000.0, 0.5);
+
+    let result =
+        CalibrationState::from_samples(&kick_samples, &snare_samples, &hihat_samples, 10, 0.01);
+
+    assert!(result.is_ok());
+    let state = result.unwrap();
+
+    // Mean of 1000, 1010, 1020, ..., 1090 = 1045
+    let expected_kick_centroid = 1045.0 * 1.2;
+    assert!((state.t_kick_centroid - expected_kick_centroid).abs() < 0.01);
+}
+
+#[test]
+fn test_from_samples_20_percent_margin() {
+    let kick_samples = create_test_samples(1000.0, 0.1);
+    let snare_samples = create_test_samples(2000.0, 0.2);
+    let hihat_samples = create_test_samples(5000.0, 0.4);
+
+    let result =
+        CalibrationState::from_samples(&kick_samples, &snare_samples, &hihat_samples, 10, 0.01);
+
+    assert!(result.is_ok());
+    let state = result.unwrap();
+
+    // Verify 20% margin (multiply by 1.2) with floating point tolerance
+    assert!((state.t_kick_centroid - 1000.0 * 1.2).abs() < 0.01); // 1200.0
+    assert!((state.t_kick_zcr - 0.1 * 1.2).abs() < 0.0001); // 0.12
+    assert!((state.t_snare_centroid - 2000.0 * 1.2).abs() < 0.01); // 2400.0
+    assert!((state.t_hihat_zcr - 0.4 * 1.2).abs() < 0.0001); // 0.48
+}
+
+#[test]
+fn test_validate_samples_edge_cases() {
+    // Test samples at exact boundaries (should be valid)
+    let kick_samples = create_test_samples(50.0, 0.0); // Min valid values
+    let snare_samples = create_test_samples(20000.0, 1.0); // Max valid values
+    let hihat_samples = create_test_samples(10000.0, 0.5);
+
+    let result =
+        CalibrationState::from_samples(&kick_samples, &snare_samples, &hihat_samples, 10, 0.01);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_serialization_includes_noise_floor_rms() {
+    // Create a calibration state with specific noise_floor_rms
+    let kick_samples = create_test_samples(1000.0, 0.05);
+    let snare_samples = create_test_samples(3000.0, 0.15);
+    let hihat_samples = create_test_samples(8000.0, 0.5);
+
+    let noise_floor = 0.0065; // Specific value to check
+    let state = CalibrationState::from_samples(
+        &kick_samples,
+        &snare_samples,
+        &hihat_samples,
+        10,
+        noise_floor,
+    )
+    .unwrap();
+
+    // Serialize to JSON
+    let json = serde_json::to_string(&state).unwrap();
+    eprintln!("Serialized JSON: {}", json);
+
+    // Verify noise_floor_rms is in the JSON
+    assert!(
+        json.contains("noise_floor_rms"),
+        "JSON should contain noise_floor_rms field: {}",
+        json
+    );
+    assert!(
+        json.contains("0.0065"),
+        "JSON should contain noise_floor_rms value 0.0065: {}",
+        json
+    );
+
+    // Deserialize and verify round-trip
+    let deserialized: CalibrationState = serde_json::from_str(&json).unwrap();
+    assert!(
+        (deserialized.noise_floor_rms - noise_floor).abs() < 0.0001,
+        "Round-trip should preserve noise_floor_rms: {} vs {}",
+        deserialized.noise_floor_rms,
+        noise_floor
+    );
+}
+
+#[test]
+fn test_deserialization_without_noise_floor_uses_default() {
+    // JSON without noise_floor_rms field (legacy format)
+    let json = r#"{
+        "level": 1,
+        "t_kick_centroid": 1200.0,
+        "t_kick_zcr": 0.06,
+        "t_snare_centroid": 3600.0,
+        "t_hihat_zcr": 0.6,
+        "is_calibrated": true
+    }"#;
+
+    let state: CalibrationState = serde_json::from_str(json).unwrap();
+
+    // Should use default value
+    assert!(
+        (state.noise_floor_rms - 0.01).abs() < 0.0001,
+        "Missing noise_floor_rms should default to 0.01: {}",
+        state.noise_floor_rms
+    );
+}
+
+#[test]
+fn test_legacy_state_defaults_to_per_sample_at_engine_rate() {
+    let json = r#"{
+        "t_kick_centroid": 1200.0,
+        "t_kick_zcr": 0.06,
+        "t_snare_centroid": 3600.0,
+        "t_hihat_zcr": 0.6,
+        "is_calibrated": true
+    }"#;
+
+    let state: CalibrationState = serde_json::from_str(json).unwrap();
+    assert_eq!(state.feature_units, FeatureUnits::PerSample);
+    assert_eq!(state.sample_rate, 48_000);
+    assert_eq!(state.input_gain, 1.0);
+}
+
+#[test]
+fn test_for_sample_rate_rescales_zcr_only() {
+    let mut state = CalibrationState::new_default();
+    state.sample_rate = 44_100;
+    state.t_hihat_zcr = 0.2;
+
+    let converted = state.for_sample_rate(48_000);
+    assert_eq!(converted.sample_rate, 48_000);
+    assert!((converted.t_hihat_zcr - 0.2 * 44_100.0 / 48_000.0).abs() < 1e-6);
+    assert_eq!(converted.t_kick_centroid, state.t_kick_centroid);
+    assert_eq!(converted.t_snare_centroid, state.t_snare_centroid);
+}
+
+#[test]
+fn test_normalized_round_trip() {
+    let state = CalibrationState::new_default();
+    let normalized = state.to_normalized();
+    assert_eq!(normalized.feature_units, FeatureUnits::Normalized);
+    assert!((normalized.t_kick_zcr - 0.1 * 48_000.0).abs() < 0.01);
+
+    let restored = normalized.for_sample_rate(48_000);
+    assert_eq!(restored.feature_units, FeatureUnits::PerSample);
+    assert!((restored.t_kick_zcr - state.t_kick_zcr).abs() < 1e-6);
+    assert!((restored.t_hihat_zcr - state.t_hihat_zcr).abs() < 1e-6);
+}
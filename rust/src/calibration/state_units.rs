@@ -0,0 +1,85 @@
+// CalibrationState - sample-rate and ZCR unit conversion
+
+use crate::analysis::features::FeatureUnits;
+
+use super::{CalibrationFeatureStats, CalibrationState, FeatureNormalization, FeatureStats};
+
+impl CalibrationState {
+    /// Convert thresholds to sample-rate-independent units (ZCR per second)
+    ///
+    /// Useful before persisting a calibration that may be loaded on another device.
+    pub fn to_normalized(&self) -> Self {
+        Self {
+            t_kick_zcr: self.zcr_per_second(self.t_kick_zcr),
+            t_hihat_zcr: self.zcr_per_second(self.t_hihat_zcr),
+            feature_units: FeatureUnits::Normalized,
+            feature_stats: self
+                .feature_stats
+                .map(|stats| stats.scale_zcr(self.zcr_per_second(1.0))),
+            feature_normalization: self
+                .feature_normalization
+                .map(|norm| norm.scale_zcr(self.zcr_per_second(1.0))),
+            ..self.clone()
+        }
+    }
+
+    /// Rescale thresholds to per-sample units at `sample_rate`
+    ///
+    /// This is the form the classifier consumes. Centroid thresholds are in Hz
+    /// and pass through unchanged; ZCR thresholds are rescaled from whichever
+    /// convention and rate the state was stored with.
+    pub fn for_sample_rate(&self, sample_rate: u32) -> Self {
+        let target = sample_rate.max(1) as f32;
+        Self {
+            t_kick_zcr: self.zcr_per_second(self.t_kick_zcr) / target,
+            t_hihat_zcr: self.zcr_per_second(self.t_hihat_zcr) / target,
+            feature_units: FeatureUnits::PerSample,
+            feature_stats: self
+                .feature_stats
+                .map(|stats| stats.scale_zcr(self.zcr_per_second(1.0) / target)),
+            feature_normalization: self
+                .feature_normalization
+                .map(|norm| norm.scale_zcr(self.zcr_per_second(1.0) / target)),
+            sample_rate,
+            ..self.clone()
+        }
+    }
+
+    /// Express a stored ZCR threshold in crossings per second
+    pub(super) fn zcr_per_second(&self, zcr: f32) -> f32 {
+        match self.feature_units {
+            FeatureUnits::PerSample => zcr * self.sample_rate as f32,
+            FeatureUnits::Normalized => zcr,
+        }
+    }
+}
+
+impl FeatureStats {
+    fn scale_zcr(self, factor: f32) -> Self {
+        Self {
+            zcr_mean: self.zcr_mean * factor,
+            zcr_variance: self.zcr_variance * factor * factor,
+            ..self
+        }
+    }
+}
+
+impl CalibrationFeatureStats {
+    fn scale_zcr(self, factor: f32) -> Self {
+        Self {
+            kick: self.kick.scale_zcr(factor),
+            snare: self.snare.scale_zcr(factor),
+            hihat: self.hihat.scale_zcr(factor),
+        }
+    }
+}
+
+impl FeatureNormalization {
+    fn scale_zcr(self, factor: f32) -> Self {
+        Self {
+            zcr_mean: self.zcr_mean * factor,
+            zcr_std: self.zcr_std * factor.abs(),
+            ..self
+        }
+    }
+}
@@ -0,0 +1,116 @@
+// CalibrationState - plausibility checks for samples and stored states
+
+use crate::analysis::features::Features;
+use crate::error::CalibrationError;
+
+use super::CalibrationState;
+
+/// Noise floor RMS above which a stored calibration is considered corrupt
+const MAX_PLAUSIBLE_NOISE_FLOOR_RMS: f64 = 0.5;
+
+/// Headroom allowed above the feature maxima, matching the 20% margin
+/// calibrated thresholds are placed at
+const THRESHOLD_HEADROOM: f32 = 1.2;
+
+impl CalibrationState {
+    /// Check that the state is plausible before loading it
+    ///
+    /// Catches calibrations from old app versions or corrupt files: an
+    /// unknown level, thresholds that are not finite or fall outside what
+    /// the features can produce, a kick centroid threshold at or above the
+    /// snare one (no centroid would classify as snare), and an implausible
+    /// noise floor or input gain. Every problem found is listed.
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut problems = Vec::new();
+
+        if !matches!(self.level, 1 | 2) {
+            problems.push(format!("level {} is not 1 or 2", self.level));
+        }
+        if self.sample_rate == 0 {
+            problems.push("sample_rate is 0".to_string());
+        }
+
+        let max_centroid = self.sample_rate as f32 / 2.0 * THRESHOLD_HEADROOM;
+        for (name, value) in [
+            ("t_kick_centroid", self.t_kick_centroid),
+            ("t_snare_centroid", self.t_snare_centroid),
+        ] {
+            if !value.is_finite() || value <= 0.0 || value > max_centroid {
+                problems.push(format!(
+                    "{name} {value} Hz is outside (0, {max_centroid}] Hz"
+                ));
+            }
+        }
+        // At most one crossing per sample, in crossings per second
+        let max_zcr = self.sample_rate as f32 * THRESHOLD_HEADROOM;
+        for (name, value) in [
+            ("t_kick_zcr", self.t_kick_zcr),
+            ("t_hihat_zcr", self.t_hihat_zcr),
+        ] {
+            if !value.is_finite() || value <= 0.0 || self.zcr_per_second(value) > max_zcr {
+                problems.push(format!("{name} {value} is outside the possible ZCR range"));
+            }
+        }
+        if self.t_kick_centroid >= self.t_snare_centroid {
+            problems.push(format!(
+                "kick centroid threshold {} Hz must be below snare centroid threshold {} Hz",
+                self.t_kick_centroid, self.t_snare_centroid
+            ));
+        }
+
+        if !self.noise_floor_rms.is_finite()
+            || !(0.0..=MAX_PLAUSIBLE_NOISE_FLOOR_RMS).contains(&self.noise_floor_rms)
+        {
+            problems.push(format!(
+                "noise_floor_rms {} is outside [0, {MAX_PLAUSIBLE_NOISE_FLOOR_RMS}]",
+                self.noise_floor_rms
+            ));
+        }
+        if !self.input_gain.is_finite() || self.input_gain <= 0.0 {
+            problems.push(format!("input_gain {} must be positive", self.input_gain));
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
+        }
+    }
+
+    /// Validate that all samples are within acceptable ranges
+    ///
+    /// # Arguments
+    /// * `samples` - Features to validate
+    /// * `sound_name` - Name of sound type for error messages
+    ///
+    /// # Returns
+    /// * `Ok(())` - All samples valid
+    /// * `Err(CalibrationError)` - Validation error with details
+    pub(super) fn validate_samples(
+        samples: &[Features],
+        sound_name: &str,
+    ) -> Result<(), CalibrationError> {
+        for (i, features) in samples.iter().enumerate() {
+            // Validate centroid range [50 Hz, 20000 Hz]
+            if features.centroid < 50.0 || features.centroid > 20000.0 {
+                return Err(CalibrationError::InvalidFeatures {
+                    reason: format!(
+                        "{} sample {}: centroid {} Hz out of range [50, 20000]",
+                        sound_name, i, features.centroid
+                    ),
+                });
+            }
+
+            // Validate ZCR range [0.0, 1.0]
+            if features.zcr < 0.0 || features.zcr > 1.0 {
+                return Err(CalibrationError::InvalidFeatures {
+                    reason: format!(
+                        "{} sample {}: ZCR {} out of range [0.0, 1.0]",
+                        sound_name, i, features.zcr
+                    ),
+                });
+            }
+        }
+        Ok(())
+    }
+}
@@ -3,88 +3,290 @@
 //! This module provides runtime configuration loading from JSON files,
 //! enabling fast iteration without recompilation. Key parameters for
 //! onset detection, calibration, and audio processing can be adjusted
-//! via the config file for rapid experimentation.
+//! via the config file for rapid experimentation. Sections and fields
+//! missing from the file fall back to their defaults.
 
+use crate::analysis::features::FrequencyScale;
+use crate::types::BeatboxHit;
+use anyhow::{ensure, Context};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 
+#[path = "config_audio.rs"]
+mod config_audio;
+#[path = "config_calibration.rs"]
+mod config_calibration;
+#[path = "config_onset.rs"]
+mod config_onset;
+
+pub use config_audio::{AudioConfig, ClickConfig, ClickStyle, InputChannel};
+pub use config_calibration::{CalibrationConfig, ExpectedFeatureRanges, FeatureRange};
+pub use config_onset::{
+    DetectionSource, ListeningWindowPolicy, OnsetDetectionConfig, OnsetMethodKind,
+};
+
 /// Complete application configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppConfig {
+    #[serde(default)]
     pub onset_detection: OnsetDetectionConfig,
+    #[serde(default)]
     pub calibration: CalibrationConfig,
+    #[serde(default)]
     pub audio: AudioConfig,
+    #[serde(default)]
+    pub classifier: ClassifierConfig,
+    #[serde(default)]
+    pub results: ResultEmissionConfig,
+    #[serde(default)]
+    pub analysis_worker: AnalysisWorkerConfig,
+    #[serde(default)]
+    pub input: InputConfig,
+    #[serde(default)]
+    pub timing: TimingConfig,
+    #[serde(default)]
+    pub display: DisplayConfig,
+}
+
+/// The [`AppConfig`] sections the analysis thread runs with
+#[derive(Debug, Clone, Default)]
+pub struct AnalysisConfig {
+    pub onset_detection: OnsetDetectionConfig,
+    pub classifier: ClassifierConfig,
+    pub results: ResultEmissionConfig,
+    pub analysis_worker: AnalysisWorkerConfig,
+    pub input: InputConfig,
+    pub timing: TimingConfig,
+    pub display: DisplayConfig,
+}
+
+/// Handling of hits whose window is clipped
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClipPolicy {
+    /// Send the result flagged `clipped`, with its confidence halved
+    #[default]
+    Flag,
+    /// Drop the result
+    Suppress,
 }
 
-/// Onset detection algorithm parameters
+/// Which classification results are sent, and how often
+///
+/// Applied after classification and the onset/level debouncing, in the
+/// order the fields are listed.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct OnsetDetectionConfig {
-    /// Threshold offset added to median for adaptive thresholding
-    pub threshold_offset: f32,
-    /// FFT window size in samples
-    pub window_size: usize,
-    /// Hop size for overlapping windows
-    pub hop_size: usize,
-    /// Half-size of median filter window (full window = 2 * this + 1)
-    pub median_window_halfsize: usize,
-    /// Minimum buffer size before processing onset detection
-    pub min_buffer_size: usize,
-}
-
-impl Default for OnsetDetectionConfig {
+#[serde(default)]
+pub struct ResultEmissionConfig {
+    /// Results with confidence below this are not sent at all, not even as
+    /// `Unknown`, to keep borderline ambient sounds off the UI (0 sends all)
+    pub suppress_below_confidence: f32,
+    /// Minimum spacing between emitted classification results in
+    /// milliseconds (0 disables)
+    pub min_result_interval_ms: f32,
+    /// Per-sound minimum spacing between emitted results in milliseconds,
+    /// e.g. `{"Kick": 200.0}`; sounds without an entry are not limited
+    pub per_sound_min_interval_ms: HashMap<BeatboxHit, f32>,
+    /// Global cap on emitted classification results per second, applied
+    /// after the per-sound limits so fast rolls cannot flood subscribers
+    /// (0 disables)
+    pub max_results_per_second: f32,
+    /// Results that may be emitted back-to-back before the cap kicks in
+    pub max_result_burst: u32,
+    /// Weight of the newest hit in the per-sound exponential moving average
+    /// of confidence reported as `smoothed_confidence` (0 disables, 1 means
+    /// no smoothing)
+    pub confidence_smoothing: f32,
+}
+
+impl Default for ResultEmissionConfig {
     fn default() -> Self {
         Self {
-            // Increased from 0.01 to 0.15 to avoid triggering on background noise
-            // This is added to the median spectral flux for adaptive thresholding
-            threshold_offset: 0.15,
-            window_size: 256,
-            hop_size: 64,
-            median_window_halfsize: 50,
-            min_buffer_size: 512,
+            suppress_below_confidence: 0.0,
+            min_result_interval_ms: 0.0,
+            per_sound_min_interval_ms: HashMap::new(),
+            max_results_per_second: 0.0,
+            max_result_burst: 4,
+            confidence_smoothing: 0.0,
         }
     }
 }
 
-/// Calibration procedure configuration
+/// Scheduling of the analysis thread itself
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CalibrationConfig {
-    /// Number of samples to collect per sound type
-    pub samples_per_sound: usize,
-    /// Minimum interval between samples in milliseconds (0 to disable)
-    pub min_sample_interval_ms: u128,
-    /// Enable debug overlay in UI
-    pub enable_debug_overlay: bool,
-    /// Log statistics every N buffers
-    pub log_every_n_buffers: u64,
-}
-
-impl Default for CalibrationConfig {
+#[serde(default)]
+pub struct AnalysisWorkerConfig {
+    /// Ask the OS for elevated scheduling priority on the analysis thread
+    /// (Linux desktop); falls back to normal priority if denied
+    pub elevated_thread_priority: bool,
+    /// Longest sleep (ms) of the analysis thread while its queue stays
+    /// empty; the sleep doubles from 1ms up to this and drops back to 1ms as
+    /// soon as a buffer arrives (1 keeps a flat 1ms poll)
+    pub max_idle_sleep_ms: u64,
+    /// Most samples the analysis accumulator holds; if analysis falls
+    /// behind, the oldest are dropped so only the most recent audio is
+    /// analyzed (never below `onset_detection.min_buffer_size`)
+    pub max_accumulator_size: usize,
+}
+
+impl Default for AnalysisWorkerConfig {
     fn default() -> Self {
         Self {
-            samples_per_sound: 10,
-            min_sample_interval_ms: 250,
-            enable_debug_overlay: true,
-            log_every_n_buffers: 100,
+            elevated_thread_priority: false,
+            max_idle_sleep_ms: 16,
+            max_accumulator_size: 16_384,
         }
     }
 }
 
-/// Audio engine configuration
+/// Checks on the input signal itself: clipping and silence
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InputConfig {
+    /// Fraction of a hit's window at the input rails (±1.0 before input
+    /// gain) from which the hit counts as clipped (0 disables)
+    pub clip_fraction_threshold: f32,
+    /// What to do with clipped hits
+    pub clip_policy: ClipPolicy,
+    /// Publish a `silence` telemetry event once the input has stayed below
+    /// the classification gate this long, in ms (0 disables). Sent once per
+    /// quiet stretch; sound above the gate re-arms it.
+    pub silence_timeout_ms: f32,
+}
+
+/// How hits are placed on the metronome grid
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct AudioConfig {
-    /// Size of buffer pool for real-time audio transfer
-    pub buffer_pool_size: usize,
-    /// Size of each audio buffer in samples
-    pub buffer_size: usize,
+#[serde(default)]
+pub struct TimingConfig {
+    /// Subdivisions per beat used for the beat position of timed results
+    pub beat_subdivisions: u32,
+    /// After a BPM change, hits within this many milliseconds are reported
+    /// as on time while the user locks onto the new grid (0 disables)
+    pub bpm_change_grace_ms: u32,
+}
+
+impl Default for TimingConfig {
+    fn default() -> Self {
+        Self {
+            beat_subdivisions: 4,
+            bpm_change_grace_ms: 300,
+        }
+    }
+}
+
+/// Units of values reported for display
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DisplayConfig {
+    /// Scale of the `centroid` and `rolloff` values reported on onset
+    /// events; the nested `features` payload stays in Hz
+    pub frequency_scale: FrequencyScale,
+}
+
+/// Classifier parameters
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ClassifierConfig {
+    /// Distance used when comparing a hit to calibrated sound prototypes;
+    /// Level 1 falls back to the nearest prototype for hits no rule claims
+    pub distance_metric: DistanceMetric,
+    /// Minimum confidence per sound; a hit classified as a listed sound with
+    /// lower confidence is reported as `Unknown` (unlisted sounds are kept)
+    pub min_confidence: HashMap<BeatboxHit, f32>,
+    /// Features the classifier may use; disabled features are left out of
+    /// both the threshold rules and prototype distances
+    pub feature_mask: FeatureMask,
 }
 
-impl Default for AudioConfig {
+/// Per-feature switches for classification (all enabled by default)
+///
+/// Rolloff has no switch: no rule or distance uses it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FeatureMask {
+    pub centroid: bool,
+    pub zcr: bool,
+    pub flatness: bool,
+    pub decay_time: bool,
+}
+
+impl Default for FeatureMask {
     fn default() -> Self {
         Self {
-            buffer_pool_size: 64,
-            buffer_size: 2048,
+            centroid: true,
+            zcr: true,
+            flatness: true,
+            decay_time: true,
+        }
+    }
+}
+
+/// Distance between a hit's features and a calibrated sound prototype
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DistanceMetric {
+    /// Root of summed squared deviations, each relative to the prototype mean
+    #[default]
+    Euclidean,
+    /// Sum of absolute deviations, each relative to the prototype mean
+    Manhattan,
+    /// Deviations scaled by the per-feature variance seen during
+    /// calibration, so features the user produces inconsistently count less
+    Mahalanobis,
+}
+
+/// Fail unless `value` is a finite number of at least zero
+fn ensure_non_negative(value: f32, name: &str) -> anyhow::Result<()> {
+    ensure!(
+        value.is_finite() && value >= 0.0,
+        "{name} must be a non-negative number"
+    );
+    Ok(())
+}
+
+/// Fail unless `value` is between 0 and 1
+fn ensure_fraction(value: f32, name: &str) -> anyhow::Result<()> {
+    ensure!(
+        (0.0..=1.0).contains(&value),
+        "{name} must be between 0 and 1"
+    );
+    Ok(())
+}
+
+impl ResultEmissionConfig {
+    fn validate(&self) -> anyhow::Result<()> {
+        ensure_fraction(
+            self.suppress_below_confidence,
+            "results.suppress_below_confidence",
+        )?;
+        ensure_fraction(self.confidence_smoothing, "results.confidence_smoothing")?;
+        ensure_non_negative(
+            self.min_result_interval_ms,
+            "results.min_result_interval_ms",
+        )?;
+        for interval in self.per_sound_min_interval_ms.values() {
+            ensure_non_negative(*interval, "results.per_sound_min_interval_ms")?;
         }
+        ensure_non_negative(
+            self.max_results_per_second,
+            "results.max_results_per_second",
+        )?;
+        ensure!(
+            self.max_result_burst >= 1,
+            "results.max_result_burst must be at least 1"
+        );
+        Ok(())
+    }
+}
+
+impl ClassifierConfig {
+    fn validate(&self) -> anyhow::Result<()> {
+        for confidence in self.min_confidence.values() {
+            ensure_fraction(*confidence, "classifier.min_confidence")?;
+        }
+        Ok(())
     }
 }
 
@@ -95,41 +297,94 @@ impl Default for AppConfig {
             onset_detection: OnsetDetectionConfig::default(),
             calibration: CalibrationConfig::default(),
             audio: AudioConfig::default(),
+            classifier: ClassifierConfig::default(),
+            results: ResultEmissionConfig::default(),
+            analysis_worker: AnalysisWorkerConfig::default(),
+            input: InputConfig::default(),
+            timing: TimingConfig::default(),
+            display: DisplayConfig::default(),
         }
     }
 }
 
 impl AppConfig {
+    /// The sections the analysis thread needs
+    pub fn analysis(&self) -> AnalysisConfig {
+        AnalysisConfig {
+            onset_detection: self.onset_detection.clone(),
+            classifier: self.classifier.clone(),
+            results: self.results.clone(),
+            analysis_worker: self.analysis_worker.clone(),
+            input: self.input.clone(),
+            timing: self.timing.clone(),
+            display: self.display.clone(),
+        }
+    }
+
+    /// Load and validate configuration from a JSON file
+    ///
+    /// Sections and fields the file omits take their default values, so a
+    /// file only needs the parameters being tuned.
+    ///
+    /// # Errors
+    /// - The file cannot be read or is not valid JSON
+    /// - A value is out of range (see [`AppConfig::validate`])
+    pub fn from_file<P: AsRef<Path>>(path: P) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("reading config file {}", path.display()))?;
+        let config: Self = serde_json::from_str(&contents)
+            .with_context(|| format!("parsing config file {}", path.display()))?;
+        config
+            .validate()
+            .with_context(|| format!("invalid config file {}", path.display()))?;
+        Ok(config)
+    }
+
+    /// Check that values are in a range the engine can run with
+    pub fn validate(&self) -> anyhow::Result<()> {
+        self.onset_detection.validate()?;
+        self.results.validate()?;
+        self.classifier.validate()?;
+        self.calibration.validate()?;
+        self.audio.validate()?;
+        self.audio.click.validate()?;
+        ensure!(
+            self.analysis_worker.max_idle_sleep_ms >= 1,
+            "analysis_worker.max_idle_sleep_ms must be at least 1"
+        );
+        ensure!(
+            self.analysis_worker.max_accumulator_size >= self.onset_detection.min_buffer_size,
+            "analysis_worker.max_accumulator_size must not be below onset_detection.min_buffer_size"
+        );
+        ensure_fraction(
+            self.input.clip_fraction_threshold,
+            "input.clip_fraction_threshold",
+        )?;
+        ensure_non_negative(self.input.silence_timeout_ms, "input.silence_timeout_ms")?;
+        ensure!(
+            self.timing.beat_subdivisions >= 1,
+            "timing.beat_subdivisions must be at least 1"
+        );
+        Ok(())
+    }
+
     /// Load configuration from JSON file
     ///
     /// # Arguments
     /// * `path` - Path to JSON config file
     ///
     /// # Returns
-    /// * `Ok(AppConfig)` - Loaded configuration
-    /// * `Err` - If file doesn't exist or JSON is invalid, returns default config
+    /// The loaded configuration, or the defaults if the file is missing or
+    /// invalid (the error is logged)
     pub fn load_from_file<P: AsRef<Path>>(path: P) -> Self {
-        match fs::read_to_string(&path) {
-            Ok(contents) => match serde_json::from_str(&contents) {
-                Ok(config) => {
-                    tracing::info!("[Config] Loaded configuration from {:?}", path.as_ref());
-                    config
-                }
-                Err(err) => {
-                    tracing::warn!(
-                        "[Config] Failed to parse JSON from {:?}: {}. Using defaults.",
-                        path.as_ref(),
-                        err
-                    );
-                    Self::default()
-                }
-            },
+        match Self::from_file(&path) {
+            Ok(config) => {
+                tracing::info!("[Config] Loaded configuration from {:?}", path.as_ref());
+                config
+            }
             Err(err) => {
-                tracing::warn!(
-                    "[Config] Failed to read config file {:?}: {}. Using defaults.",
-                    path.as_ref(),
-                    err
-                );
+                tracing::warn!("[Config] {:#}. Using defaults.", err);
                 Self::default()
             }
         }
@@ -169,31 +424,5 @@ impl AppConfig {
 }
 
 #[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_default_config() {
-        let config = AppConfig::default();
-        assert_eq!(config.onset_detection.threshold_offset, 0.15);
-        assert_eq!(config.onset_detection.window_size, 256);
-        assert_eq!(config.calibration.samples_per_sound, 10);
-        assert_eq!(config.audio.buffer_pool_size, 64);
-    }
-
-    #[test]
-    fn test_json_roundtrip() {
-        let config = AppConfig::default();
-        let json = serde_json::to_string_pretty(&config).unwrap();
-        let parsed: AppConfig = serde_json::from_str(&json).unwrap();
-
-        assert_eq!(
-            parsed.onset_detection.threshold_offset,
-            config.onset_detection.threshold_offset
-        );
-        assert_eq!(
-            parsed.calibration.samples_per_sound,
-            config.calibration.samples_per_sound
-        );
-    }
-}
+#[path = "config_tests.rs"]
+mod tests;
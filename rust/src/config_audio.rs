@@ -0,0 +1,136 @@
+// Configuration - audio engine and metronome click section
+
+use anyhow::ensure;
+use serde::{Deserialize, Serialize};
+
+use super::{ensure_fraction, ensure_non_negative};
+
+/// Audio engine configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AudioConfig {
+    /// Size of buffer pool for real-time audio transfer
+    pub buffer_pool_size: usize,
+    /// Size of each audio buffer in samples
+    pub buffer_size: usize,
+    /// Metronome click sound
+    #[serde(default)]
+    pub click: ClickConfig,
+    /// How multi-channel desktop input is reduced to mono (Android always
+    /// opens a mono input)
+    pub input_channel: InputChannel,
+}
+
+impl Default for AudioConfig {
+    fn default() -> Self {
+        Self {
+            buffer_pool_size: 64,
+            buffer_size: 2048,
+            click: ClickConfig::default(),
+            input_channel: InputChannel::default(),
+        }
+    }
+}
+
+/// Input channel analysed from a multi-channel input device
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InputChannel {
+    /// First channel only
+    Left,
+    /// Second channel only (the first on a mono device)
+    Right,
+    /// Average of all channels
+    #[default]
+    Mix,
+}
+
+/// Metronome click waveform
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClickStyle {
+    /// Deterministic white noise burst
+    Noise,
+    /// Hann-windowed sine beep
+    Pitched,
+}
+
+/// Metronome click sound parameters
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ClickConfig {
+    /// Waveform used for clicks
+    pub style: ClickStyle,
+    /// Pitch of regular clicks in Hz (pitched style only)
+    pub frequency_hz: f32,
+    /// Pitch of the accented first beat of each bar in Hz (pitched style only)
+    pub accent_frequency_hz: f32,
+    /// Click length in milliseconds
+    pub duration_ms: f32,
+    /// Beats per bar for accent placement (0 disables accents)
+    pub beats_per_bar: u32,
+    /// Pan clicks across a stereo output instead of duplicating them at
+    /// full level on every channel. Input and analysis stay mono. Devices
+    /// without stereo output fall back to mono, unpanned.
+    pub stereo: bool,
+    /// Pan of regular clicks, -1.0 (left) to 1.0 (right); stereo only.
+    /// Centered (0.0) clicks play at full level on both channels.
+    pub beat_pan: f32,
+    /// Pan of accent clicks, -1.0 (left) to 1.0 (right); stereo only
+    pub accent_pan: f32,
+    /// Fraction of bars (0.0-1.0) played silently for gap training; the
+    /// first bar always plays so the tempo is established
+    pub mute_probability: f32,
+    /// Seed choosing which bars are muted, so a session is reproducible
+    pub mute_seed: u64,
+    /// Clicks per beat, counting the beat itself (2 = 8ths, 4 = 16ths);
+    /// the extra clicks play softer. 0 or 1 disables subdivisions.
+    pub subdivision_clicks: u32,
+}
+
+impl Default for ClickConfig {
+    fn default() -> Self {
+        Self {
+            style: ClickStyle::Noise,
+            frequency_hz: 1000.0,
+            accent_frequency_hz: 1500.0,
+            duration_ms: 20.0,
+            beats_per_bar: 4,
+            stereo: false,
+            beat_pan: 0.0,
+            accent_pan: 0.0,
+            mute_probability: 0.0,
+            mute_seed: 0,
+            subdivision_clicks: 0,
+        }
+    }
+}
+
+impl AudioConfig {
+    pub(super) fn validate(&self) -> anyhow::Result<()> {
+        ensure!(
+            self.buffer_pool_size > 0 && self.buffer_size > 0,
+            "audio.buffer_pool_size and audio.buffer_size must be positive"
+        );
+        Ok(())
+    }
+}
+
+impl ClickConfig {
+    pub(super) fn validate(&self) -> anyhow::Result<()> {
+        ensure!(
+            self.frequency_hz.is_finite()
+                && self.frequency_hz > 0.0
+                && self.accent_frequency_hz.is_finite()
+                && self.accent_frequency_hz > 0.0,
+            "audio.click frequencies must be positive"
+        );
+        ensure_non_negative(self.duration_ms, "audio.click.duration_ms")?;
+        ensure!(
+            (-1.0..=1.0).contains(&self.beat_pan) && (-1.0..=1.0).contains(&self.accent_pan),
+            "audio.click pans must be between -1 and 1"
+        );
+        ensure_fraction(self.mute_probability, "audio.click.mute_probability")?;
+        Ok(())
+    }
+}
@@ -0,0 +1,152 @@
+// Configuration - calibration section
+
+use anyhow::ensure;
+use serde::{Deserialize, Serialize};
+
+/// Calibration procedure configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CalibrationConfig {
+    /// Number of samples to collect per sound type
+    pub samples_per_sound: usize,
+    /// Minimum interval between samples in milliseconds (0 to disable)
+    pub min_sample_interval_ms: u128,
+    /// Enable debug overlay in UI
+    pub enable_debug_overlay: bool,
+    /// Log statistics every N buffers
+    pub log_every_n_buffers: u64,
+    /// Advance to the next sound automatically once its samples are
+    /// complete and consistent, without waiting for confirmation
+    #[serde(default)]
+    pub auto_advance: bool,
+    /// Number of RMS buffers collected while measuring the noise floor.
+    /// Raise in noisy rooms for a steadier estimate.
+    #[serde(default = "default_noise_floor_samples")]
+    pub noise_floor_samples: u8,
+    /// Noise floor threshold (RMS) above which calibration warns that the
+    /// environment is too noisy
+    #[serde(default = "default_noise_floor_ceiling")]
+    pub noise_floor_ceiling: f64,
+    /// Number of loud hits measured before the noise floor phase to derive
+    /// an input gain (0 skips the measurement)
+    #[serde(default)]
+    pub input_level_hits: usize,
+    /// Publish a feature frame for every analysed buffer during calibration
+    /// on the calibration debug stream (for tuning UIs)
+    #[serde(default)]
+    pub debug_stream: bool,
+    /// Also classify calibration hits with the current calibration and
+    /// publish the results on the calibration preview stream (the hits
+    /// still become calibration samples)
+    #[serde(default)]
+    pub preview_classification: bool,
+    /// Typical feature ranges per sound; when set, calibration warns with
+    /// `UnexpectedTimbre` guidance about samples outside them (samples are
+    /// still accepted). Off by default.
+    #[serde(default)]
+    pub expected_ranges: Option<ExpectedFeatureRanges>,
+}
+
+/// Typical spectral centroid and ZCR range for one calibration sound
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FeatureRange {
+    pub min_centroid_hz: f32,
+    pub max_centroid_hz: f32,
+    pub min_zcr: f32,
+    pub max_zcr: f32,
+}
+
+impl FeatureRange {
+    /// Whether the features' centroid and ZCR both fall inside the range
+    pub fn contains(&self, features: &crate::analysis::features::Features) -> bool {
+        (self.min_centroid_hz..=self.max_centroid_hz).contains(&features.centroid)
+            && (self.min_zcr..=self.max_zcr).contains(&features.zcr)
+    }
+}
+
+/// Expected feature ranges for each calibrated sound
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExpectedFeatureRanges {
+    pub kick: FeatureRange,
+    pub snare: FeatureRange,
+    pub hihat: FeatureRange,
+}
+
+impl Default for ExpectedFeatureRanges {
+    fn default() -> Self {
+        Self {
+            kick: FeatureRange {
+                min_centroid_hz: 0.0,
+                max_centroid_hz: 1500.0,
+                min_zcr: 0.0,
+                max_zcr: 0.1,
+            },
+            snare: FeatureRange {
+                min_centroid_hz: 400.0,
+                max_centroid_hz: 6000.0,
+                min_zcr: 0.02,
+                max_zcr: 0.4,
+            },
+            hihat: FeatureRange {
+                min_centroid_hz: 3000.0,
+                max_centroid_hz: 20000.0,
+                min_zcr: 0.1,
+                max_zcr: 1.0,
+            },
+        }
+    }
+}
+
+fn default_noise_floor_samples() -> u8 {
+    30
+}
+
+fn default_noise_floor_ceiling() -> f64 {
+    0.05
+}
+
+impl Default for CalibrationConfig {
+    fn default() -> Self {
+        Self {
+            samples_per_sound: 10,
+            min_sample_interval_ms: 250,
+            enable_debug_overlay: true,
+            log_every_n_buffers: 100,
+            auto_advance: false,
+            noise_floor_samples: default_noise_floor_samples(),
+            noise_floor_ceiling: default_noise_floor_ceiling(),
+            input_level_hits: 0,
+            debug_stream: false,
+            preview_classification: false,
+            expected_ranges: None,
+        }
+    }
+}
+
+impl CalibrationConfig {
+    pub(super) fn validate(&self) -> anyhow::Result<()> {
+        ensure!(
+            self.samples_per_sound > 0,
+            "calibration.samples_per_sound must be positive"
+        );
+        ensure!(
+            self.noise_floor_samples > 0,
+            "calibration.noise_floor_samples must be positive"
+        );
+        ensure!(
+            self.noise_floor_ceiling.is_finite() && self.noise_floor_ceiling >= 0.0,
+            "calibration.noise_floor_ceiling must be a non-negative number"
+        );
+        if let Some(ranges) = &self.expected_ranges {
+            for range in [ranges.kick, ranges.snare, ranges.hihat] {
+                ensure!(
+                    range.min_centroid_hz <= range.max_centroid_hz
+                        && range.min_zcr <= range.max_zcr,
+                    "calibration.expected_ranges minimums must not exceed their maximums"
+                );
+            }
+        }
+        Ok(())
+    }
+}
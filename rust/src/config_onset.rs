@@ -0,0 +1,329 @@
+// Configuration - onset detection section
+
+use anyhow::ensure;
+use serde::{Deserialize, Serialize};
+
+use super::{ensure_fraction, ensure_non_negative};
+
+/// Onset detection algorithm parameters
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OnsetDetectionConfig {
+    /// Threshold offset added to median for adaptive thresholding
+    pub threshold_offset: f32,
+    /// FFT window size in samples
+    pub window_size: usize,
+    /// Hop size for overlapping windows
+    pub hop_size: usize,
+    /// Half-size of median filter window (full window = 2 * this + 1)
+    pub median_window_halfsize: usize,
+    /// Minimum buffer size before processing onset detection
+    pub min_buffer_size: usize,
+    /// Normalize each spectral bin by a running peak estimate before computing
+    /// flux, so the threshold is less sensitive to the microphone's frequency response
+    #[serde(default)]
+    pub spectral_whitening: bool,
+    /// Per-frame decay of the running peak estimate used for whitening
+    #[serde(default = "default_whitening_decay")]
+    pub whitening_decay: f32,
+    /// Lower bound for the running peak estimate; keeps near-silent bins
+    /// from being amplified into noise
+    #[serde(default = "default_whitening_floor")]
+    pub whitening_floor: f32,
+    /// Refine onset timestamps with parabolic interpolation of the flux peak
+    /// instead of snapping to the hop grid
+    #[serde(default)]
+    pub high_res_timing: bool,
+    /// Minimum spacing between emitted onsets in milliseconds; closer onsets
+    /// are suppressed (0 disables)
+    #[serde(default)]
+    pub min_onset_gap_ms: f32,
+    /// Envelope follower attack time for the noise gate level (ms)
+    #[serde(default = "default_gate_attack_ms")]
+    pub gate_attack_ms: f32,
+    /// Envelope follower release time for the noise gate level (ms)
+    #[serde(default = "default_gate_release_ms")]
+    pub gate_release_ms: f32,
+    /// Integration time of the VU-style `smoothed_rms` in audio metrics (ms);
+    /// 0 makes it follow the raw RMS
+    #[serde(default = "default_vu_time_constant_ms")]
+    pub vu_time_constant_ms: f32,
+    /// Moving-average length (in flux frames) applied to the spectral flux
+    /// before peak picking; 0 or 1 disables smoothing
+    #[serde(default)]
+    pub flux_smoothing_frames: usize,
+    /// Multiplier k for the robust spread term of the adaptive threshold
+    /// (threshold = median + k * MAD + offset); 0 keeps median + offset
+    #[serde(default)]
+    pub threshold_mad_k: f32,
+    /// Lowest frequency (Hz) the onset detection function looks at; bins
+    /// below it (e.g. mic rumble) are ignored. 0 keeps everything from DC.
+    #[serde(default)]
+    pub onset_min_hz: f32,
+    /// Highest frequency (Hz) the onset detection function looks at; bins
+    /// above it (e.g. hiss) are ignored. 0 keeps everything up to Nyquist.
+    #[serde(default)]
+    pub onset_max_hz: f32,
+    /// Report onsets only: skip feature extraction and classification and
+    /// publish bare `OnsetEvent`s (drum-trigger use)
+    #[serde(default)]
+    pub monitor_mode: bool,
+    /// What to do with hits outside the active listening windows
+    #[serde(default)]
+    pub outside_window_policy: ListeningWindowPolicy,
+    /// During calibration, the input counts as quiet (and active guidance is
+    /// cleared) once its RMS falls below the noise-floor threshold times
+    /// this multiplier
+    #[serde(default = "default_quiet_clear_enter_multiplier")]
+    pub quiet_clear_enter_multiplier: f64,
+    /// Once quiet, the input RMS must rise above the noise-floor threshold
+    /// times this multiplier before it stops counting as quiet
+    #[serde(default = "default_quiet_clear_exit_multiplier")]
+    pub quiet_clear_exit_multiplier: f64,
+    /// Observe the input level for this long after start, then lock the
+    /// classification gate and onset threshold relative to it (0 disables).
+    /// Only applies until a calibration is loaded; a calibrated noise floor
+    /// always wins.
+    #[serde(default)]
+    pub auto_sensitivity_ms: f32,
+    /// Outside calibration, ignore onsets whose spectral flatness is below
+    /// this: a spectrum that tonal is more likely voice or music bleed than
+    /// a hit (0 disables). Tonal kicks sit near 0.1, so keep it below that.
+    #[serde(default)]
+    pub min_flatness_for_hit: f32,
+    /// Outside calibration, skip feature extraction and classification when
+    /// the analysis window's energy (sum of squared samples over 1024
+    /// samples) is below this (0 disables). Saves the FFT on near-silent
+    /// windows that only just clear the gate.
+    #[serde(default)]
+    pub min_window_energy: f32,
+    /// Outside calibration, ignore hits whose smoothed envelope falls 20 dB
+    /// below its peak sooner than this, in ms (0 disables). Rejects mouth
+    /// clicks and pops. Measured within the 1024-sample analysis window
+    /// (~21 ms at 48 kHz), so keep it well below that.
+    #[serde(default)]
+    pub min_tail_ms: f32,
+    /// Time span (ms) searched for a hit's decay point when measuring the
+    /// `decay_time` feature, so it is bounded the same at every sample rate
+    /// (0 searches the 1024-sample feature window)
+    #[serde(default)]
+    pub decay_window_ms: f32,
+    /// How far before a detected onset the feature window starts, in ms
+    /// (0 keeps the most recent 1024 samples). Samples from the previous
+    /// batch are kept so onsets early in a batch still get their attack.
+    #[serde(default)]
+    pub pre_roll_ms: f32,
+    /// Report two onsets this close together (ms) as one result flagged
+    /// `flam` instead of two hits (0 disables). Results are held for this
+    /// long before being sent, so keep it tight.
+    #[serde(default)]
+    pub flam_window_ms: f32,
+    /// Shift onset and level-crossing feature windows this many samples
+    /// later (0 disables), so they capture a sound's body rather than its
+    /// initial click. Hits wait until those samples have arrived.
+    #[serde(default)]
+    pub post_onset_delay_samples: usize,
+    /// Most onsets analyzed per buffer; extra onsets from a noisy buffer are
+    /// dropped (and counted in telemetry) to bound the cost of one cycle
+    /// (0 disables)
+    #[serde(default)]
+    pub max_onsets_per_buffer: usize,
+    /// Onset detection function the detector peak-picks. `threshold_offset`
+    /// is in that function's units, so retune it when switching.
+    #[serde(default)]
+    pub onset_method: OnsetMethodKind,
+    /// Which detectors may produce classification results
+    #[serde(default)]
+    pub detection_source: DetectionSource,
+}
+
+/// Onset detection function used by the onset detector
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OnsetMethodKind {
+    /// Sum of positive per-bin magnitude increases
+    #[default]
+    SpectralFlux,
+    /// Rise in bin-weighted spectral energy, favouring bright attacks
+    HighFrequencyContent,
+    /// Deviation from a magnitude/phase prediction; catches soft onsets
+    ComplexDomain,
+    /// Rise in frame energy within the onset band
+    EnergyBased,
+}
+
+/// Detectors allowed to trigger classification outside calibration
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DetectionSource {
+    /// Spectral-flux onsets only
+    OnsetOnly,
+    /// Gate-level crossings only; suits mics whose noise makes flux jumpy
+    LevelCrossingOnly,
+    /// Both, with an onset dropped when a level crossing captured the same
+    /// hit within the debounce window (with `flam_window_ms` set, only the
+    /// first such onset; a later one can make the capture a flam)
+    #[default]
+    Both,
+}
+
+impl DetectionSource {
+    pub fn uses_onsets(self) -> bool {
+        self != DetectionSource::LevelCrossingOnly
+    }
+
+    pub fn uses_level_crossing(self) -> bool {
+        self != DetectionSource::OnsetOnly
+    }
+}
+
+/// Handling of hits outside the active listening windows
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ListeningWindowPolicy {
+    /// Emit the result with `extra` set so the UI can show it unscored
+    #[default]
+    Flag,
+    /// Drop the result entirely
+    Suppress,
+}
+
+fn default_gate_attack_ms() -> f32 {
+    1.0
+}
+
+fn default_gate_release_ms() -> f32 {
+    60.0
+}
+
+fn default_vu_time_constant_ms() -> f32 {
+    300.0
+}
+
+fn default_whitening_decay() -> f32 {
+    0.997
+}
+
+fn default_whitening_floor() -> f32 {
+    0.05
+}
+
+fn default_quiet_clear_enter_multiplier() -> f64 {
+    1.05
+}
+
+fn default_quiet_clear_exit_multiplier() -> f64 {
+    1.5
+}
+
+impl Default for OnsetDetectionConfig {
+    fn default() -> Self {
+        Self {
+            // Increased from 0.01 to 0.15 to avoid triggering on background noise
+            // This is added to the median spectral flux for adaptive thresholding
+            threshold_offset: 0.15,
+            window_size: 256,
+            hop_size: 64,
+            median_window_halfsize: 50,
+            min_buffer_size: 512,
+            spectral_whitening: false,
+            whitening_decay: default_whitening_decay(),
+            whitening_floor: default_whitening_floor(),
+            high_res_timing: false,
+            min_onset_gap_ms: 0.0,
+            gate_attack_ms: default_gate_attack_ms(),
+            gate_release_ms: default_gate_release_ms(),
+            vu_time_constant_ms: default_vu_time_constant_ms(),
+            flux_smoothing_frames: 0,
+            threshold_mad_k: 0.0,
+            onset_min_hz: 0.0,
+            onset_max_hz: 0.0,
+            monitor_mode: false,
+            outside_window_policy: ListeningWindowPolicy::default(),
+            quiet_clear_enter_multiplier: default_quiet_clear_enter_multiplier(),
+            quiet_clear_exit_multiplier: default_quiet_clear_exit_multiplier(),
+            auto_sensitivity_ms: 0.0,
+            min_flatness_for_hit: 0.0,
+            min_window_energy: 0.0,
+            min_tail_ms: 0.0,
+            decay_window_ms: 0.0,
+            pre_roll_ms: 0.0,
+            flam_window_ms: 0.0,
+            post_onset_delay_samples: 0,
+            max_onsets_per_buffer: 0,
+            onset_method: OnsetMethodKind::default(),
+            detection_source: DetectionSource::default(),
+        }
+    }
+}
+
+impl OnsetDetectionConfig {
+    pub(super) fn validate(&self) -> anyhow::Result<()> {
+        ensure_non_negative(self.threshold_offset, "onset_detection.threshold_offset")?;
+        ensure!(
+            self.window_size.is_power_of_two(),
+            "onset_detection.window_size must be a power of two"
+        );
+        ensure!(
+            self.hop_size > 0 && self.hop_size <= self.window_size,
+            "onset_detection.hop_size must be between 1 and window_size"
+        );
+        ensure!(
+            self.min_buffer_size > 0,
+            "onset_detection.min_buffer_size must be positive"
+        );
+        ensure!(
+            (0.0..=1.0).contains(&self.whitening_decay),
+            "onset_detection.whitening_decay must be between 0 and 1"
+        );
+        ensure!(
+            self.whitening_floor.is_finite() && self.whitening_floor > 0.0,
+            "onset_detection.whitening_floor must be positive"
+        );
+        ensure!(
+            self.flux_smoothing_frames <= 2 * self.median_window_halfsize + 1,
+            "onset_detection.flux_smoothing_frames must not exceed the median window"
+        );
+        ensure!(
+            self.quiet_clear_enter_multiplier.is_finite()
+                && self.quiet_clear_enter_multiplier > 0.0,
+            "onset_detection.quiet_clear_enter_multiplier must be positive"
+        );
+        ensure!(
+            self.quiet_clear_exit_multiplier >= self.quiet_clear_enter_multiplier,
+            "onset_detection.quiet_clear_exit_multiplier must not be below the enter multiplier"
+        );
+        ensure!(
+            self.onset_max_hz == 0.0 || self.onset_min_hz < self.onset_max_hz,
+            "onset_detection.onset_min_hz must be below onset_max_hz"
+        );
+        ensure_fraction(
+            self.min_flatness_for_hit,
+            "onset_detection.min_flatness_for_hit",
+        )?;
+        for (value, name) in [
+            (self.min_onset_gap_ms, "onset_detection.min_onset_gap_ms"),
+            (self.gate_attack_ms, "onset_detection.gate_attack_ms"),
+            (self.gate_release_ms, "onset_detection.gate_release_ms"),
+            (
+                self.vu_time_constant_ms,
+                "onset_detection.vu_time_constant_ms",
+            ),
+            (self.threshold_mad_k, "onset_detection.threshold_mad_k"),
+            (self.onset_min_hz, "onset_detection.onset_min_hz"),
+            (self.onset_max_hz, "onset_detection.onset_max_hz"),
+            (
+                self.auto_sensitivity_ms,
+                "onset_detection.auto_sensitivity_ms",
+            ),
+            (self.min_window_energy, "onset_detection.min_window_energy"),
+            (self.min_tail_ms, "onset_detection.min_tail_ms"),
+            (self.decay_window_ms, "onset_detection.decay_window_ms"),
+            (self.pre_roll_ms, "onset_detection.pre_roll_ms"),
+            (self.flam_window_ms, "onset_detection.flam_window_ms"),
+        ] {
+            ensure_non_negative(value, name)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,122 @@
+use super::*;
+
+#[test]
+fn test_default_config() {
+    let config = AppConfig::default();
+    assert_eq!(config.onset_detection.threshold_offset, 0.15);
+    assert_eq!(config.onset_detection.window_size, 256);
+    assert_eq!(config.calibration.samples_per_sound, 10);
+    assert_eq!(config.audio.buffer_pool_size, 64);
+}
+
+#[test]
+fn test_json_roundtrip() {
+    let config = AppConfig::default();
+    let json = serde_json::to_string_pretty(&config).unwrap();
+    let parsed: AppConfig = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(
+        parsed.onset_detection.threshold_offset,
+        config.onset_detection.threshold_offset
+    );
+    assert_eq!(
+        parsed.calibration.samples_per_sound,
+        config.calibration.samples_per_sound
+    );
+}
+
+#[test]
+fn test_partial_config_file_fills_defaults() {
+    let path = std::env::temp_dir().join(format!("bbt-partial-config-{}.json", std::process::id()));
+    fs::write(
+        &path,
+        r#"{
+            "onset_detection": { "threshold_offset": 0.3, "hop_size": 128 },
+            "calibration": { "samples_per_sound": 6 },
+            "results": { "max_results_per_second": 10.0 }
+        }"#,
+    )
+    .unwrap();
+    let config = AppConfig::from_file(&path);
+    let _ = fs::remove_file(&path);
+    let config = config.unwrap();
+
+    assert_eq!(config.onset_detection.threshold_offset, 0.3);
+    assert_eq!(config.onset_detection.hop_size, 128);
+    assert_eq!(config.onset_detection.window_size, 256);
+    assert_eq!(config.results.max_results_per_second, 10.0);
+    assert_eq!(config.results.max_result_burst, 4);
+    assert_eq!(config.calibration.samples_per_sound, 6);
+    assert_eq!(config.calibration.min_sample_interval_ms, 250);
+    assert_eq!(config.calibration.log_every_n_buffers, 100);
+    assert_eq!(config.audio.buffer_size, 2048);
+}
+
+#[test]
+fn test_config_validation_rejects_out_of_range_values() {
+    let mut config = AppConfig::default();
+    assert!(config.validate().is_ok());
+
+    config.onset_detection.hop_size = 512;
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn test_config_validation_rejects_an_empty_onset_band() {
+    let mut config = AppConfig::default();
+    config.onset_detection.onset_min_hz = 4000.0;
+    config.onset_detection.onset_max_hz = 1000.0;
+    assert!(config.validate().is_err());
+
+    config.onset_detection.onset_max_hz = 4000.0;
+    assert!(config.validate().is_err());
+
+    // 0 leaves the band open up to Nyquist
+    config.onset_detection.onset_max_hz = 0.0;
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn test_bundled_config_is_valid() {
+    AppConfig::from_file(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../assets/onset_config.json"
+    ))
+    .unwrap();
+}
+
+#[test]
+fn test_config_validation_checks_every_tuning_section() {
+    let cases: [fn(&mut AppConfig); 12] = [
+        |c| c.onset_detection.whitening_floor = 0.0,
+        |c| c.onset_detection.min_tail_ms = -1.0,
+        |c| c.onset_detection.flam_window_ms = f32::NAN,
+        |c| c.onset_detection.min_flatness_for_hit = 1.5,
+        |c| c.results.max_results_per_second = -1.0,
+        |c| c.results.max_result_burst = 0,
+        |c| {
+            c.results
+                .per_sound_min_interval_ms
+                .insert(BeatboxHit::Kick, -50.0);
+        },
+        |c| c.analysis_worker.max_idle_sleep_ms = 0,
+        |c| c.analysis_worker.max_accumulator_size = 256,
+        |c| c.input.clip_fraction_threshold = 2.0,
+        |c| c.timing.beat_subdivisions = 0,
+        |c| c.audio.click.mute_probability = -0.1,
+    ];
+    for (i, break_config) in cases.iter().enumerate() {
+        let mut config = AppConfig::default();
+        break_config(&mut config);
+        assert!(config.validate().is_err(), "case {i} passed validation");
+    }
+}
+
+#[test]
+fn test_audio_config_without_click_uses_defaults() {
+    let json = r#"{ "buffer_pool_size": 32, "buffer_size": 1024 }"#;
+    let parsed: AudioConfig = serde_json::from_str(json).unwrap();
+
+    assert_eq!(parsed.click.style, ClickStyle::Noise);
+    assert_eq!(parsed.click.duration_ms, 20.0);
+}
@@ -8,9 +8,7 @@ use crate::engine::core::EngineHandle;
 #[cfg(all(feature = "debug_http", debug_assertions))]
 use super::routes::{run_http_server, DebugHttpState};
 #[cfg(all(feature = "debug_http", debug_assertions))]
-use log::{error, info, warn};
-#[cfg(all(feature = "debug_http", debug_assertions))]
-use once_cell::sync::OnceCell;
+use log::{debug, error, info, warn};
 #[cfg(all(feature = "debug_http", debug_assertions))]
 use std::net::SocketAddr;
 #[cfg(all(feature = "debug_http", debug_assertions))]
@@ -25,7 +23,12 @@ use tokio::sync::oneshot;
 #[cfg(all(feature = "debug_http", debug_assertions))]
 static SERVER_STARTED: AtomicBool = AtomicBool::new(false);
 #[cfg(all(feature = "debug_http", debug_assertions))]
-static SERVER_HANDLE: OnceCell<HttpServerHandle> = OnceCell::new();
+static SERVER_HANDLE: Mutex<Option<HttpServerHandle>> = Mutex::new(None);
+
+/// Name of the server's threads, so a stop requested through the server
+/// itself can be recognized
+#[cfg(all(feature = "debug_http", debug_assertions))]
+const SERVER_THREAD_NAME: &str = "bbt-debug-http";
 
 /// Spawn the debug HTTP server only when the feature flag and debug builds are enabled.
 #[cfg(all(feature = "debug_http", debug_assertions))]
@@ -34,7 +37,7 @@ pub fn spawn_if_enabled(handle: &'static EngineHandle) {
         .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
         .is_err()
     {
-        warn!("Debug HTTP server already running");
+        debug!("Debug HTTP server already running");
         return;
     }
 
@@ -53,12 +56,12 @@ pub fn spawn_if_enabled(handle: &'static EngineHandle) {
 
     match HttpServerHandle::spawn(handle, addr, token) {
         Ok(server) => {
-            match SERVER_HANDLE.set(server) {
-                Ok(()) => {}
-                Err(server) => {
-                    warn!("Debug HTTP server handle already initialized; shutting duplicate server down");
-                    drop(server);
-                }
+            let mut slot = SERVER_HANDLE.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some(previous) = slot.replace(server) {
+                warn!(
+                    "Debug HTTP server handle already initialized; shutting previous server down"
+                );
+                drop(previous);
             }
         }
         Err(err) => {
@@ -68,8 +71,50 @@ pub fn spawn_if_enabled(handle: &'static EngineHandle) {
     }
 }
 
+/// Stop the debug HTTP server, if running, and allow it to be spawned again.
+///
+/// Blocks until the server thread exits. Open connections (e.g. SSE
+/// streams) get a short grace period before they are dropped.
+#[cfg(all(feature = "debug_http", debug_assertions))]
+pub fn shutdown_http_server() {
+    let server = SERVER_HANDLE
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .take();
+    if let Some(server) = server {
+        info!("Shutting down debug HTTP server");
+        drop(server);
+    }
+    SERVER_STARTED.store(false, Ordering::SeqCst);
+}
+
+/// Stop the debug HTTP server if it serves `engine`; called when that
+/// engine stops.
+///
+/// A stop requested through the server (`POST /control/stop`) leaves it
+/// running, since the server cannot wait for itself to exit.
+#[cfg(all(feature = "debug_http", debug_assertions))]
+pub fn shutdown_for_engine(engine: &EngineHandle) {
+    let on_server_thread = thread::current()
+        .name()
+        .is_some_and(|name| name.starts_with(SERVER_THREAD_NAME));
+    if on_server_thread {
+        debug!("Engine stopped through the debug HTTP server; keeping it running");
+        return;
+    }
+    let serves_engine = SERVER_HANDLE
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .as_ref()
+        .is_some_and(|server| std::ptr::eq(server.engine, engine));
+    if serves_engine {
+        shutdown_http_server();
+    }
+}
+
 #[cfg(all(feature = "debug_http", debug_assertions))]
 struct HttpServerHandle {
+    engine: &'static EngineHandle,
     inner: Mutex<ServerRuntimeHandle>,
 }
 
@@ -88,10 +133,11 @@ impl HttpServerHandle {
     ) -> anyhow::Result<Self> {
         let (shutdown_tx, shutdown_rx) = oneshot::channel();
         let thread = thread::Builder::new()
-            .name("bbt-debug-http".into())
+            .name(SERVER_THREAD_NAME.into())
             .spawn(move || {
                 let runtime = tokio::runtime::Builder::new_multi_thread()
                     .worker_threads(2)
+                    .thread_name(format!("{SERVER_THREAD_NAME}-worker"))
                     .enable_all()
                     .build()
                     .expect("Failed to build tokio runtime for debug HTTP server");
@@ -105,6 +151,7 @@ impl HttpServerHandle {
             })?;
 
         Ok(Self {
+            engine: handle,
             inner: Mutex::new(ServerRuntimeHandle {
                 shutdown: Some(shutdown_tx),
                 thread: Some(thread),
@@ -139,3 +186,104 @@ impl Drop for HttpServerHandle {
 pub fn spawn_if_enabled(_handle: &'static EngineHandle) {
     // Debug HTTP server disabled in this build.
 }
+
+#[cfg(not(all(feature = "debug_http", debug_assertions)))]
+pub fn shutdown_http_server() {
+    // Debug HTTP server disabled in this build.
+}
+
+#[cfg(not(all(feature = "debug_http", debug_assertions)))]
+pub fn shutdown_for_engine(_engine: &EngineHandle) {
+    // Debug HTTP server disabled in this build.
+}
+
+#[cfg(all(test, feature = "debug_http", debug_assertions))]
+mod tests {
+    use super::*;
+    use crate::engine::backend::DesktopStubBackend;
+    use serial_test::serial;
+    use std::io::{Read, Write};
+    use std::net::{TcpListener, TcpStream};
+    use std::time::{Duration, Instant};
+
+    fn accepts_connections(addr: SocketAddr) -> bool {
+        let deadline = Instant::now() + Duration::from_secs(2);
+        while Instant::now() < deadline {
+            if TcpStream::connect_timeout(&addr, Duration::from_millis(100)).is_ok() {
+                return true;
+            }
+            thread::sleep(Duration::from_millis(20));
+        }
+        false
+    }
+
+    fn free_addr() -> SocketAddr {
+        TcpListener::bind("127.0.0.1:0")
+            .and_then(|listener| listener.local_addr())
+            .expect("free port")
+    }
+
+    #[test]
+    #[serial]
+    fn shutdown_allows_restart_on_same_port() {
+        let addr = free_addr();
+        std::env::set_var("BEATBOX_DEBUG_HTTP_ADDR", addr.to_string());
+        let handle: &'static EngineHandle = Box::leak(Box::new(EngineHandle::new()));
+
+        spawn_if_enabled(handle);
+        assert!(accepts_connections(addr), "server did not start");
+
+        shutdown_http_server();
+        assert!(!SERVER_STARTED.load(Ordering::SeqCst));
+        assert!(
+            TcpStream::connect_timeout(&addr, Duration::from_millis(100)).is_err(),
+            "listener still open after shutdown"
+        );
+
+        spawn_if_enabled(handle);
+        assert!(accepts_connections(addr), "server did not restart");
+        shutdown_http_server();
+    }
+
+    #[test]
+    #[serial]
+    fn stopping_its_engine_stops_the_server_despite_an_open_sse_stream() {
+        let addr = free_addr();
+        std::env::set_var("BEATBOX_DEBUG_HTTP_ADDR", addr.to_string());
+        std::env::set_var("BEATBOX_DEBUG_TOKEN", "test-token");
+        let handle: &'static EngineHandle = Box::leak(Box::new(EngineHandle::with_backend(
+            Box::new(DesktopStubBackend::new()),
+        )));
+        spawn_if_enabled(handle);
+        assert!(accepts_connections(addr), "server did not start");
+
+        // Another engine stopping leaves the server alone
+        let other = EngineHandle::with_backend(Box::new(DesktopStubBackend::new()));
+        other
+            .start_metronome_only(120)
+            .expect("stub backend starts");
+        other.stop_audio().expect("stub backend stops");
+        assert!(SERVER_STARTED.load(Ordering::SeqCst));
+
+        let mut sse = TcpStream::connect(addr).expect("connect");
+        sse.write_all(b"GET /trace?token=test-token HTTP/1.1\r\nHost: localhost\r\n\r\n")
+            .expect("request");
+        sse.set_read_timeout(Some(Duration::from_secs(2))).unwrap();
+        let mut response = [0u8; 64];
+        let read = sse.read(&mut response).expect("response");
+        assert!(response[..read].starts_with(b"HTTP/1.1 200"));
+
+        handle
+            .start_metronome_only(120)
+            .expect("stub backend starts");
+        let stopping = Instant::now();
+        handle.stop_audio().expect("stub backend stops");
+        assert!(stopping.elapsed() < Duration::from_secs(5), "shutdown hung");
+        assert!(!SERVER_STARTED.load(Ordering::SeqCst));
+        assert!(
+            TcpStream::connect_timeout(&addr, Duration::from_millis(100)).is_err(),
+            "listener still open after the engine stopped"
+        );
+        std::env::remove_var("BEATBOX_DEBUG_TOKEN");
+    }
+}
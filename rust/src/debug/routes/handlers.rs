@@ -25,6 +25,10 @@ use crate::telemetry::{self, MetricEvent};
 use super::metrics::render_prometheus_metrics;
 use super::state::{spawn_watchdog_task, DebugHttpState, DebugWatchdog};
 
+/// How long shutdown waits for open connections (e.g. SSE streams, which
+/// never end on their own) before dropping them
+const GRACEFUL_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(2);
+
 pub type TraceStream = SseStream;
 pub type ClassificationStream = SseStream;
 type SseStream = Sse<Pin<Box<dyn Stream<Item = Result<Event, Infallible>> + Send>>>;
@@ -34,9 +38,11 @@ pub fn build_router(state: DebugHttpState) -> Router {
         .route("/health", get(healthz))
         .route("/healthz", get(healthz))
         .route("/metrics", get(metrics))
+        .route("/metrics/reset", axum::routing::post(reset_metrics))
         .route("/trace", get(trace_stream_handler))
         .route("/classification-stream", get(classification_stream_handler))
         .route("/params", get(list_params).post(apply_params))
+        .route("/calibration", axum::routing::post(load_calibration))
         .route("/control/start", axum::routing::post(control_start))
         .route("/control/stop", axum::routing::post(control_stop))
         .with_state(state)
@@ -52,12 +58,23 @@ pub async fn run_http_server(
         .context("binding debug HTTP listener")?;
     let router = build_router(state.clone());
     let watchdog_task = spawn_watchdog_task(state.watchdog());
-    axum::serve(listener, router)
-        .with_graceful_shutdown(async move {
-            let _ = shutdown.await;
-        })
-        .await
-        .context("serving debug HTTP router")?;
+    let (stopping_tx, stopping_rx) = oneshot::channel();
+    let serve = axum::serve(listener, router).with_graceful_shutdown(async move {
+        let _ = shutdown.await;
+        let _ = stopping_tx.send(());
+    });
+    tokio::select! {
+        served = serve => served.context("serving debug HTTP router")?,
+        _ = async {
+            if stopping_rx.await.is_ok() {
+                tokio::time::sleep(GRACEFUL_SHUTDOWN_TIMEOUT).await;
+            } else {
+                std::future::pending::<()>().await;
+            }
+        } => {
+            log::warn!("Debug HTTP connections still open after shutdown timeout; dropping them");
+        }
+    }
     watchdog_task.abort();
     Ok(())
 }
@@ -71,6 +88,8 @@ pub struct AuthQuery {
 pub enum HttpServerError {
     Unauthorized,
     BadRequest(&'static str),
+    /// Problems found by [`CalibrationState::validate`]
+    InvalidCalibration(Vec<String>),
     Backpressure,
     ServiceUnavailable(&'static str),
     Internal(String),
@@ -81,6 +100,10 @@ impl IntoResponse for HttpServerError {
         let (status, message) = match self {
             Self::Unauthorized => (StatusCode::UNAUTHORIZED, "missing or invalid token".into()),
             Self::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg.to_string()),
+            Self::InvalidCalibration(problems) => (
+                StatusCode::BAD_REQUEST,
+                format!("invalid calibration: {}", problems.join("; ")),
+            ),
             Self::Backpressure => (
                 StatusCode::TOO_MANY_REQUESTS,
                 "command queue saturated".into(),
@@ -174,6 +197,18 @@ pub async fn metrics(
         .map_err(|err| HttpServerError::Internal(err.to_string()))
 }
 
+/// Zero all telemetry counters and history; returns the fresh snapshot
+pub async fn reset_metrics(
+    State(state): State<DebugHttpState>,
+    Query(query): Query<AuthQuery>,
+    headers: HeaderMap,
+) -> Result<Json<telemetry::TelemetrySnapshot>, HttpServerError> {
+    authorize(&state, &headers, query.token.as_deref())?;
+    let hub = telemetry::hub();
+    hub.reset();
+    Ok(Json(hub.snapshot()))
+}
+
 pub async fn trace_stream_handler(
     State(state): State<DebugHttpState>,
     Query(query): Query<AuthQuery>,
@@ -202,7 +237,15 @@ pub async fn list_params(
     let calibration_state = state.handle.get_calibration_state().ok();
 
     Ok(Json(ParamDescriptor {
-        supported: &["bpm", "centroid_threshold", "zcr_threshold"],
+        supported: &[
+            "bpm",
+            "centroid_threshold",
+            "zcr_threshold",
+            "kick_centroid_threshold",
+            "kick_zcr_threshold",
+            "snare_centroid_threshold",
+            "hihat_zcr_threshold",
+        ],
         calibration_state,
     }))
 }
@@ -215,18 +258,51 @@ pub async fn apply_params(
 ) -> Result<Json<ParamAck>, HttpServerError> {
     authorize(&state, &headers, query.token.as_deref())?;
 
-    if patch.bpm.is_none() && patch.centroid_threshold.is_none() && patch.zcr_threshold.is_none() {
+    if patch.bpm.is_none()
+        && patch.centroid_threshold.is_none()
+        && patch.zcr_threshold.is_none()
+        && !patch.has_sound_thresholds()
+    {
         return Err(HttpServerError::BadRequest(
             "at least one parameter must be provided",
         ));
     }
 
-    let sender = state.handle.command_sender();
-    sender.try_send(patch.clone()).map_err(map_try_send_error)?;
+    state
+        .handle
+        .submit_params(patch)
+        .map_err(map_try_send_error)?;
 
     Ok(Json(ParamAck { accepted: true }))
 }
 
+/// POST /calibration - Replace the engine's calibration with the posted state
+///
+/// Meant for reproducing a user's exported calibration on a debug build.
+/// Returns the state the engine ends up using (rescaled to the engine's
+/// sample rate).
+pub async fn load_calibration(
+    State(state): State<DebugHttpState>,
+    Query(query): Query<AuthQuery>,
+    headers: HeaderMap,
+    Json(calibration): Json<CalibrationState>,
+) -> Result<Json<CalibrationState>, HttpServerError> {
+    authorize(&state, &headers, query.token.as_deref())?;
+    calibration
+        .validate()
+        .map_err(HttpServerError::InvalidCalibration)?;
+
+    state
+        .handle
+        .load_calibration(calibration)
+        .map_err(|err| HttpServerError::Internal(err.to_string()))?;
+    let effective = state
+        .handle
+        .get_calibration_state()
+        .map_err(|err| HttpServerError::Internal(err.to_string()))?;
+    Ok(Json(effective))
+}
+
 fn authorize(
     state: &DebugHttpState,
     headers: &HeaderMap,
@@ -1,9 +1,9 @@
 use std::collections::BTreeMap;
 use std::fmt::Write;
 
-use crate::analysis::classifier::BeatboxHit;
 use crate::api::diagnostics;
 use crate::telemetry::{DiagnosticError, LifecyclePhase, MetricEvent, TelemetrySnapshot};
+use crate::types::BeatboxHit;
 
 use super::state::DebugHttpState;
 
@@ -19,6 +19,7 @@ struct PrometheusWriter<'a> {
     buffer_levels: BTreeMap<String, f32>,
     lifecycle_phases: BTreeMap<&'static str, u64>,
     latest_latency: Option<(f32, f32, usize)>,
+    latest_processing_latency_ms: Option<f32>,
     last_error_code: Option<&'static str>,
 }
 
@@ -28,6 +29,7 @@ impl<'a> PrometheusWriter<'a> {
         let mut buffer_levels = BTreeMap::new();
         let mut lifecycle_phases = BTreeMap::new();
         let mut latest_latency = None;
+        let mut latest_processing_latency_ms = None;
         let mut last_error_code = None;
 
         for event in &snapshot.recent {
@@ -52,6 +54,10 @@ impl<'a> PrometheusWriter<'a> {
                     lifecycle_phases.insert(lifecycle_label(*phase), *timestamp_ms);
                 }
                 MetricEvent::Error { code, .. } => last_error_code = Some(error_label(*code)),
+                MetricEvent::ProcessingLatency { latency_ms } => {
+                    latest_processing_latency_ms = Some(*latency_ms)
+                }
+                MetricEvent::Silence { .. } => {}
             }
         }
 
@@ -63,6 +69,7 @@ impl<'a> PrometheusWriter<'a> {
             buffer_levels,
             lifecycle_phases,
             latest_latency,
+            latest_processing_latency_ms,
             last_error_code,
         }
     }
@@ -108,6 +115,39 @@ impl<'a> PrometheusWriter<'a> {
             self.snapshot.dropped_events
         )
         .unwrap();
+
+        writeln!(
+            &mut self.output,
+            "# HELP beatbox_results_suppressed_total Classification results dropped by the rate cap"
+        )
+        .unwrap();
+        writeln!(
+            &mut self.output,
+            "# TYPE beatbox_results_suppressed_total counter"
+        )
+        .unwrap();
+        writeln!(
+            &mut self.output,
+            "beatbox_results_suppressed_total {}",
+            self.snapshot.suppressed_results
+        )
+        .unwrap();
+        writeln!(
+            &mut self.output,
+            "# HELP beatbox_onsets_dropped_total Onsets dropped by the per-buffer onset cap"
+        )
+        .unwrap();
+        writeln!(
+            &mut self.output,
+            "# TYPE beatbox_onsets_dropped_total counter"
+        )
+        .unwrap();
+        writeln!(
+            &mut self.output,
+            "beatbox_onsets_dropped_total {}",
+            self.snapshot.dropped_onsets
+        )
+        .unwrap();
     }
 
     fn write_engine_flags(&mut self) {
@@ -217,6 +257,25 @@ impl<'a> PrometheusWriter<'a> {
             writeln!(&mut self.output, "# TYPE beatbox_latency_samples gauge").unwrap();
             writeln!(&mut self.output, "beatbox_latency_samples {}", samples).unwrap();
         }
+
+        if let Some(latency_ms) = self.latest_processing_latency_ms {
+            writeln!(
+                &mut self.output,
+                "# HELP beatbox_processing_latency_ms Detection-to-broadcast latency of the last result"
+            )
+            .unwrap();
+            writeln!(
+                &mut self.output,
+                "# TYPE beatbox_processing_latency_ms gauge"
+            )
+            .unwrap();
+            writeln!(
+                &mut self.output,
+                "beatbox_processing_latency_ms {:.4}",
+                latency_ms
+            )
+            .unwrap();
+        }
     }
 
     fn write_classifications(&mut self) {
@@ -296,7 +355,9 @@ fn error_label(code: DiagnosticError) -> &'static str {
         DiagnosticError::FixtureLoad => "fixture_load",
         DiagnosticError::BufferDrain => "buffer_drain",
         DiagnosticError::StreamBackpressure => "stream_backpressure",
+        DiagnosticError::InvalidAudio => "invalid_audio",
         DiagnosticError::Unknown => "unknown",
+        DiagnosticError::ThreadPriorityDenied => "thread_priority_denied",
     }
 }
 
@@ -94,6 +94,21 @@ async fn metrics_return_prometheus_payload() {
     assert!(body.contains("beatbox_events_total"));
 }
 
+#[tokio::test]
+async fn metrics_reset_requires_token() {
+    let response = make_router()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/metrics/reset")
+                .body(axum::body::Body::empty())
+                .expect("reset request"),
+        )
+        .await
+        .expect("reset call");
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
 #[tokio::test]
 async fn trace_requires_token() {
     let response = make_router()
@@ -146,3 +161,67 @@ async fn params_support_listing() {
     assert_eq!(status, StatusCode::OK);
     assert!(json["supported"].is_array());
 }
+
+#[tokio::test]
+async fn posting_calibration_loads_it_into_the_engine() {
+    let calibration = crate::calibration::CalibrationState {
+        t_kick_centroid: 1234.0,
+        t_snare_centroid: 4321.0,
+        is_calibrated: true,
+        ..crate::calibration::CalibrationState::new_default()
+    };
+    let (status, json) = response_json(
+        make_router()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/calibration?token={TOKEN}"))
+                    .header(CONTENT_TYPE, "application/json")
+                    .body(axum::body::Body::from(
+                        serde_json::to_vec(&calibration).expect("calibration JSON"),
+                    ))
+                    .expect("calibration request"),
+            )
+            .await
+            .expect("calibration call"),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["t_kick_centroid"], 1234.0);
+    let loaded = TEST_HANDLE.get_calibration_state().expect("calibration");
+    assert_eq!(loaded.t_kick_centroid, 1234.0);
+    assert_eq!(loaded.t_snare_centroid, 4321.0);
+    assert!(loaded.is_calibrated);
+}
+
+#[tokio::test]
+async fn posting_an_invalid_calibration_lists_its_problems() {
+    let calibration = crate::calibration::CalibrationState {
+        level: 3,
+        t_kick_centroid: 5000.0,
+        t_snare_centroid: 1000.0,
+        ..crate::calibration::CalibrationState::new_default()
+    };
+    let (status, json) = response_json(
+        make_router()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/calibration?token={TOKEN}"))
+                    .header(CONTENT_TYPE, "application/json")
+                    .body(axum::body::Body::from(
+                        serde_json::to_vec(&calibration).expect("calibration JSON"),
+                    ))
+                    .expect("calibration request"),
+            )
+            .await
+            .expect("calibration call"),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let error = json["error"].as_str().expect("error message");
+    assert!(error.contains("level 3"), "{error}");
+    assert!(error.contains("must be below snare"), "{error}");
+}
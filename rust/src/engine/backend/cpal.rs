@@ -4,7 +4,7 @@
 //! It acts as a lightweight wrapper to adapt the EngineHandle's AudioBackend trait
 //! to the AudioEngineManager's interface.
 
-use crate::config::{AudioConfig, OnsetDetectionConfig};
+use crate::config::{AnalysisConfig, AudioConfig};
 use crate::error::AudioError;
 use crate::managers::AudioEngineManager;
 
@@ -19,11 +19,11 @@ impl CpalBackend {
     /// Create a new CPAL backend
     pub fn new(
         audio_config: AudioConfig,
-        onset_config: OnsetDetectionConfig,
+        analysis_config: AnalysisConfig,
         log_every_n_buffers: u64,
     ) -> Self {
         Self {
-            manager: AudioEngineManager::new(audio_config, onset_config, log_every_n_buffers),
+            manager: AudioEngineManager::new(audio_config, analysis_config, log_every_n_buffers),
         }
     }
 }
@@ -36,10 +36,15 @@ impl AudioBackend for CpalBackend {
             ctx.calibration_procedure,
             ctx.calibration_progress_tx,
             ctx.classification_tx,
+            ctx.onset_events_tx,
             ctx.metronome_enabled,
         )
     }
 
+    fn start_metronome_only(&self, bpm: u32) -> Result<(), AudioError> {
+        self.manager.start_metronome_only(bpm)
+    }
+
     fn stop(&self) -> Result<(), AudioError> {
         self.manager.stop()
     }
@@ -44,6 +44,18 @@ impl AudioBackend for DesktopStubBackend {
         Ok(())
     }
 
+    fn start_metronome_only(&self, bpm: u32) -> Result<(), AudioError> {
+        if bpm == 0 {
+            return Err(AudioError::BpmInvalid { bpm });
+        }
+
+        if self.running.swap(true, Ordering::SeqCst) {
+            return Err(AudioError::AlreadyRunning);
+        }
+
+        Ok(())
+    }
+
     fn stop(&self) -> Result<(), AudioError> {
         if !self.running.swap(false, Ordering::SeqCst) {
             return Err(AudioError::NotRunning);
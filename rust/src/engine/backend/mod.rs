@@ -6,7 +6,7 @@ use std::time::Instant;
 use tokio::sync::broadcast;
 
 use crate::analysis::ClassificationResult;
-use crate::api::AudioMetrics;
+use crate::api::{AudioMetrics, OnsetEvent};
 use crate::calibration::{CalibrationProcedure, CalibrationProgress, CalibrationState};
 use crate::error::AudioError;
 
@@ -21,6 +21,7 @@ pub struct EngineStartContext {
     pub calibration_progress_tx: Option<broadcast::Sender<CalibrationProgress>>,
     pub classification_tx: broadcast::Sender<ClassificationResult>,
     pub audio_metrics_tx: Option<broadcast::Sender<AudioMetrics>>,
+    pub onset_events_tx: Option<broadcast::Sender<OnsetEvent>>,
     pub metronome_enabled: bool,
 }
 
@@ -30,6 +31,8 @@ pub struct EngineStartContext {
 /// to the shared channels provided via [EngineStartContext].
 pub trait AudioBackend: Send + Sync {
     fn start(&self, ctx: EngineStartContext) -> Result<(), AudioError>;
+    /// Start output-only playback (metronome) without input or analysis.
+    fn start_metronome_only(&self, bpm: u32) -> Result<(), AudioError>;
     fn stop(&self) -> Result<(), AudioError>;
     fn set_bpm(&self, bpm: u32) -> Result<(), AudioError>;
 }
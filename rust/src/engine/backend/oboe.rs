@@ -1,4 +1,4 @@
-use crate::config::{AudioConfig, OnsetDetectionConfig};
+use crate::config::{AnalysisConfig, AudioConfig};
 use crate::error::AudioError;
 use crate::managers::AudioEngineManager;
 
@@ -12,11 +12,11 @@ pub struct OboeBackend {
 impl OboeBackend {
     pub fn new(
         audio_config: AudioConfig,
-        onset_config: OnsetDetectionConfig,
+        analysis_config: AnalysisConfig,
         log_every_n_buffers: u64,
     ) -> Self {
         Self {
-            manager: AudioEngineManager::new(audio_config, onset_config, log_every_n_buffers),
+            manager: AudioEngineManager::new(audio_config, analysis_config, log_every_n_buffers),
         }
     }
 }
@@ -29,10 +29,15 @@ impl AudioBackend for OboeBackend {
             ctx.calibration_procedure,
             ctx.calibration_progress_tx,
             ctx.classification_tx,
+            ctx.onset_events_tx,
             ctx.metronome_enabled,
         )
     }
 
+    fn start_metronome_only(&self, bpm: u32) -> Result<(), AudioError> {
+        self.manager.start_metronome_only(bpm)
+    }
+
     fn stop(&self) -> Result<(), AudioError> {
         self.manager.stop()
     }
@@ -18,6 +18,7 @@ use crate::engine::backend::{AudioBackend, EngineStartContext, TimeSource};
 use crate::engine::backend::{CpalBackend, StubTimeSource};
 #[cfg(target_os = "android")]
 use crate::engine::backend::{OboeBackend, SystemTimeSource};
+use crate::engine::param_history::ParamHistory;
 use crate::error::{AudioError, CalibrationError};
 use crate::managers::{BroadcastChannelManager, CalibrationManager};
 
@@ -33,6 +34,45 @@ pub struct ParamPatch {
     pub centroid_threshold: Option<f32>,
     #[serde(default)]
     pub zcr_threshold: Option<f32>,
+    /// Kick centroid threshold (Hz), `CalibrationState::t_kick_centroid`
+    #[serde(default)]
+    pub kick_centroid_threshold: Option<f32>,
+    /// Kick ZCR threshold, `CalibrationState::t_kick_zcr`
+    #[serde(default)]
+    pub kick_zcr_threshold: Option<f32>,
+    /// Snare centroid threshold (Hz), `CalibrationState::t_snare_centroid`
+    #[serde(default)]
+    pub snare_centroid_threshold: Option<f32>,
+    /// Hi-hat ZCR threshold, `CalibrationState::t_hihat_zcr`
+    #[serde(default)]
+    pub hihat_zcr_threshold: Option<f32>,
+}
+
+impl ParamPatch {
+    /// Whether the patch sets any per-sound threshold
+    pub fn has_sound_thresholds(&self) -> bool {
+        self.kick_centroid_threshold.is_some()
+            || self.kick_zcr_threshold.is_some()
+            || self.snare_centroid_threshold.is_some()
+            || self.hihat_zcr_threshold.is_some()
+    }
+
+    /// Write the per-sound thresholds set in the patch into `state`,
+    /// leaving the others untouched
+    pub fn apply_sound_thresholds(&self, state: &mut CalibrationState) {
+        if let Some(value) = self.kick_centroid_threshold {
+            state.t_kick_centroid = value;
+        }
+        if let Some(value) = self.kick_zcr_threshold {
+            state.t_kick_zcr = value;
+        }
+        if let Some(value) = self.snare_centroid_threshold {
+            state.t_snare_centroid = value;
+        }
+        if let Some(value) = self.hihat_zcr_threshold {
+            state.t_hihat_zcr = value;
+        }
+    }
 }
 
 /// Telemetry event emitted by the engine core.
@@ -63,6 +103,7 @@ pub struct EngineHandle {
     command_tx: mpsc::Sender<ParamPatch>,
     command_rx: Arc<Mutex<mpsc::Receiver<ParamPatch>>>,
     command_worker_started: AtomicBool,
+    param_history: Arc<ParamHistory>,
     engine_running: AtomicBool,
     time_source: Arc<dyn TimeSource>,
     start_instant: Instant,
@@ -72,13 +113,23 @@ impl EngineHandle {
     /// Create a new EngineHandle with platform defaults.
     pub fn new() -> Self {
         let initial_config = Self::load_platform_config();
-        Self::from_config(initial_config)
+        let backend = Self::create_backend(&initial_config);
+        Self::from_parts(initial_config, backend)
     }
 
-    fn from_config(initial_config: AppConfig) -> Self {
+    /// Create an EngineHandle driving the given backend.
+    ///
+    /// Lets tests and tools run the full handle logic against a
+    /// deterministic backend such as `DesktopStubBackend`. Configuration is
+    /// loaded as in [`EngineHandle::new`]; the FFI layer keeps using the
+    /// global handle with the platform backend.
+    pub fn with_backend(backend: Box<dyn AudioBackend>) -> Self {
+        Self::from_parts(Self::load_platform_config(), Arc::from(backend))
+    }
+
+    fn from_parts(initial_config: AppConfig, backend: Arc<dyn AudioBackend>) -> Self {
         let config = Arc::new(RwLock::new(initial_config.clone()));
 
-        let backend = Self::create_backend(&initial_config);
         let calibration = CalibrationManager::new(initial_config.calibration.clone());
         let broadcasts = BroadcastChannelManager::new();
         let (telemetry_tx, _) = broadcast::channel(128);
@@ -94,6 +145,7 @@ impl EngineHandle {
             command_tx,
             command_rx: Arc::new(Mutex::new(command_rx)),
             command_worker_started: AtomicBool::new(false),
+            param_history: Arc::new(ParamHistory::default()),
             engine_running: AtomicBool::new(false),
             time_source,
             start_instant: Instant::now(),
@@ -116,7 +168,7 @@ impl EngineHandle {
     fn create_backend(config: &AppConfig) -> Arc<dyn AudioBackend> {
         Arc::new(OboeBackend::new(
             config.audio.clone(),
-            config.onset_detection.clone(),
+            config.analysis(),
             config.calibration.log_every_n_buffers,
         ))
     }
@@ -125,7 +177,7 @@ impl EngineHandle {
     fn create_backend(config: &AppConfig) -> Arc<dyn AudioBackend> {
         Arc::new(CpalBackend::new(
             config.audio.clone(),
-            config.onset_detection.clone(),
+            config.analysis(),
             config.calibration.log_every_n_buffers,
         ))
     }
@@ -153,6 +205,7 @@ impl EngineHandle {
         let telemetry_tx = self.telemetry_tx.clone();
         let time_source = Arc::clone(&self.time_source);
         let command_rx = Arc::clone(&self.command_rx);
+        let calibration_state = self.calibration.get_state_arc();
         let start_instant = self.start_instant;
 
         // Spawn a dedicated thread with its own Tokio runtime
@@ -190,6 +243,21 @@ impl EngineHandle {
                                     detail,
                                 );
                             }
+                            if patch.has_sound_thresholds() {
+                                match calibration_state.write() {
+                                    Ok(mut state) => patch.apply_sound_thresholds(&mut state),
+                                    Err(_) => Self::publish_event(
+                                        &telemetry_tx,
+                                        &time_source,
+                                        start_instant,
+                                        TelemetryEventKind::Warning,
+                                        Some(
+                                            "Failed to apply threshold patch: calibration state lock poisoned"
+                                                .to_string(),
+                                        ),
+                                    ),
+                                }
+                            }
                         }
                         None => break,
                     }
@@ -239,6 +307,7 @@ impl EngineHandle {
 
         // Initialize audio metrics channel for live level meter
         let audio_metrics_tx = Some(self.broadcasts.init_audio_metrics());
+        let onset_events_tx = Some(self.broadcasts.init_onset_events());
 
         let ctx = EngineStartContext {
             bpm,
@@ -247,6 +316,7 @@ impl EngineHandle {
             calibration_progress_tx,
             classification_tx: broadcast_tx,
             audio_metrics_tx,
+            onset_events_tx,
             metronome_enabled: true,
         };
 
@@ -257,6 +327,18 @@ impl EngineHandle {
         Ok(())
     }
 
+    /// Start the metronome only (output stream, no input or analysis).
+    ///
+    /// Avoids microphone permission prompts for pure metronome use. No
+    /// classification channel is initialized; `stop_audio` tears it down.
+    pub fn start_metronome_only(&self, bpm: u32) -> Result<(), AudioError> {
+        self.backend.start_metronome_only(bpm)?;
+        self.engine_running.store(true, Ordering::SeqCst);
+        self.emit_event(TelemetryEventKind::EngineStarted { bpm }, None);
+        self.init_command_worker();
+        Ok(())
+    }
+
     /// Stop the audio engine.
     pub fn stop_audio(&self) -> Result<(), AudioError> {
         if !self.engine_running.load(Ordering::SeqCst) {
@@ -266,6 +348,7 @@ impl EngineHandle {
         self.backend.stop()?;
         self.engine_running.store(false, Ordering::SeqCst);
         self.emit_event(TelemetryEventKind::EngineStopped, None);
+        crate::debug::http::shutdown_for_engine(self);
         Ok(())
     }
 
@@ -291,6 +374,10 @@ impl EngineHandle {
     pub fn start_calibration(&self) -> Result<(), CalibrationError> {
         let broadcast_tx = self.broadcasts.init_calibration();
         self.calibration.start(broadcast_tx)?;
+        self.calibration
+            .attach_debug_stream(self.broadcasts.calibration_debug_sender())?;
+        self.calibration
+            .attach_preview_stream(self.broadcasts.calibration_preview_sender())?;
 
         // Stop any existing audio and restart for calibration on all platforms
         if let Err(err) = self.stop_audio() {
@@ -306,6 +393,7 @@ impl EngineHandle {
         let calibration_procedure = self.calibration.get_procedure_arc();
         let calibration_progress_tx = self.broadcasts.get_calibration_sender();
         let audio_metrics_tx = Some(self.broadcasts.init_audio_metrics());
+        let onset_events_tx = Some(self.broadcasts.init_onset_events());
 
         let ctx = EngineStartContext {
             bpm: DEFAULT_CALIBRATION_BPM,
@@ -314,6 +402,7 @@ impl EngineHandle {
             calibration_progress_tx,
             classification_tx: broadcast_tx,
             audio_metrics_tx,
+            onset_events_tx,
             metronome_enabled: false,
         };
 
@@ -46,3 +46,88 @@ impl EngineHandle {
         ctx
     }
 }
+
+#[test]
+fn metronome_only_mode_skips_classification_pipeline() {
+    use crate::engine::backend::DesktopStubBackend;
+
+    let handle = EngineHandle::with_backend(Box::new(DesktopStubBackend::new()));
+
+    handle
+        .start_metronome_only(120)
+        .expect("stub backend starts metronome");
+    assert!(handle.is_audio_running());
+    assert!(
+        handle.broadcasts.subscribe_classification().is_none(),
+        "metronome-only mode must not open the classification channel"
+    );
+
+    handle
+        .stop_audio()
+        .expect("stop_audio tears down metronome");
+    assert!(!handle.is_audio_running());
+    assert!(handle.broadcasts.subscribe_classification().is_none());
+}
+
+#[test]
+fn stub_backend_handle_starts_emits_and_stops() {
+    use crate::analysis::ClassificationResult;
+    use crate::engine::backend::DesktopStubBackend;
+    use crate::types::BeatboxHit;
+
+    let handle = EngineHandle::with_backend(Box::new(DesktopStubBackend::new()));
+
+    handle.start_audio(100).expect("stub backend starts");
+    assert!(handle.is_audio_running());
+
+    let mut results = handle
+        .broadcasts
+        .subscribe_classification()
+        .expect("start_audio opens the classification channel");
+    let delivered = handle
+        .broadcasts
+        .inject_classification(ClassificationResult::new(BeatboxHit::Snare, None, 250, 0.8));
+    assert_eq!(delivered, Ok(1));
+    let result = results.try_recv().expect("injected result");
+    assert_eq!(
+        (result.sound, result.timestamp_ms),
+        (BeatboxHit::Snare, 250)
+    );
+
+    handle.stop_audio().expect("stub backend stops");
+    assert!(!handle.is_audio_running());
+    assert!(handle.stop_audio().is_err());
+}
+
+#[test]
+fn snare_centroid_patch_updates_only_that_threshold() {
+    use crate::engine::backend::DesktopStubBackend;
+
+    let handle = EngineHandle::with_backend(Box::new(DesktopStubBackend::new()));
+    handle
+        .load_calibration(CalibrationState::new_default())
+        .expect("load default calibration");
+    let before = handle.get_calibration_state().unwrap();
+    handle.start_audio(100).expect("stub backend starts");
+
+    handle
+        .submit_params(ParamPatch {
+            snare_centroid_threshold: Some(before.t_snare_centroid + 750.0),
+            ..ParamPatch::default()
+        })
+        .expect("patch queued");
+
+    // The command worker applies patches asynchronously
+    let deadline = Instant::now() + std::time::Duration::from_secs(2);
+    let mut after = handle.get_calibration_state().unwrap();
+    while after.t_snare_centroid == before.t_snare_centroid && Instant::now() < deadline {
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        after = handle.get_calibration_state().unwrap();
+    }
+    handle.stop_audio().expect("stub backend stops");
+
+    assert_eq!(after.t_snare_centroid, before.t_snare_centroid + 750.0);
+    assert_eq!(after.t_kick_centroid, before.t_kick_centroid);
+    assert_eq!(after.t_kick_zcr, before.t_kick_zcr);
+    assert_eq!(after.t_hihat_zcr, before.t_hihat_zcr);
+}
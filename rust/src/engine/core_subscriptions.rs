@@ -3,6 +3,7 @@ use std::sync::{Arc, RwLock};
 
 use futures::Stream;
 use tokio::runtime::Builder;
+use tokio::sync::mpsc::error::TrySendError;
 use tokio::sync::{broadcast, mpsc};
 use tokio_stream::wrappers::UnboundedReceiverStream;
 
@@ -11,8 +12,9 @@ use crate::analysis::ClassificationResult;
 use crate::api::{AudioMetrics, OnsetEvent};
 #[cfg(any(test, feature = "diagnostics_fixtures"))]
 use crate::calibration::CalibrationProcedure;
-use crate::calibration::{CalibrationProgress, CalibrationState};
+use crate::calibration::{CalibrationDebugFrame, CalibrationProgress, CalibrationState};
 use crate::config::AppConfig;
+use crate::engine::param_history::ParamHistoryEntry;
 
 use super::{EngineHandle, ParamPatch};
 
@@ -93,6 +95,76 @@ impl EngineHandle {
         rx
     }
 
+    pub fn subscribe_calibration_debug(&self) -> mpsc::UnboundedReceiver<CalibrationDebugFrame> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let mut broadcast_rx = self.broadcasts.subscribe_calibration_debug();
+
+        std::thread::spawn(move || {
+            let rt = Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("Failed to create Tokio runtime");
+            rt.block_on(async move {
+                loop {
+                    match broadcast_rx.recv().await {
+                        Ok(frame) => {
+                            if tx.send(frame).is_err() {
+                                break;
+                            }
+                        }
+                        Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                            tracing::warn!(
+                                "[subscribe_calibration_debug] Receiver lagged, skipped {} frames",
+                                skipped
+                            );
+                            continue;
+                        }
+                        Err(broadcast::error::RecvError::Closed) => {
+                            break;
+                        }
+                    }
+                }
+            });
+        });
+
+        rx
+    }
+
+    pub fn subscribe_calibration_preview(&self) -> mpsc::UnboundedReceiver<ClassificationResult> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let mut broadcast_rx = self.broadcasts.subscribe_calibration_preview();
+
+        std::thread::spawn(move || {
+            let rt = Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("Failed to create Tokio runtime");
+            rt.block_on(async move {
+                loop {
+                    match broadcast_rx.recv().await {
+                        Ok(result) => {
+                            if tx.send(result).is_err() {
+                                break;
+                            }
+                        }
+                        Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                            tracing::warn!(
+                                "[subscribe_calibration_preview] Receiver lagged, skipped {} results",
+                                skipped
+                            );
+                            continue;
+                        }
+                        Err(broadcast::error::RecvError::Closed) => {
+                            break;
+                        }
+                    }
+                }
+            });
+        });
+
+        rx
+    }
+
     pub fn subscribe_audio_metrics(&self) -> mpsc::UnboundedReceiver<AudioMetrics> {
         let (tx, rx) = mpsc::unbounded_channel();
 
@@ -237,6 +309,21 @@ impl EngineHandle {
         self.command_tx.clone()
     }
 
+    /// Queue a ParamPatch for the command worker and record it in the param history.
+    ///
+    /// Only patches accepted by the command queue are recorded, so the history
+    /// mirrors exactly what the engine was asked to apply.
+    pub fn submit_params(&self, patch: ParamPatch) -> Result<(), TrySendError<ParamPatch>> {
+        self.command_tx.try_send(patch.clone())?;
+        self.param_history.record(self.uptime_ms(), patch);
+        Ok(())
+    }
+
+    /// Snapshot of previously applied ParamPatch commands, oldest first.
+    pub fn get_param_history(&self) -> Vec<ParamHistoryEntry> {
+        self.param_history.snapshot()
+    }
+
     /// Check whether audio backend is running (best effort).
     pub fn is_audio_running(&self) -> bool {
         self.engine_running.load(Ordering::SeqCst)
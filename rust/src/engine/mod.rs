@@ -6,8 +6,10 @@
 
 pub mod backend;
 pub mod core;
+pub mod param_history;
 
 #[cfg(target_os = "android")]
 pub use backend::OboeBackend;
 pub use backend::{AudioBackend, DesktopStubBackend, StubTimeSource, SystemTimeSource, TimeSource};
 pub use core::{EngineHandle, ParamPatch, TelemetryEvent, TelemetryEventKind};
+pub use param_history::{ParamHistory, ParamHistoryEntry};
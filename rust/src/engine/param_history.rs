@@ -0,0 +1,101 @@
+//! Bounded, replayable log of applied `ParamPatch` commands.
+//!
+//! Every patch accepted by the command pipeline (FRB `apply_params` or the
+//! debug HTTP `/params` endpoint) is appended here with the engine uptime at
+//! which it was submitted. The log lets tooling inspect or replay the exact
+//! sequence of parameter changes that led to a given engine state.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+use super::core::ParamPatch;
+
+/// Default number of entries retained before the oldest are evicted.
+pub const DEFAULT_PARAM_HISTORY_CAPACITY: usize = 256;
+
+/// Single recorded parameter change.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParamHistoryEntry {
+    /// Engine uptime (ms) when the patch was accepted.
+    pub timestamp_ms: u64,
+    pub patch: ParamPatch,
+}
+
+/// Thread-safe ring buffer of [`ParamHistoryEntry`] values.
+pub struct ParamHistory {
+    capacity: usize,
+    entries: Mutex<VecDeque<ParamHistoryEntry>>,
+}
+
+impl ParamHistory {
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            capacity,
+            entries: Mutex::new(VecDeque::with_capacity(capacity)),
+        }
+    }
+
+    /// Append a patch, evicting the oldest entry once capacity is reached.
+    pub fn record(&self, timestamp_ms: u64, patch: ParamPatch) {
+        let mut entries = self
+            .entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if entries.len() == self.capacity {
+            entries.pop_front();
+        }
+        entries.push_back(ParamHistoryEntry {
+            timestamp_ms,
+            patch,
+        });
+    }
+
+    /// Snapshot entries in the order they were applied (oldest first).
+    pub fn snapshot(&self) -> Vec<ParamHistoryEntry> {
+        self.entries
+            .lock()
+            .map(|entries| entries.iter().cloned().collect())
+            .unwrap_or_else(|poisoned| poisoned.into_inner().iter().cloned().collect())
+    }
+}
+
+impl Default for ParamHistory {
+    fn default() -> Self {
+        Self::new(DEFAULT_PARAM_HISTORY_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bpm_patch(bpm: u32) -> ParamPatch {
+        ParamPatch {
+            bpm: Some(bpm),
+            ..ParamPatch::default()
+        }
+    }
+
+    #[test]
+    fn evicts_oldest_entries_when_full() {
+        let history = ParamHistory::new(2);
+        history.record(10, bpm_patch(100));
+        history.record(20, bpm_patch(110));
+        history.record(30, bpm_patch(120));
+
+        let entries = history.snapshot();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].patch.bpm, Some(110));
+        assert_eq!(entries[1].patch.bpm, Some(120));
+    }
+
+    #[test]
+    fn zero_capacity_is_clamped() {
+        let history = ParamHistory::new(0);
+        history.record(1, bpm_patch(90));
+        assert_eq!(history.snapshot().len(), 1);
+    }
+}
@@ -11,7 +11,7 @@ use std::fmt;
 /// shared between Rust and Dart. The flutter_rust_bridge will automatically
 /// generate corresponding Dart constants.
 ///
-/// Error code range: 1001-1010
+/// Error code range: 1001-1011
 #[frb(unignore)]
 pub struct AudioErrorCodes {}
 
@@ -47,6 +47,9 @@ impl AudioErrorCodes {
     /// Audio stream disconnected or channel closed unexpectedly
     pub const STREAM_FAILURE: i32 = 1010;
 
+    /// Operation is not available in this build (e.g. debug-only hooks)
+    pub const UNSUPPORTED: i32 = 1011;
+
     // Getter methods for FFI exposure (flutter_rust_bridge requires methods not const)
 
     /// Get BPM_INVALID error code
@@ -108,6 +111,12 @@ impl AudioErrorCodes {
     pub fn stream_failure() -> i32 {
         Self::STREAM_FAILURE
     }
+
+    /// Get UNSUPPORTED error code
+    #[flutter_rust_bridge::frb(sync, getter)]
+    pub fn unsupported() -> i32 {
+        Self::UNSUPPORTED
+    }
 }
 
 /// Log an audio error with structured context
@@ -133,7 +142,7 @@ pub fn log_audio_error(err: &AudioError, context: &str) {
 /// These errors cover audio engine operations including initialization,
 /// stream management, and hardware access.
 ///
-/// Error code ranges: 1001-1010
+/// Error code ranges: 1001-1011
 #[derive(Debug, Clone, PartialEq)]
 pub enum AudioError {
     /// BPM value is invalid (must be > 0, typically 40-240)
@@ -165,6 +174,9 @@ pub enum AudioError {
 
     /// Stream channel disconnected unexpectedly
     StreamFailure { reason: String },
+
+    /// Operation is not available in this build (e.g. debug-only hooks)
+    Unsupported { operation: String },
 }
 
 impl ErrorCode for AudioError {
@@ -180,6 +192,7 @@ impl ErrorCode for AudioError {
             AudioError::JniInitFailed { .. } => AudioErrorCodes::JNI_INIT_FAILED,
             AudioError::ContextNotInitialized => AudioErrorCodes::CONTEXT_NOT_INITIALIZED,
             AudioError::StreamFailure { .. } => AudioErrorCodes::STREAM_FAILURE,
+            AudioError::Unsupported { .. } => AudioErrorCodes::UNSUPPORTED,
         }
     }
 
@@ -215,6 +228,9 @@ impl ErrorCode for AudioError {
             AudioError::StreamFailure { reason } => {
                 format!("Audio stream failed: {}", reason)
             }
+            AudioError::Unsupported { operation } => {
+                format!("{} is not supported in this build", operation)
+            }
         }
     }
 }
@@ -299,6 +315,13 @@ mod tests {
             .code(),
             AudioErrorCodes::STREAM_FAILURE
         );
+        assert_eq!(
+            AudioError::Unsupported {
+                operation: "test".to_string()
+            }
+            .code(),
+            AudioErrorCodes::UNSUPPORTED
+        );
     }
 
     #[test]
@@ -353,5 +376,6 @@ mod tests {
         assert_eq!(AudioErrorCodes::jni_init_failed(), 1008);
         assert_eq!(AudioErrorCodes::context_not_initialized(), 1009);
         assert_eq!(AudioErrorCodes::stream_failure(), 1010);
+        assert_eq!(AudioErrorCodes::unsupported(), 1011);
     }
 }
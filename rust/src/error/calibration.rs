@@ -1,5 +1,6 @@
 // Calibration error types and constants
 
+use crate::calibration::progress::CalibrationSound;
 use crate::error::ErrorCode;
 use flutter_rust_bridge::frb;
 use log::error;
@@ -11,7 +12,7 @@ use std::fmt;
 /// shared between Rust and Dart. The flutter_rust_bridge will automatically
 /// generate corresponding Dart constants.
 ///
-/// Error code range: 2001-2006
+/// Error code range: 2001-2010
 #[frb(unignore)]
 pub struct CalibrationErrorCodes {}
 
@@ -35,6 +36,18 @@ impl CalibrationErrorCodes {
     /// Calibration timed out waiting for engine coordination
     pub const TIMEOUT: i32 = 2006;
 
+    /// Calibration file could not be read or written
+    pub const IO: i32 = 2007;
+
+    /// Calibration file or clip contents could not be parsed
+    pub const PARSE: i32 = 2008;
+
+    /// No built-in calibration preset has the requested name
+    pub const UNKNOWN_PRESET: i32 = 2009;
+
+    /// Operation needs a sound collection phase, not a level measurement
+    pub const NOT_A_SOUND_PHASE: i32 = 2010;
+
     // Getter methods for FFI exposure (flutter_rust_bridge requires methods not const)
 
     /// Get INSUFFICIENT_SAMPLES error code
@@ -72,6 +85,30 @@ impl CalibrationErrorCodes {
     pub fn timeout() -> i32 {
         Self::TIMEOUT
     }
+
+    /// Get IO error code
+    #[flutter_rust_bridge::frb(sync, getter)]
+    pub fn io() -> i32 {
+        Self::IO
+    }
+
+    /// Get PARSE error code
+    #[flutter_rust_bridge::frb(sync, getter)]
+    pub fn parse() -> i32 {
+        Self::PARSE
+    }
+
+    /// Get UNKNOWN_PRESET error code
+    #[flutter_rust_bridge::frb(sync, getter)]
+    pub fn unknown_preset() -> i32 {
+        Self::UNKNOWN_PRESET
+    }
+
+    /// Get NOT_A_SOUND_PHASE error code
+    #[flutter_rust_bridge::frb(sync, getter)]
+    pub fn not_a_sound_phase() -> i32 {
+        Self::NOT_A_SOUND_PHASE
+    }
 }
 
 /// Log a calibration error with structured context
@@ -92,16 +129,39 @@ pub fn log_calibration_error(err: &CalibrationError, context: &str) {
     );
 }
 
+/// Samples collected for one sound, reported when calibration cannot finish
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SoundSampleCount {
+    pub sound: CalibrationSound,
+    pub collected: usize,
+    pub needed: usize,
+}
+
+impl SoundSampleCount {
+    /// Samples still missing (0 once the sound is complete)
+    pub fn missing(&self) -> usize {
+        self.needed.saturating_sub(self.collected)
+    }
+}
+
 /// Calibration-related errors
 ///
 /// These errors cover calibration procedure operations including sample
 /// collection, feature extraction, and state management.
 ///
-/// Error code ranges: 2001-2006
+/// Error code ranges: 2001-2010
 #[derive(Debug, Clone, PartialEq)]
 pub enum CalibrationError {
     /// Insufficient samples collected for calibration
-    InsufficientSamples { required: usize, collected: usize },
+    ///
+    /// `per_sound` breaks the totals down by sound when finalizing a
+    /// calibration, so the UI can say which sound needs more samples; it is
+    /// empty where no per-sound counts apply.
+    InsufficientSamples {
+        required: usize,
+        collected: usize,
+        per_sound: Vec<SoundSampleCount>,
+    },
 
     /// Invalid features extracted from samples
     InvalidFeatures { reason: String },
@@ -117,6 +177,19 @@ pub enum CalibrationError {
 
     /// Calibration timed out waiting for native engine coordination
     Timeout { reason: String },
+
+    /// Calibration file could not be read or written
+    Io { reason: String },
+
+    /// Calibration file or clip contents could not be parsed
+    Parse { reason: String },
+
+    /// No built-in calibration preset has this name
+    UnknownPreset { name: String },
+
+    /// The operation only applies to sound collection, but `phase` is a
+    /// level measurement (input level or noise floor) with no samples
+    NotASoundPhase { phase: CalibrationSound },
 }
 
 impl ErrorCode for CalibrationError {
@@ -130,6 +203,10 @@ impl ErrorCode for CalibrationError {
             CalibrationError::AlreadyInProgress => CalibrationErrorCodes::ALREADY_IN_PROGRESS,
             CalibrationError::StatePoisoned => CalibrationErrorCodes::STATE_POISONED,
             CalibrationError::Timeout { .. } => CalibrationErrorCodes::TIMEOUT,
+            CalibrationError::Io { .. } => CalibrationErrorCodes::IO,
+            CalibrationError::Parse { .. } => CalibrationErrorCodes::PARSE,
+            CalibrationError::UnknownPreset { .. } => CalibrationErrorCodes::UNKNOWN_PRESET,
+            CalibrationError::NotASoundPhase { .. } => CalibrationErrorCodes::NOT_A_SOUND_PHASE,
         }
     }
 
@@ -138,8 +215,31 @@ impl ErrorCode for CalibrationError {
             CalibrationError::InsufficientSamples {
                 required,
                 collected,
+                per_sound,
             } => {
-                format!("Insufficient samples: need {}, got {}", required, collected)
+                let short: Vec<String> = per_sound
+                    .iter()
+                    .filter(|count| count.missing() > 0)
+                    .map(|count| {
+                        format!(
+                            "{} more {} ({}/{})",
+                            count.missing(),
+                            count.sound.display_name().to_lowercase(),
+                            count.collected,
+                            count.needed
+                        )
+                    })
+                    .collect();
+                if short.is_empty() {
+                    format!("Insufficient samples: need {}, got {}", required, collected)
+                } else {
+                    format!(
+                        "Insufficient samples: need {}, got {}; {}",
+                        required,
+                        collected,
+                        short.join(", ")
+                    )
+                }
             }
             CalibrationError::InvalidFeatures { reason } => {
                 format!("Invalid features: {}", reason)
@@ -150,6 +250,19 @@ impl ErrorCode for CalibrationError {
             CalibrationError::Timeout { reason } => {
                 format!("Calibration timed out: {}", reason)
             }
+            CalibrationError::Io { reason } => format!("Calibration I/O error: {}", reason),
+            CalibrationError::Parse { reason } => {
+                format!("Calibration parse error: {}", reason)
+            }
+            CalibrationError::UnknownPreset { name } => format!(
+                "Unknown calibration preset '{}' (available: {})",
+                name,
+                crate::calibration::presets::preset_names().join(", ")
+            ),
+            CalibrationError::NotASoundPhase { phase } => format!(
+                "The {} phase is a level measurement, not a sound collection phase",
+                phase.display_name().to_lowercase()
+            ),
         }
     }
 }
@@ -177,7 +290,8 @@ mod tests {
         assert_eq!(
             CalibrationError::InsufficientSamples {
                 required: 5,
-                collected: 3
+                collected: 3,
+                per_sound: Vec::new(),
             }
             .code(),
             CalibrationErrorCodes::INSUFFICIENT_SAMPLES
@@ -208,6 +322,34 @@ mod tests {
             .code(),
             CalibrationErrorCodes::TIMEOUT
         );
+        assert_eq!(
+            CalibrationError::Io {
+                reason: "test".to_string()
+            }
+            .code(),
+            CalibrationErrorCodes::IO
+        );
+        assert_eq!(
+            CalibrationError::Parse {
+                reason: "test".to_string()
+            }
+            .code(),
+            CalibrationErrorCodes::PARSE
+        );
+        assert_eq!(
+            CalibrationError::UnknownPreset {
+                name: "test".to_string()
+            }
+            .code(),
+            CalibrationErrorCodes::UNKNOWN_PRESET
+        );
+        assert_eq!(
+            CalibrationError::NotASoundPhase {
+                phase: CalibrationSound::NoiseFloor
+            }
+            .code(),
+            CalibrationErrorCodes::NOT_A_SOUND_PHASE
+        );
     }
 
     #[test]
@@ -215,9 +357,31 @@ mod tests {
         let err = CalibrationError::InsufficientSamples {
             required: 5,
             collected: 3,
+            per_sound: Vec::new(),
         };
         assert_eq!(err.message(), "Insufficient samples: need 5, got 3");
 
+        let err = CalibrationError::InsufficientSamples {
+            required: 6,
+            collected: 5,
+            per_sound: vec![
+                SoundSampleCount {
+                    sound: CalibrationSound::Kick,
+                    collected: 2,
+                    needed: 2,
+                },
+                SoundSampleCount {
+                    sound: CalibrationSound::Snare,
+                    collected: 1,
+                    needed: 2,
+                },
+            ],
+        };
+        assert_eq!(
+            err.message(),
+            "Insufficient samples: need 6, got 5; 1 more snare (1/2)"
+        );
+
         let err = CalibrationError::InvalidFeatures {
             reason: "test reason".to_string(),
         };
@@ -254,5 +418,8 @@ mod tests {
         assert_eq!(CalibrationErrorCodes::already_in_progress(), 2004);
         assert_eq!(CalibrationErrorCodes::state_poisoned(), 2005);
         assert_eq!(CalibrationErrorCodes::timeout(), 2006);
+        assert_eq!(CalibrationErrorCodes::io(), 2007);
+        assert_eq!(CalibrationErrorCodes::parse(), 2008);
+        assert_eq!(CalibrationErrorCodes::unknown_preset(), 2009);
     }
 }
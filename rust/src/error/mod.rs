@@ -7,7 +7,9 @@ pub mod audio;
 pub mod calibration;
 
 pub use audio::{log_audio_error, AudioError, AudioErrorCodes};
-pub use calibration::{log_calibration_error, CalibrationError, CalibrationErrorCodes};
+pub use calibration::{
+    log_calibration_error, CalibrationError, CalibrationErrorCodes, SoundSampleCount,
+};
 
 /// Error codes for structured error reporting
 ///
@@ -0,0 +1,106 @@
+//! Error type for fixture discovery, loading and recording.
+
+use std::fmt;
+use std::path::PathBuf;
+
+use crate::analysis::features::FftError;
+
+/// Failures of the fixture helpers in [`crate::fixtures`]
+#[derive(Debug)]
+pub enum FixtureError {
+    /// No fixture with this name or path exists
+    NotFound { fixture: String, root: PathBuf },
+    /// The path has no usable file stem to name the fixture after
+    InvalidName { path: PathBuf },
+    /// The WAV is readable but not in a shape fixtures support
+    UnsupportedFormat { path: PathBuf, reason: String },
+    /// The WAV could not be opened, decoded or written
+    WavDecode { path: PathBuf, source: hound::Error },
+    /// The expectation JSON could not be parsed
+    ExpectationParse {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+    /// The expectation JSON could not be serialized
+    ExpectationSerialize(serde_json::Error),
+    /// Filesystem access failed
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    /// A recorded session's onsets don't line up with its labels
+    OnsetMismatch { detected: usize, labels: usize },
+    /// Native format was required but the fixture would need converting
+    NonNativeFormat {
+        fixture: String,
+        channels: u16,
+        sample_rate: u32,
+    },
+    /// The feature extractor rejected its FFT size
+    FeatureExtractor(FftError),
+}
+
+impl fmt::Display for FixtureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FixtureError::NotFound { fixture, root } => {
+                write!(f, "Fixture '{fixture}' not found in {}", root.display())
+            }
+            FixtureError::InvalidName { path } => {
+                write!(f, "Invalid fixture name for {}", path.display())
+            }
+            FixtureError::UnsupportedFormat { path, reason } => {
+                write!(f, "Unsupported fixture {}: {reason}", path.display())
+            }
+            FixtureError::WavDecode { path, source } => {
+                write!(f, "WAV error for {}: {source}", path.display())
+            }
+            FixtureError::ExpectationParse { path, source } => {
+                write!(f, "parsing {}: {source}", path.display())
+            }
+            FixtureError::ExpectationSerialize(source) => {
+                write!(f, "serializing expectations: {source}")
+            }
+            FixtureError::Io { path, source } => write!(f, "{}: {source}", path.display()),
+            FixtureError::OnsetMismatch { detected, labels } => write!(
+                f,
+                "Detected {detected} onsets but {labels} labels were provided"
+            ),
+            FixtureError::NonNativeFormat {
+                fixture,
+                channels,
+                sample_rate,
+            } => write!(
+                f,
+                "Fixture '{fixture}' is {channels} channel(s) at {sample_rate} Hz; native format is mono at {} Hz",
+                crate::audio::ENGINE_SAMPLE_RATE
+            ),
+            FixtureError::FeatureExtractor(source) => {
+                write!(f, "building the feature extractor: {source}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for FixtureError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FixtureError::WavDecode { source, .. } => Some(source),
+            FixtureError::ExpectationParse { source, .. } => Some(source),
+            FixtureError::ExpectationSerialize(source) => Some(source),
+            FixtureError::Io { source, .. } => Some(source),
+            FixtureError::FeatureExtractor(source) => Some(source),
+            FixtureError::NotFound { .. }
+            | FixtureError::InvalidName { .. }
+            | FixtureError::UnsupportedFormat { .. }
+            | FixtureError::OnsetMismatch { .. }
+            | FixtureError::NonNativeFormat { .. } => None,
+        }
+    }
+}
+
+impl From<FftError> for FixtureError {
+    fn from(source: FftError) -> Self {
+        FixtureError::FeatureExtractor(source)
+    }
+}
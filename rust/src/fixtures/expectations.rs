@@ -0,0 +1,132 @@
+//! Expected events for a fixture and verification of actual results.
+
+use serde::{Deserialize, Serialize};
+
+use crate::analysis::ClassificationResult;
+use crate::types::BeatboxHit;
+
+/// JSON expectation schema for fixture verification.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FixtureExpectations {
+    pub fixture: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    pub events: Vec<ExpectedEvent>,
+    /// Detected events beyond `events` that are tolerated before the extras
+    /// count as failures
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub max_extra_events: usize,
+    /// Extra events below this confidence are ignored rather than counted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ignore_extras_below_confidence: Option<f32>,
+}
+
+fn is_zero(value: &usize) -> bool {
+    *value == 0
+}
+
+impl FixtureExpectations {
+    pub fn verify(
+        &self,
+        actual: &[ClassificationResult],
+    ) -> std::result::Result<(), ExpectationDiff> {
+        let mut failures = Vec::new();
+
+        for (idx, expected) in self.events.iter().enumerate() {
+            match actual.get(idx) {
+                Some(event) => {
+                    let delta = (event.timestamp_ms as f32 - expected.offset_ms).abs();
+                    if event.sound != expected.sound || delta > expected.tolerance_ms {
+                        failures.push(ExpectationFailure {
+                            index: idx,
+                            expected: expected.clone(),
+                            actual: Some(event.clone()),
+                            delta_ms: Some(delta),
+                        });
+                    }
+                }
+                None => failures.push(ExpectationFailure {
+                    index: idx,
+                    expected: expected.clone(),
+                    actual: None,
+                    delta_ms: None,
+                }),
+            }
+        }
+
+        if actual.len() > self.events.len() {
+            let min_confidence = self.ignore_extras_below_confidence.unwrap_or(0.0);
+            let extras = actual
+                .iter()
+                .enumerate()
+                .skip(self.events.len())
+                .filter(|(_, event)| event.confidence >= min_confidence)
+                .skip(self.max_extra_events);
+            for (idx, event) in extras {
+                failures.push(ExpectationFailure {
+                    index: idx,
+                    expected: ExpectedEvent {
+                        sound: BeatboxHit::Unknown,
+                        offset_ms: event.timestamp_ms as f32,
+                        tolerance_ms: 0.0,
+                    },
+                    actual: Some(event.clone()),
+                    delta_ms: Some(0.0),
+                });
+            }
+        }
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(ExpectationDiff { failures })
+        }
+    }
+}
+
+/// Expected classification event definition.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExpectedEvent {
+    pub sound: BeatboxHit,
+    pub offset_ms: f32,
+    #[serde(default = "default_tolerance")]
+    pub tolerance_ms: f32,
+}
+
+pub(super) fn default_tolerance() -> f32 {
+    50.0
+}
+
+/// Outcome of comparing actual results with expectations.
+#[derive(Debug)]
+pub struct ExpectationDiff {
+    pub failures: Vec<ExpectationFailure>,
+}
+
+impl ExpectationDiff {
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "failures": self.failures.iter().map(|failure| {
+                serde_json::json!({
+                    "index": failure.index,
+                    "expected": {
+                        "sound": failure.expected.sound,
+                        "offset_ms": failure.expected.offset_ms,
+                        "tolerance_ms": failure.expected.tolerance_ms,
+                    },
+                    "actual": failure.actual,
+                    "delta_ms": failure.delta_ms,
+                })
+            }).collect::<Vec<_>>()
+        })
+    }
+}
+
+/// Detailed diff entry for a single failure.
+#[derive(Debug)]
+pub struct ExpectationFailure {
+    pub index: usize,
+    pub expected: ExpectedEvent,
+    pub actual: Option<ClassificationResult>,
+    pub delta_ms: Option<f32>,
+}
@@ -10,16 +10,32 @@ use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
 use std::sync::Arc;
 
-use anyhow::{anyhow, Context, Result};
-use serde::{Deserialize, Serialize};
-
-use crate::analysis::classifier::{BeatboxHit, Classifier};
+use crate::analysis::classifier::Classifier;
 use crate::analysis::features::FeatureExtractor;
 use crate::analysis::onset::OnsetDetector;
 use crate::analysis::quantizer::Quantizer;
 use crate::analysis::ClassificationResult;
+use crate::audio::resample::resample;
+use crate::audio::ENGINE_SAMPLE_RATE;
 use crate::calibration::CalibrationState;
-use crate::config::{AppConfig, OnsetDetectionConfig};
+use crate::config::{AppConfig, ClassifierConfig, OnsetDetectionConfig};
+
+mod error;
+mod expectations;
+mod report;
+mod session;
+#[cfg(test)]
+mod tests;
+mod wav;
+
+pub use error::FixtureError;
+pub use expectations::{ExpectationDiff, ExpectationFailure, ExpectedEvent, FixtureExpectations};
+pub use report::{BatchReport, FixtureOutcome, SuiteAccuracy};
+pub use session::save_fixture_from_session;
+
+use wav::read_wav;
+
+type Result<T> = std::result::Result<T, FixtureError>;
 
 /// Default location for fixture WAV/JSON assets.
 pub const DEFAULT_FIXTURE_ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures");
@@ -33,121 +49,17 @@ pub struct FixtureMetadata {
 }
 
 /// Loaded fixture data with decoded PCM samples.
+///
+/// Multi-channel WAVs are downmixed to mono on load; `channels` keeps the
+/// channel count of the file as recorded.
 pub struct FixtureData {
     pub metadata: FixtureMetadata,
     pub sample_rate: u32,
+    pub channels: u16,
     pub samples: Vec<f32>,
     pub expectations: Option<FixtureExpectations>,
 }
 
-/// JSON expectation schema for fixture verification.
-#[derive(Debug, Clone, Deserialize)]
-pub struct FixtureExpectations {
-    pub fixture: String,
-    #[serde(default)]
-    pub notes: Option<String>,
-    pub events: Vec<ExpectedEvent>,
-}
-
-impl FixtureExpectations {
-    pub fn verify(
-        &self,
-        actual: &[ClassificationResult],
-    ) -> std::result::Result<(), ExpectationDiff> {
-        let mut failures = Vec::new();
-
-        for (idx, expected) in self.events.iter().enumerate() {
-            match actual.get(idx) {
-                Some(event) => {
-                    let delta = (event.timestamp_ms as f32 - expected.offset_ms).abs();
-                    if event.sound != expected.sound || delta > expected.tolerance_ms {
-                        failures.push(ExpectationFailure {
-                            index: idx,
-                            expected: expected.clone(),
-                            actual: Some(event.clone()),
-                            delta_ms: Some(delta),
-                        });
-                    }
-                }
-                None => failures.push(ExpectationFailure {
-                    index: idx,
-                    expected: expected.clone(),
-                    actual: None,
-                    delta_ms: None,
-                }),
-            }
-        }
-
-        if actual.len() > self.events.len() {
-            for (idx, event) in actual.iter().enumerate().skip(self.events.len()) {
-                failures.push(ExpectationFailure {
-                    index: idx,
-                    expected: ExpectedEvent {
-                        sound: BeatboxHit::Unknown,
-                        offset_ms: event.timestamp_ms as f32,
-                        tolerance_ms: 0.0,
-                    },
-                    actual: Some(event.clone()),
-                    delta_ms: Some(0.0),
-                });
-            }
-        }
-
-        if failures.is_empty() {
-            Ok(())
-        } else {
-            Err(ExpectationDiff { failures })
-        }
-    }
-}
-
-/// Expected classification event definition.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ExpectedEvent {
-    pub sound: BeatboxHit,
-    pub offset_ms: f32,
-    #[serde(default = "default_tolerance")]
-    pub tolerance_ms: f32,
-}
-
-fn default_tolerance() -> f32 {
-    50.0
-}
-
-/// Outcome of comparing actual results with expectations.
-#[derive(Debug)]
-pub struct ExpectationDiff {
-    pub failures: Vec<ExpectationFailure>,
-}
-
-impl ExpectationDiff {
-    pub fn to_json(&self) -> serde_json::Value {
-        serde_json::json!({
-            "failures": self.failures.iter().map(|failure| {
-                serde_json::json!({
-                    "index": failure.index,
-                    "expected": {
-                        "sound": failure.expected.sound,
-                        "offset_ms": failure.expected.offset_ms,
-                        "tolerance_ms": failure.expected.tolerance_ms,
-                    },
-                    "actual": failure.actual,
-                    "delta_ms": failure.delta_ms,
-                })
-            }).collect::<Vec<_>>()
-        })
-    }
-}
-
-/// Detailed diff entry for a single failure.
-#[derive(Debug)]
-pub struct ExpectationFailure {
-    pub index: usize,
-    pub expected: ExpectedEvent,
-    pub actual: Option<ClassificationResult>,
-    pub delta_ms: Option<f32>,
-}
-
 /// Catalog responsible for discovering fixtures on disk.
 pub struct FixtureCatalog {
     root: PathBuf,
@@ -169,9 +81,13 @@ impl FixtureCatalog {
             return Ok(fixtures);
         }
 
-        for entry in fs::read_dir(&self.root)? {
-            let entry = entry?;
-            if entry.file_type()?.is_file() {
+        let io_error = |source| FixtureError::Io {
+            path: self.root.clone(),
+            source,
+        };
+        for entry in fs::read_dir(&self.root).map_err(io_error)? {
+            let entry = entry.map_err(io_error)?;
+            if entry.file_type().map_err(io_error)?.is_file() {
                 let path = entry.path();
                 if path.extension().and_then(|ext| ext.to_str()) == Some("wav") {
                     let expect = path.with_extension("expect.json");
@@ -196,16 +112,18 @@ impl FixtureCatalog {
     pub fn load(&self, fixture: &str, override_expect: Option<PathBuf>) -> Result<FixtureData> {
         let wav_path = self.resolve_fixture_path(fixture)?;
         let metadata = self.metadata_for_path(&wav_path)?;
-        let (samples, sample_rate) = read_wav(&wav_path)?;
+        let (samples, sample_rate, channels) = read_wav(&wav_path)?;
 
         let expectation_path = override_expect.or(metadata.expect_path.clone());
         let expectations = match expectation_path {
             Some(path) => {
-                let json = fs::read_to_string(&path)
-                    .with_context(|| format!("reading expectation {}", path.display()))?;
+                let json = fs::read_to_string(&path).map_err(|source| FixtureError::Io {
+                    path: path.clone(),
+                    source,
+                })?;
                 Some(
                     serde_json::from_str(&json)
-                        .with_context(|| format!("parsing {}", path.display()))?,
+                        .map_err(|source| FixtureError::ExpectationParse { path, source })?,
                 )
             }
             None => None,
@@ -214,6 +132,7 @@ impl FixtureCatalog {
         Ok(FixtureData {
             metadata,
             sample_rate,
+            channels,
             samples,
             expectations,
         })
@@ -229,10 +148,10 @@ impl FixtureCatalog {
         if candidate.exists() {
             Ok(candidate)
         } else {
-            Err(anyhow!(
-                "Fixture '{fixture}' not found in {}",
-                self.root.display()
-            ))
+            Err(FixtureError::NotFound {
+                fixture: fixture.to_string(),
+                root: self.root.clone(),
+            })
         }
     }
 
@@ -240,7 +159,9 @@ impl FixtureCatalog {
         let name = wav_path
             .file_stem()
             .and_then(|s| s.to_str())
-            .ok_or_else(|| anyhow!("Invalid fixture name for {}", wav_path.display()))?
+            .ok_or_else(|| FixtureError::InvalidName {
+                path: wav_path.to_path_buf(),
+            })?
             .to_string();
         let expect_path = wav_path.with_extension("expect.json");
         Ok(FixtureMetadata {
@@ -258,10 +179,17 @@ impl Default for FixtureCatalog {
 }
 
 /// Executes fixtures by feeding decoded PCM samples through the DSP pipeline.
+///
+/// Fixtures recorded at another rate are resampled to `ENGINE_SAMPLE_RATE`
+/// first, so results match what the live engine would produce. With
+/// [`FixtureProcessor::require_native_format`] set, a fixture that would
+/// need downmixing or resampling is refused instead.
 pub struct FixtureProcessor {
     onset_config: OnsetDetectionConfig,
+    classifier_config: ClassifierConfig,
     calibration_state: Arc<std::sync::RwLock<CalibrationState>>,
     bpm: u32,
+    require_native_format: bool,
 }
 
 impl FixtureProcessor {
@@ -271,8 +199,10 @@ impl FixtureProcessor {
     ) -> Self {
         Self {
             onset_config: app_config.onset_detection,
+            classifier_config: app_config.classifier,
             calibration_state,
             bpm: 120,
+            require_native_format: false,
         }
     }
 
@@ -283,62 +213,92 @@ impl FixtureProcessor {
         self
     }
 
+    /// Fail fixtures that aren't mono at `ENGINE_SAMPLE_RATE` rather than
+    /// converting them, for tests that must run on the audio as recorded
+    pub fn require_native_format(mut self, required: bool) -> Self {
+        self.require_native_format = required;
+        self
+    }
+
     pub fn run(&self, data: &FixtureData) -> Result<Vec<ClassificationResult>> {
+        if self.require_native_format
+            && (data.channels != 1 || data.sample_rate != ENGINE_SAMPLE_RATE)
+        {
+            return Err(FixtureError::NonNativeFormat {
+                fixture: data.metadata.name.clone(),
+                channels: data.channels,
+                sample_rate: data.sample_rate,
+            });
+        }
         if data.samples.is_empty() {
             return Ok(Vec::new());
         }
 
-        let mut detector = OnsetDetector::with_config(data.sample_rate, self.onset_config.clone());
-        let extractor = FeatureExtractor::new(data.sample_rate);
-        let classifier = Classifier::new(Arc::clone(&self.calibration_state));
+        let sample_rate = ENGINE_SAMPLE_RATE;
+        let samples = resample(&data.samples, data.sample_rate, sample_rate);
+
+        let mut detector = OnsetDetector::with_config(sample_rate, self.onset_config.clone());
+        let extractor = FeatureExtractor::new(sample_rate)?
+            .with_decay_window_ms(self.onset_config.decay_window_ms);
+        let classifier = Classifier::new(Arc::clone(&self.calibration_state))
+            .with_config(&self.classifier_config);
         let frame_counter = Arc::new(AtomicU64::new(0));
         let bpm = Arc::new(AtomicU32::new(self.bpm));
-        let quantizer = Quantizer::new(Arc::clone(&frame_counter), bpm, data.sample_rate);
+        let quantizer = Quantizer::new(Arc::clone(&frame_counter), bpm, sample_rate);
 
-        frame_counter.store(data.samples.len() as u64, Ordering::Relaxed);
+        frame_counter.store(samples.len() as u64, Ordering::Relaxed);
 
-        let mut onsets = detector.process(&data.samples);
+        let mut onsets = detector.process_timestamps(&samples);
         if onsets.is_empty() {
-            onsets = detect_energy_onsets(&data.samples, data.sample_rate);
+            onsets = detect_energy_onsets(&samples, sample_rate);
         }
         let mut results = Vec::with_capacity(onsets.len());
 
         for onset in onsets {
             let idx = onset as usize;
-            if idx + FEATURE_WINDOW > data.samples.len() {
+            if idx + FEATURE_WINDOW > samples.len() {
                 continue;
             }
 
-            let window = &data.samples[idx..idx + FEATURE_WINDOW];
+            let window = &samples[idx..idx + FEATURE_WINDOW];
             let features = extractor.extract(window);
-            let level = self
-                .calibration_state
-                .read()
-                .map(|state| state.level)
-                .unwrap_or(1);
-            let (sound, confidence) = if level >= 2 {
-                classifier.classify_level2(&features)
-            } else {
-                classifier.classify_level1(&features)
-            };
-
-            let timing = quantizer.quantize(onset);
-            let timestamp_ms = ((onset as f32 / data.sample_rate as f32) * 1000.0)
+            let (sound, confidence) = classifier.classify(&features);
+
+            let timing = Some(quantizer.quantize(onset));
+            let timestamp_ms = ((onset as f32 / sample_rate as f32) * 1000.0)
                 .round()
                 .max(0.0) as u64;
 
-            results.push(ClassificationResult {
+            results.push(ClassificationResult::new(
                 sound,
                 timing,
                 timestamp_ms,
                 confidence,
-            });
+            ));
         }
 
         Ok(results)
     }
 }
 
+impl FixtureProcessor {
+    /// Run and verify every fixture in `catalog` that has expectations
+    pub fn run_batch(&self, catalog: &FixtureCatalog) -> Result<BatchReport> {
+        let mut report = BatchReport::default();
+        for metadata in catalog.discover()? {
+            if metadata.expect_path.is_none() {
+                continue;
+            }
+            let data = catalog.load(&metadata.wav_path.to_string_lossy(), None)?;
+            let actual = self.run(&data)?;
+            if let Some(expectations) = &data.expectations {
+                report.record(&metadata.name, expectations, &actual);
+            }
+        }
+        Ok(report)
+    }
+}
+
 const FEATURE_WINDOW: usize = 1024;
 
 fn detect_energy_onsets(samples: &[f32], sample_rate: u32) -> Vec<u64> {
@@ -364,55 +324,3 @@ fn detect_energy_onsets(samples: &[f32], sample_rate: u32) -> Vec<u64> {
 
     onsets
 }
-
-fn read_wav(path: &Path) -> Result<(Vec<f32>, u32)> {
-    let mut reader =
-        hound::WavReader::open(path).with_context(|| format!("opening {}", path.display()))?;
-    let spec = reader.spec();
-    if spec.channels != 1 {
-        return Err(anyhow!(
-            "Fixture {} must be mono (found {} channels)",
-            path.display(),
-            spec.channels
-        ));
-    }
-
-    let sample_rate = spec.sample_rate;
-
-    let samples = match spec.sample_format {
-        hound::SampleFormat::Float => reader
-            .samples::<f32>()
-            .map(|sample| sample.map_err(|err| anyhow!(err)))
-            .collect::<Result<Vec<f32>>>()?,
-        hound::SampleFormat::Int => {
-            let max = (1i64 << (spec.bits_per_sample - 1)) - 1;
-            match spec.bits_per_sample {
-                16 => reader
-                    .samples::<i16>()
-                    .map(|sample| {
-                        sample
-                            .map(|value| value as f32 / max as f32)
-                            .map_err(|err| anyhow!(err))
-                    })
-                    .collect::<Result<Vec<f32>>>()?,
-                24 | 32 => reader
-                    .samples::<i32>()
-                    .map(|sample| {
-                        sample
-                            .map(|value| value as f32 / max as f32)
-                            .map_err(|err| anyhow!(err))
-                    })
-                    .collect::<Result<Vec<f32>>>()?,
-                other => {
-                    return Err(anyhow!(
-                        "Unsupported bits per sample {} in {}",
-                        other,
-                        path.display()
-                    ))
-                }
-            }
-        }
-    };
-
-    Ok((samples, sample_rate))
-}
@@ -0,0 +1,87 @@
+//! Per-fixture outcomes and accuracy totals for batch runs.
+
+use serde::Serialize;
+
+use super::{ExpectationDiff, FixtureExpectations};
+use crate::analysis::ClassificationResult;
+
+/// Verification outcome of one fixture in a batch run.
+#[derive(Debug)]
+pub struct FixtureOutcome {
+    pub fixture: String,
+    /// Expected events in the fixture
+    pub expected: usize,
+    /// Expected events matched with the right sound within tolerance
+    pub matched: usize,
+    /// Failures reported by [`FixtureExpectations::verify`], if any
+    pub diff: Option<ExpectationDiff>,
+}
+
+impl FixtureOutcome {
+    pub fn passed(&self) -> bool {
+        self.diff.is_none()
+    }
+}
+
+/// Matched and expected event totals over a batch, with their ratio.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct SuiteAccuracy {
+    pub matched: usize,
+    pub expected: usize,
+    /// `matched / expected`; 0 when nothing was expected, so an empty
+    /// suite never passes an accuracy gate
+    pub overall_accuracy: f32,
+}
+
+/// Verification results for a set of fixtures.
+#[derive(Debug, Default)]
+pub struct BatchReport {
+    pub fixtures: Vec<FixtureOutcome>,
+}
+
+impl BatchReport {
+    /// Verify one fixture's results and add the outcome to the report
+    pub fn record(
+        &mut self,
+        fixture: &str,
+        expectations: &FixtureExpectations,
+        actual: &[ClassificationResult],
+    ) {
+        let expected = expectations.events.len();
+        let diff = expectations.verify(actual).err();
+        // Extra detections fail a fixture but don't miss an expected event
+        let missed = diff.as_ref().map_or(0, |diff| {
+            diff.failures
+                .iter()
+                .filter(|failure| failure.index < expected)
+                .count()
+        });
+        self.fixtures.push(FixtureOutcome {
+            fixture: fixture.to_string(),
+            expected,
+            matched: expected - missed,
+            diff,
+        });
+    }
+
+    /// Fraction of expected events across all fixtures that were matched
+    pub fn accuracy(&self) -> SuiteAccuracy {
+        let matched = self.fixtures.iter().map(|outcome| outcome.matched).sum();
+        let expected = self.fixtures.iter().map(|outcome| outcome.expected).sum();
+        let overall_accuracy = if expected == 0 {
+            0.0
+        } else {
+            matched as f32 / expected as f32
+        };
+        SuiteAccuracy {
+            matched,
+            expected,
+            overall_accuracy,
+        }
+    }
+
+    /// Whether every fixture passed verification
+    pub fn all_passed(&self) -> bool {
+        self.fixtures.iter().all(FixtureOutcome::passed)
+    }
+}
@@ -0,0 +1,85 @@
+//! Recording labelled sessions as new fixtures.
+
+use std::fs;
+use std::path::Path;
+
+use super::expectations::default_tolerance;
+use super::wav::write_wav;
+use super::{
+    detect_energy_onsets, ExpectedEvent, FixtureError, FixtureExpectations, Result, FEATURE_WINDOW,
+};
+use crate::analysis::onset::OnsetDetector;
+use crate::config::OnsetDetectionConfig;
+use crate::types::BeatboxHit;
+
+/// Turn a labelled recording session into a fixture.
+///
+/// `wav_samples` is mono audio at the engine sample rate. Onsets are located
+/// the same way [`FixtureProcessor::run`] finds them, and the `n`-th onset
+/// is tagged with `labels[n]`. The samples are written to `path` as a mono
+/// WAV, and a matching `<name>.expect.json` is written next to it.
+///
+/// Fails if the number of detected onsets does not match the number of
+/// labels, since a misaligned fixture would silently encode wrong truth.
+pub fn save_fixture_from_session<P: AsRef<Path>>(
+    wav_samples: &[f32],
+    labels: Vec<BeatboxHit>,
+    path: P,
+) -> Result<FixtureExpectations> {
+    let wav_path = path.as_ref();
+    let sample_rate = crate::audio::ENGINE_SAMPLE_RATE;
+    let name = wav_path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .ok_or_else(|| FixtureError::InvalidName {
+            path: wav_path.to_path_buf(),
+        })?
+        .to_string();
+
+    let onsets = session_onsets(wav_samples, sample_rate);
+    if onsets.len() != labels.len() {
+        return Err(FixtureError::OnsetMismatch {
+            detected: onsets.len(),
+            labels: labels.len(),
+        });
+    }
+
+    let events = onsets
+        .into_iter()
+        .zip(labels)
+        .map(|(onset, sound)| ExpectedEvent {
+            sound,
+            offset_ms: (onset as f32 / sample_rate as f32 * 1000.0).round(),
+            tolerance_ms: default_tolerance(),
+        })
+        .collect();
+    let expectations = FixtureExpectations {
+        fixture: name,
+        notes: Some("Generated from a labelled recording session".to_string()),
+        events,
+        max_extra_events: 0,
+        ignore_extras_below_confidence: None,
+    };
+
+    write_wav(wav_path, wav_samples, sample_rate)?;
+    let expect_path = wav_path.with_extension("expect.json");
+    let json =
+        serde_json::to_string_pretty(&expectations).map_err(FixtureError::ExpectationSerialize)?;
+    fs::write(&expect_path, json).map_err(|source| FixtureError::Io {
+        path: expect_path,
+        source,
+    })?;
+
+    Ok(expectations)
+}
+
+/// Onsets the fixture processor would classify (enough audio for a window)
+fn session_onsets(samples: &[f32], sample_rate: u32) -> Vec<u64> {
+    let mut detector = OnsetDetector::with_config(sample_rate, OnsetDetectionConfig::default());
+    let mut onsets = detector.process_timestamps(samples);
+    if onsets.is_empty() {
+        onsets = detect_energy_onsets(samples, sample_rate);
+    }
+    onsets.retain(|&onset| onset as usize + FEATURE_WINDOW <= samples.len());
+    onsets
+}
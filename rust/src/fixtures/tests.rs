@@ -0,0 +1,203 @@
+use super::expectations::default_tolerance;
+use super::*;
+use crate::types::BeatboxHit;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Half a second per hit: a short decaying noise burst, then silence
+fn session(hits: usize) -> Vec<f32> {
+    let mut rng = StdRng::seed_from_u64(418);
+    let sr = crate::audio::ENGINE_SAMPLE_RATE as usize;
+    let mut samples = vec![0.0; sr / 4];
+    for _ in 0..hits {
+        samples.extend((0..sr / 2).map(|i| {
+            let envelope = (-(i as f32) / (sr as f32 * 0.02)).exp();
+            0.8 * envelope * rng.gen_range(-1.0f32..1.0)
+        }));
+    }
+    samples
+}
+
+#[test]
+fn test_session_fixture_round_trips_through_catalog() {
+    let dir = std::env::temp_dir().join(format!("bbt-session-fixture-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let wav_path = dir.join("session.wav");
+    let labels = vec![BeatboxHit::Kick, BeatboxHit::Snare, BeatboxHit::HiHat];
+
+    let saved = save_fixture_from_session(&session(3), labels.clone(), &wav_path).unwrap();
+    let loaded = FixtureCatalog::new(&dir).load("session", None).unwrap();
+    let _ = fs::remove_dir_all(&dir);
+
+    let expectations = loaded.expectations.expect("expect.json parsed");
+    assert_eq!(expectations.fixture, "session");
+    assert_eq!(expectations.events.len(), labels.len());
+    let sounds: Vec<BeatboxHit> = expectations.events.iter().map(|e| e.sound).collect();
+    assert_eq!(sounds, labels);
+    assert!(expectations
+        .events
+        .windows(2)
+        .all(|pair| pair[0].offset_ms < pair[1].offset_ms));
+    assert_eq!(saved.events.len(), expectations.events.len());
+    assert_eq!(loaded.samples.len(), session(3).len());
+}
+
+#[test]
+fn test_session_fixture_rejects_label_count_mismatch() {
+    let path = std::env::temp_dir().join(format!("bbt-mismatch-{}.wav", std::process::id()));
+    let result = save_fixture_from_session(&session(2), vec![BeatboxHit::Kick], &path);
+    assert!(matches!(
+        result,
+        Err(FixtureError::OnsetMismatch {
+            detected: 2,
+            labels: 1
+        })
+    ));
+    assert!(!path.exists());
+}
+
+#[test]
+fn test_native_format_rejects_stereo_but_downmix_accepts_it() {
+    let dir = std::env::temp_dir().join(format!("bbt-stereo-fixture-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let spec = hound::WavSpec {
+        channels: 2,
+        sample_rate: ENGINE_SAMPLE_RATE,
+        bits_per_sample: 32,
+        sample_format: hound::SampleFormat::Float,
+    };
+    let mut writer = hound::WavWriter::create(dir.join("stereo.wav"), spec).unwrap();
+    for sample in session(3) {
+        writer.write_sample(sample).unwrap();
+        writer.write_sample(sample).unwrap();
+    }
+    writer.finalize().unwrap();
+    let data = FixtureCatalog::new(&dir).load("stereo", None).unwrap();
+    let _ = fs::remove_dir_all(&dir);
+    assert_eq!(data.channels, 2);
+    assert_eq!(data.samples.len(), session(3).len());
+
+    let processor = || {
+        FixtureProcessor::new(
+            AppConfig::default(),
+            Arc::new(std::sync::RwLock::new(CalibrationState::new_default())),
+        )
+    };
+    let strict = processor().require_native_format(true).run(&data);
+    assert!(matches!(
+        strict,
+        Err(FixtureError::NonNativeFormat { channels: 2, .. })
+    ));
+
+    let results = processor().run(&data).expect("downmixed fixture runs");
+    assert_eq!(results.len(), 3);
+}
+
+#[test]
+fn test_extras_within_allowance_do_not_fail() {
+    let expectations: FixtureExpectations = serde_json::from_str(
+        r#"{
+            "fixture": "grace",
+            "events": [{ "sound": "Kick", "offset_ms": 100.0 }],
+            "max_extra_events": 1,
+            "ignore_extras_below_confidence": 0.3
+        }"#,
+    )
+    .unwrap();
+    let hit = |ms, confidence| ClassificationResult::new(BeatboxHit::Snare, None, ms, confidence);
+    let kick = ClassificationResult::new(BeatboxHit::Kick, None, 100, 0.9);
+
+    // One confident extra is allowed, low-confidence ones are ignored
+    let within = [kick.clone(), hit(300, 0.8), hit(500, 0.1), hit(700, 0.2)];
+    assert!(expectations.verify(&within).is_ok());
+
+    // A second confident extra exceeds the allowance
+    let over = [kick.clone(), hit(300, 0.8), hit(500, 0.1), hit(700, 0.9)];
+    let diff = expectations.verify(&over).unwrap_err();
+    assert_eq!(diff.failures.len(), 1);
+    assert_eq!(diff.failures[0].index, 3);
+
+    // Without the options every extra fails, as before
+    let strict = FixtureExpectations {
+        max_extra_events: 0,
+        ignore_extras_below_confidence: None,
+        ..expectations
+    };
+    assert_eq!(strict.verify(&within).unwrap_err().failures.len(), 3);
+}
+
+#[test]
+fn test_batch_accuracy_counts_matched_expected_events() {
+    let expect = |sounds: &[(BeatboxHit, f32)]| FixtureExpectations {
+        fixture: String::new(),
+        notes: None,
+        events: sounds
+            .iter()
+            .map(|&(sound, offset_ms)| ExpectedEvent {
+                sound,
+                offset_ms,
+                tolerance_ms: default_tolerance(),
+            })
+            .collect(),
+        max_extra_events: 0,
+        ignore_extras_below_confidence: None,
+    };
+    let hit = |sound, ms| ClassificationResult::new(sound, None, ms, 0.9);
+
+    let mut report = BatchReport::default();
+    // Passing: both events matched
+    report.record(
+        "pass",
+        &expect(&[(BeatboxHit::Kick, 100.0), (BeatboxHit::Snare, 600.0)]),
+        &[hit(BeatboxHit::Kick, 110), hit(BeatboxHit::Snare, 590)],
+    );
+    // Failing: wrong sound, too late, one match, plus an extra detection
+    report.record(
+        "fail",
+        &expect(&[
+            (BeatboxHit::Kick, 100.0),
+            (BeatboxHit::Snare, 600.0),
+            (BeatboxHit::HiHat, 1100.0),
+        ]),
+        &[
+            hit(BeatboxHit::HiHat, 100),
+            hit(BeatboxHit::Snare, 800),
+            hit(BeatboxHit::HiHat, 1100),
+            hit(BeatboxHit::Kick, 1500),
+        ],
+    );
+
+    assert!(report.fixtures[0].passed());
+    assert!(!report.fixtures[1].passed());
+    assert!(!report.all_passed());
+    assert_eq!(
+        report.accuracy(),
+        SuiteAccuracy {
+            matched: 3,
+            expected: 5,
+            overall_accuracy: 0.6,
+        }
+    );
+    assert_eq!(BatchReport::default().accuracy().overall_accuracy, 0.0);
+}
+
+#[test]
+fn test_missing_fixture_is_not_found() {
+    let root = std::env::temp_dir().join(format!("bbt-no-fixtures-{}", std::process::id()));
+    let err = FixtureCatalog::new(&root)
+        .load("does-not-exist", None)
+        .err()
+        .expect("missing fixture must fail");
+
+    match &err {
+        FixtureError::NotFound {
+            fixture,
+            root: searched,
+        } => {
+            assert_eq!(fixture, "does-not-exist");
+            assert_eq!(searched, &root);
+        }
+        other => panic!("expected NotFound, got {other:?}"),
+    }
+    assert!(err.to_string().contains("does-not-exist"));
+}
@@ -0,0 +1,87 @@
+//! WAV reading and writing for fixture audio.
+
+use std::path::Path;
+
+use super::{FixtureError, Result};
+
+pub(super) fn write_wav(path: &Path, samples: &[f32], sample_rate: u32) -> Result<()> {
+    let spec = hound::WavSpec {
+        channels: 1,
+        sample_rate,
+        bits_per_sample: 32,
+        sample_format: hound::SampleFormat::Float,
+    };
+    let wav_error = |source| FixtureError::WavDecode {
+        path: path.to_path_buf(),
+        source,
+    };
+    let mut writer = hound::WavWriter::create(path, spec).map_err(wav_error)?;
+    for &sample in samples {
+        writer.write_sample(sample).map_err(wav_error)?;
+    }
+    writer.finalize().map_err(wav_error)
+}
+
+/// Decode a WAV to mono samples, returning them with the file's sample rate
+/// and channel count
+pub(super) fn read_wav(path: &Path) -> Result<(Vec<f32>, u32, u16)> {
+    let wav_error = |source| FixtureError::WavDecode {
+        path: path.to_path_buf(),
+        source,
+    };
+    let mut reader = hound::WavReader::open(path).map_err(wav_error)?;
+    let spec = reader.spec();
+    if spec.channels == 0 {
+        return Err(FixtureError::UnsupportedFormat {
+            path: path.to_path_buf(),
+            reason: "no channels".to_string(),
+        });
+    }
+
+    let sample_rate = spec.sample_rate;
+
+    let samples = match spec.sample_format {
+        hound::SampleFormat::Float => reader
+            .samples::<f32>()
+            .map(|sample| sample.map_err(wav_error))
+            .collect::<Result<Vec<f32>>>()?,
+        hound::SampleFormat::Int => {
+            let max = (1i64 << (spec.bits_per_sample - 1)) - 1;
+            match spec.bits_per_sample {
+                16 => reader
+                    .samples::<i16>()
+                    .map(|sample| {
+                        sample
+                            .map(|value| value as f32 / max as f32)
+                            .map_err(wav_error)
+                    })
+                    .collect::<Result<Vec<f32>>>()?,
+                24 | 32 => reader
+                    .samples::<i32>()
+                    .map(|sample| {
+                        sample
+                            .map(|value| value as f32 / max as f32)
+                            .map_err(wav_error)
+                    })
+                    .collect::<Result<Vec<f32>>>()?,
+                other => {
+                    return Err(FixtureError::UnsupportedFormat {
+                        path: path.to_path_buf(),
+                        reason: format!("unsupported bits per sample {other}"),
+                    })
+                }
+            }
+        }
+    };
+
+    let samples = if spec.channels == 1 {
+        samples
+    } else {
+        samples
+            .chunks(spec.channels as usize)
+            .map(|frame| frame.iter().sum::<f32>() / frame.len() as f32)
+            .collect()
+    };
+
+    Ok((samples, sample_rate, spec.channels))
+}
@@ -3,7 +3,7 @@ pub mod api;
 pub mod audio;
 mod bridge_generated;
 mod calibration;
-mod config;
+pub mod config;
 pub mod context;
 pub mod debug;
 pub mod engine;
@@ -14,6 +14,7 @@ pub mod telemetry;
 // Unconditionally expose testing to satisfy bridge_generated.rs dependencies
 // The module itself might handle feature gating internally if needed, or we accept it for now.
 pub mod testing;
+pub mod types;
 
 #[cfg(target_os = "android")]
 use jni::{JNIEnv, JavaVM};
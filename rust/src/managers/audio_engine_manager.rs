@@ -7,14 +7,16 @@ use std::sync::{Arc, Mutex, RwLock};
 use tokio::sync::broadcast;
 
 use crate::analysis::ClassificationResult;
+use crate::api::OnsetEvent;
 use crate::calibration::{CalibrationProcedure, CalibrationProgress, CalibrationState};
-use crate::config::{AudioConfig, OnsetDetectionConfig};
+use crate::config::{AnalysisConfig, AudioConfig};
 use crate::error::{log_audio_error, AudioError};
 
 #[allow(unused_imports)]
 use crate::audio::{
     buffer_pool::{BufferPool, BufferPoolChannels},
     engine::AudioEngine,
+    ENGINE_SAMPLE_RATE,
 };
 
 /// AudioEngine state container for lifecycle management
@@ -36,7 +38,7 @@ struct AudioEngineState {
 /// ```ignore
 /// let manager = AudioEngineManager::new(
 ///     AudioConfig::default(),
-///     OnsetDetectionConfig::default(),
+///     AnalysisConfig::default(),
 ///     100,
 /// );
 /// manager.start(120, calibration_state, calibration_procedure, calibration_progress_tx, classification_tx)?;
@@ -47,7 +49,7 @@ struct AudioEngineState {
 pub struct AudioEngineManager {
     engine: Arc<Mutex<Option<AudioEngineState>>>,
     audio_config: AudioConfig,
-    onset_config: OnsetDetectionConfig,
+    analysis_config: AnalysisConfig,
     log_every_n_buffers: u64,
 }
 
@@ -58,13 +60,13 @@ impl AudioEngineManager {
     /// Initializes with no audio engine running.
     pub fn new(
         audio_config: AudioConfig,
-        onset_config: OnsetDetectionConfig,
+        analysis_config: AnalysisConfig,
         log_every_n_buffers: u64,
     ) -> Self {
         Self {
             engine: Arc::new(Mutex::new(None)),
             audio_config,
-            onset_config,
+            analysis_config,
             log_every_n_buffers,
         }
     }
@@ -80,6 +82,8 @@ impl AudioEngineManager {
     /// * `calibration_procedure` - Optional calibration procedure for collecting training samples
     /// * `calibration_progress_tx` - Optional broadcast channel for calibration progress updates
     /// * `broadcast_tx` - Broadcast channel for classification results
+    /// * `onset_events_tx` - Optional broadcast channel for raw onset events
+    /// * `metronome_enabled` - Whether the metronome click is audible
     ///
     /// # Returns
     /// * `Ok(())` - Audio engine started successfully
@@ -90,6 +94,7 @@ impl AudioEngineManager {
     /// - Audio engine already running
     /// - Lock poisoning
     /// - Hardware/platform errors
+    #[allow(clippy::too_many_arguments)]
     pub fn start(
         &self,
         bpm: u32,
@@ -97,6 +102,7 @@ impl AudioEngineManager {
         calibration_procedure: Arc<Mutex<Option<CalibrationProcedure>>>,
         calibration_progress_tx: Option<broadcast::Sender<CalibrationProgress>>,
         broadcast_tx: broadcast::Sender<ClassificationResult>,
+        onset_events_tx: Option<broadcast::Sender<OnsetEvent>>,
         metronome_enabled: bool,
     ) -> Result<(), AudioError> {
         self.validate_bpm(bpm)?;
@@ -114,7 +120,8 @@ impl AudioEngineManager {
                 calibration_procedure,
                 calibration_progress_tx,
                 broadcast_tx,
-                self.onset_config.clone(),
+                onset_events_tx,
+                self.analysis_config.clone(),
                 self.log_every_n_buffers,
             )
             .inspect_err(|err| {
@@ -126,6 +133,37 @@ impl AudioEngineManager {
         Ok(())
     }
 
+    /// Start audio engine in metronome-only mode
+    ///
+    /// Opens the output stream only: no input stream, no analysis thread and
+    /// therefore no microphone permission prompt. Stopped via `stop()` like a
+    /// regular session.
+    ///
+    /// # Arguments
+    /// * `bpm` - Beats per minute (must be > 0)
+    ///
+    /// # Errors
+    /// - Invalid BPM (must be > 0)
+    /// - Audio engine already running
+    /// - Lock poisoning
+    /// - Hardware/platform errors
+    pub fn start_metronome_only(&self, bpm: u32) -> Result<(), AudioError> {
+        self.validate_bpm(bpm)?;
+
+        let mut guard = self.lock_engine()?;
+        self.check_not_running(&guard)?;
+
+        let buffer_pool = self.create_buffer_pool();
+        let mut engine = self.create_engine(bpm, buffer_pool)?;
+        engine.start_metronome_only().inspect_err(|err| {
+            log_audio_error(err, "start_metronome_only");
+        })?;
+
+        *guard = Some(AudioEngineState { engine });
+
+        Ok(())
+    }
+
     /// Stop audio engine gracefully
     ///
     /// Stops audio streams and releases resources.
@@ -256,16 +294,21 @@ impl AudioEngineManager {
         bpm: u32,
         buffer_channels: BufferPoolChannels,
     ) -> Result<AudioEngine, AudioError> {
-        let sample_rate = 48000; // Standard sample rate
-        AudioEngine::new(bpm, sample_rate, buffer_channels).inspect_err(|err| {
-            log_audio_error(err, "create_engine");
-        })
+        let mut engine =
+            AudioEngine::new(bpm, ENGINE_SAMPLE_RATE, buffer_channels).inspect_err(|err| {
+                log_audio_error(err, "create_engine");
+            })?;
+        engine.set_click_config(&self.audio_config.click);
+        // The Android input stream is opened mono, so there is nothing to select
+        #[cfg(not(target_os = "android"))]
+        engine.set_input_channel(self.audio_config.input_channel);
+        Ok(engine)
     }
 }
 
 impl Default for AudioEngineManager {
     fn default() -> Self {
-        Self::new(AudioConfig::default(), OnsetDetectionConfig::default(), 100)
+        Self::new(AudioConfig::default(), AnalysisConfig::default(), 100)
     }
 }
 
@@ -274,7 +317,7 @@ mod tests {
     use super::*;
 
     fn create_manager() -> AudioEngineManager {
-        AudioEngineManager::new(AudioConfig::default(), OnsetDetectionConfig::default(), 100)
+        AudioEngineManager::new(AudioConfig::default(), AnalysisConfig::default(), 100)
     }
 
     #[test]
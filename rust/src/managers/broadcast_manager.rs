@@ -6,7 +6,7 @@ use tokio::sync::broadcast;
 
 use crate::analysis::ClassificationResult;
 use crate::api::{AudioMetrics, OnsetEvent};
-use crate::calibration::CalibrationProgress;
+use crate::calibration::{CalibrationDebugFrame, CalibrationProgress};
 
 /// Manages all tokio broadcast channels
 ///
@@ -23,11 +23,15 @@ use crate::calibration::CalibrationProgress;
 /// - Calibration: Progress updates during calibration workflow
 /// - Audio Metrics: Debug metrics for audio analysis (RMS, spectral centroid, etc.)
 /// - Onset Events: Debug onset detection events with timing and energy
+/// - Calibration Debug: Per-buffer feature frames during calibration
+/// - Calibration Preview: Provisional classifications during calibration
 pub struct BroadcastChannelManager {
     classification: Arc<Mutex<Option<broadcast::Sender<ClassificationResult>>>>,
     calibration: Arc<Mutex<Option<broadcast::Sender<CalibrationProgress>>>>,
     audio_metrics: Arc<Mutex<Option<broadcast::Sender<AudioMetrics>>>>,
     onset_events: Arc<Mutex<Option<broadcast::Sender<OnsetEvent>>>>,
+    calibration_debug: broadcast::Sender<CalibrationDebugFrame>,
+    calibration_preview: broadcast::Sender<ClassificationResult>,
 }
 
 impl BroadcastChannelManager {
@@ -42,11 +46,17 @@ impl BroadcastChannelManager {
         // before start_audio() is called. Without eager init, the subscription
         // would return an empty receiver that never receives data.
         let (audio_metrics_tx, _) = broadcast::channel(100);
+        // Same reasoning for the calibration debug stream: tuning UIs
+        // subscribe before calibration starts.
+        let (calibration_debug_tx, _) = broadcast::channel(256);
+        let (calibration_preview_tx, _) = broadcast::channel(100);
         Self {
             classification: Arc::new(Mutex::new(None)),
             calibration: Arc::new(Mutex::new(None)),
             audio_metrics: Arc::new(Mutex::new(Some(audio_metrics_tx))),
             onset_events: Arc::new(Mutex::new(None)),
+            calibration_debug: calibration_debug_tx,
+            calibration_preview: calibration_preview_tx,
         }
     }
 
@@ -92,6 +102,36 @@ impl BroadcastChannelManager {
             .map(|tx| tx.subscribe())
     }
 
+    /// Publish a scripted result as if the audio engine had detected it
+    ///
+    /// Lets UI work exercise result rendering without audio. Initializes the
+    /// classification channel if audio has not been started yet. Only built
+    /// for debug and test builds.
+    ///
+    /// # Returns
+    /// Number of subscribers the result was delivered to
+    ///
+    /// # Errors
+    /// - Lock poisoning on the classification channel
+    #[cfg(any(test, debug_assertions))]
+    pub fn inject_classification(
+        &self,
+        result: ClassificationResult,
+    ) -> Result<usize, crate::error::AudioError> {
+        let tx = {
+            let mut guard =
+                self.classification
+                    .lock()
+                    .map_err(|_| crate::error::AudioError::LockPoisoned {
+                        component: "classification_broadcast".to_string(),
+                    })?;
+            guard
+                .get_or_insert_with(|| broadcast::channel(100).0)
+                .clone()
+        };
+        Ok(tx.send(result).unwrap_or(0))
+    }
+
     // ========================================================================
     // CALIBRATION CHANNEL
     // ========================================================================
@@ -210,8 +250,15 @@ impl BroadcastChannelManager {
     /// - Buffer size: 100 messages
     /// - Used for debug UI visualization only
     /// - Not part of critical audio path
-    ///
-    ///
+    /// - Reuses the existing channel so subscribers survive engine restarts
+    pub fn init_onset_events(&self) -> broadcast::Sender<OnsetEvent> {
+        self.onset_events
+            .lock()
+            .unwrap()
+            .get_or_insert_with(|| broadcast::channel(100).0)
+            .clone()
+    }
+
     /// Subscribe to onset events
     ///
     /// Returns a receiver for consuming onset events for debug visualization.
@@ -225,6 +272,39 @@ impl BroadcastChannelManager {
             .as_ref()
             .map(|tx| tx.subscribe())
     }
+    // ========================================================================
+    // CALIBRATION DEBUG CHANNEL (DEBUG)
+    // ========================================================================
+
+    /// Get the calibration debug frame sender
+    ///
+    /// Created eagerly at construction. Buffer size is 256 frames (~2.7s at
+    /// one frame per 512-sample buffer at 48kHz).
+    pub fn calibration_debug_sender(&self) -> broadcast::Sender<CalibrationDebugFrame> {
+        self.calibration_debug.clone()
+    }
+
+    /// Subscribe to calibration debug frames
+    pub fn subscribe_calibration_debug(&self) -> broadcast::Receiver<CalibrationDebugFrame> {
+        self.calibration_debug.subscribe()
+    }
+
+    // ========================================================================
+    // CALIBRATION PREVIEW CHANNEL
+    // ========================================================================
+
+    /// Get the calibration preview sender
+    ///
+    /// Created eagerly at construction, like the debug channel. Buffer size
+    /// is 100 results.
+    pub fn calibration_preview_sender(&self) -> broadcast::Sender<ClassificationResult> {
+        self.calibration_preview.clone()
+    }
+
+    /// Subscribe to provisional classifications made during calibration
+    pub fn subscribe_calibration_preview(&self) -> broadcast::Receiver<ClassificationResult> {
+        self.calibration_preview.subscribe()
+    }
 }
 
 impl Default for BroadcastChannelManager {
@@ -252,10 +332,27 @@ mod tests {
         assert!(rx.is_some());
     }
 
+    #[test]
+    fn test_injected_classification_reaches_subscriber() {
+        use crate::types::BeatboxHit;
+
+        let manager = BroadcastChannelManager::new();
+        // Without subscribers the injection is dropped, but it opens the channel
+        let scripted = ClassificationResult::new(BeatboxHit::Snare, None, 250, 0.9);
+        assert_eq!(manager.inject_classification(scripted.clone()), Ok(0));
+
+        let mut rx = manager.subscribe_classification().unwrap();
+        assert_eq!(manager.inject_classification(scripted), Ok(1));
+
+        let received = rx.try_recv().unwrap();
+        assert_eq!(received.sound, BeatboxHit::Snare);
+        assert_eq!(received.timestamp_ms, 250);
+    }
+
     #[test]
     fn test_classification_multiple_subscribers() {
-        use crate::analysis::classifier::BeatboxHit;
         use crate::analysis::quantizer::{TimingClassification, TimingFeedback};
+        use crate::types::BeatboxHit;
 
         let manager = BroadcastChannelManager::new();
         let tx = manager.init_classification();
@@ -267,12 +364,22 @@ mod tests {
         // Send message
         let result = ClassificationResult {
             sound: BeatboxHit::Kick,
-            timing: TimingFeedback {
+            timing: Some(TimingFeedback {
                 classification: TimingClassification::OnTime,
                 error_ms: 0.0,
-            },
+            }),
             timestamp_ms: 0,
             confidence: 0.95,
+            label: String::new(),
+            extra: false,
+            schema_version: crate::analysis::schema::CURRENT_RESULT_SCHEMA,
+            smoothed_confidence: None,
+            beat_index: None,
+            subdivision_index: None,
+            wall_clock_ms: 0,
+            trigger: crate::analysis::HitTrigger::Onset,
+            flam: false,
+            clipped: false,
         };
         tx.send(result.clone()).unwrap();
 
@@ -323,6 +430,10 @@ mod tests {
             waiting_for_confirmation: false,
             guidance: None,
             manual_accept_available: false,
+            auto_advanced: false,
+            phase_changed: false,
+            estimated_remaining_ms: None,
+            completed: None,
             debug: None,
         };
 
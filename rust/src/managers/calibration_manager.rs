@@ -3,10 +3,17 @@
 // Single Responsibility: Calibration procedure and state management
 // Extracted from AppContext to reduce complexity and improve testability
 
+use std::fs;
+use std::io::Write;
+use std::path::Path;
 use std::sync::{Arc, Mutex, RwLock};
 use tokio::sync::broadcast;
 
-use crate::calibration::{CalibrationProcedure, CalibrationProgress, CalibrationState};
+use crate::analysis::ClassificationResult;
+use crate::audio::ENGINE_SAMPLE_RATE;
+use crate::calibration::{
+    CalibrationDebugFrame, CalibrationProcedure, CalibrationProgress, CalibrationState,
+};
 use crate::config::CalibrationConfig;
 use crate::error::{log_calibration_error, CalibrationError};
 
@@ -34,6 +41,8 @@ pub struct CalibrationManager {
     procedure: Arc<Mutex<Option<CalibrationProcedure>>>,
     state: Arc<RwLock<CalibrationState>>,
     calibration_config: CalibrationConfig,
+    /// Progress stream of the running calibration, for the completion event
+    progress_tx: Mutex<Option<broadcast::Sender<CalibrationProgress>>>,
 }
 
 #[allow(dead_code)] // Methods will be used when integrated into AppContext (task 5.4)
@@ -46,6 +55,7 @@ impl CalibrationManager {
             procedure: Arc::new(Mutex::new(None)),
             state: Arc::new(RwLock::new(CalibrationState::new_default())),
             calibration_config,
+            progress_tx: Mutex::new(None),
         }
     }
 
@@ -58,7 +68,7 @@ impl CalibrationManager {
     /// Calibration sequence: KICK → SNARE → HI-HAT
     ///
     /// # Arguments
-    /// * `_broadcast_tx` - Broadcast channel for progress updates (reserved for future use)
+    /// * `broadcast_tx` - Progress channel; `finish` sends the completion event on it
     ///
     /// # Returns
     /// * `Ok(())` - Calibration started
@@ -69,7 +79,7 @@ impl CalibrationManager {
     /// - Lock poisoning on calibration procedure state
     pub fn start(
         &self,
-        _broadcast_tx: broadcast::Sender<CalibrationProgress>,
+        broadcast_tx: broadcast::Sender<CalibrationProgress>,
     ) -> Result<(), CalibrationError> {
         let mut procedure_guard = self.lock_procedure()?;
 
@@ -77,8 +87,16 @@ impl CalibrationManager {
 
         let samples_needed = self.samples_per_sound();
         let min_interval = self.calibration_config.min_sample_interval_ms;
-        let procedure = CalibrationProcedure::with_debounce(samples_needed, min_interval);
+        let procedure = CalibrationProcedure::with_debounce(samples_needed, min_interval)
+            .with_auto_advance(self.calibration_config.auto_advance)
+            .with_noise_floor_samples(self.calibration_config.noise_floor_samples)
+            .with_noise_floor_ceiling(self.calibration_config.noise_floor_ceiling)
+            .with_input_level_hits(self.calibration_config.input_level_hits)
+            .with_expected_ranges(self.calibration_config.expected_ranges.clone());
         *procedure_guard = Some(procedure);
+        if let Ok(mut progress_tx) = self.progress_tx.lock() {
+            *progress_tx = Some(broadcast_tx);
+        }
 
         Ok(())
     }
@@ -86,7 +104,9 @@ impl CalibrationManager {
     /// Finish calibration and compute thresholds
     ///
     /// Completes the calibration process, computes thresholds from collected samples,
-    /// and updates the calibration state used by the classifier.
+    /// and updates the calibration state used by the classifier. A final
+    /// progress update carrying the thresholds (`CalibrationProgress::completed`)
+    /// is sent on the stream passed to `start`.
     ///
     /// # Returns
     /// * `Ok(())` - Calibration completed successfully
@@ -105,12 +125,24 @@ impl CalibrationManager {
                 log_calibration_error(err, "finish_calibration");
             })?;
 
+            // Without a fresh input level measurement, keep the existing gain
+            let new_state = if procedure.measured_input_gain().is_some() {
+                new_state
+            } else {
+                let input_gain = self.read_state()?.input_gain;
+                new_state.with_input_gain(input_gain)
+            };
+
             eprintln!(
                 "[CalibrationManager] finish(): new_state.noise_floor_rms={}",
                 new_state.noise_floor_rms
             );
 
+            let completion = CalibrationProgress::completed(procedure.samples_needed(), &new_state);
             self.update_state(new_state)?;
+            if let Some(tx) = self.progress_tx.lock().ok().and_then(|mut tx| tx.take()) {
+                let _ = tx.send(completion);
+            }
 
             Ok(())
         } else {
@@ -166,6 +198,63 @@ impl CalibrationManager {
         Arc::clone(&self.procedure)
     }
 
+    /// Attach the high-rate debug stream to the running procedure
+    ///
+    /// Only attaches when `debug_stream` is enabled in the calibration config.
+    ///
+    /// # Returns
+    /// * `Ok(true)` - Stream attached
+    /// * `Ok(false)` - Disabled by config, or no calibration in progress
+    ///
+    /// # Errors
+    /// - Lock poisoning on calibration procedure state
+    pub fn attach_debug_stream(
+        &self,
+        tx: broadcast::Sender<CalibrationDebugFrame>,
+    ) -> Result<bool, CalibrationError> {
+        if !self.calibration_config.debug_stream {
+            return Ok(false);
+        }
+
+        let mut procedure_guard = self.lock_procedure()?;
+        match procedure_guard.as_mut() {
+            Some(procedure) => {
+                procedure.set_debug_stream(tx);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Attach the preview classification stream to the running procedure
+    ///
+    /// Only attaches when `preview_classification` is enabled in the
+    /// calibration config.
+    ///
+    /// # Returns
+    /// * `Ok(true)` - Stream attached
+    /// * `Ok(false)` - Disabled by config, or no calibration in progress
+    ///
+    /// # Errors
+    /// - Lock poisoning on calibration procedure state
+    pub fn attach_preview_stream(
+        &self,
+        tx: broadcast::Sender<ClassificationResult>,
+    ) -> Result<bool, CalibrationError> {
+        if !self.calibration_config.preview_classification {
+            return Ok(false);
+        }
+
+        let mut procedure_guard = self.lock_procedure()?;
+        match procedure_guard.as_mut() {
+            Some(procedure) => {
+                procedure.set_preview_stream(tx);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
     /// User confirms current calibration step is OK and wants to advance
     ///
     /// Called when user clicks "OK" after reviewing current sound samples.
@@ -239,6 +328,8 @@ impl CalibrationManager {
     ///
     /// Updates the calibration state with values loaded from storage.
     /// Typically called on app startup to restore previously calibrated thresholds.
+    /// Thresholds captured at a different sample rate (or stored in normalized
+    /// units) are rescaled to the engine rate before being applied.
     ///
     /// # Arguments
     /// * `state` - Calibration state to load
@@ -254,7 +345,91 @@ impl CalibrationManager {
             log_calibration_error(err, "load_calibration");
         })?;
 
-        *state_guard = state;
+        *state_guard = state.for_sample_rate(ENGINE_SAMPLE_RATE);
+        Ok(())
+    }
+
+    /// Save the current calibration state to a JSON file
+    ///
+    /// The JSON is written to a temporary file next to `path`, flushed to
+    /// disk, then renamed over `path`, so a crash mid-write leaves either the
+    /// previous file or the new one, never a truncated mix.
+    ///
+    /// # Errors
+    /// - Lock poisoning on calibration state
+    /// - The file cannot be written or renamed into place (`Io`)
+    pub fn save_calibration(&self, path: impl AsRef<Path>) -> Result<(), CalibrationError> {
+        let path = path.as_ref();
+        let state = self.get_state()?;
+        let json = serde_json::to_string_pretty(&state).map_err(|err| CalibrationError::Parse {
+            reason: format!("Failed to serialize calibration state to JSON: {err}"),
+        })?;
+
+        let mut tmp_name = path.file_name().unwrap_or_default().to_os_string();
+        tmp_name.push(".tmp");
+        let tmp_path = path.with_file_name(tmp_name);
+        let io_error = |action: &str, err: std::io::Error| CalibrationError::Io {
+            reason: format!("Failed to {action} {}: {err}", path.display()),
+        };
+
+        let write = || -> std::io::Result<()> {
+            let mut file = fs::File::create(&tmp_path)?;
+            file.write_all(json.as_bytes())?;
+            file.sync_all()
+        };
+        if let Err(err) = write() {
+            let _ = fs::remove_file(&tmp_path);
+            return Err(io_error("write calibration file", err));
+        }
+        fs::rename(&tmp_path, path).map_err(|err| {
+            let _ = fs::remove_file(&tmp_path);
+            io_error("replace calibration file", err)
+        })?;
+
+        tracing::info!(
+            "[CalibrationManager] Saved calibration to {}",
+            path.display()
+        );
+        Ok(())
+    }
+
+    /// Load calibration state from a JSON file written by `save_calibration`
+    ///
+    /// The loaded state replaces the active one, rescaled to the engine
+    /// sample rate like `load_state`.
+    ///
+    /// # Errors
+    /// - The file cannot be read (`Io`) or does not contain a calibration
+    ///   state (`Parse`)
+    /// - The state fails `CalibrationState::validate`
+    /// - Lock poisoning on calibration state
+    pub fn load_calibration_from_file(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> Result<(), CalibrationError> {
+        let path = path.as_ref();
+        let json = fs::read_to_string(path).map_err(|err| CalibrationError::Io {
+            reason: format!("Failed to read calibration file {}: {err}", path.display()),
+        })?;
+        let state: CalibrationState =
+            serde_json::from_str(&json).map_err(|err| CalibrationError::Parse {
+                reason: format!("Failed to parse calibration file {}: {err}", path.display()),
+            })?;
+        state
+            .validate()
+            .map_err(|problems| CalibrationError::InvalidFeatures {
+                reason: format!(
+                    "Implausible calibration file {}: {}",
+                    path.display(),
+                    problems.join("; ")
+                ),
+            })?;
+
+        self.load_state(state)?;
+        tracing::info!(
+            "[CalibrationManager] Loaded calibration from {}",
+            path.display()
+        );
         Ok(())
     }
 
@@ -429,6 +604,95 @@ mod tests {
         assert_eq!(loaded_state.t_kick_centroid, 2000.0);
     }
 
+    #[test]
+    fn test_save_and_load_calibration_file_round_trip() {
+        let dir = std::env::temp_dir().join(format!("bbt-calibration-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("calibration.json");
+
+        let manager = create_manager();
+        let mut state = CalibrationState::new_default();
+        state.is_calibrated = true;
+        state.t_kick_centroid = 1234.0;
+        state.noise_floor_rms = 0.004;
+        manager.load_state(state).unwrap();
+        manager.save_calibration(&path).unwrap();
+        // Only the final file remains after the atomic rename
+        assert!(path.exists());
+        assert!(!dir.join("calibration.json.tmp").exists());
+
+        let reloaded = create_manager();
+        let result = reloaded.load_calibration_from_file(&path);
+        let _ = fs::remove_dir_all(&dir);
+        result.unwrap();
+
+        let loaded = reloaded.get_state().unwrap();
+        assert!(loaded.is_calibrated);
+        assert_eq!(loaded.t_kick_centroid, 1234.0);
+        assert_eq!(loaded.noise_floor_rms, 0.004);
+
+        assert!(matches!(
+            reloaded.load_calibration_from_file(dir.join("missing.json")),
+            Err(CalibrationError::Io { .. })
+        ));
+    }
+
+    #[test]
+    fn test_load_calibration_file_rejects_malformed_and_implausible_state() {
+        let dir = std::env::temp_dir().join(format!("bbt-calibration-bad-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let garbage = dir.join("garbage.json");
+        fs::write(&garbage, "{ not json").unwrap();
+
+        let mut state = CalibrationState::new_default();
+        state.is_calibrated = true;
+        state.t_kick_centroid = -100.0;
+        let implausible = dir.join("implausible.json");
+        fs::write(&implausible, serde_json::to_string(&state).unwrap()).unwrap();
+
+        let manager = create_manager();
+        let parse_result = manager.load_calibration_from_file(&garbage);
+        let validate_result = manager.load_calibration_from_file(&implausible);
+        let _ = fs::remove_dir_all(&dir);
+
+        assert!(matches!(parse_result, Err(CalibrationError::Parse { .. })));
+        assert!(matches!(
+            validate_result,
+            Err(CalibrationError::InvalidFeatures { .. })
+        ));
+        // A rejected file leaves the active calibration untouched
+        assert!(!manager.get_state().unwrap().is_calibrated);
+    }
+
+    #[test]
+    fn test_load_state_from_other_sample_rate_classifies_correctly() {
+        use crate::analysis::classifier::Classifier;
+        use crate::analysis::features::FeatureExtractor;
+        use crate::types::BeatboxHit;
+
+        let manager = create_manager();
+
+        // Calibrated at 44.1 kHz: hi-hats cross zero more than 8820 times/sec
+        let mut state = CalibrationState::new_default();
+        state.is_calibrated = true;
+        state.sample_rate = 44_100;
+        state.t_snare_centroid = 4000.0;
+        state.t_hihat_zcr = 0.2;
+        manager.load_state(state).unwrap();
+
+        // 4.5 kHz tone (9000 crossings/sec) captured at the 48 kHz engine rate.
+        // Its per-sample ZCR (0.1875) is below the raw 44.1 kHz threshold.
+        let signal: Vec<f32> = (0..1024)
+            .map(|i| (2.0 * std::f32::consts::PI * 4500.0 * i as f32 / 48_000.0).sin())
+            .collect();
+        let features = FeatureExtractor::new(48_000).unwrap().extract(&signal);
+        assert!(features.zcr < 0.2);
+
+        let classifier = Classifier::new(manager.get_state_arc());
+        let (sound, _) = classifier.classify_level1(&features);
+        assert_eq!(sound, BeatboxHit::HiHat);
+    }
+
     #[test]
     fn test_state_persistence_across_operations() {
         let manager = create_manager();
@@ -483,11 +747,76 @@ mod tests {
         assert!(procedure_guard.is_none());
     }
 
+    /// Run a started calibration through noise floor, kick, snare and hi-hat
+    fn collect_full_calibration(manager: &CalibrationManager) {
+        use crate::analysis::features::Features;
+
+        let mut procedure_guard = manager.lock_procedure().unwrap();
+        let procedure = procedure_guard.as_mut().unwrap();
+
+        // Add noise floor samples
+        let noise_rms = 0.003;
+        for _ in 0..30 {
+            procedure.add_noise_floor_sample(noise_rms).unwrap();
+        }
+        // Confirm noise floor
+        procedure.confirm_and_advance().unwrap();
+
+        // Add kick, snare, hihat samples
+        let features = Features {
+            centroid: 1000.0,
+            zcr: 0.1,
+            flatness: 0.5,
+            rolloff: 5000.0,
+            decay_time_ms: 50.0,
+        };
+
+        for _ in 0..10 {
+            procedure.add_sample(features, 0.1, 0.5).unwrap();
+        }
+        procedure.confirm_and_advance().unwrap();
+
+        for _ in 0..10 {
+            procedure
+                .add_sample(
+                    Features {
+                        centroid: 3000.0,
+                        zcr: 0.2,
+                        ..features
+                    },
+                    0.1,
+                    0.5,
+                )
+                .unwrap();
+        }
+        procedure.confirm_and_advance().unwrap();
+
+        for _ in 0..10 {
+            procedure
+                .add_sample(
+                    Features {
+                        centroid: 8000.0,
+                        zcr: 0.5,
+                        ..features
+                    },
+                    0.1,
+                    0.5,
+                )
+                .unwrap();
+        }
+        procedure.confirm_and_advance().unwrap();
+
+        // Verify noise_floor_threshold is set before finish
+        assert!(procedure.noise_floor_threshold().is_some());
+        eprintln!(
+            "Procedure noise_floor_threshold before finish: {:?}",
+            procedure.noise_floor_threshold()
+        );
+    }
+
     /// Test that finish() correctly persists noise_floor_rms through get_state()
     #[test]
     fn test_finish_persists_noise_floor_rms() {
-        use crate::analysis::features::Features;
-
         // Use custom config with minimal samples for faster test
         let config = CalibrationConfig {
             samples_per_sound: 10,
@@ -500,70 +829,7 @@ mod tests {
         // Start calibration
         manager.start(broadcast_tx).unwrap();
 
-        // Access procedure Arc and complete full calibration
-        {
-            let mut procedure_guard = manager.lock_procedure().unwrap();
-            let procedure = procedure_guard.as_mut().unwrap();
-
-            // Add noise floor samples
-            let noise_rms = 0.003;
-            for _ in 0..30 {
-                procedure.add_noise_floor_sample(noise_rms).unwrap();
-            }
-            // Confirm noise floor
-            procedure.confirm_and_advance().unwrap();
-
-            // Add kick, snare, hihat samples
-            let features = Features {
-                centroid: 1000.0,
-                zcr: 0.1,
-                flatness: 0.5,
-                rolloff: 5000.0,
-                decay_time_ms: 50.0,
-            };
-
-            for _ in 0..10 {
-                procedure.add_sample(features, 0.1, 0.5).unwrap();
-            }
-            procedure.confirm_and_advance().unwrap();
-
-            for _ in 0..10 {
-                procedure
-                    .add_sample(
-                        Features {
-                            centroid: 3000.0,
-                            zcr: 0.2,
-                            ..features
-                        },
-                        0.1,
-                        0.5,
-                    )
-                    .unwrap();
-            }
-            procedure.confirm_and_advance().unwrap();
-
-            for _ in 0..10 {
-                procedure
-                    .add_sample(
-                        Features {
-                            centroid: 8000.0,
-                            zcr: 0.5,
-                            ..features
-                        },
-                        0.1,
-                        0.5,
-                    )
-                    .unwrap();
-            }
-            procedure.confirm_and_advance().unwrap();
-
-            // Verify noise_floor_threshold is set before finish
-            assert!(procedure.noise_floor_threshold().is_some());
-            eprintln!(
-                "Procedure noise_floor_threshold before finish: {:?}",
-                procedure.noise_floor_threshold()
-            );
-        }
+        collect_full_calibration(&manager);
 
         // Finish calibration - this should persist the state
         let result = manager.finish();
@@ -589,4 +855,31 @@ mod tests {
         );
         assert!(state.is_calibrated, "state should be marked as calibrated");
     }
+
+    #[test]
+    fn test_finish_emits_completion_with_thresholds() {
+        let config = CalibrationConfig {
+            samples_per_sound: 10,
+            min_sample_interval_ms: 0,
+            ..Default::default()
+        };
+        let manager = CalibrationManager::new(config);
+        let (broadcast_tx, mut progress_rx) = broadcast::channel(100);
+        manager.start(broadcast_tx).unwrap();
+        collect_full_calibration(&manager);
+
+        manager.finish().unwrap();
+
+        let progress = progress_rx.try_recv().expect("completion event");
+        let summary = progress.completed.expect("completion summary");
+        let state = manager.get_state().unwrap();
+        assert_eq!(summary.t_kick_centroid, state.t_kick_centroid);
+        assert_eq!(summary.t_kick_zcr, state.t_kick_zcr);
+        assert_eq!(summary.t_snare_centroid, state.t_snare_centroid);
+        assert_eq!(summary.t_hihat_zcr, state.t_hihat_zcr);
+        assert_eq!(summary.noise_floor_rms, state.noise_floor_rms);
+        // Every sample of a sound is identical, so the sounds separate fully
+        assert_eq!(summary.quality_score, Some(1.0));
+        assert_eq!(progress.samples_collected, progress.samples_needed);
+    }
 }
@@ -3,7 +3,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::analysis::classifier::BeatboxHit;
+use crate::types::BeatboxHit;
 
 /// High-level lifecycle stages reported by JNI/engine instrumentation.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
@@ -24,6 +24,10 @@ pub enum DiagnosticError {
     BufferDrain,
     StreamBackpressure,
     Unknown,
+    /// The OS refused elevated scheduling priority for the analysis thread
+    ThreadPriorityDenied,
+    /// NaN/Inf samples reached the analysis thread; the window was skipped
+    InvalidAudio,
 }
 
 /// Rich metric events covering latency, buffer occupancy, and lifecycle details.
@@ -52,4 +56,42 @@ pub enum MetricEvent {
         code: DiagnosticError,
         context: String,
     },
+    /// Time from a hit's detection to its result being broadcast (analysis
+    /// thread cost, unlike the timing error tracked by `Latency`)
+    ProcessingLatency {
+        latency_ms: f32,
+    },
+    /// The input has stayed below the classification gate for `quiet_ms`
+    /// (sent once per quiet stretch, see `silence_timeout_ms`)
+    Silence {
+        quiet_ms: f32,
+    },
+}
+
+/// Payload-free discriminant of [`MetricEvent`], used to filter subscriptions.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum MetricEventKind {
+    Latency,
+    BufferOccupancy,
+    Classification,
+    JniLifecycle,
+    Error,
+    ProcessingLatency,
+    Silence,
+}
+
+impl MetricEvent {
+    /// Variant of this event without its payload
+    pub fn kind(&self) -> MetricEventKind {
+        match self {
+            MetricEvent::Latency { .. } => MetricEventKind::Latency,
+            MetricEvent::BufferOccupancy { .. } => MetricEventKind::BufferOccupancy,
+            MetricEvent::Classification { .. } => MetricEventKind::Classification,
+            MetricEvent::JniLifecycle { .. } => MetricEventKind::JniLifecycle,
+            MetricEvent::Error { .. } => MetricEventKind::Error,
+            MetricEvent::ProcessingLatency { .. } => MetricEventKind::ProcessingLatency,
+            MetricEvent::Silence { .. } => MetricEventKind::Silence,
+        }
+    }
 }
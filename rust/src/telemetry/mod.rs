@@ -6,16 +6,17 @@
 use std::collections::{HashMap, VecDeque};
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Mutex;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use once_cell::sync::Lazy;
 use tokio::sync::{broadcast, mpsc};
 
+use crate::analysis::quantizer::TimingClassification;
 use crate::analysis::ClassificationResult;
 
 pub mod events;
 
-pub use events::{DiagnosticError, LifecyclePhase, MetricEvent};
+pub use events::{DiagnosticError, LifecyclePhase, MetricEvent, MetricEventKind};
 
 /// Global telemetry hub shared across the crate.
 static HUB: Lazy<TelemetryHub> = Lazy::new(TelemetryHub::default);
@@ -31,6 +32,12 @@ pub struct TelemetrySnapshot {
     pub recent: Vec<MetricEvent>,
     pub total_events: u64,
     pub dropped_events: u64,
+    /// Classification results dropped by the global emission rate cap
+    #[serde(default)]
+    pub suppressed_results: u64,
+    /// Onsets dropped by the per-buffer onset cap
+    #[serde(default)]
+    pub dropped_onsets: u64,
 }
 
 /// Broadcast-based collector retaining a bounded history of metrics.
@@ -73,11 +80,30 @@ impl TelemetryCollector {
     }
 
     pub fn subscribe_unbounded(&self) -> mpsc::UnboundedReceiver<MetricEvent> {
+        self.forward_unbounded(|_| true)
+    }
+
+    /// Like [`subscribe_unbounded`](Self::subscribe_unbounded), but only
+    /// events whose kind is listed in `kinds` are forwarded.
+    pub fn subscribe_filtered(
+        &self,
+        kinds: Vec<MetricEventKind>,
+    ) -> mpsc::UnboundedReceiver<MetricEvent> {
+        self.forward_unbounded(move |event| kinds.contains(&event.kind()))
+    }
+
+    fn forward_unbounded(
+        &self,
+        filter: impl Fn(&MetricEvent) -> bool + Send + 'static,
+    ) -> mpsc::UnboundedReceiver<MetricEvent> {
         let (tx, rx) = mpsc::unbounded_channel();
         let mut broadcast_rx = self.tx.subscribe();
 
         tokio::spawn(async move {
             while let Ok(event) = broadcast_rx.recv().await {
+                if !filter(&event) {
+                    continue;
+                }
                 if tx.send(event).is_err() {
                     break;
                 }
@@ -93,8 +119,19 @@ impl TelemetryCollector {
             recent: history.iter().cloned().collect(),
             total_events: self.total_events.load(Ordering::Relaxed),
             dropped_events: self.dropped_history.load(Ordering::Relaxed),
+            suppressed_results: 0,
+            dropped_onsets: 0,
         }
     }
+
+    /// Drop the history and zero the event counters.
+    ///
+    /// Subscribers stay connected and keep receiving new events.
+    pub fn reset(&self) {
+        self.history.lock().expect("history poisoned").clear();
+        self.total_events.store(0, Ordering::Relaxed);
+        self.dropped_history.store(0, Ordering::Relaxed);
+    }
 }
 
 impl Default for TelemetryCollector {
@@ -117,6 +154,10 @@ impl LatencyTracker {
         }
     }
 
+    fn clear(&mut self) {
+        self.samples.clear();
+    }
+
     fn observe(&mut self, value: f32) -> (f32, f32, usize) {
         if self.samples.len() == self.max_samples {
             self.samples.pop_front();
@@ -135,11 +176,60 @@ impl LatencyTracker {
     }
 }
 
+/// Streak tracker counts consecutive on-time hits and remembers the best run.
+#[derive(Default)]
+struct StreakTracker {
+    current: u32,
+    best: u32,
+}
+
+impl StreakTracker {
+    fn observe(&mut self, timing: &TimingClassification) {
+        match timing {
+            TimingClassification::OnTime => {
+                self.current += 1;
+                self.best = self.best.max(self.current);
+            }
+            TimingClassification::Early | TimingClassification::Late => self.current = 0,
+        }
+    }
+}
+
+/// Default change (percentage points) before a buffer gauge re-emits.
+pub const DEFAULT_BUFFER_GAUGE_DEBOUNCE: f32 = 2.5;
+
+/// Last emitted occupancy per channel and the change needed to re-emit.
+struct BufferGauges {
+    last: HashMap<&'static str, f32>,
+    debounce: f32,
+    channel_debounce: HashMap<&'static str, f32>,
+}
+
+impl BufferGauges {
+    fn new(debounce: f32) -> Self {
+        Self {
+            last: HashMap::new(),
+            debounce,
+            channel_debounce: HashMap::new(),
+        }
+    }
+
+    fn debounce_for(&self, channel: &str) -> f32 {
+        self.channel_debounce
+            .get(channel)
+            .copied()
+            .unwrap_or(self.debounce)
+    }
+}
+
 /// Top-level hub wrapping collector state plus derived gauges.
 pub struct TelemetryHub {
     collector: TelemetryCollector,
     latency: Mutex<LatencyTracker>,
-    buffer_gauges: Mutex<HashMap<&'static str, f32>>,
+    streak: Mutex<StreakTracker>,
+    buffer_gauges: Mutex<BufferGauges>,
+    suppressed_results: AtomicU64,
+    dropped_onsets: AtomicU64,
 }
 
 impl TelemetryHub {
@@ -147,28 +237,114 @@ impl TelemetryHub {
         Self {
             collector: TelemetryCollector::new(channel_capacity, history_capacity),
             latency: Mutex::new(LatencyTracker::new(latency_window)),
-            buffer_gauges: Mutex::new(HashMap::new()),
+            streak: Mutex::new(StreakTracker::default()),
+            buffer_gauges: Mutex::new(BufferGauges::new(DEFAULT_BUFFER_GAUGE_DEBOUNCE)),
+            suppressed_results: AtomicU64::new(0),
+            dropped_onsets: AtomicU64::new(0),
         }
     }
 
+    /// Use `percent` points as the buffer gauge debounce for every channel.
+    pub fn with_buffer_gauge_debounce(self, percent: f32) -> Self {
+        self.set_buffer_gauge_debounce(percent);
+        self
+    }
+
+    /// Change the default buffer gauge debounce (percentage points).
+    ///
+    /// A gauge only re-emits once occupancy moves at least this far from the
+    /// last emitted value; 0 emits every change. Per-channel overrides set
+    /// with [`Self::set_channel_gauge_debounce`] take precedence.
+    pub fn set_buffer_gauge_debounce(&self, percent: f32) {
+        self.buffer_gauges
+            .lock()
+            .expect("buffer gauge lock poisoned")
+            .debounce = sanitize_debounce(percent);
+    }
+
+    /// Override the buffer gauge debounce for one channel.
+    pub fn set_channel_gauge_debounce(&self, channel: &'static str, percent: f32) {
+        self.buffer_gauges
+            .lock()
+            .expect("buffer gauge lock poisoned")
+            .channel_debounce
+            .insert(channel, sanitize_debounce(percent));
+    }
+
     pub fn collector(&self) -> &TelemetryCollector {
         &self.collector
     }
 
     pub fn snapshot(&self) -> TelemetrySnapshot {
-        self.collector.snapshot()
+        TelemetrySnapshot {
+            suppressed_results: self.suppressed_results(),
+            dropped_onsets: self.dropped_onsets(),
+            ..self.collector.snapshot()
+        }
+    }
+
+    /// Zero every counter and clear all history for a clean measurement.
+    ///
+    /// Clears the event history, total/dropped event counts, the latency
+    /// window, streaks, suppressed results, dropped onsets and the last emitted buffer
+    /// gauges (so each channel re-emits on its next reading). Debounce
+    /// settings are kept.
+    pub fn reset(&self) {
+        self.collector.reset();
+        self.latency
+            .lock()
+            .expect("latency tracker poisoned")
+            .clear();
+        *self.streak.lock().unwrap_or_else(|e| e.into_inner()) = StreakTracker::default();
+        self.buffer_gauges
+            .lock()
+            .expect("buffer gauge lock poisoned")
+            .last
+            .clear();
+        self.suppressed_results.store(0, Ordering::Relaxed);
+        self.dropped_onsets.store(0, Ordering::Relaxed);
+    }
+
+    /// Count a classification result dropped by the emission rate cap.
+    pub fn record_suppressed_result(&self) {
+        self.suppressed_results.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Total classification results dropped by the emission rate cap.
+    pub fn suppressed_results(&self) -> u64 {
+        self.suppressed_results.load(Ordering::Relaxed)
+    }
+
+    /// Count onsets dropped by the per-buffer onset cap.
+    pub fn record_dropped_onsets(&self, count: u64) {
+        self.dropped_onsets.fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// Total onsets dropped by the per-buffer onset cap.
+    pub fn dropped_onsets(&self) -> u64 {
+        self.dropped_onsets.load(Ordering::Relaxed)
     }
 
     pub fn record_classification(&self, result: &ClassificationResult) {
         self.collector.publish(MetricEvent::Classification {
             sound: result.sound,
             confidence: result.confidence,
-            timing_error_ms: result.timing.error_ms,
+            timing_error_ms: result.timing.map_or(0.0, |timing| timing.error_ms),
         });
 
+        // Untimed (classification-only) results carry nothing to score
+        let Some(timing) = result.timing else {
+            return;
+        };
+
+        self.streak
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .observe(&timing.classification);
+
         let (avg, max, count) = {
             let mut tracker = self.latency.lock().expect("latency tracker poisoned");
-            tracker.observe(result.timing.error_ms.abs())
+            tracker.observe(timing.error_ms.abs())
         };
 
         self.collector.publish(MetricEvent::Latency {
@@ -178,6 +354,12 @@ impl TelemetryHub {
         });
     }
 
+    /// Current and best run of consecutive on-time classifications.
+    pub fn get_streak(&self) -> (u32, u32) {
+        let streak = self.streak.lock().unwrap_or_else(|e| e.into_inner());
+        (streak.current, streak.best)
+    }
+
     pub fn record_buffer_occupancy(&self, channel: &'static str, percent: f32) {
         let normalized = percent.clamp(0.0, 100.0);
         let mut gauges = self
@@ -185,13 +367,15 @@ impl TelemetryHub {
             .lock()
             .expect("buffer gauge lock poisoned");
 
+        let debounce = gauges.debounce_for(channel);
         let should_emit = gauges
+            .last
             .get(channel)
-            .map(|last| (last - normalized).abs() >= 2.5)
+            .map(|last| (last - normalized).abs() >= debounce)
             .unwrap_or(true);
 
         if should_emit {
-            gauges.insert(channel, normalized);
+            gauges.last.insert(channel, normalized);
             self.collector.publish(MetricEvent::BufferOccupancy {
                 channel: channel.to_string(),
                 percent: normalized,
@@ -206,6 +390,20 @@ impl TelemetryHub {
         });
     }
 
+    /// Publish how long a result took from detection to broadcast.
+    pub fn record_processing_latency(&self, latency: Duration) {
+        self.collector.publish(MetricEvent::ProcessingLatency {
+            latency_ms: latency.as_secs_f32() * 1000.0,
+        });
+    }
+
+    /// Publish that the input has been quiet for `quiet` without a break.
+    pub fn record_silence(&self, quiet: Duration) {
+        self.collector.publish(MetricEvent::Silence {
+            quiet_ms: quiet.as_secs_f32() * 1000.0,
+        });
+    }
+
     pub fn record_error(&self, code: DiagnosticError, context: impl Into<String>) {
         self.collector.publish(MetricEvent::Error {
             code,
@@ -220,7 +418,16 @@ impl Default for TelemetryHub {
     }
 }
 
-fn now_timestamp_ms() -> u64 {
+fn sanitize_debounce(percent: f32) -> f32 {
+    if percent.is_finite() {
+        percent.clamp(0.0, 100.0)
+    } else {
+        DEFAULT_BUFFER_GAUGE_DEBOUNCE
+    }
+}
+
+/// Wall-clock time in milliseconds since the Unix epoch
+pub(crate) fn now_timestamp_ms() -> u64 {
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .unwrap_or_default()
@@ -230,18 +437,36 @@ fn now_timestamp_ms() -> u64 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::analysis::classifier::BeatboxHit;
-    use crate::analysis::quantizer::{TimingClassification, TimingFeedback};
+    use crate::analysis::quantizer::TimingFeedback;
+    use crate::types::BeatboxHit;
 
     fn sample_result(confidence: f32, error_ms: f32) -> ClassificationResult {
+        timed_result(TimingClassification::OnTime, confidence, error_ms)
+    }
+
+    fn timed_result(
+        classification: TimingClassification,
+        confidence: f32,
+        error_ms: f32,
+    ) -> ClassificationResult {
         ClassificationResult {
             sound: BeatboxHit::Kick,
-            timing: TimingFeedback {
-                classification: TimingClassification::OnTime,
+            timing: Some(TimingFeedback {
+                classification,
                 error_ms,
-            },
+            }),
             timestamp_ms: 42,
             confidence,
+            label: String::new(),
+            extra: false,
+            schema_version: crate::analysis::schema::CURRENT_RESULT_SCHEMA,
+            smoothed_confidence: None,
+            beat_index: None,
+            subdivision_index: None,
+            wall_clock_ms: 0,
+            trigger: crate::analysis::HitTrigger::Onset,
+            flam: false,
+            clipped: false,
         }
     }
 
@@ -274,6 +499,31 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn filtered_subscription_forwards_only_listed_kinds() {
+        let collector = TelemetryCollector::new(8, 4);
+        let mut rx = collector.subscribe_filtered(vec![MetricEventKind::Latency]);
+
+        collector.publish(MetricEvent::Classification {
+            sound: BeatboxHit::Kick,
+            confidence: 0.9,
+            timing_error_ms: 1.0,
+        });
+        collector.publish(MetricEvent::Latency {
+            avg_ms: 2.0,
+            max_ms: 3.0,
+            sample_count: 1,
+        });
+
+        let event = rx.recv().await.expect("latency event forwarded");
+        assert_eq!(event.kind(), MetricEventKind::Latency);
+        tokio::task::yield_now().await;
+        assert!(
+            rx.try_recv().is_err(),
+            "classification must be filtered out"
+        );
+    }
+
     #[test]
     fn collector_drops_history_when_full() {
         let collector = TelemetryCollector::new(8, 2);
@@ -336,4 +586,81 @@ mod tests {
                 >= 2
         );
     }
+
+    #[test]
+    fn smaller_gauge_debounce_emits_more_often() {
+        let series = [10.0, 10.5, 11.0, 12.0, 13.0, 15.0, 14.5];
+        let emitted = |hub: &TelemetryHub| {
+            for percent in series {
+                hub.record_buffer_occupancy("queue", percent);
+            }
+            hub.snapshot()
+                .recent
+                .iter()
+                .filter(|event| matches!(event, MetricEvent::BufferOccupancy { .. }))
+                .count()
+        };
+
+        let coarse = TelemetryHub::new(16, 16, 4);
+        let fine = TelemetryHub::new(16, 16, 4).with_buffer_gauge_debounce(0.5);
+        // Only 10 and 13 clear 2.5 points; every step clears 0.5
+        assert_eq!(emitted(&coarse), 2);
+        assert_eq!(emitted(&fine), 7);
+
+        // A per-channel override beats the hub-wide value
+        let mixed = TelemetryHub::new(16, 16, 4).with_buffer_gauge_debounce(0.5);
+        mixed.set_channel_gauge_debounce("queue", 10.0);
+        assert_eq!(emitted(&mixed), 1);
+    }
+
+    #[test]
+    fn reset_clears_history_and_counters() {
+        let hub = TelemetryHub::new(16, 4, 8);
+        for _ in 0..3 {
+            hub.record_classification(&sample_result(0.9, 2.0));
+        }
+        hub.record_buffer_occupancy("queue", 40.0);
+        hub.record_suppressed_result();
+        let before = hub.snapshot();
+        assert!(before.total_events > 0 && before.dropped_events > 0);
+
+        hub.reset();
+        let snapshot = hub.snapshot();
+        assert!(snapshot.recent.is_empty());
+        assert_eq!(snapshot.total_events, 0);
+        assert_eq!(snapshot.dropped_events, 0);
+        assert_eq!(snapshot.suppressed_results, 0);
+        assert_eq!(snapshot.dropped_onsets, 0);
+        assert_eq!(hub.get_streak(), (0, 0));
+
+        // The latency window and gauges start over too
+        hub.record_classification(&sample_result(0.9, 2.0));
+        hub.record_buffer_occupancy("queue", 40.0);
+        let recent = hub.snapshot().recent;
+        assert!(recent.iter().any(|event| matches!(
+            event,
+            MetricEvent::Latency {
+                sample_count: 1,
+                ..
+            }
+        )));
+        assert!(recent
+            .iter()
+            .any(|event| matches!(event, MetricEvent::BufferOccupancy { .. })));
+    }
+
+    #[test]
+    fn streak_builds_on_time_and_resets_on_late() {
+        let hub = TelemetryHub::new(8, 8, 4);
+        for _ in 0..3 {
+            hub.record_classification(&sample_result(0.9, 2.0));
+        }
+        assert_eq!(hub.get_streak(), (3, 3));
+
+        hub.record_classification(&timed_result(TimingClassification::Late, 0.9, 80.0));
+        assert_eq!(hub.get_streak(), (0, 3));
+
+        hub.record_classification(&sample_result(0.9, 1.0));
+        assert_eq!(hub.get_streak(), (1, 3));
+    }
 }
@@ -0,0 +1,144 @@
+//! Offline throughput harness for the DSP pipeline.
+//!
+//! Runs the hot-path stages (`FeatureExtractor::extract`,
+//! `OnsetDetector::process`, `Classifier::classify_level1`) over a fixed
+//! synthetic buffer and reports the mean wall-clock cost per call. The output
+//! is plain serde data so CI can archive it and flag throughput regressions.
+//!
+//! Gated behind the `dsp_bench` feature; run it via
+//! `cargo run --release --features dsp_bench --bin beatbox_cli -- bench`.
+
+use std::hint::black_box;
+use std::sync::{Arc, RwLock};
+use std::time::Instant;
+
+use serde::Serialize;
+
+use crate::analysis::classifier::Classifier;
+use crate::analysis::features::fft::FFT_SIZE;
+use crate::analysis::features::{FeatureExtractor, FftError};
+use crate::analysis::onset::OnsetDetector;
+use crate::audio::ENGINE_SAMPLE_RATE;
+use crate::calibration::CalibrationState;
+
+/// Default number of timed iterations per stage.
+pub const DEFAULT_BENCH_ITERATIONS: u32 = 1_000;
+
+/// Size of the synthetic buffer fed to the onset detector (matches the
+/// analysis thread's default accumulator size).
+const ONSET_BUFFER_SIZE: usize = 512;
+
+/// Timing result for a single pipeline stage.
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchResult {
+    pub stage: &'static str,
+    pub iterations: u32,
+    pub total_ns: u128,
+    pub ns_per_iter: u128,
+}
+
+/// Timed harness over a deterministic synthetic buffer.
+pub struct DspBench {
+    iterations: u32,
+    buffer: Vec<f32>,
+}
+
+impl DspBench {
+    /// Create a harness running each stage `iterations` times (min 1).
+    pub fn new(iterations: u32) -> Self {
+        Self {
+            iterations: iterations.max(1),
+            buffer: synthetic_hit(FFT_SIZE.max(ONSET_BUFFER_SIZE)),
+        }
+    }
+
+    /// Run all stages and return one result per stage, in pipeline order.
+    ///
+    /// # Errors
+    /// Returns [`FftError`] when the feature extractor cannot be built.
+    pub fn run(&self) -> Result<Vec<BenchResult>, FftError> {
+        let extractor = FeatureExtractor::new(ENGINE_SAMPLE_RATE)?;
+        Ok(vec![
+            self.bench_onset_detection(),
+            self.bench_feature_extraction(&extractor),
+            self.bench_classification(&extractor),
+        ])
+    }
+
+    fn bench_onset_detection(&self) -> BenchResult {
+        let mut detector = OnsetDetector::new(ENGINE_SAMPLE_RATE);
+        let buffer = &self.buffer[..ONSET_BUFFER_SIZE];
+        self.time("onset_detector_process", || {
+            black_box(detector.process(black_box(buffer)));
+        })
+    }
+
+    fn bench_feature_extraction(&self, extractor: &FeatureExtractor) -> BenchResult {
+        self.time("feature_extractor_extract", || {
+            black_box(extractor.extract(black_box(&self.buffer)));
+        })
+    }
+
+    fn bench_classification(&self, extractor: &FeatureExtractor) -> BenchResult {
+        let features = extractor.extract(&self.buffer);
+        let classifier = Classifier::new(Arc::new(RwLock::new(CalibrationState::new_default())));
+        self.time("classifier_classify_level1", || {
+            black_box(classifier.classify_level1(black_box(&features)));
+        })
+    }
+
+    fn time(&self, stage: &'static str, mut body: impl FnMut()) -> BenchResult {
+        // Warm up caches and the FFT planner before timing
+        body();
+
+        let start = Instant::now();
+        for _ in 0..self.iterations {
+            body();
+        }
+        let total_ns = start.elapsed().as_nanos();
+
+        BenchResult {
+            stage,
+            iterations: self.iterations,
+            total_ns,
+            ns_per_iter: total_ns / self.iterations as u128,
+        }
+    }
+}
+
+impl Default for DspBench {
+    fn default() -> Self {
+        Self::new(DEFAULT_BENCH_ITERATIONS)
+    }
+}
+
+/// Decaying 200 Hz tone with a sharp attack, loosely resembling a kick.
+fn synthetic_hit(len: usize) -> Vec<f32> {
+    (0..len)
+        .map(|i| {
+            let t = i as f32 / ENGINE_SAMPLE_RATE as f32;
+            (-t * 40.0).exp() * (2.0 * std::f32::consts::PI * 200.0 * t).sin()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn harness_runs_every_stage() {
+        let results = DspBench::new(3).run().unwrap();
+
+        let stages: Vec<_> = results.iter().map(|r| r.stage).collect();
+        assert_eq!(
+            stages,
+            [
+                "onset_detector_process",
+                "feature_extractor_extract",
+                "classifier_classify_level1"
+            ]
+        );
+        assert!(results.iter().all(|r| r.iterations == 3));
+    }
+}
@@ -86,12 +86,14 @@ cfg_if::cfg_if! {
                     cal_progress_tx,
                     Arc::clone(&frame_counter),
                     Arc::clone(&bpm),
+                    config.audio.click.beats_per_bar,
                     ENGINE_SAMPLE_RATE,
                     classification_tx,
-                    config.onset_detection.clone(),
+                    config.analysis(),
                     config.calibration.log_every_n_buffers,
                     Some(Arc::clone(&running)),
                     None, // audio_metrics_tx - not needed for fixture tests
+                    None, // onset_events_tx
                 );
 
                 let feeder_handle = spawn_feeder_thread(
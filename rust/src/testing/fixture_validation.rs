@@ -2,9 +2,9 @@ use std::collections::HashMap;
 
 use serde::Serialize;
 
-use crate::analysis::classifier::BeatboxHit;
 use crate::analysis::ClassificationResult;
 use crate::testing::fixture_manifest::FixtureManifestEntry;
+use crate::types::BeatboxHit;
 
 /// Aggregated classification statistics for a fixture session.
 #[derive(Debug, Clone, Serialize, Default)]
@@ -243,12 +243,22 @@ mod tests {
     fn sample_result(sound: BeatboxHit, timestamp_ms: u64) -> ClassificationResult {
         ClassificationResult {
             sound,
-            timing: TimingFeedback {
+            timing: Some(TimingFeedback {
                 classification: TimingClassification::OnTime,
                 error_ms: 0.0,
-            },
+            }),
             timestamp_ms,
             confidence: 0.9,
+            label: String::new(),
+            extra: false,
+            schema_version: crate::analysis::schema::CURRENT_RESULT_SCHEMA,
+            smoothed_confidence: None,
+            beat_index: None,
+            subdivision_index: None,
+            wall_clock_ms: 0,
+            trigger: crate::analysis::HitTrigger::Onset,
+            flam: false,
+            clipped: false,
         }
     }
 
@@ -5,6 +5,8 @@
 //! build stays lean while still allowing richly instrumented harnesses during
 //! development.
 
+#[cfg(any(test, feature = "dsp_bench"))]
+pub mod dsp_bench;
 pub mod fixture_engine;
 pub mod fixture_manifest;
 pub mod fixture_validation;
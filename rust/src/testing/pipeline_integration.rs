@@ -142,7 +142,7 @@ mod tests {
     #[test]
     fn test_feature_extractor_differentiates_kick_and_hihat() {
         let sample_rate = 48000;
-        let feature_extractor = FeatureExtractor::new(sample_rate);
+        let feature_extractor = FeatureExtractor::new(sample_rate).unwrap();
 
         // Generate kick and hi-hat samples (1024 samples = ~21ms window)
         let kick = generate_kick(sample_rate, 50, 0);
@@ -175,7 +175,7 @@ mod tests {
 
         // Create pipeline components
         let mut onset_detector = OnsetDetector::new(sample_rate);
-        let feature_extractor = FeatureExtractor::new(sample_rate);
+        let feature_extractor = FeatureExtractor::new(sample_rate).unwrap();
         let calibration_state = Arc::new(RwLock::new(CalibrationState::new_default()));
         let classifier = Classifier::new(Arc::clone(&calibration_state));
         let frame_counter = Arc::new(AtomicU64::new(0));
@@ -186,7 +186,7 @@ mod tests {
         let signal = generate_kick(sample_rate, 300, sample_rate / 10);
 
         // Process through onset detector
-        let onsets = onset_detector.process(&signal);
+        let onsets = onset_detector.process_timestamps(&signal);
 
         if !onsets.is_empty() {
             // Extract features around the onset
@@ -0,0 +1,47 @@
+// Shared types - plain data used across layers
+//
+// Types here are referenced by config, analysis, calibration and telemetry
+// alike, so they live outside any one of those modules and depend on none
+// of them.
+
+/// BeatboxHit represents classified beatbox sounds
+///
+/// Level 1 sounds: Kick, Snare, HiHat
+/// Level 2 adds subcategories: ClosedHiHat, OpenHiHat, KSnare
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum BeatboxHit {
+    /// Kick drum (low frequency, low ZCR)
+    Kick,
+    /// Snare drum (mid frequency)
+    Snare,
+    /// Hi-hat (high frequency, high ZCR) - Level 1 generic
+    HiHat,
+    /// Closed hi-hat (short decay) - Level 2
+    ClosedHiHat,
+    /// Open hi-hat (long decay) - Level 2
+    OpenHiHat,
+    /// K-snare (kick+snare hybrid, noisy kick) - Level 2
+    KSnare,
+    /// Unknown sound (doesn't match any pattern)
+    Unknown,
+}
+
+impl BeatboxHit {
+    /// Every classifiable sound, in calibration/legend order (excludes `Unknown`)
+    pub const SUPPORTED: [BeatboxHit; 6] = [
+        BeatboxHit::Kick,
+        BeatboxHit::Snare,
+        BeatboxHit::HiHat,
+        BeatboxHit::ClosedHiHat,
+        BeatboxHit::OpenHiHat,
+        BeatboxHit::KSnare,
+    ];
+
+    /// Lowest classifier level that can produce this sound
+    pub fn min_level(self) -> u8 {
+        match self {
+            BeatboxHit::Kick | BeatboxHit::Snare | BeatboxHit::HiHat | BeatboxHit::Unknown => 1,
+            BeatboxHit::ClosedHiHat | BeatboxHit::OpenHiHat | BeatboxHit::KSnare => 2,
+        }
+    }
+}
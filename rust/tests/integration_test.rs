@@ -343,3 +343,31 @@ fn test_concurrent_access() {
 
     // If we got here, concurrent access is safe
 }
+
+/// Test that every submitted ParamPatch lands in the replayable history in order
+#[test]
+fn test_param_history_records_patches_in_order() {
+    use beatbox_trainer::engine::ParamPatch;
+
+    let context = AppContext::new();
+    for bpm in [90, 100, 110] {
+        let patch = ParamPatch {
+            bpm: Some(bpm),
+            ..ParamPatch::default()
+        };
+        context
+            .submit_params(patch)
+            .expect("command queue should accept patch");
+    }
+
+    let history = context.get_param_history();
+    assert_eq!(history.len(), 3, "each applied patch should be logged");
+    let bpms: Vec<_> = history.iter().map(|entry| entry.patch.bpm).collect();
+    assert_eq!(bpms, vec![Some(90), Some(100), Some(110)]);
+    assert!(
+        history
+            .windows(2)
+            .all(|pair| pair[0].timestamp_ms <= pair[1].timestamp_ms),
+        "history timestamps should be non-decreasing"
+    );
+}
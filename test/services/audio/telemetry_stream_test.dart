@@ -1,6 +1,5 @@
-import 'package:beatbox_trainer/bridge/api.dart/analysis/classifier.dart'
-    as ffi_classifier;
 import 'package:beatbox_trainer/bridge/api.dart/telemetry/events.dart' as ffi;
+import 'package:beatbox_trainer/bridge/api.dart/types.dart' as ffi_types;
 import 'package:beatbox_trainer/services/audio/telemetry_stream.dart';
 import 'package:flutter_test/flutter_test.dart';
 
@@ -27,7 +26,7 @@ void main() {
           percent: 72.0,
         ),
         ffi.MetricEvent.classification(
-          sound: ffi_classifier.BeatboxHit.kick,
+          sound: ffi_types.BeatboxHit.kick,
           confidence: 0.82,
           timingErrorMs: 4.0,
         ),
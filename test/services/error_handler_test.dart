@@ -76,6 +76,14 @@ void main() {
         expect(result, 'Internal error occurred. Please restart the app.');
       });
 
+      test('translates Unsupported error (code 1011)', () {
+        const rustError =
+            'AudioError::Unsupported { operation: "inject_classification" } (code 1011): inject_classification is not supported in this build';
+        final result = errorHandler.translateAudioError(rustError);
+
+        expect(result, 'This feature is not available in this build.');
+      });
+
       test('handles error without code using pattern matching - bpm', () {
         const rustError = 'BPM invalid: value out of range';
         final result = errorHandler.translateAudioError(rustError);
@@ -164,6 +172,47 @@ void main() {
         expect(result, 'Internal error occurred. Please restart the app.');
       });
 
+      test('translates Io error (code 2007)', () {
+        const rustError =
+            'CalibrationError::Io (code 2007): Calibration I/O error: denied';
+        final result = errorHandler.translateCalibrationError(rustError);
+
+        expect(
+          result,
+          'Could not read or save the calibration file. Please check storage access.',
+        );
+      });
+
+      test('translates Parse error (code 2008)', () {
+        const rustError =
+            'CalibrationError::Parse (code 2008): Calibration parse error: eof';
+        final result = errorHandler.translateCalibrationError(rustError);
+
+        expect(result, 'The calibration file is damaged. Please recalibrate.');
+      });
+
+      test('translates UnknownPreset error (code 2009)', () {
+        const rustError =
+            'CalibrationError::UnknownPreset (code 2009): Unknown calibration preset';
+        final result = errorHandler.translateCalibrationError(rustError);
+
+        expect(
+          result,
+          'That starter calibration is not available. Please calibrate instead.',
+        );
+      });
+
+      test('translates NotASoundPhase error (code 2010)', () {
+        const rustError =
+            'CalibrationError::NotASoundPhase (code 2010): The noise floor phase is a level measurement';
+        final result = errorHandler.translateCalibrationError(rustError);
+
+        expect(
+          result,
+          'This step measures your input level. Please wait for it to finish.',
+        );
+      });
+
       test(
         'handles error without code using pattern matching - insufficient',
         () {